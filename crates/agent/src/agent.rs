@@ -5,20 +5,33 @@ use thunderus_core::TaskContextTracker;
 use thunderus_core::memory::{MemoryRetriever, RetrievalPolicy, format_memory_context};
 use thunderus_core::*;
 use thunderus_providers::*;
-use thunderus_tools::{SessionToolDispatcher, classify_shell_command_risk, extract_scope};
+use thunderus_tools::{ErrorCategory, SessionToolDispatcher, classify_shell_command, extract_scope};
 use tokio::sync::mpsc;
 
+/// How many times a tool call is automatically re-requested after a retryable error
+const MAX_CORRECTED_RETRIES: u32 = 1;
+
 /// Metadata for tool execution
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct ToolExecutionMetadata {
     pub execution_time_ms: Option<u64>,
     pub classification_reasoning: Option<String>,
     pub affected_paths: Vec<String>,
+    /// Whether the call was stopped by approval policy instead of being executed
+    pub denied: bool,
+    /// 1 for the model's original call, 2 for its corrected retry
+    pub attempt: u32,
 }
 
 impl ToolExecutionMetadata {
     pub fn new() -> Self {
-        Self { execution_time_ms: None, classification_reasoning: None, affected_paths: Vec::new() }
+        Self {
+            execution_time_ms: None,
+            classification_reasoning: None,
+            affected_paths: Vec::new(),
+            denied: false,
+            attempt: 1,
+        }
     }
 
     pub fn with_execution_time(mut self, time_ms: u64) -> Self {
@@ -44,10 +57,16 @@ impl Default for ToolExecutionMetadata {
 }
 
 /// Events sent from agent to TUI
-#[derive(Debug, Clone)]
+///
+/// Serializes as `{"type": "<snake_case variant>", "data": ...}` for
+/// machine-readable output; unit variants omit `data`.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
 pub enum AgentEvent {
     /// Text token from model
     Token(String),
+    /// Tool call whose name is known while its arguments are still streaming
+    ToolCallStarted { name: String },
     /// Tool call initiated
     ToolCall {
         name: String,
@@ -70,6 +89,8 @@ pub enum AgentEvent {
     ApprovalRequest(ApprovalRequest),
     /// Approval response from user
     ApprovalResponse(ApprovalResponse),
+    /// Action allowed by policy without prompting, recorded for the audit trail
+    AutoApproved(ApprovalRecord),
     /// Approval mode changed
     ApprovalModeChanged { from: ApprovalMode, to: ApprovalMode },
     /// Memory retrieval completed
@@ -77,10 +98,15 @@ pub enum AgentEvent {
         query: String,
         chunks: Vec<thunderus_core::memory::RetrievedChunk>,
         total_tokens: usize,
+        token_budget: usize,
         search_time_ms: u64,
     },
     /// Error occurred
     Error(String),
+    /// Provider-reported token usage for the response, sent before `Finish`
+    Usage(Usage),
+    /// Provider-reported finish reason (see `FinishReason`), sent before `Done`
+    Finish(String),
     /// Generation complete
     Done,
 }
@@ -154,6 +180,18 @@ impl Agent {
         self
     }
 
+    /// Share a conversation buffer, so a later agent can pick up this one's history
+    pub fn with_conversation(mut self, messages: Arc<Mutex<Vec<ChatMessage>>>) -> Self {
+        self.messages = messages;
+        self
+    }
+
+    /// Share a task context tracker, so the task carries across agent turns
+    pub fn with_task_context(mut self, tracker: TaskContextTracker) -> Self {
+        self.task_context = Arc::new(tracker);
+        self
+    }
+
     /// Get the current approval mode
     pub fn approval_mode(&self) -> ApprovalMode {
         self.approval_gate.read().unwrap().mode()
@@ -202,6 +240,7 @@ impl Agent {
                         query: retrieval_result.query.clone(),
                         chunks: retrieval_result.chunks.clone(),
                         total_tokens: retrieval_result.total_tokens,
+                        token_budget: retrieval_result.token_budget,
                         search_time_ms: retrieval_result.search_time_ms,
                     });
 
@@ -215,26 +254,30 @@ impl Agent {
         }
 
         let mut messages_for_request = self.messages.lock().unwrap().clone();
-        if !system_message_content.is_empty() {
-            let has_system = messages_for_request.iter().any(|m| m.role == Role::System);
-            if has_system {
-                for msg in &mut messages_for_request {
-                    if msg.role == Role::System {
-                        msg.content.push_str(&system_message_content);
-                        break;
-                    }
-                }
-            } else {
-                messages_for_request.insert(
-                    0,
-                    ChatMessage {
-                        role: Role::System,
-                        content: format!("You are a helpful coding assistant.{}", system_message_content),
-                        tool_call_id: None,
-                        tool_calls: None,
-                    },
-                );
+        let base_prompt = self.profile.as_ref().map(|profile| {
+            build_system_prompt_with_overrides(
+                ProviderType::from_config(&profile.provider),
+                profile.system_prompt_override.as_deref(),
+                profile.system_prompt_append.as_deref(),
+            )
+        });
+        if let Some(msg) = messages_for_request.iter_mut().find(|m| m.role == Role::System) {
+            // The profile's prompt wins over the one the history was started with
+            if let Some(base_prompt) = base_prompt {
+                msg.content = base_prompt;
             }
+            msg.content.push_str(&system_message_content);
+        } else if base_prompt.is_some() || !system_message_content.is_empty() {
+            let base_prompt = base_prompt.unwrap_or_else(|| "You are a helpful coding assistant.".to_string());
+            messages_for_request.insert(
+                0,
+                ChatMessage {
+                    role: Role::System,
+                    content: format!("{}{}", base_prompt, system_message_content),
+                    tool_call_id: None,
+                    tool_calls: None,
+                },
+            );
         }
 
         messages_for_request.push(ChatMessage::user(user_input.to_string()));
@@ -251,6 +294,7 @@ impl Agent {
             .max_tokens(8192)
             .build();
 
+        let retry_request = request.clone();
         let provider = Arc::clone(&self.provider);
         let cancel_token_clone = cancel_token.clone();
         let cancel_token_for_stream = cancel_token.clone();
@@ -289,39 +333,43 @@ impl Agent {
                         assistant_buffer.push_str(&text);
                         let _ = tx.send(AgentEvent::Token(text));
                     }
+                    StreamEvent::ToolCallStarted(call) => {
+                        let _ = tx.send(AgentEvent::ToolCallStarted { name: call.function.name });
+                    }
                     StreamEvent::ToolCall(calls) => {
-                        for call in calls {
-                            let args = call.function.arguments.clone();
-                            let classification = classify_tool_risk(&call.function.name, &call.function.arguments);
-                            let description = generate_tool_description(&call.function.name, &call.function.arguments);
-                            let scope_info = extract_scope(&call.function.name, &call.function.arguments);
-                            let scope = if !scope_info.is_empty() { Some(scope_info.to_detailed()) } else { None };
-                            let task_context_str = task_context.brief_description();
-
-                            let _ = tx.send(AgentEvent::ToolCall {
-                                name: call.function.name.clone(),
-                                args,
-                                risk: classification.risk,
-                                description: Some(description),
-                                task_context: task_context_str,
-                                scope,
-                                classification_reasoning: Some(classification.reasoning),
-                            });
-
-                            if let Some(dispatcher) = &tool_dispatcher {
-                                let (tool_result, metadata) =
-                                    execute_tool_call(dispatcher, &approval_protocol, &approval_gate, &profile, &call);
-
-                                if tool_result.is_success() {
-                                    let msg = ChatMessage {
-                                        role: Role::Tool,
-                                        content: tool_result.content.clone(),
-                                        tool_call_id: Some(tool_result.tool_call_id.clone()),
-                                        tool_calls: None,
-                                    };
-                                    messages.lock().unwrap().push(msg);
+                        let mut batch = calls.clone();
+                        let mut batch_results: Vec<ChatMessage> = Vec::new();
+                        for (index, mut call) in calls.into_iter().enumerate() {
+                            let mut attempt = 1;
+                            loop {
+                                send_tool_call_event(&call, &task_context, &tx);
+
+                                let Some(dispatcher) = &tool_dispatcher else { break };
+                                let (tool_result, mut metadata) = execute_tool_call(
+                                    dispatcher,
+                                    &approval_protocol,
+                                    &approval_gate,
+                                    &profile,
+                                    &call,
+                                    &cancel_token_clone,
+                                    &tx,
+                                );
+                                metadata.attempt = attempt;
+
+                                let result_msg = ChatMessage {
+                                    role: Role::Tool,
+                                    content: tool_result.error.clone().unwrap_or_else(|| tool_result.content.clone()),
+                                    tool_call_id: Some(tool_result.tool_call_id.clone()),
+                                    tool_calls: None,
+                                };
+                                // Rejections stay in the history so the next turn sees the user's reason
+                                if tool_result.is_success() || metadata.denied {
+                                    messages.lock().unwrap().push(result_msg.clone());
                                 }
 
+                                let retry_error = retryable_error(&tool_result, &metadata)
+                                    .filter(|_| attempt <= MAX_CORRECTED_RETRIES && !cancel_token_clone.is_cancelled());
+
                                 let _ = tx.send(AgentEvent::ToolResult {
                                     name: call.function.name.clone(),
                                     result: tool_result.content.clone(),
@@ -329,9 +377,41 @@ impl Agent {
                                     error: tool_result.error.clone(),
                                     metadata,
                                 });
+
+                                let Some(error) = retry_error else {
+                                    batch_results.push(result_msg);
+                                    break;
+                                };
+                                let turn = retry_turn(&assistant_buffer, &batch, index, &batch_results, &error);
+                                match request_corrected_call(
+                                    &provider,
+                                    &retry_request,
+                                    turn,
+                                    call.name(),
+                                    &cancel_token_clone,
+                                    &tx,
+                                )
+                                .await
+                                {
+                                    Some(corrected) => {
+                                        batch[index] = corrected.clone();
+                                        call = corrected;
+                                        attempt += 1;
+                                    }
+                                    None => {
+                                        batch_results.push(result_msg);
+                                        break;
+                                    }
+                                }
                             }
                         }
                     }
+                    StreamEvent::Usage(usage) => {
+                        let _ = tx.send(AgentEvent::Usage(usage));
+                    }
+                    StreamEvent::Finish(reason) => {
+                        let _ = tx.send(AgentEvent::Finish(reason));
+                    }
                     StreamEvent::Done => {
                         if !assistant_buffer.is_empty() {
                             let msg = ChatMessage::assistant(assistant_buffer);
@@ -362,6 +442,7 @@ impl Agent {
                 affected_paths: Vec::new(),
                 metadata: std::collections::HashMap::new(),
                 classification_reasoning: None,
+                diff: None,
             },
             risk_level: ToolRisk::Safe,
             created_at: chrono::Utc::now().to_rfc3339(),
@@ -370,7 +451,9 @@ impl Agent {
         let decision = self.approval_protocol.request_approval(&approval_request)?;
 
         match decision {
-            ApprovalDecision::Approved => Ok(ToolResult::success(format!("call_{}", name), "Executed successfully")),
+            ApprovalDecision::Approved | ApprovalDecision::AutoApproved => {
+                Ok(ToolResult::success(format!("call_{}", name), "Executed successfully"))
+            }
             ApprovalDecision::Rejected => Ok(ToolResult::error(format!("call_{}", name), "Rejected by user")),
             ApprovalDecision::Cancelled => Ok(ToolResult::error(format!("call_{}", name), "Cancelled")),
         }
@@ -393,30 +476,145 @@ impl Agent {
     }
 }
 
+fn send_tool_call_event(call: &ToolCall, task_context: &TaskContextTracker, tx: &mpsc::UnboundedSender<AgentEvent>) {
+    let classification = classify_tool_risk(&call.function.name, &call.function.arguments);
+    let description = generate_tool_description(&call.function.name, &call.function.arguments);
+    let scope_info = extract_scope(&call.function.name, &call.function.arguments);
+    let scope = if !scope_info.is_empty() { Some(scope_info.to_detailed()) } else { None };
+
+    let _ = tx.send(AgentEvent::ToolCall {
+        name: call.function.name.clone(),
+        args: call.function.arguments.clone(),
+        risk: classification.risk,
+        description: Some(description),
+        task_context: task_context.brief_description(),
+        scope,
+        classification_reasoning: Some(classification.reasoning),
+    });
+}
+
+/// The error from a failed call if the model could fix it by correcting its arguments
+///
+/// Only teaching errors in a retryable category qualify; denials, cancellations
+/// and system failures would fail the same way again.
+fn retryable_error(result: &ToolResult, metadata: &ToolExecutionMetadata) -> Option<String> {
+    if metadata.denied || result.is_cancelled() {
+        return None;
+    }
+    let error = result.error.as_ref()?;
+    ErrorCategory::from_message(error)
+        .filter(|category| category.is_retryable())
+        .map(|_| error.clone())
+}
+
+/// The turn replayed when asking for a corrected call
+///
+/// Carries the streamed assistant text and every call in the batch, followed by
+/// one result per call: the results of calls already run, the teaching error
+/// for the failed call at `index`, and a note for calls still waiting on it.
+fn retry_turn(text: &str, batch: &[ToolCall], index: usize, results: &[ChatMessage], error: &str) -> Vec<ChatMessage> {
+    let failed_call = &batch[index];
+    let tool_message = |call: &ToolCall, content: String| ChatMessage {
+        role: Role::Tool,
+        content,
+        tool_call_id: Some(call.id.clone()),
+        tool_calls: None,
+    };
+
+    let mut turn = vec![ChatMessage {
+        role: Role::Assistant,
+        content: text.to_string(),
+        tool_call_id: None,
+        tool_calls: Some(batch.to_vec()),
+    }];
+    turn.extend(results.iter().cloned());
+    turn.push(tool_message(
+        failed_call,
+        format!(
+            "{}\n\nCall `{}` again with corrected arguments. This is the only automatic retry.",
+            error,
+            failed_call.name()
+        ),
+    ));
+    for pending in &batch[index + 1..] {
+        turn.push(tool_message(
+            pending,
+            format!("Not run yet: waits for the corrected `{}` call.", failed_call.name()),
+        ));
+    }
+    turn
+}
+
+/// Ask the model to correct a failed tool call
+///
+/// Replays the original request with `turn` (see [`retry_turn`]) appended,
+/// and returns the first call to the failed tool in the response.
+async fn request_corrected_call(
+    provider: &Arc<dyn Provider>, request: &ChatRequest, turn: Vec<ChatMessage>, tool_name: &str,
+    cancel_token: &CancelToken, tx: &mpsc::UnboundedSender<AgentEvent>,
+) -> Option<ToolCall> {
+    let mut request = request.clone();
+    request.messages.extend(turn);
+
+    let stream = provider.stream_chat(request, cancel_token.clone()).await.ok()?;
+    tokio::pin!(stream);
+
+    while let Some(event) = stream.next().await {
+        match event {
+            StreamEvent::ToolCall(calls) => {
+                if let Some(call) = calls.into_iter().find(|call| call.name() == tool_name) {
+                    return Some(call);
+                }
+            }
+            StreamEvent::Usage(usage) => {
+                let _ = tx.send(AgentEvent::Usage(usage));
+            }
+            StreamEvent::Done => break,
+            _ => {}
+        }
+    }
+    None
+}
+
 fn execute_tool_call(
     dispatcher: &Arc<Mutex<SessionToolDispatcher>>, approval_protocol: &Arc<dyn ApprovalProtocol>,
-    approval_gate: &Arc<RwLock<ApprovalGate>>, profile: &Option<Profile>, call: &ToolCall,
+    approval_gate: &Arc<RwLock<ApprovalGate>>, profile: &Option<Profile>, call: &ToolCall, cancel_token: &CancelToken,
+    tx: &mpsc::UnboundedSender<AgentEvent>,
 ) -> (ToolResult, ToolExecutionMetadata) {
     let tool_name = call.name();
     let args = call.arguments();
     let mut metadata = ToolExecutionMetadata::new();
 
+    if let Some(profile) = profile
+        && !profile.tools.allows(tool_name)
+    {
+        metadata.denied = true;
+        return (
+            ToolResult::error(call.id.clone(), profile.tools.denial_message(tool_name, &profile.name)),
+            metadata,
+        );
+    }
+
     let tool_is_read_only = dispatcher
         .lock()
         .ok()
         .and_then(|guard| guard.dispatcher().registry().tool_is_read_only(tool_name))
         .unwrap_or(false);
 
+    let shell_classification = match args.get("command").and_then(|v| v.as_str()) {
+        Some(command) if tool_name == "shell" => Some(classify_shell_command(command)),
+        _ => None,
+    };
     let risk = if tool_name == "shell" {
-        args.get("command")
-            .and_then(|v| v.as_str())
-            .map(classify_shell_command_risk)
+        shell_classification
+            .as_ref()
+            .map(|classification| classification.risk)
             .unwrap_or(ToolRisk::Risky)
     } else {
         dispatcher
             .lock()
             .ok()
-            .and_then(|guard| guard.dispatcher().registry().tool_risk(tool_name))
+            .and_then(|guard| guard.dispatcher().registry().call_risk(tool_name, args))
             .unwrap_or_else(|| classify_tool_risk(tool_name, args).risk)
     };
 
@@ -424,22 +622,27 @@ fn execute_tool_call(
     let approval_mode = approval_gate.read().unwrap().mode();
 
     if approval_mode == ApprovalMode::ReadOnly && !tool_is_read_only {
+        metadata.denied = true;
         return (
             ToolResult::error(call.id.clone(), "Tool execution blocked: read-only mode"),
             metadata,
         );
     }
 
-    let mut requires_approval = approval_gate
-        .read()
-        .unwrap()
-        .check_requires_approval(risk, &action_type);
+    let mut requires_approval = {
+        let gate = approval_gate.read().unwrap();
+        match args.get("command").and_then(|v| v.as_str()) {
+            Some(command) if tool_name == "shell" => gate.check_shell_command(risk, command),
+            _ => gate.check_requires_approval(risk, &action_type),
+        }
+    };
 
     if let Some(path) = extract_target_path(args) {
         metadata.affected_paths = vec![path.display().to_string()];
         if let Some(profile) = profile {
             match profile.check_path_access(&path, approval_mode) {
                 thunderus_core::config::PathAccessResult::Denied(reason) => {
+                    metadata.denied = true;
                     return (
                         ToolResult::error(
                             call.id.clone(),
@@ -450,6 +653,7 @@ fn execute_tool_call(
                 }
                 thunderus_core::config::PathAccessResult::ReadOnly => {
                     if !tool_is_read_only {
+                        metadata.denied = true;
                         return (
                             ToolResult::error(call.id.clone(), "Tool execution blocked: read-only access"),
                             metadata,
@@ -464,17 +668,61 @@ fn execute_tool_call(
         }
     }
 
-    if requires_approval && !request_tool_approval(approval_protocol, approval_gate, action_type, tool_name, args, risk)
-    {
-        return (
-            ToolResult::error(call.id.clone(), "Tool execution rejected by user"),
-            metadata,
+    let remembered_rule = if requires_approval && !risk.is_blocked() {
+        let context = ApprovalContext::new().with_name(tool_name).with_arguments(args.clone());
+        approval_gate
+            .read()
+            .unwrap()
+            .matching_rule(&action_type, &context)
+            .map(|rule| rule.to_string())
+    } else {
+        None
+    };
+
+    if let Some(rule) = remembered_rule {
+        let record = approval_gate.write().unwrap().record_auto_approval(
+            action_type,
+            format!("Execute tool: {}", tool_name),
+            ApprovalContext::new().with_name(tool_name).with_arguments(args.clone()),
+            risk,
+            format!("matches session rule: {}", rule),
         );
+        let _ = tx.send(AgentEvent::AutoApproved(record));
+    } else if requires_approval {
+        let diff = dispatcher
+            .lock()
+            .ok()
+            .and_then(|guard| guard.dispatcher().registry().preview_diff(tool_name, args));
+        let context = ApprovalContext {
+            name: Some(tool_name.to_string()),
+            arguments: Some(args.clone()),
+            affected_paths: Vec::new(),
+            metadata: std::collections::HashMap::new(),
+            classification_reasoning: shell_classification.as_ref().map(Classification::explain),
+            diff,
+        };
+        if let Err(reason) = request_tool_approval(approval_protocol, approval_gate, action_type, context, risk) {
+            metadata.denied = true;
+            let message = match reason {
+                Some(reason) => format!("Tool execution rejected by user. Reason: {}", reason),
+                None => "Tool execution rejected by user".to_string(),
+            };
+            return (ToolResult::error(call.id.clone(), message), metadata);
+        }
+    } else {
+        let record = approval_gate.write().unwrap().record_auto_approval(
+            action_type,
+            format!("Execute tool: {}", tool_name),
+            ApprovalContext::new().with_name(tool_name).with_arguments(args.clone()),
+            risk,
+            auto_approval_reason(approval_mode, risk),
+        );
+        let _ = tx.send(AgentEvent::AutoApproved(record));
     }
 
     let start = std::time::Instant::now();
     let result = match dispatcher.lock() {
-        Ok(mut guard) => guard.execute(call),
+        Ok(mut guard) => guard.execute_cancellable(call, cancel_token),
         Err(_) => Err(thunderus_core::Error::Tool("Tool dispatcher lock poisoned".to_string())),
     };
     metadata.execution_time_ms = Some(start.elapsed().as_millis() as u64);
@@ -488,41 +736,41 @@ fn execute_tool_call(
     }
 }
 
+/// Ask the user to approve a tool call and record the decision on the gate
+///
+/// Returns the user's reason, if they gave one, when the call is not approved.
 fn request_tool_approval(
     approval_protocol: &Arc<dyn ApprovalProtocol>, approval_gate: &Arc<RwLock<ApprovalGate>>, action_type: ActionType,
-    tool_name: &str, args: &serde_json::Value, risk: ToolRisk,
-) -> bool {
+    context: ApprovalContext, risk: ToolRisk,
+) -> std::result::Result<(), Option<String>> {
     let approval_request = {
         let mut gate = approval_gate.write().unwrap();
-        let id = gate.create_request(
-            action_type,
-            format!("Execute tool: {}", tool_name),
-            ApprovalContext {
-                name: Some(tool_name.to_string()),
-                arguments: Some(args.clone()),
-                affected_paths: Vec::new(),
-                metadata: std::collections::HashMap::new(),
-                classification_reasoning: None,
-            },
-            risk,
-        );
+        let description = format!("Execute tool: {}", context.name.as_deref().unwrap_or_default());
+        let id = gate.create_request(action_type, description, context, risk);
         gate.get_request(id).cloned()
     };
 
     let Some(approval_request) = approval_request else {
-        return false;
+        return Err(None);
     };
 
-    let decision = approval_protocol.request_approval(&approval_request);
-    if let Ok(decision) = decision {
-        let mut gate = approval_gate.write().unwrap();
-        let _ = gate.record_decision(ApprovalResponse::new(approval_request.id, decision));
-        matches!(decision, ApprovalDecision::Approved)
-    } else {
-        false
+    let Ok(response) = approval_protocol.request_response(&approval_request) else {
+        return Err(None);
+    };
+    let decision = response.decision;
+    let reason = response.message.clone();
+    let _ = approval_gate.write().unwrap().record_decision(response);
+    match decision {
+        ApprovalDecision::Approved => Ok(()),
+        _ => Err(reason),
     }
 }
 
+/// Explain why policy let a tool call through without prompting
+fn auto_approval_reason(mode: ApprovalMode, risk: ToolRisk) -> String {
+    format!("{} action allowed by {} mode", risk.as_str(), mode)
+}
+
 fn extract_target_path(args: &serde_json::Value) -> Option<PathBuf> {
     args.get("file_path")
         .or_else(|| args.get("path"))
@@ -549,18 +797,6 @@ fn tool_action_type(tool_name: &str, args: &serde_json::Value) -> ActionType {
     }
 }
 
-fn is_network_command(command: &str) -> bool {
-    let cmd_lower = command.to_lowercase();
-    cmd_lower.contains("curl ")
-        || cmd_lower.contains("wget ")
-        || cmd_lower.starts_with("curl")
-        || cmd_lower.starts_with("wget")
-        || cmd_lower.contains("ssh ")
-        || cmd_lower.starts_with("ssh")
-        || cmd_lower.contains("http://")
-        || cmd_lower.contains("https://")
-}
-
 /// Generate next approval ID (simplified for testing)
 fn get_next_approval_id() -> ApprovalId {
     std::time::SystemTime::now()
@@ -607,13 +843,15 @@ fn classify_tool_risk(tool_name: &str, arguments: &serde_json::Value) -> ToolCla
             }
         }
         name if name.contains("shell") || name.contains("exec") || name.contains("command") => {
-            let cmd = arguments
-                .get("command")
-                .and_then(|v| v.as_str())
-                .unwrap_or("arbitrary commands");
-            ToolClassification {
-                risk: ToolRisk::Risky,
-                reasoning: format!("Shell execution of {} can have unintended side effects", cmd),
+            match arguments.get("command").and_then(|v| v.as_str()) {
+                Some(cmd) => {
+                    let classification = classify_shell_command(cmd);
+                    ToolClassification { risk: classification.risk, reasoning: classification.explain() }
+                }
+                None => ToolClassification {
+                    risk: ToolRisk::Risky,
+                    reasoning: "Shell execution of arbitrary commands can have unintended side effects".to_string(),
+                },
             }
         }
         name if name.contains("http") || name.contains("fetch") || name.contains("request") => ToolClassification {
@@ -700,7 +938,7 @@ mod tests {
     use tempfile::TempDir;
     use thunderus_core::memory::{MemoryKind, MemoryRetriever, RetrievalResult, RetrievedChunk};
     use thunderus_providers::Provider;
-    use thunderus_tools::{EchoTool, SessionToolDispatcher, ToolDispatcher, ToolRegistry};
+    use thunderus_tools::{EchoTool, ReadHistory, SessionToolDispatcher, ToolDispatcher, ToolRegistry};
 
     type R<'a, T> = Result<Pin<Box<dyn futures::Stream<Item = T> + Send + 'a>>>;
 
@@ -754,8 +992,10 @@ mod tests {
                         event_ids: vec![],
                         kind: MemoryKind::Core,
                         score: -1.0,
+                        token_count: 5,
                     }],
                     total_tokens: 5,
+                    token_budget: 2000,
                     query: "test".to_string(),
                     search_time_ms: 1,
                 })
@@ -777,6 +1017,28 @@ mod tests {
         assert_eq!(agent.messages().len(), 0);
     }
 
+    #[test]
+    fn test_agent_event_serialization() {
+        let token = serde_json::to_value(AgentEvent::Token("hi".to_string())).unwrap();
+        assert_eq!(token, serde_json::json!({"type": "token", "data": "hi"}));
+
+        let done = serde_json::to_value(AgentEvent::Done).unwrap();
+        assert_eq!(done, serde_json::json!({"type": "done"}));
+
+        let result = serde_json::to_value(AgentEvent::ToolResult {
+            name: "read".to_string(),
+            result: "contents".to_string(),
+            success: true,
+            error: None,
+            metadata: ToolExecutionMetadata::new().with_execution_time(5),
+        })
+        .unwrap();
+        assert_eq!(result["type"], "tool_result");
+        assert_eq!(result["data"]["name"], "read");
+        assert_eq!(result["data"]["metadata"]["execution_time_ms"], 5);
+        assert_eq!(result["data"]["metadata"]["denied"], false);
+    }
+
     #[test]
     fn test_agent_approval_mode() {
         let provider = Arc::new(MockProvider { events: vec![] }) as Arc<dyn Provider>;
@@ -951,6 +1213,35 @@ mod tests {
         assert_eq!(agent.messages().len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_process_message_forwards_finish_reason_before_done() {
+        let events = vec![
+            StreamEvent::Token("Partial".to_string()),
+            StreamEvent::Finish("length".to_string()),
+            StreamEvent::Done,
+        ];
+        let provider = Arc::new(MockProvider { events }) as Arc<dyn Provider>;
+        let approval = Arc::new(InMemoryApprovalProtocol::new(true)) as Arc<dyn ApprovalProtocol>;
+        let gate = ApprovalGate::new(ApprovalMode::Auto, false);
+
+        let mut agent = Agent::new(provider, approval, gate, SessionId::new());
+        let mut rx = agent
+            .process_message("Hi", None, CancelToken::new(), Vec::new())
+            .await
+            .unwrap();
+
+        let mut finish = None;
+        while let Some(event) = rx.recv().await {
+            match event {
+                AgentEvent::Finish(reason) => finish = Some(reason),
+                AgentEvent::Done => break,
+                _ => {}
+            }
+        }
+
+        assert_eq!(finish.as_deref(), Some("length"));
+    }
+
     #[tokio::test]
     async fn test_process_message_appends_assistant_response() {
         let events = vec![StreamEvent::Token("Hello".to_string()), StreamEvent::Done];
@@ -1019,6 +1310,151 @@ mod tests {
         assert!(system.contains("Remember this"));
     }
 
+    #[tokio::test]
+    async fn test_shared_conversation_carries_history_to_next_agent() {
+        let conversation = Arc::new(Mutex::new(Vec::new()));
+        let events = vec![StreamEvent::Token("Partial".to_string()), StreamEvent::Done];
+        let provider = Arc::new(MockProvider { events }) as Arc<dyn Provider>;
+        let approval = Arc::new(InMemoryApprovalProtocol::new(true)) as Arc<dyn ApprovalProtocol>;
+
+        let mut first = Agent::new(
+            provider,
+            approval.clone(),
+            ApprovalGate::new(ApprovalMode::Auto, false),
+            SessionId::new(),
+        )
+        .with_conversation(Arc::clone(&conversation));
+        let mut rx = first
+            .process_message("Write it", None, CancelToken::new(), Vec::new())
+            .await
+            .unwrap();
+        while let Some(event) = rx.recv().await {
+            if matches!(event, AgentEvent::Done) {
+                break;
+            }
+        }
+
+        let captured = Arc::new(Mutex::new(None));
+        let provider = Arc::new(CaptureProvider { events: vec![StreamEvent::Done], captured: Arc::clone(&captured) })
+            as Arc<dyn Provider>;
+        let mut second = Agent::new(
+            provider,
+            approval,
+            ApprovalGate::new(ApprovalMode::Auto, false),
+            SessionId::new(),
+        )
+        .with_conversation(conversation);
+        let mut rx = second
+            .process_message("Continue", None, CancelToken::new(), Vec::new())
+            .await
+            .unwrap();
+        while let Some(event) = rx.recv().await {
+            if matches!(event, AgentEvent::Done) {
+                break;
+            }
+        }
+
+        let request = captured.lock().unwrap().clone().expect("expected request capture");
+        let contents: Vec<&str> = request.messages.iter().map(|m| m.content.as_str()).collect();
+        assert_eq!(contents, vec!["Write it", "Partial", "Continue"]);
+    }
+
+    #[tokio::test]
+    async fn test_system_message_applies_profile_prompt_overrides() {
+        let captured = Arc::new(Mutex::new(None));
+        let provider = Arc::new(CaptureProvider { events: vec![StreamEvent::Done], captured: Arc::clone(&captured) })
+            as Arc<dyn Provider>;
+        let approval = Arc::new(InMemoryApprovalProtocol::new(true)) as Arc<dyn ApprovalProtocol>;
+        let gate = ApprovalGate::new(ApprovalMode::Auto, false);
+
+        let config = Config::from_toml_str(
+            r#"
+default_profile = "default"
+
+[profiles.default]
+name = "default"
+working_root = "/workspace"
+system_prompt_override = "You review Rust code."
+system_prompt_append = "Never touch generated files."
+
+[profiles.default.provider]
+provider = "gemini"
+api_key = "key"
+model = "gemini-3-flash-preview"
+"#,
+        )
+        .unwrap();
+        let profile = config.profile("default").unwrap().clone();
+
+        let mut agent = Agent::new(provider, approval, gate, SessionId::new()).with_profile(profile);
+        let mut rx = agent
+            .process_message("Hi", None, CancelToken::new(), Vec::new())
+            .await
+            .unwrap();
+        while let Some(event) = rx.recv().await {
+            if matches!(event, AgentEvent::Done) {
+                break;
+            }
+        }
+
+        let request = captured.lock().unwrap().clone().expect("expected request capture");
+        let system = &request.messages[0];
+        assert_eq!(system.role, Role::System);
+        assert!(system.content.starts_with("You review Rust code."));
+        assert!(system.content.contains("Tool Usage Guidelines"));
+        assert!(system.content.ends_with("Never touch generated files."));
+    }
+
+    #[tokio::test]
+    async fn test_profile_prompt_replaces_system_message_in_history() {
+        let captured = Arc::new(Mutex::new(None));
+        let provider = Arc::new(CaptureProvider { events: vec![StreamEvent::Done], captured: Arc::clone(&captured) })
+            as Arc<dyn Provider>;
+        let approval = Arc::new(InMemoryApprovalProtocol::new(true)) as Arc<dyn ApprovalProtocol>;
+        let gate = ApprovalGate::new(ApprovalMode::Auto, false);
+
+        let config = Config::from_toml_str(
+            r#"
+default_profile = "default"
+
+[profiles.default]
+name = "default"
+working_root = "/workspace"
+system_prompt_override = "You review Rust code."
+
+[profiles.default.provider]
+provider = "gemini"
+api_key = "key"
+model = "gemini-3-flash-preview"
+"#,
+        )
+        .unwrap();
+        let profile = config.profile("default").unwrap().clone();
+
+        let conversation = Arc::new(Mutex::new(vec![
+            ChatMessage { role: Role::System, content: "Old prompt".to_string(), tool_call_id: None, tool_calls: None },
+            ChatMessage::user("Earlier question".to_string()),
+        ]));
+        let mut agent = Agent::new(provider, approval, gate, SessionId::new())
+            .with_conversation(conversation)
+            .with_profile(profile);
+        let mut rx = agent
+            .process_message("Hi", None, CancelToken::new(), Vec::new())
+            .await
+            .unwrap();
+        while let Some(event) = rx.recv().await {
+            if matches!(event, AgentEvent::Done) {
+                break;
+            }
+        }
+
+        let request = captured.lock().unwrap().clone().expect("expected request capture");
+        let system: Vec<_> = request.messages.iter().filter(|m| m.role == Role::System).collect();
+        assert_eq!(system.len(), 1);
+        assert!(system[0].content.starts_with("You review Rust code."));
+        assert!(!system[0].content.contains("Old prompt"));
+    }
+
     #[tokio::test]
     async fn test_tool_call_executes_with_dispatcher() {
         let provider = Arc::new(MockProvider {
@@ -1055,9 +1491,16 @@ mod tests {
             .unwrap();
 
         let mut saw_tool_result = false;
+        let mut saw_auto_approval = false;
 
         while let Ok(Some(event)) = tokio::time::timeout(std::time::Duration::from_millis(200), rx.recv()).await {
             match event {
+                AgentEvent::AutoApproved(record) => {
+                    assert_eq!(record.decision, ApprovalDecision::AutoApproved);
+                    assert_eq!(record.request.context.name.as_deref(), Some("echo"));
+                    assert!(record.reason.is_some());
+                    saw_auto_approval = true;
+                }
                 AgentEvent::ToolResult { name, result, success, .. } => {
                     assert_eq!(name, "echo");
                     assert!(success);
@@ -1070,6 +1513,394 @@ mod tests {
         }
 
         assert!(saw_tool_result);
+        assert!(saw_auto_approval);
+        assert_eq!(agent.approval_gate().read().unwrap().stats().auto_approved, 1);
+    }
+
+    #[test]
+    fn test_retry_turn_replays_the_whole_batch() {
+        let batch = vec![
+            ToolCall::new("call_1", "read", serde_json::json!({"file_path": "a.rs"})),
+            ToolCall::new("call_2", "edit", serde_json::json!({})),
+            ToolCall::new("call_3", "read", serde_json::json!({"file_path": "b.rs"})),
+        ];
+        let done = ChatMessage {
+            role: Role::Tool,
+            content: "fn a() {}".to_string(),
+            tool_call_id: Some("call_1".to_string()),
+            tool_calls: None,
+        };
+
+        let turn = retry_turn("Let me fix both.", &batch, 1, &[done], "missing file_path");
+
+        assert_eq!(turn.len(), 4);
+        assert_eq!(turn[0].role, Role::Assistant);
+        assert_eq!(turn[0].content, "Let me fix both.");
+        assert_eq!(turn[0].tool_calls.as_ref().map(Vec::len), Some(3));
+        let ids: Vec<_> = turn[1..].iter().map(|m| m.tool_call_id.as_deref().unwrap()).collect();
+        assert_eq!(ids, ["call_1", "call_2", "call_3"]);
+        assert_eq!(turn[1].content, "fn a() {}");
+        assert!(turn[2].content.starts_with("missing file_path"));
+        assert!(turn[3].content.contains("Not run yet"));
+    }
+
+    #[derive(Debug, Default)]
+    struct RecordingApproval {
+        requests: Arc<Mutex<Vec<ApprovalRequest>>>,
+    }
+
+    impl ApprovalProtocol for RecordingApproval {
+        fn name(&self) -> &str {
+            "recording"
+        }
+
+        fn request_approval(&self, request: &ApprovalRequest) -> Result<ApprovalDecision> {
+            self.requests.lock().unwrap().push(request.clone());
+            Ok(ApprovalDecision::Rejected)
+        }
+    }
+
+    /// Rejects every request with the same reason
+    #[derive(Debug)]
+    struct ReasonedRejection(&'static str);
+
+    impl ApprovalProtocol for ReasonedRejection {
+        fn name(&self) -> &str {
+            "reasoned-rejection"
+        }
+
+        fn request_approval(&self, _request: &ApprovalRequest) -> Result<ApprovalDecision> {
+            Ok(ApprovalDecision::Rejected)
+        }
+
+        fn request_response(&self, request: &ApprovalRequest) -> Result<ApprovalResponse> {
+            Ok(ApprovalResponse::rejected(request.id).with_message(self.0))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rejection_reason_reaches_model_and_gate() {
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("lib.rs");
+        std::fs::write(&file, "fn answer() -> u32 {\n    41\n}\n").unwrap();
+
+        let provider = Arc::new(thunderus_providers::MockProvider::scripted(vec![
+            ChatResponse::new(ChatMessage::assistant("")).with_tool_calls(vec![ToolCall::new(
+                "call_1",
+                "edit",
+                serde_json::json!({
+                    "file_path": file.to_string_lossy(),
+                    "old_string": "41",
+                    "new_string": "42"
+                }),
+            )]),
+            ChatResponse::new(ChatMessage::assistant("Understood")),
+        ])) as Arc<dyn Provider>;
+
+        let session = Session::new(AgentDir::new(temp.path())).unwrap();
+        let registry = ToolRegistry::with_builtin_tools();
+        let specs = registry.specs();
+        let session_dispatcher = SessionToolDispatcher::with_new_history(ToolDispatcher::new(registry), session);
+
+        let approval = Arc::new(ReasonedRejection("the answer is 41 on purpose"));
+        let gate = ApprovalGate::new(ApprovalMode::Auto, false);
+        let mut agent = Agent::new(provider, approval, gate, SessionId::new())
+            .with_tool_dispatcher(Arc::new(Mutex::new(session_dispatcher)));
+
+        let mut rx = agent
+            .process_message("Fix the answer", Some(specs), CancelToken::new(), Vec::new())
+            .await
+            .unwrap();
+        let mut rejection = None;
+        while let Ok(Some(event)) = tokio::time::timeout(std::time::Duration::from_millis(500), rx.recv()).await {
+            match event {
+                AgentEvent::ToolResult { error, success: false, .. } => rejection = error,
+                AgentEvent::Done => break,
+                _ => {}
+            }
+        }
+
+        let rejection = rejection.expect("the rejected call should produce a tool result");
+        assert!(
+            rejection.contains("Reason: the answer is 41 on purpose"),
+            "{}",
+            rejection
+        );
+        assert!(
+            agent
+                .messages
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|msg| msg.role == Role::Tool && msg.content.contains("the answer is 41 on purpose"))
+        );
+
+        let record = agent.approval_gate().read().unwrap().history()[0].clone();
+        assert_eq!(record.decision, ApprovalDecision::Rejected);
+        assert_eq!(record.reason.as_deref(), Some("the answer is 41 on purpose"));
+    }
+
+    #[tokio::test]
+    async fn test_edit_approval_request_carries_diff() {
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("lib.rs");
+        std::fs::write(&file, "fn answer() -> u32 {\n    41\n}\n").unwrap();
+
+        let provider = Arc::new(thunderus_providers::MockProvider::scripted(vec![
+            ChatResponse::new(ChatMessage::assistant("")).with_tool_calls(vec![ToolCall::new(
+                "call_1",
+                "edit",
+                serde_json::json!({
+                    "file_path": file.to_string_lossy(),
+                    "old_string": "41",
+                    "new_string": "42"
+                }),
+            )]),
+            ChatResponse::new(ChatMessage::assistant("Done")),
+        ])) as Arc<dyn Provider>;
+
+        let approval = Arc::new(RecordingApproval::default());
+        let requests = Arc::clone(&approval.requests);
+        let gate = ApprovalGate::new(ApprovalMode::Auto, false);
+
+        let session = Session::new(AgentDir::new(temp.path())).unwrap();
+        let registry = ToolRegistry::with_builtin_tools();
+        let specs = registry.specs();
+        let session_dispatcher = SessionToolDispatcher::with_new_history(ToolDispatcher::new(registry), session);
+
+        let mut agent = Agent::new(provider, approval, gate, SessionId::new())
+            .with_tool_dispatcher(Arc::new(Mutex::new(session_dispatcher)));
+
+        let mut rx = agent
+            .process_message("Fix the answer", Some(specs), CancelToken::new(), Vec::new())
+            .await
+            .unwrap();
+        while let Ok(Some(event)) = tokio::time::timeout(std::time::Duration::from_millis(500), rx.recv()).await {
+            if matches!(event, AgentEvent::Done) {
+                break;
+            }
+        }
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        let diff = requests[0]
+            .context
+            .diff
+            .as_deref()
+            .expect("edit approval should carry a diff");
+        assert!(diff.contains("-    41"));
+        assert!(diff.contains("+    42"));
+        assert_eq!(
+            std::fs::read_to_string(&file).unwrap(),
+            "fn answer() -> u32 {\n    41\n}\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_session_rule_skips_approval_prompt() {
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("lib.rs");
+        std::fs::write(&file, "fn answer() -> u32 {\n    41\n}\n").unwrap();
+        let args = serde_json::json!({
+            "file_path": file.to_string_lossy(),
+            "old_string": "41",
+            "new_string": "42"
+        });
+
+        let provider = Arc::new(thunderus_providers::MockProvider::scripted(vec![
+            ChatResponse::new(ChatMessage::assistant("")).with_tool_calls(vec![ToolCall::new(
+                "call_1",
+                "edit",
+                args.clone(),
+            )]),
+            ChatResponse::new(ChatMessage::assistant("Done")),
+        ])) as Arc<dyn Provider>;
+
+        let approval = Arc::new(RecordingApproval::default());
+        let requests = Arc::clone(&approval.requests);
+        let mut gate = ApprovalGate::new(ApprovalMode::Auto, false);
+        let sibling = args["file_path"].as_str().unwrap().replace("lib.rs", "main.rs");
+        let approved = ApprovalRequest::new(
+            0,
+            ActionType::FileWrite,
+            "Execute tool: edit",
+            ApprovalContext::new()
+                .with_name("edit")
+                .with_arguments(serde_json::json!({"file_path": sibling})),
+            ToolRisk::Risky,
+        );
+        gate.remember(thunderus_core::ApprovalRule::from_request(&approved));
+
+        let session = Session::new(AgentDir::new(temp.path())).unwrap();
+        let registry = ToolRegistry::with_builtin_tools();
+        let specs = registry.specs();
+        let read_history = ReadHistory::new();
+        read_history.record_read(&file.to_string_lossy(), 3, 0);
+        let session_dispatcher = SessionToolDispatcher::new(ToolDispatcher::new(registry), session, read_history, None);
+
+        let mut agent = Agent::new(provider, approval, gate, SessionId::new())
+            .with_tool_dispatcher(Arc::new(Mutex::new(session_dispatcher)));
+
+        let mut rx = agent
+            .process_message("Fix the answer", Some(specs), CancelToken::new(), Vec::new())
+            .await
+            .unwrap();
+        let mut auto_approved = None;
+        while let Ok(Some(event)) = tokio::time::timeout(std::time::Duration::from_millis(500), rx.recv()).await {
+            match event {
+                AgentEvent::AutoApproved(record) => auto_approved = Some(record),
+                AgentEvent::Done => break,
+                _ => {}
+            }
+        }
+
+        assert!(requests.lock().unwrap().is_empty());
+        let record = auto_approved.expect("session rule should auto-approve the edit");
+        assert!(
+            record
+                .reason
+                .unwrap()
+                .starts_with("matches session rule: FileWrite edit")
+        );
+        assert_eq!(
+            std::fs::read_to_string(&file).unwrap(),
+            "fn answer() -> u32 {\n    42\n}\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_scripted_provider_drives_multi_turn_tool_flow() {
+        let scripted = Arc::new(thunderus_providers::MockProvider::scripted(vec![
+            ChatResponse::new(ChatMessage::assistant("")).with_tool_calls(vec![ToolCall::new(
+                "call_1",
+                "echo",
+                serde_json::json!({"message": "ping"}),
+            )]),
+            ChatResponse::new(ChatMessage::assistant("Echo returned ping")),
+        ]));
+        let provider = scripted.clone() as Arc<dyn Provider>;
+
+        let approval = Arc::new(InMemoryApprovalProtocol::new(true)) as Arc<dyn ApprovalProtocol>;
+        let gate = ApprovalGate::new(ApprovalMode::Auto, false);
+
+        let temp = TempDir::new().unwrap();
+        let session = Session::new(AgentDir::new(temp.path())).unwrap();
+
+        let registry = ToolRegistry::new();
+        registry.register(EchoTool).unwrap();
+        let specs = registry.specs();
+        let session_dispatcher = SessionToolDispatcher::with_new_history(ToolDispatcher::new(registry), session);
+
+        let mut agent = Agent::new(provider, approval, gate, SessionId::new())
+            .with_tool_dispatcher(Arc::new(Mutex::new(session_dispatcher)));
+
+        let mut rx = agent
+            .process_message("Echo ping", Some(specs.clone()), CancelToken::new(), Vec::new())
+            .await
+            .unwrap();
+        let mut saw_tool_result = false;
+        while let Ok(Some(event)) = tokio::time::timeout(std::time::Duration::from_millis(200), rx.recv()).await {
+            match event {
+                AgentEvent::ToolResult { name, result, .. } => {
+                    assert_eq!(name, "echo");
+                    assert_eq!(result, "ping");
+                    saw_tool_result = true;
+                }
+                AgentEvent::Done => break,
+                _ => {}
+            }
+        }
+        assert!(saw_tool_result);
+
+        let mut rx = agent
+            .process_message("Continue", Some(specs), CancelToken::new(), Vec::new())
+            .await
+            .unwrap();
+        let mut reply = String::new();
+        while let Ok(Some(event)) = tokio::time::timeout(std::time::Duration::from_millis(200), rx.recv()).await {
+            match event {
+                AgentEvent::Token(text) => reply.push_str(&text),
+                AgentEvent::Done => break,
+                _ => {}
+            }
+        }
+        assert_eq!(reply, "Echo returned ping");
+
+        assert_eq!(scripted.turn(), 2);
+        let requests = scripted.requests();
+        let tool_message = requests[1]
+            .messages
+            .iter()
+            .find(|message| message.role == Role::Tool)
+            .expect("second turn should carry the tool result");
+        assert_eq!(tool_message.content, "ping");
+        assert_eq!(tool_message.tool_call_id.as_deref(), Some("call_1"));
+    }
+
+    #[tokio::test]
+    async fn test_retryable_tool_error_gets_one_corrected_retry() {
+        let scripted = Arc::new(thunderus_providers::MockProvider::scripted(vec![
+            ChatResponse::new(ChatMessage::assistant("")).with_tool_calls(vec![ToolCall::new(
+                "call_1",
+                "echo",
+                serde_json::json!({"text": "ping"}),
+            )]),
+            ChatResponse::new(ChatMessage::assistant("")).with_tool_calls(vec![ToolCall::new(
+                "call_2",
+                "echo",
+                serde_json::json!({"message": "ping"}),
+            )]),
+        ]));
+        let provider = scripted.clone() as Arc<dyn Provider>;
+
+        let approval = Arc::new(InMemoryApprovalProtocol::new(true)) as Arc<dyn ApprovalProtocol>;
+        let gate = ApprovalGate::new(ApprovalMode::Auto, false);
+
+        let temp = TempDir::new().unwrap();
+        let session = Session::new(AgentDir::new(temp.path())).unwrap();
+
+        let registry = ToolRegistry::new();
+        registry.register(EchoTool).unwrap();
+        let specs = registry.specs();
+        let session_dispatcher = SessionToolDispatcher::with_new_history(ToolDispatcher::new(registry), session);
+
+        let mut agent = Agent::new(provider, approval, gate, SessionId::new())
+            .with_tool_dispatcher(Arc::new(Mutex::new(session_dispatcher)));
+
+        let mut rx = agent
+            .process_message("Echo ping", Some(specs), CancelToken::new(), Vec::new())
+            .await
+            .unwrap();
+        let mut results = Vec::new();
+        while let Ok(Some(event)) = tokio::time::timeout(std::time::Duration::from_millis(200), rx.recv()).await {
+            match event {
+                AgentEvent::ToolResult { success, metadata, .. } => results.push((metadata.attempt, success)),
+                AgentEvent::Done => break,
+                _ => {}
+            }
+        }
+        assert_eq!(results, vec![(1, false), (2, true)]);
+
+        assert_eq!(scripted.turn(), 2);
+        let requests = scripted.requests();
+        let feedback = requests[1]
+            .messages
+            .iter()
+            .find(|message| message.role == Role::Tool)
+            .expect("retry request should carry the teaching error");
+        assert_eq!(feedback.tool_call_id.as_deref(), Some("call_1"));
+        assert!(feedback.content.contains("**Error Type**: Usage"));
+    }
+
+    #[test]
+    fn test_retryable_error_skips_denied_and_system_errors() {
+        let usage = thunderus_tools::ArgumentErrors::missing_parameter("echo", "message").format();
+        let metadata = ToolExecutionMetadata::new();
+        assert!(retryable_error(&ToolResult::error("call_1", usage.clone()), &metadata).is_some());
+
+        let denied = ToolExecutionMetadata { denied: true, ..ToolExecutionMetadata::new() };
+        assert!(retryable_error(&ToolResult::error("call_1", usage), &denied).is_none());
+        assert!(retryable_error(&ToolResult::error("call_1", "disk full"), &metadata).is_none());
     }
 
     #[tokio::test]
@@ -1,6 +1,7 @@
 use anyhow::{Context, Result};
 use clap::{Command, CommandFactory, Parser, Subcommand};
 use clap_complete::{Generator, Shell, generate};
+use futures::StreamExt;
 use owo_colors::OwoColorize;
 use serde::Serialize;
 use std::io::{self, Write};
@@ -9,14 +10,17 @@ use std::sync::Arc;
 use std::time::Duration;
 use thunderus_core::logging::LoggingConfig;
 use thunderus_core::{
-    AgentDir, Config, ContextLoader, PatchQueueManager, Session,
-    memory::{Gardener, MemoryPaths, MemoryRetriever, RetrievalPolicy},
+    AgentDir, Config, ContextLoader, Event, MemoryConfig, PatchQueueManager, Session, SessionTrace,
+    memory::{DriftResult, Gardener, MemoryPaths, MemoryRetriever, RetrievalPolicy, StalenessSeverity},
+};
+use thunderus_core::{ApprovalGate, ApprovalProtocol, AutoApprove, AutoReject, init_logging};
+use thunderus_providers::{
+    CancelToken, ChatMessage, ChatRequest, FinishReason, HealthCheckResult, MockProvider, MockResponse, Provider,
+    ProviderFactory, ProviderHealthChecker, StreamEvent,
 };
-use thunderus_core::{ApprovalGate, ApprovalProtocol, AutoApprove, init_logging};
-use thunderus_providers::{CancelToken, ProviderFactory, ProviderHealthChecker};
 use thunderus_store::{IndexResult, MemoryIndexer, MemoryStore, StoreRetriever};
 use thunderus_tools::{SessionToolDispatcher, ToolDispatcher, ToolRegistry};
-use thunderus_ui::state::AppState;
+use thunderus_ui::state::{AppState, HistoryFile};
 
 /// Resolve the configuration file path based on priority:
 /// 1. Explicit --config flag (highest priority)
@@ -92,6 +96,10 @@ enum Commands {
         /// Deterministic test mode for TUI testing
         #[arg(long)]
         test_mode: bool,
+
+        /// Resume a session instead of starting a new one (latest if no ID is given)
+        #[arg(long, value_name = "SESSION_ID", num_args = 0..=1)]
+        resume: Option<Option<String>>,
     },
     /// Execute a single command and exit (non-interactive mode)
     Exec {
@@ -102,6 +110,18 @@ enum Commands {
         /// Arguments to pass to the command
         #[arg(value_name = "ARGS")]
         args: Vec<String>,
+
+        /// Approve every tool call instead of enforcing the profile's approval mode
+        #[arg(long)]
+        approve_all: bool,
+
+        /// Emit agent events as newline-delimited JSON on stdout
+        #[arg(long)]
+        json: bool,
+
+        /// Advisory mode: only read-only tools are available, so the agent cannot modify anything
+        #[arg(long)]
+        read_only: bool,
     },
     /// Show current status
     Status {
@@ -109,6 +129,22 @@ enum Commands {
         #[arg(long)]
         check_providers: bool,
     },
+    /// Export a session as a self-contained trace for bug reports
+    ExportTrace {
+        /// Session ID to export (the session directory name under .agent/sessions)
+        #[arg(value_name = "SESSION_ID")]
+        session_id: String,
+
+        /// Output file (default: <SESSION_ID>.trace.json)
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+    /// Replay an exported trace against the mock provider and print its transcript
+    ImportTrace {
+        /// Trace file written by export-trace
+        #[arg(value_name = "FILE")]
+        path: PathBuf,
+    },
     /// Generate shell completion scripts
     Completions {
         /// Shell type to generate completions for
@@ -161,14 +197,49 @@ fn run() -> Result<()> {
     let rt = tokio::runtime::Runtime::new()?;
     rt.block_on(async {
         match cli.command {
-            None | Some(Commands::Start { dir: None, test_mode: false }) => {
-                cmd_start(config, config_path.clone(), cli.dir, cli.profile, cli.verbose, false).await
+            None => {
+                cmd_start(
+                    config,
+                    config_path.clone(),
+                    cli.dir,
+                    cli.profile,
+                    cli.verbose,
+                    false,
+                    None,
+                )
+                .await
             }
-            Some(Commands::Start { dir, test_mode }) => {
-                cmd_start(config, config_path.clone(), dir, cli.profile, cli.verbose, test_mode).await
+            Some(Commands::Start { dir, test_mode, resume }) => {
+                cmd_start(
+                    config,
+                    config_path.clone(),
+                    dir.or(cli.dir),
+                    cli.profile,
+                    cli.verbose,
+                    test_mode,
+                    resume,
+                )
+                .await
             }
-            Some(Commands::Exec { command, args }) => cmd_exec(config, command, args, cli.profile, cli.verbose),
-            Some(Commands::Status { check_providers }) => cmd_status(config, cli.verbose, check_providers),
+            Some(Commands::Exec { command, args, approve_all, json, read_only }) => cmd_exec(
+                config,
+                command,
+                args,
+                cli.profile,
+                cli.verbose,
+                ExecOptions { approve_all, json, read_only },
+            ),
+            Some(Commands::Status { check_providers }) => cmd_status(config, cli.dir, cli.verbose, check_providers),
+            Some(Commands::ExportTrace { session_id, output }) => cmd_export_trace(
+                &config,
+                &config_path,
+                cli.dir,
+                cli.profile,
+                session_id,
+                output,
+                cli.verbose,
+            ),
+            Some(Commands::ImportTrace { path }) => cmd_import_trace(&path).await,
             Some(Commands::Completions { shell }) => print_completions(shell, &mut Cli::command()),
         }
     })
@@ -185,7 +256,19 @@ fn load_or_create_config(path: &Path, verbose: bool) -> Result<Config> {
         if verbose {
             eprintln!("{} Loading config from {}", "Info:".green().bold(), path.display());
         }
-        Config::from_file(&PathBuf::from(path)).map_err(|e| anyhow::anyhow!("Failed to load config: {}", e))
+        let content = std::fs::read_to_string(path).context("Failed to read config")?;
+        Config::from_toml_str_checked(&content).map_err(|errors| {
+            eprintln!(
+                "{} Found {} problem(s) in {}:",
+                "Error:".red().bold(),
+                errors.len(),
+                path.display()
+            );
+            for (idx, error) in errors.iter().enumerate() {
+                eprintln!("  {}. {}", idx + 1, error);
+            }
+            anyhow::anyhow!("Failed to load config: {} problem(s) found", errors.len())
+        })
     } else {
         eprintln!("{} Config not found at {}", "Warning:".yellow().bold(), path.display());
         eprintln!("{} Creating config from example...", "Info:".blue().bold());
@@ -206,6 +289,17 @@ fn load_or_create_config(path: &Path, verbose: bool) -> Result<Config> {
     }
 }
 
+/// Warn about profile allow/deny entries that name no registered tool
+fn warn_unknown_profile_tools(registry: &ToolRegistry) {
+    for name in registry.unknown_profile_tools() {
+        eprintln!(
+            "{} Profile tools list names unknown tool '{}'; check it for typos",
+            "Warning:".yellow().bold(),
+            name
+        );
+    }
+}
+
 fn detect_git_branch(path: &Path) -> Option<String> {
     match std::process::Command::new("git")
         .args(["-C", path.to_str().unwrap_or("."), "branch", "--show-current"])
@@ -220,9 +314,12 @@ fn detect_git_branch(path: &Path) -> Option<String> {
 }
 
 /// Start the interactive TUI session
+///
+/// `resume` is `None` for a new session, `Some(None)` for the latest session
+/// and `Some(Some(id))` for a specific one.
 async fn cmd_start(
     config: Config, config_path: PathBuf, dir: Option<PathBuf>, profile_name: Option<String>, verbose: bool,
-    test_mode: bool,
+    test_mode: bool, resume: Option<Option<String>>,
 ) -> Result<()> {
     let working_dir = if let Some(d) = dir { d } else { std::env::current_dir()? };
     let profile_name = profile_name.unwrap_or_else(|| config.default_profile.clone());
@@ -254,15 +351,27 @@ async fn cmd_start(
         std::fs::create_dir_all(agent_dir.views_dir()).context("Failed to create views directory")?;
     }
 
-    let (mut session, is_recovery) = {
-        if verbose {
-            eprintln!("{} Creating session...", "Info:".blue().bold());
+    let (mut session, is_recovery) = match resume {
+        Some(id) => {
+            if verbose {
+                eprintln!("{} Resuming session...", "Info:".blue().bold());
+            }
+            (resume_session(&agent_dir, id.as_deref())?, true)
+        }
+        None => {
+            if verbose {
+                eprintln!("{} Creating session...", "Info:".blue().bold());
+            }
+            (
+                Session::new(agent_dir.clone()).context("Failed to create session")?,
+                false,
+            )
         }
-        (
-            Session::new(agent_dir.clone()).context("Failed to create session")?,
-            false,
-        )
     };
+    session
+        .set_redaction(&profile.redaction)
+        .context("Invalid redaction config")?;
+    session.set_rollover(&profile.rollover);
 
     if verbose {
         eprintln!("{} Session ID: {}", "Info:".blue().bold(), session.id.cyan());
@@ -278,12 +387,17 @@ async fn cmd_start(
     }
 
     if !test_mode {
-        let mut context_loader = ContextLoader::new(working_dir.clone());
+        let mut context_loader = ContextLoader::new(working_dir.clone())
+            .with_include(profile.context.include.clone())
+            .with_max_tokens(profile.context.max_tokens);
         match context_loader.append_to_session(&mut session) {
             Ok(count) => {
                 if verbose && count > 0 {
                     eprintln!("{} Loaded {} context file(s)", "Info:".green().bold(), count);
                 }
+                for warning in context_loader.warnings() {
+                    eprintln!("{} {}", "Warning:".yellow().bold(), warning);
+                }
             }
             Err(e) => {
                 if verbose {
@@ -298,7 +412,11 @@ async fn cmd_start(
     }
 
     if verbose {
-        eprintln!("{} Initializing memory index...", "Info:".blue().bold());
+        if profile.memory.enabled {
+            eprintln!("{} Initializing memory index...", "Info:".blue().bold());
+        } else {
+            eprintln!("{} Memory disabled for this profile", "Info:".blue().bold());
+        }
     }
 
     let memory_paths = MemoryPaths::from_thunderus_root(&working_dir);
@@ -308,9 +426,10 @@ async fn cmd_start(
         .join("indexes")
         .join("memory.db");
 
-    let store_result = MemoryStore::open(&db_path).await;
+    let store_result = if profile.memory.enabled { Some(MemoryStore::open(&db_path).await) } else { None };
     let (memory_store, index_result) = match store_result {
-        Ok(store) => {
+        None => (None, IndexResult::default()),
+        Some(Ok(store)) => {
             let store_clone = store.clone();
             let indexer = MemoryIndexer::new(store, memory_paths.clone(), &working_dir);
             let result = indexer.index_changed().await;
@@ -357,7 +476,7 @@ async fn cmd_start(
                 }
             }
         }
-        Err(e) => {
+        Some(Err(e)) => {
             if verbose {
                 eprintln!(
                     "{} Warning: Failed to open memory store: {}",
@@ -381,9 +500,12 @@ async fn cmd_start(
         let policy = RetrievalPolicy {
             enable_vector_fallback: profile.memory.enable_vector_search,
             score_threshold: profile.memory.vector_fallback_threshold,
+            token_budget: profile.memory.retrieval_token_budget,
+            never_verified_penalty: profile.memory.never_verified_penalty,
             ..Default::default()
         };
-        Arc::new(StoreRetriever::new(Arc::new(store), policy)) as Arc<dyn MemoryRetriever>
+        Arc::new(StoreRetriever::new(Arc::new(store), policy).with_pinned_memory(memory_paths.clone()))
+            as Arc<dyn MemoryRetriever>
     });
 
     if !is_recovery && !test_mode {
@@ -413,6 +535,67 @@ async fn cmd_start(
         app_state.set_theme_variant(variant);
     }
 
+    if let Some(verbosity) = profile.options.get("verbosity") {
+        match thunderus_ui::state::VerbosityLevel::parse_str(verbosity) {
+            Some(level) => app_state.config.verbosity = level,
+            None => eprintln!(
+                "{} Ignoring invalid verbosity '{}': expected quiet, default or verbose",
+                "Warning:".yellow().bold(),
+                verbosity
+            ),
+        }
+    }
+
+    if let Some(lines) = profile.options.get("tool_output_collapse_lines") {
+        match lines.parse::<usize>() {
+            Ok(lines) => app_state.ui.tool_output_collapse_lines = lines,
+            Err(_) => eprintln!(
+                "{} Ignoring invalid tool_output_collapse_lines '{}': expected a line count",
+                "Warning:".yellow().bold(),
+                lines
+            ),
+        }
+    }
+
+    if let Some(interval) = profile.options.get("redraw_interval_ms") {
+        match interval.parse::<u64>() {
+            Ok(ms) => app_state.ui.redraw_interval = std::time::Duration::from_millis(ms),
+            Err(_) => eprintln!(
+                "{} Ignoring invalid redraw_interval_ms '{}': expected milliseconds",
+                "Warning:".yellow().bold(),
+                interval
+            ),
+        }
+    }
+
+    if let Some(animations) = profile.options.get("animations") {
+        match animations.parse::<bool>() {
+            Ok(enabled) => app_state.ui.animations = enabled,
+            Err(_) => eprintln!(
+                "{} Ignoring invalid animations '{}': expected true or false",
+                "Warning:".yellow().bold(),
+                animations
+            ),
+        }
+    }
+
+    if !test_mode {
+        let history_size = match profile.options.get("history_size") {
+            Some(size) => size.parse::<usize>().unwrap_or_else(|_| {
+                eprintln!(
+                    "{} Ignoring invalid history_size '{}': expected a message count",
+                    "Warning:".yellow().bold(),
+                    size
+                );
+                thunderus_ui::state::DEFAULT_HISTORY_SIZE
+            }),
+            None => thunderus_ui::state::DEFAULT_HISTORY_SIZE,
+        };
+        app_state
+            .input
+            .attach_history_file(HistoryFile::for_workspace(&working_dir).with_max_entries(history_size));
+    }
+
     let mut app = thunderus_ui::App::with_provider(app_state, provider)
         .with_session(session.clone())
         .with_profile(profile.clone());
@@ -421,6 +604,30 @@ async fn cmd_start(
         app = app.with_memory_retriever(retriever);
     }
 
+    if let Some(max_entries) = profile.options.get("max_transcript_entries") {
+        match max_entries.parse::<usize>() {
+            Ok(max_entries) if max_entries > 0 => app.transcript_mut().set_max_entries(max_entries),
+            _ => eprintln!(
+                "{} Ignoring invalid max_transcript_entries '{}': expected a positive entry count",
+                "Warning:".yellow().bold(),
+                max_entries
+            ),
+        }
+    }
+
+    app.state_mut().config.memory_enabled = profile.memory.enabled;
+
+    if profile.memory.enabled
+        && let Err(e) = app.state_mut().memory_hits.load_pins(&memory_paths)
+        && verbose
+    {
+        eprintln!(
+            "{} Warning: Failed to load pinned memory: {}",
+            "Warning:".yellow().bold(),
+            e
+        );
+    }
+
     if let Some(branch) = git_branch {
         app.state_mut().config.git_branch = Some(branch);
     }
@@ -440,13 +647,31 @@ async fn cmd_start(
             .add_system_message(format!("Session recovered: {}", session.id));
     }
 
+    if !test_mode && profile.memory.enabled {
+        let gardener = Gardener::new(memory_paths.clone());
+        if gardener.config().drift_check_on_start {
+            match gardener.check_drift_auto() {
+                Ok(result) => {
+                    if let Some(summary) = drift_summary(&result) {
+                        app.transcript_mut().add_system_message(summary);
+                    }
+                }
+                Err(e) => {
+                    if verbose {
+                        eprintln!("{} Skipping memory drift check: {}", "Info:".blue().bold(), e);
+                    }
+                }
+            }
+        }
+    }
+
     if test_mode {
         app.state_mut().set_test_mode(true);
     }
 
     match app.run().await {
         Ok(_) => {
-            run_consolidation(&session, &agent_dir, &memory_paths, &working_dir, verbose).await?;
+            run_consolidation(&session, &agent_dir, &memory_paths, &profile.memory, verbose).await?;
             Ok(())
         }
         Err(e) => {
@@ -456,10 +681,33 @@ async fn cmd_start(
     }
 }
 
+/// Summarize stale memory documents for the startup transcript
+///
+/// Returns `None` when every document is up to date.
+fn drift_summary(result: &DriftResult) -> Option<String> {
+    if result.stale_docs.is_empty() {
+        return None;
+    }
+
+    Some(format!(
+        "Memory drift: {} stale document(s) ({} critical, {} major, {} minor). \
+         Review them with /garden drift, then run /garden verify <doc-id>.",
+        result.stale_docs.len(),
+        result.count(StalenessSeverity::Critical),
+        result.count(StalenessSeverity::Major),
+        result.count(StalenessSeverity::Minor)
+    ))
+}
+
 /// Run consolidation on a completed session
+///
+/// Does nothing when the profile has memory disabled.
 async fn run_consolidation(
-    session: &Session, agent_dir: &AgentDir, memory_paths: &MemoryPaths, _working_dir: &Path, verbose: bool,
+    session: &Session, agent_dir: &AgentDir, memory_paths: &MemoryPaths, memory: &MemoryConfig, verbose: bool,
 ) -> Result<()> {
+    if !memory.enabled {
+        return Ok(());
+    }
     if verbose {
         eprintln!("{} Running memory consolidation...", "Info:".blue().bold());
     }
@@ -526,10 +774,35 @@ async fn run_consolidation(
     }
 }
 
+/// Flags controlling a non-interactive `exec` run
+#[derive(Debug, Clone, Copy, Default)]
+struct ExecOptions {
+    /// Approve every tool call instead of enforcing the profile's approval mode
+    approve_all: bool,
+    /// Emit agent events as newline-delimited JSON on stdout
+    json: bool,
+    /// Register only read-only tools so nothing can be modified
+    read_only: bool,
+}
+
 /// Execute a single command and exit (non-interactive mode)
+///
+/// There is no one to prompt, so tool calls that would need approval under the
+/// profile's approval mode are rejected and the command exits non-zero, unless
+/// `approve_all` is set.
+///
+/// With `read_only`, the tool registry holds only read-only tools (no edit,
+/// write, patch or multiedit, and a shell that refuses mutating commands), so
+/// the agent is structurally unable to change anything. Skills are not loaded
+/// and the remaining tool calls are approved.
+///
+/// With `json`, every agent event is written to stdout as one JSON object per
+/// line and stderr is left for diagnostics.
 fn cmd_exec(
     config: Config, command: String, args: Vec<String>, profile_name: Option<String>, verbose: bool,
+    options: ExecOptions,
 ) -> Result<()> {
+    let ExecOptions { approve_all, json, read_only } = options;
     let profile_name = profile_name.unwrap_or_else(|| config.default_profile.clone());
     let profile = config
         .profile(&profile_name)
@@ -547,13 +820,33 @@ fn cmd_exec(
         );
         eprintln!("{} Profile: {}", "Info:".blue().bold(), profile_name.cyan());
         eprintln!("{} Working directory: {}", "Info:".blue().bold(), working_dir.display());
+        eprintln!(
+            "{} Approval: {}",
+            "Info:".blue().bold(),
+            if read_only {
+                "read-only (mutating tools disabled)".to_string()
+            } else if approve_all {
+                "approve all".to_string()
+            } else {
+                profile.approval_mode.to_string()
+            }
+            .cyan()
+        );
     }
 
     let rt = tokio::runtime::Runtime::new()?;
     rt.block_on(async {
-        let session = Session::new(agent_dir).context("Failed to create session")?;
+        let mut session = Session::new(agent_dir).context("Failed to create session")?;
+        session
+            .set_redaction(&profile.redaction)
+            .context("Invalid redaction config")?;
+        session.set_rollover(&profile.rollover);
         let provider = ProviderFactory::create_from_config(&profile.provider).context("Failed to create provider")?;
-        let approval_protocol = Arc::new(AutoApprove::new()) as Arc<dyn ApprovalProtocol>;
+        let approval_protocol = if approve_all || read_only {
+            Arc::new(AutoApprove::new()) as Arc<dyn ApprovalProtocol>
+        } else {
+            Arc::new(AutoReject::new()) as Arc<dyn ApprovalProtocol>
+        };
         let approval_gate = ApprovalGate::new(profile.approval_mode, profile.is_network_allowed());
         let cancel_token = CancelToken::new();
 
@@ -566,28 +859,59 @@ fn cmd_exec(
 
         agent = agent.with_profile(profile.clone());
 
-        let mut tool_registry = ToolRegistry::with_builtin_tools();
-        if let Err(e) = tool_registry.load_skills()
-            && verbose
-        {
-            eprintln!("{} Warning: Failed to load skills: {}", "Warning:".yellow(), e);
-        }
+        let mut tool_registry =
+            if read_only { ToolRegistry::with_read_only_tools() } else { ToolRegistry::with_builtin_tools() };
         tool_registry.set_profile(profile.clone());
+        if !read_only {
+            if let Err(e) = tool_registry.load_skills()
+                && verbose
+            {
+                eprintln!("{} Warning: Failed to load skills: {}", "Warning:".yellow(), e);
+            }
+            warn_unknown_profile_tools(&tool_registry);
+        }
+
+        let full_command = if args.is_empty() { command } else { format!("{} {}", command, args.join(" ")) };
+        if verbose {
+            for (skill, trigger) in tool_registry.triggered_skills(&full_command) {
+                eprintln!(
+                    "{} Skill '{}' enabled (matched '{}')",
+                    "Info:".blue().bold(),
+                    skill.name,
+                    trigger
+                );
+            }
+        }
 
-        let tool_specs = tool_registry.specs();
+        let tool_specs = tool_registry.specs_for_message(&full_command);
         let dispatcher = ToolDispatcher::new(tool_registry);
         let session_dispatcher = SessionToolDispatcher::with_new_history(dispatcher, session.clone());
         agent = agent.with_tool_dispatcher(std::sync::Arc::new(std::sync::Mutex::new(session_dispatcher)));
 
-        let full_command = if args.is_empty() { command } else { format!("{} {}", command, args.join(" ")) };
-
         let mut event_rx = agent
             .process_message(&full_command, Some(tool_specs), cancel_token.clone(), vec![])
             .await
             .context("Failed to process message")?;
 
         let mut has_output = false;
+        let mut denied = 0;
         while let Some(event) = event_rx.recv().await {
+            if let thunderus_agent::AgentEvent::ToolResult { metadata, .. } = &event
+                && metadata.denied
+            {
+                denied += 1;
+            }
+
+            if json {
+                let line = serde_json::to_string(&event).context("Failed to serialize agent event")?;
+                println!("{}", line);
+                std::io::stdout().flush().ok();
+                if matches!(event, thunderus_agent::AgentEvent::Done) {
+                    break;
+                }
+                continue;
+            }
+
             match event {
                 thunderus_agent::AgentEvent::Token(text) => {
                     print!("{}", text);
@@ -600,12 +924,20 @@ fn cmd_exec(
                         eprintln!("  Description: {}", desc);
                     }
                 }
-                thunderus_agent::AgentEvent::ToolResult { name, result, success, error, .. } => {
+                thunderus_agent::AgentEvent::ToolResult { name, result, success, error, metadata } => {
                     if !has_output {
                         eprintln!();
                     }
-                    if success {
-                        eprintln!("{} {} completed:", "Success:".green(), name);
+                    if metadata.denied {
+                        eprintln!(
+                            "{} {} denied: {}",
+                            "Error:".red(),
+                            name,
+                            error.unwrap_or_else(|| "blocked by approval policy".to_string())
+                        );
+                    } else if success {
+                        let retried = if metadata.attempt > 1 { " (corrected retry)" } else { "" };
+                        eprintln!("{} {} completed{}:", "Success:".green(), name, retried);
                         let output: String = result.chars().take(500).collect();
                         if result.len() > 500 {
                             eprintln!("    {}...\n(truncated, {} total chars)", output, result.len());
@@ -618,11 +950,17 @@ fn cmd_exec(
                         eprintln!("{} {} failed", "Error:".red(), name);
                     }
                 }
-                // NOTE: in exec mode, we auto-approve
+                // NOTE: approvals are decided by the exec approval protocol, never prompted
                 thunderus_agent::AgentEvent::ApprovalRequest(_) => {}
                 thunderus_agent::AgentEvent::Error(msg) => {
                     eprintln!("{} {}", "Error:".red(), msg);
                 }
+                thunderus_agent::AgentEvent::Finish(reason) if reason == FinishReason::Length.as_str() => {
+                    eprintln!(
+                        "\n{} Response truncated by the max_tokens limit",
+                        "Warning:".yellow().bold()
+                    );
+                }
                 thunderus_agent::AgentEvent::Done => {
                     if has_output {
                         eprintln!();
@@ -634,14 +972,69 @@ fn cmd_exec(
             }
         }
 
+        if denied > 0 {
+            anyhow::bail!(
+                "{} tool call(s) denied under approval mode '{}' (use --approve-all to allow them)",
+                denied,
+                profile.approval_mode
+            );
+        }
+
         Ok(())
     })
 }
 
+/// Per-profile timeout for `status --check-providers`
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Maximum number of provider health checks run at once
+const MAX_CONCURRENT_HEALTH_CHECKS: usize = 4;
+
+/// Run `check` on every item with at most `limit` in flight, in completion order
+async fn run_bounded<I, F, Fut, T>(items: I, limit: usize, mut check: F) -> Result<Vec<T>>
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> Fut,
+    Fut: std::future::Future<Output = T> + Send + 'static,
+    T: Send + 'static,
+{
+    let mut tasks = tokio::task::JoinSet::new();
+    let mut results = Vec::new();
+    for item in items {
+        if tasks.len() >= limit
+            && let Some(result) = tasks.join_next().await
+        {
+            results.push(result.context("Provider health check task failed")?);
+        }
+        tasks.spawn(check(item));
+    }
+    while let Some(result) = tasks.join_next().await {
+        results.push(result.context("Provider health check task failed")?);
+    }
+    Ok(results)
+}
+
+/// Await a provider health check, failing it once `timeout` elapses
+///
+/// Returns the latency on success and the error message otherwise.
+async fn health_check_within<F, E>(timeout: Duration, check: F) -> std::result::Result<u64, String>
+where
+    F: std::future::Future<Output = std::result::Result<HealthCheckResult, E>>,
+    E: std::fmt::Display,
+{
+    match tokio::time::timeout(timeout, check).await {
+        Ok(Ok(result)) if result.healthy => Ok(result.latency_ms),
+        Ok(Ok(result)) => Err(result.error.unwrap_or_else(|| "Health check failed".to_string())),
+        Ok(Err(e)) => Err(e.to_string()),
+        Err(_) => Err(format!("Health check timed out after {}ms", timeout.as_millis())),
+    }
+}
+
 /// Show current status as JSON
 ///
 /// TODO: This should be its own mod
-fn cmd_status(config: Config, verbose: bool, check_providers: bool) -> Result<()> {
+fn cmd_status(config: Config, dir: Option<PathBuf>, verbose: bool, check_providers: bool) -> Result<()> {
+    let working_dir = if let Some(d) = dir { d } else { std::env::current_dir()? };
     #[derive(Serialize)]
     struct StatusOutput {
         title: String,
@@ -650,6 +1043,8 @@ fn cmd_status(config: Config, verbose: bool, check_providers: bool) -> Result<()
         provider_health: Option<ProviderHealthSection>,
         #[serde(skip_serializing_if = "Option::is_none")]
         agent_directory: Option<AgentDirectoryStatus>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        memory: Option<MemoryStatus>,
     }
 
     #[derive(Serialize)]
@@ -691,6 +1086,19 @@ fn cmd_status(config: Config, verbose: bool, check_providers: bool) -> Result<()
         sessions: Option<Vec<String>>,
     }
 
+    #[derive(Serialize)]
+    struct MemoryStatus {
+        label: String,
+        path: String,
+        initialized: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        note: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        store: Option<thunderus_store::StoreStats>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        manifest: Option<thunderus_core::ManifestStats>,
+    }
+
     let mut profiles = Vec::new();
     for profile_name in config.profile_names() {
         let profile = config.profile(&profile_name).unwrap();
@@ -703,88 +1111,39 @@ fn cmd_status(config: Config, verbose: bool, check_providers: bool) -> Result<()
         available_profiles: profiles,
     };
 
+    /// Check every profile's provider concurrently, reporting in profile-name order
+    async fn check_provider_health(config: &Config) -> Result<Vec<ProviderHealthStatus>> {
+        let mut statuses = run_bounded(config.profile_names(), MAX_CONCURRENT_HEALTH_CHECKS, |profile_name| {
+            let provider = config.profile(&profile_name).unwrap().provider.clone();
+            check_profile_health(profile_name, provider)
+        })
+        .await?;
+
+        statuses.sort_by(|a, b| a.profile.cmp(&b.profile));
+        Ok(statuses)
+    }
+
+    async fn check_profile_health(profile: String, provider: thunderus_core::ProviderConfig) -> ProviderHealthStatus {
+        let result = match ProviderFactory::create_from_config(&provider) {
+            Ok(instance) => {
+                let checker = ProviderHealthChecker::new(instance, HEALTH_CHECK_TIMEOUT);
+                health_check_within(HEALTH_CHECK_TIMEOUT, checker.check()).await
+            }
+            Err(e) => Err(e.to_string()),
+        };
+
+        let healthy = result.is_ok();
+        let (latency_ms, error) = match result {
+            Ok(latency_ms) => (Some(latency_ms), None),
+            Err(error) => (None, Some(error)),
+        };
+        ProviderHealthStatus { profile, provider, healthy, latency_ms, error }
+    }
+
     let provider_health = if check_providers {
         let results = match tokio::runtime::Handle::try_current() {
-            Ok(_) => tokio::task::block_in_place(|| {
-                let handle = tokio::runtime::Handle::try_current()?;
-                handle.block_on(async {
-                    let mut health_statuses = Vec::new();
-                    for profile_name in config.profile_names() {
-                        let profile = config.profile(&profile_name).unwrap();
-
-                        let status = match ProviderFactory::create_from_config(&profile.provider) {
-                            Ok(provider) => match ProviderHealthChecker::new(provider, Duration::from_secs(10))
-                                .check()
-                                .await
-                            {
-                                Ok(result) => ProviderHealthStatus {
-                                    profile: profile_name.clone(),
-                                    provider: profile.provider.clone(),
-                                    healthy: result.healthy,
-                                    latency_ms: if result.healthy { Some(result.latency_ms) } else { None },
-                                    error: if result.healthy { None } else { result.error },
-                                },
-                                Err(e) => ProviderHealthStatus {
-                                    profile: profile_name.clone(),
-                                    provider: profile.provider.clone(),
-                                    healthy: false,
-                                    latency_ms: None,
-                                    error: Some(e.to_string()),
-                                },
-                            },
-                            Err(e) => ProviderHealthStatus {
-                                profile: profile_name.clone(),
-                                provider: profile.provider.clone(),
-                                healthy: false,
-                                latency_ms: None,
-                                error: Some(e.to_string()),
-                            },
-                        };
-                        health_statuses.push(status);
-                    }
-                    Ok::<Vec<ProviderHealthStatus>, anyhow::Error>(health_statuses)
-                })
-            }),
-            Err(_) => {
-                let rt = tokio::runtime::Runtime::new()?;
-                rt.block_on(async {
-                    let mut health_statuses = Vec::new();
-                    for profile_name in config.profile_names() {
-                        let profile = config.profile(&profile_name).unwrap();
-
-                        let status = match ProviderFactory::create_from_config(&profile.provider) {
-                            Ok(provider) => match ProviderHealthChecker::new(provider, Duration::from_secs(10))
-                                .check()
-                                .await
-                            {
-                                Ok(result) => ProviderHealthStatus {
-                                    profile: profile_name.clone(),
-                                    provider: profile.provider.clone(),
-                                    healthy: result.healthy,
-                                    latency_ms: if result.healthy { Some(result.latency_ms) } else { None },
-                                    error: if result.healthy { None } else { result.error },
-                                },
-                                Err(e) => ProviderHealthStatus {
-                                    profile: profile_name.clone(),
-                                    provider: profile.provider.clone(),
-                                    healthy: false,
-                                    latency_ms: None,
-                                    error: Some(e.to_string()),
-                                },
-                            },
-                            Err(e) => ProviderHealthStatus {
-                                profile: profile_name.clone(),
-                                provider: profile.provider.clone(),
-                                healthy: false,
-                                latency_ms: None,
-                                error: Some(e.to_string()),
-                            },
-                        };
-                        health_statuses.push(status);
-                    }
-                    Ok::<Vec<ProviderHealthStatus>, anyhow::Error>(health_statuses)
-                })
-            }
+            Ok(handle) => tokio::task::block_in_place(|| handle.block_on(check_provider_health(&config))),
+            Err(_) => tokio::runtime::Runtime::new()?.block_on(check_provider_health(&config)),
         }?;
         Some(ProviderHealthSection { label: "Provider Health Checks".to_string(), profiles: results })
     } else {
@@ -792,7 +1151,7 @@ fn cmd_status(config: Config, verbose: bool, check_providers: bool) -> Result<()
     };
 
     let agent_directory = if verbose {
-        let agent_dir = AgentDir::from_current_dir().context("Failed to get current directory")?;
+        let agent_dir = AgentDir::new(&working_dir);
         let agent_dir_path = agent_dir.agent_dir();
         let sessions = if agent_dir_path.exists() {
             let sessions_dir = agent_dir.sessions_dir();
@@ -821,24 +1180,251 @@ fn cmd_status(config: Config, verbose: bool, check_providers: bool) -> Result<()
         None
     };
 
+    let memory = if verbose {
+        let memory_paths = MemoryPaths::from_thunderus_root(&working_dir);
+        let db_path = memory_paths.indexes.join("memory.db");
+
+        let mut status = MemoryStatus {
+            label: "Memory".to_string(),
+            path: memory_paths.root_memory.display().to_string(),
+            initialized: db_path.exists(),
+            note: None,
+            store: None,
+            manifest: None,
+        };
+
+        if status.initialized {
+            let stats = match tokio::runtime::Handle::try_current() {
+                Ok(handle) => tokio::task::block_in_place(|| handle.block_on(memory_store_stats(&db_path))),
+                Err(_) => tokio::runtime::Runtime::new()?.block_on(memory_store_stats(&db_path)),
+            };
+            match stats {
+                Ok(stats) => status.store = Some(stats),
+                Err(e) => status.note = Some(format!("Failed to read memory store: {}", e)),
+            }
+
+            if memory_paths.manifest_file().exists() {
+                match thunderus_core::MemoryManifest::load(&memory_paths) {
+                    Ok(manifest) => status.manifest = Some(manifest.stats),
+                    Err(e) => status.note = Some(format!("Failed to read memory manifest: {}", e)),
+                }
+            }
+        } else {
+            status.note = Some("Memory store not initialized (run `thunderus start` to build the index)".to_string());
+        }
+
+        Some(status)
+    } else {
+        None
+    };
+
     let output =
-        StatusOutput { title: "Thunderus Status".to_string(), configuration, provider_health, agent_directory };
+        StatusOutput { title: "Thunderus Status".to_string(), configuration, provider_health, agent_directory, memory };
 
     println!("{}", serde_json::to_string_pretty(&output)?);
     Ok(())
 }
 
+/// Load the session to resume: the given ID, or the latest session if `None`
+///
+/// An unknown or malformed ID fails with the list of sessions that can be resumed.
+fn resume_session(agent_dir: &AgentDir, session_id: Option<&str>) -> Result<Session> {
+    let id = match session_id {
+        Some(input) => agent_dir
+            .resolve_session(input)
+            .map_err(|e| anyhow::anyhow!("{}\n{}", e, available_sessions(agent_dir)))?,
+        None => agent_dir
+            .latest_session()
+            .with_context(|| format!("No sessions to resume in {}", agent_dir.sessions_dir().display()))?,
+    };
+
+    Session::load(agent_dir.clone(), id.clone()).with_context(|| format!("Failed to load session '{}'", id))
+}
+
+/// Most recent session IDs, for error messages about unknown sessions
+fn available_sessions(agent_dir: &AgentDir) -> String {
+    const LISTED: usize = 10;
+
+    let sessions = agent_dir.list_sessions();
+    if sessions.is_empty() {
+        return "No sessions found.".to_string();
+    }
+
+    let mut lines = vec!["Available sessions (newest first):".to_string()];
+    lines.extend(sessions.iter().take(LISTED).map(|id| format!("  {}", id)));
+    if sessions.len() > LISTED {
+        lines.push(format!("  ... and {} more", sessions.len() - LISTED));
+    }
+    lines.join("\n")
+}
+
+/// Bundle a session's events, redacted config and views into a trace file
+fn cmd_export_trace(
+    config: &Config, config_path: &Path, dir: Option<PathBuf>, profile_name: Option<String>, session_id: String,
+    output: Option<PathBuf>, verbose: bool,
+) -> Result<()> {
+    let profile_name = profile_name.unwrap_or_else(|| config.default_profile.clone());
+    let profile = config
+        .profile(&profile_name)
+        .with_context(|| format!("Failed to load profile '{}'", profile_name))?;
+
+    let working_dir = if let Some(d) = dir { d } else { std::env::current_dir()? };
+    let agent_dir = AgentDir::new(&working_dir);
+    let id = agent_dir
+        .resolve_session(&session_id)
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+    let mut session =
+        Session::load(agent_dir, id).with_context(|| format!("Failed to load session '{}'", session_id))?;
+    session
+        .set_redaction(&profile.redaction)
+        .context("Invalid redaction config")?;
+
+    // Without a config file, export the defaults the session ran with
+    let raw_config = if config_path.exists() {
+        std::fs::read_to_string(config_path).context("Failed to read config")?
+    } else {
+        config.to_toml_string().context("Failed to encode config")?
+    };
+    let trace = SessionTrace::from_session(&session, &raw_config).context("Failed to build trace")?;
+
+    let output = output.unwrap_or_else(|| PathBuf::from(format!("{}.trace.json", session.id)));
+    trace.save(&output).context("Failed to write trace")?;
+
+    if verbose {
+        eprintln!("{} Exported {} events", "Info:".blue().bold(), trace.events.len());
+    }
+    println!("{} Trace written to {}", "Success:".green().bold(), output.display());
+    Ok(())
+}
+
+/// Replay a trace against the mock provider and print the reconstructed transcript
+async fn cmd_import_trace(path: &Path) -> Result<()> {
+    let trace = SessionTrace::load(path).with_context(|| format!("Failed to load trace {}", path.display()))?;
+
+    println!("{} {}", "Session:".bold(), trace.session_id);
+    println!("{} {}", "Exported:".bold(), trace.exported_at);
+    println!();
+    for line in replay_trace(&trace).await? {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+/// Rebuild a session transcript by replaying its model turns through the mock provider
+///
+/// Each recorded model message or tool call becomes one scripted mock turn, and
+/// each turn is requested with the conversation so far, so the transcript comes
+/// from the same streaming path a live session uses.
+async fn replay_trace(trace: &SessionTrace) -> Result<Vec<String>> {
+    let responses = trace
+        .events
+        .iter()
+        .filter_map(|logged| match &logged.event {
+            Event::ModelMessage { content, .. } => Some(MockResponse::Text { content: content.clone() }),
+            Event::ToolCall { tool, arguments, .. } => {
+                Some(MockResponse::ToolCall { name: tool.clone(), args: arguments.clone() })
+            }
+            _ => None,
+        })
+        .collect();
+    let provider = MockProvider::from_responses(responses);
+
+    let mut conversation = Vec::new();
+    let mut transcript = Vec::new();
+
+    for logged in &trace.events {
+        match &logged.event {
+            Event::UserMessage { content } => {
+                transcript.push(format!("user: {}", content));
+                conversation.push(ChatMessage::user(content.clone()));
+            }
+            Event::ModelMessage { .. } | Event::ToolCall { .. } => {
+                let request = ChatRequest::builder().messages(conversation.clone()).build();
+                let mut stream = provider
+                    .stream_chat(request, CancelToken::new())
+                    .await
+                    .context("Mock provider failed")?;
+
+                let mut text = String::new();
+                while let Some(event) = stream.next().await {
+                    match event {
+                        StreamEvent::Token(token) => text.push_str(&token),
+                        StreamEvent::ToolCall(calls) => {
+                            for call in calls {
+                                transcript
+                                    .push(format!("tool call: {} {}", call.function.name, call.function.arguments));
+                            }
+                        }
+                        StreamEvent::Error(message) => anyhow::bail!("Replay failed: {}", message),
+                        StreamEvent::Done => break,
+                        _ => {}
+                    }
+                }
+
+                if !text.is_empty() {
+                    transcript.push(format!("assistant: {}", text));
+                    conversation.push(ChatMessage::assistant(text));
+                }
+            }
+            Event::ToolResult { tool, success, error, .. } => {
+                let status = match (success, error) {
+                    (true, _) => "ok".to_string(),
+                    (false, Some(error)) => format!("failed: {}", error),
+                    (false, None) => "failed".to_string(),
+                };
+                transcript.push(format!("tool result: {} {}", tool, status));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(transcript)
+}
+
+/// Open an existing memory store and read its statistics
+async fn memory_store_stats(db_path: &Path) -> Result<thunderus_store::StoreStats> {
+    let store = MemoryStore::open(db_path)
+        .await
+        .context("Failed to open memory store")?;
+    store.stats().await.context("Failed to read memory store stats")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use clap::CommandFactory;
     use tempfile::TempDir;
+    use thunderus_core::REDACTION_MASK;
 
     #[test]
     fn test_cli_verify() {
         Cli::command().debug_assert();
     }
 
+    #[test]
+    fn test_drift_summary() {
+        use thunderus_core::memory::StalenessInfo;
+
+        let stale = |doc_id: &str, severity| StalenessInfo {
+            doc_id: doc_id.to_string(),
+            path: PathBuf::from(format!("{}.md", doc_id)),
+            last_verified: None,
+            changed_files: Vec::new(),
+            severity,
+        };
+        let mut result = DriftResult { stale_docs: Vec::new(), current_commit: "abc123".to_string() };
+        assert!(drift_summary(&result).is_none());
+
+        result.stale_docs = vec![
+            stale("fact.a", StalenessSeverity::Critical),
+            stale("fact.b", StalenessSeverity::Minor),
+            stale("fact.c", StalenessSeverity::Minor),
+        ];
+        let summary = drift_summary(&result).unwrap();
+        assert!(summary.contains("3 stale document(s) (1 critical, 0 major, 2 minor)"));
+        assert!(summary.contains("/garden verify"));
+    }
+
     #[test]
     fn test_cli_default_values() {
         let cli = Cli::try_parse_from(["thunderus", "status"]).unwrap();
@@ -871,22 +1457,66 @@ mod tests {
         assert!(matches!(cli.command.unwrap(), Commands::Start { .. }));
 
         let cli = Cli::try_parse_from(["thunderus", "start", "--dir", "/workspace"]).unwrap();
-        if let Some(Commands::Start { dir, test_mode: _ }) = cli.command {
+        if let Some(Commands::Start { dir, test_mode: _, resume }) = cli.command {
             assert_eq!(dir, Some(PathBuf::from("/workspace")));
+            assert_eq!(resume, None);
         } else {
             panic!("Expected Start command");
         }
     }
 
+    #[test]
+    fn test_cli_start_resume() {
+        let cli = Cli::try_parse_from(["thunderus", "start", "--resume"]).unwrap();
+        assert!(matches!(cli.command, Some(Commands::Start { resume: Some(None), .. })));
+
+        let cli = Cli::try_parse_from(["thunderus", "start", "--resume", "2026-01-23T22-30-00Z"]).unwrap();
+        if let Some(Commands::Start { resume: Some(Some(id)), .. }) = cli.command {
+            assert_eq!(id, "2026-01-23T22-30-00Z");
+        } else {
+            panic!("Expected Start command with a session ID");
+        }
+    }
+
+    #[test]
+    fn test_resume_session() {
+        let temp = TempDir::new().unwrap();
+        let agent_dir = AgentDir::new(temp.path());
+
+        let err = resume_session(&agent_dir, None).unwrap_err();
+        assert!(err.to_string().contains("No sessions to resume"));
+
+        let mut session = Session::new(agent_dir.clone()).unwrap();
+        session.append_user_message("Fix the build").unwrap();
+
+        assert_eq!(resume_session(&agent_dir, None).unwrap().id, session.id);
+        assert_eq!(
+            resume_session(&agent_dir, Some(session.id.as_str())).unwrap().id,
+            session.id
+        );
+
+        let err = resume_session(&agent_dir, Some("2020-01-01T00-00-00Z")).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("no session '2020-01-01T00-00-00Z'"));
+        assert!(message.contains("Available sessions"));
+        assert!(message.contains(session.id.as_str()));
+
+        let err = resume_session(&agent_dir, Some("last-week")).unwrap_err();
+        assert!(err.to_string().contains("Available sessions"));
+    }
+
     #[test]
     fn test_cli_exec_command() {
         let cli = Cli::try_parse_from(["thunderus", "exec", "cargo", "test"]).unwrap();
         let cmd = cli.command.unwrap();
         assert!(matches!(cmd, Commands::Exec { .. }));
 
-        if let Commands::Exec { command, args } = cmd {
+        if let Commands::Exec { command, args, approve_all, json, read_only } = cmd {
             assert_eq!(command, "cargo");
             assert_eq!(args, vec!["test"]);
+            assert!(!approve_all);
+            assert!(!json);
+            assert!(!read_only);
         } else {
             panic!("Expected Exec command");
         }
@@ -896,7 +1526,7 @@ mod tests {
     fn test_cli_exec_command_with_args() {
         let cli = Cli::try_parse_from(["thunderus", "exec", "cargo", "build", "--", "--release"]).unwrap();
 
-        if let Some(Commands::Exec { command, args }) = cli.command {
+        if let Some(Commands::Exec { command, args, .. }) = cli.command {
             assert_eq!(command, "cargo");
             assert_eq!(args, vec!["build", "--release"]);
         } else {
@@ -904,6 +1534,121 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_exec_command_approve_all() {
+        let cli = Cli::try_parse_from(["thunderus", "exec", "--approve-all", "fix the build"]).unwrap();
+
+        if let Some(Commands::Exec { command, approve_all, .. }) = cli.command {
+            assert_eq!(command, "fix the build");
+            assert!(approve_all);
+        } else {
+            panic!("Expected Exec command");
+        }
+    }
+
+    #[test]
+    fn test_cli_exec_command_json() {
+        let cli = Cli::try_parse_from(["thunderus", "exec", "--json", "run tests"]).unwrap();
+
+        if let Some(Commands::Exec { command, json, .. }) = cli.command {
+            assert_eq!(command, "run tests");
+            assert!(json);
+        } else {
+            panic!("Expected Exec command");
+        }
+    }
+
+    #[test]
+    fn test_cli_export_trace_command() {
+        let cli = Cli::parse_from([
+            "thunderus",
+            "export-trace",
+            "2024-01-01T12-00-00Z",
+            "-o",
+            "bug.trace.json",
+        ]);
+        if let Some(Commands::ExportTrace { session_id, output }) = cli.command {
+            assert_eq!(session_id, "2024-01-01T12-00-00Z");
+            assert_eq!(output, Some(PathBuf::from("bug.trace.json")));
+        } else {
+            panic!("Expected ExportTrace command");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_export_and_replay_trace() {
+        let temp = TempDir::new().unwrap();
+        let config_path = temp.path().join("config.toml");
+        std::fs::write(&config_path, Config::example()).unwrap();
+
+        let mut session = Session::new(AgentDir::new(temp.path())).unwrap();
+        session.append_user_message("List the files").unwrap();
+        session
+            .append_tool_call("glob", serde_json::json!({"pattern": "*.rs"}))
+            .unwrap();
+        session
+            .append_tool_result("glob", serde_json::json!("main.rs"), true, None)
+            .unwrap();
+        session.append_model_message("There is one file.", None).unwrap();
+
+        let config = Config::from_toml_str(Config::example()).unwrap();
+        let output = temp.path().join("session.trace.json");
+        cmd_export_trace(
+            &config,
+            &config_path,
+            Some(temp.path().to_path_buf()),
+            None,
+            session.id.to_string(),
+            Some(output.clone()),
+            false,
+        )
+        .unwrap();
+
+        let fallback_output = temp.path().join("fallback.trace.json");
+        cmd_export_trace(
+            &config,
+            &temp.path().join("missing.toml"),
+            Some(temp.path().to_path_buf()),
+            None,
+            session.id.to_string(),
+            Some(fallback_output.clone()),
+            false,
+        )
+        .unwrap();
+        let fallback = SessionTrace::load(&fallback_output).unwrap();
+        assert!(fallback.config.contains("default_profile"));
+        assert!(fallback.config.contains(REDACTION_MASK));
+        assert!(!fallback.config.contains("your-api-key-here"));
+
+        let err = cmd_export_trace(
+            &config,
+            &config_path,
+            Some(temp.path().to_path_buf()),
+            None,
+            "2020-01-01T00-00-00Z".to_string(),
+            None,
+            false,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("no session '2020-01-01T00-00-00Z'"));
+
+        let trace = SessionTrace::load(&output).unwrap();
+        assert_eq!(trace.events.len(), 4);
+        assert!(!trace.config.contains("your-api-key-here"));
+        assert!(trace.config.contains(REDACTION_MASK));
+
+        let transcript = replay_trace(&trace).await.unwrap();
+        assert_eq!(
+            transcript,
+            vec![
+                "user: List the files".to_string(),
+                "tool call: glob {\"pattern\":\"*.rs\"}".to_string(),
+                "tool result: glob ok".to_string(),
+                "assistant: There is one file.".to_string(),
+            ]
+        );
+    }
+
     #[test]
     fn test_cli_status_command() {
         let cli = Cli::try_parse_from(["thunderus", "status"]).unwrap();
@@ -949,35 +1694,98 @@ mod tests {
     #[test]
     fn test_cmd_status() {
         let config = create_test_config();
-        let result = cmd_status(config, false, false);
+        let result = cmd_status(config, None, false, false);
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_cmd_status_verbose() {
         let config = create_test_config();
-        let result = cmd_status(config, true, false);
+        let temp = TempDir::new().unwrap();
+        let result = cmd_status(config, Some(temp.path().to_path_buf()), true, false);
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_cli_exec_command_read_only() {
+        let cli = Cli::try_parse_from(["thunderus", "exec", "--read-only", "review this diff"]).unwrap();
+
+        if let Some(Commands::Exec { command, read_only, .. }) = cli.command {
+            assert_eq!(command, "review this diff");
+            assert!(read_only);
+        } else {
+            panic!("Expected Exec command");
+        }
+    }
+
     #[test]
     fn test_cmd_status_check_providers() {
         let config = create_test_config();
-        let result = cmd_status(config, false, true);
+        let result = cmd_status(config, None, false, true);
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_health_check_within_times_out() {
+        let hung = std::future::pending::<std::result::Result<HealthCheckResult, String>>();
+        let result = health_check_within(Duration::from_millis(10), hung).await;
+        assert_eq!(result, Err("Health check timed out after 10ms".to_string()));
+
+        let healthy = async { Ok::<_, String>(HealthCheckResult::healthy(42)) };
+        assert_eq!(health_check_within(Duration::from_secs(1), healthy).await, Ok(42));
+    }
+
+    #[tokio::test]
+    async fn test_run_bounded_caps_concurrency() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let results = run_bounded(0..10, MAX_CONCURRENT_HEALTH_CHECKS, |i| {
+            let (in_flight, peak) = (in_flight.clone(), peak.clone());
+            async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                i
+            }
+        })
+        .await
+        .unwrap();
+
+        let mut results = results;
+        results.sort();
+        assert_eq!(results, (0..10).collect::<Vec<_>>());
+        assert_eq!(peak.load(Ordering::SeqCst), MAX_CONCURRENT_HEALTH_CHECKS);
+    }
+
     #[test]
     fn test_cmd_exec() {
         let config = create_test_config();
-        let result = cmd_exec(config, "echo".to_string(), vec!["test".to_string()], None, false);
+        let result = cmd_exec(
+            config,
+            "echo".to_string(),
+            vec!["test".to_string()],
+            None,
+            false,
+            ExecOptions::default(),
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_cmd_exec_read_only() {
+        let config = create_test_config();
+        let options = ExecOptions { read_only: true, ..Default::default() };
+        let result = cmd_exec(config, "review".to_string(), vec![], None, false, options);
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_cmd_exec_verbose() {
         let config = create_test_config();
-        let result = cmd_exec(config, "ls".to_string(), vec![], None, true);
+        let result = cmd_exec(config, "ls".to_string(), vec![], None, true, ExecOptions::default());
         assert!(result.is_ok());
     }
 
@@ -1102,6 +1910,7 @@ mod tests {
             Some("nonexistent".to_string()),
             false,
             false,
+            None,
         )
         .await;
         assert!(result.is_err());
@@ -64,6 +64,9 @@ pub struct ApprovalContext {
     pub metadata: HashMap<String, String>,
     /// Classification reasoning (if available)
     pub classification_reasoning: Option<String>,
+    /// Unified diff of the change, for file-mutating actions
+    #[serde(default)]
+    pub diff: Option<String>,
 }
 
 impl ApprovalContext {
@@ -74,6 +77,7 @@ impl ApprovalContext {
             affected_paths: Vec::new(),
             metadata: HashMap::new(),
             classification_reasoning: None,
+            diff: None,
         }
     }
 
@@ -106,6 +110,11 @@ impl ApprovalContext {
         self.classification_reasoning = Some(reasoning.into());
         self
     }
+
+    pub fn with_diff(mut self, diff: impl Into<String>) -> Self {
+        self.diff = Some(diff.into());
+        self
+    }
 }
 
 impl Default for ApprovalContext {
@@ -193,11 +202,18 @@ pub enum ApprovalDecision {
     Rejected,
     /// User cancelled (no decision)
     Cancelled,
+    /// Approved by policy without prompting the user
+    AutoApproved,
 }
 
 impl ApprovalDecision {
+    /// Whether the action was allowed to proceed, by the user or by policy
     pub fn is_approved(&self) -> bool {
-        matches!(self, Self::Approved)
+        matches!(self, Self::Approved | Self::AutoApproved)
+    }
+
+    pub fn is_auto_approved(&self) -> bool {
+        matches!(self, Self::AutoApproved)
     }
 
     pub fn is_rejected(&self) -> bool {
@@ -266,6 +282,102 @@ pub struct ApprovalGate {
     pending: HashMap<ApprovalId, ApprovalRequest>,
     /// Track decision history
     history: Vec<ApprovalRecord>,
+    /// Hosts that network commands may reach without prompting in auto mode
+    allowed_hosts: Vec<String>,
+    /// Rules remembered from "approve for this session" decisions
+    session_rules: Vec<ApprovalRule>,
+}
+
+/// Session-scoped rule that approves requests resembling one the user already approved
+///
+/// Rules match on action type, tool name and a glob over the action's subject
+/// (the target path, or the shell command). They live only in the gate and
+/// are never written to disk.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ApprovalRule {
+    /// Type of action the rule covers
+    pub action_type: ActionType,
+    /// Tool the rule covers
+    pub tool: Option<String>,
+    /// Glob over the action's subject; `*` covers every invocation of the tool
+    pub scope: String,
+}
+
+impl ApprovalRule {
+    /// Derive a rule from an approved request
+    ///
+    /// File actions generalise to sibling files with the same extension, so
+    /// approving an edit to `src/ui/app.rs` covers `src/ui/*.rs`. Shell
+    /// commands only match the exact same command.
+    pub fn from_request(request: &ApprovalRequest) -> Self {
+        let scope = match (request_path(&request.context), request_command(&request.context)) {
+            (Some(path), _) => {
+                let path = std::path::Path::new(&path);
+                let file = match path.extension().and_then(|ext| ext.to_str()) {
+                    Some(ext) => format!("*.{}", glob::Pattern::escape(ext)),
+                    None => "*".to_string(),
+                };
+                match path
+                    .parent()
+                    .and_then(|parent| parent.to_str())
+                    .filter(|p| !p.is_empty())
+                {
+                    Some(parent) => format!("{}/{}", glob::Pattern::escape(parent.trim_end_matches('/')), file),
+                    None => file,
+                }
+            }
+            (None, Some(command)) => glob::Pattern::escape(&command),
+            (None, None) => "*".to_string(),
+        };
+
+        Self { action_type: request.action_type.clone(), tool: request.context.name.clone(), scope }
+    }
+
+    /// Check whether an action falls under this rule
+    pub fn matches(&self, action_type: &ActionType, context: &ApprovalContext) -> bool {
+        if &self.action_type != action_type || self.tool != context.name {
+            return false;
+        }
+        if self.scope == "*" {
+            return true;
+        }
+
+        let Some(subject) = request_path(context).or_else(|| request_command(context)) else {
+            return false;
+        };
+        let options = glob::MatchOptions { require_literal_separator: true, ..Default::default() };
+        glob::Pattern::new(&self.scope)
+            .map(|pattern| pattern.matches_with(&subject, options))
+            .unwrap_or(false)
+    }
+}
+
+impl Display for ApprovalRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.tool {
+            Some(tool) => write!(f, "{} {} {}", self.action_type, tool, self.scope),
+            None => write!(f, "{} {}", self.action_type, self.scope),
+        }
+    }
+}
+
+fn request_path(context: &ApprovalContext) -> Option<String> {
+    context
+        .arguments
+        .as_ref()
+        .and_then(|args| args.get("file_path").or_else(|| args.get("path")))
+        .and_then(|value| value.as_str())
+        .map(str::to_string)
+        .or_else(|| context.affected_paths.first().cloned())
+}
+
+fn request_command(context: &ApprovalContext) -> Option<String> {
+    context
+        .arguments
+        .as_ref()
+        .and_then(|args| args.get("command"))
+        .and_then(|value| value.as_str())
+        .map(str::to_string)
 }
 
 /// Record of an approval decision
@@ -277,12 +389,23 @@ pub struct ApprovalRecord {
     pub decision: ApprovalDecision,
     /// When the decision was made
     pub decided_at: String,
+    /// Why the action was approved automatically, or why the user rejected it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
 }
 
 impl ApprovalGate {
     /// Create a new approval gate
     pub fn new(mode: ApprovalMode, allow_network: bool) -> Self {
-        Self { mode, allow_network, next_id: 0, pending: HashMap::new(), history: Vec::new() }
+        Self {
+            mode,
+            allow_network,
+            next_id: 0,
+            pending: HashMap::new(),
+            history: Vec::new(),
+            allowed_hosts: Vec::new(),
+            session_rules: Vec::new(),
+        }
     }
 
     /// Check if an action requires approval based on mode and risk
@@ -316,6 +439,73 @@ impl ApprovalGate {
         }
     }
 
+    /// Check if a shell command requires approval, inspecting the hosts it targets
+    ///
+    /// In auto mode, a network command whose every target host is on the
+    /// allowlist is judged on its risk alone, as if it made no network access.
+    /// Commands whose hosts cannot be determined fall back to the regular
+    /// network policy.
+    pub fn check_shell_command(&self, risk_level: ToolRisk, command: &str) -> bool {
+        if risk_level.is_blocked() {
+            return true;
+        }
+        if !crate::network::is_network_command(command) {
+            return self.requires_approval(risk_level, false);
+        }
+
+        if self.mode == ApprovalMode::Auto
+            && let Some(hosts) = crate::network::extract_network_hosts(command)
+            && !hosts.is_empty()
+            && hosts.iter().all(|host| self.is_host_allowed(host))
+        {
+            return self.requires_approval(risk_level, false);
+        }
+        self.check_requires_approval(risk_level, &ActionType::Network)
+    }
+
+    /// Check if a host is on the network allowlist
+    pub fn is_host_allowed(&self, host: &str) -> bool {
+        self.allowed_hosts
+            .iter()
+            .any(|allowed| crate::network::host_matches(host, allowed))
+    }
+
+    /// Get the network host allowlist
+    pub fn allowed_hosts(&self) -> &[String] {
+        &self.allowed_hosts
+    }
+
+    /// Set the network host allowlist
+    pub fn set_allowed_hosts(&mut self, hosts: Vec<String>) {
+        self.allowed_hosts = hosts;
+    }
+
+    /// Remember a rule for the rest of the session, returning false if it was already known
+    pub fn remember(&mut self, rule: ApprovalRule) -> bool {
+        if self.session_rules.contains(&rule) {
+            return false;
+        }
+        self.session_rules.push(rule);
+        true
+    }
+
+    /// Get the rules remembered this session
+    pub fn session_rules(&self) -> &[ApprovalRule] {
+        &self.session_rules
+    }
+
+    /// Forget every remembered rule, returning how many were cleared
+    pub fn clear_session_rules(&mut self) -> usize {
+        std::mem::take(&mut self.session_rules).len()
+    }
+
+    /// Find a remembered rule covering an action
+    pub fn matching_rule(&self, action_type: &ActionType, context: &ApprovalContext) -> Option<&ApprovalRule> {
+        self.session_rules
+            .iter()
+            .find(|rule| rule.matches(action_type, context))
+    }
+
     /// Create an approval request and return its ID
     pub fn create_request(
         &mut self, action_type: ActionType, description: impl Into<String>, context: ApprovalContext,
@@ -354,12 +544,37 @@ impl ApprovalGate {
         let now = chrono::Utc::now();
         let decided_at = now.to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
 
-        let record = ApprovalRecord { request, decision: response.decision, decided_at };
+        let record = ApprovalRecord { request, decision: response.decision, decided_at, reason: response.message };
         self.history.push(record);
 
         Ok(())
     }
 
+    /// Record an action that policy allowed without prompting
+    ///
+    /// No pending request is created; the record goes straight into the
+    /// history so the audit trail covers every decision.
+    pub fn record_auto_approval(
+        &mut self, action_type: ActionType, description: impl Into<String>, context: ApprovalContext,
+        risk_level: ToolRisk, reason: impl Into<String>,
+    ) -> ApprovalRecord {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let request = ApprovalRequest::new(id, action_type, description, context, risk_level);
+        let now = chrono::Utc::now();
+        let decided_at = now.to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+
+        let record = ApprovalRecord {
+            request,
+            decision: ApprovalDecision::AutoApproved,
+            decided_at,
+            reason: Some(reason.into()),
+        };
+        self.history.push(record.clone());
+        record
+    }
+
     /// Approve a pending request
     pub fn approve(&mut self, request_id: ApprovalId) -> Result<()> {
         self.record_decision(ApprovalResponse::approved(request_id))
@@ -370,6 +585,11 @@ impl ApprovalGate {
         self.record_decision(ApprovalResponse::rejected(request_id))
     }
 
+    /// Reject a pending request, recording the user's reason
+    pub fn reject_with_reason(&mut self, request_id: ApprovalId, reason: impl Into<String>) -> Result<()> {
+        self.record_decision(ApprovalResponse::rejected(request_id).with_message(reason))
+    }
+
     /// Cancel a pending request
     pub fn cancel(&mut self, request_id: ApprovalId) -> Result<()> {
         self.record_decision(ApprovalResponse::cancelled(request_id))
@@ -402,11 +622,23 @@ impl ApprovalGate {
 
     /// Get decision statistics
     pub fn stats(&self) -> ApprovalStats {
-        let approved = self.history.iter().filter(|r| r.decision.is_approved()).count();
+        let approved = self
+            .history
+            .iter()
+            .filter(|r| r.decision == ApprovalDecision::Approved)
+            .count();
+        let auto_approved = self.history.iter().filter(|r| r.decision.is_auto_approved()).count();
         let rejected = self.history.iter().filter(|r| r.decision.is_rejected()).count();
         let cancelled = self.history.iter().filter(|r| r.decision.is_cancelled()).count();
 
-        ApprovalStats { total: self.history.len(), approved, rejected, cancelled, pending: self.pending.len() }
+        ApprovalStats {
+            total: self.history.len(),
+            approved,
+            auto_approved,
+            rejected,
+            cancelled,
+            pending: self.pending.len(),
+        }
     }
 }
 
@@ -415,6 +647,7 @@ impl ApprovalGate {
 pub struct ApprovalStats {
     pub total: usize,
     pub approved: usize,
+    pub auto_approved: usize,
     pub rejected: usize,
     pub cancelled: usize,
     pub pending: usize,
@@ -425,6 +658,14 @@ pub trait ApprovalProtocol: Send + Sync {
     /// Request approval for an action
     fn request_approval(&self, request: &ApprovalRequest) -> Result<ApprovalDecision>;
 
+    /// Request approval, keeping any message the user gave with the decision
+    ///
+    /// Backends that let the user explain a rejection override this; the
+    /// default wraps [`ApprovalProtocol::request_approval`] without a message.
+    fn request_response(&self, request: &ApprovalRequest) -> Result<ApprovalResponse> {
+        Ok(ApprovalResponse::new(request.id, self.request_approval(request)?))
+    }
+
     /// Get the name of this protocol
     fn name(&self) -> &str;
 }
@@ -732,6 +973,19 @@ mod tests {
         assert_eq!(gate.history()[0].decision, ApprovalDecision::Rejected);
     }
 
+    #[test]
+    fn test_approval_gate_reject_with_reason() {
+        let mut gate = ApprovalGate::new(ApprovalMode::Auto, false);
+        let id = gate.create_request(ActionType::Tool, "Test", ApprovalContext::new(), ToolRisk::Risky);
+
+        gate.reject_with_reason(id, "edit the test file instead").unwrap();
+
+        let record = &gate.history()[0];
+        assert_eq!(record.decision, ApprovalDecision::Rejected);
+        assert_eq!(record.reason.as_deref(), Some("edit the test file instead"));
+        assert_eq!(gate.stats().rejected, 1);
+    }
+
     #[test]
     fn test_approval_gate_cancel() {
         let mut gate = ApprovalGate::new(ApprovalMode::Auto, false);
@@ -773,6 +1027,47 @@ mod tests {
         assert_eq!(stats.pending, 0);
     }
 
+    #[test]
+    fn test_record_auto_approval() {
+        let mut gate = ApprovalGate::new(ApprovalMode::Auto, false);
+
+        let record = gate.record_auto_approval(
+            ActionType::Tool,
+            "Execute tool: read",
+            ApprovalContext::new().with_name("read"),
+            ToolRisk::Safe,
+            "safe tool in auto mode",
+        );
+        assert_eq!(record.decision, ApprovalDecision::AutoApproved);
+        assert_eq!(record.reason.as_deref(), Some("safe tool in auto mode"));
+        assert!(record.decision.is_approved());
+        assert_eq!(gate.pending_count(), 0);
+        assert_eq!(gate.history().len(), 1);
+
+        let stats = gate.stats();
+        assert_eq!(stats.total, 1);
+        assert_eq!(stats.approved, 0);
+        assert_eq!(stats.auto_approved, 1);
+    }
+
+    #[test]
+    fn test_check_shell_command_allowed_hosts() {
+        let mut gate = ApprovalGate::new(ApprovalMode::Auto, false);
+        gate.set_allowed_hosts(vec!["localhost".to_string(), "example.com".to_string()]);
+
+        assert!(!gate.check_shell_command(ToolRisk::Safe, "git clone https://example.com/org/repo"));
+        assert!(!gate.check_shell_command(ToolRisk::Safe, "git fetch http://localhost:8080/repo.git"));
+        assert!(gate.check_shell_command(ToolRisk::Safe, "git clone https://evil.io/org/repo"));
+        assert!(gate.check_shell_command(ToolRisk::Safe, "wget $TARGET"));
+        assert!(gate.check_shell_command(ToolRisk::Risky, "curl http://localhost:8080/health"));
+        assert!(gate.check_shell_command(ToolRisk::Risky, "rm -rf ~ http://localhost"));
+        assert!(gate.check_shell_command(ToolRisk::Blocked, "curl http://localhost"));
+        assert!(!gate.check_shell_command(ToolRisk::Safe, "ls -la"));
+
+        gate.set_mode(ApprovalMode::ReadOnly);
+        assert!(gate.check_shell_command(ToolRisk::Safe, "git clone https://example.com/org/repo"));
+    }
+
     #[test]
     fn test_approval_gate_stats_with_pending() {
         let mut gate = ApprovalGate::new(ApprovalMode::Auto, false);
@@ -868,6 +1163,7 @@ mod tests {
             request: request.clone(),
             decision: ApprovalDecision::Approved,
             decided_at: "2025-01-12T00:00:00Z".to_string(),
+            reason: None,
         };
 
         let json = serde_json::to_string(&record).unwrap();
@@ -880,9 +1176,9 @@ mod tests {
 
     #[test]
     fn test_approval_stats_equality() {
-        let stats1 = ApprovalStats { total: 10, approved: 5, rejected: 3, cancelled: 2, pending: 1 };
-        let stats2 = ApprovalStats { total: 10, approved: 5, rejected: 3, cancelled: 2, pending: 1 };
-        let stats3 = ApprovalStats { total: 10, approved: 5, rejected: 3, cancelled: 2, pending: 0 };
+        let stats1 = ApprovalStats { total: 10, approved: 5, auto_approved: 0, rejected: 3, cancelled: 2, pending: 1 };
+        let stats2 = ApprovalStats { total: 10, approved: 5, auto_approved: 0, rejected: 3, cancelled: 2, pending: 1 };
+        let stats3 = ApprovalStats { total: 10, approved: 5, auto_approved: 0, rejected: 3, cancelled: 2, pending: 0 };
 
         assert_eq!(stats1, stats2);
         assert_ne!(stats1, stats3);
@@ -959,4 +1255,66 @@ mod tests {
             assert!(gate.check_requires_approval(ToolRisk::Risky, &action_type));
         }
     }
+
+    fn edit_request(path: &str) -> ApprovalRequest {
+        let context = ApprovalContext::new()
+            .with_name("edit")
+            .with_arguments(serde_json::json!({"file_path": path}));
+        ApprovalRequest::new(0, ActionType::FileWrite, "Execute tool: edit", context, ToolRisk::Risky)
+    }
+
+    #[test]
+    fn test_approval_rule_from_file_request() {
+        let rule = ApprovalRule::from_request(&edit_request("src/ui/app.rs"));
+        assert_eq!(rule.action_type, ActionType::FileWrite);
+        assert_eq!(rule.tool.as_deref(), Some("edit"));
+        assert_eq!(rule.scope, "src/ui/*.rs");
+        assert_eq!(rule.to_string(), "FileWrite edit src/ui/*.rs");
+
+        let sibling = edit_request("src/ui/footer.rs");
+        assert!(rule.matches(&sibling.action_type, &sibling.context));
+
+        let nested = edit_request("src/ui/state/mod.rs");
+        assert!(!rule.matches(&nested.action_type, &nested.context));
+
+        let other_ext = edit_request("src/ui/README.md");
+        assert!(!rule.matches(&other_ext.action_type, &other_ext.context));
+
+        let write = ApprovalContext::new()
+            .with_name("write")
+            .with_arguments(serde_json::json!({"file_path": "src/ui/footer.rs"}));
+        assert!(!rule.matches(&ActionType::FileWrite, &write));
+    }
+
+    #[test]
+    fn test_approval_rule_shell_command_is_exact() {
+        let context = ApprovalContext::new()
+            .with_name("shell")
+            .with_arguments(serde_json::json!({"command": "cargo test [unit]*"}));
+        let request = ApprovalRequest::new(0, ActionType::Shell, "Execute tool: shell", context, ToolRisk::Risky);
+        let rule = ApprovalRule::from_request(&request);
+        assert!(rule.matches(&ActionType::Shell, &request.context));
+
+        let other = ApprovalContext::new()
+            .with_name("shell")
+            .with_arguments(serde_json::json!({"command": "cargo test unit-x"}));
+        assert!(!rule.matches(&ActionType::Shell, &other));
+    }
+
+    #[test]
+    fn test_gate_session_rules() {
+        let mut gate = ApprovalGate::new(ApprovalMode::Auto, false);
+        let request = edit_request("src/lib.rs");
+        assert!(gate.matching_rule(&request.action_type, &request.context).is_none());
+
+        assert!(gate.remember(ApprovalRule::from_request(&request)));
+        assert!(!gate.remember(ApprovalRule::from_request(&edit_request("src/main.rs"))));
+        assert_eq!(gate.session_rules().len(), 1);
+
+        let next = edit_request("src/main.rs");
+        assert!(gate.matching_rule(&next.action_type, &next.context).is_some());
+
+        assert_eq!(gate.clear_session_rules(), 1);
+        assert!(gate.matching_rule(&next.action_type, &next.context).is_none());
+    }
 }
@@ -44,6 +44,74 @@ impl ToolRisk {
     }
 }
 
+/// Family of commands a classification rule belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RiskCategory {
+    /// Test runners
+    Test,
+    /// Formatters and linters
+    Formatter,
+    /// Commands that only read or display files
+    ReadOnly,
+    /// Git commands that only inspect the repository
+    GitRead,
+    /// Check and verify commands
+    Verify,
+    /// Text processing tools whose risk depends on their flags
+    TextTool,
+    /// File and directory deletion
+    Deletion,
+    /// Package installation and dependency changes
+    Package,
+    /// File moves, copies and permission changes
+    FileModify,
+    /// Network transfers and remote access
+    Network,
+    /// Nested shells that can run arbitrary commands
+    Shell,
+    /// Git commands that rewrite history or publish changes
+    GitWrite,
+    /// Commands that are always denied
+    Blocked,
+    /// Patterns added at runtime
+    Custom,
+    /// No rule matched
+    Unknown,
+}
+
+impl RiskCategory {
+    /// Short human-readable label
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Test => "test",
+            Self::Formatter => "formatter",
+            Self::ReadOnly => "read-only",
+            Self::GitRead => "git read",
+            Self::Verify => "verify",
+            Self::TextTool => "text tool",
+            Self::Deletion => "deletion",
+            Self::Package => "package management",
+            Self::FileModify => "file modification",
+            Self::Network => "network",
+            Self::Shell => "shell access",
+            Self::GitWrite => "git write",
+            Self::Blocked => "blocked",
+            Self::Custom => "custom rule",
+            Self::Unknown => "unknown command",
+        }
+    }
+}
+
+/// The rule behind a classification
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ClassificationBasis {
+    /// Category of the rule that matched
+    pub category: RiskCategory,
+    /// The command, flag or pattern that matched, if any
+    pub pattern: Option<String>,
+}
+
 /// Classification result with reasoning
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct Classification {
@@ -51,11 +119,14 @@ pub struct Classification {
     pub reasoning: String,
     /// Suggested safer alternative (if applicable)
     pub suggestion: Option<String>,
+    /// Which rule produced this classification
+    #[serde(default)]
+    pub basis: Option<ClassificationBasis>,
 }
 
 impl Classification {
     pub fn new(risk: ToolRisk, reasoning: impl Into<String>) -> Self {
-        Self { risk, reasoning: reasoning.into(), suggestion: None }
+        Self { risk, reasoning: reasoning.into(), suggestion: None, basis: None }
     }
 
     pub fn with_suggestion(mut self, suggestion: impl Into<String>) -> Self {
@@ -63,6 +134,30 @@ impl Classification {
         self
     }
 
+    /// Record the rule category and matched pattern behind this classification
+    pub fn with_basis(mut self, category: RiskCategory, pattern: Option<&str>) -> Self {
+        self.basis = Some(ClassificationBasis { category, pattern: pattern.map(str::to_string) });
+        self
+    }
+
+    /// Reasoning for approval prompts, prefixed with the risk and the rule that matched
+    ///
+    /// e.g. `risky (network, matched 'curl'): Command 'curl' performs network operations...`
+    pub fn explain(&self) -> String {
+        let basis = match &self.basis {
+            Some(ClassificationBasis { category, pattern: Some(pattern) }) => {
+                format!(" ({}, matched '{}')", category.label(), pattern)
+            }
+            Some(ClassificationBasis { category, pattern: None }) => format!(" ({})", category.label()),
+            None => String::new(),
+        };
+        let mut explanation = format!("{}{}: {}", self.risk.as_str(), basis, self.reasoning);
+        if let Some(suggestion) = &self.suggestion {
+            explanation.push_str(&format!("\nSuggestion: {}", suggestion));
+        }
+        explanation
+    }
+
     pub fn is_safe(&self) -> bool {
         self.risk.is_safe()
     }
@@ -139,6 +234,24 @@ mod tests {
         assert!(deserialized.suggestion.is_none());
     }
 
+    #[test]
+    fn test_classification_explain() {
+        let classification = Classification::new(ToolRisk::Risky, "Command 'curl' performs network operations")
+            .with_basis(RiskCategory::Network, Some("curl"));
+        assert_eq!(
+            classification.explain(),
+            "risky (network, matched 'curl'): Command 'curl' performs network operations"
+        );
+
+        let unmatched =
+            Classification::new(ToolRisk::Safe, "defaulting to safe").with_basis(RiskCategory::Unknown, None);
+        assert_eq!(unmatched.explain(), "safe (unknown command): defaulting to safe");
+
+        let json = serde_json::to_string(&classification).unwrap();
+        let deserialized: Classification = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.basis, classification.basis);
+    }
+
     #[test]
     fn test_classification_with_suggestion() {
         let classification = Classification::new(ToolRisk::Risky, "Using sed -i is risky".to_string())
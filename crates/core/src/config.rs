@@ -1,4 +1,4 @@
-use crate::{Error, Result};
+use crate::{ApprovalDecision, Error, Result};
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -215,6 +215,37 @@ pub enum ProviderConfig {
     },
 }
 
+/// The `provider` tag of a [`ProviderConfig`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProviderKind {
+    Glm,
+    Gemini,
+    Mock,
+}
+
+impl ProviderKind {
+    pub const VALUES: &[ProviderKind] = &[ProviderKind::Glm, ProviderKind::Gemini, ProviderKind::Mock];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProviderKind::Glm => "glm",
+            ProviderKind::Gemini => "gemini",
+            ProviderKind::Mock => "mock",
+        }
+    }
+}
+
+impl ProviderConfig {
+    /// Which provider this configures
+    pub fn kind(&self) -> ProviderKind {
+        match self {
+            ProviderConfig::Glm { .. } => ProviderKind::Glm,
+            ProviderConfig::Gemini { .. } => ProviderKind::Gemini,
+            ProviderConfig::Mock { .. } => ProviderKind::Mock,
+        }
+    }
+}
+
 fn default_glm_base_url() -> String {
     "https://api.z.ai/api/paas/v4/".to_string()
 }
@@ -238,6 +269,110 @@ pub struct SkillsConfig {
     /// Enable auto-discovery based on task intent
     #[serde(default = "default_skills_auto_discovery")]
     pub auto_discovery: bool,
+
+    /// Register skill tools as `skill:<name>` instead of the bare skill name
+    #[serde(default)]
+    pub namespace_tools: bool,
+}
+
+/// Context file loading configuration
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct ContextConfig {
+    /// Additional context file globs, relative to the repository root
+    #[serde(default)]
+    pub include: Vec<String>,
+
+    /// Maximum total tokens across all context files (optional)
+    #[serde(default)]
+    pub max_tokens: Option<usize>,
+}
+
+/// Secret redaction configuration
+///
+/// Redaction runs on shell commands and tool arguments before they are written
+/// to `events.jsonl`, and on the Markdown views and traces exported from it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct RedactionConfig {
+    /// Redact secrets before they are logged (default: true)
+    pub enabled: bool,
+
+    /// Additional regex patterns to redact, on top of the built-in ones
+    ///
+    /// If a pattern has a capture group named `secret`, only that group is
+    /// replaced; otherwise the whole match is.
+    pub patterns: Vec<String>,
+}
+
+impl Default for RedactionConfig {
+    fn default() -> Self {
+        Self { enabled: true, patterns: Vec::new() }
+    }
+}
+
+/// Session log rollover thresholds
+///
+/// When the current `events.jsonl` crosses any threshold, the session continues
+/// in a new session directory linked to the previous one. All unset (the
+/// default) disables rollover.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct RolloverConfig {
+    /// Roll over once the log holds this many events
+    pub max_events: Option<usize>,
+
+    /// Roll over once the log file reaches this many bytes
+    pub max_bytes: Option<u64>,
+
+    /// Roll over once the session is this many hours old
+    pub max_age_hours: Option<u64>,
+}
+
+impl RolloverConfig {
+    /// Whether any threshold is set
+    pub fn is_enabled(&self) -> bool {
+        self.max_events.is_some() || self.max_bytes.is_some() || self.max_age_hours.is_some()
+    }
+}
+
+/// Which tools a profile makes available to the model
+///
+/// An empty `allow` list means every tool is allowed; `deny` always wins.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct ToolsConfig {
+    /// Tool names to offer; all others are hidden (empty: no restriction)
+    pub allow: Vec<String>,
+
+    /// Tool names never offered, even if listed in `allow`
+    pub deny: Vec<String>,
+}
+
+impl ToolsConfig {
+    /// Whether `tool` may be offered to and called by the model
+    pub fn allows(&self, tool: &str) -> bool {
+        (self.allow.is_empty() || self.allow.iter().any(|name| name == tool))
+            && !self.deny.iter().any(|name| name == tool)
+    }
+
+    /// Tool result returned when the model calls a tool this profile withholds
+    pub fn denial_message(&self, tool: &str, profile: &str) -> String {
+        let mut message = format!("Tool '{}' is not available in profile '{}'.", tool, profile);
+        if !self.allow.is_empty() {
+            let available: Vec<&str> = self
+                .allow
+                .iter()
+                .map(String::as_str)
+                .filter(|name| self.allows(name))
+                .collect();
+            message.push_str(&format!(" Available tools: {}.", available.join(", ")));
+        }
+        message.push_str(
+            " Do not call it again; complete the task with the available tools or explain what you would need.",
+        );
+        message
+    }
 }
 
 fn default_skills_enabled() -> bool {
@@ -346,6 +481,14 @@ pub struct Profile {
     /// Profile name
     pub name: String,
 
+    /// Parent profile whose fields this profile inherits and overrides
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extends: Option<String>,
+
+    /// Abstract profiles only serve as a base for `extends` and are hidden from profile listings
+    #[serde(default, rename = "abstract", skip_serializing_if = "std::ops::Not::not")]
+    pub is_abstract: bool,
+
     /// Working root directory (absolute path)
     pub working_root: PathBuf,
 
@@ -361,6 +504,10 @@ pub struct Profile {
     #[serde(default)]
     pub approval_mode: ApprovalMode,
 
+    /// Approval prompt behaviour (timeouts)
+    #[serde(default)]
+    pub approval: ApprovalConfig,
+
     /// Sandbox mode
     #[serde(default)]
     pub sandbox_mode: SandboxMode,
@@ -384,10 +531,34 @@ pub struct Profile {
     #[serde(default)]
     pub skills: SkillsConfig,
 
+    /// Context file loading configuration
+    #[serde(default)]
+    pub context: ContextConfig,
+
+    /// Secret redaction for session logs and exports
+    #[serde(default)]
+    pub redaction: RedactionConfig,
+
+    /// Tool allowlist and denylist
+    #[serde(default)]
+    pub tools: ToolsConfig,
+
+    /// Session log rollover thresholds
+    #[serde(default)]
+    pub rollover: RolloverConfig,
+
     /// Logging configuration
     #[serde(default)]
     pub logging: LoggingConfig,
 
+    /// Project-specific instructions appended to the system prompt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system_prompt_append: Option<String>,
+
+    /// Replaces the built-in system prompt (tool usage guidance is still included)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system_prompt_override: Option<String>,
+
     /// Additional configuration options
     #[serde(default)]
     pub options: HashMap<String, String>,
@@ -420,6 +591,49 @@ impl Default for WorkspaceConfig {
     }
 }
 
+/// Approval prompt configuration
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct ApprovalConfig {
+    /// Seconds a prompt may stay unanswered before it resolves on its own (unset: wait forever)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+
+    /// Decision applied when the timeout expires
+    #[serde(default)]
+    pub on_timeout: ApprovalTimeoutAction,
+}
+
+impl ApprovalConfig {
+    /// Timeout as a duration, ignoring a zero value
+    pub fn timeout(&self) -> Option<std::time::Duration> {
+        self.timeout_secs
+            .filter(|secs| *secs > 0)
+            .map(std::time::Duration::from_secs)
+    }
+}
+
+/// Decision applied to an approval prompt whose timeout expired
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum ApprovalTimeoutAction {
+    /// Reject the action; the agent continues the turn without it
+    #[default]
+    Reject,
+    /// Cancel the action; the agent aborts the turn
+    Cancel,
+}
+
+impl ApprovalTimeoutAction {
+    /// Decision sent to the agent on timeout
+    pub fn decision(&self) -> ApprovalDecision {
+        match self {
+            Self::Reject => ApprovalDecision::Rejected,
+            Self::Cancel => ApprovalDecision::Cancelled,
+        }
+    }
+}
+
 /// Network sandbox configuration
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(default)]
@@ -434,9 +648,15 @@ pub struct NetworkConfig {
 }
 
 /// Memory configuration including vector search settings
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct MemoryConfig {
+    /// Enable the memory system (default: true)
+    ///
+    /// When false, sessions skip the memory store, indexing, retrieval and
+    /// consolidation entirely.
+    pub enabled: bool,
+
     /// Enable vector search (default: false - lexical-only)
     #[serde(default)]
     pub enable_vector_search: bool,
@@ -453,6 +673,28 @@ pub struct MemoryConfig {
     /// Lower = more likely to use vector search. Default -3.0.
     #[serde(default = "default_vector_threshold")]
     pub vector_fallback_threshold: f64,
+
+    /// Token budget for retrieved memory, measured with each document's token count (optional)
+    #[serde(default)]
+    pub retrieval_token_budget: Option<usize>,
+
+    /// Score penalty applied to never-verified memory during retrieval (default: 0.0 - disabled)
+    #[serde(default)]
+    pub never_verified_penalty: f64,
+}
+
+impl Default for MemoryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            enable_vector_search: false,
+            vector_model: String::new(),
+            vector_dims: 0,
+            vector_fallback_threshold: default_vector_threshold(),
+            retrieval_token_budget: None,
+            never_verified_penalty: 0.0,
+        }
+    }
 }
 
 impl MemoryConfig {
@@ -542,6 +784,58 @@ impl Profile {
         false
     }
 
+    /// Collect every validation problem in this profile
+    pub fn validation_errors(&self, name: &str) -> Vec<ConfigError> {
+        let mut errors = Vec::new();
+
+        if !self.working_root.is_absolute() {
+            errors.push(ConfigError::AbsolutePathRequired(format!(
+                "working_root for profile '{}'",
+                name
+            )));
+        }
+
+        for root in &self.extra_writable_roots {
+            if !root.is_absolute() {
+                errors.push(ConfigError::AbsolutePathRequired(format!(
+                    "extra_writable_root in profile '{}'",
+                    name
+                )));
+            }
+        }
+
+        match &self.provider {
+            ProviderConfig::Glm { api_key, .. } | ProviderConfig::Gemini { api_key, .. }
+                if api_key.trim().is_empty() =>
+            {
+                errors.push(ConfigError::MissingApiKey(name.to_string()));
+            }
+            _ => {}
+        }
+
+        for pattern in &self.context.include {
+            if let Err(e) = glob::Pattern::new(pattern) {
+                errors.push(ConfigError::InvalidGlob {
+                    field: format!("profiles.{}.context.include", name),
+                    pattern: pattern.clone(),
+                    message: e.to_string(),
+                });
+            }
+        }
+
+        for pattern in &self.redaction.patterns {
+            if let Err(e) = regex::Regex::new(pattern) {
+                errors.push(ConfigError::InvalidRegex {
+                    field: format!("profiles.{}.redaction.patterns", name),
+                    pattern: pattern.clone(),
+                    message: e.to_string(),
+                });
+            }
+        }
+
+        errors
+    }
+
     /// Check if a path is a sensitive directory
     pub fn is_sensitive_dir(path: &Path) -> bool {
         let path_str = path.to_string_lossy();
@@ -631,10 +925,30 @@ fn default_profile() -> String {
 
 impl Config {
     /// Load configuration from a TOML string
+    ///
+    /// `${ENV_VAR}` references in any string value are resolved from the
+    /// environment; referencing an unset variable is an error.
     pub fn from_toml_str(toml_str: &str) -> Result<Self> {
-        let config: Config = toml::from_str(toml_str).map_err(|e| Error::Config(format!("TOML parse error: {}", e)))?;
-        config.validate()?;
-        Ok(config)
+        Self::from_toml_str_checked(toml_str).map_err(config_errors_to_error)
+    }
+
+    /// Load configuration from a TOML string, collecting every problem found
+    ///
+    /// Unlike [`Config::from_toml_str`], this does not stop at the first error,
+    /// so all misconfigured profiles can be reported together.
+    pub fn from_toml_str_checked(toml_str: &str) -> std::result::Result<Self, Vec<ConfigError>> {
+        parse_config(toml_str, true)
+    }
+
+    /// Load configuration from a TOML string without resolving `${ENV_VAR}` references
+    ///
+    /// Use this when the config will be written back to disk, so resolved
+    /// secrets are never persisted in place of their references.
+    ///
+    /// Profile inheritance is still applied and abstract profiles are dropped,
+    /// so use [`Config::update_profile_file`] to change a single setting.
+    pub fn from_toml_str_unresolved(toml_str: &str) -> Result<Self> {
+        parse_config(toml_str, false).map_err(config_errors_to_error)
     }
 
     /// Load configuration from a file
@@ -643,9 +957,76 @@ impl Config {
         Self::from_toml_str(&content)
     }
 
+    /// Load configuration from a file without resolving `${ENV_VAR}` references
+    pub fn from_file_unresolved(path: &PathBuf) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Self::from_toml_str_unresolved(&content)
+    }
+
+    /// Encode configuration as TOML
+    pub fn to_toml_string(&self) -> Result<String> {
+        toml::to_string_pretty(self).map_err(|e| Error::Config(format!("TOML encode error: {}", e)))
+    }
+
     /// Save configuration to a file as TOML
     pub fn save_to_file(&self, path: &Path) -> Result<()> {
-        let toml_str = toml::to_string_pretty(self).map_err(|e| Error::Config(format!("TOML encode error: {}", e)))?;
+        let toml_str = self.to_toml_string()?;
+        std::fs::write(path, toml_str).map_err(|e| Error::Config(format!("Failed to write config: {}", e)))?;
+        Ok(())
+    }
+
+    /// Set keys on one profile in a config file, leaving the rest of the file as written
+    ///
+    /// Keys are dotted paths relative to the profile, e.g. `options.theme`.
+    /// Only these keys land in the profile's own table, so values it inherits
+    /// through `extends` keep following its parent. The file is edited in
+    /// place, so comments and formatting survive, and the result is checked
+    /// before anything is written.
+    pub fn update_profile_file(path: &Path, profile: &str, values: &[(&str, toml::Value)]) -> Result<()> {
+        let content = std::fs::read_to_string(path)?;
+        let mut document: toml_edit::DocumentMut = content
+            .parse()
+            .map_err(|e: toml_edit::TomlError| Error::Config(ConfigError::TomlParse(e.to_string()).to_string()))?;
+
+        let Some(target) = document
+            .get_mut("profiles")
+            .and_then(|profiles| profiles.as_table_like_mut())
+            .and_then(|profiles| profiles.get_mut(profile))
+            .filter(|profile| profile.is_table_like())
+        else {
+            return Err(Error::Config(
+                ConfigError::ProfileNotFound(profile.to_string()).to_string(),
+            ));
+        };
+
+        for (key, value) in values {
+            let mut parts: Vec<&str> = key.split('.').collect();
+            let last = parts.pop().unwrap_or_default();
+            let mut table_path = format!("profiles.{}", profile);
+            let mut current = &mut *target;
+            for part in parts {
+                let table = current
+                    .as_table_like_mut()
+                    .ok_or_else(|| Error::Config(format!("{} is not a table", table_path)))?;
+                current = table.entry(part).or_insert_with(toml_edit::table);
+                table_path = format!("{}.{}", table_path, part);
+            }
+            let current = current
+                .as_table_like_mut()
+                .ok_or_else(|| Error::Config(format!("{} is not a table", table_path)))?;
+
+            let mut new_value: toml_edit::Value = value
+                .to_string()
+                .parse()
+                .map_err(|e: toml_edit::TomlError| Error::Config(format!("TOML encode error: {}", e)))?;
+            if let Some(old_value) = current.get(last).and_then(|item| item.as_value()) {
+                *new_value.decor_mut() = old_value.decor().clone();
+            }
+            current.insert(last, toml_edit::Item::Value(new_value));
+        }
+
+        let toml_str = document.to_string();
+        Self::from_toml_str_unresolved(&toml_str)?;
         std::fs::write(path, toml_str).map_err(|e| Error::Config(format!("Failed to write config: {}", e)))?;
         Ok(())
     }
@@ -665,44 +1046,52 @@ impl Config {
             .collect()
     }
 
-    /// Get a profile by name
+    /// Get a profile by name, with any `extends` parent already merged in
+    ///
+    /// Abstract profiles cannot be selected directly.
     pub fn profile(&self, name: &str) -> Result<&Profile> {
-        self.profiles
+        let profile = self
+            .profiles
             .get(name)
-            .ok_or_else(|| Error::Config(ConfigError::ProfileNotFound(name.to_string()).to_string()))
-    }
-
-    /// Get all profile names
-    pub fn profile_names(&self) -> Vec<String> {
-        self.profiles.keys().cloned().collect()
-    }
+            .ok_or_else(|| Error::Config(ConfigError::ProfileNotFound(name.to_string()).to_string()))?;
 
-    /// Validate the configuration
-    fn validate(&self) -> Result<()> {
-        if !self.profiles.contains_key(&self.default_profile) {
+        if profile.is_abstract {
             return Err(Error::Config(
-                ConfigError::ProfileNotFound(self.default_profile.clone()).to_string(),
+                ConfigError::AbstractProfile(name.to_string()).to_string(),
             ));
         }
+        Ok(profile)
+    }
 
-        for (name, profile) in &self.profiles {
-            if !profile.working_root.is_absolute() {
-                return Err(Error::Config(
-                    ConfigError::AbsolutePathRequired(format!("working_root for profile '{}'", name)).to_string(),
-                ));
-            }
+    /// Get all profile names, excluding abstract base profiles
+    pub fn profile_names(&self) -> Vec<String> {
+        self.profiles
+            .iter()
+            .filter(|(_, profile)| !profile.is_abstract)
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
 
-            for root in &profile.extra_writable_roots {
-                if !root.is_absolute() {
-                    return Err(Error::Config(
-                        ConfigError::AbsolutePathRequired(format!("extra_writable_root in profile '{}'", name))
-                            .to_string(),
-                    ));
-                }
+    /// Validate the configuration, returning every problem found
+    pub fn validate(&self) -> std::result::Result<(), Vec<ConfigError>> {
+        let default_is_abstract = self
+            .profiles
+            .get(&self.default_profile)
+            .map(|profile| profile.is_abstract);
+        let mut errors: Vec<ConfigError> = default_profile_error(&self.default_profile, default_is_abstract)
+            .into_iter()
+            .collect();
+
+        let mut names: Vec<&String> = self.profiles.keys().collect();
+        names.sort();
+        for name in names {
+            let profile = &self.profiles[name];
+            if !profile.is_abstract {
+                errors.extend(profile.validation_errors(name));
             }
         }
 
-        Ok(())
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
     }
 
     /// Get example configuration (as a string)
@@ -732,7 +1121,7 @@ allow_network = false
 [profiles.default.provider]
 # Provider type: "glm", "gemini", or "mock"
 provider = "glm"
-# API key for the provider
+# API key for the provider (use "${GLM_API_KEY}" to read it from the environment)
 api_key = "your-api-key-here"
 # Model name to use
 model = "glm-4.7"
@@ -775,6 +1164,8 @@ allow_domains = []
 
 # Memory configuration
 [profiles.default.memory]
+# Enable the memory system (false runs sessions without memory)
+enabled = true
 # Enable vector search (default: false - lexical-only)
 enable_vector_search = false
 # Vector embedding model name (optional)
@@ -783,6 +1174,10 @@ vector_model = "all-MiniLM-L6-v2"
 vector_dims = 384
 # BM25 threshold for vector fallback (lower = more likely)
 vector_fallback_threshold = -3.0
+# Token budget for retrieved memory (optional, pinned docs are included first)
+# retrieval_token_budget = 2000
+# Down-rank memory that was never verified against the repo (0.0 disables)
+# never_verified_penalty = 1.0
 
 # Skills configuration
 [profiles.default.skills]
@@ -792,12 +1187,50 @@ enabled = true
 # skills_dir = "/abs/path/to/skills"
 # Enable auto-discovery based on task intent
 auto_discovery = true
+# Register skill tools as "skill:<name>" so they can't collide with built-in tools
+namespace_tools = false
+
+# Approval prompt configuration
+[profiles.default.approval]
+# Resolve unanswered approval prompts after this many seconds (optional)
+# timeout_secs = 120
+# Decision applied on timeout: "reject" (continue without the action) or "cancel" (abort the turn)
+on_timeout = "reject"
+
+# Context file configuration
+[profiles.default.context]
+# Additional context file globs, relative to the repository root
+include = []
+# Maximum total tokens across all context files (optional)
+# max_tokens = 8000
+
+# Secret redaction for session logs and exports
+[profiles.default.redaction]
+# Replace API keys, --password= style flags and secret env assignments with ***
+enabled = true
+# Extra regex patterns; a capture group named "secret" limits what is replaced
+patterns = []
+
+# Tools offered to the model
+[profiles.default.tools]
+# Only offer these tools, e.g. ["read", "grep", "glob", "tree"] for a review-only profile (empty: all)
+allow = []
+# Never offer these tools, e.g. ["shell"]
+deny = []
+
+# Continue long sessions in a new, linked session file (unset: never roll over)
+[profiles.default.rollover]
+# max_events = 5000
+# max_bytes = 10485760
+# max_age_hours = 24
 
 # Additional options (optional)
 # [profiles.default.options]
 # max_tokens = "8192"
 # temperature = "0.7"
 # theme = "iceberg"
+# redraw_interval_ms = "100"  # coalesce streaming redraws on slow terminals
+# animations = "false"
 #
 # # Gemini provider example
 # [profiles.gemini-work]
@@ -840,6 +1273,296 @@ impl Default for Config {
     }
 }
 
+/// Profile keys that describe the profile itself and are never inherited
+const NON_INHERITED_PROFILE_KEYS: &[&str] = &["name", "extends", "abstract"];
+
+/// Merge each profile's `extends` parent into it, child values taking precedence
+fn resolve_profile_inheritance(table: &mut toml::Table) -> std::result::Result<(), ConfigError> {
+    let Some(toml::Value::Table(profiles)) = table.get("profiles") else {
+        return Ok(());
+    };
+
+    let mut resolved: HashMap<String, toml::Table> = HashMap::new();
+    for name in profiles.keys() {
+        resolve_profile(name, profiles, &mut resolved, &mut Vec::new())?;
+    }
+
+    if let Some(toml::Value::Table(profiles)) = table.get_mut("profiles") {
+        for (name, merged) in resolved {
+            profiles.insert(name, toml::Value::Table(merged));
+        }
+    }
+    Ok(())
+}
+
+fn resolve_profile(
+    name: &str, profiles: &toml::Table, resolved: &mut HashMap<String, toml::Table>, chain: &mut Vec<String>,
+) -> std::result::Result<toml::Table, ConfigError> {
+    if let Some(done) = resolved.get(name) {
+        return Ok(done.clone());
+    }
+
+    if chain.iter().any(|seen| seen == name) {
+        chain.push(name.to_string());
+        return Err(ConfigError::InheritanceCycle(chain.join(" -> ")));
+    }
+
+    let Some(toml::Value::Table(profile)) = profiles.get(name) else {
+        return Err(ConfigError::ProfileNotFound(name.to_string()));
+    };
+
+    let merged = match profile.get("extends").and_then(|v| v.as_str()) {
+        Some(parent) => {
+            if !profiles.contains_key(parent) {
+                return Err(ConfigError::ProfileNotFound(format!(
+                    "{} (extended by '{}')",
+                    parent, name
+                )));
+            }
+            chain.push(name.to_string());
+            let mut base = resolve_profile(parent, profiles, resolved, chain)?;
+            chain.pop();
+
+            base.retain(|key, _| !NON_INHERITED_PROFILE_KEYS.contains(&key));
+            merge_tables(&mut base, profile);
+            base
+        }
+        None => profile.clone(),
+    };
+
+    resolved.insert(name.to_string(), merged.clone());
+    Ok(merged)
+}
+
+/// Deep-merge `overlay` into `base`; nested tables merge, everything else is replaced
+///
+/// A provider table that switches to a different `provider` replaces the
+/// parent's table wholesale so fields from another provider don't leak in.
+fn merge_tables(base: &mut toml::Table, overlay: &toml::Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(key), value) {
+            (Some(toml::Value::Table(base_child)), toml::Value::Table(overlay_child))
+                if base_child.get("provider") == overlay_child.get("provider")
+                    || overlay_child.get("provider").is_none() =>
+            {
+                merge_tables(base_child, overlay_child);
+            }
+            _ => {
+                base.insert(key.clone(), value.clone());
+            }
+        }
+    }
+}
+
+/// Parse a TOML config, collecting all errors instead of stopping at the first
+fn parse_config(toml_str: &str, resolve_env: bool) -> std::result::Result<Config, Vec<ConfigError>> {
+    let mut table: toml::Table = toml::from_str(toml_str).map_err(|e| vec![ConfigError::from(e)])?;
+
+    let mut errors = Vec::new();
+    if resolve_env {
+        resolve_env_vars(&mut table, "", &mut errors);
+    }
+
+    if let Err(e) = resolve_profile_inheritance(&mut table) {
+        errors.push(e);
+        return Err(errors);
+    }
+
+    let default_name = table
+        .get("default_profile")
+        .and_then(|v| v.as_str())
+        .map(str::to_string)
+        .unwrap_or_else(default_profile);
+
+    match table.get_mut("profiles") {
+        Some(toml::Value::Table(profiles)) => {
+            let default_is_abstract = profiles.get(&default_name).map(is_abstract_profile);
+            errors.extend(default_profile_error(&default_name, default_is_abstract));
+
+            let mut names: Vec<&String> = profiles.keys().collect();
+            names.sort();
+            for name in names {
+                if !is_abstract_profile(&profiles[name.as_str()]) {
+                    errors.extend(check_raw_profile(name, &profiles[name.as_str()]));
+                }
+            }
+
+            // Abstract profiles only feed `extends`, which is already merged,
+            // so they need not be complete enough to run on their own
+            profiles.retain(|_, profile| !is_abstract_profile(profile));
+        }
+        _ => errors.push(ConfigError::MissingField("profiles".to_string())),
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    table.try_into().map_err(|e| vec![ConfigError::from(e)])
+}
+
+fn is_abstract_profile(profile: &toml::Value) -> bool {
+    profile.get("abstract").and_then(|v| v.as_bool()).unwrap_or(false)
+}
+
+/// Collapse collected config errors into a single crate error
+fn config_errors_to_error(errors: Vec<ConfigError>) -> Error {
+    let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+    Error::Config(messages.join("; "))
+}
+
+/// Check that the default profile exists and can be selected
+fn default_profile_error(name: &str, is_abstract: Option<bool>) -> Option<ConfigError> {
+    match is_abstract {
+        None => Some(ConfigError::ProfileNotFound(name.to_string())),
+        Some(true) => Some(ConfigError::AbstractProfile(name.to_string())),
+        Some(false) => None,
+    }
+}
+
+/// Check a profile table for errors that would otherwise only surface one at a time during deserialization
+///
+/// Each field reported here is stood in for with a valid placeholder, so the
+/// checks in [`Profile::validation_errors`] still run on the rest of the profile.
+fn check_raw_profile(name: &str, value: &toml::Value) -> Vec<ConfigError> {
+    let Some(profile) = value.as_table() else {
+        return vec![ConfigError::TomlParse(format!("profile '{}' must be a table", name))];
+    };
+
+    let mut errors = Vec::new();
+    let mut repaired = profile.clone();
+    let placeholder = |s: &str| toml::Value::String(s.to_string());
+
+    if !profile.contains_key("working_root") {
+        errors.push(ConfigError::MissingField(format!("profiles.{}.working_root", name)));
+        repaired.insert("working_root".to_string(), placeholder("/"));
+    }
+
+    if let Some(mode) = profile.get("approval_mode").and_then(|v| v.as_str())
+        && !ApprovalMode::VALUES.iter().any(|m| m.as_str() == mode)
+    {
+        errors.push(ConfigError::InvalidApprovalMode(format!(
+            "{} (profile '{}')",
+            mode, name
+        )));
+        repaired.remove("approval_mode");
+    }
+
+    if let Some(mode) = profile.get("sandbox_mode").and_then(|v| v.as_str())
+        && !SandboxMode::VALUES.iter().any(|m| m.as_str() == mode)
+    {
+        errors.push(ConfigError::InvalidSandboxMode(format!(
+            "{} (profile '{}')",
+            mode, name
+        )));
+        repaired.remove("sandbox_mode");
+    }
+
+    let mut mock = toml::Table::new();
+    mock.insert("provider".to_string(), placeholder(ProviderKind::Mock.as_str()));
+
+    let provider = profile.get("provider").and_then(|v| v.as_table());
+    let kind = provider
+        .and_then(|p| p.get("provider"))
+        .and_then(|v| v.as_str())
+        .unwrap_or("");
+    match (provider, ProviderKind::VALUES.iter().find(|k| k.as_str() == kind)) {
+        (None, _) => {
+            errors.push(ConfigError::MissingField(format!("profiles.{}.provider", name)));
+            repaired.insert("provider".to_string(), toml::Value::Table(mock));
+        }
+        (Some(_), None) => {
+            errors.push(ConfigError::UnknownProvider { profile: name.to_string(), provider: kind.to_string() });
+            repaired.insert("provider".to_string(), toml::Value::Table(mock));
+        }
+        (Some(_), Some(ProviderKind::Mock)) => {}
+        (Some(provider), Some(_)) => {
+            let mut provider = provider.clone();
+            if !provider.contains_key("api_key") {
+                errors.push(ConfigError::MissingApiKey(name.to_string()));
+                provider.insert("api_key".to_string(), placeholder("placeholder"));
+            }
+            if !provider.contains_key("model") {
+                errors.push(ConfigError::MissingField(format!("profiles.{}.provider.model", name)));
+                provider.insert("model".to_string(), placeholder("placeholder"));
+            }
+            repaired.insert("provider".to_string(), toml::Value::Table(provider));
+        }
+    }
+
+    match toml::Value::Table(repaired).try_into::<Profile>() {
+        Ok(profile) => errors.extend(profile.validation_errors(name)),
+        Err(e) => errors.push(ConfigError::TomlParse(format!("profile '{}': {}", name, e))),
+    }
+    errors
+}
+
+/// Resolve `${ENV_VAR}` references in every string value of a TOML table
+fn resolve_env_vars(table: &mut toml::Table, path: &str, errors: &mut Vec<ConfigError>) {
+    for (key, value) in table.iter_mut() {
+        let field = if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+        resolve_env_vars_in_value(value, &field, errors);
+    }
+}
+
+fn resolve_env_vars_in_value(value: &mut toml::Value, field: &str, errors: &mut Vec<ConfigError>) {
+    match value {
+        toml::Value::String(s) => match expand_env_vars(s, field) {
+            Ok(expanded) => *s = expanded,
+            Err(e) => errors.push(e),
+        },
+        toml::Value::Array(items) => {
+            for (idx, item) in items.iter_mut().enumerate() {
+                resolve_env_vars_in_value(item, &format!("{}[{}]", field, idx), errors);
+            }
+        }
+        toml::Value::Table(table) => resolve_env_vars(table, field, errors),
+        _ => {}
+    }
+}
+
+/// Expand `${ENV_VAR}` references in a config string
+///
+/// `$${` escapes a literal `${`. `field` names the config key for error messages.
+pub fn expand_env_vars(input: &str, field: &str) -> std::result::Result<String, ConfigError> {
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(start) = rest.find("${") {
+        if rest[..start].ends_with('$') {
+            output.push_str(&rest[..start - 1]);
+            output.push_str("${");
+            rest = &rest[start + 2..];
+            continue;
+        }
+
+        output.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(end) = after.find('}') else {
+            return Err(ConfigError::InvalidEnvVarReference {
+                reference: rest[start..].to_string(),
+                field: field.to_string(),
+            });
+        };
+
+        let name = &after[..end];
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(ConfigError::InvalidEnvVarReference {
+                reference: format!("${{{}}}", name),
+                field: field.to_string(),
+            });
+        }
+
+        let resolved = std::env::var(name)
+            .map_err(|_| ConfigError::EnvVarNotSet { var: name.to_string(), field: field.to_string() })?;
+        output.push_str(&resolved);
+        rest = &after[end + 1..];
+    }
+
+    output.push_str(rest);
+    Ok(output)
+}
+
 /// Configuration-specific errors
 #[derive(Debug, thiserror::Error)]
 pub enum ConfigError {
@@ -862,6 +1585,50 @@ pub enum ConfigError {
     /// TOML parse error
     #[error("TOML parse error: {0}")]
     TomlParse(String),
+
+    /// Profile `extends` chain loops back on itself
+    #[error("profile inheritance cycle: {0}")]
+    InheritanceCycle(String),
+
+    /// Abstract profile used where a concrete profile is required
+    #[error("profile '{0}' is abstract and cannot be used directly")]
+    AbstractProfile(String),
+
+    /// Required field is missing
+    #[error("missing required field: {0}")]
+    MissingField(String),
+
+    /// Provider requires an API key but none is configured
+    #[error("missing api_key for profile '{0}'")]
+    MissingApiKey(String),
+
+    /// Provider name is not recognized
+    #[error("unknown provider '{provider}' in profile '{profile}'")]
+    UnknownProvider { profile: String, provider: String },
+
+    /// Glob pattern failed to compile
+    #[error("invalid glob '{pattern}' in {field}: {message}")]
+    InvalidGlob {
+        field: String,
+        pattern: String,
+        message: String,
+    },
+
+    /// Regex pattern failed to compile
+    #[error("invalid regex '{pattern}' in {field}: {message}")]
+    InvalidRegex {
+        field: String,
+        pattern: String,
+        message: String,
+    },
+
+    /// Referenced environment variable is not set
+    #[error("environment variable '{var}' referenced by {field} is not set")]
+    EnvVarNotSet { var: String, field: String },
+
+    /// Malformed `${...}` reference
+    #[error("invalid environment variable reference '{reference}' in {field}")]
+    InvalidEnvVarReference { reference: String, field: String },
 }
 
 impl From<toml::de::Error> for ConfigError {
@@ -923,6 +1690,7 @@ mod tests {
             extra_writable_roots: vec![PathBuf::from("/data"), PathBuf::from("/cache")],
             workspace: WorkspaceConfig::default(),
             approval_mode: ApprovalMode::default(),
+            approval: ApprovalConfig::default(),
             sandbox_mode: SandboxMode::default(),
             provider: ProviderConfig::Glm {
                 api_key: "test-key".to_string(),
@@ -935,7 +1703,15 @@ mod tests {
             network: NetworkConfig::default(),
             memory: MemoryConfig::default(),
             skills: SkillsConfig::default(),
+            context: ContextConfig::default(),
+            redaction: RedactionConfig::default(),
+            tools: ToolsConfig::default(),
+            rollover: RolloverConfig::default(),
+            extends: None,
+            is_abstract: false,
             logging: LoggingConfig::default(),
+            system_prompt_append: None,
+            system_prompt_override: None,
             options: HashMap::new(),
         };
 
@@ -954,6 +1730,7 @@ mod tests {
             extra_writable_roots: vec![PathBuf::from("/data")],
             workspace: WorkspaceConfig::default(),
             approval_mode: ApprovalMode::default(),
+            approval: ApprovalConfig::default(),
             sandbox_mode: SandboxMode::default(),
             provider: ProviderConfig::Glm {
                 api_key: "test-key".to_string(),
@@ -966,7 +1743,15 @@ mod tests {
             network: NetworkConfig::default(),
             memory: MemoryConfig::default(),
             skills: SkillsConfig::default(),
+            context: ContextConfig::default(),
+            redaction: RedactionConfig::default(),
+            tools: ToolsConfig::default(),
+            rollover: RolloverConfig::default(),
+            extends: None,
+            is_abstract: false,
             logging: LoggingConfig::default(),
+            system_prompt_append: None,
+            system_prompt_override: None,
             options: HashMap::new(),
         };
 
@@ -1111,6 +1896,154 @@ model = "glm-4.7"
         assert_eq!(profile.options.get("temperature"), Some(&"0.7".to_string()));
     }
 
+    #[test]
+    fn test_config_from_toml_str_with_approval_timeout() {
+        let toml = r#"
+default_profile = "default"
+
+[profiles.default]
+name = "default"
+working_root = "/workspace"
+
+[profiles.default.approval]
+timeout_secs = 90
+on_timeout = "cancel"
+
+[profiles.default.provider]
+provider = "glm"
+api_key = "test-api-key"
+model = "glm-4.7"
+"#;
+
+        let config = Config::from_toml_str(toml).unwrap();
+        let profile = config.default_profile().unwrap();
+        assert_eq!(profile.approval.timeout(), Some(std::time::Duration::from_secs(90)));
+        assert_eq!(profile.approval.on_timeout.decision(), ApprovalDecision::Cancelled);
+    }
+
+    #[test]
+    fn test_config_redaction_patterns() {
+        let toml = r#"
+default_profile = "default"
+
+[profiles.default]
+name = "default"
+working_root = "/workspace"
+
+[profiles.default.redaction]
+patterns = ["internal-[0-9]+", "(unclosed"]
+
+[profiles.default.provider]
+provider = "glm"
+api_key = "test-api-key"
+model = "glm-4.7"
+"#;
+
+        let errors = Config::from_toml_str_checked(toml).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(&errors[0], ConfigError::InvalidRegex { pattern, .. } if pattern == "(unclosed"));
+
+        let config = Config::from_toml_str(&toml.replace(", \"(unclosed\"", "")).unwrap();
+        let redaction = &config.default_profile().unwrap().redaction;
+        assert!(redaction.enabled);
+        assert_eq!(redaction.patterns, vec!["internal-[0-9]+".to_string()]);
+    }
+
+    #[test]
+    fn test_config_rollover() {
+        let toml = r#"
+default_profile = "default"
+
+[profiles.default]
+name = "default"
+working_root = "/workspace"
+
+[profiles.default.rollover]
+max_events = 5000
+max_age_hours = 24
+
+[profiles.default.provider]
+provider = "glm"
+api_key = "test-api-key"
+model = "glm-4.7"
+"#;
+
+        let config = Config::from_toml_str(toml).unwrap();
+        let rollover = &config.default_profile().unwrap().rollover;
+        assert!(rollover.is_enabled());
+        assert_eq!(rollover.max_events, Some(5000));
+        assert_eq!(rollover.max_bytes, None);
+        assert_eq!(rollover.max_age_hours, Some(24));
+        assert!(!RolloverConfig::default().is_enabled());
+    }
+
+    #[test]
+    fn test_config_tools_allowlist() {
+        let toml = r#"
+default_profile = "review"
+
+[profiles.review]
+name = "review"
+working_root = "/workspace"
+
+[profiles.review.tools]
+allow = ["read", "grep", "shell"]
+deny = ["shell"]
+
+[profiles.review.provider]
+provider = "glm"
+api_key = "test-api-key"
+model = "glm-4.7"
+"#;
+
+        let config = Config::from_toml_str(toml).unwrap();
+        let tools = &config.default_profile().unwrap().tools;
+        assert!(tools.allows("read"));
+        assert!(!tools.allows("shell"));
+        assert!(!tools.allows("edit"));
+
+        let message = tools.denial_message("edit", "review");
+        assert!(message.contains("not available in profile 'review'"));
+        assert!(message.contains("Available tools: read, grep."));
+
+        assert!(ToolsConfig::default().allows("edit"));
+    }
+
+    #[test]
+    fn test_config_memory_enabled() {
+        let toml = r#"
+default_profile = "default"
+
+[profiles.default]
+name = "default"
+working_root = "/workspace"
+
+[profiles.default.provider]
+provider = "glm"
+api_key = "test-api-key"
+model = "glm-4.7"
+"#;
+
+        let config = Config::from_toml_str(toml).unwrap();
+        assert!(config.default_profile().unwrap().memory.enabled);
+
+        let disabled = format!("{}\n[profiles.default.memory]\nenabled = false\n", toml);
+        let config = Config::from_toml_str(&disabled).unwrap();
+        let memory = &config.default_profile().unwrap().memory;
+        assert!(!memory.enabled);
+        assert_eq!(memory.vector_fallback_threshold, -3.0);
+    }
+
+    #[test]
+    fn test_approval_config_default() {
+        let config = ApprovalConfig::default();
+        assert_eq!(config.timeout(), None);
+        assert_eq!(config.on_timeout, ApprovalTimeoutAction::Reject);
+
+        let zero = ApprovalConfig { timeout_secs: Some(0), ..Default::default() };
+        assert_eq!(zero.timeout(), None);
+    }
+
     #[test]
     fn test_config_validation_missing_default_profile() {
         let toml = r#"
@@ -1328,6 +2261,355 @@ model = "gemini-2.5-flash"
         assert_eq!(err.to_string(), "TOML parse error: parse error");
     }
 
+    #[test]
+    fn test_config_env_var_substitution() {
+        unsafe { std::env::set_var("THUNDERUS_TEST_SUBST_KEY", "secret-from-env") };
+        let toml = r#"
+default_profile = "default"
+
+[profiles.default]
+name = "default"
+working_root = "/workspace"
+approval_mode = "auto"
+sandbox_mode = "policy"
+
+[profiles.default.provider]
+provider = "glm"
+api_key = "${THUNDERUS_TEST_SUBST_KEY}"
+model = "glm-4.7"
+"#;
+
+        let config = Config::from_toml_str(toml).unwrap();
+        match &config.default_profile().unwrap().provider {
+            ProviderConfig::Glm { api_key, .. } => assert_eq!(api_key, "secret-from-env"),
+            other => panic!("unexpected provider: {:?}", other),
+        }
+
+        let unresolved = Config::from_toml_str_unresolved(toml).unwrap();
+        match &unresolved.default_profile().unwrap().provider {
+            ProviderConfig::Glm { api_key, .. } => assert_eq!(api_key, "${THUNDERUS_TEST_SUBST_KEY}"),
+            other => panic!("unexpected provider: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_config_env_var_missing() {
+        let toml = r#"
+default_profile = "default"
+
+[profiles.default]
+name = "default"
+working_root = "/workspace"
+approval_mode = "auto"
+sandbox_mode = "policy"
+
+[profiles.default.provider]
+provider = "glm"
+api_key = "${THUNDERUS_TEST_UNSET_VAR}"
+model = "glm-4.7"
+"#;
+
+        let err = Config::from_toml_str(toml).unwrap_err().to_string();
+        assert!(err.contains("THUNDERUS_TEST_UNSET_VAR"));
+        assert!(err.contains("profiles.default.provider.api_key"));
+    }
+
+    #[test]
+    fn test_expand_env_vars() {
+        unsafe { std::env::set_var("THUNDERUS_TEST_EXPAND_HOST", "api.example.com") };
+
+        assert_eq!(expand_env_vars("plain", "f").unwrap(), "plain");
+        assert_eq!(
+            expand_env_vars("https://${THUNDERUS_TEST_EXPAND_HOST}/v1", "f").unwrap(),
+            "https://api.example.com/v1"
+        );
+        assert_eq!(expand_env_vars("$${LITERAL}", "f").unwrap(), "${LITERAL}");
+        assert!(matches!(
+            expand_env_vars("${UNCLOSED", "f"),
+            Err(ConfigError::InvalidEnvVarReference { .. })
+        ));
+        assert!(matches!(
+            expand_env_vars("${BAD-NAME}", "f"),
+            Err(ConfigError::InvalidEnvVarReference { .. })
+        ));
+    }
+
+    const INHERITANCE_TOML: &str = r#"
+default_profile = "work"
+
+[profiles.base]
+name = "base"
+abstract = true
+working_root = "/workspace"
+approval_mode = "auto"
+sandbox_mode = "policy"
+
+[profiles.base.provider]
+provider = "glm"
+api_key = "base-key"
+model = "glm-4.7"
+base_url = "https://glm.example.com"
+
+[profiles.base.memory]
+enable_vector_search = true
+
+[profiles.work]
+name = "work"
+extends = "base"
+approval_mode = "read-only"
+
+[profiles.work.provider]
+provider = "glm"
+api_key = "work-key"
+
+[profiles.personal]
+name = "personal"
+extends = "base"
+
+[profiles.personal.provider]
+provider = "gemini"
+api_key = "personal-key"
+model = "gemini-2.5-flash"
+"#;
+
+    #[test]
+    fn test_profile_inheritance_merges_parent() {
+        let config = Config::from_toml_str(INHERITANCE_TOML).unwrap();
+
+        let work = config.profile("work").unwrap();
+        assert_eq!(work.name, "work");
+        assert_eq!(work.working_root, PathBuf::from("/workspace"));
+        assert_eq!(work.approval_mode, ApprovalMode::ReadOnly);
+        assert!(work.memory.enable_vector_search);
+        assert!(!work.is_abstract);
+        match &work.provider {
+            ProviderConfig::Glm { api_key, model, base_url, .. } => {
+                assert_eq!(api_key, "work-key");
+                assert_eq!(model, "glm-4.7");
+                assert_eq!(base_url, "https://glm.example.com");
+            }
+            other => panic!("unexpected provider: {:?}", other),
+        }
+
+        let personal = config.profile("personal").unwrap();
+        assert_eq!(personal.approval_mode, ApprovalMode::Auto);
+        match &personal.provider {
+            ProviderConfig::Gemini { api_key, base_url, .. } => {
+                assert_eq!(api_key, "personal-key");
+                assert_eq!(base_url, &default_gemini_base_url());
+            }
+            other => panic!("unexpected provider: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_profile_inheritance_hides_abstract() {
+        let config = Config::from_toml_str(INHERITANCE_TOML).unwrap();
+
+        let names = config.profile_names();
+        assert_eq!(names.len(), 2);
+        assert!(!names.contains(&"base".to_string()));
+        assert!(config.profile("base").is_err());
+    }
+
+    #[test]
+    fn test_abstract_profile_not_validated() {
+        let toml = r#"
+default_profile = "work"
+
+[profiles.base]
+name = "base"
+abstract = true
+approval_mode = "read-only"
+
+[profiles.work]
+name = "work"
+extends = "base"
+working_root = "/workspace"
+
+[profiles.work.provider]
+provider = "mock"
+"#;
+
+        let config = Config::from_toml_str(toml).unwrap();
+        assert_eq!(config.profile("work").unwrap().approval_mode, ApprovalMode::ReadOnly);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_update_profile_file_writes_only_changed_keys() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("config.toml");
+        std::fs::write(&path, INHERITANCE_TOML).unwrap();
+
+        Config::update_profile_file(&path, "work", &[("options.theme", "light".into())]).unwrap();
+
+        let raw: toml::Table = toml::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        let work = raw["profiles"]["work"].as_table().unwrap();
+        assert_eq!(work["options"]["theme"].as_str(), Some("light"));
+        assert!(!work.contains_key("working_root"));
+        assert!(work["provider"].get("model").is_none());
+        assert!(raw["profiles"].get("base").is_some());
+
+        let edited = std::fs::read_to_string(&path)
+            .unwrap()
+            .replace("https://glm.example.com", "https://glm2.example.com");
+        std::fs::write(&path, edited).unwrap();
+        let config = Config::from_file(&path).unwrap();
+        match &config.profile("work").unwrap().provider {
+            ProviderConfig::Glm { base_url, .. } => assert_eq!(base_url, "https://glm2.example.com"),
+            other => panic!("unexpected provider: {:?}", other),
+        }
+
+        assert!(Config::update_profile_file(&path, "missing", &[("approval_mode", "auto".into())]).is_err());
+        assert!(Config::update_profile_file(&path, "work", &[("approval_mode", "bogus".into())]).is_err());
+    }
+
+    #[test]
+    fn test_update_profile_file_keeps_comments() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("config.toml");
+        let commented = INHERITANCE_TOML
+            .replace("[profiles.work]\n", "# Day-to-day profile\n[profiles.work]\n")
+            .replace(
+                "approval_mode = \"read-only\"\n",
+                "approval_mode = \"read-only\" # keep it safe\n",
+            );
+        std::fs::write(&path, &commented).unwrap();
+
+        Config::update_profile_file(&path, "work", &[("approval_mode", "auto".into())]).unwrap();
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert!(written.contains("# Day-to-day profile\n[profiles.work]\n"));
+        assert!(written.contains("approval_mode = \"auto\" # keep it safe\n"));
+        assert_eq!(written, commented.replace("\"read-only\" #", "\"auto\" #"));
+    }
+
+    #[test]
+    fn test_profile_inheritance_cycle() {
+        let toml = r#"
+default_profile = "a"
+
+[profiles.a]
+name = "a"
+extends = "b"
+
+[profiles.b]
+name = "b"
+extends = "a"
+"#;
+
+        let err = Config::from_toml_str(toml).unwrap_err().to_string();
+        assert!(err.contains("inheritance cycle"), "{}", err);
+    }
+
+    #[test]
+    fn test_profile_inheritance_unknown_parent() {
+        let toml = r#"
+default_profile = "a"
+
+[profiles.a]
+name = "a"
+extends = "missing"
+"#;
+
+        let err = Config::from_toml_str(toml).unwrap_err().to_string();
+        assert!(err.contains("profile not found: missing"), "{}", err);
+    }
+
+    #[test]
+    fn test_config_checked_reports_all_errors() {
+        let toml = r#"
+default_profile = "missing"
+
+[profiles.alpha]
+name = "alpha"
+working_root = "relative/path"
+approval_mode = "sometimes"
+
+[profiles.alpha.provider]
+provider = "openai"
+api_key = "key"
+model = "gpt"
+
+[profiles.beta]
+name = "beta"
+working_root = "/workspace"
+sandbox_mode = "bogus"
+
+[profiles.beta.provider]
+provider = "glm"
+model = "glm-4.7"
+
+[profiles.gamma]
+name = "gamma"
+working_root = "/workspace"
+
+[profiles.gamma.provider]
+provider = "gemini"
+api_key = ""
+model = "gemini-2.5-flash"
+
+[profiles.gamma.context]
+include = ["docs/[unclosed"]
+"#;
+
+        let errors = Config::from_toml_str_checked(toml).unwrap_err();
+        let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+
+        assert!(
+            messages.iter().any(|m| m == "profile not found: missing"),
+            "{:?}",
+            messages
+        );
+        assert!(messages.iter().any(|m| m.contains("invalid approval mode: sometimes")));
+        assert!(
+            messages
+                .iter()
+                .any(|m| m.contains("unknown provider 'openai' in profile 'alpha'"))
+        );
+        assert!(messages.iter().any(|m| m.contains("invalid sandbox mode: bogus")));
+        assert!(messages.iter().any(|m| m == "missing api_key for profile 'beta'"));
+        assert!(messages.iter().any(|m| m == "missing api_key for profile 'gamma'"));
+        assert!(messages.iter().any(|m| m.contains("invalid glob 'docs/[unclosed'")));
+        assert!(messages.iter().any(|m| m.contains("working_root for profile 'alpha'")));
+        assert_eq!(errors.len(), 8, "{:?}", messages);
+    }
+
+    #[test]
+    fn test_provider_kind_matches_serde_tag() {
+        let configs = [
+            create_test_profile_with_workspace("/workspace").provider,
+            ProviderConfig::Gemini {
+                api_key: "key".to_string(),
+                model: "gemini-2.5-flash".to_string(),
+                base_url: default_gemini_base_url(),
+                thinking: Default::default(),
+                options: Default::default(),
+            },
+            ProviderConfig::Mock { responses_file: None },
+        ];
+
+        for config in &configs {
+            let value = toml::Value::try_from(config).unwrap();
+            assert_eq!(value["provider"].as_str(), Some(config.kind().as_str()));
+        }
+        assert_eq!(configs.len(), ProviderKind::VALUES.len());
+    }
+
+    #[test]
+    fn test_config_validate_collects_profile_errors() {
+        let mut config = Config::default();
+        let mut profile = create_test_profile_with_workspace("/workspace");
+        profile.working_root = PathBuf::from("relative");
+        profile.extra_writable_roots = vec![PathBuf::from("also-relative")];
+        config.profiles.insert("default".to_string(), profile);
+
+        let errors = config.validate().unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().all(|e| matches!(e, ConfigError::AbsolutePathRequired(_))));
+    }
+
     #[test]
     fn test_sensitive_dirs() {
         assert!(SENSITIVE_DIRS.contains(&"~/.ssh"));
@@ -1475,6 +2757,7 @@ model = "gemini-2.5-flash"
             extra_writable_roots: vec![],
             workspace,
             approval_mode: ApprovalMode::Auto,
+            approval: ApprovalConfig::default(),
             sandbox_mode: SandboxMode::Policy,
             provider: ProviderConfig::Glm {
                 api_key: "test-key".to_string(),
@@ -1487,7 +2770,15 @@ model = "gemini-2.5-flash"
             network: NetworkConfig::default(),
             memory: MemoryConfig::default(),
             skills: SkillsConfig::default(),
+            context: ContextConfig::default(),
+            redaction: RedactionConfig::default(),
+            tools: ToolsConfig::default(),
+            rollover: RolloverConfig::default(),
+            extends: None,
+            is_abstract: false,
             logging: LoggingConfig::default(),
+            system_prompt_append: None,
+            system_prompt_override: None,
             options: HashMap::new(),
         }
     }
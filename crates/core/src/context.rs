@@ -4,9 +4,9 @@
 //! from the repository root and current working directory.
 
 use crate::error::Result;
-use crate::session::Session;
+use crate::session::{Event, Session};
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -23,6 +23,12 @@ pub const CONTEXT_FILES: &[(&str, u8)] = &[
 /// User-specific context files (overlay, gitignored)
 pub const LOCAL_CONTEXT_PATTERN: &str = "*.local.md";
 
+/// Priority assigned to the first configured include glob
+///
+/// Later globs get successively lower priority, so include order decides
+/// which files are trimmed first when the token budget is exceeded.
+pub const INCLUDE_PRIORITY_BASE: u8 = 10;
+
 /// A loaded context file with metadata
 #[derive(Debug, Clone)]
 pub struct LoadedContext {
@@ -48,6 +54,11 @@ impl LoadedContext {
         Ok(Self { source, path, content, content_hash, priority })
     }
 
+    /// Approximate token count of the content
+    pub fn token_count(&self) -> usize {
+        self.content.len() / 4
+    }
+
     /// Compute a simple hash of the content for deduplication
     fn compute_hash(content: &str) -> String {
         use std::hash::{Hash, Hasher};
@@ -66,13 +77,36 @@ pub struct ContextLoader {
     cwd: PathBuf,
     /// Git repository root (if found)
     git_root: Option<PathBuf>,
+    /// Additional context file globs, relative to the repository root
+    include: Vec<String>,
+    /// Maximum total tokens across all context files
+    max_tokens: Option<usize>,
+    /// Warnings produced by the last load (e.g. trimmed files)
+    warnings: Vec<String>,
 }
 
 impl ContextLoader {
     /// Create a new context loader
     pub fn new(cwd: PathBuf) -> Self {
         let git_root = Self::find_git_root(&cwd);
-        Self { loaded: HashMap::new(), cwd, git_root }
+        Self { loaded: HashMap::new(), cwd, git_root, include: Vec::new(), max_tokens: None, warnings: Vec::new() }
+    }
+
+    /// Load additional context files matching these globs
+    pub fn with_include(mut self, patterns: Vec<String>) -> Self {
+        self.include = patterns;
+        self
+    }
+
+    /// Cap the total tokens across all loaded context files
+    pub fn with_max_tokens(mut self, max_tokens: Option<usize>) -> Self {
+        self.max_tokens = max_tokens;
+        self
+    }
+
+    /// Warnings produced by the last load
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
     }
 
     /// Scan for and load all context files
@@ -81,8 +115,12 @@ impl ContextLoader {
     /// 1. Repository root
     /// 2. Current working directory
     /// 3. Parent directories (up to git root)
+    /// 4. Configured include globs
+    ///
+    /// Files are trimmed lowest-priority first if the token budget is exceeded.
     pub fn load_all(&mut self) -> Vec<LoadedContext> {
         self.loaded.clear();
+        self.warnings.clear();
 
         if let Some(root) = self.git_root.clone() {
             self.scan_directory(&root);
@@ -90,6 +128,8 @@ impl ContextLoader {
 
         self.scan_directory(&self.cwd.clone());
         self.scan_local_files();
+        self.scan_includes();
+        self.enforce_token_budget();
         let mut contexts: Vec<LoadedContext> = self.loaded.values().cloned().collect();
         contexts.sort_by_key(|c| c.priority);
         contexts
@@ -129,11 +169,31 @@ impl ContextLoader {
     }
 
     /// Load all contexts and append events to session
+    ///
+    /// Content already loaded in the session (the same file reached through a
+    /// symlink, or context events from before a resume) is skipped by content
+    /// hash. Returns the number of events appended.
     pub fn append_to_session(&mut self, session: &mut Session) -> Result<usize> {
         let contexts = self.load_all();
+        let mut seen: HashSet<String> = session
+            .read_chain_events()?
+            .into_iter()
+            .filter_map(|logged| match logged.event {
+                Event::ContextLoad { content_hash, .. } => Some(content_hash),
+                _ => None,
+            })
+            .collect();
         let mut count = 0;
 
         for ctx in contexts {
+            if !seen.insert(ctx.content_hash.clone()) {
+                tracing::info!(
+                    "Skipping context file '{}' ({}): content already loaded in this session",
+                    ctx.source,
+                    ctx.path.display()
+                );
+                continue;
+            }
             session.append_context_load(&ctx.source, ctx.path.to_string_lossy(), &ctx.content_hash)?;
             count += 1;
         }
@@ -181,6 +241,104 @@ impl ContextLoader {
         }
     }
 
+    /// Scan configured include globs relative to the repository root
+    ///
+    /// Matches that resolve outside the root, such as symlinks pointing
+    /// elsewhere, are skipped with a warning.
+    fn scan_includes(&mut self) {
+        let base = self.git_root.clone().unwrap_or_else(|| self.cwd.clone());
+        let Ok(canonical_base) = base.canonicalize() else {
+            return;
+        };
+
+        let mut globs = Vec::new();
+        for (idx, pattern) in self.include.clone().iter().enumerate() {
+            match glob::Pattern::new(pattern.trim_start_matches("./")) {
+                Ok(glob) => globs.push((idx, glob)),
+                Err(e) => self.warn(format!("Invalid context include glob '{}': {}", pattern, e)),
+            }
+        }
+        if globs.is_empty() {
+            return;
+        }
+        let options = glob::MatchOptions { require_literal_separator: true, ..Default::default() };
+
+        // One walk for all patterns; a file takes the priority of the first pattern it matches
+        let mut matches: Vec<(usize, PathBuf)> = crate::workspace_walker(&base)
+            .hidden(false)
+            .filter_entry(|entry| entry.file_name() != ".git")
+            .build()
+            .flatten()
+            .map(|entry| entry.into_path())
+            .filter(|path| path.is_file())
+            .filter_map(|path| {
+                let relative = path.strip_prefix(&base).ok()?;
+                let (idx, _) = globs
+                    .iter()
+                    .find(|(_, glob)| glob.matches_path_with(relative, options))?;
+                Some((*idx, path))
+            })
+            .collect();
+        matches.sort();
+
+        for (idx, path) in matches {
+            let priority = u8::try_from(idx)
+                .map_or(u8::MAX, |idx| INCLUDE_PRIORITY_BASE.saturating_add(idx))
+                .min(u8::MAX - 1);
+            let source = path.strip_prefix(&base).unwrap_or(&path).to_string_lossy().to_string();
+            if self.loaded.contains_key(&source) {
+                continue;
+            }
+            if !path.canonicalize().is_ok_and(|real| real.starts_with(&canonical_base)) {
+                self.warn(format!(
+                    "Skipping context include '{}': it resolves outside the workspace",
+                    source
+                ));
+                continue;
+            }
+
+            if let Ok(mut ctx) = LoadedContext::from_path(path, priority) {
+                ctx.source = source.clone();
+                self.loaded.insert(source, ctx);
+            }
+        }
+    }
+
+    /// Trim lowest-priority context files until the total fits the token budget
+    fn enforce_token_budget(&mut self) {
+        let Some(max_tokens) = self.max_tokens else { return };
+
+        let mut total: usize = self.loaded.values().map(|c| c.token_count()).sum();
+        if total <= max_tokens {
+            return;
+        }
+
+        let mut by_priority: Vec<(u8, String, usize)> = self
+            .loaded
+            .iter()
+            .map(|(key, ctx)| (ctx.priority, key.clone(), ctx.token_count()))
+            .collect();
+        by_priority.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| b.1.cmp(&a.1)));
+
+        for (_, key, tokens) in by_priority {
+            if total <= max_tokens {
+                break;
+            }
+            self.loaded.remove(&key);
+            total -= tokens;
+            self.warn(format!(
+                "Context file '{}' ({} tokens) trimmed: context budget of {} tokens exceeded",
+                key, tokens, max_tokens
+            ));
+        }
+    }
+
+    /// Record a non-fatal loading warning
+    fn warn(&mut self, message: String) {
+        tracing::warn!("{}", message);
+        self.warnings.push(message);
+    }
+
     /// Find the git repository root by traversing upward
     fn find_git_root(start: &Path) -> Option<PathBuf> {
         let mut current = start.canonicalize().ok()?;
@@ -384,6 +542,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_context_loader_append_skips_duplicate_content() {
+        let temp = TempDir::new().unwrap();
+        let agent_dir = AgentDir::new(temp.path());
+        fs::write(temp.path().join("CLAUDE.md"), "# Shared Context").unwrap();
+        fs::write(temp.path().join("AGENTS.md"), "# Shared Context").unwrap();
+
+        let mut session = Session::new(agent_dir).unwrap();
+        let mut loader = ContextLoader::new(temp.path().to_path_buf());
+
+        assert_eq!(loader.append_to_session(&mut session).unwrap(), 1);
+        let events = session.read_events().unwrap();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            &events[0].event,
+            session::Event::ContextLoad { source, .. } if source == "CLAUDE.md"
+        ));
+
+        assert_eq!(loader.append_to_session(&mut session).unwrap(), 0);
+        assert_eq!(session.read_events().unwrap().len(), 1);
+    }
+
     #[test]
     fn test_context_loader_no_duplicate_sources() {
         let temp = TempDir::new().unwrap();
@@ -401,6 +581,127 @@ mod tests {
         assert_eq!(loader.loaded.keys().filter(|k| k.contains("CLAUDE")).count(), 1);
     }
 
+    #[test]
+    fn test_context_loader_include_globs() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir(temp.path().join(".git")).unwrap();
+        fs::create_dir(temp.path().join("docs")).unwrap();
+        fs::write(temp.path().join("docs/conventions.md"), "# Conventions").unwrap();
+        fs::write(temp.path().join("docs/notes.txt"), "ignored").unwrap();
+
+        let mut loader = ContextLoader::new(temp.path().to_path_buf()).with_include(vec!["docs/*.md".to_string()]);
+        let contexts = loader.load_all();
+
+        assert_eq!(contexts.len(), 1);
+        let conventions = loader.get("docs/conventions.md").unwrap();
+        assert_eq!(conventions.priority, INCLUDE_PRIORITY_BASE);
+        assert!(conventions.content.contains("Conventions"));
+    }
+
+    #[test]
+    fn test_context_loader_include_first_matching_glob_sets_priority() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir(temp.path().join("docs")).unwrap();
+        fs::write(temp.path().join("docs/conventions.md"), "# Conventions").unwrap();
+        fs::write(temp.path().join("docs/notes.txt"), "Notes").unwrap();
+
+        let mut loader = ContextLoader::new(temp.path().to_path_buf())
+            .with_include(vec!["docs/*.md".to_string(), "docs/*".to_string()]);
+        loader.load_all();
+
+        assert_eq!(
+            loader.get("docs/conventions.md").unwrap().priority,
+            INCLUDE_PRIORITY_BASE
+        );
+        assert_eq!(
+            loader.get("docs/notes.txt").unwrap().priority,
+            INCLUDE_PRIORITY_BASE + 1
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_context_loader_include_skips_symlinks_outside_workspace() {
+        let outside = TempDir::new().unwrap();
+        fs::write(outside.path().join("secret.md"), "# Secret").unwrap();
+        let temp = TempDir::new().unwrap();
+        fs::create_dir(temp.path().join("docs")).unwrap();
+        fs::write(temp.path().join("docs/guide.md"), "# Guide").unwrap();
+        std::os::unix::fs::symlink(outside.path().join("secret.md"), temp.path().join("docs/secret.md")).unwrap();
+
+        let mut loader = ContextLoader::new(temp.path().to_path_buf()).with_include(vec!["docs/*.md".to_string()]);
+        loader.load_all();
+
+        assert!(loader.get("docs/guide.md").is_some());
+        assert!(loader.get("docs/secret.md").is_none());
+        assert!(loader.warnings().iter().any(|w| w.contains("docs/secret.md")));
+    }
+
+    #[test]
+    fn test_context_loader_include_skips_thunderusignore() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("vendor/lib")).unwrap();
+        fs::create_dir(temp.path().join("docs")).unwrap();
+        fs::write(temp.path().join("vendor/lib/README.md"), "# Vendored").unwrap();
+        fs::write(temp.path().join("docs/guide.md"), "# Guide").unwrap();
+        fs::write(temp.path().join(crate::IGNORE_FILE_NAME), "vendor/\n").unwrap();
+
+        let mut loader = ContextLoader::new(temp.path().to_path_buf()).with_include(vec!["**/*.md".to_string()]);
+        loader.load_all();
+
+        assert!(loader.get("docs/guide.md").is_some());
+        assert!(loader.get("vendor/lib/README.md").is_none());
+    }
+
+    #[test]
+    fn test_context_loader_include_logs_context_load() {
+        let temp = TempDir::new().unwrap();
+        let agent_dir = AgentDir::new(temp.path());
+        fs::write(temp.path().join("conventions.md"), "# Conventions").unwrap();
+
+        let mut session = Session::new(agent_dir).unwrap();
+        let mut loader = ContextLoader::new(temp.path().to_path_buf()).with_include(vec!["conventions.md".to_string()]);
+
+        assert_eq!(loader.append_to_session(&mut session).unwrap(), 1);
+
+        let events = session.read_events().unwrap();
+        assert!(matches!(
+            &events[0].event,
+            session::Event::ContextLoad { source, .. } if source == "conventions.md"
+        ));
+    }
+
+    #[test]
+    fn test_context_loader_token_budget_trims_lowest_priority() {
+        let temp = TempDir::new().unwrap();
+        fs::write(temp.path().join("CLAUDE.md"), "a".repeat(400)).unwrap();
+        fs::write(temp.path().join("first.md"), "b".repeat(400)).unwrap();
+        fs::write(temp.path().join("second.md"), "c".repeat(400)).unwrap();
+
+        let mut loader = ContextLoader::new(temp.path().to_path_buf())
+            .with_include(vec!["first.md".to_string(), "second.md".to_string()])
+            .with_max_tokens(Some(250));
+        let contexts = loader.load_all();
+
+        assert_eq!(contexts.len(), 2);
+        assert!(loader.get("CLAUDE.md").is_some());
+        assert!(loader.get("first.md").is_some());
+        assert!(loader.get("second.md").is_none());
+        assert_eq!(loader.warnings().len(), 1);
+        assert!(loader.warnings()[0].contains("second.md"));
+    }
+
+    #[test]
+    fn test_context_loader_invalid_include_glob_warns() {
+        let temp = TempDir::new().unwrap();
+
+        let mut loader = ContextLoader::new(temp.path().to_path_buf()).with_include(vec!["docs/[".to_string()]);
+        let contexts = loader.load_all();
+
+        assert!(contexts.is_empty());
+        assert_eq!(loader.warnings().len(), 1);
+    }
+
     #[test]
     fn test_context_files_constant() {
         assert_eq!(CONTEXT_FILES[0].0, "CLAUDE.md");
@@ -4,30 +4,60 @@ use notify::{Event, RecursiveMode, Watcher};
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, mpsc};
+use std::time::Duration;
 use tokio::sync::broadcast;
 
 #[derive(Debug, Clone)]
 pub enum DriftEvent {
-    FileSystemChange(Vec<PathBuf>),
-    StateMismatch { expected: String, actual: String },
+    /// Files changed on disk, with line counts for those that differ from HEAD
+    FileSystemChange {
+        paths: Vec<PathBuf>,
+        diffs: Vec<GitDiff>,
+    },
+    StateMismatch {
+        expected: String,
+        actual: String,
+    },
 }
 
+/// Per-file diff stat
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitDiff {
+    /// File path, relative to the repository root
+    pub path: String,
+    /// Number of lines added
+    pub added: usize,
+    /// Number of lines deleted
+    pub deleted: usize,
+}
+
+/// How long the workspace must be quiet before a burst of changes is reported
+pub const DRIFT_DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// Watches the workspace and reports file changes as [`DriftEvent`]s
+///
+/// Filesystem events are batched until the workspace has been quiet for
+/// [`DRIFT_DEBOUNCE`], so a save that fires several events, or a tool that
+/// touches many files, produces one event. Diff stats are measured against
+/// the baseline snapshot set with [`Self::set_baseline`], falling back to HEAD.
 pub struct DriftMonitor {
     _watcher: Box<dyn Watcher + Send + Sync>,
     event_tx: broadcast::Sender<DriftEvent>,
+    baseline: Arc<Mutex<Option<String>>>,
 }
 
 impl DriftMonitor {
     pub fn new<P: AsRef<Path>>(path: P) -> Result<Self> {
         let (event_tx, _) = broadcast::channel(16);
-        let tx = event_tx.clone();
+        let (raw_tx, raw_rx) = mpsc::channel::<Vec<PathBuf>>();
+        let baseline = Arc::new(Mutex::new(None));
 
         let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
             if let Ok(event) = res
                 && (event.kind.is_modify() || event.kind.is_create() || event.kind.is_remove())
             {
-                let paths = event.paths;
-                let _ = tx.send(DriftEvent::FileSystemChange(paths));
+                let _ = raw_tx.send(event.paths);
             }
         })
         .map_err(|e| Error::Watcher(e.to_string()))?;
@@ -36,14 +66,56 @@ impl DriftMonitor {
             .watch(path.as_ref(), RecursiveMode::Recursive)
             .map_err(|e| Error::Watcher(e.to_string()))?;
 
-        Ok(Self { _watcher: Box::new(watcher), event_tx })
+        let snapshots = SnapshotManager::new(path.as_ref());
+        let tx = event_tx.clone();
+        let thread_baseline = Arc::clone(&baseline);
+        std::thread::spawn(move || {
+            while let Some(paths) = debounced_paths(&raw_rx, DRIFT_DEBOUNCE) {
+                let base = thread_baseline.lock().ok().and_then(|base| base.clone());
+                let diffs = snapshots.diff_stats(&paths, base.as_deref()).unwrap_or_default();
+                let _ = tx.send(DriftEvent::FileSystemChange { paths, diffs });
+            }
+        });
+
+        Ok(Self { _watcher: Box::new(watcher), event_tx, baseline })
     }
 
     pub fn subscribe(&self) -> broadcast::Receiver<DriftEvent> {
         self.event_tx.subscribe()
     }
+
+    /// Measure later diff stats against `snapshot`, a commit from [`SnapshotManager::snapshot_git`]
+    ///
+    /// `None` measures against HEAD.
+    pub fn set_baseline(&self, snapshot: Option<String>) {
+        if let Ok(mut baseline) = self.baseline.lock() {
+            *baseline = snapshot;
+        }
+    }
+}
+
+/// Wait for a burst of changed paths and return them once `quiet` passes with no more
+///
+/// Paths are deduplicated in the order first seen. Returns `None` once the
+/// watcher is gone.
+fn debounced_paths(rx: &mpsc::Receiver<Vec<PathBuf>>, quiet: Duration) -> Option<Vec<PathBuf>> {
+    let mut paths = rx.recv().ok()?;
+    loop {
+        match rx.recv_timeout(quiet) {
+            Ok(more) => paths.extend(more),
+            Err(mpsc::RecvTimeoutError::Timeout) => break,
+            Err(mpsc::RecvTimeoutError::Disconnected) => return None,
+        }
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    paths.retain(|path| seen.insert(path.clone()));
+    Some(paths)
 }
 
+/// Ref namespace holding git snapshots, so they survive garbage collection
+const SNAPSHOT_REF_PREFIX: &str = "refs/thunderus/snapshots/";
+
 #[derive(Clone)]
 pub struct SnapshotManager {
     repo_path: PathBuf,
@@ -55,7 +127,7 @@ impl SnapshotManager {
     }
 
     pub fn get_current_state(&self) -> Result<String> {
-        let repo = git2::Repository::discover(&self.repo_path).map_err(|e| Error::Git(e.to_string()))?;
+        let repo = snapshot_repo(&self.repo_path)?;
 
         let mut opts = git2::StatusOptions::new();
         opts.include_untracked(true).recurse_untracked_dirs(true);
@@ -86,6 +158,184 @@ impl SnapshotManager {
             Ok(format!("dirty-{}-{:x}", head_target, hash))
         }
     }
+
+    /// Capture the working tree, including untracked files, as a git commit
+    ///
+    /// The commit is parented on HEAD and kept under `refs/thunderus/snapshots/`;
+    /// the working tree and index are left untouched. Returns the commit id,
+    /// which is what checkpoint events store as their `snapshot_id`.
+    pub fn snapshot_git(&self) -> Result<String> {
+        let repo = snapshot_repo(&self.repo_path)?;
+
+        let mut index = repo.index().map_err(|e| Error::Git(e.to_string()))?;
+        index
+            .add_all(["*"], git2::IndexAddOption::DEFAULT, None)
+            .map_err(|e| Error::Git(e.to_string()))?;
+        let tree_id = index.write_tree().map_err(|e| Error::Git(e.to_string()))?;
+        let tree = repo.find_tree(tree_id).map_err(|e| Error::Git(e.to_string()))?;
+
+        let head = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+        let parents: Vec<&git2::Commit> = head.iter().collect();
+        let sig = snapshot_signature(&repo)?;
+        let commit_id = repo
+            .commit(None, &sig, &sig, "thunderus snapshot", &tree, &parents)
+            .map_err(|e| Error::Git(e.to_string()))?;
+
+        repo.reference(
+            &format!("{}{}", SNAPSHOT_REF_PREFIX, commit_id),
+            commit_id,
+            true,
+            "thunderus snapshot",
+        )
+        .map_err(|e| Error::Git(e.to_string()))?;
+
+        Ok(commit_id.to_string())
+    }
+
+    /// Reset the working tree to a snapshot taken by [`Self::snapshot_git`]
+    ///
+    /// Uncommitted changes are stashed first rather than discarded, and the
+    /// index is reset to HEAD so nothing ends up staged. Returns the id of the
+    /// stash holding the previous changes, if there were any.
+    pub fn restore_git(&self, snapshot: &str) -> Result<Option<String>> {
+        let mut repo = snapshot_repo(&self.repo_path)?;
+
+        let commit_id = git2::Oid::from_str(snapshot).map_err(|e| Error::Git(e.to_string()))?;
+        let snapshot_tree = repo
+            .find_commit(commit_id)
+            .and_then(|commit| commit.tree())
+            .map_err(|e| Error::Git(format!("Snapshot {} not found: {}", snapshot, e)))?
+            .id();
+
+        let mut opts = git2::StatusOptions::new();
+        opts.include_untracked(true).recurse_untracked_dirs(true);
+        let dirty = !repo
+            .statuses(Some(&mut opts))
+            .map_err(|e| Error::Git(e.to_string()))?
+            .is_empty();
+
+        let stash = if dirty {
+            let sig = snapshot_signature(&repo)?;
+            let message = format!("thunderus: before restoring snapshot {}", snapshot);
+            let stash_id = repo
+                .stash_save(&sig, &message, Some(git2::StashFlags::INCLUDE_UNTRACKED))
+                .map_err(|e| Error::Git(e.to_string()))?;
+            Some(stash_id.to_string())
+        } else {
+            None
+        };
+
+        let tree = repo.find_tree(snapshot_tree).map_err(|e| Error::Git(e.to_string()))?;
+        repo.checkout_tree(
+            tree.as_object(),
+            Some(git2::build::CheckoutBuilder::new().force().remove_untracked(true)),
+        )
+        .map_err(|e| Error::Git(e.to_string()))?;
+
+        let head = repo
+            .head()
+            .and_then(|head| head.peel(git2::ObjectType::Commit))
+            .map_err(|e| Error::Git(e.to_string()))?;
+        repo.reset(&head, git2::ResetType::Mixed, None)
+            .map_err(|e| Error::Git(e.to_string()))?;
+
+        Ok(stash)
+    }
+
+    /// Line counts of working tree changes for the given paths
+    ///
+    /// Changes are measured against `base`, a commit such as one from
+    /// [`Self::snapshot_git`], or against HEAD when `base` is `None`. Paths
+    /// outside the repository and unchanged files are skipped.
+    pub fn diff_stats(&self, paths: &[PathBuf], base: Option<&str>) -> Result<Vec<GitDiff>> {
+        let repo = git2::Repository::discover(&self.repo_path).map_err(|e| Error::Git(e.to_string()))?;
+        let workdir = repo
+            .workdir()
+            .ok_or_else(|| Error::Git("repository has no working directory".to_string()))?
+            .to_path_buf();
+        let canonical_workdir = workdir.canonicalize().unwrap_or_else(|_| workdir.clone());
+
+        let relative: Vec<PathBuf> = paths
+            .iter()
+            .filter_map(|path| {
+                if path.is_relative() {
+                    return Some(path.clone());
+                }
+                path.strip_prefix(&workdir)
+                    .or_else(|_| path.strip_prefix(&canonical_workdir))
+                    .map(Path::to_path_buf)
+                    .ok()
+            })
+            .filter(|path| !path.starts_with(".git"))
+            .collect();
+        if relative.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut opts = git2::DiffOptions::new();
+        opts.include_untracked(true)
+            .recurse_untracked_dirs(true)
+            .show_untracked_content(true)
+            .disable_pathspec_match(true);
+        for path in &relative {
+            opts.pathspec(path);
+        }
+
+        // Snapshots hold untracked files the index knows nothing about, so they
+        // are compared with the working tree directly
+        let diff = match base {
+            Some(base) => {
+                let commit_id = git2::Oid::from_str(base).map_err(|e| Error::Git(e.to_string()))?;
+                let tree = repo
+                    .find_commit(commit_id)
+                    .and_then(|commit| commit.tree())
+                    .map_err(|e| Error::Git(format!("Snapshot {} not found: {}", base, e)))?;
+                repo.diff_tree_to_workdir(Some(&tree), Some(&mut opts))
+            }
+            None => {
+                let head_tree = repo.head().ok().and_then(|head| head.peel_to_tree().ok());
+                repo.diff_tree_to_workdir_with_index(head_tree.as_ref(), Some(&mut opts))
+            }
+        }
+        .map_err(|e| Error::Git(e.to_string()))?;
+
+        let mut stats = Vec::new();
+        for index in 0..diff.deltas().len() {
+            let Some(patch) = git2::Patch::from_diff(&diff, index).map_err(|e| Error::Git(e.to_string()))? else {
+                continue;
+            };
+            let (_, added, deleted) = patch.line_stats().map_err(|e| Error::Git(e.to_string()))?;
+            let delta = patch.delta();
+            let Some(path) = delta.new_file().path().or_else(|| delta.old_file().path()) else {
+                continue;
+            };
+            stats.push(GitDiff { path: path.to_string_lossy().to_string(), added, deleted });
+        }
+        Ok(stats)
+    }
+}
+
+/// Open the repository for snapshotting, with the agent's own directories ignored
+///
+/// Session logs and memory live in the workspace but are not gitignored, so
+/// without these rules every session write would read as drift and a restore
+/// would stash or roll back the running session. The rules only apply to this handle and are never written to the repository.
+fn snapshot_repo(path: &Path) -> Result<git2::Repository> {
+    let repo = git2::Repository::discover(path).map_err(|e| Error::Git(e.to_string()))?;
+    repo.add_ignore_rule(&format!(
+        "{}/\n{}/\n",
+        crate::layout::AGENT_DIR_NAME,
+        crate::THUNDERUS_DIR_NAME
+    ))
+    .map_err(|e| Error::Git(e.to_string()))?;
+    Ok(repo)
+}
+
+/// Committer identity for snapshots, falling back when git has no user configured
+fn snapshot_signature(repo: &git2::Repository) -> Result<git2::Signature<'static>> {
+    repo.signature()
+        .or_else(|_| git2::Signature::now("thunderus", "thunderus@localhost"))
+        .map_err(|e| Error::Git(e.to_string()))
 }
 
 #[cfg(test)]
@@ -129,4 +379,132 @@ mod tests {
         let dirty_state_2 = manager.get_current_state().unwrap();
         assert_eq!(dirty_state, dirty_state_2);
     }
+
+    #[test]
+    fn test_diff_stats_counts_changed_lines() {
+        let temp = TempDir::new().unwrap();
+        let repo = git2::Repository::init(temp.path()).unwrap();
+        std::fs::write(temp.path().join("config.rs"), "a\nb\nc\n").unwrap();
+        std::fs::write(temp.path().join("other.rs"), "x\n").unwrap();
+
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("config.rs")).unwrap();
+        index.add_path(Path::new("other.rs")).unwrap();
+        index.write().unwrap();
+        let oid = index.write_tree().unwrap();
+        let sig = git2::Signature::now("test", "test@example.com").unwrap();
+        let tree = repo.find_tree(oid).unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "Initial", &tree, &[]).unwrap();
+
+        std::fs::write(temp.path().join("config.rs"), "a\nB\nc\nd\ne\n").unwrap();
+        std::fs::write(temp.path().join("other.rs"), "y\n").unwrap();
+        std::fs::write(temp.path().join("new.rs"), "1\n2\n").unwrap();
+
+        let manager = SnapshotManager::new(temp.path());
+        let stats = manager
+            .diff_stats(&[temp.path().join("config.rs"), PathBuf::from("new.rs")], None)
+            .unwrap();
+
+        assert_eq!(
+            stats,
+            vec![
+                GitDiff { path: "config.rs".to_string(), added: 3, deleted: 1 },
+                GitDiff { path: "new.rs".to_string(), added: 2, deleted: 0 },
+            ]
+        );
+        assert!(
+            manager
+                .diff_stats(&[PathBuf::from("/elsewhere/file.rs")], None)
+                .unwrap()
+                .is_empty()
+        );
+
+        let snapshot = manager.snapshot_git().unwrap();
+        std::fs::write(temp.path().join("config.rs"), "a\nB\nc\nd\n").unwrap();
+        let stats = manager
+            .diff_stats(
+                &[temp.path().join("config.rs"), PathBuf::from("new.rs")],
+                Some(&snapshot),
+            )
+            .unwrap();
+        assert_eq!(
+            stats,
+            vec![GitDiff { path: "config.rs".to_string(), added: 0, deleted: 1 }]
+        );
+    }
+
+    #[test]
+    fn test_debounced_paths_batches_a_burst() {
+        let (tx, rx) = mpsc::channel();
+        tx.send(vec![PathBuf::from("a.rs")]).unwrap();
+        tx.send(vec![PathBuf::from("b.rs"), PathBuf::from("a.rs")]).unwrap();
+
+        let paths = debounced_paths(&rx, Duration::from_millis(20)).unwrap();
+        assert_eq!(paths, vec![PathBuf::from("a.rs"), PathBuf::from("b.rs")]);
+
+        tx.send(vec![PathBuf::from("c.rs")]).unwrap();
+        drop(tx);
+        assert!(debounced_paths(&rx, Duration::from_millis(20)).is_none());
+    }
+
+    #[test]
+    fn test_snapshot_git_and_restore() {
+        let temp = TempDir::new().unwrap();
+        let repo = git2::Repository::init(temp.path()).unwrap();
+        std::fs::write(temp.path().join("tracked.txt"), "initial\n").unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("tracked.txt")).unwrap();
+        index.write().unwrap();
+        let oid = index.write_tree().unwrap();
+        let sig = git2::Signature::now("test", "test@example.com").unwrap();
+        let tree = repo.find_tree(oid).unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "Initial", &tree, &[]).unwrap();
+
+        std::fs::write(temp.path().join("tracked.txt"), "agent edit\n").unwrap();
+        std::fs::write(temp.path().join("created.txt"), "new file\n").unwrap();
+
+        let manager = SnapshotManager::new(temp.path());
+        let state_before = manager.get_current_state().unwrap();
+        let snapshot = manager.snapshot_git().unwrap();
+        assert_eq!(manager.get_current_state().unwrap(), state_before);
+        assert!(
+            repo.find_reference(&format!("{}{}", SNAPSHOT_REF_PREFIX, snapshot))
+                .is_ok()
+        );
+
+        std::fs::write(temp.path().join("tracked.txt"), "user edit\n").unwrap();
+        std::fs::remove_file(temp.path().join("created.txt")).unwrap();
+        std::fs::write(temp.path().join("scratch.txt"), "scratch\n").unwrap();
+        std::fs::create_dir(temp.path().join(crate::layout::AGENT_DIR_NAME)).unwrap();
+        let session_log = temp.path().join(crate::layout::AGENT_DIR_NAME).join("events.jsonl");
+        std::fs::write(&session_log, "{}\n").unwrap();
+
+        let stash = manager.restore_git(&snapshot).unwrap();
+        assert!(stash.is_some());
+        assert_eq!(std::fs::read_to_string(&session_log).unwrap(), "{}\n");
+
+        let read = |name: &str| std::fs::read_to_string(temp.path().join(name)).ok();
+        assert_eq!(read("tracked.txt").as_deref(), Some("agent edit\n"));
+        assert_eq!(read("created.txt").as_deref(), Some("new file\n"));
+        assert!(read("scratch.txt").is_none());
+        assert_eq!(manager.get_current_state().unwrap(), state_before);
+
+        let mut repo = git2::Repository::open(temp.path()).unwrap();
+        let mut stashes = Vec::new();
+        repo.stash_foreach(|_, message, _| {
+            stashes.push(message.to_string());
+            true
+        })
+        .unwrap();
+        assert_eq!(stashes.len(), 1);
+        assert!(stashes[0].contains(&snapshot));
+    }
+
+    #[test]
+    fn test_restore_git_rejects_unknown_snapshot() {
+        let temp = TempDir::new().unwrap();
+        git2::Repository::init(temp.path()).unwrap();
+        let manager = SnapshotManager::new(temp.path());
+        assert!(manager.restore_git("not-a-commit").is_err());
+    }
 }
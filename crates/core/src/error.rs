@@ -1,4 +1,5 @@
 use crate::layout::SessionIdError;
+use crate::memory::ValidationError;
 
 use std::path::PathBuf;
 use thiserror::Error;
@@ -41,6 +42,10 @@ pub enum Error {
     #[error("validation error: {0}")]
     Validation(String),
 
+    /// Memory document frontmatter failed schema validation
+    #[error("invalid frontmatter: {0}")]
+    Frontmatter(#[from] ValidationError),
+
     /// Generic errors
     #[error("{0}")]
     Other(String),
@@ -0,0 +1,301 @@
+//! Image format and dimension detection
+//!
+//! Reads just enough of an image header to name its format and size, so a
+//! file read can report "[image: 800x600 PNG]" instead of raw bytes. The note
+//! written into tool results can be parsed back with [`ImageInfo::parse_note`],
+//! which is how the transcript finds images to draw inline.
+
+use std::fmt;
+use std::io::Read;
+use std::path::Path;
+
+/// Bytes read from the start of a file when looking for an image header
+///
+/// JPEG dimensions live in the SOF segment, which can sit after a large EXIF
+/// block, so this is generous.
+pub const IMAGE_HEADER_BYTES: usize = 256 * 1024;
+
+/// Bytes needed to recognize any supported format by its magic number
+pub const IMAGE_MAGIC_BYTES: usize = 12;
+
+/// Image formats that can be detected from their header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Png,
+    Jpeg,
+    Gif,
+    Webp,
+    Bmp,
+}
+
+impl ImageFormat {
+    /// Short uppercase name, as shown in image notes
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Png => "PNG",
+            Self::Jpeg => "JPEG",
+            Self::Gif => "GIF",
+            Self::Webp => "WEBP",
+            Self::Bmp => "BMP",
+        }
+    }
+
+    /// Identify a format from its magic bytes alone
+    ///
+    /// Needs at most [`IMAGE_MAGIC_BYTES`] bytes; says nothing about whether
+    /// the header goes on to carry dimensions.
+    pub fn sniff(bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+            Some(Self::Png)
+        } else if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            Some(Self::Jpeg)
+        } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+            Some(Self::Gif)
+        } else if bytes.len() >= 12 && bytes.starts_with(b"RIFF") && &bytes[8..12] == b"WEBP" {
+            Some(Self::Webp)
+        } else if bytes.starts_with(b"BM") {
+            Some(Self::Bmp)
+        } else {
+            None
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "PNG" => Some(Self::Png),
+            "JPEG" => Some(Self::Jpeg),
+            "GIF" => Some(Self::Gif),
+            "WEBP" => Some(Self::Webp),
+            "BMP" => Some(Self::Bmp),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ImageFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Format and pixel dimensions of an image
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageInfo {
+    pub format: ImageFormat,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl ImageInfo {
+    /// Detect an image from the first bytes of a file
+    ///
+    /// Returns `None` for anything that isn't a recognized image or whose
+    /// header is cut short before the dimensions.
+    pub fn detect(bytes: &[u8]) -> Option<Self> {
+        let format = ImageFormat::sniff(bytes)?;
+        let (width, height) = match format {
+            ImageFormat::Png => png_size(bytes)?,
+            ImageFormat::Jpeg => jpeg_size(bytes)?,
+            ImageFormat::Gif => gif_size(bytes)?,
+            ImageFormat::Webp => webp_size(bytes)?,
+            ImageFormat::Bmp => bmp_size(bytes)?,
+        };
+        Some(Self { format, width, height })
+    }
+
+    /// Detect an image from the header of a file on disk
+    ///
+    /// Only files whose first bytes carry an image magic number are read
+    /// further, up to [`IMAGE_HEADER_BYTES`].
+    pub fn from_path(path: &Path) -> std::io::Result<Option<Self>> {
+        let mut file = std::fs::File::open(path)?;
+        let mut header = Vec::new();
+        (&mut file).take(IMAGE_MAGIC_BYTES as u64).read_to_end(&mut header)?;
+        if ImageFormat::sniff(&header).is_none() {
+            return Ok(None);
+        }
+        file.take((IMAGE_HEADER_BYTES - header.len()) as u64)
+            .read_to_end(&mut header)?;
+        Ok(Self::detect(&header))
+    }
+
+    /// Textual stand-in for the image, e.g. `[image: 800x600 PNG]`
+    pub fn note(&self) -> String {
+        format!("[image: {}x{} {}]", self.width, self.height, self.format)
+    }
+
+    /// Find an image note written by [`ImageInfo::note`] in a block of text
+    pub fn parse_note(text: &str) -> Option<Self> {
+        let start = text.find("[image: ")? + "[image: ".len();
+        let end = start + text[start..].find(']')?;
+        let (size, format) = text[start..end].split_once(' ')?;
+        let (width, height) = size.split_once('x')?;
+        Some(Self { format: ImageFormat::from_name(format)?, width: width.parse().ok()?, height: height.parse().ok()? })
+    }
+}
+
+fn be_u16(bytes: &[u8], at: usize) -> Option<u32> {
+    Some(u16::from_be_bytes(bytes.get(at..at + 2)?.try_into().ok()?) as u32)
+}
+
+fn le_u16(bytes: &[u8], at: usize) -> Option<u32> {
+    Some(u16::from_le_bytes(bytes.get(at..at + 2)?.try_into().ok()?) as u32)
+}
+
+fn le_u24(bytes: &[u8], at: usize) -> Option<u32> {
+    let b = bytes.get(at..at + 3)?;
+    Some(b[0] as u32 | (b[1] as u32) << 8 | (b[2] as u32) << 16)
+}
+
+fn png_size(bytes: &[u8]) -> Option<(u32, u32)> {
+    if bytes.get(12..16)? != b"IHDR" {
+        return None;
+    }
+    let width = u32::from_be_bytes(bytes.get(16..20)?.try_into().ok()?);
+    let height = u32::from_be_bytes(bytes.get(20..24)?.try_into().ok()?);
+    Some((width, height))
+}
+
+fn gif_size(bytes: &[u8]) -> Option<(u32, u32)> {
+    Some((le_u16(bytes, 6)?, le_u16(bytes, 8)?))
+}
+
+fn bmp_size(bytes: &[u8]) -> Option<(u32, u32)> {
+    let dib_header_len = u32::from_le_bytes(bytes.get(14..18)?.try_into().ok()?);
+    if !matches!(dib_header_len, 40 | 52 | 56 | 108 | 124) {
+        return None;
+    }
+    let width = i32::from_le_bytes(bytes.get(18..22)?.try_into().ok()?);
+    let height = i32::from_le_bytes(bytes.get(22..26)?.try_into().ok()?);
+    Some((width.unsigned_abs(), height.unsigned_abs()))
+}
+
+/// Walk JPEG segments to the first start-of-frame marker
+fn jpeg_size(bytes: &[u8]) -> Option<(u32, u32)> {
+    let mut at = 2;
+    loop {
+        while *bytes.get(at)? == 0xFF && *bytes.get(at + 1)? == 0xFF {
+            at += 1;
+        }
+        if *bytes.get(at)? != 0xFF {
+            return None;
+        }
+        let marker = *bytes.get(at + 1)?;
+        let is_frame = matches!(marker, 0xC0..=0xCF) && !matches!(marker, 0xC4 | 0xC8 | 0xCC);
+        if is_frame {
+            return Some((be_u16(bytes, at + 7)?, be_u16(bytes, at + 5)?));
+        }
+        at += 2 + be_u16(bytes, at + 2)? as usize;
+    }
+}
+
+fn webp_size(bytes: &[u8]) -> Option<(u32, u32)> {
+    match bytes.get(12..16)? {
+        b"VP8X" => Some((le_u24(bytes, 24)? + 1, le_u24(bytes, 27)? + 1)),
+        b"VP8 " => Some((le_u16(bytes, 26)? & 0x3FFF, le_u16(bytes, 28)? & 0x3FFF)),
+        b"VP8L" => {
+            let b = bytes.get(21..25)?;
+            let width = 1 + (((b[1] as u32 & 0x3F) << 8) | b[0] as u32);
+            let height = 1 + (((b[3] as u32 & 0x0F) << 10) | (b[2] as u32) << 2 | (b[1] as u32 & 0xC0) >> 6);
+            Some((width, height))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Smallest PNG header that carries dimensions
+    fn png_header(width: u32, height: u32) -> Vec<u8> {
+        let mut bytes = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+        bytes.extend_from_slice(&width.to_be_bytes());
+        bytes.extend_from_slice(&height.to_be_bytes());
+        bytes.extend_from_slice(&[8, 6, 0, 0, 0]);
+        bytes
+    }
+
+    #[test]
+    fn test_sniff_needs_only_magic_bytes() {
+        assert_eq!(ImageFormat::sniff(b"\x89PNG\r\n\x1a\n"), Some(ImageFormat::Png));
+        assert_eq!(ImageFormat::sniff(b"RIFF\0\0\0\0WEBP"), Some(ImageFormat::Webp));
+        assert_eq!(ImageFormat::sniff(b"fn main() {}"), None);
+        assert_eq!(ImageInfo::detect(b"\x89PNG\r\n\x1a\n"), None);
+    }
+
+    #[test]
+    fn test_detect_png_gif_bmp() {
+        assert_eq!(
+            ImageInfo::detect(&png_header(800, 600)),
+            Some(ImageInfo { format: ImageFormat::Png, width: 800, height: 600 })
+        );
+
+        let gif = [b"GIF89a".as_slice(), &[0x40, 0x01, 0xF0, 0x00]].concat();
+        assert_eq!(
+            ImageInfo::detect(&gif),
+            Some(ImageInfo { format: ImageFormat::Gif, width: 320, height: 240 })
+        );
+
+        let mut bmp = vec![0u8; 26];
+        bmp[..2].copy_from_slice(b"BM");
+        bmp[14..18].copy_from_slice(&40u32.to_le_bytes());
+        bmp[18..22].copy_from_slice(&64i32.to_le_bytes());
+        bmp[22..26].copy_from_slice(&(-32i32).to_le_bytes());
+        assert_eq!(
+            ImageInfo::detect(&bmp),
+            Some(ImageInfo { format: ImageFormat::Bmp, width: 64, height: 32 })
+        );
+    }
+
+    #[test]
+    fn test_detect_jpeg_skips_segments() {
+        let mut jpeg = vec![0xFF, 0xD8];
+        jpeg.extend_from_slice(&[0xFF, 0xE0, 0x00, 0x04, 0x00, 0x00]);
+        jpeg.extend_from_slice(&[0xFF, 0xC4, 0x00, 0x03, 0x00]);
+        jpeg.extend_from_slice(&[0xFF, 0xC0, 0x00, 0x11, 0x08, 0x02, 0x58, 0x03, 0x20]);
+        jpeg.splice(2..2, [0xFF]);
+
+        let info = ImageInfo::detect(&jpeg).unwrap();
+        assert_eq!((info.format, info.width, info.height), (ImageFormat::Jpeg, 800, 600));
+    }
+
+    #[test]
+    fn test_detect_webp_variants() {
+        let riff = |chunk: &[u8], payload: &[u8]| {
+            let mut bytes = b"RIFF\0\0\0\0WEBP".to_vec();
+            bytes.extend_from_slice(chunk);
+            bytes.extend_from_slice(payload);
+            bytes
+        };
+
+        let vp8x = riff(b"VP8X", &[0, 0, 0, 0, 0, 0, 0, 0, 0x1F, 0x03, 0x00, 0x57, 0x02, 0x00]);
+        assert_eq!(ImageInfo::detect(&vp8x).map(|i| (i.width, i.height)), Some((800, 600)));
+
+        let vp8 = riff(b"VP8 ", &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x20, 0x03, 0x58, 0x02]);
+        assert_eq!(ImageInfo::detect(&vp8).map(|i| (i.width, i.height)), Some((800, 600)));
+
+        let vp8l = riff(b"VP8L", &[0, 0, 0, 0, 0x2F, 0x1F, 0xC3, 0x95, 0x00]);
+        assert_eq!(ImageInfo::detect(&vp8l).map(|i| (i.width, i.height)), Some((800, 600)));
+    }
+
+    #[test]
+    fn test_detect_rejects_text_and_truncated_headers() {
+        assert_eq!(ImageInfo::detect(b"fn main() {}"), None);
+        assert_eq!(ImageInfo::detect(b"BMW service history, 2019 through 2024"), None);
+        assert_eq!(ImageInfo::detect(&png_header(1, 1)[..18]), None);
+        assert_eq!(ImageInfo::detect(&[0xFF, 0xD8, 0xFF, 0xE0]), None);
+    }
+
+    #[test]
+    fn test_note_round_trip() {
+        let info = ImageInfo { format: ImageFormat::Jpeg, width: 1920, height: 1080 };
+        assert_eq!(info.note(), "[image: 1920x1080 JPEG]");
+        assert_eq!(
+            ImageInfo::parse_note(&format!("read a.jpg\n\n{}", info.note())),
+            Some(info)
+        );
+        assert_eq!(ImageInfo::parse_note("[image: big TIFF]"), None);
+        assert_eq!(ImageInfo::parse_note("no images here"), None);
+    }
+}
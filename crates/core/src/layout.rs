@@ -20,12 +20,21 @@ pub const METADATA_FILE: &str = "metadata.json";
 /// Subdirectory for patches within a session
 pub const PATCHES_DIR: &str = "patches";
 
+/// Length of the `YYYY-MM-DDTHH-MM-SSZ` timestamp that starts a session ID
+const SESSION_TIMESTAMP_LEN: usize = 20;
+
+/// Example session ID shown in parse errors
+const SESSION_ID_EXAMPLE: &str = "2026-01-23T22-30-00Z";
+
 /// Pattern for patch files
 pub const PATCH_FILE_PATTERN: &str = "*.patch";
 
 /// Subdirectory for materialized Markdown views
 pub const VIEWS_DIR: &str = "views";
 
+/// Subdirectory for file backups taken before bulk edits
+pub const BACKUPS_DIR: &str = "backups";
+
 /// Materialized view filenames
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ViewFile {
@@ -87,11 +96,51 @@ impl SessionId {
         Ok(Self(ts))
     }
 
+    /// Parse a session ID typed by a user, e.g. from a command-line flag
+    ///
+    /// Stricter than [`SessionId::from_timestamp`]: the ID must start with a
+    /// `YYYY-MM-DDTHH-MM-SSZ` timestamp. Colons are accepted in place of the
+    /// dashes in the time, so an RFC 3339 timestamp copied from a log works.
+    /// Use [`AgentDir::resolve_session`] to also check the session exists.
+    pub fn parse(input: &str) -> Result<Self, SessionIdError> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Err(SessionIdError::Empty);
+        }
+
+        if let Some((position, character)) = input
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !(c.is_ascii_alphanumeric() || *c == '-' || *c == ':'))
+        {
+            return Err(SessionIdError::InvalidCharacter { id: input.to_string(), character, position });
+        }
+
+        let id = Self(input.replace(':', "-"));
+        if id.0.len() < SESSION_TIMESTAMP_LEN {
+            return Err(SessionIdError::TooShort { id: input.to_string() });
+        }
+        if id.started_at().is_none() {
+            return Err(SessionIdError::InvalidFormat);
+        }
+        Ok(id)
+    }
+
     /// Get the timestamp string
     pub fn as_str(&self) -> &str {
         &self.0
     }
 
+    /// When the session was started, parsed from the timestamp prefix
+    ///
+    /// Returns `None` for IDs that don't start with a `YYYY-MM-DDTHH-MM-SSZ` timestamp.
+    pub fn started_at(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        let timestamp = self.0.get(..SESSION_TIMESTAMP_LEN)?;
+        chrono::NaiveDateTime::parse_from_str(timestamp, "%Y-%m-%dT%H-%M-%SZ")
+            .ok()
+            .map(|naive| naive.and_utc())
+    }
+
     /// Validate timestamp format
     fn validate_timestamp(ts: &str) -> Result<(), SessionIdError> {
         if ts.is_empty() {
@@ -136,6 +185,14 @@ impl TryFrom<&str> for SessionId {
     }
 }
 
+impl std::str::FromStr for SessionId {
+    type Err = SessionIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
 /// Errors that can occur when creating or parsing SessionId
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SessionIdError {
@@ -143,6 +200,16 @@ pub enum SessionIdError {
     Empty,
     /// Timestamp format is invalid
     InvalidFormat,
+    /// ID is shorter than the timestamp it must start with
+    TooShort { id: String },
+    /// ID contains a character that can't appear in a session directory name
+    InvalidCharacter {
+        id: String,
+        character: char,
+        position: usize,
+    },
+    /// ID is well-formed but no session with it exists
+    NotFound { id: String, sessions_dir: PathBuf },
 }
 
 impl fmt::Display for SessionIdError {
@@ -150,6 +217,19 @@ impl fmt::Display for SessionIdError {
         match self {
             SessionIdError::Empty => write!(f, "SessionId timestamp cannot be empty"),
             SessionIdError::InvalidFormat => write!(f, "SessionId has invalid timestamp format"),
+            SessionIdError::TooShort { id } => write!(
+                f,
+                "session ID '{}' is too short; expected a timestamp like {}",
+                id, SESSION_ID_EXAMPLE
+            ),
+            SessionIdError::InvalidCharacter { id, character, position } => write!(
+                f,
+                "session ID '{}' has invalid character '{}' at position {}; only letters, digits, '-' and ':' are allowed",
+                id, character, position
+            ),
+            SessionIdError::NotFound { id, sessions_dir } => {
+                write!(f, "no session '{}' found in {}", id, sessions_dir.display())
+            }
         }
     }
 }
@@ -194,6 +274,11 @@ impl AgentDir {
         self.agent_dir().join(VIEWS_DIR)
     }
 
+    /// Get the backups directory path (`.agent/backups/`)
+    pub fn backups_dir(&self) -> PathBuf {
+        self.agent_dir().join(BACKUPS_DIR)
+    }
+
     /// Get path to a session directory (`.agent/sessions/<timestamp>/`)
     pub fn session_dir(&self, session_id: &SessionId) -> PathBuf {
         self.sessions_dir().join(session_id.as_str())
@@ -242,7 +327,9 @@ impl AgentDir {
 
     /// List all available sessions, sorted by timestamp (newest first)
     ///
-    /// Returns SessionId for each valid session directory that contains an events.jsonl file
+    /// Returns SessionId for each valid session directory that contains an events.jsonl file.
+    /// Segments that continue another session after a log rollover are not
+    /// listed; their events belong to the session the chain started from.
     pub fn list_sessions(&self) -> Vec<SessionId> {
         let sessions_dir = self.sessions_dir();
 
@@ -259,10 +346,7 @@ impl AgentDir {
                 let session_id_str = entry.file_name().to_string_lossy().to_string();
                 SessionId::from_timestamp(session_id_str).ok()
             })
-            .filter(|session_id| {
-                let events_file = self.events_file(session_id);
-                events_file.exists()
-            })
+            .filter(|session_id| self.session_exists(session_id) && !self.is_continuation(session_id))
             .collect();
 
         sessions.sort();
@@ -270,6 +354,28 @@ impl AgentDir {
         sessions
     }
 
+    /// Check whether a session with an events log exists
+    pub fn session_exists(&self, session_id: &SessionId) -> bool {
+        self.events_file(session_id).exists()
+    }
+
+    /// Check whether a session directory is a rollover segment continuing another session
+    fn is_continuation(&self, session_id: &SessionId) -> bool {
+        fs::read_to_string(self.metadata_file(session_id))
+            .ok()
+            .and_then(|json| serde_json::from_str::<crate::session::SessionMetadata>(&json).ok())
+            .is_some_and(|metadata| metadata.continues_from.is_some())
+    }
+
+    /// Parse a user-supplied session ID and check that the session exists
+    pub fn resolve_session(&self, input: &str) -> Result<SessionId, SessionIdError> {
+        let id = SessionId::parse(input)?;
+        if !self.session_exists(&id) {
+            return Err(SessionIdError::NotFound { id: id.to_string(), sessions_dir: self.sessions_dir() });
+        }
+        Ok(id)
+    }
+
     /// Get the most recent session (if any)
     pub fn latest_session(&self) -> Option<SessionId> {
         self.list_sessions().into_iter().next()
@@ -321,6 +427,59 @@ mod tests {
         assert_eq!(id.as_str(), "2025-01-11T14-30-45Z");
     }
 
+    #[test]
+    fn test_session_id_parse() {
+        let id = SessionId::parse(" 2025-01-11T14:30:45Z ").unwrap();
+        assert_eq!(id.as_str(), "2025-01-11T14-30-45Z");
+        assert_eq!(
+            "2025-01-11T14-30-45Z-1".parse::<SessionId>().unwrap().as_str(),
+            "2025-01-11T14-30-45Z-1"
+        );
+
+        assert_eq!(SessionId::parse(""), Err(SessionIdError::Empty));
+        let err = SessionId::parse("2025-01-11").unwrap_err();
+        assert!(matches!(err, SessionIdError::TooShort { .. }));
+        assert!(err.to_string().contains("too short"));
+
+        let err = SessionId::parse("2025-01-11T14/30-45Z").unwrap_err();
+        assert_eq!(
+            err,
+            SessionIdError::InvalidCharacter { id: "2025-01-11T14/30-45Z".to_string(), character: '/', position: 13 }
+        );
+        assert_eq!(
+            SessionId::parse("yesterday-afternoon-session"),
+            Err(SessionIdError::InvalidFormat)
+        );
+    }
+
+    #[test]
+    fn test_resolve_session() {
+        let temp = TempDir::new().unwrap();
+        let agent_dir = AgentDir::new(temp.path());
+        let id = SessionId::from_timestamp("2025-01-11T14-30-45Z").unwrap();
+        assert!(!agent_dir.session_exists(&id));
+
+        let err = agent_dir.resolve_session("2025-01-11T14:30:45Z").unwrap_err();
+        assert!(matches!(err, SessionIdError::NotFound { .. }));
+        assert!(err.to_string().contains("no session '2025-01-11T14-30-45Z'"));
+
+        fs::create_dir_all(agent_dir.session_dir(&id)).unwrap();
+        fs::write(agent_dir.events_file(&id), "").unwrap();
+        assert!(agent_dir.session_exists(&id));
+        assert_eq!(agent_dir.resolve_session("2025-01-11T14:30:45Z").unwrap(), id);
+    }
+
+    #[test]
+    fn test_session_id_started_at() {
+        let expected = chrono::DateTime::parse_from_rfc3339("2025-01-11T14:30:45Z").unwrap();
+        let id = SessionId::from_timestamp("2025-01-11T14-30-45Z").unwrap();
+        assert_eq!(id.started_at(), Some(expected.to_utc()));
+
+        let rolled = SessionId::from_timestamp("2025-01-11T14-30-45Z-1").unwrap();
+        assert_eq!(rolled.started_at(), Some(expected.to_utc()));
+        assert!(SessionId::from_timestamp("custom").unwrap().started_at().is_none());
+    }
+
     #[test]
     fn test_session_id_from_invalid_timestamp_empty() {
         let result = SessionId::from_timestamp("");
@@ -361,6 +520,7 @@ mod tests {
         assert_eq!(agent.agent_dir(), temp.path().join(".agent"));
         assert_eq!(agent.sessions_dir(), temp.path().join(".agent/sessions"));
         assert_eq!(agent.views_dir(), temp.path().join(".agent/views"));
+        assert_eq!(agent.backups_dir(), temp.path().join(".agent/backups"));
     }
 
     #[test]
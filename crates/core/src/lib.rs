@@ -4,47 +4,61 @@ pub mod config;
 pub mod context;
 pub mod drift;
 pub mod error;
+pub mod image;
 pub mod layout;
 pub mod logging;
 pub mod memory;
+pub mod mentions;
+pub mod network;
 pub mod patch;
 pub mod patch_queue_manager;
 pub mod provenance;
+pub mod redaction;
 pub mod search;
 pub mod session;
 pub mod task_context;
 pub mod teaching;
+pub mod trace;
 pub mod trajectory;
 pub mod views;
+pub mod walk;
 
 pub use logging::{LogFormat, PrivacyConfig, ToolOutputLogging, init_logging, redact_sensitive, sanitize_path};
+pub use trace::{SessionTrace, TRACE_FORMAT_VERSION};
 pub use trajectory::{TrajectoryNode, TrajectoryWalker};
 
 pub use approval::{
     ActionType, ApprovalContext, ApprovalDecision, ApprovalGate, ApprovalId, ApprovalProtocol, ApprovalRecord,
-    ApprovalRequest, ApprovalResponse, ApprovalStats, AutoApprove, AutoReject,
+    ApprovalRequest, ApprovalResponse, ApprovalRule, ApprovalStats, AutoApprove, AutoReject,
 };
-pub use classification::{Classification, ToolRisk};
+pub use classification::{Classification, ClassificationBasis, RiskCategory, ToolRisk};
 pub use config::{
-    ApprovalMode, Config, FileLoggingConfig, LoggingConfig, PrivacyLoggingConfig, Profile, ProviderConfig, SandboxMode,
+    ApprovalConfig, ApprovalMode, ApprovalTimeoutAction, Config, ConfigError, ContextConfig, FileLoggingConfig,
+    LoggingConfig, MemoryConfig, PrivacyLoggingConfig, Profile, ProviderConfig, ProviderKind, RedactionConfig,
+    RolloverConfig, SandboxMode, ToolsConfig,
 };
 pub use context::{CONTEXT_FILES, ContextLoader, LOCAL_CONTEXT_PATTERN, LoadedContext};
-pub use drift::{DriftEvent, DriftMonitor, SnapshotManager};
+pub use drift::{DriftEvent, DriftMonitor, GitDiff, SnapshotManager};
 pub use error::{BlockedCommandError, Error, Result};
+pub use image::{ImageFormat, ImageInfo};
 pub use layout::{AgentDir, SessionId, SessionIdError, ViewFile};
 pub use memory::{
     CORE_MEMORY_DIR, CORE_MEMORY_FILE, CORE_MEMORY_HARD_LIMIT, CORE_MEMORY_SOFT_LIMIT, CoreMemory, CoreMemoryLint,
-    CoreMemorySource, DECISIONS_DIR, EPISODIC_MEMORY_DIR, FACTS_DIR, INDEXES_DIR, LintSeverity, MANIFEST_FILE,
-    MEMORY_DIR, MEMORY_VERSION, ManifestEntry, ManifestStats, MemoryDoc, MemoryFrontmatter, MemoryKind, MemoryManifest,
-    MemoryPaths, PLAYBOOKS_DIR, PROCEDURAL_MEMORY_DIR, ProceduralMemory, Provenance, ProvenanceInfo,
+    CoreMemorySource, DECISIONS_DIR, EPISODIC_MEMORY_DIR, EvidenceSource, FACTS_DIR, INDEXES_DIR, LintSeverity,
+    MANIFEST_FILE, MEMORY_DIR, MEMORY_VERSION, ManifestEntry, ManifestStats, MemoryDoc, MemoryFrontmatter, MemoryKind,
+    MemoryManifest, MemoryPaths, PLAYBOOKS_DIR, PROCEDURAL_MEMORY_DIR, ProceduralMemory, Provenance, ProvenanceInfo,
     SEMANTIC_MEMORY_DIR, SemanticMemory, SessionMeta, TAGS_FILE, THUNDERUS_DIR_NAME, Verification, VerificationInfo,
-    VerificationStatus,
+    VerificationState, VerificationStatus,
 };
+pub use mentions::{ExpandedMessage, InlinedFile, MAX_MENTION_BYTES, expand_mentions, extract_mentions};
+pub use network::{extract_network_hosts, host_matches, is_network_command};
 pub use patch::{Hunk, MemoryPatch, MemoryPatchParams, Patch, PatchId, PatchQueue};
 pub use patch_queue_manager::PatchQueueManager;
 pub use provenance::{ProvenanceValidator, ValidationMode};
-pub use search::{SearchHit, SearchScope, search_session};
+pub use redaction::{REDACTION_MASK, Redactor};
+pub use search::{SearchHit, SearchScope, SessionSearchHits, search_all_sessions, search_session};
 pub use session::{Event, LoggedEvent, PatchStatus, Seq, Session, TokensUsed};
 pub use task_context::{TaskContext, TaskContextTracker};
-pub use teaching::{TeachingState, get_hint_for_concept, suggest_concept};
+pub use teaching::{DismissedHints, TeachingState, get_hint_for_concept, suggest_concept};
 pub use views::{MaterializedViews, ViewKind, ViewMaterializer};
+pub use walk::{IGNORE_FILE_NAME, workspace_walker};
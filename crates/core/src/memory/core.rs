@@ -287,6 +287,83 @@ impl CoreMemory {
         lints
     }
 
+    /// Check a write to a core memory source against the token limits
+    ///
+    /// Returns an error lint when the write would grow core memory past
+    /// [`CORE_MEMORY_HARD_LIMIT`]; such writes must be blocked. Crossing
+    /// [`CORE_MEMORY_SOFT_LIMIT`] yields a warning. Writes that do not grow
+    /// memory past a limit it is already over are allowed without a lint.
+    pub fn check_write(&self, path: &Path, content: &str) -> Option<CoreMemoryLint> {
+        let projected = self.projected_tokens(path, content);
+        let grows = projected > self.token_count;
+
+        if projected > CORE_MEMORY_HARD_LIMIT && grows {
+            return Some(CoreMemoryLint {
+                rule: "mem004".to_string(),
+                severity: LintSeverity::Error,
+                message: format!(
+                    "Core memory would grow to {} tokens, over the hard limit of {}. \
+                     Trim at least {} tokens before saving. {}",
+                    projected,
+                    CORE_MEMORY_HARD_LIMIT,
+                    projected - CORE_MEMORY_HARD_LIMIT,
+                    Self::trim_guidance(content)
+                ),
+            });
+        }
+
+        if projected > CORE_MEMORY_SOFT_LIMIT && grows {
+            return Some(CoreMemoryLint {
+                rule: "mem003".to_string(),
+                severity: LintSeverity::Warning,
+                message: format!(
+                    "Core memory is {} tokens, over the soft limit of {}. {}",
+                    projected,
+                    CORE_MEMORY_SOFT_LIMIT,
+                    Self::trim_guidance(content)
+                ),
+            });
+        }
+
+        None
+    }
+
+    /// Estimate the merged token count after writing `content` to `path`
+    pub fn projected_tokens(&self, path: &Path, content: &str) -> usize {
+        let old_len = Self::load_source(path)
+            .map(|old| Self::wrap_source(path, &old).len())
+            .unwrap_or(0);
+        let new_len = Self::wrap_source(path, content).len();
+        Self::estimate_tokens(&self.content).saturating_sub(old_len / 4) + new_len / 4
+    }
+
+    /// Point at the largest `##` sections as candidates for trimming
+    fn trim_guidance(content: &str) -> String {
+        let mut sections: Vec<(&str, usize)> = Vec::new();
+        for line in content.lines() {
+            if let Some(heading) = line.strip_prefix("## ") {
+                sections.push((heading.trim(), 0));
+            } else if let Some((_, len)) = sections.last_mut() {
+                *len += line.len() + 1;
+            }
+        }
+        sections.sort_by_key(|&(_, len)| std::cmp::Reverse(len));
+
+        let largest: Vec<String> = sections
+            .iter()
+            .take(3)
+            .filter(|(_, len)| *len > 0)
+            .map(|(heading, len)| format!("{} (~{} tokens)", heading, len / 4))
+            .collect();
+
+        let advice = "Move details into fact or playbook documents and keep core memory to essentials.";
+        if largest.is_empty() {
+            advice.to_string()
+        } else {
+            format!("Largest sections: {}. {}", largest.join(", "), advice)
+        }
+    }
+
     /// Get errors only (excluding warnings and info)
     pub fn errors(&self) -> Vec<CoreMemoryLint> {
         self.validate()
@@ -405,6 +482,46 @@ Test content"#;
         assert!(!core.is_over_hard_limit());
     }
 
+    #[test]
+    fn test_check_write_limits() {
+        let temp = create_test_core_memory("# Project\n\n## Identity\nSmall");
+        let paths = MemoryPaths::from_thunderus_root(temp.path());
+        let core_file = paths.core_memory_file();
+        let core = CoreMemory::load(&paths, temp.path()).unwrap();
+
+        assert!(
+            core.check_write(&core_file, "# Project\n\n## Identity\nStill small")
+                .is_none()
+        );
+
+        let soft = format!("## Identity\nShort\n\n## Commands\n{}", "a".repeat(20000));
+        let lint = core.check_write(&core_file, &soft).unwrap();
+        assert_eq!(lint.severity, LintSeverity::Warning);
+        assert_eq!(lint.rule, "mem003");
+        assert!(lint.message.contains("Largest sections: Commands"));
+
+        let hard = format!("## Commands\n{}", "a".repeat(40000));
+        let lint = core.check_write(&core_file, &hard).unwrap();
+        assert_eq!(lint.severity, LintSeverity::Error);
+        assert_eq!(lint.rule, "mem004");
+        assert!(lint.message.contains("Trim at least"));
+    }
+
+    #[test]
+    fn test_check_write_allows_shrinking_oversized_memory() {
+        let temp = create_test_core_memory(&"a".repeat(40000));
+        let paths = MemoryPaths::from_thunderus_root(temp.path());
+        let core = CoreMemory::load(&paths, temp.path()).unwrap();
+        assert!(core.is_over_hard_limit());
+
+        let smaller = "a".repeat(36000);
+        assert!(core.check_write(&paths.core_memory_file(), &smaller).is_none());
+        assert!(
+            core.check_write(&paths.core_memory_file(), &"a".repeat(44000))
+                .is_some()
+        );
+    }
+
     #[test]
     fn test_core_memory_hard_limit() {
         let content = "a".repeat(40000);
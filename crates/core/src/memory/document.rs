@@ -3,7 +3,7 @@
 //! Handles YAML frontmatter parsing and validation for memory documents.
 
 use crate::error::{Error, Result};
-use crate::memory::kinds::{MemoryKind, Provenance, SessionMeta, Verification};
+use crate::memory::kinds::{MemoryKind, Provenance, SessionMeta, Verification, VerificationStatus};
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -44,6 +44,9 @@ pub struct MemoryFrontmatter {
     pub session: Option<SessionMeta>,
 }
 
+/// Frontmatter fields every memory document must set
+const REQUIRED_FIELDS: &[&str] = &["id", "title", "kind", "tags", "created", "updated"];
+
 /// Validation error for a memory document
 #[derive(Debug, Clone)]
 pub struct ValidationError {
@@ -51,15 +54,34 @@ pub struct ValidationError {
     pub field: String,
     /// Error message
     pub message: String,
+    /// 1-based line of the offending frontmatter key, when known
+    pub line: Option<usize>,
 }
 
 impl ValidationError {
     /// Create a new validation error
     pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
-        Self { field: field.into(), message: message.into() }
+        Self { field: field.into(), message: message.into(), line: None }
+    }
+
+    /// Attach the line number of the offending key
+    pub fn at_line(mut self, line: Option<usize>) -> Self {
+        self.line = line;
+        self
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{} (line {}): {}", self.field, line, self.message),
+            None => write!(f, "{}: {}", self.field, self.message),
+        }
     }
 }
 
+impl std::error::Error for ValidationError {}
+
 impl Display for MemoryDoc {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let frontmatter_yaml = serde_yml::to_string(&self.frontmatter).expect("Frontmatter serialization failed");
@@ -84,7 +106,12 @@ impl MemoryDoc {
     ///
     /// # Body content here
     /// ```
+    ///
+    /// Frontmatter fields are checked before deserializing, so a missing field,
+    /// unknown `kind`, bad `verification.status` or non-array `tags` is reported
+    /// as [`Error::Frontmatter`] naming the field and its line.
     pub fn parse(content: &str) -> Result<Self> {
+        let original = content;
         let content = content.trim_start();
 
         if !content.starts_with("---") {
@@ -100,6 +127,11 @@ impl MemoryDoc {
         let body_start = end_idx + 3;
         let body = after_delim[body_start..].trim_start().to_string();
 
+        let value: serde_yml::Value = serde_yml::from_str(frontmatter_str)
+            .map_err(|e| Error::Parse(format!("Invalid YAML frontmatter: {}", e)))?;
+        let delimiter_line = original[..original.len() - content.len()].matches('\n').count() + 1;
+        check_frontmatter(&value, frontmatter_str, delimiter_line)?;
+
         let frontmatter: MemoryFrontmatter = serde_yml::from_str(frontmatter_str)
             .map_err(|e| Error::Parse(format!("Invalid YAML frontmatter: {}", e)))?;
 
@@ -253,6 +285,130 @@ impl From<MemoryDoc> for String {
     }
 }
 
+/// Check frontmatter field shapes before deserializing into [`MemoryFrontmatter`]
+///
+/// `delimiter_line` is the 1-based line of the opening `---`, used to report
+/// the line of the offending key.
+fn check_frontmatter(value: &serde_yml::Value, frontmatter: &str, delimiter_line: usize) -> Result<()> {
+    let line_of =
+        |key: &str, parent: Option<&str>| key_line(frontmatter, key, parent).map(|index| delimiter_line + index);
+
+    if !value.is_mapping() {
+        return Err(ValidationError::new("frontmatter", "Expected a mapping of fields")
+            .at_line(Some(delimiter_line))
+            .into());
+    }
+
+    for field in REQUIRED_FIELDS {
+        if value.get(field).is_none_or(serde_yml::Value::is_null) {
+            return Err(ValidationError::new(*field, "Missing required field").into());
+        }
+    }
+
+    for field in ["id", "title"] {
+        if !value[field].is_string() {
+            return Err(ValidationError::new(field, "Expected a string")
+                .at_line(line_of(field, None))
+                .into());
+        }
+    }
+
+    match value["kind"].as_str() {
+        Some(kind) if MemoryKind::VALUES.iter().any(|k| k.as_str() == kind) => {}
+        other => {
+            let found = other.map_or_else(|| "a non-string value".to_string(), |kind| format!("'{}'", kind));
+            return Err(ValidationError::new(
+                "kind",
+                format!(
+                    "Unknown memory kind {} (expected one of: {})",
+                    found,
+                    MemoryKind::VALUES
+                        .iter()
+                        .map(|k| k.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            )
+            .at_line(line_of("kind", None))
+            .into());
+        }
+    }
+
+    let tags_are_strings = value["tags"]
+        .as_sequence()
+        .is_some_and(|tags| tags.iter().all(serde_yml::Value::is_string));
+    if !tags_are_strings {
+        return Err(ValidationError::new("tags", "Expected an array of strings")
+            .at_line(line_of("tags", None))
+            .into());
+    }
+
+    for field in ["created", "updated"] {
+        if value[field]
+            .as_str()
+            .and_then(|ts| ts.parse::<DateTime<Utc>>().ok())
+            .is_none()
+        {
+            return Err(ValidationError::new(field, "Expected an RFC 3339 timestamp")
+                .at_line(line_of(field, None))
+                .into());
+        }
+    }
+
+    if let Some(verification) = value.get("verification").filter(|v| !v.is_null()) {
+        if !verification.is_mapping() {
+            return Err(ValidationError::new("verification", "Expected a mapping")
+                .at_line(line_of("verification", None))
+                .into());
+        }
+        match verification.get("status").and_then(serde_yml::Value::as_str) {
+            Some(status) if VerificationStatus::VALUES.iter().any(|s| s.as_str() == status) => {}
+            other => {
+                let found = other.map_or_else(|| "missing".to_string(), |status| format!("'{}'", status));
+                return Err(ValidationError::new(
+                    "verification.status",
+                    format!(
+                        "Invalid verification status {} (expected one of: {})",
+                        found,
+                        VerificationStatus::VALUES
+                            .iter()
+                            .map(|s| s.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                )
+                .at_line(line_of("status", Some("verification")).or_else(|| line_of("verification", None)))
+                .into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Find the 0-based line index of `key` in the frontmatter
+///
+/// Top-level keys must start the line; with a `parent`, the first indented
+/// `key:` after the parent's line is returned.
+fn key_line(frontmatter: &str, key: &str, parent: Option<&str>) -> Option<usize> {
+    let prefix = format!("{}:", key);
+    let mut lines = frontmatter.lines().enumerate();
+
+    match parent {
+        None => lines
+            .find(|(_, line)| line.starts_with(&prefix))
+            .map(|(index, _)| index),
+        Some(parent) => {
+            let parent_prefix = format!("{}:", parent);
+            lines.find(|(_, line)| line.starts_with(&parent_prefix))?;
+            lines
+                .take_while(|(_, line)| line.is_empty() || line.starts_with(char::is_whitespace))
+                .find(|(_, line)| line.trim_start().starts_with(&prefix))
+                .map(|(index, _)| index)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -371,6 +527,57 @@ Use `imara-diff` crate.
         assert!(result.is_err());
     }
 
+    fn frontmatter_error(content: &str) -> ValidationError {
+        match MemoryDoc::parse(content) {
+            Err(Error::Frontmatter(error)) => error,
+            other => panic!(
+                "expected a frontmatter error, got {:?}",
+                other.map(|doc| doc.frontmatter.id)
+            ),
+        }
+    }
+
+    #[test]
+    fn test_parse_unknown_kind() {
+        let error = frontmatter_error(&VALID_CORE_DOC.replace("kind: core", "kind: memo"));
+        assert_eq!(error.field, "kind");
+        assert_eq!(error.line, Some(4));
+        assert!(error.message.contains("'memo'"));
+    }
+
+    #[test]
+    fn test_parse_non_array_tags() {
+        let error = frontmatter_error(&VALID_CORE_DOC.replace("tags: [core, always-loaded]", "tags: core"));
+        assert_eq!(error.field, "tags");
+        assert_eq!(error.line, Some(5));
+    }
+
+    #[test]
+    fn test_parse_invalid_verification_status() {
+        let error = frontmatter_error(&VALID_ADR_DOC.replace("status: verified", "status: maybe"));
+        assert_eq!(error.field, "verification.status");
+        assert_eq!(error.line, Some(14));
+        assert_eq!(
+            error.to_string(),
+            "verification.status (line 14): Invalid verification status 'maybe' (expected one of: verified, stale, unknown)"
+        );
+    }
+
+    #[test]
+    fn test_parse_missing_field_and_leading_blank_lines() {
+        let error = frontmatter_error(&VALID_CORE_DOC.replace("title: Project Core Memory\n", ""));
+        assert_eq!(error.field, "title");
+        assert_eq!(error.line, None);
+
+        let padded = format!(
+            "\n\n{}",
+            VALID_CORE_DOC.replace("created: 2026-01-21T00:00:00Z", "created: yesterday")
+        );
+        let error = frontmatter_error(&padded);
+        assert_eq!(error.field, "created");
+        assert_eq!(error.line, Some(8));
+    }
+
     #[test]
     fn test_to_string_roundtrip() {
         let doc = MemoryDoc::parse(VALID_CORE_DOC).unwrap();
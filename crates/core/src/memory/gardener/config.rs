@@ -29,6 +29,9 @@ pub struct GardenerConfig {
     pub recap: RecapConfig,
 
     /// Mode for provenance validation
+    ///
+    /// In `Strict` mode, consolidation drops any fact or ADR update whose
+    /// provenance does not reference an event from the session.
     pub provenance_validation_mode: ValidationMode,
 }
 
@@ -56,8 +59,11 @@ pub struct ExtractionConfig {
     /// Keywords that signal decisions
     pub decision_keywords: Vec<String>,
 
-    /// Minimum steps for workflow detection
+    /// Minimum steps for workflow detection, in command and tool-call sequences alike
     pub min_workflow_steps: usize,
+
+    /// Minimum number of times a tool-call sequence must repeat in a session
+    pub min_sequence_repetitions: usize,
 }
 
 impl Default for ExtractionConfig {
@@ -73,6 +79,7 @@ impl Default for ExtractionConfig {
                 "opted for".to_string(),
             ],
             min_workflow_steps: 3,
+            min_sequence_repetitions: 2,
         }
     }
 }
@@ -198,6 +205,7 @@ mod tests {
         let config = ExtractionConfig::default();
         assert_eq!(config.fact_confidence_threshold, 0.7);
         assert_eq!(config.min_workflow_steps, 3);
+        assert_eq!(config.min_sequence_repetitions, 2);
         assert!(!config.decision_keywords.is_empty());
     }
 
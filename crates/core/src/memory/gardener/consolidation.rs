@@ -5,9 +5,10 @@
 use crate::LoggedEvent;
 use crate::error::{Error, Result};
 use crate::layout::{AgentDir, SessionId};
-use crate::memory::gardener::config::GardenerConfig;
-use crate::memory::gardener::entities::{AdrStatus, AdrUpdate, CommandEntity};
+use crate::memory::gardener::config::{DeduplicationStrategy, GardenerConfig};
+use crate::memory::gardener::entities::{AdrStatus, AdrUpdate, CommandEntity, WorkflowEntity};
 use crate::memory::gardener::extraction::{EntityExtractor, ExtractedEntities};
+use crate::memory::gardener::hygiene::{DUPLICATE_SIMILARITY_THRESHOLD, FactDeduplicator};
 use crate::memory::gardener::recap::{RecapGenerator, RecapResult};
 use crate::memory::kinds::MemoryKind;
 use crate::memory::paths::MemoryPaths;
@@ -17,6 +18,7 @@ use crate::provenance::ProvenanceValidator;
 use crate::session::Session;
 
 use std::fs;
+use std::path::PathBuf;
 
 /// Represents an update to a FACT document
 #[derive(Debug, Clone)]
@@ -36,6 +38,12 @@ pub enum FactUpdate {
         content: String,
         provenance: Vec<String>,
     },
+    /// Replace the body of an existing fact document with newer content
+    Replace {
+        doc_id: String,
+        content: String,
+        provenance: Vec<String>,
+    },
     /// No change needed (already present)
     NoOp { doc_id: String, reason: String },
 }
@@ -47,7 +55,7 @@ pub struct ConsolidationResult {
     pub facts: Vec<FactUpdate>,
     /// ADRs created or updated
     pub adrs: Vec<AdrUpdate>,
-    /// Playbooks created or updated (placeholder for future)
+    /// IDs of playbooks created from extracted workflows
     pub playbooks: Vec<String>,
     /// Session recap written
     pub recap: Option<RecapResult>,
@@ -74,18 +82,83 @@ impl ConsolidationResult {
     }
 }
 
+/// Provenance checks shared by the patch generators
+///
+/// Validates a document's events and records the tool and files behind each
+/// one, so the inspector doesn't have to re-derive them from the log.
+struct EventProvenance<'a> {
+    validator: ProvenanceValidator,
+    entities: &'a ExtractedEntities,
+}
+
+impl EventProvenance<'_> {
+    /// Validate a new document's provenance and attach its event sources
+    fn validate(&self, doc: &mut MemoryDoc, session_id: &SessionId) -> Result<()> {
+        self.validator.validate(doc, session_id)?;
+        self.attach_sources(doc);
+        Ok(())
+    }
+
+    /// Validate event IDs about to be merged into an existing document
+    fn validate_provenance(&self, events: &mut Vec<String>, doc_id: &str, session_id: &SessionId) -> Result<()> {
+        self.validator.validate_provenance(events, doc_id, session_id)
+    }
+
+    /// Record the tool and files behind each of the document's events
+    fn attach_sources(&self, doc: &mut MemoryDoc) {
+        for source in self.entities.sources_for(&doc.frontmatter.provenance.events) {
+            doc.frontmatter.provenance.add_source(source);
+        }
+    }
+}
+
+/// A fact document with every update from one consolidation run applied
+struct PendingFact {
+    doc_id: String,
+    path: PathBuf,
+    doc: MemoryDoc,
+    /// Description of each update folded into the document
+    changes: Vec<String>,
+    source_events: Vec<String>,
+    seq: u64,
+}
+
+impl PendingFact {
+    /// Note an applied update and the events behind it
+    fn record(&mut self, change: String, events: Vec<String>, resolver: &EventProvenance) {
+        for event in events {
+            self.doc.add_provenance_event(event.clone());
+            if !self.source_events.contains(&event) {
+                self.source_events.push(event);
+            }
+        }
+        resolver.attach_sources(&mut self.doc);
+        self.changes.push(change);
+    }
+}
+
 /// Orchestrates the episodic to semantic/procedural promotion
 #[derive(Debug, Clone)]
 pub struct ConsolidationJob {
     session_id: SessionId,
-    agent_dir: AgentDir,
+    events_file: PathBuf,
     config: GardenerConfig,
 }
 
 impl ConsolidationJob {
     /// Create a new consolidation job for a completed session
     pub fn new(session_id: &SessionId, agent_dir: &AgentDir, config: GardenerConfig) -> Self {
-        Self { session_id: session_id.clone(), agent_dir: agent_dir.clone(), config }
+        let events_file = agent_dir.events_file(session_id);
+        Self { session_id: session_id.clone(), events_file, config }
+    }
+
+    /// Read events from `events_file` instead of the session's default log
+    ///
+    /// The file may still be growing, so a live session can be consolidated
+    /// without ending it.
+    pub fn with_events_file(mut self, events_file: impl Into<PathBuf>) -> Self {
+        self.events_file = events_file.into();
+        self
     }
 
     /// Execute the consolidation pipeline
@@ -100,20 +173,32 @@ impl ConsolidationJob {
         let adrs = self.generate_adr_updates(&entities, &manifest);
 
         let mut patches = Vec::new();
-        let validator = ProvenanceValidator::new(self.config.provenance_validation_mode);
+        let mut warnings = self.collect_warnings(&facts, &adrs);
+        let facts = self.match_existing_facts(facts, &manifest, &mut warnings);
+        let resolver = EventProvenance {
+            validator: ProvenanceValidator::new(self.config.provenance_validation_mode)
+                .with_known_events(events.iter().map(|e| format!("{}_{}", e.session_id, e.seq))),
+            entities: &entities,
+        };
 
-        self.generate_fact_patches(&facts, &mut patches, mem_paths, &validator)?;
-        self.generate_adr_patches(&adrs, &mut patches, mem_paths, &validator)?;
+        self.generate_fact_patches(&facts, &manifest, &mut patches, mem_paths, &resolver, &mut warnings);
+        self.generate_adr_patches(&adrs, &mut patches, mem_paths, &resolver, &mut warnings);
+        let playbooks = self.generate_playbook_patches(
+            &entities.workflows,
+            &manifest,
+            &mut patches,
+            mem_paths,
+            &resolver,
+            &mut warnings,
+        );
         let recap = self.generate_recap(&events, &entities, mem_paths).await.ok();
-        let warnings = self.collect_warnings(&facts, &adrs);
 
-        Ok(ConsolidationResult { facts, adrs, playbooks: Vec::new(), recap, patches, warnings })
+        Ok(ConsolidationResult { facts, adrs, playbooks, recap, patches, warnings })
     }
 
     /// Load events from the session file
     fn load_events(&self) -> Result<Vec<LoggedEvent>> {
-        let session = Session::load(self.agent_dir.clone(), self.session_id.clone())?;
-        session.read_events()
+        Session::read_events_file(&self.events_file)
     }
 
     /// Load the memory manifest
@@ -240,14 +325,80 @@ impl ConsolidationJob {
         updates
     }
 
+    /// Redirect new facts that closely match an existing fact
+    ///
+    /// A `Create` whose content is similar to an existing fact becomes an
+    /// update of that fact, as chosen by the hygiene `dedup_strategy`:
+    /// `MergeToFirst` appends to it, `KeepNewest` replaces its body, and
+    /// `FlagForReview` keeps the new document but warns about the overlap.
+    fn match_existing_facts(
+        &self, facts: Vec<FactUpdate>, manifest: &MemoryManifest, warnings: &mut Vec<String>,
+    ) -> Vec<FactUpdate> {
+        let strategy = self.config.hygiene.dedup_strategy;
+        let dedup = FactDeduplicator::new(strategy);
+        let existing: Vec<(String, String)> = manifest
+            .by_kind(MemoryKind::Fact)
+            .into_iter()
+            .filter_map(|entry| {
+                let content = fs::read_to_string(&entry.path).ok()?;
+                Some((entry.id.clone(), MemoryDoc::parse(&content).ok()?.body))
+            })
+            .collect();
+
+        facts
+            .into_iter()
+            .map(|fact| {
+                let FactUpdate::Create { doc_id, title, tags, content, provenance } = fact else {
+                    return fact;
+                };
+
+                let best = existing
+                    .iter()
+                    .map(|(id, body)| (id, dedup.compute_similarity(&content, body)))
+                    .filter(|(_, similarity)| *similarity > DUPLICATE_SIMILARITY_THRESHOLD)
+                    .max_by(|a, b| a.1.total_cmp(&b.1));
+
+                let Some((existing_id, similarity)) = best else {
+                    return FactUpdate::Create { doc_id, title, tags, content, provenance };
+                };
+
+                match strategy {
+                    DeduplicationStrategy::MergeToFirst => {
+                        let heading = format!("## {}", title);
+                        let content = content
+                            .strip_prefix(&heading)
+                            .unwrap_or(&content)
+                            .trim_start()
+                            .to_string();
+                        FactUpdate::Append { doc_id: existing_id.clone(), section: title, content, provenance }
+                    }
+                    DeduplicationStrategy::KeepNewest => {
+                        FactUpdate::Replace { doc_id: existing_id.clone(), content, provenance }
+                    }
+                    DeduplicationStrategy::FlagForReview => {
+                        warnings.push(format!(
+                            "New fact '{}' resembles existing fact '{}' (similarity {:.2}); review before approving",
+                            doc_id, existing_id, similarity
+                        ));
+                        FactUpdate::Create { doc_id, title, tags, content, provenance }
+                    }
+                }
+            })
+            .collect()
+    }
+
     /// Format commands as markdown
     fn format_commands_as_markdown(&self, commands: &[&CommandEntity]) -> String {
         let mut md = String::new();
         for cmd in commands {
             md.push_str(&format!(
-                "- `{}{}`\n",
+                "- `{}`{}\n",
                 cmd.command,
-                if cmd.outcome == crate::memory::gardener::entities::CommandOutcome::Success { " ✓" } else { "" }
+                if cmd.outcome == crate::memory::gardener::entities::CommandOutcome::Success {
+                    " (verified succeeding)"
+                } else {
+                    ""
+                }
             ));
         }
         md
@@ -276,13 +427,21 @@ impl ConsolidationJob {
     }
 
     /// Generate memory patches from fact updates
+    ///
+    /// Every update to the same document is folded into one patch, so
+    /// approving it applies them all instead of each overwriting the last.
+    /// Updates that fail provenance validation are dropped and reported in `warnings`.
     fn generate_fact_patches(
-        &self, facts: &[FactUpdate], patches: &mut Vec<MemoryPatchParams>, paths: &MemoryPaths,
-        validator: &ProvenanceValidator,
-    ) -> Result<()> {
+        &self, facts: &[FactUpdate], manifest: &MemoryManifest, patches: &mut Vec<MemoryPatchParams>,
+        paths: &MemoryPaths, resolver: &EventProvenance, warnings: &mut Vec<String>,
+    ) {
+        let mut pending: Vec<PendingFact> = Vec::new();
+
         for (idx, fact) in facts.iter().enumerate() {
             match fact {
-                FactUpdate::Create { doc_id, title, tags, content, provenance } => {
+                FactUpdate::Create { doc_id, title, tags, content, provenance }
+                    if !pending.iter().any(|p| &p.doc_id == doc_id) =>
+                {
                     let path = paths.facts.join(format!("{}.md", doc_id.replace('.', "_")));
                     let mut doc = MemoryDoc::new(
                         doc_id.clone(),
@@ -293,47 +452,109 @@ impl ConsolidationJob {
                     );
                     doc.frontmatter.provenance.events = provenance.clone();
 
-                    validator.validate(&mut doc, &self.session_id)?;
+                    if let Err(e) = resolver.validate(&mut doc, &self.session_id) {
+                        Self::drop_unverified(doc_id, e, warnings);
+                        continue;
+                    }
 
-                    patches.push(MemoryPatchParams {
-                        path,
+                    pending.push(PendingFact {
                         doc_id: doc_id.clone(),
-                        kind: MemoryKind::Fact,
-                        description: format!("Create fact: {}", title),
-                        diff: format!("{}", doc),
+                        path,
                         source_events: doc.frontmatter.provenance.events.clone(),
-                        session_id: self.session_id.clone(),
+                        doc,
+                        changes: vec![format!("Create fact: {}", title)],
                         seq: idx as u64,
                     });
                 }
                 FactUpdate::Append { doc_id, section, content, provenance } => {
                     let mut validated_provenance = provenance.clone();
-                    validator.validate_provenance(&mut validated_provenance, doc_id, &self.session_id)?;
-
-                    let path = paths.facts.join(format!("{}.md", doc_id.replace('.', "_")));
-                    patches.push(MemoryPatchParams {
-                        path,
-                        doc_id: doc_id.clone(),
-                        kind: MemoryKind::Fact,
-                        description: format!("Append to fact: {}", doc_id),
-                        diff: self.append_fact_diff(section, content),
-                        source_events: validated_provenance,
-                        session_id: self.session_id.clone(),
-                        seq: idx as u64,
-                    });
+                    if let Err(e) = resolver.validate_provenance(&mut validated_provenance, doc_id, &self.session_id) {
+                        Self::drop_unverified(doc_id, e, warnings);
+                        continue;
+                    }
+
+                    let Some(fact) = Self::pending_fact(&mut pending, doc_id, idx, manifest, paths, warnings) else {
+                        continue;
+                    };
+                    let added = Self::merge_into_section(&mut fact.doc, section, content);
+                    if added == 0 {
+                        continue;
+                    }
+                    fact.record(
+                        format!("Merge {} line(s) into fact: {} ({})", added, doc_id, section),
+                        validated_provenance,
+                        resolver,
+                    );
+                }
+                FactUpdate::Create { doc_id, content, provenance, .. }
+                | FactUpdate::Replace { doc_id, content, provenance } => {
+                    let mut validated_provenance = provenance.clone();
+                    if let Err(e) = resolver.validate_provenance(&mut validated_provenance, doc_id, &self.session_id) {
+                        Self::drop_unverified(doc_id, e, warnings);
+                        continue;
+                    }
+
+                    let Some(fact) = Self::pending_fact(&mut pending, doc_id, idx, manifest, paths, warnings) else {
+                        continue;
+                    };
+                    fact.doc.update_body(content.clone());
+                    fact.record(
+                        format!("Replace fact with newer content: {}", doc_id),
+                        validated_provenance,
+                        resolver,
+                    );
                 }
                 FactUpdate::NoOp { .. } => {}
             }
         }
 
-        Ok(())
+        patches.extend(
+            pending
+                .into_iter()
+                .filter(|fact| !fact.changes.is_empty())
+                .map(|fact| MemoryPatchParams {
+                    path: fact.path,
+                    doc_id: fact.doc_id,
+                    kind: MemoryKind::Fact,
+                    description: fact.changes.join("; "),
+                    diff: format!("{}", fact.doc),
+                    source_events: fact.source_events,
+                    session_id: self.session_id.clone(),
+                    seq: fact.seq,
+                }),
+        );
+    }
+
+    /// The fact already being updated in this run, or the one on disk
+    fn pending_fact<'a>(
+        pending: &'a mut Vec<PendingFact>, doc_id: &str, seq: usize, manifest: &MemoryManifest, paths: &MemoryPaths,
+        warnings: &mut Vec<String>,
+    ) -> Option<&'a mut PendingFact> {
+        let idx = match pending.iter().position(|fact| fact.doc_id == doc_id) {
+            Some(idx) => idx,
+            None => {
+                let (path, doc) = Self::load_existing_fact(doc_id, manifest, paths, warnings)?;
+                pending.push(PendingFact {
+                    doc_id: doc_id.to_string(),
+                    path,
+                    doc,
+                    changes: Vec::new(),
+                    source_events: Vec::new(),
+                    seq: seq as u64,
+                });
+                pending.len() - 1
+            }
+        };
+        pending.get_mut(idx)
     }
 
     /// Generate memory patches from ADR updates
+    ///
+    /// ADRs that fail provenance validation are dropped and reported in `warnings`.
     fn generate_adr_patches(
         &self, adrs: &[AdrUpdate], patches: &mut Vec<MemoryPatchParams>, paths: &MemoryPaths,
-        validator: &ProvenanceValidator,
-    ) -> Result<()> {
+        resolver: &EventProvenance, warnings: &mut Vec<String>,
+    ) {
         for (idx, adr) in adrs.iter().enumerate() {
             let filename = format!("ADR-{:04}.md", adr.number);
             let path = paths.decisions.join(filename);
@@ -348,7 +569,10 @@ impl ConsolidationJob {
             );
             doc.frontmatter.provenance.events = adr.event_ids.clone();
 
-            validator.validate(&mut doc, &self.session_id)?;
+            if let Err(e) = resolver.validate(&mut doc, &self.session_id) {
+                Self::drop_unverified(&doc_id, e, warnings);
+                continue;
+            }
 
             patches.push(MemoryPatchParams {
                 path,
@@ -361,13 +585,126 @@ impl ConsolidationJob {
                 seq: idx as u64,
             });
         }
+    }
 
-        Ok(())
+    /// Generate memory patches creating playbooks from extracted workflows
+    ///
+    /// Workflows whose playbook already exists are skipped. Returns the IDs of
+    /// the playbooks that were queued.
+    fn generate_playbook_patches(
+        &self, workflows: &[WorkflowEntity], manifest: &MemoryManifest, patches: &mut Vec<MemoryPatchParams>,
+        paths: &MemoryPaths, resolver: &EventProvenance, warnings: &mut Vec<String>,
+    ) -> Vec<String> {
+        let mut playbooks = Vec::new();
+
+        for (idx, workflow) in workflows.iter().enumerate() {
+            let doc_id = workflow.doc_id();
+            if playbooks.contains(&doc_id) || manifest.docs.iter().any(|d| d.id == doc_id) {
+                continue;
+            }
+
+            let mut doc = MemoryDoc::new(
+                doc_id.clone(),
+                workflow.title.clone(),
+                MemoryKind::Playbook,
+                vec!["workflow".to_string()],
+                workflow.to_markdown(),
+            );
+            doc.frontmatter.provenance.events = workflow.event_ids.clone();
+
+            if let Err(e) = resolver.validate(&mut doc, &self.session_id) {
+                Self::drop_unverified(&doc_id, e, warnings);
+                continue;
+            }
+
+            patches.push(MemoryPatchParams {
+                path: paths.playbooks.join(format!("{}.md", doc_id.replace('.', "_"))),
+                doc_id: doc_id.clone(),
+                kind: MemoryKind::Playbook,
+                description: format!("Create playbook: {}", workflow.title),
+                diff: format!("{}", doc),
+                source_events: doc.frontmatter.provenance.events.clone(),
+                session_id: self.session_id.clone(),
+                seq: idx as u64,
+            });
+            playbooks.push(doc_id);
+        }
+
+        playbooks
+    }
+
+    /// Record a memory update that was dropped for lacking valid provenance
+    fn drop_unverified(doc_id: &str, error: Error, warnings: &mut Vec<String>) {
+        tracing::warn!(doc_id, %error, "Dropping memory update without valid provenance");
+        warnings.push(format!("Dropped update to '{}': {}", doc_id, error));
+    }
+
+    /// Read an existing fact so it can be updated in place
+    ///
+    /// Uses the manifest path when the fact is indexed, falling back to the
+    /// conventional facts directory. Missing or unparsable facts are reported
+    /// in `warnings`.
+    fn load_existing_fact(
+        doc_id: &str, manifest: &MemoryManifest, paths: &MemoryPaths, warnings: &mut Vec<String>,
+    ) -> Option<(PathBuf, MemoryDoc)> {
+        let path = manifest
+            .by_id(doc_id)
+            .map(|entry| entry.path.clone())
+            .unwrap_or_else(|| paths.facts.join(format!("{}.md", doc_id.replace('.', "_"))));
+
+        let doc = fs::read_to_string(&path)
+            .map_err(Error::Io)
+            .and_then(|content| MemoryDoc::parse(&content));
+        match doc {
+            Ok(doc) => Some((path, doc)),
+            Err(e) => {
+                warnings.push(format!("Skipped update to '{}': {}", doc_id, e));
+                None
+            }
+        }
     }
 
-    /// Append a diff for appending to a fact
-    fn append_fact_diff(&self, section: &str, content: &str) -> String {
-        format!("### {}\n\n{}", section, content)
+    /// Merge lines into a `##` section of a document, skipping lines already present
+    ///
+    /// The section is created at the end of the body when missing. Returns the
+    /// number of lines added.
+    fn merge_into_section(doc: &mut MemoryDoc, section: &str, content: &str) -> usize {
+        let new_lines: Vec<&str> = content
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !doc.body.lines().any(|existing| existing == *line))
+            .collect();
+        if new_lines.is_empty() {
+            return 0;
+        }
+
+        let heading = format!("## {}", section);
+        let mut lines: Vec<String> = doc.body.lines().map(str::to_string).collect();
+        match lines.iter().position(|line| line.trim() == heading) {
+            Some(start) => {
+                let end = lines[start + 1..]
+                    .iter()
+                    .position(|line| line.starts_with("## "))
+                    .map_or(lines.len(), |offset| start + 1 + offset);
+                let insert_at = (start + 1..end)
+                    .rev()
+                    .find(|&idx| !lines[idx].trim().is_empty())
+                    .map_or(end, |idx| idx + 1);
+                for (offset, line) in new_lines.iter().enumerate() {
+                    lines.insert(insert_at + offset, line.to_string());
+                }
+            }
+            None => {
+                if lines.last().is_some_and(|line| !line.trim().is_empty()) {
+                    lines.push(String::new());
+                }
+                lines.push(heading);
+                lines.push(String::new());
+                lines.extend(new_lines.iter().map(|line| line.to_string()));
+            }
+        }
+
+        doc.update_body(lines.join("\n"));
+        new_lines.len()
     }
 
     /// Generate a session recap
@@ -451,7 +788,11 @@ mod tests {
             create_test_event(
                 0,
                 "test-session",
-                Event::ToolCall { tool: "shell".to_string(), arguments: json!({"cmd": "cargo build"}) },
+                Event::ToolCall {
+                    tool: "shell".to_string(),
+                    arguments: json!({"cmd": "cargo build"}),
+                    tool_call_id: None,
+                },
             ),
             create_test_event(
                 1,
@@ -461,12 +802,17 @@ mod tests {
                     result: json!({"cmd": "cargo build", "exit_code": 0}),
                     success: true,
                     error: None,
+                    tool_call_id: None,
                 },
             ),
             create_test_event(
                 2,
                 "test-session",
-                Event::ToolCall { tool: "shell".to_string(), arguments: json!({"cmd": "cargo test"}) },
+                Event::ToolCall {
+                    tool: "shell".to_string(),
+                    arguments: json!({"cmd": "cargo test"}),
+                    tool_call_id: None,
+                },
             ),
             create_test_event(
                 3,
@@ -476,6 +822,7 @@ mod tests {
                     result: json!({"cmd": "cargo test", "exit_code": 0}),
                     success: true,
                     error: None,
+                    tool_call_id: None,
                 },
             ),
         ];
@@ -501,6 +848,7 @@ mod tests {
                     result: json!({"cmd": "cargo build", "exit_code": 1}),
                     success: false,
                     error: Some("error: feature not found".to_string()),
+                    tool_call_id: None,
                 },
             ),
             create_test_event(
@@ -511,6 +859,7 @@ mod tests {
                     result: json!({"cmd": "cargo build --features foo", "exit_code": 0}),
                     success: true,
                     error: None,
+                    tool_call_id: None,
                 },
             ),
         ];
@@ -552,7 +901,11 @@ mod tests {
             create_test_event(
                 0,
                 "test-session",
-                Event::ToolCall { tool: "shell".to_string(), arguments: json!({"cmd": "cargo build"}) },
+                Event::ToolCall {
+                    tool: "shell".to_string(),
+                    arguments: json!({"cmd": "cargo build"}),
+                    tool_call_id: None,
+                },
             ),
             create_test_event(
                 1,
@@ -562,6 +915,7 @@ mod tests {
                     result: json!({"cmd": "cargo build", "exit_code": 0}),
                     success: true,
                     error: None,
+                    tool_call_id: None,
                 },
             ),
         ];
@@ -598,6 +952,7 @@ mod tests {
                     result: json!({"cmd": "cargo fmt", "exit_code": 0}),
                     success: true,
                     error: None,
+                    tool_call_id: None,
                 },
             ),
             create_test_event(
@@ -608,6 +963,7 @@ mod tests {
                     result: json!({"cmd": "cargo clippy", "exit_code": 0}),
                     success: true,
                     error: None,
+                    tool_call_id: None,
                 },
             ),
             create_test_event(
@@ -618,6 +974,7 @@ mod tests {
                     result: json!({"cmd": "cargo test", "exit_code": 0}),
                     success: true,
                     error: None,
+                    tool_call_id: None,
                 },
             ),
             create_test_event(3, "test-session", Event::UserMessage { content: "Done".to_string() }),
@@ -655,6 +1012,7 @@ mod tests {
                 result: json!({"cmd": "cargo build", "exit_code": 0}),
                 success: true,
                 error: None,
+                tool_call_id: None,
             },
         );
         let json_line = serde_json::to_string(&event).unwrap();
@@ -675,4 +1033,350 @@ mod tests {
 
         assert!(patch.source_events.contains(&"test-session_0".to_string()));
     }
+
+    #[tokio::test]
+    async fn test_consolidation_records_event_sources() {
+        let temp = TempDir::new().unwrap();
+        let paths = MemoryPaths::from_thunderus_root(temp.path());
+        paths.ensure().unwrap();
+
+        let events = vec![
+            create_test_event(
+                0,
+                "test-session",
+                Event::ToolCall {
+                    tool: "shell".to_string(),
+                    arguments: json!({"command": "cargo build"}),
+                    tool_call_id: None,
+                },
+            ),
+            create_test_event(
+                1,
+                "test-session",
+                Event::ToolResult {
+                    tool: "shell".to_string(),
+                    result: json!({"cmd": "cargo build", "exit_code": 0}),
+                    success: true,
+                    error: None,
+                    tool_call_id: None,
+                },
+            ),
+        ];
+        write_session_events(&temp, &events);
+
+        let agent_dir = AgentDir::new(temp.path());
+        let session_id = SessionId::from_timestamp("test-session").unwrap();
+        let job = ConsolidationJob::new(&session_id, &agent_dir, GardenerConfig::default());
+        let result = job.run(&paths).await.unwrap();
+
+        let patch = result
+            .patches
+            .iter()
+            .find(|p| p.doc_id == "fact.commands.build")
+            .unwrap();
+        let doc = MemoryDoc::parse(&patch.diff).unwrap();
+        let sources = &doc.frontmatter.provenance.sources;
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].event, "test-session_1");
+        assert_eq!(sources[0].tool, "shell");
+    }
+
+    fn write_session_events(temp: &TempDir, events: &[LoggedEvent]) {
+        let session_dir = temp.path().join(".agent/sessions/test-session");
+        std::fs::create_dir_all(&session_dir).unwrap();
+        let lines: Vec<String> = events.iter().map(|e| serde_json::to_string(e).unwrap()).collect();
+        std::fs::write(session_dir.join("events.jsonl"), format!("{}\n", lines.join("\n"))).unwrap();
+    }
+
+    #[test]
+    fn test_strict_mode_drops_unverified_updates() {
+        let config = GardenerConfig {
+            provenance_validation_mode: crate::provenance::ValidationMode::Strict,
+            ..Default::default()
+        };
+        let temp = TempDir::new().unwrap();
+        let paths = MemoryPaths::from_thunderus_root(temp.path());
+        let agent_dir = AgentDir::new(temp.path());
+        let session_id = SessionId::from_timestamp("test-session").unwrap();
+        let job = ConsolidationJob::new(&session_id, &agent_dir, config);
+
+        let facts = vec![
+            FactUpdate::Create {
+                doc_id: "fact.backed".to_string(),
+                title: "Backed".to_string(),
+                tags: vec![],
+                content: "Backed content".to_string(),
+                provenance: vec!["test-session_0".to_string()],
+            },
+            FactUpdate::Create {
+                doc_id: "fact.hallucinated".to_string(),
+                title: "Hallucinated".to_string(),
+                tags: vec![],
+                content: "Made up".to_string(),
+                provenance: vec![],
+            },
+            FactUpdate::Append {
+                doc_id: "fact.unknown".to_string(),
+                section: "Notes".to_string(),
+                content: "Unbacked".to_string(),
+                provenance: vec!["test-session_99".to_string()],
+            },
+        ];
+
+        let entities = ExtractedEntities::default();
+        let resolver = EventProvenance {
+            validator: ProvenanceValidator::new(crate::provenance::ValidationMode::Strict)
+                .with_known_events(["test-session_0".to_string()]),
+            entities: &entities,
+        };
+        let mut patches = Vec::new();
+        let mut warnings = Vec::new();
+        job.generate_fact_patches(
+            &facts,
+            &MemoryManifest::default(),
+            &mut patches,
+            &paths,
+            &resolver,
+            &mut warnings,
+        );
+
+        assert_eq!(patches.len(), 1);
+        assert_eq!(patches[0].doc_id, "fact.backed");
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings.iter().any(|w| w.contains("fact.hallucinated")));
+        assert!(warnings.iter().any(|w| w.contains("fact.unknown")));
+    }
+
+    fn write_existing_fact(paths: &MemoryPaths, body: &str) -> MemoryManifest {
+        paths.ensure().unwrap();
+        let doc = MemoryDoc::new(
+            "fact.commands.build",
+            "Build Commands",
+            MemoryKind::Fact,
+            vec!["commands".to_string()],
+            body,
+        );
+        fs::write(paths.facts.join("fact_commands_build.md"), doc.to_string()).unwrap();
+        MemoryManifest::rebuild(paths).unwrap()
+    }
+
+    #[test]
+    fn test_append_merges_into_existing_fact() {
+        let temp = TempDir::new().unwrap();
+        let paths = MemoryPaths::from_thunderus_root(temp.path());
+        let manifest = write_existing_fact(
+            &paths,
+            "## Build\n\n- `cargo build`\n\n## Notes\n\nKeep the cache warm.",
+        );
+        let agent_dir = AgentDir::new(temp.path());
+        let session_id = SessionId::from_timestamp("test-session").unwrap();
+        let job = ConsolidationJob::new(&session_id, &agent_dir, GardenerConfig::default());
+
+        let facts = vec![FactUpdate::Append {
+            doc_id: "fact.commands.build".to_string(),
+            section: "Build".to_string(),
+            content: "- `cargo build`\n- `cargo build --release`".to_string(),
+            provenance: vec!["test-session_0".to_string()],
+        }];
+        let entities = ExtractedEntities::default();
+        let resolver = EventProvenance {
+            validator: ProvenanceValidator::new(crate::provenance::ValidationMode::Strict)
+                .with_known_events(["test-session_0".to_string()]),
+            entities: &entities,
+        };
+        let mut patches = Vec::new();
+        let mut warnings = Vec::new();
+        job.generate_fact_patches(&facts, &manifest, &mut patches, &paths, &resolver, &mut warnings);
+
+        assert!(warnings.is_empty());
+        assert_eq!(patches.len(), 1);
+        assert_eq!(patches[0].path, paths.facts.join("fact_commands_build.md"));
+        assert!(patches[0].description.contains("1 line(s)"));
+
+        let merged = MemoryDoc::parse(&patches[0].diff).unwrap();
+        assert_eq!(
+            merged.body,
+            "## Build\n\n- `cargo build`\n- `cargo build --release`\n\n## Notes\n\nKeep the cache warm."
+        );
+        assert!(
+            merged
+                .frontmatter
+                .provenance
+                .events
+                .contains(&"test-session_0".to_string())
+        );
+    }
+
+    #[test]
+    fn test_updates_to_one_fact_share_a_patch() {
+        let temp = TempDir::new().unwrap();
+        let paths = MemoryPaths::from_thunderus_root(temp.path());
+        let manifest = write_existing_fact(
+            &paths,
+            "## Build\n\n- `cargo build`\n\n## Notes\n\nKeep the cache warm.",
+        );
+        let agent_dir = AgentDir::new(temp.path());
+        let session_id = SessionId::from_timestamp("test-session").unwrap();
+        let job = ConsolidationJob::new(&session_id, &agent_dir, GardenerConfig::default());
+
+        let facts = vec![
+            FactUpdate::Append {
+                doc_id: "fact.commands.build".to_string(),
+                section: "Build".to_string(),
+                content: "- `cargo build --release`".to_string(),
+                provenance: vec!["test-session_0".to_string()],
+            },
+            FactUpdate::Append {
+                doc_id: "fact.commands.build".to_string(),
+                section: "Notes".to_string(),
+                content: "Run clippy first.".to_string(),
+                provenance: vec!["test-session_1".to_string()],
+            },
+        ];
+        let entities = ExtractedEntities::default();
+        let resolver = EventProvenance {
+            validator: ProvenanceValidator::new(crate::provenance::ValidationMode::Strict)
+                .with_known_events(["test-session_0".to_string(), "test-session_1".to_string()]),
+            entities: &entities,
+        };
+        let mut patches = Vec::new();
+        let mut warnings = Vec::new();
+        job.generate_fact_patches(&facts, &manifest, &mut patches, &paths, &resolver, &mut warnings);
+
+        assert!(warnings.is_empty());
+        assert_eq!(patches.len(), 1);
+        assert_eq!(patches[0].source_events, vec!["test-session_0", "test-session_1"]);
+        assert!(patches[0].description.contains("(Build)") && patches[0].description.contains("(Notes)"));
+
+        let merged = MemoryDoc::parse(&patches[0].diff).unwrap();
+        assert!(merged.body.contains("- `cargo build --release`"));
+        assert!(merged.body.contains("Run clippy first."));
+    }
+
+    #[test]
+    fn test_similar_fact_follows_dedup_strategy() {
+        let temp = TempDir::new().unwrap();
+        let paths = MemoryPaths::from_thunderus_root(temp.path());
+        let manifest = write_existing_fact(&paths, "## Build\n\n- `cargo build` (verified succeeding)");
+        let agent_dir = AgentDir::new(temp.path());
+        let session_id = SessionId::from_timestamp("test-session").unwrap();
+
+        let new_fact = || {
+            vec![FactUpdate::Create {
+                doc_id: "fact.commands.compile".to_string(),
+                title: "Build".to_string(),
+                tags: vec!["commands".to_string()],
+                content: "## Build\n\n- `cargo build` (verified succeeding)".to_string(),
+                provenance: vec!["test-session_0".to_string()],
+            }]
+        };
+        let job_with = |dedup_strategy| {
+            let mut config = GardenerConfig::default();
+            config.hygiene.dedup_strategy = dedup_strategy;
+            ConsolidationJob::new(&session_id, &agent_dir, config)
+        };
+
+        let mut warnings = Vec::new();
+        let merged =
+            job_with(DeduplicationStrategy::MergeToFirst).match_existing_facts(new_fact(), &manifest, &mut warnings);
+        assert!(matches!(
+            &merged[0],
+            FactUpdate::Append { doc_id, section, content, .. }
+                if doc_id == "fact.commands.build" && section == "Build" && content == "- `cargo build` (verified succeeding)"
+        ));
+
+        let replaced =
+            job_with(DeduplicationStrategy::KeepNewest).match_existing_facts(new_fact(), &manifest, &mut warnings);
+        assert!(matches!(&replaced[0], FactUpdate::Replace { doc_id, .. } if doc_id == "fact.commands.build"));
+        assert!(warnings.is_empty());
+
+        let flagged =
+            job_with(DeduplicationStrategy::FlagForReview).match_existing_facts(new_fact(), &manifest, &mut warnings);
+        assert!(matches!(&flagged[0], FactUpdate::Create { doc_id, .. } if doc_id == "fact.commands.compile"));
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("fact.commands.build"));
+    }
+
+    #[tokio::test]
+    async fn test_consolidation_provenance_strict() {
+        let config = GardenerConfig {
+            provenance_validation_mode: crate::provenance::ValidationMode::Strict,
+            ..Default::default()
+        };
+
+        let temp = TempDir::new().unwrap();
+        let paths = MemoryPaths::from_thunderus_root(temp.path());
+        paths.ensure().unwrap();
+        write_session_events(
+            &temp,
+            &[create_test_event(
+                0,
+                "test-session",
+                Event::ToolResult {
+                    tool: "shell".to_string(),
+                    result: json!({"cmd": "cargo build", "exit_code": 0}),
+                    success: true,
+                    error: None,
+                    tool_call_id: None,
+                },
+            )],
+        );
+
+        let agent_dir = AgentDir::new(temp.path());
+        let session_id = SessionId::from_timestamp("test-session").unwrap();
+        let result = ConsolidationJob::new(&session_id, &agent_dir, config)
+            .run(&paths)
+            .await
+            .unwrap();
+
+        assert!(result.patches.iter().any(|p| p.doc_id == "fact.commands.build"));
+        assert!(!result.warnings.iter().any(|w| w.starts_with("Dropped")));
+    }
+
+    #[tokio::test]
+    async fn test_consolidation_creates_playbook_from_tool_sequence() {
+        let temp = TempDir::new().unwrap();
+        let paths = MemoryPaths::from_thunderus_root(temp.path());
+        paths.ensure().unwrap();
+
+        let tools = ["read", "edit", "shell", "read", "edit", "shell"];
+        let events: Vec<LoggedEvent> = tools
+            .iter()
+            .enumerate()
+            .map(|(seq, tool)| {
+                create_test_event(
+                    seq as u64,
+                    "test-session",
+                    Event::ToolCall {
+                        tool: tool.to_string(),
+                        arguments: json!({"cmd": "cargo test"}),
+                        tool_call_id: None,
+                    },
+                )
+            })
+            .collect();
+        write_session_events(&temp, &events);
+
+        let agent_dir = AgentDir::new(temp.path());
+        let session_id = SessionId::from_timestamp("test-session").unwrap();
+        let result = ConsolidationJob::new(&session_id, &agent_dir, GardenerConfig::default())
+            .run(&paths)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result.playbooks,
+            vec!["playbook.tool-workflow-read-edit-shell".to_string()]
+        );
+        let patch = result.patches.iter().find(|p| p.kind == MemoryKind::Playbook).unwrap();
+        assert_eq!(
+            patch.path,
+            paths.playbooks.join("playbook_tool-workflow-read-edit-shell.md")
+        );
+        assert!(patch.diff.contains("## Steps"));
+        assert_eq!(patch.source_events.len(), 6);
+
+        let doc = MemoryDoc::parse(&patch.diff).unwrap();
+        assert!(doc.validate().is_empty());
+    }
 }
@@ -45,6 +45,24 @@ pub struct DriftResult {
     pub current_commit: String,
 }
 
+/// Result of re-verifying every stale document
+#[derive(Debug, Clone, Default)]
+pub struct VerifyAllResult {
+    /// Documents marked verified at the current commit
+    pub verified: Vec<String>,
+    /// Documents whose referenced files changed meaningfully and need review
+    pub needs_review: Vec<StalenessInfo>,
+    /// Commit the documents were verified against
+    pub current_commit: String,
+}
+
+impl DriftResult {
+    /// Number of stale documents with the given severity
+    pub fn count(&self, severity: StalenessSeverity) -> usize {
+        self.stale_docs.iter().filter(|doc| doc.severity == severity).count()
+    }
+}
+
 /// Detects memory-repo drift
 pub struct DriftDetector {
     repo_path: PathBuf,
@@ -100,7 +118,7 @@ impl DriftDetector {
         for changed_file in changed_files {
             if referenced_files.contains(&changed_file) {
                 doc_changed_files.push(changed_file.clone());
-                if !changed_file.exists() {
+                if !self.repo_path.join(&changed_file).exists() {
                     severity = StalenessSeverity::Critical;
                 } else if severity != StalenessSeverity::Critical {
                     severity = StalenessSeverity::Major;
@@ -121,6 +139,45 @@ impl DriftDetector {
         }
     }
 
+    /// Check whether a stale document's referenced files changed beyond whitespace
+    ///
+    /// Compares each changed file at the document's last verified commit with
+    /// `head_commit`. Added or deleted files always count as meaningful.
+    pub fn has_meaningful_changes(&self, info: &StalenessInfo, head_commit: &str) -> Result<bool> {
+        let Some(last_verified) = &info.last_verified else {
+            return Ok(true);
+        };
+
+        let repo = git2::Repository::discover(&self.repo_path)
+            .map_err(|e| Error::Other(format!("Failed to open repository: {}", e)))?;
+        let tree_at = |commit: &str| -> Result<git2::Tree<'_>> {
+            let oid = git2::Oid::from_str(commit).map_err(|e| Error::Other(format!("Invalid commit ID: {}", e)))?;
+            repo.find_commit(oid)
+                .and_then(|commit| commit.tree())
+                .map_err(|e| Error::Other(format!("Failed to get tree: {}", e)))
+        };
+        let old_tree = tree_at(last_verified)?;
+        let new_tree = tree_at(head_commit)?;
+
+        for path in &info.changed_files {
+            let old = Self::blob_content(&repo, &old_tree, path);
+            let new = Self::blob_content(&repo, &new_tree, path);
+            match (old, new) {
+                (Some(old), Some(new)) if normalize_whitespace(&old) == normalize_whitespace(&new) => {}
+                _ => return Ok(true),
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Read a file's content from a tree, if it exists there
+    fn blob_content(repo: &git2::Repository, tree: &git2::Tree<'_>, path: &Path) -> Option<Vec<u8>> {
+        let entry = tree.get_path(path).ok()?;
+        let blob = repo.find_blob(entry.id()).ok()?;
+        Some(blob.content().to_vec())
+    }
+
     /// Get the current HEAD commit hash
     fn get_head_commit(&self, repo: &git2::Repository) -> Result<String> {
         let head = repo
@@ -287,6 +344,15 @@ impl DriftDetector {
     }
 }
 
+/// Collapse all whitespace so cosmetic reformatting compares equal
+fn normalize_whitespace(content: &[u8]) -> Vec<u8> {
+    content
+        .split(|b| b.is_ascii_whitespace())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(&b' ')
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -335,6 +401,15 @@ See src/ for the main source code.
         assert!(paths.iter().any(|p| p.ends_with("tests/integration_test.rs")));
     }
 
+    #[test]
+    fn test_normalize_whitespace() {
+        assert_eq!(
+            normalize_whitespace(b"fn a()  {\n\t}\n"),
+            normalize_whitespace(b"fn a() { }")
+        );
+        assert_ne!(normalize_whitespace(b"fn a() {}"), normalize_whitespace(b"fn b() {}"));
+    }
+
     #[test]
     fn test_extract_referenced_paths_empty() {
         let temp = tempfile::TempDir::new().unwrap();
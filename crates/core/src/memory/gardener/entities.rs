@@ -101,6 +101,45 @@ pub struct WorkflowEntity {
     pub event_ids: Vec<String>,
 }
 
+impl WorkflowEntity {
+    /// Playbook document ID derived from the title, e.g. `playbook.run-rust-tests`
+    pub fn doc_id(&self) -> String {
+        let slug = self
+            .title
+            .to_lowercase()
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<_>>()
+            .join("-");
+        format!("playbook.{}", slug)
+    }
+
+    /// Generate the markdown content for this workflow as a playbook
+    pub fn to_markdown(&self) -> String {
+        let preconditions = self.description.as_deref().unwrap_or("None recorded.");
+
+        let mut steps = String::new();
+        for (idx, step) in self.steps.iter().enumerate() {
+            steps.push_str(&format!("{}. {}\n", idx + 1, step.description));
+            if let Some(action) = &step.action {
+                steps.push_str(&format!("   - Action: `{}`\n", action));
+            }
+            steps.push_str(&format!("   - Expected: {}\n", step.outcome));
+        }
+
+        let verification = self
+            .steps
+            .last()
+            .map(|step| step.outcome.clone())
+            .unwrap_or_else(|| "All steps complete.".to_string());
+
+        format!(
+            "## Preconditions\n\n{}\n\n## Steps\n\n{}\n## Verification\n\n{}",
+            preconditions, steps, verification
+        )
+    }
+}
+
 /// A single step in a workflow
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct WorkflowStep {
@@ -249,6 +288,35 @@ mod tests {
         assert_eq!(workflow.steps[0].action, Some("cargo fmt".to_string()));
     }
 
+    #[test]
+    fn test_workflow_entity_to_markdown() {
+        let workflow = WorkflowEntity {
+            title: "Tool workflow: read → edit → shell".to_string(),
+            description: None,
+            steps: vec![
+                WorkflowStep {
+                    description: "Use the read tool".to_string(),
+                    action: None,
+                    outcome: "Succeeded in 2 of 2 runs".to_string(),
+                },
+                WorkflowStep {
+                    description: "Use the shell tool".to_string(),
+                    action: Some("cargo test".to_string()),
+                    outcome: "Tests pass".to_string(),
+                },
+            ],
+            event_ids: vec![],
+        };
+
+        assert_eq!(workflow.doc_id(), "playbook.tool-workflow-read-edit-shell");
+
+        let md = workflow.to_markdown();
+        assert!(md.contains("## Preconditions"));
+        assert!(md.contains("1. Use the read tool"));
+        assert!(md.contains("   - Action: `cargo test`"));
+        assert!(md.contains("## Verification\n\nTests pass"));
+    }
+
     #[test]
     fn test_adr_update_to_markdown() {
         let adr = AdrUpdate {
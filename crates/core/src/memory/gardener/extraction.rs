@@ -7,17 +7,84 @@ use crate::memory::gardener::config::ExtractionConfig;
 use crate::memory::gardener::entities::{
     CommandEntity, CommandOutcome, DecisionEntity, GotchaCategory, GotchaEntity, WorkflowEntity, WorkflowStep,
 };
-use crate::session::{Event, LoggedEvent};
+use crate::memory::kinds::EvidenceSource;
+use crate::session::{Event, LoggedEvent, PatchStatus};
 
 use std::collections::HashMap;
 
+/// Tools whose successful results count as an edit toward fixing a failure
+const EDIT_TOOLS: &[&str] = &["edit", "multiedit", "write", "patch"];
+
+/// Longest tool-call sequence considered as a workflow
+const MAX_SEQUENCE_LENGTH: usize = 8;
+
 /// Extracted entities from a session
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct ExtractedEntities {
     pub commands: Vec<CommandEntity>,
     pub gotchas: Vec<GotchaEntity>,
     pub decisions: Vec<DecisionEntity>,
     pub workflows: Vec<WorkflowEntity>,
+    /// Tool and files behind each tool event, keyed by event ID
+    pub sources: HashMap<String, EvidenceSource>,
+}
+
+impl ExtractedEntities {
+    /// Sources for the given provenance event IDs, skipping events without one
+    pub fn sources_for(&self, event_ids: &[String]) -> Vec<EvidenceSource> {
+        let mut sources: Vec<EvidenceSource> = Vec::new();
+        for event_id in event_ids {
+            let key = event_id.split(":err:").next().unwrap_or(event_id);
+            if let Some(source) = self.sources.get(key)
+                && !sources.iter().any(|existing| existing.event == source.event)
+            {
+                sources.push(source.clone());
+            }
+        }
+        sources
+    }
+}
+
+/// A tool call in session order, reduced to what workflow detection needs
+#[derive(Debug, Clone)]
+struct ToolCallRecord {
+    /// Tool name
+    tool: String,
+    /// Command or file the call acted on, if any
+    action: Option<String>,
+    /// Source event ID
+    event_id: String,
+    /// Whether the call's result reported success, once seen
+    success: Option<bool>,
+}
+
+/// A failed command waiting for a later success to resolve it
+#[derive(Debug, Clone)]
+struct PendingFailure {
+    /// The first failing run of the command
+    command: CommandEntity,
+    /// How many times the command failed before being resolved
+    attempts: usize,
+    /// Files edited since the first failure
+    edited_files: Vec<String>,
+    /// Events that edited those files
+    edit_event_ids: Vec<String>,
+}
+
+impl PendingFailure {
+    fn new(command: CommandEntity) -> Self {
+        Self { command, attempts: 1, edited_files: Vec::new(), edit_event_ids: Vec::new() }
+    }
+
+    /// Record files edited while the failure was outstanding
+    fn record_edit(&mut self, files: impl IntoIterator<Item = String>, event_id: String) {
+        for file in files {
+            if !self.edited_files.contains(&file) {
+                self.edited_files.push(file);
+            }
+        }
+        self.edit_event_ids.push(event_id);
+    }
 }
 
 /// Extracts durable knowledge entities from session events
@@ -42,19 +109,56 @@ impl EntityExtractor {
         let mut commands = Vec::new();
         let mut gotchas = Vec::new();
         let mut decisions = Vec::new();
-        let mut pending_tool_calls: HashMap<String, (String, serde_json::Value)> = HashMap::new();
         let mut command_sequences: Vec<Vec<CommandEntity>> = Vec::new();
         let mut current_sequence: Vec<CommandEntity> = Vec::new();
 
-        let mut last_failed_command: Option<CommandEntity> = None;
-
-        for (idx, logged_event) in events.iter().enumerate() {
+        let mut last_failed_command: Option<PendingFailure> = None;
+        let mut pending_edit_paths: HashMap<String, String> = HashMap::new();
+        let mut tool_calls: Vec<ToolCallRecord> = Vec::new();
+        let mut unanswered_calls: HashMap<String, usize> = HashMap::new();
+        let mut sources = HashMap::new();
+        let mut call_files: HashMap<String, Vec<String>> = HashMap::new();
+
+        for logged_event in events {
+            let event_id = format!("{}_{}", logged_event.session_id, logged_event.seq);
+            if let Some((tool, files)) = Self::evidence_source(&logged_event.event, &mut call_files) {
+                sources.insert(event_id.clone(), EvidenceSource::new(event_id.clone(), tool, files));
+            }
+            if let Event::ToolResult { tool, success, tool_call_id, .. } = &logged_event.event
+                && let Some(index) = unanswered_calls.remove(&Self::call_key(tool, tool_call_id.as_deref()))
+            {
+                tool_calls[index].success = Some(*success);
+            }
             match &logged_event.event {
-                Event::ToolCall { tool, arguments } => {
-                    pending_tool_calls.insert(format!("{}_{}", idx, tool), (tool.clone(), arguments.clone()));
+                Event::ToolCall { tool, arguments, tool_call_id } => {
+                    unanswered_calls.insert(Self::call_key(tool, tool_call_id.as_deref()), tool_calls.len());
+                    tool_calls.push(ToolCallRecord {
+                        tool: tool.clone(),
+                        action: Self::tool_action(arguments),
+                        event_id: event_id.clone(),
+                        success: None,
+                    });
+                    if EDIT_TOOLS.contains(&tool.as_str())
+                        && let Some(path) = Self::edited_path(arguments)
+                    {
+                        pending_edit_paths.insert(Self::call_key(tool, tool_call_id.as_deref()), path);
+                    }
                 }
 
-                Event::ToolResult { tool, result, success, error } => {
+                Event::ToolResult { tool, success, tool_call_id, .. } if EDIT_TOOLS.contains(&tool.as_str()) => {
+                    let path = pending_edit_paths.remove(&Self::call_key(tool, tool_call_id.as_deref()));
+                    if *success && let Some(failure) = last_failed_command.as_mut() {
+                        failure.record_edit(path, event_id);
+                    }
+                }
+
+                Event::Patch { files, status: PatchStatus::Applied, .. } => {
+                    if let Some(failure) = last_failed_command.as_mut() {
+                        failure.record_edit(files.iter().cloned(), event_id);
+                    }
+                }
+
+                Event::ToolResult { tool, result, success, error, .. } => {
                     if tool == "shell"
                         && let Some(cmd_entity) = self.extract_shell_command(
                             result,
@@ -64,25 +168,35 @@ impl EntityExtractor {
                             logged_event.seq,
                         )
                     {
-                        if !*success {
-                            last_failed_command = Some(cmd_entity.clone());
-                        } else {
-                            if let Some(failed_cmd) = &last_failed_command
-                                && self.is_resolution_attempt(&failed_cmd.command, &cmd_entity.command)
-                            {
-                                if let Some(gotcha) =
-                                    self.extract_gotcha(failed_cmd, &cmd_entity, &logged_event.session_id)
-                                {
-                                    gotchas.push(gotcha);
-                                }
-                                last_failed_command = None;
-                            }
-
-                            current_sequence.push(cmd_entity.clone());
-                            commands.push(cmd_entity);
-                        }
+                        self.record_command(
+                            cmd_entity,
+                            &logged_event.session_id,
+                            &mut last_failed_command,
+                            &mut commands,
+                            &mut gotchas,
+                            &mut current_sequence,
+                        );
+                    }
+                }
+
+                Event::ShellCommand { command, args, working_dir, exit_code, .. } => {
+                    if let Some(cmd_entity) = self.extract_shell_event(
+                        command,
+                        args,
+                        working_dir,
+                        *exit_code,
+                        &logged_event.session_id,
+                        logged_event.seq,
+                    ) {
+                        self.record_command(
+                            cmd_entity,
+                            &logged_event.session_id,
+                            &mut last_failed_command,
+                            &mut commands,
+                            &mut gotchas,
+                            &mut current_sequence,
+                        );
                     }
-                    pending_tool_calls.retain(|k, _| !k.starts_with(&format!("{}_", idx)));
                 }
 
                 Event::ModelMessage { content, .. } => {
@@ -107,9 +221,44 @@ impl EntityExtractor {
             }
         }
 
-        let workflows = self.extract_workflows(&command_sequences, events);
+        if let Some(failure) = last_failed_command {
+            gotchas.push(self.extract_unresolved_gotcha(&failure));
+        }
+
+        let mut workflows = self.extract_workflows(&command_sequences, events);
+        workflows.extend(self.extract_tool_sequence_workflows(&tool_calls));
 
-        ExtractedEntities { commands, gotchas, decisions, workflows }
+        ExtractedEntities { commands, gotchas, decisions, workflows, sources }
+    }
+
+    /// Key pairing a tool call with its result
+    ///
+    /// Logs written before calls carried ids fall back to the tool name, which
+    /// pairs correctly as long as calls to the same tool do not overlap.
+    fn call_key(tool: &str, tool_call_id: Option<&str>) -> String {
+        tool_call_id.unwrap_or(tool).to_string()
+    }
+
+    /// The tool behind an event and the files it touched
+    ///
+    /// A tool result inherits the files named by the call it answers, which
+    /// `call_files` tracks by [`Self::call_key`].
+    fn evidence_source(event: &Event, call_files: &mut HashMap<String, Vec<String>>) -> Option<(String, Vec<String>)> {
+        match event {
+            Event::ToolCall { tool, arguments, tool_call_id } => {
+                let files: Vec<String> = Self::edited_path(arguments).into_iter().collect();
+                call_files.insert(Self::call_key(tool, tool_call_id.as_deref()), files.clone());
+                Some((tool.clone(), files))
+            }
+            Event::ToolResult { tool, tool_call_id, .. } => {
+                let files = call_files.remove(&Self::call_key(tool, tool_call_id.as_deref()));
+                Some((tool.clone(), files.unwrap_or_default()))
+            }
+            Event::Patch { files, .. } => Some(("patch".to_string(), files.clone())),
+            Event::ShellCommand { .. } => Some(("shell".to_string(), Vec::new())),
+            Event::FileRead { file_path, .. } => Some(("read".to_string(), vec![file_path.clone()])),
+            _ => None,
+        }
     }
 
     /// Extract a shell command from tool result
@@ -130,6 +279,72 @@ impl EntityExtractor {
         Some(CommandEntity { command: cmd.to_string(), cwd, args: vec![], outcome, event_ids: vec![event_id] })
     }
 
+    /// Extract a command from a `ShellCommand` event
+    ///
+    /// Commands without an exit code never finished, so their outcome is unknown
+    /// and they are skipped.
+    fn extract_shell_event(
+        &self, command: &str, args: &[String], working_dir: &std::path::Path, exit_code: Option<i32>, session_id: &str,
+        seq: u64,
+    ) -> Option<CommandEntity> {
+        let exit_code = exit_code?;
+        let outcome = if exit_code == 0 { CommandOutcome::Success } else { CommandOutcome::Failure };
+
+        let full_command =
+            if args.is_empty() { command.to_string() } else { format!("{} {}", command, args.join(" ")) };
+
+        let mut event_id = format!("{}_{}", session_id, seq);
+        if outcome == CommandOutcome::Failure {
+            event_id.push_str(&format!(":err:exit code {}", exit_code));
+        }
+
+        Some(CommandEntity {
+            command: full_command,
+            cwd: Some(working_dir.display().to_string()),
+            args: args.to_vec(),
+            outcome,
+            event_ids: vec![event_id],
+        })
+    }
+
+    /// Route a command by outcome
+    ///
+    /// Successful commands are kept as recommended commands and may resolve the
+    /// last failure into a gotcha. Failed commands are held back; repeats of the
+    /// same failure are counted, and one that is never resolved becomes a gotcha
+    /// on its own.
+    fn record_command(
+        &self, cmd_entity: CommandEntity, session_id: &str, last_failed_command: &mut Option<PendingFailure>,
+        commands: &mut Vec<CommandEntity>, gotchas: &mut Vec<GotchaEntity>, current_sequence: &mut Vec<CommandEntity>,
+    ) {
+        if cmd_entity.outcome == CommandOutcome::Failure {
+            match last_failed_command {
+                Some(failure) if self.is_resolution_attempt(&failure.command.command, &cmd_entity.command) => {
+                    failure.attempts += 1;
+                    failure.command.event_ids.extend(cmd_entity.event_ids);
+                }
+                _ => {
+                    if let Some(previous) = last_failed_command.replace(PendingFailure::new(cmd_entity)) {
+                        gotchas.push(self.extract_unresolved_gotcha(&previous));
+                    }
+                }
+            }
+            return;
+        }
+
+        if let Some(failure) = last_failed_command.as_ref()
+            && self.is_resolution_attempt(&failure.command.command, &cmd_entity.command)
+        {
+            if let Some(gotcha) = self.extract_gotcha(failure, &cmd_entity, session_id) {
+                gotchas.push(gotcha);
+            }
+            *last_failed_command = None;
+        }
+
+        current_sequence.push(cmd_entity.clone());
+        commands.push(cmd_entity);
+    }
+
     /// Check if a command is an attempt to resolve a previous failure
     fn is_resolution_attempt(&self, failed_cmd: &str, resolution_cmd: &str) -> bool {
         let failed_base = failed_cmd.split_whitespace().next().unwrap_or("");
@@ -138,10 +353,46 @@ impl EntityExtractor {
     }
 
     /// Extract a gotcha from a failed command and its resolution
+    ///
+    /// When files were edited between the failure and the success, the edits
+    /// are the fix; otherwise the succeeding command itself is.
     fn extract_gotcha(
-        &self, failed: &CommandEntity, resolution: &CommandEntity, session_id: &str,
+        &self, failure: &PendingFailure, resolution: &CommandEntity, session_id: &str,
     ) -> Option<GotchaEntity> {
-        let category = self.classify_gotcha(&failed.command);
+        let category = self.classify_gotcha(&failure.command.command);
+        let issue = self.describe_failure(failure);
+
+        let resolution_text = if failure.edited_files.is_empty() {
+            format!("Fixed with: {}", resolution.command)
+        } else {
+            format!(
+                "Fixed by editing {}, then `{}` succeeded",
+                failure.edited_files.join(", "),
+                resolution.command
+            )
+        };
+
+        let mut event_ids = failure.command.event_ids.clone();
+        event_ids.extend(failure.edit_event_ids.clone());
+        event_ids.extend(resolution.event_ids.clone());
+        event_ids.push(format!("session:{}", session_id));
+
+        Some(GotchaEntity { issue, resolution: resolution_text, category, event_ids })
+    }
+
+    /// Extract a gotcha from a failed command that was never resolved
+    fn extract_unresolved_gotcha(&self, failure: &PendingFailure) -> GotchaEntity {
+        GotchaEntity {
+            issue: self.describe_failure(failure),
+            resolution: "No resolution recorded; avoid relying on this command".to_string(),
+            category: self.classify_gotcha(&failure.command.command),
+            event_ids: failure.command.event_ids.clone(),
+        }
+    }
+
+    /// Describe a failure, including its error and how often it repeated
+    fn describe_failure(&self, failure: &PendingFailure) -> String {
+        let failed = &failure.command;
         let error_context = failed
             .event_ids
             .iter()
@@ -149,19 +400,34 @@ impl EntityExtractor {
             .next()
             .unwrap_or("");
 
-        let issue = if error_context.is_empty() {
-            format!("Command failed: {}", failed.command)
+        let failed_text = if failure.attempts > 1 {
+            format!("Command failed {} times: {}", failure.attempts, failed.command)
         } else {
-            format!("Command failed: {} - Error: {}", failed.command, error_context)
+            format!("Command failed: {}", failed.command)
         };
 
-        let resolution_text = format!("Fixed with: {}", resolution.command);
+        if error_context.is_empty() {
+            failed_text
+        } else {
+            format!("{} - Error: {}", failed_text, error_context)
+        }
+    }
 
-        let mut event_ids = failed.event_ids.clone();
-        event_ids.extend(resolution.event_ids.clone());
-        event_ids.push(format!("session:{}", session_id));
+    /// Command or file a tool call acted on, if its arguments name one
+    fn tool_action(arguments: &serde_json::Value) -> Option<String> {
+        ["cmd", "command"]
+            .iter()
+            .find_map(|key| arguments.get(*key).and_then(|v| v.as_str()))
+            .map(|command| command.to_string())
+            .or_else(|| Self::edited_path(arguments))
+    }
 
-        Some(GotchaEntity { issue, resolution: resolution_text, category, event_ids })
+    /// Path targeted by an edit tool call, if its arguments name one
+    fn edited_path(arguments: &serde_json::Value) -> Option<String> {
+        ["file_path", "path"]
+            .iter()
+            .find_map(|key| arguments.get(*key).and_then(|v| v.as_str()))
+            .map(|path| path.to_string())
     }
 
     /// Classify a gotcha into a category
@@ -306,6 +572,97 @@ impl EntityExtractor {
         workflows
     }
 
+    /// Extract workflows from ordered tool-call sequences that repeat in a session
+    ///
+    /// Sequences run from `min_workflow_steps` up to [`MAX_SEQUENCE_LENGTH`]
+    /// calls. Longer sequences are preferred; a shorter sequence contained in one
+    /// that was already extracted is skipped. Sequences of a single repeated tool
+    /// are not workflows.
+    fn extract_tool_sequence_workflows(&self, calls: &[ToolCallRecord]) -> Vec<WorkflowEntity> {
+        let min_len = self.config.min_workflow_steps.max(2);
+        let min_reps = self.config.min_sequence_repetitions.max(2);
+        let names: Vec<&str> = calls.iter().map(|c| c.tool.as_str()).collect();
+
+        let mut selected: Vec<&[&str]> = Vec::new();
+        let mut workflows = Vec::new();
+
+        for len in (min_len..=(names.len() / min_reps).min(MAX_SEQUENCE_LENGTH)).rev() {
+            let mut order: Vec<&[&str]> = Vec::new();
+            let mut starts: HashMap<&[&str], Vec<usize>> = HashMap::new();
+
+            for start in 0..=names.len() - len {
+                let window = &names[start..start + len];
+                let positions = starts.entry(window).or_insert_with(|| {
+                    order.push(window);
+                    Vec::new()
+                });
+                if positions.last().is_none_or(|&last| start >= last + len) {
+                    positions.push(start);
+                }
+            }
+
+            for window in order {
+                let positions = &starts[window];
+                let distinct_tools = window.iter().collect::<std::collections::HashSet<_>>().len();
+                if positions.len() < min_reps
+                    || distinct_tools < 2
+                    || selected.iter().any(|longer| longer.windows(len).any(|w| w == window))
+                {
+                    continue;
+                }
+
+                selected.push(window);
+                workflows.push(self.build_tool_sequence_workflow(calls, window, positions));
+            }
+        }
+
+        workflows
+    }
+
+    /// Build a workflow from each occurrence of a repeated tool-call sequence
+    fn build_tool_sequence_workflow(
+        &self, calls: &[ToolCallRecord], window: &[&str], positions: &[usize],
+    ) -> WorkflowEntity {
+        let first = positions[0];
+        let steps = window
+            .iter()
+            .enumerate()
+            .map(|(offset, tool)| WorkflowStep {
+                description: format!("Use the {} tool", tool),
+                action: calls[first + offset].action.clone(),
+                outcome: Self::step_outcome(calls, positions, offset),
+            })
+            .collect();
+
+        let event_ids = positions
+            .iter()
+            .flat_map(|&start| calls[start..start + window.len()].iter().map(|c| c.event_id.clone()))
+            .collect();
+
+        WorkflowEntity {
+            title: format!("Tool workflow: {}", window.join(" → ")),
+            description: Some(format!(
+                "Tool-call sequence repeated {} times in one session",
+                positions.len()
+            )),
+            steps,
+            event_ids,
+        }
+    }
+
+    /// Observed outcome of one step across every run of a tool-call sequence
+    fn step_outcome(calls: &[ToolCallRecord], positions: &[usize], offset: usize) -> String {
+        let results: Vec<bool> = positions
+            .iter()
+            .filter_map(|&start| calls[start + offset].success)
+            .collect();
+        if results.is_empty() {
+            return "No result recorded".to_string();
+        }
+        let succeeded = results.iter().filter(|&&success| success).count();
+        format!("Succeeded in {} of {} runs", succeeded, results.len())
+    }
+
     /// Generate a workflow title from a command sequence
     fn generate_workflow_title(&self, sequence: &[CommandEntity], _events: &[LoggedEvent]) -> String {
         let commands: Vec<&str> = sequence.iter().map(|c| c.command.as_str()).collect();
@@ -492,7 +849,11 @@ mod tests {
             create_test_event(
                 0,
                 "test_session",
-                Event::ToolCall { tool: "shell".to_string(), arguments: json!({"cmd": "cargo test"}) },
+                Event::ToolCall {
+                    tool: "shell".to_string(),
+                    arguments: json!({"cmd": "cargo test"}),
+                    tool_call_id: None,
+                },
             ),
             create_test_event(
                 1,
@@ -502,6 +863,7 @@ mod tests {
                     result: json!({"cmd": "cargo test", "exit_code": 0}),
                     success: true,
                     error: None,
+                    tool_call_id: None,
                 },
             ),
             create_test_event(
@@ -535,6 +897,7 @@ mod tests {
                     result: json!({"cmd": "cargo build", "exit_code": 1}),
                     success: false,
                     error: Some("error: feature not found".to_string()),
+                    tool_call_id: None,
                 },
             ),
             create_test_event(
@@ -545,6 +908,7 @@ mod tests {
                     result: json!({"cmd": "cargo build --features foo", "exit_code": 0}),
                     success: true,
                     error: None,
+                    tool_call_id: None,
                 },
             ),
         ];
@@ -557,6 +921,423 @@ mod tests {
         assert_eq!(entities.gotchas[0].category, GotchaCategory::Build);
     }
 
+    #[test]
+    fn test_extract_shell_command_events() {
+        let extractor = EntityExtractor::new();
+        let shell = |command: &str, args: &[&str], exit_code| Event::ShellCommand {
+            command: command.to_string(),
+            args: args.iter().map(|a| a.to_string()).collect(),
+            working_dir: std::path::PathBuf::from("/workspace"),
+            exit_code,
+            output_ref: None,
+        };
+
+        let events = vec![
+            create_test_event(0, "test_session", shell("cargo", &["test"], Some(0))),
+            create_test_event(1, "test_session", shell("npm", &["run", "lint"], Some(2))),
+            create_test_event(2, "test_session", shell("cargo", &["run"], None)),
+        ];
+
+        let entities = extractor.extract(&events);
+
+        assert_eq!(entities.commands.len(), 1);
+        assert_eq!(entities.commands[0].command, "cargo test");
+        assert_eq!(entities.commands[0].args, vec!["test".to_string()]);
+        assert_eq!(entities.commands[0].cwd, Some("/workspace".to_string()));
+        assert_eq!(entities.commands[0].outcome, CommandOutcome::Success);
+
+        assert_eq!(entities.gotchas.len(), 1);
+        assert!(entities.gotchas[0].issue.contains("npm run lint"));
+        assert!(entities.gotchas[0].issue.contains("exit code 2"));
+        assert!(entities.gotchas[0].resolution.contains("No resolution"));
+    }
+
+    #[test]
+    fn test_extract_shell_command_failure_resolution() {
+        let extractor = EntityExtractor::new();
+        let shell = |args: &[&str], exit_code| Event::ShellCommand {
+            command: "cargo".to_string(),
+            args: args.iter().map(|a| a.to_string()).collect(),
+            working_dir: std::path::PathBuf::from("/workspace"),
+            exit_code: Some(exit_code),
+            output_ref: None,
+        };
+
+        let events = vec![
+            create_test_event(0, "test_session", shell(&["build"], 101)),
+            create_test_event(1, "test_session", shell(&["build", "--features", "foo"], 0)),
+        ];
+
+        let entities = extractor.extract(&events);
+
+        assert_eq!(entities.commands.len(), 1);
+        assert_eq!(entities.gotchas.len(), 1);
+        assert!(entities.gotchas[0].issue.contains("exit code 101"));
+        assert!(entities.gotchas[0].resolution.contains("--features foo"));
+    }
+
+    #[test]
+    fn test_extract_gotcha_from_repeated_failure_and_fix() {
+        let extractor = EntityExtractor::new();
+        let failing_build = || Event::ToolResult {
+            tool: "shell".to_string(),
+            result: json!({"cmd": "cargo build", "exit_code": 101}),
+            success: false,
+            error: Some("error[E0425]: cannot find value `config`".to_string()),
+            tool_call_id: None,
+        };
+
+        let events = vec![
+            create_test_event(0, "test_session", failing_build()),
+            create_test_event(1, "test_session", failing_build()),
+            create_test_event(
+                2,
+                "test_session",
+                Event::ToolCall {
+                    tool: "edit".to_string(),
+                    arguments: json!({"file_path": "src/main.rs", "old_string": "config", "new_string": "cfg"}),
+                    tool_call_id: None,
+                },
+            ),
+            create_test_event(
+                3,
+                "test_session",
+                Event::ToolResult {
+                    tool: "edit".to_string(),
+                    result: json!("Edited src/main.rs"),
+                    success: true,
+                    error: None,
+                    tool_call_id: None,
+                },
+            ),
+            create_test_event(
+                4,
+                "test_session",
+                Event::Patch {
+                    name: "fix imports".to_string(),
+                    status: PatchStatus::Applied,
+                    files: vec!["src/lib.rs".to_string()],
+                    diff: String::new(),
+                },
+            ),
+            create_test_event(
+                5,
+                "test_session",
+                Event::ToolResult {
+                    tool: "shell".to_string(),
+                    result: json!({"cmd": "cargo build", "exit_code": 0}),
+                    success: true,
+                    error: None,
+                    tool_call_id: None,
+                },
+            ),
+        ];
+
+        let entities = extractor.extract(&events);
+
+        assert_eq!(entities.gotchas.len(), 1);
+        let gotcha = &entities.gotchas[0];
+        assert_eq!(gotcha.category, GotchaCategory::Build);
+        assert!(gotcha.issue.contains("failed 2 times: cargo build"));
+        assert!(gotcha.issue.contains("E0425"));
+        assert_eq!(
+            gotcha.resolution,
+            "Fixed by editing src/main.rs, src/lib.rs, then `cargo build` succeeded"
+        );
+        assert!(gotcha.event_ids.contains(&"test_session_3".to_string()));
+        assert!(gotcha.event_ids.contains(&"test_session_4".to_string()));
+
+        assert_eq!(entities.commands.len(), 1);
+        assert_eq!(entities.commands[0].outcome, CommandOutcome::Success);
+    }
+
+    #[test]
+    fn test_failed_edit_is_not_a_fix() {
+        let extractor = EntityExtractor::new();
+
+        let events = vec![
+            create_test_event(
+                0,
+                "test_session",
+                Event::ToolResult {
+                    tool: "shell".to_string(),
+                    result: json!({"cmd": "pytest tests/", "exit_code": 1}),
+                    success: false,
+                    error: None,
+                    tool_call_id: None,
+                },
+            ),
+            create_test_event(
+                1,
+                "test_session",
+                Event::ToolCall {
+                    tool: "write".to_string(),
+                    arguments: json!({"file_path": "tests/conftest.py"}),
+                    tool_call_id: None,
+                },
+            ),
+            create_test_event(
+                2,
+                "test_session",
+                Event::ToolResult {
+                    tool: "write".to_string(),
+                    result: json!(null),
+                    success: false,
+                    error: Some("permission denied".to_string()),
+                    tool_call_id: None,
+                },
+            ),
+            create_test_event(
+                3,
+                "test_session",
+                Event::ToolResult {
+                    tool: "shell".to_string(),
+                    result: json!({"cmd": "pytest tests/ -x", "exit_code": 0}),
+                    success: true,
+                    error: None,
+                    tool_call_id: None,
+                },
+            ),
+        ];
+
+        let entities = extractor.extract(&events);
+
+        assert_eq!(entities.gotchas.len(), 1);
+        assert_eq!(entities.gotchas[0].category, GotchaCategory::Test);
+        assert_eq!(entities.gotchas[0].resolution, "Fixed with: pytest tests/ -x");
+    }
+
+    #[test]
+    fn test_overlapping_edit_calls_pair_by_call_id() {
+        let extractor = EntityExtractor::new();
+        let edit_call = |id: &str, path: &str| Event::ToolCall {
+            tool: "edit".to_string(),
+            arguments: json!({"file_path": path}),
+            tool_call_id: Some(id.to_string()),
+        };
+        let edit_result = |id: &str, success: bool| Event::ToolResult {
+            tool: "edit".to_string(),
+            result: json!(null),
+            success,
+            error: None,
+            tool_call_id: Some(id.to_string()),
+        };
+
+        let events = vec![
+            create_test_event(
+                0,
+                "test_session",
+                Event::ToolResult {
+                    tool: "shell".to_string(),
+                    result: json!({"cmd": "cargo build", "exit_code": 101}),
+                    success: false,
+                    error: None,
+                    tool_call_id: None,
+                },
+            ),
+            create_test_event(1, "test_session", edit_call("call_a", "src/a.rs")),
+            create_test_event(2, "test_session", edit_call("call_b", "src/b.rs")),
+            create_test_event(3, "test_session", edit_result("call_b", false)),
+            create_test_event(4, "test_session", edit_result("call_a", true)),
+            create_test_event(
+                5,
+                "test_session",
+                Event::ToolResult {
+                    tool: "shell".to_string(),
+                    result: json!({"cmd": "cargo build", "exit_code": 0}),
+                    success: true,
+                    error: None,
+                    tool_call_id: None,
+                },
+            ),
+        ];
+
+        let entities = extractor.extract(&events);
+
+        assert_eq!(entities.gotchas.len(), 1);
+        assert_eq!(
+            entities.gotchas[0].resolution,
+            "Fixed by editing src/a.rs, then `cargo build` succeeded"
+        );
+    }
+
+    #[test]
+    fn test_extract_records_event_sources() {
+        let extractor = EntityExtractor::new();
+        let events = vec![
+            create_test_event(
+                0,
+                "s",
+                Event::ToolCall {
+                    tool: "edit".to_string(),
+                    arguments: json!({"file_path": "src/lib.rs"}),
+                    tool_call_id: None,
+                },
+            ),
+            create_test_event(
+                1,
+                "s",
+                Event::ToolResult {
+                    tool: "edit".to_string(),
+                    result: json!(null),
+                    success: true,
+                    error: None,
+                    tool_call_id: None,
+                },
+            ),
+            create_test_event(
+                2,
+                "s",
+                Event::ToolResult {
+                    tool: "shell".to_string(),
+                    result: json!({"cmd": "cargo build", "exit_code": 1}),
+                    success: false,
+                    error: Some("linker error".to_string()),
+                    tool_call_id: None,
+                },
+            ),
+            create_test_event(3, "s", Event::UserMessage { content: "thanks".to_string() }),
+        ];
+
+        let entities = extractor.extract(&events);
+
+        assert_eq!(
+            entities.sources["s_0"],
+            EvidenceSource::new("s_0", "edit", vec!["src/lib.rs".to_string()])
+        );
+        assert_eq!(entities.sources["s_1"].files, vec!["src/lib.rs".to_string()]);
+        assert!(!entities.sources.contains_key("s_3"));
+
+        let ids = vec!["s_2:err:linker error".to_string(), "s_1".to_string(), "s_2".to_string()];
+        let sources = entities.sources_for(&ids);
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].tool, "shell");
+        assert_eq!(sources[1].describe(), "editing src/lib.rs via edit tool");
+    }
+
+    #[test]
+    fn test_event_sources_pair_results_by_call_id() {
+        let extractor = EntityExtractor::new();
+        let call = |seq, id: &str, path: &str| {
+            create_test_event(
+                seq,
+                "s",
+                Event::ToolCall {
+                    tool: "write".to_string(),
+                    arguments: json!({"file_path": path}),
+                    tool_call_id: Some(id.to_string()),
+                },
+            )
+        };
+        let result = |seq, id: &str| {
+            create_test_event(
+                seq,
+                "s",
+                Event::ToolResult {
+                    tool: "write".to_string(),
+                    result: json!(null),
+                    success: true,
+                    error: None,
+                    tool_call_id: Some(id.to_string()),
+                },
+            )
+        };
+        let events = vec![
+            call(0, "call_a", "a.txt"),
+            call(1, "call_b", "b.txt"),
+            result(2, "call_b"),
+            result(3, "call_a"),
+        ];
+
+        let entities = extractor.extract(&events);
+
+        assert_eq!(entities.sources["s_2"].files, vec!["b.txt".to_string()]);
+        assert_eq!(entities.sources["s_3"].files, vec!["a.txt".to_string()]);
+    }
+
+    #[test]
+    fn test_extract_repeated_tool_sequence_workflow() {
+        let extractor = EntityExtractor::new();
+        let call = |seq, tool: &str, arguments| {
+            create_test_event(
+                seq,
+                "test_session",
+                Event::ToolCall { tool: tool.to_string(), arguments, tool_call_id: None },
+            )
+        };
+
+        let shell_result = |seq, exit_code: i32| {
+            create_test_event(
+                seq,
+                "test_session",
+                Event::ToolResult {
+                    tool: "shell".to_string(),
+                    result: json!({"cmd": "cargo test", "exit_code": exit_code}),
+                    success: exit_code == 0,
+                    error: None,
+                    tool_call_id: None,
+                },
+            )
+        };
+
+        let events = vec![
+            call(0, "read", json!({"file_path": "src/lib.rs"})),
+            call(1, "edit", json!({"file_path": "src/lib.rs"})),
+            call(2, "shell", json!({"cmd": "cargo test"})),
+            shell_result(3, 101),
+            call(4, "grep", json!({"pattern": "todo"})),
+            call(5, "read", json!({"file_path": "src/main.rs"})),
+            call(6, "edit", json!({"file_path": "src/main.rs"})),
+            call(7, "shell", json!({"cmd": "cargo test"})),
+            shell_result(8, 0),
+        ];
+
+        let entities = extractor.extract(&events);
+
+        assert_eq!(entities.workflows.len(), 1);
+        let workflow = &entities.workflows[0];
+        assert_eq!(workflow.title, "Tool workflow: read → edit → shell");
+        assert_eq!(workflow.steps.len(), 3);
+        assert_eq!(workflow.steps[0].action, Some("src/lib.rs".to_string()));
+        assert_eq!(workflow.steps[0].outcome, "No result recorded");
+        assert_eq!(workflow.steps[2].action, Some("cargo test".to_string()));
+        assert_eq!(workflow.steps[2].outcome, "Succeeded in 1 of 2 runs");
+        assert_eq!(workflow.event_ids.len(), 6);
+        assert!(workflow.event_ids.contains(&"test_session_7".to_string()));
+    }
+
+    #[test]
+    fn test_tool_sequence_thresholds() {
+        let tools = ["read", "edit", "read", "edit", "read", "edit"];
+        let events: Vec<LoggedEvent> = tools
+            .iter()
+            .enumerate()
+            .map(|(seq, tool)| {
+                create_test_event(
+                    seq as Seq,
+                    "test_session",
+                    Event::ToolCall { tool: tool.to_string(), arguments: json!({}), tool_call_id: None },
+                )
+            })
+            .collect();
+
+        let default = EntityExtractor::new().extract(&events);
+        assert!(default.workflows.is_empty());
+
+        let config = ExtractionConfig { min_workflow_steps: 2, min_sequence_repetitions: 3, ..Default::default() };
+        let entities = EntityExtractor::with_config(config).extract(&events);
+        assert_eq!(entities.workflows.len(), 1);
+        assert_eq!(entities.workflows[0].title, "Tool workflow: read → edit");
+
+        let config = ExtractionConfig { min_workflow_steps: 2, min_sequence_repetitions: 4, ..Default::default() };
+        assert!(
+            EntityExtractor::with_config(config)
+                .extract(&events)
+                .workflows
+                .is_empty()
+        );
+    }
+
     #[test]
     fn test_extract_workflow_from_sequence() {
         let extractor = EntityExtractor::with_config(ExtractionConfig { min_workflow_steps: 2, ..Default::default() });
@@ -570,6 +1351,7 @@ mod tests {
                     result: json!({"cmd": "cargo fmt", "exit_code": 0}),
                     success: true,
                     error: None,
+                    tool_call_id: None,
                 },
             ),
             create_test_event(
@@ -580,6 +1362,7 @@ mod tests {
                     result: json!({"cmd": "cargo clippy", "exit_code": 0}),
                     success: true,
                     error: None,
+                    tool_call_id: None,
                 },
             ),
             create_test_event(
@@ -590,6 +1373,7 @@ mod tests {
                     result: json!({"cmd": "cargo test", "exit_code": 0}),
                     success: true,
                     error: None,
+                    tool_call_id: None,
                 },
             ),
             create_test_event(3, "test_session", Event::UserMessage { content: "Done".to_string() }),
@@ -281,6 +281,9 @@ impl HygieneChecker {
     }
 }
 
+/// Word-overlap similarity above which two facts are treated as duplicates
+pub const DUPLICATE_SIMILARITY_THRESHOLD: f64 = 0.8;
+
 /// Detects and handles duplicate facts
 #[derive(Debug, Clone)]
 pub struct FactDeduplicator {
@@ -320,7 +323,7 @@ impl FactDeduplicator {
 
                 if let Ok(Some(other_content)) = store.get_by_id(&other_entry.id, &MemoryKind::Fact) {
                     let similarity = self.compute_similarity(&content, &other_content);
-                    if similarity > 0.8 {
+                    if similarity > DUPLICATE_SIMILARITY_THRESHOLD {
                         duplicates.push((other_entry.id.clone(), similarity));
                     }
                 }
@@ -345,7 +348,7 @@ impl FactDeduplicator {
     }
 
     /// Compute text similarity (simple word overlap)
-    fn compute_similarity(&self, a: &str, b: &str) -> f64 {
+    pub fn compute_similarity(&self, a: &str, b: &str) -> f64 {
         let words_a: std::collections::HashSet<&str> = a.split_whitespace().collect();
         let words_b: std::collections::HashSet<&str> = b.split_whitespace().collect();
 
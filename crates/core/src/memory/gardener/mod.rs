@@ -15,7 +15,7 @@ pub use config::{
     DeduplicationStrategy, DriftConfig, ExtractionConfig, GardenerConfig, HygieneConfig, RecapConfig, SizeLimits,
 };
 pub use consolidation::{ConsolidationJob, ConsolidationResult, FactUpdate};
-pub use drift::{DriftDetector, DriftResult, StalenessInfo, StalenessSeverity};
+pub use drift::{DriftDetector, DriftResult, StalenessInfo, StalenessSeverity, VerifyAllResult};
 pub use entities::{
     AdrUpdate, CommandEntity, CommandOutcome, DecisionEntity, GotchaCategory, GotchaEntity, WorkflowEntity,
     WorkflowStep,
@@ -25,6 +25,7 @@ pub use hygiene::{DuplicateGroup, FactDeduplicator, HygieneChecker, HygieneRule,
 pub use recap::{RecapGenerator, RecapResult, RecapStats, RecapTemplate};
 
 use crate::error::{Error, Result};
+use crate::memory::kinds::VerificationStatus;
 use crate::memory::paths::MemoryPaths;
 use crate::{AgentDir, SessionId};
 
@@ -50,17 +51,23 @@ impl Gardener {
         Self { paths, config }
     }
 
-    /// Run consolidation on a completed session
+    /// Gardener configuration
+    pub fn config(&self) -> &GardenerConfig {
+        &self.config
+    }
+
+    /// Run consolidation on a session's events
     ///
-    /// This extracts entities from the session events and generates
-    /// memory updates (facts, ADRs, playbooks) for user approval.
+    /// This extracts entities from the events in `events_file` and generates
+    /// memory updates (facts, ADRs, playbooks) for user approval. The session
+    /// does not need to have ended.
     pub async fn consolidate_session(
-        &self, session_id: &str, _events_file: &std::path::Path,
+        &self, session_id: &str, events_file: &std::path::Path,
     ) -> Result<ConsolidationResult> {
         let session_id =
             SessionId::from_timestamp(session_id).map_err(|e| Error::Other(format!("Invalid session ID: {}", e)))?;
         let agent_dir = AgentDir::new(&self.paths.root);
-        let job = ConsolidationJob::new(&session_id, &agent_dir, self.config.clone());
+        let job = ConsolidationJob::new(&session_id, &agent_dir, self.config.clone()).with_events_file(events_file);
         job.run(&self.paths).await
     }
 
@@ -106,14 +113,51 @@ impl Gardener {
         let commit_id = commit.id().to_string();
 
         let manifest = self.load_manifest()?;
+        self.set_verification(&manifest, doc_id, VerificationStatus::Verified, Some(commit_id))
+    }
+
+    /// Re-verify every stale document at the current commit
+    ///
+    /// Documents whose referenced files only changed cosmetically are marked
+    /// verified. Documents with meaningful changes are marked stale and
+    /// returned for review instead.
+    pub fn verify_all_stale(&self) -> Result<VerifyAllResult> {
+        let repo = git2::Repository::discover(&self.paths.root)
+            .map_err(|e| Error::Other(format!("Failed to open git repo: {}", e)))?;
+        let detector = DriftDetector::new(&repo)?;
+        let manifest = self.load_manifest()?;
+        let drift = detector.check_all(&manifest)?;
+
+        let mut result = VerifyAllResult { current_commit: drift.current_commit.clone(), ..Default::default() };
+        for info in drift.stale_docs {
+            if detector.has_meaningful_changes(&info, &drift.current_commit)? {
+                self.set_verification(&manifest, &info.doc_id, VerificationStatus::Stale, None)?;
+                result.needs_review.push(info);
+            } else {
+                let commit = Some(drift.current_commit.clone());
+                self.set_verification(&manifest, &info.doc_id, VerificationStatus::Verified, commit)?;
+                result.verified.push(info.doc_id);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Rewrite a document's verification status, and its verified commit when given
+    fn set_verification(
+        &self, manifest: &crate::memory::MemoryManifest, doc_id: &str, status: VerificationStatus,
+        commit: Option<String>,
+    ) -> Result<()> {
         if let Some(entry) = manifest.docs.iter().find(|e| e.id == doc_id) {
             let path = self.paths.root.join(&entry.path);
             let content = std::fs::read_to_string(&path).map_err(Error::Io)?;
             let mut doc = crate::memory::document::MemoryDoc::parse(&content)
                 .map_err(|e| Error::Parse(format!("Failed to parse document: {}", e)))?;
 
-            doc.frontmatter.verification.last_verified_commit = Some(commit_id.clone());
-            doc.frontmatter.verification.status = crate::memory::kinds::VerificationStatus::Verified;
+            if let Some(commit) = commit {
+                doc.frontmatter.verification.last_verified_commit = Some(commit);
+            }
+            doc.frontmatter.verification.status = status;
 
             let new_content = format!("{}", doc);
             std::fs::write(&path, new_content).map_err(Error::Io)?;
@@ -250,6 +294,69 @@ mod tests {
         assert!(!gardener.config.hygiene_on_change);
     }
 
+    fn commit_files(repo: &git2::Repository, files: &[&str], message: &str) -> String {
+        let mut index = repo.index().unwrap();
+        for file in files {
+            index.add_path(std::path::Path::new(file)).unwrap();
+        }
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let signature = git2::Signature::now("test", "test@example.com").unwrap();
+        let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+        repo.commit(Some("HEAD"), &signature, &signature, message, &tree, &parents)
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_verify_all_stale() {
+        let (temp, gardener) = create_test_gardener();
+        let repo = git2::Repository::init(temp.path()).unwrap();
+        std::fs::create_dir_all(temp.path().join("src")).unwrap();
+        std::fs::write(temp.path().join("src/lib.rs"), "pub fn build() {}\n").unwrap();
+        std::fs::write(temp.path().join("src/main.rs"), "fn main() {}\n").unwrap();
+        let first = commit_files(&repo, &["src/lib.rs", "src/main.rs"], "initial");
+
+        for (id, file, referenced) in [
+            ("fact.lib", "lib.md", "src/lib.rs"),
+            ("fact.main", "main.md", "src/main.rs"),
+        ] {
+            let body = format!("Build logic lives in {}.", referenced);
+            let mut doc = crate::memory::MemoryDoc::new(id, id, crate::memory::MemoryKind::Fact, vec![], &body);
+            doc.frontmatter.verification.last_verified_commit = Some(first.clone());
+            std::fs::write(gardener.paths.facts.join(file), format!("{}", doc)).unwrap();
+        }
+        crate::memory::MemoryManifest::rebuild(&gardener.paths)
+            .unwrap()
+            .save(&gardener.paths)
+            .unwrap();
+
+        std::fs::write(temp.path().join("src/lib.rs"), "pub fn  build()\n{}\n\n").unwrap();
+        std::fs::write(temp.path().join("src/main.rs"), "fn main() { run(); }\n").unwrap();
+        let second = commit_files(&repo, &["src/lib.rs", "src/main.rs"], "update");
+
+        let result = gardener.verify_all_stale().unwrap();
+        assert_eq!(result.current_commit, second);
+        assert_eq!(result.verified, vec!["fact.lib".to_string()]);
+        assert_eq!(result.needs_review.len(), 1);
+        assert_eq!(result.needs_review[0].doc_id, "fact.main");
+
+        let read = |file: &str| {
+            let content = std::fs::read_to_string(gardener.paths.facts.join(file)).unwrap();
+            crate::memory::MemoryDoc::parse(&content)
+                .unwrap()
+                .frontmatter
+                .verification
+        };
+        let lib = read("lib.md");
+        assert_eq!(lib.status, VerificationStatus::Verified);
+        assert_eq!(lib.last_verified_commit, Some(second));
+        let main = read("main.md");
+        assert_eq!(main.status, VerificationStatus::Stale);
+        assert_eq!(main.last_verified_commit, Some(first));
+    }
+
     #[test]
     fn test_memory_store_read_document() {
         let temp = TempDir::new().unwrap();
@@ -217,6 +217,7 @@ fn parse_timestamp(s: &str) -> Option<chrono::DateTime<Utc>> {
 mod tests {
     use super::*;
     use crate::session::{Event, LoggedEvent, Seq};
+    use std::collections::HashMap;
 
     fn create_test_event(seq: Seq, session_id: &str, event: Event) -> LoggedEvent {
         LoggedEvent { seq, session_id: session_id.to_string(), timestamp: "2026-01-22T10:00:00Z".to_string(), event }
@@ -243,7 +244,13 @@ mod tests {
             ),
         ];
 
-        let entities = ExtractedEntities { commands: vec![], gotchas: vec![], decisions: vec![], workflows: vec![] };
+        let entities = ExtractedEntities {
+            commands: vec![],
+            gotchas: vec![],
+            decisions: vec![],
+            workflows: vec![],
+            sources: HashMap::new(),
+        };
 
         let stats = generator.calculate_stats(&events, &entities);
         assert_eq!(stats.event_count, 2);
@@ -261,7 +268,13 @@ mod tests {
             Event::UserMessage { content: "Test message".to_string() },
         )];
 
-        let entities = ExtractedEntities { commands: vec![], gotchas: vec![], decisions: vec![], workflows: vec![] };
+        let entities = ExtractedEntities {
+            commands: vec![],
+            gotchas: vec![],
+            decisions: vec![],
+            workflows: vec![],
+            sources: HashMap::new(),
+        };
 
         let recap = generator.render_recap("test-session", &events, &entities, &[]);
         assert!(recap.is_ok());
@@ -39,6 +39,25 @@ impl Display for MemoryKind {
 }
 
 impl MemoryKind {
+    pub const VALUES: &[MemoryKind] = &[
+        MemoryKind::Core,
+        MemoryKind::Fact,
+        MemoryKind::Adr,
+        MemoryKind::Playbook,
+        MemoryKind::Recap,
+    ];
+
+    /// Name used for this kind in frontmatter
+    pub fn as_str(self) -> &'static str {
+        match self {
+            MemoryKind::Core => "core",
+            MemoryKind::Fact => "fact",
+            MemoryKind::Adr => "adr",
+            MemoryKind::Playbook => "playbook",
+            MemoryKind::Recap => "recap",
+        }
+    }
+
     /// Get the directory name for this memory kind
     pub fn dir_name(self) -> &'static str {
         match self {
@@ -90,6 +109,21 @@ pub enum VerificationStatus {
 }
 
 impl VerificationStatus {
+    pub const VALUES: &[VerificationStatus] = &[
+        VerificationStatus::Verified,
+        VerificationStatus::Stale,
+        VerificationStatus::Unknown,
+    ];
+
+    /// Name used for this status in frontmatter
+    pub fn as_str(self) -> &'static str {
+        match self {
+            VerificationStatus::Verified => "verified",
+            VerificationStatus::Stale => "stale",
+            VerificationStatus::Unknown => "unknown",
+        }
+    }
+
     /// Check if the document is considered current
     pub fn is_current(self) -> bool {
         matches!(self, VerificationStatus::Verified)
@@ -101,6 +135,52 @@ impl VerificationStatus {
     }
 }
 
+/// How trustworthy a document's verification is, as shown to the user
+///
+/// Unlike [`VerificationStatus`], this separates documents that were never
+/// checked against the repository from ones verified against an older commit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationState {
+    /// Verified against the current repository state
+    Verified,
+    /// Verified against an older commit; the repository has changed since
+    Stale,
+    /// Never verified (no `last_verified_commit`)
+    NeverVerified,
+}
+
+impl VerificationState {
+    /// Classify a verification record
+    pub fn from_parts(last_verified_commit: Option<&str>, status: VerificationStatus) -> Self {
+        match (last_verified_commit, status) {
+            (None, _) => Self::NeverVerified,
+            (Some(_), VerificationStatus::Verified) => Self::Verified,
+            (Some(_), _) => Self::Stale,
+        }
+    }
+
+    /// Re-check a verified state against the repository's current HEAD
+    ///
+    /// A document verified at any commit other than `head` has gone stale
+    /// since it was indexed. Without a known commit or HEAD the state is kept.
+    pub fn at_head(self, verified_commit: Option<&str>, head: Option<&str>) -> Self {
+        match (self, verified_commit, head) {
+            (Self::Verified, Some(commit), Some(head)) if commit != head => Self::Stale,
+            _ => self,
+        }
+    }
+
+    /// Short human-readable label
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Verified => "verified",
+            Self::Stale => "stale",
+            Self::NeverVerified => "never verified",
+        }
+    }
+}
+
 /// Provenance information for a memory document
 ///
 /// Tracks the source events, patches, and commits that created or modified
@@ -116,6 +196,9 @@ pub struct Provenance {
     /// Commit hashes related to this document
     #[serde(default)]
     pub commits: Vec<String>,
+    /// Tool and files behind each event, captured at consolidation time
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<EvidenceSource>,
 }
 
 impl Provenance {
@@ -142,6 +225,23 @@ impl Provenance {
         self
     }
 
+    /// Record the source of an event, replacing any earlier record for it
+    pub fn add_source(&mut self, source: EvidenceSource) {
+        match self.sources.iter_mut().find(|existing| existing.event == source.event) {
+            Some(existing) => *existing = source,
+            None => self.sources.push(source),
+        }
+    }
+
+    /// Describe where the document came from, e.g. "derived from editing src/x.rs via edit tool"
+    pub fn summary(&self) -> Option<String> {
+        if self.sources.is_empty() {
+            return None;
+        }
+        let described: Vec<String> = self.sources.iter().map(EvidenceSource::describe).collect();
+        Some(format!("derived from {}", described.join("; ")))
+    }
+
     /// Check if the provenance is empty
     pub fn is_empty(&self) -> bool {
         self.events.is_empty() && self.patches.is_empty() && self.commits.is_empty()
@@ -153,6 +253,38 @@ impl Provenance {
     }
 }
 
+/// The tool that produced a piece of evidence and the files it touched
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EvidenceSource {
+    /// Event ID this source describes
+    pub event: String,
+    /// Tool that produced the event (e.g. `edit`, `shell`)
+    pub tool: String,
+    /// Files the tool read or modified
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<String>,
+}
+
+impl EvidenceSource {
+    /// Create a source record for an event
+    pub fn new(event: impl Into<String>, tool: impl Into<String>, files: Vec<String>) -> Self {
+        Self { event: event.into(), tool: tool.into(), files }
+    }
+
+    /// Describe the source, e.g. "editing src/x.rs via edit tool"
+    pub fn describe(&self) -> String {
+        if self.files.is_empty() {
+            return format!("{} tool", self.tool);
+        }
+
+        let verb = match self.tool.as_str() {
+            "edit" | "multiedit" | "write" | "patch" => "editing",
+            _ => "reading",
+        };
+        format!("{} {} via {} tool", verb, self.files.join(", "), self.tool)
+    }
+}
+
 /// Verification metadata for a memory document
 ///
 /// Tracks verification state against repository commits.
@@ -184,6 +316,16 @@ impl Verification {
     pub fn is_verified(&self) -> bool {
         self.status.is_current()
     }
+
+    /// Classify as verified, stale or never verified
+    pub fn state(&self) -> VerificationState {
+        VerificationState::from_parts(self.last_verified_commit.as_deref(), self.status)
+    }
+
+    /// Classify against the repository's current HEAD (see [`VerificationState::at_head`])
+    pub fn state_at(&self, head: Option<&str>) -> VerificationState {
+        self.state().at_head(self.last_verified_commit.as_deref(), head)
+    }
 }
 
 impl Default for Verification {
@@ -240,6 +382,16 @@ impl Default for SessionMeta {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_as_str_matches_serde_names() {
+        for kind in MemoryKind::VALUES {
+            assert_eq!(serde_json::to_value(kind).unwrap(), kind.as_str());
+        }
+        for status in VerificationStatus::VALUES {
+            assert_eq!(serde_json::to_value(status).unwrap(), status.as_str());
+        }
+    }
+
     #[test]
     fn test_memory_kind_dir_name() {
         assert_eq!(MemoryKind::Core.dir_name(), "core");
@@ -370,6 +522,26 @@ mod tests {
         assert_eq!(prov.len(), 2);
     }
 
+    #[test]
+    fn test_provenance_sources() {
+        let mut prov = Provenance::new().with_event("s_1").with_event("s_2");
+        assert_eq!(prov.summary(), None);
+
+        prov.add_source(EvidenceSource::new("s_1", "edit", vec!["src/x.rs".to_string()]));
+        prov.add_source(EvidenceSource::new("s_2", "shell", Vec::new()));
+        prov.add_source(EvidenceSource::new("s_1", "write", vec!["src/y.rs".to_string()]));
+
+        assert_eq!(prov.sources.len(), 2);
+        assert_eq!(
+            prov.summary().as_deref(),
+            Some("derived from editing src/y.rs via write tool; shell tool")
+        );
+        assert_eq!(
+            EvidenceSource::new("s_3", "read", vec!["Cargo.toml".to_string()]).describe(),
+            "reading Cargo.toml via read tool"
+        );
+    }
+
     #[test]
     fn test_verification_new() {
         let verif = Verification::new();
@@ -394,6 +566,33 @@ mod tests {
         assert!(!verif.is_verified());
     }
 
+    #[test]
+    fn test_verification_state() {
+        assert_eq!(Verification::new().state(), VerificationState::NeverVerified);
+        assert_eq!(Verification::verified("abc123").state(), VerificationState::Verified);
+        assert_eq!(Verification::stale("abc123").state(), VerificationState::Stale);
+
+        let unchecked =
+            Verification { last_verified_commit: Some("abc123".to_string()), status: VerificationStatus::Unknown };
+        assert_eq!(unchecked.state(), VerificationState::Stale);
+
+        let verified_without_commit = Verification { last_verified_commit: None, status: VerificationStatus::Verified };
+        assert_eq!(verified_without_commit.state(), VerificationState::NeverVerified);
+        assert_eq!(VerificationState::NeverVerified.label(), "never verified");
+    }
+
+    #[test]
+    fn test_verification_state_at_head() {
+        let verified = Verification::verified("abc123");
+        assert_eq!(verified.state_at(Some("abc123")), VerificationState::Verified);
+        assert_eq!(verified.state_at(Some("def456")), VerificationState::Stale);
+        assert_eq!(verified.state_at(None), VerificationState::Verified);
+        assert_eq!(
+            Verification::new().state_at(Some("def456")),
+            VerificationState::NeverVerified
+        );
+    }
+
     #[test]
     fn test_verification_default() {
         let verif = Verification::default();
@@ -5,7 +5,7 @@
 
 use crate::error::{Error, Result};
 use crate::memory::document::MemoryDoc;
-use crate::memory::kinds::MemoryKind;
+use crate::memory::kinds::{EvidenceSource, MemoryKind, VerificationState, VerificationStatus};
 use crate::memory::paths::MemoryPaths;
 
 use chrono::{DateTime, Utc};
@@ -48,6 +48,9 @@ pub struct ProvenanceInfo {
     pub patches: Vec<String>,
     #[serde(default)]
     pub commits: Vec<String>,
+    /// Tool and files behind each event
+    #[serde(default)]
+    pub sources: Vec<EvidenceSource>,
 }
 
 /// Verification information for manifest
@@ -57,6 +60,15 @@ pub struct VerificationInfo {
     pub status: String,
 }
 
+impl VerificationInfo {
+    /// Classify as verified, stale or never verified
+    pub fn state(&self) -> VerificationState {
+        let status = serde_json::from_value(serde_json::Value::String(self.status.clone()))
+            .unwrap_or(VerificationStatus::Unknown);
+        VerificationState::from_parts(self.last_verified_commit.as_deref(), status)
+    }
+}
+
 /// Statistics about the memory store
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ManifestStats {
@@ -221,6 +233,7 @@ impl MemoryManifest {
                 events: doc.frontmatter.provenance.events,
                 patches: doc.frontmatter.provenance.patches,
                 commits: doc.frontmatter.provenance.commits,
+                sources: doc.frontmatter.provenance.sources,
             },
             verification: VerificationInfo {
                 last_verified_commit: doc.frontmatter.verification.last_verified_commit,
@@ -368,6 +381,22 @@ Test consequences
         assert_eq!(manifest.stats.by_kind.get("adr"), Some(&1));
     }
 
+    #[test]
+    fn test_verification_info_state() {
+        let (_temp, paths) = create_test_memory_files();
+        let manifest = MemoryManifest::rebuild(&paths).unwrap();
+        let fact = manifest.by_id("fact.test.coverage").unwrap();
+        assert_eq!(fact.verification.state(), VerificationState::NeverVerified);
+
+        let info = |commit: &str, status: &str| VerificationInfo {
+            last_verified_commit: Some(commit.to_string()),
+            status: status.to_string(),
+        };
+        assert_eq!(info("abc123", "verified").state(), VerificationState::Verified);
+        assert_eq!(info("abc123", "stale").state(), VerificationState::Stale);
+        assert_eq!(info("abc123", "bogus").state(), VerificationState::Stale);
+    }
+
     #[test]
     fn test_manifest_by_kind() {
         let (_temp, paths) = create_test_memory_files();
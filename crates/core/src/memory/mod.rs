@@ -44,6 +44,7 @@ mod kinds;
 mod lint;
 mod manifest;
 mod paths;
+mod pins;
 mod procedural;
 mod retriever;
 mod semantic;
@@ -57,17 +58,20 @@ pub use gardener::{
     DecisionEntity, DeduplicationStrategy, DriftConfig, DriftDetector, DriftResult, DuplicateGroup, EntityExtractor,
     ExtractedEntities, ExtractionConfig, FactDeduplicator, FactUpdate as GardenerFactUpdate, Gardener, GotchaCategory,
     GotchaEntity, HygieneChecker, HygieneConfig, HygieneRule, HygieneViolation, RecapConfig, RecapGenerator,
-    RecapResult, RecapStats, RecapTemplate, Severity, SizeLimits, StalenessInfo, StalenessSeverity, WorkflowEntity,
-    WorkflowStep,
+    RecapResult, RecapStats, RecapTemplate, Severity, SizeLimits, StalenessInfo, StalenessSeverity, VerifyAllResult,
+    WorkflowEntity, WorkflowStep,
+};
+pub use kinds::{
+    EvidenceSource, MemoryKind, Provenance, SessionMeta, Verification, VerificationState, VerificationStatus,
 };
-pub use kinds::{MemoryKind, Provenance, SessionMeta, Verification, VerificationStatus};
 pub use lint::{LintDiagnostic, LintRule, LintSeverity as MemoryLintSeverity, MemoryLinter};
 pub use manifest::{ManifestEntry, ManifestStats, MemoryManifest, ProvenanceInfo, VerificationInfo};
 pub use paths::{
     CORE_LOCAL_MEMORY_FILE, CORE_MEMORY_DIR, CORE_MEMORY_FILE, DECISIONS_DIR, EPISODIC_MEMORY_DIR, FACTS_DIR,
-    INDEXES_DIR, MANIFEST_FILE, MEMORY_DIR, MemoryPaths, PLAYBOOKS_DIR, PROCEDURAL_MEMORY_DIR, SEMANTIC_MEMORY_DIR,
-    TAGS_FILE, THUNDERUS_DIR_NAME,
+    INDEXES_DIR, MANIFEST_FILE, MEMORY_DIR, MemoryPaths, PINS_FILE, PLAYBOOKS_DIR, PROCEDURAL_MEMORY_DIR,
+    SEMANTIC_MEMORY_DIR, TAGS_FILE, THUNDERUS_DIR_NAME,
 };
+pub use pins::{MAX_PINNED_DOCS, PinnedMemory};
 pub use procedural::{
     IssueSeverity, NewPlaybook, PlaybookDoc, PlaybookIssue, PlaybookSections, PlaybookUpdate, ProceduralMemory,
 };
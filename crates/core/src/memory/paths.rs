@@ -47,6 +47,9 @@ pub const MANIFEST_FILE: &str = "manifest.json";
 /// Tags index filename
 pub const TAGS_FILE: &str = "tags.json";
 
+/// Pinned documents filename
+pub const PINS_FILE: &str = "pins.json";
+
 /// Standard memory subdirectory structure
 ///
 /// Provides deterministic paths for all memory directories and files.
@@ -112,6 +115,11 @@ impl MemoryPaths {
         self.indexes.join(TAGS_FILE)
     }
 
+    /// Get path to pins.json file
+    pub fn pins_file(&self) -> PathBuf {
+        self.indexes.join(PINS_FILE)
+    }
+
     /// Get episodic directory for a specific year-month
     pub fn episodic_month_dir(&self, year_month: &str) -> PathBuf {
         self.episodic.join(year_month)
@@ -134,6 +142,13 @@ impl MemoryPaths {
     pub fn is_memory_path(&self, path: &Path) -> bool {
         path.starts_with(&self.root_memory)
     }
+
+    /// Current HEAD commit of the repository containing the memory, if any
+    pub fn head_commit(&self) -> Option<String> {
+        let repo = git2::Repository::discover(&self.root).ok()?;
+        let commit = repo.head().ok()?.peel_to_commit().ok()?;
+        Some(commit.id().to_string())
+    }
 }
 
 #[cfg(test)]
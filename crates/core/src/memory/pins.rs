@@ -0,0 +1,154 @@
+//! Pinned memory documents
+//!
+//! Pinned documents are always injected into retrieved context regardless of
+//! query score. The pin list is persisted under `.thunderus/memory/indexes/`
+//! so pins survive across sessions.
+
+use crate::error::{Error, Result};
+use crate::memory::paths::MemoryPaths;
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+/// Maximum number of documents that can be pinned at once
+pub const MAX_PINNED_DOCS: usize = 10;
+
+/// Persisted list of pinned memory document IDs
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PinnedMemory {
+    /// Pinned document IDs in the order they were pinned
+    #[serde(default)]
+    pub ids: Vec<String>,
+}
+
+impl PinnedMemory {
+    /// Load the pin list from disk
+    ///
+    /// Returns an empty list if the pin file does not exist yet.
+    pub fn load(paths: &MemoryPaths) -> Result<Self> {
+        let pins_file = paths.pins_file();
+        if !pins_file.exists() {
+            return Ok(Self::default());
+        }
+
+        let content =
+            fs::read_to_string(&pins_file).map_err(|e| Error::Other(format!("Failed to read pin list: {}", e)))?;
+
+        serde_json::from_str(&content).map_err(|e| Error::Parse(format!("Failed to parse pin list: {}", e)))
+    }
+
+    /// Save the pin list to disk
+    pub fn save(&self, paths: &MemoryPaths) -> Result<()> {
+        let pins_file = paths.pins_file();
+
+        if let Some(parent) = pins_file.parent() {
+            fs::create_dir_all(parent).map_err(|e| Error::Other(format!("Failed to create pin directory: {}", e)))?;
+        }
+
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| Error::Parse(format!("Failed to serialize pin list: {}", e)))?;
+
+        fs::write(&pins_file, content).map_err(|e| Error::Other(format!("Failed to write pin list: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Check if a document is pinned
+    pub fn contains(&self, id: &str) -> bool {
+        self.ids.iter().any(|pinned| pinned == id)
+    }
+
+    /// Pin a document
+    ///
+    /// Returns `false` without modifying the list if the document is already
+    /// pinned. Fails if [`MAX_PINNED_DOCS`] would be exceeded.
+    pub fn pin(&mut self, id: impl Into<String>) -> Result<bool> {
+        let id = id.into();
+        if self.contains(&id) {
+            return Ok(false);
+        }
+
+        if self.ids.len() >= MAX_PINNED_DOCS {
+            return Err(Error::Validation(format!(
+                "Cannot pin more than {} memory documents",
+                MAX_PINNED_DOCS
+            )));
+        }
+
+        self.ids.push(id);
+        Ok(true)
+    }
+
+    /// Unpin a document, returning whether it was pinned
+    pub fn unpin(&mut self, id: &str) -> bool {
+        let before = self.ids.len();
+        self.ids.retain(|pinned| pinned != id);
+        self.ids.len() != before
+    }
+
+    /// Number of pinned documents
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Check if no documents are pinned
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_pinned_memory_load_missing() {
+        let temp = TempDir::new().unwrap();
+        let paths = MemoryPaths::from_thunderus_root(temp.path());
+
+        let pins = PinnedMemory::load(&paths).unwrap();
+        assert!(pins.is_empty());
+    }
+
+    #[test]
+    fn test_pinned_memory_save_load() {
+        let temp = TempDir::new().unwrap();
+        let paths = MemoryPaths::from_thunderus_root(temp.path());
+
+        let mut pins = PinnedMemory::default();
+        assert!(pins.pin("fact.test.coverage").unwrap());
+        assert!(pins.pin("adr.0001").unwrap());
+        pins.save(&paths).unwrap();
+
+        let loaded = PinnedMemory::load(&paths).unwrap();
+        assert_eq!(loaded, pins);
+        assert!(paths.pins_file().exists());
+    }
+
+    #[test]
+    fn test_pinned_memory_pin_unpin() {
+        let mut pins = PinnedMemory::default();
+
+        assert!(pins.pin("fact.a").unwrap());
+        assert!(!pins.pin("fact.a").unwrap());
+        assert_eq!(pins.len(), 1);
+        assert!(pins.contains("fact.a"));
+
+        assert!(pins.unpin("fact.a"));
+        assert!(!pins.unpin("fact.a"));
+        assert!(pins.is_empty());
+    }
+
+    #[test]
+    fn test_pinned_memory_limit() {
+        let mut pins = PinnedMemory::default();
+        for i in 0..MAX_PINNED_DOCS {
+            pins.pin(format!("fact.{}", i)).unwrap();
+        }
+
+        let result = pins.pin("fact.overflow");
+        assert!(matches!(result, Err(Error::Validation(_))));
+        assert_eq!(pins.len(), MAX_PINNED_DOCS);
+    }
+}
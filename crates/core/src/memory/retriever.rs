@@ -3,7 +3,7 @@
 //! The MemoryRetriever queries the memory store before agent actions
 //! and retrieves relevant chunks with full citation information.
 
-use crate::memory::MemoryKind;
+use crate::memory::{MemoryKind, VerificationState};
 use std::pin::Pin;
 use std::time::Instant;
 
@@ -20,6 +20,20 @@ pub struct RetrievalPolicy {
     pub always_include: Vec<MemoryKind>,
     /// Enable semantic/vector retrieval fallback
     pub enable_vector_fallback: bool,
+    /// Document IDs always included ahead of query results
+    pub pinned_ids: Vec<String>,
+    /// Maximum tokens pinned documents may consume
+    pub max_pinned_tokens: usize,
+    /// Token budget measured with each document's `token_count`
+    ///
+    /// When set, hits are selected greedily by score until the budget is
+    /// exhausted. When unset, `max_tokens` is applied to chunk content length.
+    pub token_budget: Option<usize>,
+    /// Score penalty added to never-verified documents
+    ///
+    /// Scores are BM25 (lower = better), so a positive penalty ranks
+    /// never-verified documents below verified and stale ones. `0.0` disables it.
+    pub never_verified_penalty: f64,
 }
 
 impl Default for RetrievalPolicy {
@@ -30,6 +44,33 @@ impl Default for RetrievalPolicy {
             score_threshold: -5.0,
             always_include: vec![MemoryKind::Core],
             enable_vector_fallback: false,
+            pinned_ids: Vec::new(),
+            max_pinned_tokens: 1000,
+            token_budget: None,
+            never_verified_penalty: 0.0,
+        }
+    }
+}
+
+impl RetrievalPolicy {
+    /// Effective token budget for a retrieval
+    pub fn effective_budget(&self) -> usize {
+        self.token_budget.unwrap_or(self.max_tokens)
+    }
+
+    /// Adjust a hit's score for its verification state
+    pub fn adjusted_score(&self, score: f64, verification: Option<VerificationState>) -> f64 {
+        match verification {
+            Some(VerificationState::NeverVerified) => score + self.never_verified_penalty,
+            _ => score,
+        }
+    }
+
+    /// Token cost of a chunk under this policy
+    pub fn chunk_cost(&self, chunk: &RetrievedChunk) -> usize {
+        match self.token_budget {
+            Some(_) if chunk.token_count > 0 => chunk.token_count,
+            _ => chunk.content.len() / 4,
         }
     }
 }
@@ -41,6 +82,8 @@ pub struct RetrievalResult {
     pub chunks: Vec<RetrievedChunk>,
     /// Total tokens used
     pub total_tokens: usize,
+    /// Token budget the retrieval was allowed to use
+    pub token_budget: usize,
     /// Query that was executed
     pub query: String,
     /// Search execution time (ms)
@@ -48,7 +91,7 @@ pub struct RetrievalResult {
 }
 
 /// A chunk of memory with full citation
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct RetrievedChunk {
     /// The memory content (may be truncated)
     pub content: String,
@@ -62,6 +105,8 @@ pub struct RetrievedChunk {
     pub kind: MemoryKind,
     /// Relevance score
     pub score: f64,
+    /// Approximate token count of the source document
+    pub token_count: usize,
 }
 
 /// Stop words for query term extraction
@@ -74,6 +119,13 @@ pub const STOP_WORDS: &[&str] = &[
     "over", "after", "before", "between", "under", "again", "there", "here", "up", "down", "off", "out",
 ];
 
+impl RetrievalResult {
+    /// Tokens still available under the retrieval budget
+    pub fn remaining_budget(&self) -> usize {
+        self.token_budget.saturating_sub(self.total_tokens)
+    }
+}
+
 impl RetrievedChunk {
     /// Format the chunk as a markdown citation
     pub fn format_citation(&self) -> String {
@@ -163,6 +215,7 @@ impl MemoryRetriever for InMemoryRetriever {
             Ok(RetrievalResult {
                 chunks: Vec::new(),
                 total_tokens: 0,
+                token_budget: self.policy.effective_budget(),
                 query,
                 search_time_ms: start.elapsed().as_millis() as u64,
             })
@@ -179,7 +232,7 @@ pub fn format_memory_context(result: &RetrievalResult) -> String {
     if result.chunks.is_empty() {
         "No relevant memory found.".to_string()
     } else {
-        result
+        let chunks = result
             .chunks
             .iter()
             .map(|chunk| {
@@ -187,7 +240,15 @@ pub fn format_memory_context(result: &RetrievalResult) -> String {
                 format!("**{}**\n{}\n", citation, chunk.content)
             })
             .collect::<Vec<_>>()
-            .join("\n---\n")
+            .join("\n---\n");
+
+        format!(
+            "_Memory budget: {}/{} tokens used ({} remaining)_\n\n{}",
+            result.total_tokens,
+            result.token_budget,
+            result.remaining_budget(),
+            chunks
+        )
     }
 }
 
@@ -203,6 +264,8 @@ mod tests {
         assert_eq!(policy.score_threshold, -5.0);
         assert_eq!(policy.always_include, vec![MemoryKind::Core]);
         assert!(!policy.enable_vector_fallback);
+        assert!(policy.pinned_ids.is_empty());
+        assert_eq!(policy.max_pinned_tokens, 1000);
     }
 
     #[test]
@@ -214,6 +277,7 @@ mod tests {
             event_ids: vec!["evt-1".to_string()],
             kind: MemoryKind::Core,
             score: -3.5,
+            token_count: 10,
         };
 
         let citation = chunk.format_citation();
@@ -229,6 +293,7 @@ mod tests {
             event_ids: vec![],
             kind: MemoryKind::Fact,
             score: -2.0,
+            token_count: 10,
         };
 
         let citation = chunk.format_citation();
@@ -255,7 +320,13 @@ mod tests {
 
     #[test]
     fn test_format_memory_context_empty() {
-        let result = RetrievalResult { chunks: vec![], total_tokens: 0, query: "test".to_string(), search_time_ms: 10 };
+        let result = RetrievalResult {
+            chunks: vec![],
+            total_tokens: 0,
+            token_budget: 2000,
+            query: "test".to_string(),
+            search_time_ms: 10,
+        };
 
         let context = format_memory_context(&result);
         assert_eq!(context, "No relevant memory found.");
@@ -272,6 +343,7 @@ mod tests {
                     event_ids: vec![],
                     kind: MemoryKind::Core,
                     score: -1.0,
+                    token_count: 40,
                 },
                 RetrievedChunk {
                     content: "Test content 2".to_string(),
@@ -280,9 +352,11 @@ mod tests {
                     event_ids: vec![],
                     kind: MemoryKind::Fact,
                     score: -2.0,
+                    token_count: 60,
                 },
             ],
             total_tokens: 100,
+            token_budget: 2000,
             query: "test".to_string(),
             search_time_ms: 10,
         };
@@ -293,6 +367,45 @@ mod tests {
         assert!(context.contains("[Fact](semantic/FACTS/test.md#section)"));
         assert!(context.contains("Test content 2"));
         assert!(context.contains("---"));
+        assert!(context.contains("100/2000 tokens used (1900 remaining)"));
+    }
+
+    #[test]
+    fn test_retrieval_policy_chunk_cost() {
+        let chunk = RetrievedChunk {
+            content: "x".repeat(40),
+            path: "semantic/FACTS/test.md".to_string(),
+            anchor: None,
+            event_ids: vec![],
+            kind: MemoryKind::Fact,
+            score: -2.0,
+            token_count: 250,
+        };
+
+        let legacy = RetrievalPolicy::default();
+        assert_eq!(legacy.chunk_cost(&chunk), 10);
+        assert_eq!(legacy.effective_budget(), 2000);
+
+        let budgeted = RetrievalPolicy { token_budget: Some(500), ..Default::default() };
+        assert_eq!(budgeted.chunk_cost(&chunk), 250);
+        assert_eq!(budgeted.effective_budget(), 500);
+    }
+
+    #[test]
+    fn test_retrieval_policy_adjusted_score() {
+        let policy = RetrievalPolicy::default();
+        assert_eq!(
+            policy.adjusted_score(-4.0, Some(VerificationState::NeverVerified)),
+            -4.0
+        );
+
+        let policy = RetrievalPolicy { never_verified_penalty: 1.5, ..Default::default() };
+        assert_eq!(
+            policy.adjusted_score(-4.0, Some(VerificationState::NeverVerified)),
+            -2.5
+        );
+        assert_eq!(policy.adjusted_score(-4.0, Some(VerificationState::Stale)), -4.0);
+        assert_eq!(policy.adjusted_score(-4.0, None), -4.0);
     }
 
     #[tokio::test]
@@ -302,6 +415,7 @@ mod tests {
 
         assert_eq!(result.chunks.len(), 0);
         assert_eq!(result.total_tokens, 0);
+        assert_eq!(result.remaining_budget(), 2000);
         assert!(result.query.contains("test"));
         assert!(result.query.contains("query"));
     }
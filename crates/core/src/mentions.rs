@@ -0,0 +1,196 @@
+//! `@path` file mentions in user messages
+//!
+//! The fuzzy finder inserts `@path` into the input. When the message is sent,
+//! each mention is resolved against the working directory and the file's
+//! contents are appended to the prompt, so the model sees the file without a
+//! separate Read call. Mentions that cannot be inlined become warnings instead
+//! of being dropped.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Largest file, in bytes, that a mention will inline
+pub const MAX_MENTION_BYTES: u64 = 100 * 1024;
+
+/// Characters stripped from the end of a mention (sentence punctuation)
+const TRAILING_PUNCTUATION: &[char] = &[',', '.', ';', ':', '!', '?', ')', ']', '\'', '"', '`'];
+
+/// A mentioned file whose contents were inlined
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InlinedFile {
+    /// Path as written after the `@`
+    pub mention: String,
+    /// Path resolved against the working directory
+    pub path: PathBuf,
+    /// File contents
+    pub content: String,
+    /// Number of lines in `content`
+    pub line_count: usize,
+}
+
+/// A message with its file mentions expanded
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExpandedMessage {
+    /// Text to send to the model: the message followed by inlined files and warnings
+    pub prompt: String,
+    /// Files that were inlined, in mention order
+    pub files: Vec<InlinedFile>,
+    /// One warning per mention that could not be inlined
+    pub warnings: Vec<String>,
+}
+
+/// Collect the `@path` mentions in a message, in order and without duplicates
+///
+/// A mention is a whitespace-separated word starting with `@`; trailing
+/// sentence punctuation is ignored, so `see @src/lib.rs.` mentions `src/lib.rs`.
+pub fn extract_mentions(message: &str) -> Vec<String> {
+    let mut mentions: Vec<String> = Vec::new();
+    for word in message.split_whitespace() {
+        let Some(path) = word.strip_prefix('@') else {
+            continue;
+        };
+        let path = path.trim_end_matches(TRAILING_PUNCTUATION);
+        if !path.is_empty() && !mentions.iter().any(|m| m == path) {
+            mentions.push(path.to_string());
+        }
+    }
+    mentions
+}
+
+/// Inline the contents of every file mentioned in `message`
+///
+/// Relative mentions resolve against `cwd`. Paths that resolve outside `cwd`,
+/// directories, missing files, non-UTF-8 files and files over
+/// [`MAX_MENTION_BYTES`] produce a warning that is both returned and appended
+/// to the prompt. A message without mentions is
+/// returned unchanged.
+pub fn expand_mentions(message: &str, cwd: &Path) -> ExpandedMessage {
+    let mut expanded = ExpandedMessage { prompt: message.to_string(), ..Default::default() };
+    let root = cwd.canonicalize().unwrap_or_else(|_| cwd.to_path_buf());
+
+    for mention in extract_mentions(message) {
+        let path = cwd.join(&mention);
+        match read_mention(&path, &root) {
+            Ok(content) => {
+                let line_count = content.lines().count();
+                expanded.files.push(InlinedFile { mention, path, content, line_count });
+            }
+            Err(reason) => expanded
+                .warnings
+                .push(format!("Could not read @{}: {}", mention, reason)),
+        }
+    }
+
+    if !expanded.files.is_empty() {
+        expanded.prompt.push_str("\n\n## Referenced Files\n");
+        for file in &expanded.files {
+            expanded.prompt.push_str(&format!(
+                "\n### {}\n```\n{}\n```\n",
+                file.path.display(),
+                file.content.trim_end_matches('\n')
+            ));
+        }
+    }
+    for warning in &expanded.warnings {
+        expanded.prompt.push_str(&format!("\n> Warning: {}", warning));
+    }
+
+    expanded
+}
+
+fn read_mention(path: &Path, root: &Path) -> Result<String, String> {
+    let resolved = path.canonicalize().map_err(|e| e.to_string())?;
+    if !resolved.starts_with(root) {
+        return Err("path is outside the workspace".to_string());
+    }
+    let metadata = fs::metadata(path).map_err(|e| e.to_string())?;
+    if metadata.is_dir() {
+        return Err("path is a directory".to_string());
+    }
+    if metadata.len() > MAX_MENTION_BYTES {
+        return Err(format!(
+            "file is {} bytes, over the {} byte limit",
+            metadata.len(),
+            MAX_MENTION_BYTES
+        ));
+    }
+    fs::read_to_string(path).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_extract_mentions() {
+        assert_eq!(
+            extract_mentions("Compare @src/lib.rs and @src/main.rs, then fix @src/lib.rs."),
+            vec!["src/lib.rs", "src/main.rs"]
+        );
+        assert!(extract_mentions("mail me at dev@example.com or type @").is_empty());
+    }
+
+    #[test]
+    fn test_expand_mentions_inlines_files() {
+        let temp = TempDir::new().unwrap();
+        fs::write(temp.path().join("notes.txt"), "first\nsecond\n").unwrap();
+
+        let expanded = expand_mentions("Summarize @notes.txt", temp.path());
+        assert_eq!(expanded.files.len(), 1);
+        assert_eq!(expanded.files[0].line_count, 2);
+        assert_eq!(expanded.files[0].path, temp.path().join("notes.txt"));
+        assert!(expanded.warnings.is_empty());
+        assert!(
+            expanded
+                .prompt
+                .starts_with("Summarize @notes.txt\n\n## Referenced Files")
+        );
+        assert!(expanded.prompt.contains("```\nfirst\nsecond\n```"));
+    }
+
+    #[test]
+    fn test_expand_mentions_warns_on_unreadable_paths() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir(temp.path().join("src")).unwrap();
+        fs::write(temp.path().join("big.log"), vec![b'x'; MAX_MENTION_BYTES as usize + 1]).unwrap();
+
+        let expanded = expand_mentions("Check @missing.rs @src @big.log", temp.path());
+        assert!(expanded.files.is_empty());
+        assert_eq!(expanded.warnings.len(), 3);
+        assert!(expanded.warnings[1].contains("directory"));
+        assert!(expanded.warnings[2].contains("byte limit"));
+        assert!(expanded.prompt.contains("> Warning: Could not read @missing.rs"));
+    }
+
+    #[test]
+    fn test_expand_mentions_rejects_paths_outside_workspace() {
+        let outside = TempDir::new().unwrap();
+        fs::write(outside.path().join("secret.txt"), "token").unwrap();
+        let temp = TempDir::new().unwrap();
+        let workspace = temp.path().join("workspace");
+        fs::create_dir(&workspace).unwrap();
+        fs::write(temp.path().join("sibling.txt"), "private").unwrap();
+
+        let absolute = outside.path().join("secret.txt");
+        let message = format!("Read @{} and @../sibling.txt", absolute.display());
+        let expanded = expand_mentions(&message, &workspace);
+
+        assert!(expanded.files.is_empty());
+        assert_eq!(expanded.warnings.len(), 2);
+        assert!(expanded.warnings.iter().all(|w| w.contains("outside the workspace")));
+        assert!(!expanded.prompt.contains("token"));
+        assert!(!expanded.prompt.contains("private"));
+    }
+
+    #[test]
+    fn test_expand_mentions_without_mentions() {
+        let temp = TempDir::new().unwrap();
+        let expanded = expand_mentions("Just a question", temp.path());
+        assert_eq!(expanded.prompt, "Just a question");
+        assert_eq!(
+            expanded,
+            ExpandedMessage { prompt: "Just a question".to_string(), ..Default::default() }
+        );
+    }
+}
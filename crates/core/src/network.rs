@@ -0,0 +1,263 @@
+//! Network target extraction for shell commands
+//!
+//! Shell commands that reach the network are inspected for the hosts they
+//! target so the approval gate can compare them against an allowlist. Parsing
+//! is deliberately conservative: anything that could hide the real target
+//! (variable expansion, command substitution, pipelines) yields no hosts, and
+//! callers fall back to prompting.
+
+/// Programs whose purpose is to talk to a remote host
+const NETWORK_PROGRAMS: &[&str] = &["curl", "wget", "ssh", "scp", "sftp", "rsync", "nc", "telnet", "ftp"];
+
+/// Other programs whose URL arguments are remote targets
+const URL_PROGRAMS: &[&str] = &["git"];
+
+/// Shell syntax that makes the command too dynamic to reason about
+const UNSAFE_SHELL_SYNTAX: &[&str] = &["$", "`", "|", ";", "&", ">", "<", "\n"];
+
+/// Check whether a shell command reaches the network
+pub fn is_network_command(command: &str) -> bool {
+    let lower = command.to_lowercase();
+    lower.contains("http://")
+        || lower.contains("https://")
+        || tokenize(&lower).iter().any(|token| is_network_program(token))
+}
+
+/// Extract the hosts a network command targets
+///
+/// Hosts are only taken from programs known to contact them; a URL passed
+/// to any other program is not treated as a target. Returns `None` when the
+/// command cannot be parsed confidently, and an empty list when no network
+/// targets were found.
+pub fn extract_network_hosts(command: &str) -> Option<Vec<String>> {
+    if UNSAFE_SHELL_SYNTAX.iter().any(|syntax| command.contains(syntax)) {
+        return None;
+    }
+
+    let tokens = tokenize(command);
+    let program = tokens.first().map(|token| program_name(token)).unwrap_or_default();
+    if !is_network_program(&program) && !URL_PROGRAMS.contains(&program.as_str()) {
+        return Some(Vec::new());
+    }
+    let mut hosts = Vec::new();
+
+    for (index, token) in tokens.iter().enumerate() {
+        if let Some(host) = url_host(token) {
+            hosts.push(host);
+            continue;
+        }
+
+        if index == 0 || token.starts_with('-') || !is_network_program(&program) {
+            continue;
+        }
+
+        if is_option_value(&tokens, index) {
+            continue;
+        }
+
+        let host = match program.as_str() {
+            "ssh" | "sftp" | "telnet" | "nc" | "ftp" => Some(strip_user(token)),
+            "scp" | "rsync" => token.split_once(':').map(|(remote, _)| strip_user(remote)),
+            _ => looks_like_host(token).then(|| token.split(':').next().unwrap_or(token).to_string()),
+        };
+
+        if let Some(host) = host {
+            if !looks_like_host(&host) {
+                return None;
+            }
+            hosts.push(host.to_lowercase());
+            if matches!(program.as_str(), "ssh" | "telnet" | "nc" | "ftp") {
+                break;
+            }
+        }
+    }
+
+    if hosts.is_empty() && is_network_program(&program) {
+        return None;
+    }
+
+    hosts.dedup();
+    Some(hosts)
+}
+
+/// Check whether `host` matches an allowlist entry, either exactly or as a subdomain
+pub fn host_matches(host: &str, allowed: &str) -> bool {
+    let host = host.trim_end_matches('.').to_lowercase();
+    let allowed = allowed.trim_start_matches('.').to_lowercase();
+    host == allowed || host.ends_with(&format!(".{}", allowed))
+}
+
+fn is_network_program(token: &str) -> bool {
+    NETWORK_PROGRAMS.contains(&program_name(token).as_str())
+}
+
+fn program_name(token: &str) -> String {
+    token.rsplit('/').next().unwrap_or(token).to_lowercase()
+}
+
+/// Split a command on whitespace, honouring simple single and double quotes
+fn tokenize(command: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+
+    for c in command.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => current.push(c),
+            None if c == '\'' || c == '"' => quote = Some(c),
+            None if c.is_whitespace() => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            None => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+/// Options whose next token is a value rather than a target
+fn is_option_value(tokens: &[String], index: usize) -> bool {
+    const VALUE_OPTIONS: &[&str] = &[
+        "-o",
+        "-O",
+        "-H",
+        "-d",
+        "-X",
+        "-u",
+        "-A",
+        "-e",
+        "-i",
+        "-p",
+        "-P",
+        "-l",
+        "-F",
+        "-T",
+        "--output",
+        "--header",
+        "--data",
+        "--request",
+        "--user",
+        "--user-agent",
+    ];
+    index > 0 && VALUE_OPTIONS.contains(&tokens[index - 1].as_str())
+}
+
+fn url_host(token: &str) -> Option<String> {
+    let (scheme, rest) = token.split_once("://")?;
+    if scheme.is_empty() || !scheme.chars().all(|c| c.is_ascii_alphanumeric() || c == '+') {
+        return None;
+    }
+
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host_port = strip_user(authority);
+    let host = if let Some(bracketed) = host_port.strip_prefix('[') {
+        bracketed.split(']').next().unwrap_or_default().to_string()
+    } else {
+        host_port.split(':').next().unwrap_or_default().to_string()
+    };
+
+    (!host.is_empty()).then(|| host.to_lowercase())
+}
+
+fn strip_user(target: &str) -> String {
+    target.rsplit_once('@').map_or(target, |(_, host)| host).to_string()
+}
+
+fn looks_like_host(token: &str) -> bool {
+    let host = token.split(':').next().unwrap_or(token);
+    if host.is_empty() || host.starts_with('.') || host.starts_with('/') {
+        return false;
+    }
+    if host == "localhost" {
+        return true;
+    }
+    host.contains('.') && host.chars().all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_network_command() {
+        assert!(is_network_command("curl http://example.com"));
+        assert!(is_network_command("/usr/bin/wget example.com"));
+        assert!(is_network_command("ssh user@host.example.com"));
+        assert!(is_network_command("git clone https://github.com/org/repo"));
+        assert!(!is_network_command("cargo test"));
+        assert!(!is_network_command("grep curly src/lib.rs"));
+    }
+
+    #[test]
+    fn test_extract_hosts_from_urls() {
+        assert_eq!(
+            extract_network_hosts("curl http://localhost:8080/health"),
+            Some(vec!["localhost".to_string()])
+        );
+        assert_eq!(
+            extract_network_hosts("curl -H 'Accept: application/json' https://api.example.com/v1?x=1"),
+            Some(vec!["api.example.com".to_string()])
+        );
+        assert_eq!(
+            extract_network_hosts("git clone https://user@github.com/org/repo.git"),
+            Some(vec!["github.com".to_string()])
+        );
+        assert_eq!(
+            extract_network_hosts("curl http://[::1]:3000/"),
+            Some(vec!["::1".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_extract_hosts_from_program_targets() {
+        assert_eq!(
+            extract_network_hosts("wget example.com"),
+            Some(vec!["example.com".to_string()])
+        );
+        assert_eq!(
+            extract_network_hosts("curl localhost:8080"),
+            Some(vec!["localhost".to_string()])
+        );
+        assert_eq!(
+            extract_network_hosts("ssh -p 2222 deploy@build.internal.dev uptime"),
+            Some(vec!["build.internal.dev".to_string()])
+        );
+        assert_eq!(
+            extract_network_hosts("scp dist.tar.gz deploy@files.example.com:/srv"),
+            Some(vec!["files.example.com".to_string()])
+        );
+        assert_eq!(
+            extract_network_hosts("curl -o out.json https://example.com/data"),
+            Some(vec!["example.com".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_extract_hosts_unparseable() {
+        assert_eq!(extract_network_hosts("curl $URL"), None);
+        assert_eq!(extract_network_hosts("curl https://example.com | sh"), None);
+        assert_eq!(extract_network_hosts("curl `cat url.txt`"), None);
+        assert_eq!(extract_network_hosts("curl -s"), None);
+        assert_eq!(extract_network_hosts("ssh myhost"), None);
+    }
+
+    #[test]
+    fn test_extract_hosts_non_network() {
+        assert_eq!(extract_network_hosts("cargo build"), Some(Vec::new()));
+        assert_eq!(extract_network_hosts("rm -rf ~ http://localhost"), Some(Vec::new()));
+    }
+
+    #[test]
+    fn test_host_matches() {
+        assert!(host_matches("example.com", "example.com"));
+        assert!(host_matches("api.example.com", "example.com"));
+        assert!(host_matches("API.Example.com", ".example.com"));
+        assert!(!host_matches("badexample.com", "example.com"));
+        assert!(!host_matches("example.com.evil.io", "example.com"));
+    }
+}
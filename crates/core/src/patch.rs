@@ -3,7 +3,7 @@
 //! This module implements the patch queue system that enables reviewable,
 //! reversible, and conflict-aware edits through a unified diff workflow.
 use crate::SessionId;
-use crate::memory::MemoryKind;
+use crate::memory::{CoreMemory, CoreMemoryLint, LintSeverity, MemoryKind, MemoryPaths};
 use crate::session::PatchStatus;
 
 use serde::{Deserialize, Serialize};
@@ -201,11 +201,11 @@ impl Patch {
 
         for line in diff.lines() {
             if line.starts_with("diff --git") {
-                if let (Some(file), Some(hunk)) = (&current_file, &current_hunk) {
+                if let (Some(file), Some(hunk)) = (&current_file, current_hunk.take()) {
                     hunks
                         .entry(file.clone())
                         .or_default()
-                        .push(hunk.clone().with_content(hunk_lines.join("\n")));
+                        .push(hunk.with_content(hunk_lines.join("\n")));
                     hunk_lines.clear();
                 }
 
@@ -397,6 +397,9 @@ pub struct MemoryPatch {
     pub seq: u64,
     /// When the patch was created
     pub created_at: String,
+    /// Line-by-line review against the document on disk, computed when the patch is queued
+    #[serde(default)]
+    pub review: Vec<String>,
 }
 
 impl MemoryPatch {
@@ -404,7 +407,7 @@ impl MemoryPatch {
     pub fn new(id: PatchId, params: MemoryPatchParams) -> Self {
         let created_at = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
 
-        Self {
+        let mut patch = Self {
             id,
             path: params.path,
             doc_id: params.doc_id,
@@ -416,7 +419,10 @@ impl MemoryPatch {
             session_id: params.session_id,
             seq: params.seq,
             created_at,
-        }
+            review: Vec::new(),
+        };
+        patch.refresh_review();
+        patch
     }
 
     /// Approve this memory patch for application
@@ -468,11 +474,77 @@ impl MemoryPatch {
         format!("+{} -{}", added, removed)
     }
 
+    /// Line-by-line comparison of the proposed content against the file on disk
+    ///
+    /// Each line is prefixed with `+` (added), `-` (removed) or a space
+    /// (unchanged), so updates to existing documents show what the merge
+    /// keeps and what it adds. When the file does not exist yet, every line
+    /// is an addition. This is the cached result of [`MemoryPatch::refresh_review`].
+    pub fn review_lines(&self) -> &[String] {
+        &self.review
+    }
+
+    /// Recompute [`MemoryPatch::review_lines`] from the current diff and file on disk
+    pub fn refresh_review(&mut self) {
+        self.review = self.compare_with_disk();
+    }
+
+    fn compare_with_disk(&self) -> Vec<String> {
+        let current = fs::read_to_string(&self.path).unwrap_or_default();
+        let old: Vec<&str> = current.lines().collect();
+        let new: Vec<&str> = self.diff.lines().collect();
+
+        let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+        for i in (0..old.len()).rev() {
+            for j in (0..new.len()).rev() {
+                lcs[i][j] = if old[i] == new[j] { lcs[i + 1][j + 1] + 1 } else { lcs[i + 1][j].max(lcs[i][j + 1]) };
+            }
+        }
+
+        let mut lines = Vec::with_capacity(old.len().max(new.len()));
+        let (mut i, mut j) = (0, 0);
+        while i < old.len() || j < new.len() {
+            if i < old.len() && j < new.len() && old[i] == new[j] {
+                lines.push(format!(" {}", old[i]));
+                i += 1;
+                j += 1;
+            } else if j < new.len() && (i == old.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+                lines.push(format!("+{}", new[j]));
+                j += 1;
+            } else {
+                lines.push(format!("-{}", old[i]));
+                i += 1;
+            }
+        }
+        lines
+    }
+
+    /// Check a core memory write against the token limits of the memory under `cwd`
+    ///
+    /// Returns `None` for non-core patches and writes that stay within limits.
+    pub fn check_core_limits(&self, cwd: &Path) -> Option<CoreMemoryLint> {
+        if self.kind != MemoryKind::Core {
+            return None;
+        }
+        CoreMemory::load(&MemoryPaths::from_thunderus_root(cwd), cwd)
+            .ok()?
+            .check_write(&self.path, &self.diff)
+    }
+
     /// Apply this memory patch by writing the content to the file
     ///
     /// For memory patches, the diff field contains the full document content
-    /// (not a unified diff), so we simply write it to the target path.
-    pub fn apply(&self) -> Result<(), String> {
+    /// (not a unified diff), so we simply write it to the target path. Core
+    /// memory writes over the hard limit are refused; a soft-limit warning is
+    /// returned alongside a successful write.
+    pub fn apply(&self, cwd: &Path) -> Result<Option<CoreMemoryLint>, String> {
+        let lint = self.check_core_limits(cwd);
+        if let Some(lint) = &lint
+            && lint.severity == LintSeverity::Error
+        {
+            return Err(format!("Blocked core memory patch: {}", lint.message));
+        }
+
         if let Some(parent) = self.path.parent()
             && !parent.exists()
         {
@@ -481,7 +553,7 @@ impl MemoryPatch {
 
         fs::write(&self.path, &self.diff).map_err(|e| format!("Failed to write memory doc {:?}: {}", self.path, e))?;
 
-        Ok(())
+        Ok(lint)
     }
 }
 
@@ -964,6 +1036,24 @@ mod tests {
         assert_eq!(patch.total_hunk_count(), 2);
     }
 
+    #[test]
+    fn test_patch_parse_diff_with_multiple_files() {
+        let diff = "diff --git a/a.txt b/a.txt\n@@ -1,1 +1,1 @@\n-old\n+new\ndiff --git a/b.txt b/b.txt\n@@ -3,1 +3,1 @@\n-old\n+new";
+        let patch = Patch::new(
+            PatchId::new("patch1"),
+            "test patch".to_string(),
+            "abc123".to_string(),
+            diff.to_string(),
+            SessionId::new(),
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(patch.hunk_count(Path::new("a.txt")), Some(1));
+        assert_eq!(patch.hunk_count(Path::new("b.txt")), Some(1));
+        assert_eq!(patch.hunks[&PathBuf::from("b.txt")][0].old_start, 3);
+    }
+
     #[test]
     fn test_patch_label_hunks() {
         let diff = "diff --git a/test.txt b/test.txt\n@@ -1,2 +1,2 @@\n-old\n+new\n@@ -5,2 +5,2 @@\n-old2\n+new2";
@@ -1016,4 +1106,84 @@ mod tests {
         assert_eq!(hunks[0].intent, Some("First hunk".to_string()));
         assert_eq!(hunks[1].intent, None);
     }
+
+    #[test]
+    fn test_memory_patch_apply_checks_core_limits() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let paths = MemoryPaths::from_thunderus_root(temp.path());
+        let patch = |kind, content: String| {
+            MemoryPatch::new(
+                PatchId::new("mem_000"),
+                MemoryPatchParams {
+                    path: paths.core_local_memory_file(),
+                    doc_id: "core.local".to_string(),
+                    kind,
+                    description: "Grow core memory".to_string(),
+                    diff: content,
+                    source_events: vec![],
+                    session_id: SessionId::new(),
+                    seq: 0,
+                },
+            )
+        };
+
+        let err = patch(MemoryKind::Core, "a".repeat(40000))
+            .apply(temp.path())
+            .unwrap_err();
+        assert!(err.contains("Blocked core memory patch"), "{}", err);
+        assert!(!paths.core_local_memory_file().exists());
+
+        let lint = patch(MemoryKind::Core, "a".repeat(20000))
+            .apply(temp.path())
+            .unwrap()
+            .unwrap();
+        assert_eq!(lint.severity, LintSeverity::Warning);
+        assert!(
+            patch(MemoryKind::Core, "short".to_string())
+                .apply(temp.path())
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            patch(MemoryKind::Fact, "a".repeat(40000))
+                .check_core_limits(temp.path())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_memory_patch_review_lines() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("fact_build.md");
+        let mut patch = MemoryPatch::new(
+            PatchId::new("mem1"),
+            MemoryPatchParams {
+                path: path.clone(),
+                doc_id: "fact.build".to_string(),
+                kind: MemoryKind::Fact,
+                description: "Merge into fact".to_string(),
+                diff: "# Build\n- cargo build\n- cargo build --release\n".to_string(),
+                source_events: vec![],
+                session_id: SessionId::new(),
+                seq: 0,
+            },
+        );
+
+        assert_eq!(
+            patch.review_lines(),
+            vec!["+# Build", "+- cargo build", "+- cargo build --release"]
+        );
+
+        fs::write(&path, "# Build\n- cargo build\n- make\n").unwrap();
+        assert_eq!(patch.review_lines().len(), 3);
+        patch.refresh_review();
+        assert_eq!(
+            patch.review_lines(),
+            vec![" # Build", " - cargo build", "+- cargo build --release", "-- make"]
+        );
+
+        patch.diff = "# Build\n- cargo build\n- make\n".to_string();
+        patch.refresh_review();
+        assert!(patch.review_lines().iter().all(|line| line.starts_with(' ')));
+    }
 }
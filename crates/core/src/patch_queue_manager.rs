@@ -44,6 +44,13 @@ impl PatchQueueManager {
 
             self.queue = serde_json::from_str(&content)
                 .map_err(|e| Error::Parse(format!("Failed to parse patch queue: {}", e)))?;
+
+            // Queues saved before the review was cached have none to show
+            for patch in &mut self.queue.memory_patches {
+                if patch.review.is_empty() {
+                    patch.refresh_review();
+                }
+            }
         }
 
         Ok(self)
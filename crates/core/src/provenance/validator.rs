@@ -3,11 +3,13 @@ use crate::error::{Error, Result};
 use crate::layout::SessionId;
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
 /// Mode for provenance validation
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ValidationMode {
-    /// Reject documents with missing provenance
+    /// Reject documents with missing provenance, or whose provenance does not
+    /// reference any known session event
     Strict,
     /// Auto-link to the current session ID if missing
     Loose,
@@ -16,11 +18,20 @@ pub enum ValidationMode {
 /// Validator for memory document provenance
 pub struct ProvenanceValidator {
     mode: ValidationMode,
+    known_events: Option<HashSet<String>>,
 }
 
 impl ProvenanceValidator {
     pub fn new(mode: ValidationMode) -> Self {
-        Self { mode }
+        Self { mode, known_events: None }
+    }
+
+    /// Restrict valid provenance to the given event IDs (`{session_id}_{seq}`)
+    ///
+    /// Only enforced in [`ValidationMode::Strict`].
+    pub fn with_known_events(mut self, events: impl IntoIterator<Item = String>) -> Self {
+        self.known_events = Some(events.into_iter().collect());
+        self
     }
 
     /// Validate a memory document's provenance
@@ -43,10 +54,25 @@ impl ProvenanceValidator {
                 }
             }
         }
+
+        if self.mode == ValidationMode::Strict
+            && let Some(known) = &self.known_events
+            && !events.iter().any(|event| references_known_event(event, known))
+        {
+            return Err(Error::Other(format!(
+                "Provenance validation failed: Document '{}' does not reference any event from the session.",
+                doc_id
+            )));
+        }
         Ok(())
     }
 }
 
+/// Event IDs may carry a suffix such as `:err:<message>` after the `{session_id}_{seq}` part
+fn references_known_event(event: &str, known: &HashSet<String>) -> bool {
+    event.split(':').next().is_some_and(|id| known.contains(id))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -83,4 +109,27 @@ mod tests {
         validator.validate(&mut doc, &session_id).unwrap();
         assert_eq!(doc.frontmatter.provenance.events.len(), 1);
     }
+
+    #[test]
+    fn test_strict_validation_requires_known_events() {
+        let session_id = SessionId::new();
+        let validator = ProvenanceValidator::new(ValidationMode::Strict).with_known_events(["s1_0".to_string()]);
+
+        let mut backed = vec!["s1_0:err:build failed".to_string()];
+        assert!(
+            validator
+                .validate_provenance(&mut backed, "fact.a", &session_id)
+                .is_ok()
+        );
+
+        let mut unbacked = vec!["s1_7".to_string()];
+        assert!(
+            validator
+                .validate_provenance(&mut unbacked, "fact.b", &session_id)
+                .is_err()
+        );
+
+        let loose = ProvenanceValidator::new(ValidationMode::Loose).with_known_events(["s1_0".to_string()]);
+        assert!(loose.validate_provenance(&mut unbacked, "fact.b", &session_id).is_ok());
+    }
 }
@@ -1,3 +1,5 @@
+use crate::layout::{AgentDir, SessionId};
+use crate::session::Session;
 use crate::{Error, Result};
 use std::path::Path;
 use std::process::Command;
@@ -26,7 +28,42 @@ pub struct SearchHit {
     pub context: Option<Vec<String>>,
 }
 
+/// Search hits from a single session
+#[derive(Debug, Clone)]
+pub struct SessionSearchHits {
+    /// Session the hits were found in
+    pub session_id: SessionId,
+    /// Hits within that session
+    pub hits: Vec<SearchHit>,
+}
+
+/// Search every session under the agent directory
+///
+/// Sessions are returned newest first, and sessions without hits are omitted.
+/// A rollover chain is reported once, under the session it started from.
+/// Views are shared across sessions, so [`SearchScope::Views`] is rejected;
+/// use [`search_session`] for those.
+pub fn search_all_sessions(agent_dir: &AgentDir, query: &str, scope: SearchScope) -> Result<Vec<SessionSearchHits>> {
+    if scope == SearchScope::Views {
+        return Err(Error::Other(
+            "Views are shared across sessions; search them without --all".to_string(),
+        ));
+    }
+
+    let mut results = Vec::new();
+    for session_id in agent_dir.list_sessions() {
+        let hits = search_session(&agent_dir.session_dir(&session_id), query, scope)?;
+        if !hits.is_empty() {
+            results.push(SessionSearchHits { session_id, hits });
+        }
+    }
+
+    Ok(results)
+}
+
 /// Search a session directory using ripgrep
+///
+/// Event searches cover every segment of the session's rollover chain.
 pub fn search_session(session_dir: &Path, query: &str, scope: SearchScope) -> Result<Vec<SearchHit>> {
     let mut cmd = Command::new("rg");
 
@@ -35,17 +72,21 @@ pub fn search_session(session_dir: &Path, query: &str, scope: SearchScope) -> Re
         .arg("--with-filename")
         .arg("--color=never")
         .arg("--fixed-strings")
-        .arg("--case-insensitive")
+        .arg("--ignore-case")
         .arg("--max-count=50");
 
     cmd.arg(query);
 
     match scope {
         SearchScope::All => {
-            cmd.arg(session_dir);
+            cmd.args(Session::chain_dirs(session_dir));
         }
         SearchScope::Events => {
-            cmd.arg(session_dir.join("events.jsonl"));
+            cmd.args(
+                Session::chain_dirs(session_dir)
+                    .into_iter()
+                    .map(|dir| dir.join("events.jsonl")),
+            );
         }
         SearchScope::Views => {
             cmd.arg(session_dir.join("../views"));
@@ -82,6 +123,15 @@ mod tests {
     use std::fs;
     use tempfile::TempDir;
 
+    /// Whether ripgrep is installed; tests that shell out to it print a skip notice otherwise
+    fn rg_available(test: &str) -> bool {
+        let available = Command::new("rg").arg("--version").output().is_ok();
+        if !available {
+            eprintln!("skipping {}: ripgrep (rg) is not installed", test);
+        }
+        available
+    }
+
     #[test]
     fn test_search_scope_variants() {
         assert_eq!(SearchScope::All, SearchScope::All);
@@ -119,4 +169,53 @@ mod tests {
         let results = search_session(&session_dir, "test", SearchScope::Events);
         assert!(results.is_ok() || results.is_err());
     }
+
+    #[test]
+    fn test_search_all_sessions_groups_by_session() {
+        if !rg_available("test_search_all_sessions_groups_by_session") {
+            return;
+        }
+        let temp = TempDir::new().unwrap();
+        let agent_dir = AgentDir::new(temp.path());
+        for (session, content) in [
+            ("2026-01-01T10-00-00Z", "deploy to staging\n"),
+            ("2026-01-02T10-00-00Z", "unrelated\n"),
+            ("2026-01-03T10-00-00Z", "staging is flaky\nstaging again\n"),
+        ] {
+            let dir = agent_dir.sessions_dir().join(session);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join("events.jsonl"), content).unwrap();
+        }
+
+        let results = search_all_sessions(&agent_dir, "staging", SearchScope::Events).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].session_id.as_str(), "2026-01-03T10-00-00Z");
+        assert_eq!(results[0].hits.len(), 2);
+        assert_eq!(results[1].session_id.as_str(), "2026-01-01T10-00-00Z");
+    }
+
+    #[test]
+    fn test_search_follows_rollover_chain() {
+        if !rg_available("test_search_follows_rollover_chain") {
+            return;
+        }
+        let temp = TempDir::new().unwrap();
+        let agent_dir = AgentDir::new(temp.path());
+        let mut session = Session::new(agent_dir.clone()).unwrap();
+        session.set_rollover(&crate::RolloverConfig { max_events: Some(1), ..Default::default() });
+        session.append_user_message("deploy to staging").unwrap();
+        session.append_user_message("staging is flaky").unwrap();
+
+        let results = search_all_sessions(&agent_dir, "staging", SearchScope::Events).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].session_id, session.id);
+        assert_eq!(results[0].hits.len(), 2);
+    }
+
+    #[test]
+    fn test_search_all_sessions_rejects_views() {
+        let temp = TempDir::new().unwrap();
+        let agent_dir = AgentDir::new(temp.path());
+        assert!(search_all_sessions(&agent_dir, "x", SearchScope::Views).is_err());
+    }
 }
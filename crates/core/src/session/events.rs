@@ -23,6 +23,9 @@ pub enum Event {
         tool: String,
         /// Arguments passed to the tool
         arguments: serde_json::Value,
+        /// Provider-assigned call id, pairing the call with its result
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        tool_call_id: Option<String>,
     },
     /// Result from a tool execution
     ToolResult {
@@ -34,6 +37,9 @@ pub enum Event {
         success: bool,
         /// Error message if the tool call failed
         error: Option<String>,
+        /// Provider-assigned id of the call this result answers
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        tool_call_id: Option<String>,
     },
     /// Approval action by the user
     Approval {
@@ -41,6 +47,9 @@ pub enum Event {
         action: String,
         /// Whether the action was approved
         approved: bool,
+        /// Why the action was approved automatically, or why the user rejected it
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        reason: Option<String>,
     },
     /// Patch proposed or applied
     Patch {
@@ -119,17 +128,20 @@ pub enum Event {
         label: String,
         /// Description of what was accomplished
         description: String,
-        /// Optional snapshot ID reference
+        /// Optional snapshot ID reference, e.g. from `SnapshotManager::snapshot_git`
         snapshot_id: Option<String>,
     },
     /// Plan item update (add, complete, remove)
     PlanUpdate {
-        /// Action performed (add, complete, remove, update)
+        /// Action performed (add, complete, remove, move, update)
         action: String,
         /// The plan item text
         item: String,
         /// Optional reason for the update
         reason: Option<String>,
+        /// Target position in the task list for `move` updates
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        position: Option<usize>,
     },
     /// Memory document created or updated
     MemoryUpdate {
@@ -142,6 +154,11 @@ pub enum Event {
         /// Hash of the new content
         content_hash: String,
     },
+    /// Active task title set by the user
+    TaskUpdate {
+        /// New task title
+        title: String,
+    },
 }
 
 /// Token usage information for model responses
@@ -322,6 +339,7 @@ mod tests {
             action: "remove".to_string(),
             item: "Deprecated task".to_string(),
             reason: Some("No longer needed".to_string()),
+            position: None,
         };
 
         let json = serde_json::to_string(&event).unwrap();
@@ -1,4 +1,5 @@
 use crate::config::ApprovalMode;
+use crate::layout::SessionId;
 use crate::teaching::TeachingState;
 use serde::{Deserialize, Serialize};
 
@@ -19,6 +20,12 @@ pub struct SessionMetadata {
     /// Whether the user has seen the drift explainer
     #[serde(default)]
     pub drift_explainer_shown: bool,
+    /// Session this one continues after a log rollover
+    #[serde(default)]
+    pub continues_from: Option<SessionId>,
+    /// Session that continued this one after a log rollover
+    #[serde(default)]
+    pub continued_by: Option<SessionId>,
     /// When the session was created
     pub created_at: String,
     /// When the session was last updated
@@ -39,6 +46,8 @@ impl SessionMetadata {
             created_at: now.clone(),
             updated_at: now,
             drift_explainer_shown: false,
+            continues_from: None,
+            continued_by: None,
         }
     }
 
@@ -1,11 +1,13 @@
-use crate::config::ApprovalMode;
+use crate::config::{ApprovalMode, RedactionConfig, RolloverConfig};
 use crate::error::{Error, Result, SessionError};
 use crate::layout::{AgentDir, SessionId};
+use crate::redaction::Redactor;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs::{File, OpenOptions};
 use std::io::{BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
 use tracing::{debug, info, instrument};
 
 /// Monotonically increasing sequence number for events
@@ -18,16 +20,37 @@ pub use events::{Event, LoggedEvent, PatchStatus, TokensUsed};
 pub use metadata::SessionMetadata;
 
 /// Session manages events and their storage in JSONL format
+///
+/// When the log rolls over, events continue in a new segment directory linked
+/// to the previous one, but the session keeps its original `id`: metadata,
+/// patches and anything else keyed by the id stay where they were.
 #[derive(Debug, Clone)]
 pub struct Session {
     /// Unique identifier for this session
     pub id: SessionId,
     /// Agent directory layout
     agent_dir: AgentDir,
-    /// Next sequence number to assign
-    next_seq: Seq,
+    /// Segment currently written to and its sequence counter, shared by every clone
+    log: Arc<Mutex<LogSegment>>,
     /// File ownership tracking (PathBuf -> OwnerId)
     pub file_ownership: HashMap<PathBuf, String>,
+    /// Masks secrets in shell commands and tool arguments before they are logged
+    redactor: Redactor,
+    /// Thresholds at which the log continues in a new, linked segment
+    rollover: RolloverConfig,
+}
+
+/// The log segment a session is appending to
+#[derive(Debug)]
+struct LogSegment {
+    /// Session directory holding the segment's `events.jsonl`
+    id: SessionId,
+    /// Next sequence number to assign
+    next_seq: Seq,
+    /// Size of the segment's log, read once when rollover is first checked
+    bytes: Option<u64>,
+    /// Events in the segment's log, counted once when `max_events` is first checked
+    events: Option<usize>,
 }
 
 impl Session {
@@ -59,11 +82,9 @@ impl Session {
             File::create(&events_file)?;
         }
 
-        let next_seq = Self::load_next_seq(&events_file)?;
-
         debug!(session_id = %id, "Session initialized");
 
-        Ok(Self { id, agent_dir, next_seq, file_ownership: HashMap::new() })
+        Self::open(agent_dir, id)
     }
 
     /// Load an existing session by ID
@@ -81,9 +102,35 @@ impl Session {
             return Err(Error::Session(SessionError::EventsNotFound(id.to_string())));
         }
 
-        let next_seq = Self::load_next_seq(&events_file)?;
+        Self::open(agent_dir, id)
+    }
 
-        Ok(Self { id, agent_dir, next_seq, file_ownership: HashMap::new() })
+    /// Open a session, resuming at the newest segment of its rollover chain
+    fn open(agent_dir: AgentDir, id: SessionId) -> Result<Self> {
+        let chain = Self::chain_dirs(&agent_dir.session_dir(&id));
+        let mut next_seq = 0;
+        for dir in &chain {
+            next_seq = next_seq.max(Self::load_next_seq(&dir.join(crate::layout::EVENTS_FILE))?);
+        }
+        let segment = chain
+            .last()
+            .and_then(|dir| dir.file_name())
+            .and_then(|name| SessionId::from_timestamp(name.to_string_lossy().to_string()).ok())
+            .unwrap_or_else(|| id.clone());
+
+        Ok(Self {
+            id,
+            agent_dir,
+            log: Arc::new(Mutex::new(LogSegment {
+                id: segment,
+                next_seq,
+                bytes: None,
+                events: None,
+            })),
+            file_ownership: HashMap::new(),
+            redactor: Redactor::default(),
+            rollover: RolloverConfig::default(),
+        })
     }
 
     /// Load the next sequence number from the events file
@@ -113,9 +160,44 @@ impl Session {
         Ok(max_seq)
     }
 
-    /// Get the path to the events file for this session
+    fn lock_log(&self) -> Result<MutexGuard<'_, LogSegment>> {
+        self.log
+            .lock()
+            .map_err(|_| Error::Other("Session log lock poisoned".to_string()))
+    }
+
+    /// Sequence number the next appended event will get
+    #[cfg(test)]
+    fn next_seq(&self) -> Seq {
+        self.lock_log().map(|log| log.next_seq).unwrap_or_default()
+    }
+
+    /// Replace the built-in redaction rules with the profile's configuration
+    pub fn set_redaction(&mut self, config: &RedactionConfig) -> Result<()> {
+        self.redactor = Redactor::new(config)?;
+        Ok(())
+    }
+
+    /// Redactor applied to events before they are logged
+    pub fn redactor(&self) -> &Redactor {
+        &self.redactor
+    }
+
+    /// Set the thresholds at which the log rolls over into a new segment
+    pub fn set_rollover(&mut self, config: &RolloverConfig) {
+        self.rollover = config.clone();
+    }
+
+    /// ID of the segment currently being written; equals `id` until the log rolls over
+    pub fn segment_id(&self) -> SessionId {
+        self.lock_log()
+            .map(|log| log.id.clone())
+            .unwrap_or_else(|_| self.id.clone())
+    }
+
+    /// Get the path to the events file the session is currently appending to
     pub fn events_file(&self) -> PathBuf {
-        self.agent_dir.events_file(&self.id)
+        self.agent_dir.events_file(&self.segment_id())
     }
 
     /// Get the path to the patches directory for this session
@@ -135,27 +217,153 @@ impl Session {
 
     /// Append an event to the session log
     ///
-    /// The event is assigned a sequence number and written to the JSONL file
-    #[instrument(skip(self, event), fields(session_id = %self.id, seq = self.next_seq))]
-    pub fn append_event(&mut self, event: Event) -> Result<Seq> {
+    /// The event is assigned a sequence number and written to the JSONL file.
+    /// Secrets in shell commands and tool arguments are redacted first. If the
+    /// log has crossed a rollover threshold, the event starts a new segment
+    /// that continues this one.
+    #[instrument(skip(self, event), fields(session_id = %self.id))]
+    pub fn append_event(&mut self, mut event: Event) -> Result<Seq> {
         let event_type = format!("{:?}", std::mem::discriminant(&event));
+        self.redactor.redact_event(&mut event);
 
-        let seq = self.next_seq;
-        let logged_event = LoggedEvent::new(seq, &self.id, event);
+        let mut log = self.lock_log()?;
+        if self.rollover.is_enabled() && self.rollover_due(&mut log)? {
+            self.roll_over(&mut log)?;
+        }
 
-        let events_file = self.events_file();
+        let seq = log.next_seq;
+        let logged_event = LoggedEvent::new(seq, &log.id, event);
+
+        let events_file = self.agent_dir.events_file(&log.id);
         let mut file = OpenOptions::new().create(true).append(true).open(&events_file)?;
 
         let json_line = serde_json::to_string(&logged_event)
             .map_err(|e| Error::Parse(format!("JSON serialization error: {}", e)))?;
         writeln!(file, "{}", json_line)?;
 
-        self.next_seq += 1;
+        log.next_seq += 1;
+        if let Some(bytes) = &mut log.bytes {
+            *bytes += json_line.len() as u64 + 1;
+        }
+        if let Some(count) = &mut log.events {
+            *count += 1;
+        }
         debug!(session_id = %self.id, seq, event_type, "Event appended");
 
         Ok(seq)
     }
 
+    /// Check whether the current segment has crossed any rollover threshold
+    ///
+    /// The segment's size and event count are read from disk once and then
+    /// tracked in memory as events are appended.
+    fn rollover_due(&self, log: &mut LogSegment) -> Result<bool> {
+        let events_file = self.agent_dir.events_file(&log.id);
+        let bytes = *log
+            .bytes
+            .get_or_insert_with(|| std::fs::metadata(&events_file).map(|m| m.len()).unwrap_or(0));
+        if bytes == 0 {
+            return Ok(false);
+        }
+
+        if self.rollover.max_bytes.is_some_and(|max| bytes >= max) {
+            return Ok(true);
+        }
+        if let Some(max) = self.rollover.max_events {
+            let count = match log.events {
+                Some(count) => count,
+                None => Self::read_events_file(&events_file)?.len(),
+            };
+            log.events = Some(count);
+            if count >= max {
+                return Ok(true);
+            }
+        }
+        if let (Some(hours), Some(started)) = (self.rollover.max_age_hours, log.id.started_at()) {
+            return Ok(chrono::Utc::now() - started >= chrono::Duration::hours(hours as i64));
+        }
+        Ok(false)
+    }
+
+    /// Continue the log in a new segment linked to the current one
+    ///
+    /// The new segment inherits the current metadata and keeps the sequence
+    /// numbering going, so event IDs stay unique across the whole chain.
+    fn roll_over(&self, log: &mut LogSegment) -> Result<()> {
+        let previous = log.id.clone();
+        let next = self.next_segment_id(&previous)?;
+
+        std::fs::create_dir_all(self.agent_dir.patches_dir(&next))?;
+        File::create(self.agent_dir.events_file(&next))?;
+
+        let previous_file = self.agent_dir.metadata_file(&previous);
+        let mut metadata = Self::read_metadata(&previous_file)?;
+        let now = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+        let continued = SessionMetadata {
+            continues_from: Some(previous.clone()),
+            continued_by: None,
+            created_at: now.clone(),
+            updated_at: now.clone(),
+            ..metadata.clone()
+        };
+        Self::write_metadata(&self.agent_dir.metadata_file(&next), &continued)?;
+
+        metadata.continued_by = Some(next.clone());
+        metadata.updated_at = now;
+        Self::write_metadata(&previous_file, &metadata)?;
+
+        info!(session_id = %self.id, segment = %next, continues_from = %previous, "Session log rolled over");
+        log.id = next;
+        log.bytes = Some(0);
+        log.events = Some(0);
+        Ok(())
+    }
+
+    /// A fresh segment ID that sorts after `current` and isn't taken
+    fn next_segment_id(&self, current: &SessionId) -> Result<SessionId> {
+        let base = SessionId::now().max(current.clone());
+        let mut candidate = base.clone();
+        let mut suffix = 1;
+        while candidate == *current || self.agent_dir.session_dir(&candidate).exists() {
+            candidate = SessionId::from_timestamp(format!("{}-{}", base, suffix)).map_err(SessionError::from)?;
+            suffix += 1;
+        }
+        Ok(candidate)
+    }
+
+    /// Segment directories in the rollover chain containing `session_dir`, oldest first
+    ///
+    /// Follows `continues_from` links back to the first segment and
+    /// `continued_by` links forward to the newest, so any segment of a chain
+    /// yields the whole chain. A session that never rolled over is a chain of one.
+    pub fn chain_dirs(session_dir: &Path) -> Vec<PathBuf> {
+        let link = |dir: &Path, forward: bool| {
+            std::fs::read_to_string(dir.join(crate::layout::METADATA_FILE))
+                .ok()
+                .and_then(|json| serde_json::from_str::<SessionMetadata>(&json).ok())
+                .and_then(|metadata| if forward { metadata.continued_by } else { metadata.continues_from })
+                .and_then(|id| dir.parent().map(|parent| parent.join(id.as_str())))
+                .filter(|next| next.exists())
+        };
+
+        let mut seen = HashSet::from([session_dir.to_path_buf()]);
+        let mut earlier = Vec::new();
+        let mut current = session_dir.to_path_buf();
+        while let Some(previous) = link(&current, false).filter(|dir| seen.insert(dir.clone())) {
+            earlier.push(previous.clone());
+            current = previous;
+        }
+
+        let mut chain: Vec<PathBuf> = earlier.into_iter().rev().collect();
+        chain.push(session_dir.to_path_buf());
+        let mut current = session_dir.to_path_buf();
+        while let Some(next) = link(&current, true).filter(|dir| seen.insert(dir.clone())) {
+            chain.push(next.clone());
+            current = next;
+        }
+        chain
+    }
+
     /// Append a user message
     pub fn append_user_message(&mut self, content: impl Into<String>) -> Result<Seq> {
         self.append_event(Event::UserMessage { content: content.into() })
@@ -168,19 +376,50 @@ impl Session {
 
     /// Append a tool call
     pub fn append_tool_call(&mut self, tool: impl Into<String>, arguments: serde_json::Value) -> Result<Seq> {
-        self.append_event(Event::ToolCall { tool: tool.into(), arguments })
+        self.append_event(Event::ToolCall { tool: tool.into(), arguments, tool_call_id: None })
+    }
+
+    /// Append a tool call tagged with the provider's call id
+    pub fn append_tool_call_with_id(
+        &mut self, tool_call_id: impl Into<String>, tool: impl Into<String>, arguments: serde_json::Value,
+    ) -> Result<Seq> {
+        self.append_event(Event::ToolCall { tool: tool.into(), arguments, tool_call_id: Some(tool_call_id.into()) })
     }
 
     /// Append a tool result
     pub fn append_tool_result(
         &mut self, tool: impl Into<String>, result: serde_json::Value, success: bool, error: Option<String>,
     ) -> Result<Seq> {
-        self.append_event(Event::ToolResult { tool: tool.into(), result, success, error })
+        self.append_event(Event::ToolResult { tool: tool.into(), result, success, error, tool_call_id: None })
+    }
+
+    /// Append a tool result tagged with the id of the call it answers
+    pub fn append_tool_result_with_id(
+        &mut self, tool_call_id: impl Into<String>, tool: impl Into<String>, result: serde_json::Value, success: bool,
+        error: Option<String>,
+    ) -> Result<Seq> {
+        self.append_event(Event::ToolResult {
+            tool: tool.into(),
+            result,
+            success,
+            error,
+            tool_call_id: Some(tool_call_id.into()),
+        })
     }
 
     /// Append an approval action
     pub fn append_approval(&mut self, action: impl Into<String>, approved: bool) -> Result<Seq> {
-        self.append_event(Event::Approval { action: action.into(), approved })
+        self.append_event(Event::Approval { action: action.into(), approved, reason: None })
+    }
+
+    /// Append a rejection along with the reason the user gave for it
+    pub fn append_rejection(&mut self, action: impl Into<String>, reason: impl Into<String>) -> Result<Seq> {
+        self.append_event(Event::Approval { action: action.into(), approved: false, reason: Some(reason.into()) })
+    }
+
+    /// Append an approval granted automatically by policy
+    pub fn append_auto_approval(&mut self, action: impl Into<String>, reason: impl Into<String>) -> Result<Seq> {
+        self.append_event(Event::Approval { action: action.into(), approved: true, reason: Some(reason.into()) })
     }
 
     /// Append a patch
@@ -212,10 +451,26 @@ impl Session {
         self.append_event(Event::FileRead { file_path: file_path.into(), line_count, offset, success })
     }
 
-    /// Read all events from the session log
+    /// Read all events from the current log segment
     pub fn read_events(&self) -> Result<Vec<LoggedEvent>> {
-        let events_file = self.events_file();
-        let file = File::open(&events_file)?;
+        Self::read_events_file(&self.events_file())
+    }
+
+    /// Read every event in the session, across all segments of its rollover chain
+    pub fn read_chain_events(&self) -> Result<Vec<LoggedEvent>> {
+        let mut events = Vec::new();
+        for dir in Self::chain_dirs(&self.session_dir()) {
+            let events_file = dir.join(crate::layout::EVENTS_FILE);
+            if events_file.exists() {
+                events.extend(Self::read_events_file(&events_file)?);
+            }
+        }
+        Ok(events)
+    }
+
+    /// Read all events from an `events.jsonl` file without loading its session
+    pub fn read_events_file(events_file: &Path) -> Result<Vec<LoggedEvent>> {
+        let file = File::open(events_file)?;
         let reader = BufReader::new(file);
 
         let mut events = Vec::new();
@@ -234,9 +489,9 @@ impl Session {
         Ok(events)
     }
 
-    /// Read events from a specific sequence number onwards
+    /// Read events from a specific sequence number onwards, across the whole rollover chain
     pub fn read_events_from(&self, from_seq: Seq) -> Result<Vec<LoggedEvent>> {
-        let all_events = self.read_events()?;
+        let all_events = self.read_chain_events()?;
         Ok(all_events.into_iter().filter(|e| e.seq >= from_seq).collect())
     }
 
@@ -271,7 +526,7 @@ impl Session {
     /// Returns the sequence number of the most recent read event for the file,
     /// or None if the file has not been read
     pub fn was_file_read(&self, file_path: &str) -> Result<Option<Seq>> {
-        let events = self.read_events()?;
+        let events = self.read_chain_events()?;
 
         let last_read_seq = events
             .iter()
@@ -292,7 +547,7 @@ impl Session {
     ///
     /// Returns a vector of (file_path, seq) tuples for all successful reads
     pub fn read_files(&self) -> Result<Vec<(String, Seq)>> {
-        let events = self.read_events()?;
+        let events = self.read_chain_events()?;
 
         let files: Vec<(String, Seq)> = events
             .iter()
@@ -317,13 +572,15 @@ impl Session {
     ///
     /// Returns default metadata if the file doesn't exist
     pub fn load_metadata(&self) -> Result<SessionMetadata> {
-        let metadata_file = self.metadata_file();
+        Self::read_metadata(&self.metadata_file())
+    }
 
+    fn read_metadata(metadata_file: &Path) -> Result<SessionMetadata> {
         if !metadata_file.exists() {
             return Ok(SessionMetadata::new(ApprovalMode::Auto));
         }
 
-        let file = File::open(&metadata_file)?;
+        let file = File::open(metadata_file)?;
         let metadata: SessionMetadata = serde_json::from_reader(file)
             .map_err(|e| Error::Parse(format!("Failed to parse session metadata: {}", e)))?;
 
@@ -332,12 +589,14 @@ impl Session {
 
     /// Save session metadata to disk
     pub fn save_metadata(&self, metadata: &SessionMetadata) -> Result<()> {
-        let metadata_file = self.metadata_file();
+        Self::write_metadata(&self.metadata_file(), metadata)
+    }
 
+    fn write_metadata(metadata_file: &Path, metadata: &SessionMetadata) -> Result<()> {
         let json = serde_json::to_string_pretty(metadata)
             .map_err(|e| Error::Parse(format!("Failed to serialize session metadata: {}", e)))?;
 
-        std::fs::write(&metadata_file, json)
+        std::fs::write(metadata_file, json)
             .map_err(|e| Error::Other(format!("Failed to write session metadata: {}", e)))?;
 
         Ok(())
@@ -451,7 +710,22 @@ impl Session {
     pub fn append_plan_update(
         &mut self, action: impl Into<String>, item: impl Into<String>, reason: Option<String>,
     ) -> Result<Seq> {
-        self.append_event(Event::PlanUpdate { action: action.into(), item: item.into(), reason })
+        self.append_event(Event::PlanUpdate { action: action.into(), item: item.into(), reason, position: None })
+    }
+
+    /// Append a plan update event moving an item to `position` in the task list
+    pub fn append_plan_move(&mut self, item: impl Into<String>, position: usize) -> Result<Seq> {
+        self.append_event(Event::PlanUpdate {
+            action: "move".to_string(),
+            item: item.into(),
+            reason: None,
+            position: Some(position),
+        })
+    }
+
+    /// Append a task update event (active task relabelled by the user)
+    pub fn append_task_update(&mut self, title: impl Into<String>) -> Result<Seq> {
+        self.append_event(Event::TaskUpdate { title: title.into() })
     }
 
     /// Append a memory update event (memory document created or updated)
@@ -530,7 +804,7 @@ mod tests {
         assert!(session.patches_dir().exists());
         assert!(session.events_file().exists());
 
-        assert_eq!(session.next_seq, 0);
+        assert_eq!(session.next_seq(), 0);
         assert_eq!(session.event_count().unwrap(), 0);
         drop(temp);
     }
@@ -553,7 +827,7 @@ mod tests {
         let loaded_session = Session::load(agent_dir, session.id.clone()).unwrap();
 
         assert_eq!(loaded_session.id, session.id);
-        assert_eq!(loaded_session.next_seq, session.next_seq);
+        assert_eq!(loaded_session.next_seq(), session.next_seq());
     }
 
     #[test]
@@ -621,7 +895,7 @@ mod tests {
         let events = session.read_events().unwrap();
         assert_eq!(events.len(), 1);
 
-        if let Event::ToolCall { tool, arguments } = &events[0].event {
+        if let Event::ToolCall { tool, arguments, .. } = &events[0].event {
             assert_eq!(tool, "fs.read");
             assert_eq!(arguments, &serde_json::json!({ "path": "/tmp/test" }));
         } else {
@@ -641,7 +915,7 @@ mod tests {
         let events = session.read_events().unwrap();
         assert_eq!(events.len(), 1);
 
-        if let Event::ToolResult { tool, result: res, success, error } = &events[0].event {
+        if let Event::ToolResult { tool, result: res, success, error, .. } = &events[0].event {
             assert_eq!(tool, "fs.read");
             assert_eq!(res, &serde_json::json!({ "output": "test output" }));
             assert!(*success);
@@ -661,7 +935,7 @@ mod tests {
         let events = session.read_events().unwrap();
         assert_eq!(events.len(), 1);
 
-        if let Event::Approval { action, approved } = &events[0].event {
+        if let Event::Approval { action, approved, .. } = &events[0].event {
             assert_eq!(action, "patch.feature");
             assert!(*approved);
         } else {
@@ -670,6 +944,39 @@ mod tests {
         drop(temp);
     }
 
+    #[test]
+    fn test_append_auto_approval() {
+        let (temp, mut session) = create_test_session();
+
+        session
+            .append_auto_approval("Execute tool: read", "safe action allowed by auto mode")
+            .unwrap();
+        session.append_approval("patch.feature", false).unwrap();
+        session
+            .append_rejection("Execute tool: shell", "run the tests first")
+            .unwrap();
+
+        let events = session.read_events().unwrap();
+        if let Event::Approval { approved, reason, .. } = &events[0].event {
+            assert!(*approved);
+            assert_eq!(reason.as_deref(), Some("safe action allowed by auto mode"));
+        } else {
+            panic!("Expected Approval event");
+        }
+        if let Event::Approval { reason, .. } = &events[1].event {
+            assert!(reason.is_none());
+        } else {
+            panic!("Expected Approval event");
+        }
+        if let Event::Approval { approved, reason, .. } = &events[2].event {
+            assert!(!*approved);
+            assert_eq!(reason.as_deref(), Some("run the tests first"));
+        } else {
+            panic!("Expected Approval event");
+        }
+        drop(temp);
+    }
+
     #[test]
     fn test_append_patch() {
         let (temp, mut session) = create_test_session();
@@ -729,6 +1036,61 @@ mod tests {
         drop(temp);
     }
 
+    #[test]
+    fn test_append_event_redacts_secrets() {
+        let (temp, mut session) = create_test_session();
+
+        session
+            .append_tool_call(
+                "shell",
+                serde_json::json!({"command": "GITHUB_TOKEN=abc123 gh pr list"}),
+            )
+            .unwrap();
+        session
+            .append_shell_command(
+                "psql",
+                vec!["--password".to_string(), "hunter2".to_string()],
+                PathBuf::from("/workspace"),
+                Some(0),
+                None,
+            )
+            .unwrap();
+
+        session
+            .set_redaction(&RedactionConfig { enabled: false, patterns: Vec::new() })
+            .unwrap();
+        session
+            .append_tool_call(
+                "shell",
+                serde_json::json!({"command": "GITHUB_TOKEN=abc123 gh pr list"}),
+            )
+            .unwrap();
+
+        let events = session.read_events().unwrap();
+        assert_eq!(
+            events[0].event,
+            Event::ToolCall {
+                tool: "shell".to_string(),
+                arguments: serde_json::json!({"command": "GITHUB_TOKEN=*** gh pr list"}),
+                tool_call_id: None,
+            }
+        );
+        if let Event::ShellCommand { args, .. } = &events[1].event {
+            assert_eq!(args, &vec!["--password".to_string(), "***".to_string()]);
+        } else {
+            panic!("Expected ShellCommand event");
+        }
+        assert_eq!(
+            events[2].event,
+            Event::ToolCall {
+                tool: "shell".to_string(),
+                arguments: serde_json::json!({"command": "GITHUB_TOKEN=abc123 gh pr list"}),
+                tool_call_id: None,
+            }
+        );
+        drop(temp);
+    }
+
     #[test]
     fn test_append_git_snapshot() {
         let (temp, mut session) = create_test_session();
@@ -761,7 +1123,7 @@ mod tests {
         assert_eq!(seq2, 1);
         assert_eq!(seq3, 2);
 
-        assert_eq!(session.next_seq, 3);
+        assert_eq!(session.next_seq(), 3);
         assert_eq!(session.event_count().unwrap(), 3);
         drop(temp);
     }
@@ -776,7 +1138,7 @@ mod tests {
         let agent_dir = AgentDir::new(temp.path());
         let mut loaded_session = Session::load(agent_dir, session.id.clone()).unwrap();
 
-        assert_eq!(loaded_session.next_seq, 2);
+        assert_eq!(loaded_session.next_seq(), 2);
 
         let seq = loaded_session.append_user_message("Third").unwrap();
         assert_eq!(seq, 2);
@@ -809,7 +1171,7 @@ mod tests {
         let temp2 = TempDir::new().unwrap();
         let agent_dir = AgentDir::new(temp2.path());
         let id = SessionId::new();
-        let non_existent = Session { id, agent_dir, next_seq: 0, file_ownership: HashMap::new() };
+        let non_existent = Session::open(agent_dir, id).unwrap();
         assert!(!non_existent.exists());
         drop(temp2);
     }
@@ -1004,7 +1366,7 @@ mod tests {
         let agent_dir = AgentDir::new(temp.path());
         let loaded = Session::load(agent_dir, session.id.clone()).unwrap();
 
-        assert_eq!(loaded.next_seq, 5);
+        assert_eq!(loaded.next_seq(), 5);
         drop(temp);
     }
 
@@ -1249,7 +1611,7 @@ mod tests {
         let events = session.read_events().unwrap();
         assert_eq!(events.len(), 1);
 
-        if let Event::PlanUpdate { action, item, reason } = &events[0].event {
+        if let Event::PlanUpdate { action, item, reason, .. } = &events[0].event {
             assert_eq!(action, "add");
             assert_eq!(item, "Implement new feature");
             assert_eq!(reason, &Some("User request".to_string()));
@@ -1503,4 +1865,100 @@ mod tests {
         assert_eq!(event2.seq, 1);
         drop(temp);
     }
+
+    #[test]
+    fn test_rollover_by_event_count() {
+        let (_temp, mut session) = create_test_session_with_id("2026-01-01T10-00-00Z");
+        session.set_rollover(&RolloverConfig { max_events: Some(2), ..Default::default() });
+        let first = session.id.clone();
+        session
+            .save_metadata(&SessionMetadata::new(ApprovalMode::ReadOnly).with_title("Parser fixes"))
+            .unwrap();
+
+        session.append_user_message("one").unwrap();
+        session.append_user_message("two").unwrap();
+        assert_eq!(session.segment_id(), first);
+
+        let seq = session.append_user_message("three").unwrap();
+        assert_eq!(seq, 2);
+        assert_eq!(session.id, first);
+        let segment = session.segment_id();
+        assert!(segment > first);
+        assert_eq!(session.event_count().unwrap(), 1);
+        assert_eq!(session.read_events().unwrap()[0].seq, 2);
+        assert_eq!(session.read_chain_events().unwrap().len(), 3);
+
+        let agent_dir = session.agent_dir();
+        let continued = Session::read_metadata(&agent_dir.metadata_file(&segment)).unwrap();
+        assert_eq!(continued.continues_from, Some(first.clone()));
+        assert_eq!(continued.approval_mode, ApprovalMode::ReadOnly);
+        assert_eq!(continued.title.as_deref(), Some("Parser fixes"));
+
+        let metadata = session.load_metadata().unwrap();
+        assert_eq!(metadata.continued_by, Some(segment.clone()));
+        assert_eq!(metadata.title.as_deref(), Some("Parser fixes"));
+        assert_eq!(
+            Session::chain_dirs(&session.session_dir()),
+            vec![session.session_dir(), agent_dir.session_dir(&segment)]
+        );
+        assert_eq!(
+            Session::chain_dirs(&agent_dir.session_dir(&segment)),
+            Session::chain_dirs(&session.session_dir())
+        );
+        assert_eq!(agent_dir.list_sessions(), vec![first]);
+    }
+
+    #[test]
+    fn test_rollover_by_size() {
+        let (_temp, mut session) = create_test_session();
+        session.set_rollover(&RolloverConfig { max_bytes: Some(64), ..Default::default() });
+        let first = session.id.clone();
+
+        session.append_user_message("x".repeat(100)).unwrap();
+        assert_eq!(session.segment_id(), first);
+        session.append_user_message("next").unwrap();
+        assert_ne!(session.segment_id(), first);
+        assert_eq!(session.id, first);
+    }
+
+    #[test]
+    fn test_rollover_shared_by_clones() {
+        let (_temp, mut session) = create_test_session();
+        session.set_rollover(&RolloverConfig { max_events: Some(2), ..Default::default() });
+        let mut other = session.clone();
+
+        session.append_user_message("one").unwrap();
+        session.append_user_message("two").unwrap();
+        session.append_user_message("three").unwrap();
+
+        let seq = other.append_user_message("four").unwrap();
+        assert_eq!(seq, 3);
+        assert_eq!(other.segment_id(), session.segment_id());
+        assert_eq!(session.read_events().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_load_resumes_newest_segment() {
+        let (_temp, mut session) = create_test_session();
+        session.set_rollover(&RolloverConfig { max_events: Some(1), ..Default::default() });
+        session.append_file_read("src/lib.rs", 10, 0, true).unwrap();
+        session.append_user_message("two").unwrap();
+
+        let mut resumed = Session::load(session.agent_dir(), session.id.clone()).unwrap();
+        assert_eq!(resumed.segment_id(), session.segment_id());
+        assert_eq!(resumed.append_user_message("three").unwrap(), 2);
+        assert!(resumed.was_file_read("src/lib.rs").unwrap().is_some());
+        assert_eq!(resumed.read_chain_events().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_no_rollover_by_default() {
+        let (_temp, mut session) = create_test_session();
+        let first = session.id.clone();
+        for i in 0..5 {
+            session.append_user_message(format!("message {}", i)).unwrap();
+        }
+        assert_eq!(session.id, first);
+        assert_eq!(Session::chain_dirs(&session.session_dir()), vec![session.session_dir()]);
+    }
 }
@@ -8,6 +8,7 @@
 //! tracking the higher-level intent (e.g., "Fix authentication bug", "Add dark mode").
 
 use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, RwLock};
 
 /// Current task context being tracked
@@ -71,12 +72,14 @@ impl TaskContext {
 pub struct TaskContextTracker {
     /// Current task context
     context: Arc<RwLock<Option<TaskContext>>>,
+    /// Whether the task was set explicitly and should not be re-inferred
+    pinned: Arc<AtomicBool>,
 }
 
 impl TaskContextTracker {
     /// Create a new task context tracker
     pub fn new() -> Self {
-        Self { context: Arc::new(RwLock::new(None)) }
+        Self { context: Arc::new(RwLock::new(None)), pinned: Arc::new(AtomicBool::new(false)) }
     }
 
     /// Get the current task context
@@ -92,12 +95,40 @@ impl TaskContextTracker {
     /// Clear the task context
     pub fn clear(&self) {
         *self.context.write().unwrap() = None;
+        self.pinned.store(false, Ordering::Relaxed);
+    }
+
+    /// Set the task explicitly, e.g. from a `/task` command
+    ///
+    /// A pinned task is kept until cleared; later messages only update its subtask.
+    pub fn pin(&self, task: impl Into<String>) {
+        self.set(TaskContext::new(task));
+        self.pinned.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether the current task was set explicitly
+    pub fn is_pinned(&self) -> bool {
+        self.pinned.load(Ordering::Relaxed)
+    }
+
+    /// Get the current task title, without any subtask
+    pub fn title(&self) -> Option<String> {
+        self.get().map(|ctx| ctx.task)
+    }
+
+    /// Infer a task title from a message without changing the tracked context
+    pub fn infer_title(message: &str) -> Option<String> {
+        extract_task_from_message(message).map(|ctx| ctx.task)
     }
 
     /// Update the task context from a user message
     ///
     /// This analyzes the user message to extract task intent and updates the context accordingly.
+    /// Pinned tasks are left unchanged.
     pub fn update_from_user_message(&self, message: &str) {
+        if self.is_pinned() {
+            return;
+        }
         let extracted = extract_task_from_message(message);
         if let Some(task) = extracted {
             self.set(task);
@@ -110,7 +141,9 @@ impl TaskContextTracker {
     pub fn update_from_model_response(&self, response: &str) {
         if let Some(ref current) = self.get() {
             if indicates_completion(response) {
-                self.clear();
+                if !self.is_pinned() {
+                    self.clear();
+                }
             } else if let Some(subtask) = extract_subtask_from_response(response) {
                 let updated = TaskContext {
                     task: current.task.clone(),
@@ -476,6 +509,24 @@ mod tests {
         assert!(tracker.get().is_none());
     }
 
+    #[test]
+    fn test_tracker_pinned_task() {
+        let tracker = TaskContextTracker::new();
+        tracker.pin("Ship the release");
+        assert!(tracker.is_pinned());
+
+        tracker.update_from_user_message("Fix the login bug");
+        assert_eq!(tracker.title(), Some("Ship the release".to_string()));
+
+        tracker.update_from_model_response("All done!");
+        assert_eq!(tracker.title(), Some("Ship the release".to_string()));
+
+        tracker.clear();
+        assert!(!tracker.is_pinned());
+        tracker.update_from_user_message("Fix the login bug");
+        assert_eq!(tracker.title(), Some("Fixing login bug".to_string()));
+    }
+
     #[test]
     fn test_tracker_brief_description() {
         let tracker = TaskContextTracker::new();
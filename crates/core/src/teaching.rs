@@ -7,11 +7,14 @@
 //! - Hints are shown only once per concept per session
 //! - Hints are brief and educational, not nagging
 //! - Teaching state is tracked in session metadata
+//! - Concepts can be dismissed permanently; dismissals live in a user state
+//!   file so they carry across sessions
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
 
-use crate::ToolRisk;
+use crate::{Result, ToolRisk};
 
 /// Concept IDs that have been taught in this session
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
@@ -60,6 +63,65 @@ impl TeachingState {
     }
 }
 
+/// Concepts the user has asked never to be shown again
+///
+/// Unlike [`TeachingState`], which is scoped to a session, dismissals are stored
+/// in `~/.thunderus/state/dismissed_hints.json` and apply to every session.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct DismissedHints {
+    /// Set of permanently dismissed concept IDs
+    #[serde(default)]
+    dismissed: BTreeSet<String>,
+}
+
+impl DismissedHints {
+    /// Default location of the user state file
+    pub fn default_path() -> Option<PathBuf> {
+        let home = std::env::var("HOME").or_else(|_| std::env::var("USERPROFILE")).ok()?;
+        Some(
+            PathBuf::from(home)
+                .join(".thunderus")
+                .join("state")
+                .join("dismissed_hints.json"),
+        )
+    }
+
+    /// Load dismissals from `path`
+    ///
+    /// A missing or unreadable file yields an empty set so hints keep working.
+    pub fn load(path: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Load dismissals from the default user state file
+    pub fn load_default() -> Self {
+        Self::default_path().map(|path| Self::load(&path)).unwrap_or_default()
+    }
+
+    /// Write dismissals to `path`, creating parent directories as needed
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(self).map_err(|e| crate::Error::Parse(e.to_string()))?;
+        std::fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// Permanently dismiss a concept
+    pub fn dismiss(&mut self, concept: impl Into<String>) {
+        self.dismissed.insert(concept.into());
+    }
+
+    /// Check if a concept has been permanently dismissed
+    pub fn is_dismissed(&self, concept: &str) -> bool {
+        self.dismissed.contains(concept)
+    }
+}
+
 /// Get the educational hint for a concept
 ///
 /// Public API for getting hints without needing a TeachingState instance.
@@ -153,7 +215,14 @@ pub fn get_hint_for_concept(concept: &str) -> Option<String> {
 /// Suggest a concept to teach based on context
 ///
 /// This analyzes the context (action type, risk level, tool/command name) and returns an appropriate concept ID to teach.
-pub fn suggest_concept(action_type: &str, risk_level: ToolRisk, context: &str) -> Option<String> {
+/// Concepts in `dismissed` are never suggested.
+pub fn suggest_concept(
+    action_type: &str, risk_level: ToolRisk, context: &str, dismissed: &DismissedHints,
+) -> Option<String> {
+    match_concept(action_type, risk_level, context).filter(|concept| !dismissed.is_dismissed(concept))
+}
+
+fn match_concept(action_type: &str, risk_level: ToolRisk, context: &str) -> Option<String> {
     match (action_type, risk_level.is_risky()) {
         ("shell", true) => match context {
             c if c.contains("rm") || c.contains("shred") || c.contains("rmdir") => Some("file_destruction".to_string()),
@@ -253,85 +322,125 @@ mod tests {
 
     #[test]
     fn test_suggest_concept_sed_risky() {
-        let concept = suggest_concept("shell", ToolRisk::Risky, "sed -i 's/old/new/g' file.txt");
+        let concept = suggest_concept(
+            "shell",
+            ToolRisk::Risky,
+            "sed -i 's/old/new/g' file.txt",
+            &DismissedHints::default(),
+        );
         assert_eq!(concept, Some("sed_risky_explained".to_string()));
     }
 
     #[test]
     fn test_suggest_concept_file_deletion() {
-        let concept = suggest_concept("shell", ToolRisk::Risky, "rm -rf /tmp/test");
+        let concept = suggest_concept("shell", ToolRisk::Risky, "rm -rf /tmp/test", &DismissedHints::default());
         assert_eq!(concept, Some("file_destruction".to_string()));
     }
 
     #[test]
     fn test_suggest_concept_network() {
-        let concept = suggest_concept("shell", ToolRisk::Risky, "curl https://api.example.com");
+        let concept = suggest_concept(
+            "shell",
+            ToolRisk::Risky,
+            "curl https://api.example.com",
+            &DismissedHints::default(),
+        );
         assert_eq!(concept, Some("network_command_explained".to_string()));
     }
 
     #[test]
     fn test_suggest_concept_package_install() {
-        let concept = suggest_concept("shell", ToolRisk::Risky, "npm install lodash");
+        let concept = suggest_concept(
+            "shell",
+            ToolRisk::Risky,
+            "npm install lodash",
+            &DismissedHints::default(),
+        );
         assert_eq!(concept, Some("package_install".to_string()));
     }
 
     #[test]
     fn test_suggest_concept_git_write() {
-        let concept = suggest_concept("shell", ToolRisk::Risky, "git push origin main");
+        let concept = suggest_concept(
+            "shell",
+            ToolRisk::Risky,
+            "git push origin main",
+            &DismissedHints::default(),
+        );
         assert_eq!(concept, Some("git_write_operations".to_string()));
     }
 
     #[test]
     fn test_suggest_concept_risky_command_generic() {
-        let concept = suggest_concept("shell", ToolRisk::Risky, "chmod +x script.sh");
+        let concept = suggest_concept(
+            "shell",
+            ToolRisk::Risky,
+            "chmod +x script.sh",
+            &DismissedHints::default(),
+        );
         assert_eq!(concept, Some("risky_command_explained".to_string()));
     }
 
     #[test]
     fn test_suggest_concept_edit_tool() {
-        let concept = suggest_concept("tool", ToolRisk::Risky, "edit");
+        let concept = suggest_concept("tool", ToolRisk::Risky, "edit", &DismissedHints::default());
         assert_eq!(concept, Some("edit_tool_benefits".to_string()));
     }
 
     #[test]
     fn test_suggest_concept_file_write() {
-        let concept = suggest_concept("file_write", ToolRisk::Risky, "");
+        let concept = suggest_concept("file_write", ToolRisk::Risky, "", &DismissedHints::default());
         assert_eq!(concept, Some("backup_on_risky".to_string()));
     }
 
     #[test]
     fn test_suggest_concept_file_delete() {
-        let concept = suggest_concept("file_delete", ToolRisk::Safe, "");
+        let concept = suggest_concept("file_delete", ToolRisk::Safe, "", &DismissedHints::default());
         assert_eq!(concept, Some("file_destruction".to_string()));
     }
 
     #[test]
     fn test_suggest_concept_network_action() {
-        let concept = suggest_concept("network", ToolRisk::Risky, "");
+        let concept = suggest_concept("network", ToolRisk::Risky, "", &DismissedHints::default());
         assert_eq!(concept, Some("network_command_explained".to_string()));
     }
 
     #[test]
     fn test_suggest_concept_none_for_safe_operations() {
-        let concept = suggest_concept("tool", ToolRisk::Safe, "grep");
+        let concept = suggest_concept("tool", ToolRisk::Safe, "grep", &DismissedHints::default());
         assert!(concept.is_none());
     }
 
     #[test]
     fn test_suggest_concept_sed_safe_full_access() {
-        let concept = suggest_concept("shell", ToolRisk::Safe, "sed 's/old/new/g' file.txt");
+        let concept = suggest_concept(
+            "shell",
+            ToolRisk::Safe,
+            "sed 's/old/new/g' file.txt",
+            &DismissedHints::default(),
+        );
         assert_eq!(concept, Some("sed_full_access".to_string()));
     }
 
     #[test]
     fn test_suggest_concept_awk_safe_read_only() {
-        let concept = suggest_concept("shell", ToolRisk::Safe, "awk '{print $1}' file.txt");
+        let concept = suggest_concept(
+            "shell",
+            ToolRisk::Safe,
+            "awk '{print $1}' file.txt",
+            &DismissedHints::default(),
+        );
         assert_eq!(concept, Some("awk_read_only_recommended".to_string()));
     }
 
     #[test]
     fn test_suggest_concept_awk_risky_with_redirection() {
-        let concept = suggest_concept("shell", ToolRisk::Risky, "awk '{print $1}' file.txt > output.txt");
+        let concept = suggest_concept(
+            "shell",
+            ToolRisk::Risky,
+            "awk '{print $1}' file.txt > output.txt",
+            &DismissedHints::default(),
+        );
         assert_eq!(concept, Some("awk_full_access".to_string()));
     }
 
@@ -423,4 +532,31 @@ mod tests {
         assert!(deserialized.has_taught("concept2"));
         assert!(!deserialized.has_taught("concept3"));
     }
+
+    #[test]
+    fn test_suggest_concept_skips_dismissed() {
+        let mut dismissed = DismissedHints::default();
+        dismissed.dismiss("sed_risky_explained");
+
+        let concept = suggest_concept("shell", ToolRisk::Risky, "sed -i 's/a/b/' f.txt", &dismissed);
+        assert!(concept.is_none());
+
+        let concept = suggest_concept("shell", ToolRisk::Risky, "rm -rf build", &dismissed);
+        assert_eq!(concept, Some("file_destruction".to_string()));
+    }
+
+    #[test]
+    fn test_dismissed_hints_roundtrip() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("state").join("dismissed_hints.json");
+        assert_eq!(DismissedHints::load(&path), DismissedHints::default());
+
+        let mut dismissed = DismissedHints::default();
+        dismissed.dismiss("file_destruction");
+        dismissed.save(&path).unwrap();
+
+        let loaded = DismissedHints::load(&path);
+        assert!(loaded.is_dismissed("file_destruction"));
+        assert!(!loaded.is_dismissed("sed_risky_explained"));
+    }
 }
@@ -0,0 +1,134 @@
+//! Portable session traces for bug reports
+//!
+//! A trace bundles everything needed to reproduce a session without the
+//! original repository: the raw event log, the configuration it ran with
+//! (secrets redacted), and the materialized views. Traces are written as a
+//! single JSON document so they can be attached to an issue as-is.
+
+use crate::error::{Error, Result};
+use crate::session::{LoggedEvent, Session};
+use crate::views::{MaterializedViews, ViewMaterializer};
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Version of the trace format written by [`SessionTrace::save`]
+pub const TRACE_FORMAT_VERSION: u32 = 1;
+
+/// Self-contained export of a session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionTrace {
+    /// Trace format version
+    pub version: u32,
+    /// ID of the exported session
+    pub session_id: String,
+    /// When the trace was exported (RFC 3339)
+    pub exported_at: String,
+    /// Configuration the session ran with, as TOML with secrets redacted
+    pub config: String,
+    /// Every event from the session's events.jsonl files, across rollover segments, in order
+    pub events: Vec<LoggedEvent>,
+    /// Views materialized from the events at export time
+    pub views: MaterializedViews,
+}
+
+impl SessionTrace {
+    /// Build a trace from a session and the raw TOML config it ran with
+    ///
+    /// Events and config are masked with the session's redactor, so set the
+    /// profile's redaction rules on the session first.
+    pub fn from_session(session: &Session, config: &str) -> Result<Self> {
+        let mut events = session.read_chain_events()?;
+        for logged in &mut events {
+            session.redactor().redact_event(&mut logged.event);
+        }
+        let views = ViewMaterializer::new(session).materialize_all()?;
+
+        Ok(Self {
+            version: TRACE_FORMAT_VERSION,
+            session_id: session.id.to_string(),
+            exported_at: chrono::Utc::now().to_rfc3339(),
+            config: session.redactor().redact_config(config)?,
+            events,
+            views,
+        })
+    }
+
+    /// Write the trace to `path` as pretty-printed JSON
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| Error::Parse(e.to_string()))?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Read a trace from `path`, rejecting unsupported format versions
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)?;
+        let trace: Self = serde_json::from_str(&content).map_err(|e| Error::Parse(e.to_string()))?;
+
+        if trace.version != TRACE_FORMAT_VERSION {
+            return Err(Error::Validation(format!(
+                "unsupported trace version {} (expected {})",
+                trace.version, TRACE_FORMAT_VERSION
+            )));
+        }
+
+        Ok(trace)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::AgentDir;
+    use tempfile::TempDir;
+
+    const CONFIG: &str = r#"
+default_profile = "default"
+
+[profiles.default]
+name = "default"
+
+[profiles.default.provider]
+provider = "glm"
+api_key = "sk-live-1234"
+model = "glm-4.7"
+max_tokens = 4096
+
+[[profiles.default.mcp]]
+auth_token = "ghp_abc"
+"#;
+
+    #[test]
+    fn test_trace_round_trip() {
+        let temp = TempDir::new().unwrap();
+        let mut session = Session::new(AgentDir::new(temp.path())).unwrap();
+        session.append_user_message("Fix the bug").unwrap();
+        session.append_model_message("Done", None).unwrap();
+
+        let trace = SessionTrace::from_session(&session, CONFIG).unwrap();
+        assert_eq!(trace.version, TRACE_FORMAT_VERSION);
+        assert_eq!(trace.session_id, session.id.to_string());
+        assert_eq!(trace.events.len(), 2);
+        assert!(!trace.config.contains("sk-live-1234"));
+
+        let path = temp.path().join("session.trace.json");
+        trace.save(&path).unwrap();
+        let loaded = SessionTrace::load(&path).unwrap();
+        assert_eq!(loaded.events, trace.events);
+        assert_eq!(loaded.views.memory, trace.views.memory);
+    }
+
+    #[test]
+    fn test_trace_load_rejects_unknown_version() {
+        let temp = TempDir::new().unwrap();
+        let session = Session::new(AgentDir::new(temp.path())).unwrap();
+        let mut trace = SessionTrace::from_session(&session, CONFIG).unwrap();
+        trace.version = TRACE_FORMAT_VERSION + 1;
+
+        let path = temp.path().join("future.trace.json");
+        trace.save(&path).unwrap();
+        assert!(matches!(SessionTrace::load(&path), Err(Error::Validation(_))));
+    }
+}
@@ -1,5 +1,6 @@
 use crate::MemoryDoc;
 use crate::error::{Error, Result};
+use crate::session::Session;
 use crate::session::events::{Event, LoggedEvent};
 
 use serde::{Deserialize, Serialize};
@@ -30,6 +31,9 @@ impl TrajectoryWalker {
     }
 
     /// Walk the trajectory for a given memory document
+    ///
+    /// If the session rolled over, the logs of the sessions it continues are
+    /// read first, so provenance can point at events from earlier in the chain.
     pub async fn walk(&self, doc: &MemoryDoc) -> Result<Vec<TrajectoryNode>> {
         let event_ids = &doc.frontmatter.provenance.events;
         if event_ids.is_empty() {
@@ -54,22 +58,33 @@ impl TrajectoryWalker {
             .collect();
 
         let mut trajectory = Vec::new();
+        let mut index = Vec::new();
 
-        let file = File::open(&events_path).await.map_err(Error::Io)?;
-        let mut reader = BufReader::new(file).lines();
+        for dir in Session::chain_dirs(&self.session_dir) {
+            let events_path = dir.join("events.jsonl");
+            if !events_path.exists() {
+                continue;
+            }
 
-        while let Some(line) = reader.next_line().await.map_err(Error::Io)? {
-            let logged: LoggedEvent =
-                serde_json::from_str(&line).map_err(|e| Error::Parse(format!("Failed to parse event: {}", e)))?;
+            let file = File::open(&events_path).await.map_err(Error::Io)?;
+            let mut reader = BufReader::new(file).lines();
 
-            if requested_seqs.contains(&logged.seq) {
-                let impact = self.derive_impact(&logged.event);
-                let causal_links = self.resolve_causal_links(&logged, &events_path).await?;
+            while let Some(line) = reader.next_line().await.map_err(Error::Io)? {
+                let logged: LoggedEvent =
+                    serde_json::from_str(&line).map_err(|e| Error::Parse(format!("Failed to parse event: {}", e)))?;
 
-                trajectory.push(TrajectoryNode { event: logged, impact, causal_links });
+                index.push(IndexedEvent::from(&logged));
+                if requested_seqs.contains(&logged.seq) {
+                    let impact = self.derive_impact(&logged.event);
+                    trajectory.push(TrajectoryNode { event: logged, impact, causal_links: Vec::new() });
+                }
             }
         }
 
+        for node in &mut trajectory {
+            node.causal_links = self.resolve_causal_links(&node.event, &index);
+        }
+
         trajectory.sort_by_key(|n| n.event.seq);
 
         Ok(trajectory)
@@ -81,6 +96,7 @@ impl TrajectoryWalker {
             Event::ToolResult { success: true, .. } => "Evidence".to_string(),
             Event::ToolResult { success: false, .. } => "Failure".to_string(),
             Event::Patch { .. } => "Change".to_string(),
+            Event::Approval { approved: true, reason: Some(_), .. } => "Policy".to_string(),
             Event::Approval { approved: true, .. } => "Verification".to_string(),
             Event::Approval { approved: false, .. } => "Correction".to_string(),
             Event::ViewEdit { .. } => "Human Intervention".to_string(),
@@ -88,53 +104,51 @@ impl TrajectoryWalker {
         }
     }
 
-    /// Resolve causal links for an event by scanning for related events
-    async fn resolve_causal_links(&self, node: &LoggedEvent, events_path: &std::path::Path) -> Result<Vec<u64>> {
-        let mut links = Vec::new();
-
+    /// Resolve causal links for an event from the index of the whole log
+    ///
+    /// A tool call links to the next result from the same tool; a patch links
+    /// back to the last tool call logged before it.
+    fn resolve_causal_links(&self, node: &LoggedEvent, index: &[IndexedEvent]) -> Vec<u64> {
         match &node.event {
-            Event::ToolCall { .. } => {
-                let file = File::open(events_path).await.map_err(Error::Io)?;
-                let mut reader = BufReader::new(file).lines();
-                while let Some(line) = reader.next_line().await.map_err(Error::Io)? {
-                    let logged: LoggedEvent = serde_json::from_str(&line)
-                        .map_err(|e| Error::Parse(format!("Failed to parse event: {}", e)))?;
-
-                    if logged.seq > node.seq
-                        && let Event::ToolResult { tool, .. } = &logged.event
-                        && let Event::ToolCall { tool: call_tool, .. } = &node.event
-                        && tool == call_tool
-                    {
-                        links.push(logged.seq);
-                        break;
-                    }
-                }
-            }
-            Event::Patch { .. } => {
-                let file = File::open(events_path).await.map_err(Error::Io)?;
-                let mut reader = BufReader::new(file).lines();
-                let mut last_tool_call_seq = None;
-
-                while let Some(line) = reader.next_line().await.map_err(Error::Io)? {
-                    let logged: LoggedEvent = serde_json::from_str(&line)
-                        .map_err(|e| Error::Parse(format!("Failed to parse event: {}", e)))?;
-
-                    if logged.seq < node.seq {
-                        if let Event::ToolCall { .. } = &logged.event {
-                            last_tool_call_seq = Some(logged.seq);
-                        }
-                    } else {
-                        break;
-                    }
-                }
-                if let Some(seq) = last_tool_call_seq {
-                    links.push(seq);
-                }
-            }
-            _ => {}
+            Event::ToolCall { tool: call_tool, .. } => index
+                .iter()
+                .find(|entry| entry.seq > node.seq && entry.tool_result.as_ref() == Some(call_tool))
+                .map(|entry| entry.seq)
+                .into_iter()
+                .collect(),
+            Event::Patch { .. } => index
+                .iter()
+                .take_while(|entry| entry.seq < node.seq)
+                .filter(|entry| entry.is_tool_call)
+                .last()
+                .map(|entry| entry.seq)
+                .into_iter()
+                .collect(),
+            _ => Vec::new(),
         }
+    }
+}
 
-        Ok(links)
+/// The parts of a logged event needed to resolve causal links
+///
+/// Built in the same pass that collects trajectory nodes, so links are
+/// resolved without re-reading the log or keeping every event in memory.
+struct IndexedEvent {
+    seq: u64,
+    is_tool_call: bool,
+    tool_result: Option<String>,
+}
+
+impl From<&LoggedEvent> for IndexedEvent {
+    fn from(logged: &LoggedEvent) -> Self {
+        Self {
+            seq: logged.seq,
+            is_tool_call: matches!(logged.event, Event::ToolCall { .. }),
+            tool_result: match &logged.event {
+                Event::ToolResult { tool, .. } => Some(tool.clone()),
+                _ => None,
+            },
+        }
     }
 }
 
@@ -169,6 +183,7 @@ mod tests {
                     result: serde_json::json!({"files": []}),
                     success: true,
                     error: None,
+                    tool_call_id: None,
                 },
             },
         ];
@@ -214,7 +229,11 @@ mod tests {
                 seq: 0,
                 session_id: "test".to_string(),
                 timestamp: "T1".to_string(),
-                event: Event::ToolCall { tool: "edit".to_string(), arguments: serde_json::json!({}) },
+                event: Event::ToolCall {
+                    tool: "edit".to_string(),
+                    arguments: serde_json::json!({}),
+                    tool_call_id: None,
+                },
             },
             LoggedEvent {
                 seq: 1,
@@ -236,6 +255,7 @@ mod tests {
                     result: serde_json::json!({}),
                     success: true,
                     error: None,
+                    tool_call_id: None,
                 },
             },
         ];
@@ -263,4 +283,84 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_causal_links_reach_events_outside_provenance() -> Result<()> {
+        let dir = tempdir().map_err(Error::Io)?;
+        let mut file = File::create(dir.path().join("events.jsonl")).await.map_err(Error::Io)?;
+
+        let call = |tool: &str| Event::ToolCall {
+            tool: tool.to_string(),
+            arguments: serde_json::json!({}),
+            tool_call_id: None,
+        };
+        let result = |tool: &str| Event::ToolResult {
+            tool: tool.to_string(),
+            result: serde_json::json!({}),
+            success: true,
+            error: None,
+            tool_call_id: None,
+        };
+        let patch = Event::Patch {
+            name: "fix".to_string(),
+            status: crate::session::events::PatchStatus::Applied,
+            files: vec!["f1.rs".to_string()],
+            diff: String::new(),
+        };
+
+        let events = [
+            call("read"),
+            call("edit"),
+            result("read"),
+            patch,
+            result("edit"),
+            result("edit"),
+        ];
+        for (seq, event) in events.into_iter().enumerate() {
+            let logged =
+                LoggedEvent { seq: seq as u64, session_id: "test".to_string(), timestamp: "T".to_string(), event };
+            let json = serde_json::to_string(&logged).unwrap();
+            file.write_all(json.as_bytes()).await.map_err(Error::Io)?;
+            file.write_all(b"\n").await.map_err(Error::Io)?;
+        }
+        file.flush().await.map_err(Error::Io)?;
+
+        let mut doc = MemoryDoc::new("f.t", "T", crate::MemoryKind::Fact, vec![], "B");
+        doc.add_provenance_event("evt_3");
+        doc.add_provenance_event("evt_1");
+
+        let trajectory = TrajectoryWalker::new(dir.path().to_path_buf()).walk(&doc).await?;
+
+        assert_eq!(trajectory.len(), 2);
+        assert_eq!(trajectory[0].event.seq, 1);
+        assert_eq!(trajectory[0].causal_links, vec![4]);
+        assert_eq!(trajectory[1].event.seq, 3);
+        assert_eq!(trajectory[1].causal_links, vec![1]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_trajectory_follows_rollover_chain() -> Result<()> {
+        let dir = tempdir().map_err(Error::Io)?;
+        let mut session = Session::new(crate::AgentDir::new(dir.path()))?;
+        session.set_rollover(&crate::RolloverConfig { max_events: Some(2), ..Default::default() });
+
+        session.append_user_message("Fix the parser")?;
+        session.append_tool_call("read", serde_json::json!({"path": "parser.rs"}))?;
+        session.append_tool_result("read", serde_json::json!({}), true, None)?;
+
+        let mut doc = MemoryDoc::new("f.t", "T", crate::MemoryKind::Fact, vec![], "B");
+        doc.add_provenance_event("evt_0");
+        doc.add_provenance_event("evt_2");
+
+        let trajectory = TrajectoryWalker::new(session.session_dir()).walk(&doc).await?;
+
+        assert_eq!(Session::chain_dirs(&session.session_dir()).len(), 2);
+        assert_eq!(trajectory.len(), 2);
+        assert_eq!(trajectory[0].impact, "Input");
+        assert_eq!(trajectory[1].event.seq, 2);
+
+        Ok(())
+    }
 }
@@ -40,6 +40,21 @@ impl ViewKind {
             ViewKind::Decisions => "Decisions",
         }
     }
+
+    /// All view kinds, in materialization order
+    pub const ALL: [ViewKind; 3] = [ViewKind::Memory, ViewKind::Plan, ViewKind::Decisions];
+
+    /// Check whether an event can change this view's content
+    pub fn depends_on(&self, event: &Event) -> bool {
+        match (self, event) {
+            (_, Event::ViewEdit { view, .. }) => view == self.filename(),
+            (ViewKind::Memory | ViewKind::Decisions, Event::ModelMessage { .. }) => true,
+            (ViewKind::Memory, Event::MemoryUpdate { kind, .. }) => kind == "core",
+            (ViewKind::Decisions, Event::MemoryUpdate { kind, .. }) => kind == "semantic" || kind == "decisions",
+            (ViewKind::Plan, Event::PlanUpdate { .. } | Event::Checkpoint { .. }) => true,
+            _ => false,
+        }
+    }
 }
 
 /// All materialized views with their content and metadata
@@ -53,6 +68,28 @@ pub struct MaterializedViews {
     pub decisions: String,
     /// Last sequence number used for materialization
     pub last_seq: u64,
+    /// Number of events the views were materialized from
+    #[serde(default)]
+    pub event_count: usize,
+}
+
+impl MaterializedViews {
+    /// Get the content of a specific view
+    pub fn get(&self, view: ViewKind) -> &str {
+        match view {
+            ViewKind::Memory => &self.memory,
+            ViewKind::Plan => &self.plan,
+            ViewKind::Decisions => &self.decisions,
+        }
+    }
+
+    fn set(&mut self, view: ViewKind, content: String) {
+        match view {
+            ViewKind::Memory => self.memory = content,
+            ViewKind::Plan => self.plan = content,
+            ViewKind::Decisions => self.decisions = content,
+        }
+    }
 }
 
 /// View materializer that regenerates markdown views from events
@@ -70,28 +107,59 @@ impl<'a> ViewMaterializer<'a> {
 
     /// Regenerate all views from events.jsonl
     pub fn materialize_all(&self) -> Result<MaterializedViews> {
-        let events = self.session.read_events()?;
+        let events = self.session.read_chain_events()?;
         let last_seq = events.iter().map(|e| e.seq).max().unwrap_or(0);
 
         Ok(MaterializedViews {
-            memory: self.materialize(ViewKind::Memory)?,
-            plan: self.materialize(ViewKind::Plan)?,
-            decisions: self.materialize(ViewKind::Decisions)?,
+            memory: self.render(ViewKind::Memory, &events),
+            plan: self.render(ViewKind::Plan, &events),
+            decisions: self.render(ViewKind::Decisions, &events),
             last_seq,
+            event_count: events.len(),
         })
     }
 
+    /// Bring previously materialized views up to date
+    ///
+    /// Only views that depend on events appended since `previous` was built are
+    /// re-rendered; the rest are carried over. Returns the updated views and the
+    /// kinds that were re-rendered. Falls back to a full rebuild if the event log
+    /// is shorter than `previous` expects.
+    pub fn materialize_incremental(&self, previous: &MaterializedViews) -> Result<(MaterializedViews, Vec<ViewKind>)> {
+        let events = self.session.read_chain_events()?;
+        if events.len() < previous.event_count {
+            return Ok((self.materialize_all()?, ViewKind::ALL.to_vec()));
+        }
+
+        let new_events = &events[previous.event_count..];
+        let affected: Vec<ViewKind> = ViewKind::ALL
+            .into_iter()
+            .filter(|view| new_events.iter().any(|e| view.depends_on(&e.event)))
+            .collect();
+
+        let mut views = previous.clone();
+        for view in &affected {
+            views.set(*view, self.render(*view, &events));
+        }
+        views.last_seq = events.iter().map(|e| e.seq).max().unwrap_or(0);
+        views.event_count = events.len();
+
+        Ok((views, affected))
+    }
+
     /// Regenerate a specific view
     pub fn materialize(&self, view: ViewKind) -> Result<String> {
-        let events = self.session.read_events()?;
+        let events = self.session.read_chain_events()?;
+        Ok(self.render(view, &events))
+    }
 
+    fn render(&self, view: ViewKind, events: &[LoggedEvent]) -> String {
         let content = match view {
-            ViewKind::Memory => self.materialize_memory(&events),
-            ViewKind::Plan => self.materialize_plan(&events),
-            ViewKind::Decisions => self.materialize_decisions(&events),
+            ViewKind::Memory => self.materialize_memory(events),
+            ViewKind::Plan => self.materialize_plan(events),
+            ViewKind::Decisions => self.materialize_decisions(events),
         };
-
-        Ok(content)
+        self.session.redactor().redact(&content)
     }
 
     /// Apply user edit to a view and log as event
@@ -286,10 +354,16 @@ impl<'a> ViewMaterializer<'a> {
 
         for event in events {
             match &event.event {
-                Event::PlanUpdate { action, item, reason } => {
+                Event::PlanUpdate { action, item, reason, position } => {
                     let completed = action == "complete" || action == "done";
                     if action == "remove" {
                         tasks.retain(|(t, _, _)| t != item);
+                    } else if action == "move" {
+                        if let Some(from) = tasks.iter().position(|(t, _, _)| t == item) {
+                            let task = tasks.remove(from);
+                            let to = position.unwrap_or(tasks.len()).min(tasks.len());
+                            tasks.insert(to, task);
+                        }
                     } else {
                         tasks.push((item.clone(), completed, reason.clone()));
                     }
@@ -722,6 +796,22 @@ Some text here
         assert_eq!(view, deserialized);
     }
 
+    #[test]
+    fn test_task_move() {
+        let (temp, mut session) = create_test_session();
+        session.append_plan_update("add", "Task 1", None).unwrap();
+        session.append_plan_update("add", "Task 2", None).unwrap();
+        session.append_plan_update("add", "Task 3", None).unwrap();
+        session.append_plan_move("Task 3", 0).unwrap();
+
+        let plan = ViewMaterializer::new(&session).materialize(ViewKind::Plan).unwrap();
+        let task_3 = plan.find("Task 3").unwrap();
+        assert!(task_3 < plan.find("Task 1").unwrap());
+        assert!(plan.find("Task 1").unwrap() < plan.find("Task 2").unwrap());
+
+        drop(temp);
+    }
+
     #[test]
     fn test_materialized_views_serialization() {
         let views = MaterializedViews {
@@ -729,6 +819,7 @@ Some text here
             plan: "# Plan".to_string(),
             decisions: "# Decisions".to_string(),
             last_seq: 42,
+            event_count: 43,
         };
 
         let json = serde_json::to_string(&views).unwrap();
@@ -887,4 +978,80 @@ Some text here
 
         drop(temp);
     }
+
+    #[test]
+    fn test_view_dependencies() {
+        let plan_event =
+            Event::PlanUpdate { action: "add".to_string(), item: "Task".to_string(), reason: None, position: None };
+        assert!(ViewKind::Plan.depends_on(&plan_event));
+        assert!(!ViewKind::Memory.depends_on(&plan_event));
+
+        let user_event = Event::UserMessage { content: "hello".to_string() };
+        assert!(ViewKind::ALL.iter().all(|view| !view.depends_on(&user_event)));
+    }
+
+    #[test]
+    fn test_materialize_incremental_only_affected_views() {
+        let (temp, mut session) = create_test_session();
+        session.append_plan_update("add", "Task 1", None).unwrap();
+
+        let materializer = ViewMaterializer::new(&session);
+        let mut initial = materializer.materialize_all().unwrap();
+        initial.memory = "stale memory".to_string();
+
+        session.append_user_message("Keep going").unwrap();
+        session.append_plan_update("add", "Task 2", None).unwrap();
+
+        let materializer = ViewMaterializer::new(&session);
+        let (views, affected) = materializer.materialize_incremental(&initial).unwrap();
+
+        assert_eq!(affected, vec![ViewKind::Plan]);
+        assert!(views.plan.contains("Task 2"));
+        assert_eq!(views.memory, "stale memory");
+        assert_eq!(views.event_count, 3);
+        assert_eq!(views.last_seq, 2);
+
+        let (unchanged, affected) = materializer.materialize_incremental(&views).unwrap();
+        assert!(affected.is_empty());
+        assert_eq!(unchanged.plan, views.plan);
+
+        drop(temp);
+    }
+
+    #[test]
+    fn test_materialize_across_rollover() {
+        let (temp, mut session) = create_test_session();
+        session.set_rollover(&crate::config::RolloverConfig { max_events: Some(2), ..Default::default() });
+        session.append_plan_update("add", "Task 1", None).unwrap();
+        session
+            .append_model_message("## Commands\n- `cargo test` - run tests\n", None)
+            .unwrap();
+
+        let materializer = ViewMaterializer::new(&session);
+        let before = materializer.materialize_all().unwrap();
+
+        session.append_plan_update("add", "Task 2", None).unwrap();
+        let first = session.segment_id();
+        session.append_user_message("Keep going").unwrap();
+        session.append_plan_update("complete", "Task 1", None).unwrap();
+        assert_ne!(session.segment_id(), first);
+
+        let materializer = ViewMaterializer::new(&session);
+        let views = materializer.materialize_all().unwrap();
+        assert!(views.plan.contains("[x] Task 1"));
+        assert!(views.plan.contains("Task 2"));
+        assert!(views.memory.contains("cargo test"));
+        assert_eq!(views.event_count, 5);
+
+        let (incremental, affected) = materializer.materialize_incremental(&before).unwrap();
+        assert_eq!(affected, vec![ViewKind::Plan]);
+        assert_eq!(incremental.plan, views.plan);
+        assert!(incremental.memory.contains("cargo test"));
+        assert_eq!(materializer.materialize(ViewKind::Plan).unwrap(), views.plan);
+
+        let seqs: Vec<_> = session.read_events_from(1).unwrap().iter().map(|e| e.seq).collect();
+        assert_eq!(seqs, vec![1, 2, 3, 4]);
+
+        drop(temp);
+    }
 }
@@ -0,0 +1,66 @@
+//! Workspace file walking shared by every code path that scans the tree
+//!
+//! Walks honour `.gitignore` (with git's global and exclude files) and a
+//! `.thunderusignore` in gitignore syntax. The latter applies outside git
+//! checkouts too, so it can exclude directories like `node_modules` that
+//! aren't git-ignored but are irrelevant to the agent.
+
+use ignore::WalkBuilder;
+use std::path::Path;
+
+/// Name of the agent-specific ignore file
+pub const IGNORE_FILE_NAME: &str = ".thunderusignore";
+
+/// Build a walker over `root` that skips hidden and ignored files
+pub fn workspace_walker(root: impl AsRef<Path>) -> WalkBuilder {
+    let mut builder = WalkBuilder::new(root);
+    builder
+        .standard_filters(true)
+        .add_custom_ignore_filename(IGNORE_FILE_NAME);
+    builder
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn walked_files(root: &Path) -> Vec<String> {
+        let mut files: Vec<String> = workspace_walker(root)
+            .build()
+            .flatten()
+            .filter(|entry| entry.path().is_file())
+            .map(|entry| entry.path().strip_prefix(root).unwrap().to_string_lossy().to_string())
+            .collect();
+        files.sort();
+        files
+    }
+
+    #[test]
+    fn test_workspace_walker_honours_thunderusignore_outside_git() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("node_modules/pkg")).unwrap();
+        fs::create_dir_all(temp.path().join("src")).unwrap();
+        fs::write(temp.path().join("node_modules/pkg/index.js"), "").unwrap();
+        fs::write(temp.path().join("src/main.rs"), "").unwrap();
+        fs::write(temp.path().join("notes.log"), "").unwrap();
+        fs::write(temp.path().join(IGNORE_FILE_NAME), "node_modules/\n*.log\n").unwrap();
+
+        assert_eq!(walked_files(temp.path()), vec!["src/main.rs"]);
+    }
+
+    #[test]
+    fn test_workspace_walker_combines_with_gitignore() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir(temp.path().join(".git")).unwrap();
+        fs::write(temp.path().join(".gitignore"), "target/\n").unwrap();
+        fs::write(temp.path().join(IGNORE_FILE_NAME), "vendor/\n").unwrap();
+        for dir in ["target", "vendor", "src"] {
+            fs::create_dir(temp.path().join(dir)).unwrap();
+            fs::write(temp.path().join(dir).join("file.rs"), "").unwrap();
+        }
+
+        assert_eq!(walked_files(temp.path()), vec!["src/file.rs"]);
+    }
+}
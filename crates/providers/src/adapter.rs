@@ -5,6 +5,7 @@ use eventsource_stream::Eventsource;
 use futures::{StreamExt, stream::Stream};
 use reqwest::Client as HttpClient;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::pin::Pin;
 use std::sync::Arc;
 use thunderus_core::Result;
@@ -25,6 +26,35 @@ pub trait Provider: Send + Sync {
     async fn stream_chat<'a>(
         &'a self, request: ChatRequest, cancel_token: CancelToken,
     ) -> Result<Pin<Box<dyn Stream<Item = StreamEvent> + Send + 'a>>>;
+
+    /// Model name requests are sent to, if the provider targets a specific model
+    fn model(&self) -> Option<&str> {
+        None
+    }
+
+    /// Maximum number of tokens the target model accepts as context, if known
+    fn context_window(&self) -> Option<usize> {
+        None
+    }
+
+    /// List the model names available to this provider
+    ///
+    /// Returns `Ok(None)` when the provider API has no model-listing endpoint.
+    async fn list_models(&self) -> Result<Option<Vec<String>>> {
+        Ok(None)
+    }
+}
+
+/// Model list response from an OpenAI-compatible `/models` endpoint
+#[derive(Debug, Deserialize)]
+struct GlmModelList {
+    #[serde(default)]
+    data: Vec<GlmModelEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GlmModelEntry {
+    id: String,
 }
 
 /// GLM-4.7 provider implementation
@@ -102,19 +132,44 @@ impl GlmProvider {
         })
     }
 
+    /// Deserialize an SSE chunk once for all of the parsers below
+    fn decode_chunk(chunk: &str) -> serde_json::Result<GlmChunk> {
+        serde_json::from_str(chunk)
+    }
+
+    /// Extract token usage from a decoded chunk, if the chunk carries it
+    fn parse_usage(data: &GlmChunk) -> Option<Usage> {
+        data.usage.clone()
+    }
+
+    /// Extract raw tool-call deltas from a decoded chunk
+    fn parse_tool_call_deltas(data: &GlmChunk) -> &[GlmToolCall] {
+        data.choices
+            .as_ref()
+            .and_then(|choices| choices.first())
+            .and_then(|choice| choice.delta.tool_calls.as_deref())
+            .unwrap_or_default()
+    }
+
     /// Parse SSE chunk into ParsedChunk with metadata
+    #[cfg(test)]
     fn parse_chunk(&self, chunk: &str) -> ParsedChunk {
+        self.parse_decoded(chunk, &Self::decode_chunk(chunk))
+    }
+
+    /// Build a ParsedChunk from an SSE chunk and its decoded form
+    fn parse_decoded(&self, chunk: &str, decoded: &serde_json::Result<GlmChunk>) -> ParsedChunk {
         if chunk.trim().is_empty() || chunk.starts_with("[DONE]") {
             return ParsedChunk { event: StreamEvent::Done, request_id: None, model: None, finish_reason: None };
         }
 
-        match serde_json::from_str::<GlmChunk>(chunk) {
+        match decoded {
             Ok(data) => {
                 let request_id = data.id.clone();
                 let model = data.model.clone();
                 let mut finish_reason = None;
 
-                if let Some(choices) = data.choices
+                if let Some(choices) = &data.choices
                     && let Some(choice) = choices.first()
                 {
                     finish_reason = choice.finish_reason.clone();
@@ -231,6 +286,9 @@ impl Provider for GlmProvider {
 
             let eventsource = response.bytes_stream().eventsource();
             tokio::pin!(eventsource);
+            let mut finish_reason = None;
+            let mut usage = None;
+            let mut tool_calls = GlmToolCallAccumulator::default();
 
             while let Some(event_result) = eventsource.next().await {
                 if cancel_token_clone.is_cancelled() {
@@ -240,12 +298,24 @@ impl Provider for GlmProvider {
 
                 match event_result {
                     Ok(event) => {
-                        let parsed = self.parse_chunk(&event.data);
+                        let decoded = Self::decode_chunk(&event.data);
+                        let parsed = self.parse_decoded(&event.data, &decoded);
                         let is_done = matches!(parsed.event, StreamEvent::Done);
+                        if let Some(ref reason) = parsed.finish_reason {
+                            finish_reason = Some(FinishReason::from_glm(reason));
+                        }
+                        if let Ok(data) = &decoded {
+                            if let Some(chunk_usage) = Self::parse_usage(data) {
+                                usage = Some(chunk_usage);
+                            }
+                            for started in tool_calls.push(Self::parse_tool_call_deltas(data)) {
+                                yield StreamEvent::ToolCallStarted(started);
+                            }
+                        }
 
                         if is_done
                             && let Some(ref reason) = parsed.finish_reason {
-                                if let Ok(chunk_data) = serde_json::from_str::<GlmChunk>(&event.data) {
+                                if let Ok(chunk_data) = &decoded {
                                     tracing::debug!(
                                         request_id = ?parsed.request_id,
                                         model = ?parsed.model,
@@ -265,7 +335,22 @@ impl Provider for GlmProvider {
                                 }
                             }
 
-                        yield parsed.event;
+                        if is_done && !tool_calls.is_empty() {
+                            yield StreamEvent::ToolCall(tool_calls.finish());
+                        }
+
+                        if is_done && let Some(usage) = usage.take() {
+                            yield StreamEvent::Usage(usage);
+                        }
+
+                        if is_done && let Some(reason) = finish_reason.take() {
+                            yield StreamEvent::Finish(reason.to_string());
+                        }
+
+                        // Tool calls are emitted as one batch once the response is complete
+                        if !matches!(parsed.event, StreamEvent::ToolCall(_)) {
+                            yield parsed.event;
+                        }
 
                         if is_done {
                             break;
@@ -277,10 +362,55 @@ impl Provider for GlmProvider {
                     }
                 }
             }
+
+            if !tool_calls.is_empty() {
+                yield StreamEvent::ToolCall(tool_calls.finish());
+            }
         };
 
         Ok(Box::pin(stream))
     }
+
+    fn model(&self) -> Option<&str> {
+        Some(&self.model)
+    }
+
+    fn context_window(&self) -> Option<usize> {
+        if self.model.starts_with("glm-4.6") || self.model.starts_with("glm-4.7") {
+            Some(200_000)
+        } else {
+            Some(128_000)
+        }
+    }
+
+    async fn list_models(&self) -> Result<Option<Vec<String>>> {
+        let url = format!("{}/models", self.base_url);
+        let response = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", self.api_key))
+            .send()
+            .await
+            .map_err(|e| thunderus_core::Error::Provider(format!("GLM model list request failed: {}", e)))?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(thunderus_core::Error::Provider(format!(
+                "GLM API error: {} - {}",
+                status, body
+            )));
+        }
+
+        let list: GlmModelList = response
+            .json()
+            .await
+            .map_err(|e| thunderus_core::Error::Provider(format!("Failed to parse GLM model list: {}", e)))?;
+        Ok(Some(list.data.into_iter().map(|m| m.id).collect()))
+    }
 }
 
 /// GLM API request format
@@ -327,6 +457,8 @@ struct GlmChunk {
     created: Option<u64>,
     model: Option<String>,
     choices: Option<Vec<GlmChoice>>,
+    #[serde(default)]
+    usage: Option<Usage>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -350,6 +482,8 @@ struct GlmDelta {
 
 #[derive(Debug, Deserialize)]
 struct GlmToolCall {
+    #[serde(default)]
+    index: Option<u32>,
     #[serde(default)]
     id: Option<String>,
     #[serde(default)]
@@ -366,6 +500,103 @@ struct GlmFunction {
     arguments: Option<String>,
 }
 
+/// Assembles streamed GLM tool-call deltas into complete calls
+///
+/// GLM may return several tool calls in one response, each streamed as deltas
+/// tagged with the call's `index`. Fragments are merged per index so every call
+/// reaches the dispatcher as a single batch.
+#[derive(Debug, Default)]
+struct GlmToolCallAccumulator {
+    calls: BTreeMap<u32, GlmPendingToolCall>,
+}
+
+#[derive(Debug, Default)]
+struct GlmPendingToolCall {
+    id: String,
+    call_type: Option<String>,
+    name: String,
+    arguments: String,
+}
+
+impl GlmToolCallAccumulator {
+    /// Merge deltas into the pending calls, returning calls whose name just became known
+    fn push(&mut self, deltas: &[GlmToolCall]) -> Vec<ToolCall> {
+        let mut started = Vec::new();
+        for (position, delta) in deltas.iter().enumerate() {
+            let index = delta.index.unwrap_or(position as u32);
+            let call = self.calls.entry(index).or_default();
+            let was_named = !call.name.is_empty();
+
+            if let Some(id) = delta.id.as_ref().filter(|id| !id.is_empty()) {
+                call.id = id.clone();
+            }
+            if let Some(call_type) = &delta.r#type {
+                call.call_type = Some(call_type.clone());
+            }
+            if let Some(function) = &delta.function {
+                if let Some(name) = function.name.as_ref().filter(|name| !name.is_empty()) {
+                    call.name = name.clone();
+                }
+                if let Some(arguments) = &function.arguments {
+                    call.arguments.push_str(arguments);
+                }
+            }
+
+            if !was_named && !call.name.is_empty() {
+                started.push(ToolCall {
+                    id: call.id.clone(),
+                    call_type: call.call_type.clone().unwrap_or_else(|| "function".to_string()),
+                    function: FunctionCall { name: call.name.clone(), arguments: serde_json::Value::Null },
+                });
+            }
+        }
+        started
+    }
+
+    fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    /// Drain the accumulated calls in index order
+    fn finish(&mut self) -> Vec<ToolCall> {
+        std::mem::take(&mut self.calls)
+            .into_values()
+            .filter(|call| !call.name.is_empty())
+            .map(|call| ToolCall {
+                id: call.id,
+                call_type: call.call_type.unwrap_or_else(|| "function".to_string()),
+                function: FunctionCall {
+                    name: call.name,
+                    arguments: serde_json::from_str(&call.arguments).unwrap_or(serde_json::Value::Null),
+                },
+            })
+            .collect()
+    }
+}
+
+/// Model list response from the Gemini `models` endpoint
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GeminiModelList {
+    #[serde(default)]
+    models: Vec<GeminiModelEntry>,
+    #[serde(default)]
+    next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GeminiModelEntry {
+    name: String,
+}
+
+/// Gemini reports `STOP` after function calls, so report those turns as tool calls
+fn gemini_stream_finish(reason: FinishReason, saw_tool_call: bool) -> FinishReason {
+    match reason {
+        FinishReason::Stop if saw_tool_call => FinishReason::ToolCalls,
+        reason => reason,
+    }
+}
+
 /// Gemini provider implementation
 pub struct GeminiProvider {
     client: HttpClient,
@@ -398,16 +629,12 @@ impl GeminiProvider {
 
     /// Convert ChatRequest to Gemini API format
     fn to_gemini_request(&self, request: &ChatRequest) -> Result<GeminiChatRequest> {
-        let mut system_instruction = None;
+        let mut system_parts: Vec<&str> = Vec::new();
         let mut contents: Vec<GeminiContent> = Vec::new();
 
         for msg in &request.messages {
             match msg.role {
-                Role::System => {
-                    system_instruction = Some(GeminiSystemInstruction {
-                        parts: vec![GeminiPart { text: Some(msg.content.clone()), ..Default::default() }],
-                    });
-                }
+                Role::System => system_parts.push(&msg.content),
                 Role::User => {
                     contents.push(GeminiContent {
                         role: "user".to_string(),
@@ -423,12 +650,13 @@ impl GeminiProvider {
                                 function_call: Some(GeminiFunctionCall {
                                     name: tc.function.name.clone(),
                                     args: tc.function.arguments.clone(),
+                                    ..Default::default()
                                 }),
                                 ..Default::default()
                             });
                         }
                     }
-                    contents.push(GeminiContent { role: "assistant".to_string(), parts });
+                    contents.push(GeminiContent { role: "model".to_string(), parts });
                 }
                 Role::Tool => {
                     if let Some(ref tool_call_id) = msg.tool_call_id {
@@ -447,6 +675,11 @@ impl GeminiProvider {
             }
         }
 
+        // Gemini takes a single system instruction outside `contents`, so merge them
+        let system_instruction = (!system_parts.is_empty()).then(|| GeminiSystemInstruction {
+            parts: vec![GeminiPart { text: Some(system_parts.join("\n\n")), ..Default::default() }],
+        });
+
         let tools = request.tools.as_ref().map(|tools| {
             vec![GeminiTool {
                 function_declarations: tools
@@ -478,8 +711,7 @@ impl GeminiProvider {
     /// Convert parameters to uppercase types for Gemini 3 (STRING, INTEGER, OBJECT, etc.)
     fn convert_to_uppercase_parameters(params: &ToolParameter) -> ToolParameter {
         match params {
-            ToolParameter::String { description } => ToolParameter::String { description: description.clone() },
-            ToolParameter::Number { description } => ToolParameter::Number { description: description.clone() },
+            ToolParameter::String { .. } | ToolParameter::Number { .. } => params.clone(),
             ToolParameter::Boolean { description } => ToolParameter::Boolean { description: description.clone() },
             ToolParameter::Array { items, description } => ToolParameter::Array {
                 items: Box::new(Self::convert_to_uppercase_parameters(items)),
@@ -499,13 +731,48 @@ impl GeminiProvider {
         }
     }
 
+    /// Deserialize a streamed chunk once for all of the parsers below
+    fn decode_chunk(chunk: &str) -> serde_json::Result<GeminiChunk> {
+        serde_json::from_str(chunk)
+    }
+
+    /// Extract token usage from a decoded chunk, if the chunk carries it
+    fn parse_usage(data: &GeminiChunk) -> Option<Usage> {
+        let metadata = data.usage_metadata.as_ref()?;
+        Some(Usage::new(
+            metadata.prompt_token_count,
+            metadata.candidates_token_count + metadata.thoughts_token_count,
+        ))
+    }
+
+    /// Take the `functionCall` parts out of a decoded chunk
+    fn parse_function_calls(data: GeminiChunk) -> Vec<GeminiFunctionCall> {
+        data.candidates
+            .and_then(|candidates| candidates.into_iter().next())
+            .and_then(|candidate| candidate.content)
+            .map(|content| {
+                content
+                    .parts
+                    .into_iter()
+                    .filter_map(|part| part.function_call)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Parse Gemini API chunk into ParsedChunk with metadata
+    #[cfg(test)]
     fn parse_chunk(&self, chunk: &str) -> ParsedChunk {
+        self.parse_decoded(chunk, &Self::decode_chunk(chunk))
+    }
+
+    /// Build a ParsedChunk from a streamed chunk and its decoded form
+    fn parse_decoded(&self, chunk: &str, decoded: &serde_json::Result<GeminiChunk>) -> ParsedChunk {
         if chunk.trim().is_empty() {
             return ParsedChunk { event: StreamEvent::Done, request_id: None, model: None, finish_reason: None };
         }
 
-        match serde_json::from_str::<GeminiChunk>(chunk) {
+        match decoded {
             Ok(data) => {
                 let finish_reason = data
                     .candidates
@@ -626,6 +893,10 @@ impl Provider for GeminiProvider {
             tokio::pin!(bytes_stream);
 
             let mut buffer = Vec::new();
+            let mut finish_reason = None;
+            let mut saw_tool_call = false;
+            let mut usage = None;
+            let mut tool_calls = GeminiToolCallAccumulator::default();
 
             while let Some(item_result) = bytes_stream.next().await {
                 if cancel_token_clone.is_cancelled() {
@@ -643,8 +914,23 @@ impl Provider for GeminiProvider {
 
                             let line = String::from_utf8_lossy(&line_bytes).to_string();
                             if !line.trim().is_empty() {
-                                let parsed = self.parse_chunk(&line);
+                                let decoded = Self::decode_chunk(&line);
+                                let parsed = self.parse_decoded(&line, &decoded);
                                 let is_done = matches!(parsed.event, StreamEvent::Done);
+                                saw_tool_call |= matches!(parsed.event, StreamEvent::ToolCall(_));
+                                if let Some(ref reason) = parsed.finish_reason {
+                                    finish_reason = Some(FinishReason::from_gemini(reason));
+                                }
+                                if let Ok(data) = decoded {
+                                    if let Some(chunk_usage) = Self::parse_usage(&data) {
+                                        usage = Some(chunk_usage);
+                                    }
+                                    for call in Self::parse_function_calls(data) {
+                                        for event in tool_calls.push(call) {
+                                            yield event;
+                                        }
+                                    }
+                                }
 
                                 if is_done
                                     && let Some(ref reason) = parsed.finish_reason {
@@ -655,7 +941,22 @@ impl Provider for GeminiProvider {
                                         );
                                     }
 
-                                yield parsed.event;
+                                if is_done && let Some(event) = tool_calls.finish() {
+                                    yield event;
+                                }
+
+                                if is_done && let Some(usage) = usage.take() {
+                                    yield StreamEvent::Usage(usage);
+                                }
+
+                                if is_done && let Some(reason) = finish_reason.take() {
+                                    yield StreamEvent::Finish(gemini_stream_finish(reason, saw_tool_call).to_string());
+                                }
+
+                                // Function calls are emitted by the accumulator once complete
+                                if !matches!(parsed.event, StreamEvent::ToolCall(_)) {
+                                    yield parsed.event;
+                                }
 
                                 if is_done {
                                     break;
@@ -669,17 +970,83 @@ impl Provider for GeminiProvider {
                     }
                 }
             }
+
+            if let Some(event) = tool_calls.finish() {
+                yield event;
+            }
+
+            if let Some(reason) = finish_reason {
+                if let Some(usage) = usage {
+                    yield StreamEvent::Usage(usage);
+                }
+                yield StreamEvent::Finish(gemini_stream_finish(reason, saw_tool_call).to_string());
+                yield StreamEvent::Done;
+            }
         };
 
         Ok(Box::pin(stream))
     }
+
+    fn model(&self) -> Option<&str> {
+        Some(&self.model)
+    }
+
+    fn context_window(&self) -> Option<usize> {
+        Some(1_048_576)
+    }
+
+    async fn list_models(&self) -> Result<Option<Vec<String>>> {
+        let mut models = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let mut url = format!("{}/models?pageSize=1000&key={}", self.base_url, self.api_key);
+            if let Some(token) = &page_token {
+                url.push_str(&format!("&pageToken={}", token));
+            }
+
+            let response = self
+                .client
+                .get(&url)
+                .send()
+                .await
+                .map_err(|e| thunderus_core::Error::Provider(format!("Gemini model list request failed: {}", e)))?;
+
+            if !response.status().is_success() {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                return Err(thunderus_core::Error::Provider(format!(
+                    "Gemini API error: {} - {}",
+                    status, body
+                )));
+            }
+
+            let page: GeminiModelList = response
+                .json()
+                .await
+                .map_err(|e| thunderus_core::Error::Provider(format!("Failed to parse Gemini model list: {}", e)))?;
+
+            models.extend(
+                page.models
+                    .into_iter()
+                    .map(|m| m.name.strip_prefix("models/").map(str::to_string).unwrap_or(m.name)),
+            );
+
+            match page.next_page_token.filter(|t| !t.is_empty()) {
+                Some(token) => page_token = Some(token),
+                None => break,
+            }
+        }
+
+        Ok(Some(models))
+    }
 }
 
 /// Gemini API request format
 #[derive(Debug, Serialize)]
 struct GeminiChatRequest {
     contents: Vec<GeminiContent>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "systemInstruction", skip_serializing_if = "Option::is_none")]
     system_instruction: Option<GeminiSystemInstruction>,
     #[serde(skip_serializing_if = "Option::is_none")]
     tools: Option<Vec<GeminiTool>>,
@@ -694,6 +1061,7 @@ struct GeminiContent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
 struct GeminiPart {
     #[serde(skip_serializing_if = "Option::is_none")]
     text: Option<String>,
@@ -752,6 +1120,20 @@ struct GeminiFunctionCallingConfig {
 #[derive(Debug, Deserialize)]
 struct GeminiChunk {
     candidates: Option<Vec<GeminiCandidate>>,
+    #[serde(rename = "usageMetadata", default)]
+    usage_metadata: Option<GeminiUsageMetadata>,
+}
+
+/// Gemini token counts; reported cumulatively on each streamed chunk
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GeminiUsageMetadata {
+    #[serde(default)]
+    prompt_token_count: u32,
+    #[serde(default)]
+    candidates_token_count: u32,
+    #[serde(default)]
+    thoughts_token_count: u32,
 }
 
 #[derive(Debug, Deserialize)]
@@ -762,10 +1144,186 @@ struct GeminiCandidate {
 }
 
 /// Gemini function call (args is JSON object, not string)
-#[derive(Debug, Serialize, Deserialize)]
+///
+/// With `streamFunctionCallArguments`, a call arrives across several parts: the
+/// first carries the name, later ones carry `partialArgs`, and `willContinue`
+/// stays set until the last.
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
 struct GeminiFunctionCall {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     name: String,
+    #[serde(default)]
     args: serde_json::Value,
+    #[serde(default, skip_serializing)]
+    partial_args: Vec<GeminiPartialArg>,
+    #[serde(default, skip_serializing)]
+    will_continue: bool,
+}
+
+/// One streamed argument value, addressed by JSON path (e.g. `$.path`)
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct GeminiPartialArg {
+    json_path: String,
+    #[serde(default)]
+    string_value: Option<String>,
+    #[serde(default)]
+    number_value: Option<f64>,
+    #[serde(default)]
+    bool_value: Option<bool>,
+}
+
+/// Assembles Gemini function calls whose arguments are streamed in pieces
+#[derive(Debug, Default)]
+struct GeminiToolCallAccumulator {
+    pending: Option<GeminiPendingToolCall>,
+    completed: usize,
+}
+
+#[derive(Debug)]
+struct GeminiPendingToolCall {
+    id: String,
+    name: String,
+    args: serde_json::Value,
+    strings: BTreeMap<String, String>,
+}
+
+impl GeminiPendingToolCall {
+    fn into_tool_call(mut self) -> ToolCall {
+        for (path, value) in std::mem::take(&mut self.strings) {
+            set_json_path(&mut self.args, &path, serde_json::Value::String(value));
+        }
+        ToolCall {
+            id: self.id,
+            call_type: "function".to_string(),
+            function: FunctionCall { name: self.name, arguments: self.args },
+        }
+    }
+}
+
+impl GeminiToolCallAccumulator {
+    /// Feed one `functionCall` part, returning the stream events it completes
+    fn push(&mut self, call: GeminiFunctionCall) -> Vec<StreamEvent> {
+        let mut events = Vec::new();
+
+        if !call.name.is_empty() {
+            if let Some(previous) = self.pending.take() {
+                events.push(StreamEvent::ToolCall(vec![previous.into_tool_call()]));
+            }
+            let id = format!(
+                "gemini_{}_{}",
+                std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap()
+                    .as_millis(),
+                self.completed
+            );
+            self.completed += 1;
+            events.push(StreamEvent::ToolCallStarted(ToolCall {
+                id: id.clone(),
+                call_type: "function".to_string(),
+                function: FunctionCall { name: call.name.clone(), arguments: serde_json::Value::Null },
+            }));
+            self.pending = Some(GeminiPendingToolCall {
+                id,
+                name: call.name,
+                args: serde_json::Value::Object(Default::default()),
+                strings: BTreeMap::new(),
+            });
+        }
+
+        let Some(pending) = self.pending.as_mut() else {
+            return events;
+        };
+
+        if let serde_json::Value::Object(args) = call.args {
+            for (key, value) in args {
+                set_json_path(&mut pending.args, &format!("$.{}", key), value);
+            }
+        }
+        for arg in call.partial_args {
+            if let Some(text) = arg.string_value {
+                pending.strings.entry(arg.json_path).or_default().push_str(&text);
+            } else if let Some(number) = arg.number_value {
+                set_json_path(&mut pending.args, &arg.json_path, json_number(number));
+            } else if let Some(flag) = arg.bool_value {
+                set_json_path(&mut pending.args, &arg.json_path, serde_json::Value::Bool(flag));
+            } else {
+                set_json_path(&mut pending.args, &arg.json_path, serde_json::Value::Null);
+            }
+        }
+
+        if !call.will_continue
+            && let Some(done) = self.pending.take()
+        {
+            events.push(StreamEvent::ToolCall(vec![done.into_tool_call()]));
+        }
+        events
+    }
+
+    /// Emit a call left open when the stream ended
+    fn finish(&mut self) -> Option<StreamEvent> {
+        self.pending
+            .take()
+            .map(|call| StreamEvent::ToolCall(vec![call.into_tool_call()]))
+    }
+}
+
+/// Convert a Gemini `numberValue` to JSON, keeping whole numbers as integers
+///
+/// Tool schemas expecting integers reject `3.0`, so whole values that fit are
+/// emitted as `i64`/`u64` and everything else stays a float.
+fn json_number(number: f64) -> serde_json::Value {
+    if number.fract() == 0.0 {
+        if number >= i64::MIN as f64 && number < i64::MAX as f64 {
+            return serde_json::Value::from(number as i64);
+        }
+        if number >= 0.0 && number < u64::MAX as f64 {
+            return serde_json::Value::from(number as u64);
+        }
+    }
+    serde_json::json!(number)
+}
+
+/// Set `value` at a JSON path such as `$.a.b` or `$.items[0]`, creating parents as needed
+fn set_json_path(root: &mut serde_json::Value, path: &str, value: serde_json::Value) {
+    let mut target = root;
+    let path = path.strip_prefix('$').unwrap_or(path);
+
+    for segment in path.split('.').filter(|segment| !segment.is_empty()) {
+        let (key, indices) = match segment.find('[') {
+            Some(bracket) => (&segment[..bracket], &segment[bracket..]),
+            None => (segment, ""),
+        };
+
+        if !key.is_empty() {
+            if !target.is_object() {
+                *target = serde_json::Value::Object(Default::default());
+            }
+            target = target
+                .as_object_mut()
+                .unwrap()
+                .entry(key)
+                .or_insert(serde_json::Value::Null);
+        }
+
+        for index in indices
+            .split(['[', ']'])
+            .filter_map(|index| index.parse::<usize>().ok())
+        {
+            if !target.is_array() {
+                *target = serde_json::Value::Array(Vec::new());
+            }
+            let items = target.as_array_mut().unwrap();
+            if items.len() <= index {
+                items.resize(index + 1, serde_json::Value::Null);
+            }
+            target = &mut items[index];
+        }
+    }
+
+    *target = value;
 }
 
 /// Gemini function response
@@ -918,6 +1476,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_gemini_request_merges_system_messages() {
+        let provider = GeminiProvider::new(
+            "test-key".to_string(),
+            "gemini-2.5-flash".to_string(),
+            None,
+            GeminiThinkingLevel::Minimal,
+        );
+        let request = ChatRequest::builder()
+            .add_message(ChatMessage::system("You are helpful"))
+            .add_message(ChatMessage::user("Hello"))
+            .add_message(ChatMessage::assistant("Hi"))
+            .add_message(ChatMessage::system("Be concise"))
+            .build();
+
+        let gem_req = provider.to_gemini_request(&request).unwrap();
+        let roles: Vec<&str> = gem_req.contents.iter().map(|c| c.role.as_str()).collect();
+        assert_eq!(roles, vec!["user", "model"]);
+        assert_eq!(
+            gem_req.system_instruction.as_ref().unwrap().parts[0].text,
+            Some("You are helpful\n\nBe concise".to_string())
+        );
+
+        let json = serde_json::to_value(&gem_req).unwrap();
+        assert!(json.get("systemInstruction").is_some());
+        assert!(json.get("system_instruction").is_none());
+    }
+
     #[test]
     fn test_gemini_request_with_tools() {
         let provider = GeminiProvider::new(
@@ -948,6 +1534,120 @@ mod tests {
         assert_eq!(parsed.model, Some("glm-4.7".to_string()));
     }
 
+    #[test]
+    fn test_glm_parallel_tool_calls_in_one_chunk() {
+        let chunk = r#"{"id":"req-1","choices":[{"index":0,"delta":{"role":"assistant","content":"","tool_calls":[
+            {"index":0,"id":"call_a","type":"function","function":{"name":"read","arguments":"{\"path\":\"a.rs\"}"}},
+            {"index":1,"id":"call_b","type":"function","function":{"name":"grep","arguments":"{\"pattern\":\"todo\"}"}}
+        ]},"finish_reason":"tool_calls"}]}"#;
+
+        let mut accumulator = GlmToolCallAccumulator::default();
+        let started = accumulator.push(GlmProvider::parse_tool_call_deltas(
+            &GlmProvider::decode_chunk(chunk).unwrap(),
+        ));
+        assert_eq!(
+            started.iter().map(ToolCall::name).collect::<Vec<_>>(),
+            vec!["read", "grep"]
+        );
+        let calls = accumulator.finish();
+
+        assert_eq!(calls.len(), 2);
+        assert_eq!((calls[0].id.as_str(), calls[0].name()), ("call_a", "read"));
+        assert_eq!(calls[0].function.arguments, serde_json::json!({"path": "a.rs"}));
+        assert_eq!((calls[1].id.as_str(), calls[1].name()), ("call_b", "grep"));
+        assert_eq!(calls[1].function.arguments, serde_json::json!({"pattern": "todo"}));
+        assert!(accumulator.is_empty());
+    }
+
+    #[test]
+    fn test_glm_parallel_tool_calls_streamed_deltas() {
+        let chunks = [
+            r#"{"choices":[{"index":0,"delta":{"tool_calls":[{"index":0,"id":"call_a","type":"function","function":{"name":"read","arguments":"{\"pa"}}]}}]}"#,
+            r#"{"choices":[{"index":0,"delta":{"tool_calls":[{"index":1,"id":"call_b","type":"function","function":{"name":"grep","arguments":""}}]}}]}"#,
+            r#"{"choices":[{"index":0,"delta":{"tool_calls":[{"index":0,"function":{"arguments":"th\":\"a.rs\"}"}}]}}]}"#,
+            r#"{"choices":[{"index":0,"delta":{"tool_calls":[{"index":1,"function":{"arguments":"{\"pattern\":\"todo\"}"}}]}}]}"#,
+        ];
+
+        let mut accumulator = GlmToolCallAccumulator::default();
+        let started: Vec<Vec<ToolCall>> = chunks
+            .iter()
+            .map(|chunk| {
+                accumulator.push(GlmProvider::parse_tool_call_deltas(
+                    &GlmProvider::decode_chunk(chunk).unwrap(),
+                ))
+            })
+            .collect();
+        assert_eq!(started.iter().map(Vec::len).collect::<Vec<_>>(), vec![1, 1, 0, 0]);
+
+        let calls = accumulator.finish();
+        assert_eq!(calls.len(), 2);
+        assert_eq!((calls[0].id.as_str(), calls[0].name()), ("call_a", "read"));
+        assert_eq!(calls[0].function.arguments, serde_json::json!({"path": "a.rs"}));
+        assert_eq!((calls[1].id.as_str(), calls[1].name()), ("call_b", "grep"));
+        assert_eq!(calls[1].function.arguments, serde_json::json!({"pattern": "todo"}));
+    }
+
+    #[test]
+    fn test_gemini_streamed_function_call_arguments() {
+        let chunks = [
+            r#"{"candidates":[{"content":{"role":"model","parts":[{"functionCall":{"name":"edit","willContinue":true}}]}}]}"#,
+            r#"{"candidates":[{"content":{"role":"model","parts":[{"functionCall":{"partialArgs":[{"jsonPath":"$.path","stringValue":"src/"}],"willContinue":true}}]}}]}"#,
+            r#"{"candidates":[{"content":{"role":"model","parts":[{"functionCall":{"partialArgs":[{"jsonPath":"$.path","stringValue":"lib.rs"},{"jsonPath":"$.lines[0]","numberValue":3}],"willContinue":true}}]}}]}"#,
+            r#"{"candidates":[{"content":{"role":"model","parts":[{"functionCall":{}}]},"finishReason":"STOP"}]}"#,
+        ];
+
+        let mut accumulator = GeminiToolCallAccumulator::default();
+        let events: Vec<StreamEvent> = chunks
+            .iter()
+            .flat_map(|chunk| GeminiProvider::parse_function_calls(GeminiProvider::decode_chunk(chunk).unwrap()))
+            .flat_map(|call| accumulator.push(call))
+            .collect();
+
+        assert_eq!(events.len(), 2);
+        assert!(matches!(&events[0], StreamEvent::ToolCallStarted(call) if call.name() == "edit"));
+        let StreamEvent::ToolCall(calls) = &events[1] else { panic!("expected a completed tool call") };
+        assert_eq!(calls[0].name(), "edit");
+        assert_eq!(
+            calls[0].function.arguments,
+            serde_json::json!({"path": "src/lib.rs", "lines": [3]})
+        );
+        assert!(accumulator.finish().is_none());
+    }
+
+    #[test]
+    fn test_json_number_keeps_integers() {
+        assert_eq!(json_number(3.0), serde_json::json!(3));
+        assert_eq!(json_number(-7.0), serde_json::json!(-7));
+        assert_eq!(json_number(1.5), serde_json::json!(1.5));
+        assert_eq!(json_number(1e19), serde_json::json!(10_000_000_000_000_000_000u64));
+        assert_eq!(json_number(1e30), serde_json::json!(1e30));
+        assert!(json_number(3.0).is_i64());
+    }
+
+    #[test]
+    fn test_gemini_complete_function_calls() {
+        let chunk = r#"{"candidates":[{"content":{"role":"model","parts":[
+            {"functionCall":{"name":"read","args":{"path":"a.rs"}}},
+            {"functionCall":{"name":"grep","args":{"pattern":"todo"}}}
+        ]},"finishReason":"STOP"}]}"#;
+
+        let mut accumulator = GeminiToolCallAccumulator::default();
+        let calls: Vec<ToolCall> = GeminiProvider::parse_function_calls(GeminiProvider::decode_chunk(chunk).unwrap())
+            .into_iter()
+            .flat_map(|call| accumulator.push(call))
+            .filter_map(|event| match event {
+                StreamEvent::ToolCall(calls) => Some(calls),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].function.arguments, serde_json::json!({"path": "a.rs"}));
+        assert_eq!(calls[1].function.arguments, serde_json::json!({"pattern": "todo"}));
+        assert_ne!(calls[0].id, calls[1].id);
+    }
+
     #[test]
     fn test_glm_parse_chunk_done() {
         let provider = GlmProvider::new("test-key".to_string(), "glm-4.7".to_string(), None, false, false);
@@ -977,6 +1677,27 @@ mod tests {
         assert_eq!(parsed.model, Some("gemini-2.5-flash".to_string()));
     }
 
+    #[test]
+    fn test_finish_reason_normalization() {
+        assert_eq!(FinishReason::from_glm("stop"), FinishReason::Stop);
+        assert_eq!(FinishReason::from_glm("length"), FinishReason::Length);
+        assert_eq!(FinishReason::from_glm("tool_calls"), FinishReason::ToolCalls);
+        assert_eq!(FinishReason::from_glm("sensitive"), FinishReason::ContentFilter);
+        assert_eq!(FinishReason::from_glm("network_error"), FinishReason::Other);
+
+        assert_eq!(FinishReason::from_gemini("STOP"), FinishReason::Stop);
+        assert_eq!(FinishReason::from_gemini("MAX_TOKENS"), FinishReason::Length);
+        assert_eq!(FinishReason::from_gemini("SAFETY"), FinishReason::ContentFilter);
+        assert_eq!(
+            FinishReason::from_gemini("MALFORMED_FUNCTION_CALL"),
+            FinishReason::Other
+        );
+
+        assert_eq!(gemini_stream_finish(FinishReason::Stop, true), FinishReason::ToolCalls);
+        assert_eq!(gemini_stream_finish(FinishReason::Length, true), FinishReason::Length);
+        assert_eq!(FinishReason::Length.to_string(), "length");
+    }
+
     #[test]
     fn test_gemini_parse_chunk_with_finish_reason() {
         let provider = GeminiProvider::new(
@@ -990,6 +1711,37 @@ mod tests {
         assert_eq!(parsed.finish_reason, Some("STOP".to_string()));
     }
 
+    #[test]
+    fn test_parse_usage() {
+        let glm_chunk = r#"{"id":"1","choices":[{"index":0,"delta":{},"finish_reason":"stop"}],"usage":{"prompt_tokens":120,"completion_tokens":30,"total_tokens":150}}"#;
+        let usage = GlmProvider::parse_usage(&GlmProvider::decode_chunk(glm_chunk).unwrap()).unwrap();
+        assert_eq!(usage.prompt_tokens, 120);
+        assert_eq!(usage.total_tokens, 150);
+        assert!(GlmProvider::parse_usage(&GlmProvider::decode_chunk(r#"{"id":"1","choices":[]}"#).unwrap()).is_none());
+
+        let gemini_chunk = r#"{"candidates":[],"usageMetadata":{"promptTokenCount":200,"candidatesTokenCount":40,"thoughtsTokenCount":10,"totalTokenCount":250}}"#;
+        let usage = GeminiProvider::parse_usage(&GeminiProvider::decode_chunk(gemini_chunk).unwrap()).unwrap();
+        assert_eq!(usage.prompt_tokens, 200);
+        assert_eq!(usage.completion_tokens, 50);
+        assert_eq!(usage.total_tokens, 250);
+    }
+
+    #[test]
+    fn test_context_window() {
+        let glm = GlmProvider::new("test-key".to_string(), "glm-4.7".to_string(), None, false, false);
+        assert_eq!(glm.context_window(), Some(200_000));
+        let glm = GlmProvider::new("test-key".to_string(), "glm-4.5-air".to_string(), None, false, false);
+        assert_eq!(glm.context_window(), Some(128_000));
+
+        let gemini = GeminiProvider::new(
+            "test-key".to_string(),
+            "gemini-2.5-flash".to_string(),
+            None,
+            GeminiThinkingLevel::Minimal,
+        );
+        assert_eq!(gemini.context_window(), Some(1_048_576));
+    }
+
     #[test]
     fn test_cancel_token() {
         let cancel = CancelToken::new();
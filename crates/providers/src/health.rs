@@ -39,7 +39,15 @@ impl ProviderHealthChecker {
     }
 
     /// Perform a quick health check using a minimal request
+    ///
+    /// When the provider can list its models, the configured model is also
+    /// checked against that list so a typo'd model name fails here rather
+    /// than on the first real request.
     pub async fn check(&self) -> Result<HealthCheckResult> {
+        if let Some(error) = self.check_model().await {
+            return Ok(HealthCheckResult::unhealthy(error));
+        }
+
         let start = std::time::Instant::now();
 
         let check = tokio::time::timeout(self.timeout, async {
@@ -98,6 +106,63 @@ impl ProviderHealthChecker {
     }
 }
 
+impl ProviderHealthChecker {
+    /// Verify the configured model is available, returning an error message if not
+    ///
+    /// Providers without a model-listing endpoint are not checked.
+    async fn check_model(&self) -> Option<String> {
+        let model = self.provider.model()?;
+
+        let models = match tokio::time::timeout(self.timeout, self.provider.list_models()).await {
+            Ok(Ok(Some(models))) => models,
+            Ok(Ok(None)) => return None,
+            Ok(Err(e)) => return Some(format!("Health check failed: {}", e)),
+            Err(_) => return Some("Health check failed: model list timeout".to_string()),
+        };
+
+        if models.iter().any(|m| m == model) {
+            return None;
+        }
+
+        let suggestions = closest_models(model, &models, 3);
+        Some(if suggestions.is_empty() {
+            format!("Model '{}' not found ({} models available)", model, models.len())
+        } else {
+            format!("Model '{}' not found; did you mean: {}?", model, suggestions.join(", "))
+        })
+    }
+}
+
+/// Pick up to `limit` model names closest to `model` by edit distance
+fn closest_models(model: &str, models: &[String], limit: usize) -> Vec<String> {
+    let max_distance = (model.chars().count() / 3).max(2);
+    let mut scored: Vec<(usize, &String)> = models
+        .iter()
+        .map(|candidate| (edit_distance(model, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+
+    scored.sort();
+    scored.into_iter().take(limit).map(|(_, name)| name.clone()).collect()
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == *cb { 0 } else { 1 };
+            current[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(current[j] + 1);
+        }
+        prev = current;
+    }
+
+    prev[b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -110,6 +175,75 @@ mod tests {
         assert!(result.error.is_none());
     }
 
+    struct ListingProvider {
+        model: String,
+        models: Option<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl Provider for ListingProvider {
+        async fn stream_chat<'a>(
+            &'a self, _request: crate::ChatRequest, _cancel_token: crate::CancelToken,
+        ) -> Result<std::pin::Pin<Box<dyn futures::Stream<Item = crate::StreamEvent> + Send + 'a>>> {
+            Ok(Box::pin(futures::stream::iter(vec![
+                crate::StreamEvent::Token("ok".to_string()),
+                crate::StreamEvent::Done,
+            ])))
+        }
+
+        fn model(&self) -> Option<&str> {
+            Some(&self.model)
+        }
+
+        async fn list_models(&self) -> Result<Option<Vec<String>>> {
+            Ok(self.models.clone())
+        }
+    }
+
+    fn checker(model: &str, models: Option<&[&str]>) -> ProviderHealthChecker {
+        let provider = ListingProvider {
+            model: model.to_string(),
+            models: models.map(|m| m.iter().map(|s| s.to_string()).collect()),
+        };
+        ProviderHealthChecker::new(std::sync::Arc::new(provider), Duration::from_secs(5))
+    }
+
+    #[tokio::test]
+    async fn test_health_check_known_model() {
+        let result = checker("glm-4.7", Some(&["glm-4.7", "glm-4.7-flash"]))
+            .check()
+            .await
+            .unwrap();
+        assert!(result.healthy, "{:?}", result.error);
+    }
+
+    #[tokio::test]
+    async fn test_health_check_unknown_model_suggests_alternatives() {
+        let result = checker("glm-4.8", Some(&["glm-4.7", "glm-4.7-flash", "embedding-3"]))
+            .check()
+            .await
+            .unwrap();
+        assert!(!result.healthy);
+        let error = result.error.unwrap();
+        assert!(error.contains("Model 'glm-4.8' not found"), "{}", error);
+        assert!(error.contains("glm-4.7"));
+        assert!(!error.contains("embedding-3"));
+    }
+
+    #[tokio::test]
+    async fn test_health_check_without_model_list() {
+        let result = checker("anything", None).check().await.unwrap();
+        assert!(result.healthy);
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("glm-4.7", "glm-4.7"), 0);
+        assert_eq!(edit_distance("glm-4.8", "glm-4.7"), 1);
+        assert_eq!(edit_distance("gemini", "gemin"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+
     #[test]
     fn test_health_check_result_unhealthy() {
         let result = HealthCheckResult::unhealthy("Connection failed".to_string());
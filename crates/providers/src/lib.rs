@@ -9,10 +9,11 @@ pub mod types;
 
 pub use adapter::{GeminiProvider, GlmProvider, Provider, ProviderFactory};
 pub use health::{HealthCheckResult, ProviderHealthChecker};
-pub use mock::MockProvider;
+pub use mock::{MockEvent, MockProvider, MockResponse};
 pub use prompts::{
-    ProviderType, base_system_prompt, build_system_prompt_for_provider, provider_prompt_adaptation,
-    result_formatting_guidance, system_prompt, teaching_error_messages, tool_usage_guidance,
+    ProviderType, base_system_prompt, build_system_prompt_for_provider, build_system_prompt_with_overrides,
+    provider_prompt_adaptation, result_formatting_guidance, system_prompt, teaching_error_messages,
+    tool_usage_guidance,
 };
 pub use replay::{RecordedEvent, RecordedRequest, ReplayMode, ReplayProvider};
 pub use retry::{RetryConfig, is_retryable_error};
@@ -20,8 +21,8 @@ pub use schemas::{
     GeminiFunctionDeclaration, GeminiToolSchema, GlmFunction, GlmToolSchema, gemini_tool_schemas, glm_tool_schemas,
 };
 pub use types::{
-    CancelToken, ChatMessage, ChatRequest, ChatResponse, FunctionCall, Role, StreamEvent, ToolCall, ToolParameter,
-    ToolResult, ToolSpec,
+    CancelToken, ChatMessage, ChatRequest, ChatResponse, FinishReason, FunctionCall, Role, StreamEvent,
+    TOOL_CANCELLED_ERROR, ToolCall, ToolParameter, ToolResult, ToolSpec, Usage,
 };
 
 pub use thunderus_core::{Error, Result};
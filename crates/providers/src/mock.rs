@@ -4,8 +4,8 @@ use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::Path;
 use std::pin::Pin;
-use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 use thunderus_core::Result;
 use tokio_stream::Stream;
 
@@ -13,10 +13,23 @@ use tokio_stream::Stream;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "lowercase")]
 pub enum MockResponse {
-    Text { content: String },
-    ToolCall { name: String, args: serde_json::Value },
-    Error { message: String },
-    Sequence { events: Vec<MockEvent> },
+    Text {
+        content: String,
+    },
+    ToolCall {
+        name: String,
+        args: serde_json::Value,
+    },
+    Error {
+        message: String,
+    },
+    Sequence {
+        events: Vec<MockEvent>,
+    },
+    /// A complete response, streamed as its text followed by its tool calls
+    Chat {
+        response: ChatResponse,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,6 +37,7 @@ pub enum MockResponse {
 pub enum MockEvent {
     Token { text: String },
     ToolCall { name: String, args: serde_json::Value },
+    Finish { reason: String },
     Done,
 }
 
@@ -34,9 +48,14 @@ struct MockConfig {
 }
 
 /// Mock provider for deterministic testing without API calls
+///
+/// Each call to `stream_chat` is one turn: turn `n` replays the `n`th
+/// scripted response and records the request so tests can assert on what
+/// the agent sent, such as tool results from the previous turn.
 pub struct MockProvider {
     responses: Vec<MockResponse>,
     current: Arc<AtomicUsize>,
+    requests: Arc<Mutex<Vec<ChatRequest>>>,
 }
 
 impl MockProvider {
@@ -47,7 +66,32 @@ impl MockProvider {
             vec![MockResponse::Text { content: "Mock response - configure responses_file in config".to_string() }]
         };
 
-        Self { responses, current: Arc::new(AtomicUsize::new(0)) }
+        Self::from_responses(responses)
+    }
+
+    /// Create a mock that replays `responses` in order, one per turn
+    pub fn from_responses(responses: Vec<MockResponse>) -> Self {
+        Self { responses, current: Arc::new(AtomicUsize::new(0)), requests: Arc::new(Mutex::new(Vec::new())) }
+    }
+
+    /// Create a mock that replays complete chat responses, one per turn
+    pub fn scripted(responses: Vec<ChatResponse>) -> Self {
+        Self::from_responses(
+            responses
+                .into_iter()
+                .map(|response| MockResponse::Chat { response })
+                .collect(),
+        )
+    }
+
+    /// Number of turns served so far
+    pub fn turn(&self) -> usize {
+        self.current.load(Ordering::SeqCst)
+    }
+
+    /// Requests received so far, in turn order
+    pub fn requests(&self) -> Vec<ChatRequest> {
+        self.requests.lock().unwrap().clone()
     }
 
     fn load_responses(path: &str) -> Vec<MockResponse> {
@@ -91,8 +135,9 @@ impl MockProvider {
 #[async_trait::async_trait]
 impl Provider for MockProvider {
     async fn stream_chat<'a>(
-        &'a self, _request: ChatRequest, _cancel_token: CancelToken,
+        &'a self, request: ChatRequest, _cancel_token: CancelToken,
     ) -> Result<Pin<Box<dyn Stream<Item = StreamEvent> + Send + 'a>>> {
+        self.requests.lock().unwrap().push(request);
         let response = self.get_next_response();
 
         let stream = async_stream::stream! {
@@ -107,6 +152,17 @@ impl Provider for MockProvider {
                 MockResponse::Error { message } => {
                     yield StreamEvent::Error(message);
                 }
+                MockResponse::Chat { response } => {
+                    if !response.message.content.is_empty() {
+                        yield StreamEvent::Token(response.message.content);
+                    }
+                    if let Some(calls) = response.tool_calls.filter(|calls| !calls.is_empty()) {
+                        yield StreamEvent::ToolCall(calls);
+                    }
+                    if let Some(reason) = response.finish_reason {
+                        yield StreamEvent::Finish(reason);
+                    }
+                }
                 MockResponse::Sequence { events } => {
                     for event in events {
                         match event {
@@ -117,6 +173,9 @@ impl Provider for MockProvider {
                                 let call = ToolCall::new("mock_id", name, args);
                                 yield StreamEvent::ToolCall(vec![call]);
                             }
+                            MockEvent::Finish { reason } => {
+                                yield StreamEvent::Finish(reason);
+                            }
                             MockEvent::Done => {
                                 yield StreamEvent::Done;
                                 return;
@@ -171,6 +230,44 @@ events = [
         assert!(matches!(config.responses[2], MockResponse::Sequence { .. }));
     }
 
+    async fn collect_turn(provider: &MockProvider, prompt: &str) -> Vec<StreamEvent> {
+        use tokio_stream::StreamExt;
+
+        let request = ChatRequest::builder().add_message(ChatMessage::user(prompt)).build();
+        let stream = provider.stream_chat(request, CancelToken::new()).await.unwrap();
+        stream.collect().await
+    }
+
+    #[tokio::test]
+    async fn test_scripted_provider_replays_turns_in_order() {
+        let provider = MockProvider::scripted(vec![
+            ChatResponse::new(ChatMessage::assistant("Reading")).with_tool_calls(vec![ToolCall::new(
+                "call_1",
+                "read",
+                serde_json::json!({"file_path": "/tmp/a"}),
+            )]),
+            ChatResponse::new(ChatMessage::assistant("All done")),
+        ]);
+
+        let first = collect_turn(&provider, "first").await;
+        assert!(matches!(&first[0], StreamEvent::Token(text) if text == "Reading"));
+        assert!(matches!(&first[1], StreamEvent::ToolCall(calls) if calls[0].id == "call_1"));
+        assert!(matches!(&first[2], StreamEvent::Finish(reason) if reason == "tool_calls"));
+        assert!(matches!(first[3], StreamEvent::Done));
+
+        let second = collect_turn(&provider, "second").await;
+        assert!(matches!(&second[0], StreamEvent::Token(text) if text == "All done"));
+        assert_eq!(second.len(), 2);
+
+        assert_eq!(provider.turn(), 2);
+        let requests = provider.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].messages[0].content, "second");
+
+        let exhausted = collect_turn(&provider, "third").await;
+        assert!(matches!(&exhausted[0], StreamEvent::Token(text) if text.contains("No more mock responses")));
+    }
+
     #[test]
     fn test_recorded_request_from_chat_request() {
         let chat_req = ChatRequest::builder().add_message(ChatMessage::user("Hello")).build();
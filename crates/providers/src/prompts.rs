@@ -140,6 +140,17 @@ pub enum ProviderType {
     Gemini,
 }
 
+impl ProviderType {
+    /// Provider type of a configured provider, if it has prompt adaptations
+    pub fn from_config(config: &thunderus_core::ProviderConfig) -> Option<Self> {
+        match config {
+            thunderus_core::ProviderConfig::Glm { .. } => Some(Self::Glm),
+            thunderus_core::ProviderConfig::Gemini { .. } => Some(Self::Gemini),
+            thunderus_core::ProviderConfig::Mock { .. } => None,
+        }
+    }
+}
+
 /// Build a complete system prompt for a specific provider
 ///
 /// Combines the base system prompt, tool usage guidance, and any
@@ -156,6 +167,32 @@ pub fn build_system_prompt_for_provider(provider_type: ProviderType) -> String {
     }
 }
 
+/// Build the system prompt for a provider with profile overrides applied
+///
+/// `override_prompt` replaces the built-in prompt, but the tool usage
+/// guidance is always kept so tool calling keeps working. `append` is added
+/// last under a project instructions heading. Without a provider type the
+/// prompt is built without provider-specific adaptations.
+pub fn build_system_prompt_with_overrides(
+    provider_type: Option<ProviderType>, override_prompt: Option<&str>, append: Option<&str>,
+) -> String {
+    let mut prompt = match (override_prompt, provider_type) {
+        (Some(custom), _) => format!("{}\n\n{}", custom.trim(), tool_usage_guidance()),
+        (None, Some(provider_type)) => build_system_prompt_for_provider(provider_type),
+        (None, None) => format!(
+            "{}\n\n{}\n\n{}",
+            system_prompt(),
+            result_formatting_guidance(),
+            teaching_error_messages()
+        ),
+    };
+
+    if let Some(extra) = append.map(str::trim).filter(|extra| !extra.is_empty()) {
+        prompt.push_str(&format!("\n\n## Project Instructions\n{}", extra));
+    }
+    prompt
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -217,6 +254,32 @@ mod tests {
         assert!(!gemini_prompt.contains("GLM-4.7"));
     }
 
+    #[test]
+    fn test_build_system_prompt_with_overrides() {
+        let appended = build_system_prompt_with_overrides(Some(ProviderType::Glm), None, Some("Use tabs."));
+        assert!(appended.starts_with(&build_system_prompt_for_provider(ProviderType::Glm)));
+        assert!(appended.ends_with("## Project Instructions\nUse tabs."));
+
+        let overridden = build_system_prompt_with_overrides(
+            Some(ProviderType::Gemini),
+            Some("You are a reviewer."),
+            Some("Be brief."),
+        );
+        assert!(overridden.starts_with("You are a reviewer."));
+        assert!(!overridden.contains("Thunderus"));
+        assert!(overridden.contains("Grep for code search"));
+        assert!(overridden.ends_with("Be brief."));
+
+        assert_eq!(
+            build_system_prompt_with_overrides(Some(ProviderType::Glm), None, Some("  ")),
+            build_system_prompt_for_provider(ProviderType::Glm)
+        );
+
+        let generic = build_system_prompt_with_overrides(None, None, None);
+        assert!(generic.contains("Thunderus"));
+        assert!(!generic.contains("GLM-4.7") && !generic.contains("Gemini model"));
+    }
+
     #[test]
     fn test_result_formatting_guidance() {
         let guidance = result_formatting_guidance();
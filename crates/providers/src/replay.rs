@@ -23,6 +23,8 @@ pub enum ReplayMode {
 pub enum RecordedEvent {
     Token { text: String },
     ToolCall { name: String, args: serde_json::Value },
+    Usage { prompt_tokens: u32, completion_tokens: u32 },
+    Finish { reason: String },
     Done,
     Error { message: String },
 }
@@ -153,6 +155,8 @@ impl Provider for ReplayProvider {
                             StreamEvent::Token(text) => {
                                 events.push(RecordedEvent::Token { text: text.clone() });
                             }
+                            // The completed call is recorded from the following `ToolCall`
+                            StreamEvent::ToolCallStarted(_) => {}
                             StreamEvent::ToolCall(calls) => {
                                 for call in calls {
                                     events.push(RecordedEvent::ToolCall {
@@ -161,6 +165,15 @@ impl Provider for ReplayProvider {
                                     });
                                 }
                             }
+                            StreamEvent::Usage(usage) => {
+                                events.push(RecordedEvent::Usage {
+                                    prompt_tokens: usage.prompt_tokens,
+                                    completion_tokens: usage.completion_tokens,
+                                });
+                            }
+                            StreamEvent::Finish(reason) => {
+                                events.push(RecordedEvent::Finish { reason: reason.clone() });
+                            }
                             StreamEvent::Done => {
                                 events.push(RecordedEvent::Done);
                             }
@@ -188,6 +201,12 @@ impl Provider for ReplayProvider {
                                 let call = ToolCall::new("replay_id", name, args);
                                 yield StreamEvent::ToolCall(vec![call]);
                             }
+                            RecordedEvent::Usage { prompt_tokens, completion_tokens } => {
+                                yield StreamEvent::Usage(Usage::new(prompt_tokens, completion_tokens));
+                            }
+                            RecordedEvent::Finish { reason } => {
+                                yield StreamEvent::Finish(reason);
+                            }
                             RecordedEvent::Done => {
                                 yield StreamEvent::Done;
                             }
@@ -217,6 +236,8 @@ impl Provider for ReplayProvider {
                             StreamEvent::Token(text) => {
                                 live_events.push(RecordedEvent::Token { text: text.clone() });
                             }
+                            // The completed call is recorded from the following `ToolCall`
+                            StreamEvent::ToolCallStarted(_) => {}
                             StreamEvent::ToolCall(calls) => {
                                 for call in calls {
                                     live_events.push(RecordedEvent::ToolCall {
@@ -225,6 +246,15 @@ impl Provider for ReplayProvider {
                                     });
                                 }
                             }
+                            StreamEvent::Usage(usage) => {
+                                live_events.push(RecordedEvent::Usage {
+                                    prompt_tokens: usage.prompt_tokens,
+                                    completion_tokens: usage.completion_tokens,
+                                });
+                            }
+                            StreamEvent::Finish(reason) => {
+                                live_events.push(RecordedEvent::Finish { reason: reason.clone() });
+                            }
                             StreamEvent::Done => {
                                 live_events.push(RecordedEvent::Done);
                             }
@@ -241,6 +271,14 @@ impl Provider for ReplayProvider {
             }
         }
     }
+
+    fn model(&self) -> Option<&str> {
+        self.inner_provider.model()
+    }
+
+    fn context_window(&self) -> Option<usize> {
+        self.inner_provider.context_window()
+    }
 }
 
 #[cfg(test)]
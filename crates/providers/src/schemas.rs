@@ -40,18 +40,20 @@ pub fn glm_tool_schemas() -> Vec<GlmToolSchema> {
                     ),
                     (
                         "output_mode".to_string(),
-                        ToolParameter::new_string("Output format")
+                        ToolParameter::new_enum("Output format", &["files_with_matches", "content", "count"])
                             .with_description("Output mode: 'files_with_matches' (default), 'content', or 'count'"),
                     ),
                     (
                         "context_before".to_string(),
                         ToolParameter::new_number("Lines before match")
-                            .with_description("Number of lines to show before each match (like grep -B)"),
+                            .with_description("Number of lines to show before each match (like grep -B)")
+                            .with_minimum(0.0),
                     ),
                     (
                         "context_after".to_string(),
                         ToolParameter::new_number("Lines after match")
-                            .with_description("Number of lines to show after each match (like grep -A)"),
+                            .with_description("Number of lines to show after each match (like grep -A)")
+                            .with_minimum(0.0),
                     ),
                     (
                         "case_insensitive".to_string(),
@@ -61,7 +63,16 @@ pub fn glm_tool_schemas() -> Vec<GlmToolSchema> {
                     (
                         "head_limit".to_string(),
                         ToolParameter::new_number("Max results")
-                            .with_description("Maximum number of results to return (default: 100 files/lines)"),
+                            .with_description("Maximum number of results to return (default: 100 files/lines)")
+                            .with_minimum(1.0),
+                    ),
+                    (
+                        "max_matches".to_string(),
+                        ToolParameter::new_number("Max matches")
+                            .with_description(
+                                "Stop scanning after this many matches; the result notes if more may exist",
+                            )
+                            .with_minimum(1.0),
                     ),
                 ]),
             },
@@ -97,7 +108,14 @@ pub fn glm_tool_schemas() -> Vec<GlmToolSchema> {
                     (
                         "limit".to_string(),
                         ToolParameter::new_number("Max results")
-                            .with_description("Maximum number of files to return (default: unlimited)"),
+                            .with_description("Maximum number of files to return (default: 100)")
+                            .with_minimum(1.0),
+                    ),
+                    (
+                        "offset".to_string(),
+                        ToolParameter::new_number("Result offset")
+                            .with_description("Number of sorted files to skip, for paging through large result sets")
+                            .with_minimum(0.0),
                     ),
                 ]),
             },
@@ -229,18 +247,20 @@ pub fn gemini_tool_schemas() -> Vec<GeminiToolSchema> {
                     ),
                     (
                         "output_mode".to_string(),
-                        ToolParameter::new_string("Output format")
+                        ToolParameter::new_enum("Output format", &["files_with_matches", "content", "count"])
                             .with_description("Output mode: 'files_with_matches' (default), 'content', or 'count'"),
                     ),
                     (
                         "context_before".to_string(),
                         ToolParameter::new_number("Lines before match")
-                            .with_description("Number of lines to show before each match (like grep -B)"),
+                            .with_description("Number of lines to show before each match (like grep -B)")
+                            .with_minimum(0.0),
                     ),
                     (
                         "context_after".to_string(),
                         ToolParameter::new_number("Lines after match")
-                            .with_description("Number of lines to show after each match (like grep -A)"),
+                            .with_description("Number of lines to show after each match (like grep -A)")
+                            .with_minimum(0.0),
                     ),
                     (
                         "case_insensitive".to_string(),
@@ -250,7 +270,16 @@ pub fn gemini_tool_schemas() -> Vec<GeminiToolSchema> {
                     (
                         "head_limit".to_string(),
                         ToolParameter::new_number("Max results")
-                            .with_description("Maximum number of results to return (default: 100 files/lines)"),
+                            .with_description("Maximum number of results to return (default: 100 files/lines)")
+                            .with_minimum(1.0),
+                    ),
+                    (
+                        "max_matches".to_string(),
+                        ToolParameter::new_number("Max matches")
+                            .with_description(
+                                "Stop scanning after this many matches; the result notes if more may exist",
+                            )
+                            .with_minimum(1.0),
                     ),
                 ]),
             },
@@ -283,7 +312,14 @@ pub fn gemini_tool_schemas() -> Vec<GeminiToolSchema> {
                     (
                         "limit".to_string(),
                         ToolParameter::new_number("Max results")
-                            .with_description("Maximum number of files to return (default: unlimited)"),
+                            .with_description("Maximum number of files to return (default: 100)")
+                            .with_minimum(1.0),
+                    ),
+                    (
+                        "offset".to_string(),
+                        ToolParameter::new_number("Result offset")
+                            .with_description("Number of sorted files to skip, for paging through large result sets")
+                            .with_minimum(0.0),
                     ),
                 ]),
             },
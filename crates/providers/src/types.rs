@@ -84,9 +84,19 @@ impl ToolCall {
 #[serde(tag = "type", content = "properties")]
 pub enum ToolParameter {
     #[serde(rename = "string")]
-    String { description: Option<String> },
+    String {
+        description: Option<String>,
+        #[serde(rename = "enum", default, skip_serializing_if = "Option::is_none")]
+        variants: Option<Vec<String>>,
+    },
     #[serde(rename = "number")]
-    Number { description: Option<String> },
+    Number {
+        description: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        minimum: Option<f64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        maximum: Option<f64>,
+    },
     #[serde(rename = "boolean")]
     Boolean { description: Option<String> },
     #[serde(rename = "array")]
@@ -105,11 +115,19 @@ pub enum ToolParameter {
 
 impl ToolParameter {
     pub fn new_string(description: impl Into<String>) -> Self {
-        Self::String { description: Some(description.into()) }
+        Self::String { description: Some(description.into()), variants: None }
+    }
+
+    /// A string parameter restricted to a fixed set of values
+    pub fn new_enum(description: impl Into<String>, variants: &[&str]) -> Self {
+        Self::String {
+            description: Some(description.into()),
+            variants: Some(variants.iter().map(|v| v.to_string()).collect()),
+        }
     }
 
     pub fn new_number(description: impl Into<String>) -> Self {
-        Self::Number { description: Some(description.into()) }
+        Self::Number { description: Some(description.into()), minimum: None, maximum: None }
     }
 
     pub fn new_boolean(description: impl Into<String>) -> Self {
@@ -124,10 +142,55 @@ impl ToolParameter {
         Self::Object { properties, description: None, required: None }
     }
 
+    /// Mark properties of an object parameter as required
+    ///
+    /// Has no effect on non-object parameters.
+    pub fn with_required(self, fields: &[&str]) -> Self {
+        match self {
+            Self::Object { properties, description, .. } => {
+                Self::Object { properties, description, required: Some(fields.iter().map(|f| f.to_string()).collect()) }
+            }
+            other => other,
+        }
+    }
+
+    /// Set the inclusive lower bound of a number parameter
+    ///
+    /// Has no effect on non-number parameters.
+    pub fn with_minimum(self, value: f64) -> Self {
+        match self {
+            Self::Number { description, maximum, .. } => Self::Number { description, minimum: Some(value), maximum },
+            other => other,
+        }
+    }
+
+    /// Set the inclusive upper bound of a number parameter
+    ///
+    /// Has no effect on non-number parameters.
+    pub fn with_maximum(self, value: f64) -> Self {
+        match self {
+            Self::Number { description, minimum, .. } => Self::Number { description, minimum, maximum: Some(value) },
+            other => other,
+        }
+    }
+
+    /// JSON type name used in schemas and validation messages
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::String { .. } => "string",
+            Self::Number { .. } => "number",
+            Self::Boolean { .. } => "boolean",
+            Self::Array { .. } => "array",
+            Self::Object { .. } => "object",
+        }
+    }
+
     pub fn with_description(self, description: impl Into<String>) -> Self {
         match self {
-            Self::String { .. } => Self::String { description: Some(description.into()) },
-            Self::Number { .. } => Self::Number { description: Some(description.into()) },
+            Self::String { variants, .. } => Self::String { description: Some(description.into()), variants },
+            Self::Number { minimum, maximum, .. } => {
+                Self::Number { description: Some(description.into()), minimum, maximum }
+            }
             Self::Boolean { .. } => Self::Boolean { description: Some(description.into()) },
             Self::Array { items, .. } => Self::Array { items, description: Some(description.into()) },
             Self::Object { properties, required, .. } => {
@@ -173,6 +236,9 @@ impl ToolSpec {
     }
 }
 
+/// Error message carried by a [`ToolResult`] whose execution was cancelled
+pub const TOOL_CANCELLED_ERROR: &str = "cancelled";
+
 /// Result from executing a tool
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ToolResult {
@@ -186,6 +252,9 @@ pub struct ToolResult {
     /// Human-readable explanation of why this tool was classified as safe/risky
     #[serde(skip_serializing_if = "Option::is_none")]
     pub classification_reasoning: Option<String>,
+    /// Structured output for the host, such as the diff a patch tool generated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
 }
 
 impl ToolResult {
@@ -196,6 +265,7 @@ impl ToolResult {
             error: None,
             risk_level: None,
             classification_reasoning: None,
+            metadata: None,
         }
     }
 
@@ -206,13 +276,24 @@ impl ToolResult {
             error: Some(error.into()),
             risk_level: None,
             classification_reasoning: None,
+            metadata: None,
         }
     }
 
+    /// Create a result for a tool call that was aborted by cancellation
+    pub fn cancelled(tool_call_id: impl Into<String>) -> Self {
+        Self::error(tool_call_id, TOOL_CANCELLED_ERROR)
+    }
+
     pub fn is_success(&self) -> bool {
         self.error.is_none()
     }
 
+    /// Check if the tool call was aborted by cancellation
+    pub fn is_cancelled(&self) -> bool {
+        self.error.as_deref() == Some(TOOL_CANCELLED_ERROR)
+    }
+
     pub fn is_error(&self) -> bool {
         self.error.is_some()
     }
@@ -223,6 +304,12 @@ impl ToolResult {
         self.classification_reasoning = Some(classification.reasoning);
         self
     }
+
+    /// Attaches structured output for the host alongside the text content
+    pub fn with_metadata(mut self, metadata: serde_json::Value) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
 }
 
 /// Token usage information
@@ -363,14 +450,78 @@ impl ChatResponse {
 pub enum StreamEvent {
     /// A single token or chunk of content
     Token(String),
-    /// Tool calls initiated by the model
+    /// A tool call whose name is known while its arguments are still streaming
+    ///
+    /// Arguments are `Null` here; the complete call follows in `ToolCall`.
+    ToolCallStarted(ToolCall),
+    /// Tool calls initiated by the model, with fully assembled arguments
     ToolCall(Vec<ToolCall>),
+    /// Token usage reported by the provider for this response; sent before `Finish`
+    Usage(Usage),
+    /// Why the model stopped, normalized with [`FinishReason`]; sent before `Done`
+    Finish(String),
     /// End of stream
     Done,
     /// An error occurred during streaming
     Error(String),
 }
 
+/// Provider-independent reason a response ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FinishReason {
+    /// The model finished its answer
+    Stop,
+    /// The response was cut off by `max_tokens`
+    Length,
+    /// The model stopped to call tools
+    ToolCalls,
+    /// The response was blocked by a safety or content filter
+    ContentFilter,
+    /// Any other reason, including provider-side errors
+    Other,
+}
+
+impl FinishReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FinishReason::Stop => "stop",
+            FinishReason::Length => "length",
+            FinishReason::ToolCalls => "tool_calls",
+            FinishReason::ContentFilter => "content_filter",
+            FinishReason::Other => "other",
+        }
+    }
+
+    /// Normalize a GLM (OpenAI-compatible) `finish_reason`
+    pub fn from_glm(reason: &str) -> Self {
+        match reason {
+            "stop" => FinishReason::Stop,
+            "length" | "model_context_window_exceeded" => FinishReason::Length,
+            "tool_calls" => FinishReason::ToolCalls,
+            "sensitive" | "content_filter" => FinishReason::ContentFilter,
+            _ => FinishReason::Other,
+        }
+    }
+
+    /// Normalize a Gemini `finishReason`
+    pub fn from_gemini(reason: &str) -> Self {
+        match reason {
+            "STOP" => FinishReason::Stop,
+            "MAX_TOKENS" => FinishReason::Length,
+            "SAFETY" | "RECITATION" | "BLOCKLIST" | "PROHIBITED_CONTENT" | "SPII" | "IMAGE_SAFETY" => {
+                FinishReason::ContentFilter
+            }
+            _ => FinishReason::Other,
+        }
+    }
+}
+
+impl std::fmt::Display for FinishReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Token for cancelling streaming operations
 #[derive(Debug, Clone, Default)]
 pub struct CancelToken {
@@ -408,6 +559,36 @@ mod tests {
         } else {
             panic!("Expected Object parameter");
         }
+
+        let param = ToolParameter::new_object(vec![("field".to_string(), ToolParameter::new_string("Field"))])
+            .with_required(&["field"]);
+        if let ToolParameter::Object { required, .. } = param {
+            assert_eq!(required, Some(vec!["field".to_string()]));
+        } else {
+            panic!("Expected Object parameter");
+        }
+    }
+
+    #[test]
+    fn test_tool_parameter_constraints() {
+        let param = ToolParameter::new_enum("Mode", &["a", "b"]).with_description("Output mode");
+        let json = serde_json::to_value(&param).unwrap();
+        assert_eq!(json["properties"]["enum"], serde_json::json!(["a", "b"]));
+        assert_eq!(json["properties"]["description"], "Output mode");
+
+        let param = ToolParameter::new_number("Limit").with_minimum(1.0).with_maximum(10.0);
+        let json = serde_json::to_value(&param).unwrap();
+        assert_eq!(json["properties"]["minimum"], 1.0);
+        assert_eq!(json["properties"]["maximum"], 10.0);
+
+        let json = serde_json::to_value(ToolParameter::new_string("Plain")).unwrap();
+        assert!(json["properties"].get("enum").is_none());
+
+        let roundtrip: ToolParameter = serde_json::to_value(&param).and_then(serde_json::from_value).unwrap();
+        assert!(matches!(
+            roundtrip,
+            ToolParameter::Number { minimum: Some(_), maximum: Some(_), .. }
+        ));
     }
 
     #[test]
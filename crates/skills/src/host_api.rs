@@ -6,8 +6,11 @@
 
 use crate::types::SkillPermissions;
 
+use serde::{Deserialize, Serialize};
+use std::io::Read;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
+use std::time::Duration;
 use thiserror::Error;
 
 /// Errors that can occur during permission checks or host API operations.
@@ -33,6 +36,9 @@ pub enum HostApiError {
 
     #[error("Operation failed: {0}")]
     OperationFailed(String),
+
+    #[error("Key-value store limit exceeded: {0}")]
+    KvLimitExceeded(String),
 }
 
 /// Result type for host API operations.
@@ -58,24 +64,137 @@ pub struct HostContext {
     pub kv_store: Arc<RwLock<KvStore>>,
 }
 
+/// Timeout for a single plugin HTTP request, including redirects.
+const HTTP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Maximum number of redirects a plugin HTTP request follows.
+const HTTP_MAX_REDIRECTS: usize = 10;
+
+/// Largest response body a plugin HTTP request reads, in bytes.
+pub const HTTP_MAX_RESPONSE_BYTES: usize = 1024 * 1024;
+
+/// An HTTP request made by a plugin through [`HostContext::http_request`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HttpRequest {
+    /// HTTP method, e.g. `GET` or `POST` (defaults to `GET`)
+    #[serde(default)]
+    pub method: String,
+    /// Absolute `http` or `https` URL
+    pub url: String,
+    /// Request headers as name/value pairs
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    /// Request body
+    #[serde(default)]
+    pub body: Option<String>,
+}
+
+/// The response returned to a plugin from [`HostContext::http_request`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HttpResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// Response body, decoded as UTF-8 (lossily)
+    pub body: String,
+}
+
+/// Default cap on the total size of one skill's keys and values, in bytes.
+pub const DEFAULT_KV_MAX_BYTES: usize = 1024 * 1024;
+
 /// Simple key-value store for plugin-scoped persistence.
-#[derive(Debug, Clone, Default)]
+///
+/// Stores created with [`KvStore::for_skill`] live in
+/// `.thunderus/skills/<skill>/kv/store.json`, so each skill only ever sees
+/// its own keys. Writes that would grow the store past its size cap are
+/// rejected.
+#[derive(Debug, Clone)]
 pub struct KvStore {
     data: std::collections::HashMap<String, String>,
     storage_path: Option<PathBuf>,
+    max_bytes: usize,
+}
+
+impl Default for KvStore {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl KvStore {
     pub fn new() -> Self {
-        Self { data: std::collections::HashMap::new(), storage_path: None }
+        Self { data: std::collections::HashMap::new(), storage_path: None, max_bytes: DEFAULT_KV_MAX_BYTES }
     }
 
     pub fn with_path(storage_path: PathBuf) -> Self {
-        let mut store = Self { data: std::collections::HashMap::new(), storage_path: Some(storage_path) };
+        let mut store = Self { storage_path: Some(storage_path), ..Self::new() };
         store.load_from_disk();
         store
     }
 
+    /// Open the persistent store of a single skill within a workspace.
+    ///
+    /// The skill name is encoded into a single path component so a crafted name
+    /// cannot point at another skill's store. A store left at the old
+    /// `.thunderus/kv/<skill>.json` location is moved into place on first open.
+    pub fn for_skill(workspace_root: &Path, skill_name: &str) -> Self {
+        let path = Self::skill_store_path(workspace_root, skill_name);
+        Self::migrate_legacy_store(workspace_root, skill_name, &path);
+        Self::with_path(path)
+    }
+
+    /// Location of a skill's persistent store.
+    ///
+    /// Characters other than ASCII alphanumerics, `-` and `_` are
+    /// percent-encoded byte by byte, so distinct skill names never share a store.
+    pub fn skill_store_path(workspace_root: &Path, skill_name: &str) -> PathBuf {
+        let mut namespace = String::with_capacity(skill_name.len());
+        for byte in skill_name.bytes() {
+            if byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_' {
+                namespace.push(byte as char);
+            } else {
+                namespace.push_str(&format!("%{byte:02X}"));
+            }
+        }
+        workspace_root
+            .join(".thunderus")
+            .join("skills")
+            .join(namespace)
+            .join("kv")
+            .join("store.json")
+    }
+
+    /// Move a store from the pre-namespacing `.thunderus/kv/<skill>.json` path.
+    fn migrate_legacy_store(workspace_root: &Path, skill_name: &str, path: &Path) {
+        if path.exists() || skill_name.contains(['/', '\\']) || skill_name.starts_with('.') {
+            return;
+        }
+        let legacy = workspace_root
+            .join(".thunderus")
+            .join("kv")
+            .join(format!("{skill_name}.json"));
+        if !legacy.is_file() {
+            return;
+        }
+        let moved = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|_| std::fs::rename(&legacy, path));
+        if let Err(e) = moved {
+            tracing::warn!("Failed to migrate kv store {}: {}", legacy.display(), e);
+        }
+    }
+
+    /// Set the size cap, in bytes, for all keys and values together.
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /// Total size of all keys and values, in bytes.
+    pub fn size_bytes(&self) -> usize {
+        self.data.iter().map(|(key, value)| key.len() + value.len()).sum()
+    }
+
     fn load_from_disk(&mut self) {
         let Some(path) = &self.storage_path else {
             return;
@@ -108,6 +227,15 @@ impl KvStore {
     }
 
     pub fn set(&mut self, key: String, value: String) -> Result<()> {
+        let replaced = self.data.get(&key).map_or(0, |old| key.len() + old.len());
+        let size = self.size_bytes() - replaced + key.len() + value.len();
+        if size > self.max_bytes {
+            return Err(HostApiError::KvLimitExceeded(format!(
+                "setting '{}' would grow the store to {} bytes (limit {})",
+                key, size, self.max_bytes
+            )));
+        }
+
         self.data.insert(key, value);
         self.persist_to_disk()
     }
@@ -121,16 +249,8 @@ impl KvStore {
 impl HostContext {
     /// Create a new host context.
     pub fn new(plugin_name: String, workspace_root: PathBuf, permissions: SkillPermissions) -> Self {
-        let kv_path = workspace_root
-            .join(".thunderus")
-            .join("kv")
-            .join(format!("{plugin_name}.json"));
-        Self {
-            plugin_name,
-            workspace_root,
-            permissions,
-            kv_store: Arc::new(RwLock::new(KvStore::with_path(kv_path))),
-        }
+        let kv_store = KvStore::for_skill(&workspace_root, &plugin_name);
+        Self { plugin_name, workspace_root, permissions, kv_store: Arc::new(RwLock::new(kv_store)) }
     }
 
     /// Get the canonicalized workspace root for comparison.
@@ -167,6 +287,144 @@ impl HostContext {
         Ok(resolved)
     }
 
+    /// Read a file the plugin is allowed to read.
+    pub fn read_file(&self, path: &str) -> Result<Vec<u8>> {
+        let resolved = self.check_read_permission(path)?;
+        Ok(std::fs::read(resolved)?)
+    }
+
+    /// Write a file the plugin is allowed to write, creating parent directories.
+    pub fn write_file(&self, path: &str, data: &[u8]) -> Result<()> {
+        let resolved = self.check_write_permission(path)?;
+        if let Some(parent) = resolved.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(resolved, data)?;
+        Ok(())
+    }
+
+    /// List the entries of a directory that the plugin is allowed to read.
+    ///
+    /// Returns workspace-relative paths, sorted. Entries outside the plugin's
+    /// read patterns are omitted, except directories that lead to a readable
+    /// path. If nothing is visible and the directory itself is not readable
+    /// either, the listing is denied.
+    pub fn list_dir(&self, path: &str) -> Result<Vec<String>> {
+        let resolved = self.resolve_path(path)?;
+        let patterns = &self.permissions.filesystem.read;
+        let dir_readable = self.matches_patterns(&resolved, patterns);
+        let workspace_canonical = self
+            .canonical_workspace_root()
+            .unwrap_or_else(|| self.workspace_root.clone());
+
+        let mut entries = Vec::new();
+        for entry in std::fs::read_dir(&resolved)? {
+            let entry_path = entry?.path();
+            let relative = entry_path
+                .strip_prefix(&workspace_canonical)
+                .unwrap_or(&entry_path)
+                .to_string_lossy()
+                .replace('\\', "/");
+            let leads_to_readable = entry_path.is_dir()
+                && patterns.iter().any(|pattern| {
+                    pattern
+                        .strip_prefix("./")
+                        .unwrap_or(pattern)
+                        .starts_with(&format!("{relative}/"))
+                });
+
+            if dir_readable || leads_to_readable || self.matches_patterns(&entry_path, patterns) {
+                entries.push(relative);
+            }
+        }
+
+        if entries.is_empty() && !dir_readable {
+            return Err(HostApiError::PermissionDenied(format!(
+                "list: {} (not in allowed paths for plugin '{}')",
+                path, self.plugin_name
+            )));
+        }
+
+        entries.sort();
+        Ok(entries)
+    }
+
+    /// Make an HTTP request to a host the plugin is allowed to reach.
+    ///
+    /// The URL's host is checked against the plugin's network allowlist before
+    /// anything is sent, and redirects are only followed to allowed hosts.
+    pub fn http_request(&self, request: &HttpRequest) -> Result<HttpResponse> {
+        let url = reqwest::Url::parse(&request.url)
+            .map_err(|e| HostApiError::OperationFailed(format!("invalid URL '{}': {e}", request.url)))?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(HostApiError::PermissionDenied(format!(
+                "http: unsupported scheme '{}' for plugin '{}'",
+                url.scheme(),
+                self.plugin_name
+            )));
+        }
+        let host = url
+            .host_str()
+            .ok_or_else(|| HostApiError::OperationFailed(format!("URL '{}' has no host", request.url)))?;
+        self.check_network_permission(host)?;
+
+        let method_name = if request.method.is_empty() { "GET" } else { request.method.as_str() };
+        let method = reqwest::Method::from_bytes(method_name.to_uppercase().as_bytes())
+            .map_err(|_| HostApiError::OperationFailed(format!("invalid HTTP method '{}'", request.method)))?;
+
+        let redirect_ctx = self.clone();
+        let redirect_policy = reqwest::redirect::Policy::custom(move |attempt| {
+            let allowed = attempt
+                .url()
+                .host_str()
+                .is_some_and(|host| redirect_ctx.check_network_permission(host).is_ok());
+            if allowed && attempt.previous().len() < HTTP_MAX_REDIRECTS {
+                attempt.follow()
+            } else {
+                attempt.stop()
+            }
+        });
+
+        let headers = request.headers.clone();
+        let body = request.body.clone();
+        // NOTE: reqwest's blocking client must not run on an async runtime thread,
+        // and host functions may be called from one, so the request gets its own thread.
+        std::thread::spawn(move || {
+            let client = reqwest::blocking::Client::builder()
+                .timeout(HTTP_TIMEOUT)
+                .redirect(redirect_policy)
+                .build()
+                .map_err(|e| HostApiError::OperationFailed(format!("Failed to build HTTP client: {e}")))?;
+
+            let mut builder = client.request(method, url);
+            for (name, value) in headers {
+                builder = builder.header(name, value);
+            }
+            if let Some(body) = body {
+                builder = builder.body(body);
+            }
+
+            let response = builder
+                .send()
+                .map_err(|e| HostApiError::OperationFailed(format!("HTTP request failed: {e}")))?;
+            let status = response.status().as_u16();
+            let mut body = Vec::new();
+            response
+                .take(HTTP_MAX_RESPONSE_BYTES as u64 + 1)
+                .read_to_end(&mut body)
+                .map_err(|e| HostApiError::OperationFailed(format!("Failed to read HTTP response: {e}")))?;
+            if body.len() > HTTP_MAX_RESPONSE_BYTES {
+                return Err(HostApiError::OperationFailed(format!(
+                    "HTTP response body exceeds {} bytes",
+                    HTTP_MAX_RESPONSE_BYTES
+                )));
+            }
+            Ok(HttpResponse { status, body: String::from_utf8_lossy(&body).into_owned() })
+        })
+        .join()
+        .map_err(|_| HostApiError::OperationFailed("HTTP request thread panicked".to_string()))?
+    }
+
     /// Check if a host is allowed for network access.
     pub fn check_network_permission(&self, host: &str) -> Result<()> {
         if self.matches_host(host, &self.permissions.network.allowed_hosts) {
@@ -379,6 +637,39 @@ mod tests {
         assert!(ctx.check_read_permission("secret.txt").is_err());
     }
 
+    #[test]
+    fn test_scoped_filesystem_api() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path();
+
+        fs::create_dir_all(workspace.join("data/nested")).unwrap();
+        fs::write(workspace.join("data/file.txt"), "hello").unwrap();
+        fs::write(workspace.join("secret.txt"), "hidden").unwrap();
+
+        let mut permissions = SkillPermissions::default();
+        permissions.filesystem.read = vec!["data/**".to_string()];
+        permissions.filesystem.write = vec!["output/**".to_string()];
+        let ctx = HostContext::new("test-plugin".to_string(), workspace.to_path_buf(), permissions);
+
+        assert_eq!(ctx.read_file("data/file.txt").unwrap(), b"hello");
+        assert!(matches!(
+            ctx.read_file("secret.txt"),
+            Err(HostApiError::PermissionDenied(_))
+        ));
+
+        ctx.write_file("output/report/summary.txt", b"done").unwrap();
+        assert_eq!(fs::read(workspace.join("output/report/summary.txt")).unwrap(), b"done");
+        assert!(matches!(
+            ctx.write_file("data/file.txt", b"overwrite"),
+            Err(HostApiError::PermissionDenied(_))
+        ));
+        assert_eq!(fs::read(workspace.join("data/file.txt")).unwrap(), b"hello");
+
+        assert_eq!(ctx.list_dir("data").unwrap(), vec!["data/file.txt", "data/nested"]);
+        assert_eq!(ctx.list_dir(".").unwrap(), vec!["data"]);
+        assert!(matches!(ctx.list_dir("output"), Err(HostApiError::PermissionDenied(_))));
+    }
+
     #[test]
     fn test_check_network_permission_allowed() {
         let ctx = create_test_context();
@@ -398,6 +689,82 @@ mod tests {
         assert!(ctx.check_network_permission("github.com").is_ok());
     }
 
+    #[test]
+    fn test_http_request_rejects_disallowed_host() {
+        let ctx = create_test_context();
+        let request = HttpRequest { url: "https://evil.com/steal".to_string(), ..Default::default() };
+        assert!(matches!(
+            ctx.http_request(&request),
+            Err(HostApiError::HostNotAllowed(_))
+        ));
+
+        let request = HttpRequest { url: "file:///etc/passwd".to_string(), ..Default::default() };
+        assert!(matches!(
+            ctx.http_request(&request),
+            Err(HostApiError::PermissionDenied(_))
+        ));
+    }
+
+    #[test]
+    fn test_http_request_to_allowed_host() {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buf = [0u8; 4096];
+            let n = stream.read(&mut buf).unwrap();
+            stream
+                .write_all(b"HTTP/1.1 201 Created\r\nContent-Length: 7\r\nConnection: close\r\n\r\ncreated")
+                .unwrap();
+            String::from_utf8_lossy(&buf[..n]).to_string()
+        });
+
+        let mut permissions = SkillPermissions::default();
+        permissions.network.allowed_hosts = vec!["127.0.0.1".to_string()];
+        let ctx = HostContext::new("test-plugin".to_string(), PathBuf::from("/workspace"), permissions);
+
+        let request = HttpRequest {
+            method: "post".to_string(),
+            url: format!("http://127.0.0.1:{port}/items"),
+            headers: vec![("X-Skill".to_string(), "demo".to_string())],
+            body: Some("{}".to_string()),
+        };
+        let response = ctx.http_request(&request).unwrap();
+        assert_eq!(response, HttpResponse { status: 201, body: "created".to_string() });
+
+        let received = server.join().unwrap();
+        assert!(received.starts_with("POST /items"));
+        assert!(received.to_lowercase().contains("x-skill: demo"));
+    }
+
+    #[test]
+    fn test_http_request_rejects_oversized_body() {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buf = [0u8; 4096];
+            let _ = stream.read(&mut buf).unwrap();
+            let len = HTTP_MAX_RESPONSE_BYTES + 1;
+            let head = format!("HTTP/1.1 200 OK\r\nContent-Length: {len}\r\nConnection: close\r\n\r\n");
+            let _ = stream.write_all(head.as_bytes());
+            let _ = stream.write_all(&vec![b'x'; len]);
+        });
+
+        let mut permissions = SkillPermissions::default();
+        permissions.network.allowed_hosts = vec!["127.0.0.1".to_string()];
+        let ctx = HostContext::new("test-plugin".to_string(), PathBuf::from("/workspace"), permissions);
+
+        let request = HttpRequest { url: format!("http://127.0.0.1:{port}/big"), ..Default::default() };
+        let result = ctx.http_request(&request);
+        assert!(matches!(result, Err(HostApiError::OperationFailed(ref msg)) if msg.contains("exceeds")));
+        server.join().unwrap();
+    }
+
     #[test]
     fn test_env_var_permission() {
         let ctx = create_test_context();
@@ -417,6 +784,58 @@ mod tests {
         assert!(store.get("test").is_none());
     }
 
+    #[test]
+    fn test_kv_store_persists_per_skill() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path();
+
+        let mut store = KvStore::for_skill(workspace, "counter");
+        store.set("count".to_string(), "3".to_string()).unwrap();
+        assert!(workspace.join(".thunderus/skills/counter/kv/store.json").exists());
+
+        let reopened = KvStore::for_skill(workspace, "counter");
+        assert_eq!(reopened.get("count"), Some("3".to_string()));
+
+        let other = KvStore::for_skill(workspace, "other");
+        assert!(other.get("count").is_none());
+
+        let escaped = KvStore::skill_store_path(workspace, "../counter");
+        assert!(escaped.starts_with(workspace.join(".thunderus/skills")));
+        assert_ne!(escaped, KvStore::skill_store_path(workspace, "counter"));
+        assert_ne!(
+            KvStore::skill_store_path(workspace, "a.b"),
+            KvStore::skill_store_path(workspace, "a_b")
+        );
+    }
+
+    #[test]
+    fn test_kv_store_migrates_legacy_location() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace = temp_dir.path();
+        let legacy = workspace.join(".thunderus/kv/counter.json");
+        std::fs::create_dir_all(legacy.parent().unwrap()).unwrap();
+        std::fs::write(&legacy, r#"{"count":"7"}"#).unwrap();
+
+        let store = KvStore::for_skill(workspace, "counter");
+        assert_eq!(store.get("count"), Some("7".to_string()));
+        assert!(!legacy.exists());
+        assert!(workspace.join(".thunderus/skills/counter/kv/store.json").exists());
+    }
+
+    #[test]
+    fn test_kv_store_size_limit() {
+        let mut store = KvStore::new().with_max_bytes(16);
+        store.set("key".to_string(), "0123456789".to_string()).unwrap();
+
+        let result = store.set("other".to_string(), "value".to_string());
+        assert!(matches!(result, Err(HostApiError::KvLimitExceeded(_))));
+        assert!(store.get("other").is_none());
+
+        store.set("key".to_string(), "short".to_string()).unwrap();
+        store.set("other".to_string(), "abc".to_string()).unwrap();
+        assert_eq!(store.size_bytes(), 16);
+    }
+
     #[test]
     fn test_pattern_matching() {
         let ctx = create_test_context();
@@ -10,7 +10,7 @@ mod parser;
 mod runtimes;
 mod types;
 
-pub use host_api::{HostApiError, HostContext, KvStore};
+pub use host_api::{DEFAULT_KV_MAX_BYTES, HostApiError, HostContext, HttpRequest, HttpResponse, KvStore};
 pub use loader::SkillLoader;
 pub use parser::parse_skill;
 pub use types::{
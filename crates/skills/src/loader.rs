@@ -60,6 +60,11 @@ impl SkillLoader {
         PathBuf::from(".thunderus").join("skills")
     }
 
+    /// The configuration this loader was created with.
+    pub fn config(&self) -> &SkillsConfig {
+        &self.config
+    }
+
     /// Get the search directories for skills, respecting config override.
     fn search_dirs(&self) -> Vec<PathBuf> {
         if let Some(custom_dir) = &self.config.skills_dir {
@@ -17,9 +17,9 @@
 
 use crate::types::{
     FilesystemPermissions, NetworkPermissions, PluginFunction, Result, ScriptType, Skill, SkillDriver, SkillMeta,
-    SkillPermissions, SkillRisk, SkillScript,
+    SkillPermissions, SkillRisk, SkillScript, trigger_regex,
 };
-use std::path::{Path, PathBuf};
+use std::path::Path;
 use std::{fs, io};
 
 /// Parse a SKILL.md file and extract metadata and content.
@@ -31,14 +31,17 @@ pub fn parse_skill(skill_dir: &Path) -> Result<Skill> {
     }
 
     let content = fs::read_to_string(&skill_md)?;
-    let (meta, body) = extract_frontmatter(&content)?;
+    let (meta, body) = extract_frontmatter(&content, skill_dir)?;
     let scripts = discover_scripts(skill_dir)?;
 
-    Ok(Skill { meta: SkillMeta { path: skill_dir.to_path_buf(), ..meta }, content: body, scripts })
+    Ok(Skill { meta, content: body, scripts })
 }
 
 /// Extract YAML frontmatter and body from markdown content.
-fn extract_frontmatter(content: &str) -> Result<(SkillMeta, String)> {
+///
+/// `skill_dir` is used to inspect the declared entry when the driver is
+/// omitted or needs checking against it.
+fn extract_frontmatter(content: &str, skill_dir: &Path) -> Result<(SkillMeta, String)> {
     if !content.starts_with("---") {
         return Err(crate::types::SkillError::InvalidFrontmatter(
             "SKILL.md must start with ---".to_string(),
@@ -84,10 +87,21 @@ fn extract_frontmatter(content: &str) -> Result<(SkillMeta, String)> {
         ));
     }
 
-    let driver = frontmatter
-        .driver
-        .and_then(|s| parse_driver(&s).ok())
-        .unwrap_or_default();
+    let declared_driver = frontmatter.driver.and_then(|s| parse_driver(&s).ok());
+    let detected = frontmatter
+        .entry
+        .as_deref()
+        .map_or(ScriptType::Unknown, |entry| ScriptType::detect(&skill_dir.join(entry)));
+    if let Some(declared) = declared_driver
+        && driver_conflicts(declared, detected)
+    {
+        tracing::warn!(
+            skill = %frontmatter.name,
+            "driver {declared:?} conflicts with entry '{}', which looks like a {detected:?} script",
+            frontmatter.entry.as_deref().unwrap_or_default()
+        );
+    }
+    let driver = declared_driver.or_else(|| detected.driver()).unwrap_or_default();
 
     if driver == SkillDriver::Mcp {
         if frontmatter.mcp_server.as_ref().is_none_or(|s| s.is_empty()) {
@@ -104,6 +118,13 @@ fn extract_frontmatter(content: &str) -> Result<(SkillMeta, String)> {
 
     let permissions = parse_permissions(frontmatter.permissions)?;
 
+    let triggers = frontmatter.triggers.unwrap_or_default();
+    for pattern in triggers.iter().filter_map(|trigger| trigger_regex(trigger)) {
+        regex::Regex::new(pattern).map_err(|e| {
+            crate::types::SkillError::InvalidFrontmatter(format!("invalid trigger pattern '/{pattern}/': {e}"))
+        })?;
+    }
+
     let functions = frontmatter
         .functions
         .map(|funcs| {
@@ -125,7 +146,7 @@ fn extract_frontmatter(content: &str) -> Result<(SkillMeta, String)> {
         author: frontmatter.author.unwrap_or_default(),
         tags: frontmatter.tags.unwrap_or_default(),
         requires: frontmatter.requires.unwrap_or_default(),
-        path: PathBuf::new(), // Will be set by caller
+        path: skill_dir.to_path_buf(),
         risk_level: frontmatter
             .risk_level
             .and_then(|s| parse_risk_level(&s).ok())
@@ -142,6 +163,7 @@ fn extract_frontmatter(content: &str) -> Result<(SkillMeta, String)> {
         permissions,
         parameters: frontmatter.parameters.unwrap_or(serde_json::json!({})),
         functions,
+        triggers,
     };
 
     Ok((meta, body.trim().to_string()))
@@ -172,6 +194,11 @@ fn parse_driver(s: &str) -> Result<SkillDriver> {
     }
 }
 
+/// Whether a declared driver can't run an entry of the detected type.
+fn driver_conflicts(declared: SkillDriver, detected: ScriptType) -> bool {
+    declared != SkillDriver::Mcp && detected.driver().is_some_and(|inferred| inferred != declared)
+}
+
 /// Parse permissions frontmatter into SkillPermissions.
 fn parse_permissions(perms: Option<FrontmatterPermissions>) -> Result<SkillPermissions> {
     let Some(perms) = perms else {
@@ -225,17 +252,7 @@ fn discover_scripts(skill_dir: &Path) -> Result<Vec<SkillScript>> {
             continue;
         }
 
-        let script_type = path
-            .extension()
-            .and_then(|ext| ext.to_str())
-            .map(|ext| match ext.to_lowercase().as_str() {
-                "sh" => ScriptType::Bash,
-                "js" => ScriptType::JavaScript,
-                "py" => ScriptType::Python,
-                "lua" => ScriptType::Lua,
-                _ => ScriptType::Unknown,
-            })
-            .unwrap_or(ScriptType::Unknown);
+        let script_type = ScriptType::detect(&path);
 
         if let Some(name) = path.file_name() {
             scripts.push(SkillScript { name: name.to_string_lossy().to_string(), path, script_type });
@@ -289,6 +306,9 @@ struct Frontmatter {
 
     #[serde(default)]
     functions: Option<Vec<FrontmatterFunction>>,
+
+    #[serde(default)]
+    triggers: Option<Vec<String>>,
 }
 
 /// Permissions frontmatter (matches SkillPermissions structure).
@@ -350,13 +370,39 @@ version: 1.0.0
 This is the body.
 "#;
 
-        let (meta, body) = extract_frontmatter(content).unwrap();
+        let (meta, body) = extract_frontmatter(content, Path::new("")).unwrap();
         assert_eq!(meta.name, "test-skill");
         assert_eq!(meta.description, "A test skill");
         assert_eq!(meta.version, "1.0.0");
         assert_eq!(body, "# Test Skill\n\nThis is the body.");
     }
 
+    #[test]
+    fn test_extract_frontmatter_triggers() {
+        let content = r#"---
+name: db-migrate
+description: Run database migrations
+triggers:
+  - migration
+  - /\bschema (change|update)\b/
+---
+"#;
+
+        let (meta, _) = extract_frontmatter(content, Path::new("")).unwrap();
+        assert_eq!(meta.matching_trigger("Write a Migration for users"), Some("migration"));
+        assert_eq!(
+            meta.matching_trigger("plan a schema change"),
+            Some(r"/\bschema (change|update)\b/")
+        );
+        assert_eq!(meta.matching_trigger("fix the login page"), None);
+
+        let invalid = "---\nname: bad\ndescription: Bad trigger\ntriggers:\n  - /([/\n---\n";
+        assert!(matches!(
+            extract_frontmatter(invalid, Path::new("")),
+            Err(crate::types::SkillError::InvalidFrontmatter(_))
+        ));
+    }
+
     #[test]
     fn test_extract_frontmatter_missing_name() {
         let content = r#"---
@@ -366,7 +412,7 @@ description: A test skill
 # Test Skill
 "#;
 
-        let result = extract_frontmatter(content);
+        let result = extract_frontmatter(content, Path::new(""));
         assert!(result.is_err());
     }
 
@@ -379,7 +425,7 @@ name: test-skill
 # Test Skill
 "#;
 
-        let result = extract_frontmatter(content);
+        let result = extract_frontmatter(content, Path::new(""));
         assert!(result.is_err());
     }
 
@@ -393,7 +439,7 @@ description: A test skill
 # Test Skill
 "#;
 
-        let result = extract_frontmatter(content);
+        let result = extract_frontmatter(content, Path::new(""));
         assert!(result.is_err());
     }
 
@@ -427,6 +473,60 @@ version: 1.0.0
         assert_eq!(skill.scripts[0].script_type, ScriptType::Bash);
     }
 
+    #[test]
+    fn test_script_type_detection() {
+        let temp_dir = TempDir::new().unwrap();
+        let write = |name: &str, content: &[u8]| {
+            let path = temp_dir.path().join(name);
+            fs::write(&path, content).unwrap();
+            path
+        };
+
+        assert_eq!(ScriptType::detect(&write("plugin.WASM", b"")), ScriptType::Wasm);
+        assert_eq!(ScriptType::detect(&write("main.py", b"")), ScriptType::Python);
+        assert_eq!(
+            ScriptType::detect(&write("search", b"#!/usr/bin/env python3\nprint()")),
+            ScriptType::Python
+        );
+        assert_eq!(
+            ScriptType::detect(&write("deploy", b"#!/bin/bash -e\n")),
+            ScriptType::Bash
+        );
+        assert_eq!(
+            ScriptType::detect(&write("module", b"\0asm\x01\0\0\0")),
+            ScriptType::Wasm
+        );
+        assert_eq!(ScriptType::detect(&write("notes", b"plain text")), ScriptType::Unknown);
+        assert_eq!(
+            ScriptType::detect(&temp_dir.path().join("missing")),
+            ScriptType::Unknown
+        );
+        assert_eq!(ScriptType::from_shebang("#!/usr/bin/env -S luajit"), ScriptType::Lua);
+    }
+
+    #[test]
+    fn test_driver_inferred_from_entry() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("index.lua"),
+            "function run(input) return input end",
+        )
+        .unwrap();
+
+        let inferred = "---\nname: lua-skill\ndescription: Lua skill\nentry: index.lua\n---\n";
+        let (meta, _) = extract_frontmatter(inferred, temp_dir.path()).unwrap();
+        assert_eq!(meta.driver, SkillDriver::Lua);
+        assert_eq!(meta.path, temp_dir.path());
+
+        let declared = "---\nname: lua-skill\ndescription: Lua skill\ndriver: wasm\nentry: index.lua\n---\n";
+        let (meta, _) = extract_frontmatter(declared, temp_dir.path()).unwrap();
+        assert_eq!(meta.driver, SkillDriver::Wasm);
+
+        assert!(driver_conflicts(SkillDriver::Wasm, ScriptType::Lua));
+        assert!(!driver_conflicts(SkillDriver::Shell, ScriptType::Python));
+        assert!(!driver_conflicts(SkillDriver::Lua, ScriptType::Unknown));
+    }
+
     #[test]
     fn test_risk_level_parsing() {
         assert!(matches!(parse_risk_level("safe"), Ok(SkillRisk::Safe)));
@@ -3,11 +3,11 @@
 //! This module provides a Lua runtime implementation with sandboxing and
 //! permission-checked host API injection.
 
-use crate::host_api::{HostApiError, HostContext};
+use crate::host_api::{HostApiError, HostContext, HttpRequest};
 use crate::runtimes::{Plugin, PluginError, PluginFunction};
 use crate::types::SkillDriver;
 
-use mlua::{Function as LuaFunction, Lua, LuaOptions, StdLib, String as LuaString, Value as LuaValue};
+use mlua::{Function as LuaFunction, Lua, LuaOptions, LuaSerdeExt, StdLib, String as LuaString, Value as LuaValue};
 use std::collections::HashMap;
 use std::path::Path;
 
@@ -24,6 +24,8 @@ impl LuaEngine {
     }
 
     /// Load a Lua plugin from a file.
+    ///
+    /// Fails if the script doesn't define every declared function.
     pub fn load(
         &mut self, name: String, script_path: &Path, context: HostContext, functions: Vec<PluginFunction>,
     ) -> Result<(), PluginError> {
@@ -43,6 +45,7 @@ impl LuaEngine {
             .map_err(|e| PluginError::ExecutionFailed(format!("Failed to execute Lua script: {e}")))?;
 
         let plugin = LuaPlugin { name: name.clone(), lua, functions, _context: context };
+        plugin.verify_functions()?;
         self.plugins.insert(name, plugin);
         Ok(())
     }
@@ -100,12 +103,10 @@ impl LuaEngine {
         let read_ctx = context.clone();
         thunderus.set(
             "read_file",
-            lua.create_function(move |lua, path: String| {
-                let resolved = read_ctx.check_read_permission(&path).map_err(map_host_error)?;
-                match std::fs::read(&resolved) {
-                    Ok(bytes) => Ok(Some(lua.create_string(&bytes)?)),
-                    Err(_) => Ok(None),
-                }
+            lua.create_function(move |lua, path: String| match read_ctx.read_file(&path) {
+                Ok(bytes) => Ok(Some(lua.create_string(&bytes)?)),
+                Err(HostApiError::Io(_)) => Ok(None),
+                Err(e) => Err(map_host_error(e)),
             })?,
         )?;
 
@@ -113,12 +114,23 @@ impl LuaEngine {
         thunderus.set(
             "write_file",
             lua.create_function(move |_, (path, data): (String, LuaString)| {
-                let resolved = write_ctx.check_write_permission(&path).map_err(map_host_error)?;
-                if let Some(parent) = resolved.parent() {
-                    std::fs::create_dir_all(parent).map_err(|e| map_host_error(e.into()))?;
-                }
-                std::fs::write(&resolved, data.as_bytes()).map_err(|e| map_host_error(e.into()))?;
-                Ok(())
+                write_ctx.write_file(&path, &data.as_bytes()).map_err(map_host_error)
+            })?,
+        )?;
+
+        let list_ctx = context.clone();
+        thunderus.set(
+            "list_dir",
+            lua.create_function(move |_, path: String| list_ctx.list_dir(&path).map_err(map_host_error))?,
+        )?;
+
+        let http_ctx = context.clone();
+        thunderus.set(
+            "http_request",
+            lua.create_function(move |lua, request: LuaValue| {
+                let request: HttpRequest = lua.from_value(request)?;
+                let response = http_ctx.http_request(&request).map_err(map_host_error)?;
+                lua.to_value(&response)
             })?,
         )?;
 
@@ -241,4 +253,36 @@ end
         let output = engine.execute("lua-test", "run", b"ping").unwrap();
         assert_eq!(String::from_utf8_lossy(&output), "ping_ok");
     }
+
+    #[test]
+    #[cfg(feature = "lua")]
+    fn test_lua_load_rejects_undeclared_functions() {
+        let temp_dir = TempDir::new().unwrap();
+        let script_path = temp_dir.path().join("plugin.lua");
+        std::fs::write(&script_path, "function run(input)\n    return input\nend\n").unwrap();
+
+        let declare = |name: &str| PluginFunction {
+            name: name.to_string(),
+            description: String::new(),
+            parameters: serde_json::Value::Null,
+        };
+        let ctx = HostContext::new(
+            "lua-test".to_string(),
+            temp_dir.path().to_path_buf(),
+            SkillPermissions::default(),
+        );
+
+        let mut engine = LuaEngine::new();
+        let err = engine
+            .load(
+                "lua-test".to_string(),
+                &script_path,
+                ctx,
+                vec![declare("run"), declare("index")],
+            )
+            .unwrap_err();
+        assert!(matches!(err, PluginError::MissingFunctions(_)));
+        assert!(err.to_string().contains("index"));
+        assert!(engine.get("lua-test").is_none());
+    }
 }
@@ -42,6 +42,28 @@ pub trait Plugin: Send + Sync {
 
     /// Get the driver type
     fn driver(&self) -> crate::types::SkillDriver;
+
+    /// Check that every function declared in SKILL.md is exported by the plugin
+    ///
+    /// Catches manifests that have drifted from the code they describe.
+    fn verify_functions(&self) -> Result<()> {
+        let missing: Vec<String> = self
+            .functions()
+            .into_iter()
+            .filter(|function| !self.has_function(&function.name))
+            .map(|function| function.name)
+            .collect();
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(PluginError::MissingFunctions(format!(
+                "{} declares {} not exported by the plugin",
+                self.name(),
+                missing.join(", ")
+            )))
+        }
+    }
 }
 
 pub use crate::types::PluginFunction;
@@ -55,6 +77,9 @@ pub enum PluginError {
     #[error("Function not found: {0}")]
     FunctionNotFound(String),
 
+    #[error("Declared functions missing: {0}")]
+    MissingFunctions(String),
+
     #[error("Execution failed: {0}")]
     ExecutionFailed(String),
 
@@ -74,3 +99,57 @@ pub enum PluginError {
 
 /// Result type for plugin operations.
 pub type Result<T> = std::result::Result<T, PluginError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::SkillDriver;
+
+    struct StubPlugin {
+        declared: Vec<&'static str>,
+        exported: Vec<&'static str>,
+    }
+
+    impl Plugin for StubPlugin {
+        fn name(&self) -> &str {
+            "stub"
+        }
+
+        fn functions(&self) -> Vec<PluginFunction> {
+            self.declared
+                .iter()
+                .map(|name| PluginFunction {
+                    name: name.to_string(),
+                    description: String::new(),
+                    parameters: serde_json::Value::Null,
+                })
+                .collect()
+        }
+
+        fn has_function(&self, name: &str) -> bool {
+            self.exported.contains(&name)
+        }
+
+        fn call(&mut self, func: &str, _input: &[u8]) -> Result<Vec<u8>> {
+            Err(PluginError::FunctionNotFound(func.to_string()))
+        }
+
+        fn driver(&self) -> SkillDriver {
+            SkillDriver::Lua
+        }
+    }
+
+    #[test]
+    fn test_verify_functions() {
+        let plugin = StubPlugin { declared: vec!["search", "index"], exported: vec!["search", "index", "helper"] };
+        assert!(plugin.verify_functions().is_ok());
+
+        let plugin = StubPlugin { declared: vec!["search", "index", "purge"], exported: vec!["search"] };
+        let err = plugin.verify_functions().unwrap_err();
+        assert!(matches!(err, PluginError::MissingFunctions(_)));
+        assert_eq!(
+            err.to_string(),
+            "Declared functions missing: stub declares index, purge not exported by the plugin"
+        );
+    }
+}
@@ -36,7 +36,8 @@ impl WasmEngine {
     /// * `permissions` - Permission grants for the plugin
     ///
     /// # Errors
-    /// Returns an error if the WASM file cannot be loaded or compiled.
+    /// Returns an error if the WASM file cannot be loaded or compiled, or if
+    /// it doesn't export every declared function.
     pub fn load(
         &mut self, name: String, wasm_path: &Path, context: HostContext, functions: Vec<PluginFunction>,
     ) -> Result<(), PluginError> {
@@ -48,6 +49,7 @@ impl WasmEngine {
             .map_err(|e| PluginError::Wasm(format!("Failed to load plugin: {e}")))?;
 
         let wasm_plugin = WasmPlugin { functions, name: name.clone(), plugin };
+        wasm_plugin.verify_functions()?;
         self.plugins.insert(name, wasm_plugin);
         Ok(())
     }
@@ -153,7 +155,7 @@ pub struct WasmPlugin {
     name: String,
     /// The underlying Extism plugin
     plugin: ExtismPlugin,
-    /// Available functions (discovered at load time)
+    /// Functions declared in SKILL.md
     functions: Vec<PluginFunction>,
 }
 
@@ -174,7 +176,7 @@ impl Plugin for WasmPlugin {
     }
 
     fn has_function(&self, name: &str) -> bool {
-        self.functions.iter().any(|f| f.name == name)
+        self.plugin.function_exists(name)
     }
 
     /// Execute a WASM function using Extism's "bytes-in, bytes-out" ABI.
@@ -8,7 +8,7 @@
 //! should use Extism's user_data feature to pass plugin-specific context to
 //! host functions for proper per-plugin isolation.
 
-use crate::host_api::{HostApiError, HostContext};
+use crate::host_api::{HostApiError, HostContext, HttpRequest};
 use extism::{Function, PTR, UserData, host_fn};
 
 fn map_host_error(err: HostApiError) -> extism::Error {
@@ -32,22 +32,20 @@ host_fn!(thunderus_log(user_data: HostContext; level: String, msg: String) -> ()
 host_fn!(thunderus_read_file(user_data: HostContext; path: String) -> Vec<u8> {
     let ctx = user_data.get()?;
     let ctx = ctx.lock().unwrap();
-    let resolved = ctx.check_read_permission(&path).map_err(map_host_error)?;
-    std::fs::read(&resolved)
-        .map_err(|e| extism::Error::msg(format!("Failed to read file: {e}")))
+    ctx.read_file(&path).map_err(map_host_error)
 });
 
 host_fn!(thunderus_write_file(user_data: HostContext; path: String, data: Vec<u8>) -> () {
     let ctx = user_data.get()?;
     let ctx = ctx.lock().unwrap();
-    let resolved = ctx.check_write_permission(&path).map_err(map_host_error)?;
-    if let Some(parent) = resolved.parent() {
-        std::fs::create_dir_all(parent)
-            .map_err(|e| extism::Error::msg(format!("Failed to create directory: {e}")))?;
-    }
-    std::fs::write(&resolved, data)
-        .map_err(|e| extism::Error::msg(format!("Failed to write file: {e}")))?;
-    Ok(())
+    ctx.write_file(&path, &data).map_err(map_host_error)
+});
+
+host_fn!(thunderus_list_dir(user_data: HostContext; path: String) -> String {
+    let ctx = user_data.get()?;
+    let ctx = ctx.lock().unwrap();
+    let entries = ctx.list_dir(&path).map_err(map_host_error)?;
+    serde_json::to_string(&entries).map_err(|e| extism::Error::msg(format!("Failed to encode listing: {e}")))
 });
 
 host_fn!(thunderus_kv_get(user_data: HostContext; key: String) -> String {
@@ -65,6 +63,15 @@ host_fn!(thunderus_kv_set(user_data: HostContext; key: String, value: String) ->
     Ok(())
 });
 
+host_fn!(thunderus_http_request(user_data: HostContext; request: String) -> String {
+    let ctx = user_data.get()?;
+    let ctx = ctx.lock().unwrap().clone();
+    let request: HttpRequest = serde_json::from_str(&request)
+        .map_err(|e| extism::Error::msg(format!("Invalid HTTP request: {e}")))?;
+    let response = ctx.http_request(&request).map_err(map_host_error)?;
+    serde_json::to_string(&response).map_err(|e| extism::Error::msg(format!("Failed to encode response: {e}")))
+});
+
 /// Get all host functions as Extism Function objects.
 ///
 /// This function converts the module-level host functions into
@@ -87,6 +94,20 @@ pub fn get_host_functions(context: HostContext) -> Vec<Function> {
             user_data.clone(),
             thunderus_write_file,
         ),
+        Function::new(
+            "thunderus_list_dir",
+            [PTR],
+            [PTR],
+            user_data.clone(),
+            thunderus_list_dir,
+        ),
+        Function::new(
+            "thunderus_http_request",
+            [PTR],
+            [PTR],
+            user_data.clone(),
+            thunderus_http_request,
+        ),
         Function::new("thunderus_kv_get", [PTR], [PTR], user_data.clone(), thunderus_kv_get),
         Function::new("thunderus_kv_set", [PTR, PTR], [], user_data, thunderus_kv_set),
     ]
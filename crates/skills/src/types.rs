@@ -3,7 +3,8 @@
 //! Skills are on-demand capabilities loaded from `.thunderus/skills/` directories.
 //! Each skill has a SKILL.md file with frontmatter metadata and implementation scripts.
 
-use std::path::PathBuf;
+use std::io::Read;
+use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
 
@@ -131,6 +132,37 @@ pub struct SkillMeta {
     /// Multiple functions from single plugin
     #[serde(default)]
     pub functions: Vec<PluginFunction>,
+
+    /// Keywords or `/regex/` patterns that make this skill relevant to a message
+    ///
+    /// Skills without triggers are always offered to the model.
+    #[serde(default)]
+    pub triggers: Vec<String>,
+}
+
+impl SkillMeta {
+    /// Return the first trigger that matches `message`, if any.
+    ///
+    /// Plain triggers match case-insensitively anywhere in the message;
+    /// triggers written as `/pattern/` are regular expressions.
+    pub fn matching_trigger(&self, message: &str) -> Option<&str> {
+        let message_lower = message.to_lowercase();
+        self.triggers
+            .iter()
+            .find(|trigger| match trigger_regex(trigger) {
+                Some(pattern) => regex::Regex::new(pattern).is_ok_and(|re| re.is_match(message)),
+                None => message_lower.contains(&trigger.to_lowercase()),
+            })
+            .map(String::as_str)
+    }
+}
+
+/// The pattern inside a `/pattern/` trigger, or `None` for a plain keyword.
+pub(crate) fn trigger_regex(trigger: &str) -> Option<&str> {
+    trigger
+        .strip_prefix('/')
+        .and_then(|rest| rest.strip_suffix('/'))
+        .filter(|pattern| !pattern.is_empty())
 }
 
 /// Risk level determines approval requirements for skill execution.
@@ -173,16 +205,88 @@ pub struct SkillScript {
     pub script_type: ScriptType,
 }
 
-/// The type of script based on file extension.
+/// The type of script based on file extension or shebang.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum ScriptType {
     Bash,
     JavaScript,
     Python,
     Lua,
+    Wasm,
     Unknown,
 }
 
+impl ScriptType {
+    /// Detect a script's type from its extension, falling back to its shebang
+    /// (or the WASM magic number) when the extension is missing or unrecognized.
+    pub fn detect(path: &Path) -> Self {
+        let by_extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map_or(Self::Unknown, Self::from_extension);
+        if by_extension != Self::Unknown {
+            return by_extension;
+        }
+
+        let mut head = [0u8; 128];
+        let read = std::fs::File::open(path)
+            .and_then(|mut file| file.read(&mut head))
+            .unwrap_or(0);
+        let head = &head[..read];
+
+        if head.starts_with(b"\0asm") {
+            Self::Wasm
+        } else {
+            let first_line = String::from_utf8_lossy(head);
+            Self::from_shebang(first_line.lines().next().unwrap_or_default())
+        }
+    }
+
+    /// Map a file extension (without the dot) to a script type.
+    pub fn from_extension(ext: &str) -> Self {
+        match ext.to_lowercase().as_str() {
+            "sh" | "bash" => Self::Bash,
+            "js" | "mjs" => Self::JavaScript,
+            "py" => Self::Python,
+            "lua" => Self::Lua,
+            "wasm" => Self::Wasm,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// Map a `#!` line to a script type, looking through `/usr/bin/env`.
+    pub fn from_shebang(line: &str) -> Self {
+        let Some(command) = line.strip_prefix("#!") else {
+            return Self::Unknown;
+        };
+
+        let mut words = command.split_whitespace();
+        let mut interpreter = words.next().unwrap_or_default();
+        if interpreter.rsplit('/').next() == Some("env") {
+            interpreter = words.find(|word| !word.starts_with('-')).unwrap_or_default();
+        }
+
+        let program = interpreter.rsplit('/').next().unwrap_or_default();
+        match program.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.') {
+            "sh" | "bash" | "zsh" | "dash" => Self::Bash,
+            "node" | "deno" | "bun" => Self::JavaScript,
+            "python" => Self::Python,
+            "lua" | "luajit" | "luau" => Self::Lua,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// The driver that runs this kind of script, if known.
+    pub fn driver(self) -> Option<SkillDriver> {
+        match self {
+            Self::Bash | Self::JavaScript | Self::Python => Some(SkillDriver::Shell),
+            Self::Lua => Some(SkillDriver::Lua),
+            Self::Wasm => Some(SkillDriver::Wasm),
+            Self::Unknown => None,
+        }
+    }
+}
+
 /// A matched skill from a query, with relevance score.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SkillMatch {
@@ -208,11 +312,15 @@ pub struct SkillsConfig {
 
     /// Enable auto-discovery based on task intent
     pub auto_discovery: bool,
+
+    /// Register skill tools as `skill:<name>` so they can't collide with
+    /// built-in tools; by default they use the bare skill name
+    pub namespace_tools: bool,
 }
 
 impl Default for SkillsConfig {
     fn default() -> Self {
-        Self { enabled: true, skills_dir: None, auto_discovery: true }
+        Self { enabled: true, skills_dir: None, auto_discovery: true, namespace_tools: false }
     }
 }
 
@@ -327,6 +327,8 @@ impl MemoryIndexer {
             event_ids: doc.frontmatter.provenance.events.clone(),
             patch_ids: doc.frontmatter.provenance.patches.clone(),
             token_count: content.len() / 4,
+            verification: Some(doc.frontmatter.verification.state()),
+            verified_commit: doc.frontmatter.verification.last_verified_commit.clone(),
         };
 
         let full_doc = format!(
@@ -491,6 +493,43 @@ Use idiomatic Rust
         assert_eq!(hits[0].title, "Project Core Memory");
     }
 
+    #[tokio::test]
+    async fn test_indexer_reports_invalid_frontmatter_per_document() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = MemoryStore::open(&temp_dir.path().join("memory.db")).await.unwrap();
+
+        let facts_dir = temp_dir
+            .path()
+            .join(".thunderus")
+            .join("memory")
+            .join("semantic")
+            .join("FACTS");
+        fs::create_dir_all(&facts_dir).await.unwrap();
+
+        let fact = |kind: &str| {
+            format!(
+                "---\nid: fact.testing\ntitle: Testing\nkind: {}\ntags: [testing]\ncreated: 2026-01-21T00:00:00Z\nupdated: 2026-01-21T00:00:00Z\n---\n\nRun cargo test.\n",
+                kind
+            )
+        };
+        fs::write(facts_dir.join("good.md"), fact("fact")).await.unwrap();
+        fs::write(
+            facts_dir.join("bad.md"),
+            fact("factoid").replace("fact.testing", "fact.bad"),
+        )
+        .await
+        .unwrap();
+
+        let paths = MemoryPaths::from_thunderus_root(temp_dir.path());
+        let indexer = MemoryIndexer::new(store, paths, temp_dir.path());
+        let result = indexer.reindex_all().await.unwrap();
+
+        assert_eq!(result.docs_added, 1);
+        assert_eq!(result.errors.len(), 1);
+        assert!(result.errors[0].path.ends_with("bad.md"));
+        assert!(result.errors[0].message.contains("kind (line 4)"));
+    }
+
     #[tokio::test]
     async fn test_namespace_from_path() {
         let temp_dir = TempDir::new().unwrap();
@@ -23,6 +23,8 @@
 //!     event_ids: vec![],
 //!     patch_ids: vec![],
 //!     token_count: 100,
+//!     verification: None,
+//!     verified_commit: None,
 //! };
 //! store.put("semantic/facts", "testing.md", content, meta).await?;
 //!
@@ -43,7 +45,7 @@ mod vector;
 
 pub use error::{Error, Result};
 pub use indexer::{IndexError, IndexResult, MemoryIndexer, generate_placeholder_embedding};
-pub use memory_store::{MemoryMeta, MemoryStore, SearchFilters, SearchHit, StoreStats};
+pub use memory_store::{MemoryMeta, MemoryRecord, MemoryStore, SearchFilters, SearchHit, StoreStats};
 pub use migration::{BM25_WEIGHTS, MigrationManager};
 pub use retriever::StoreRetriever;
 pub use schema::{BM25_COLUMN_WEIGHTS, BM25_FUNCTION, MEMORY_DOCS_SQL, SCHEMA_VERSION, SCHEMA_VERSION_SQL};
@@ -2,6 +2,7 @@
 //!
 //! Provides durable storage and full-text search for memory documents.
 use crate::error::{Error, Result};
+use crate::indexer::{IndexError, IndexResult};
 use crate::migration::MigrationManager;
 use crate::schema;
 
@@ -9,7 +10,7 @@ use chrono::{DateTime, Utc};
 use rusqlite::{OptionalExtension, params};
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, path::Path, sync::Arc};
-use thunderus_core::memory::MemoryKind;
+use thunderus_core::memory::{MemoryKind, VerificationState};
 use tokio_rusqlite::Connection;
 use tracing::instrument;
 
@@ -40,6 +41,12 @@ pub struct MemoryMeta {
     pub patch_ids: Vec<String>,
     /// Approximate token count
     pub token_count: usize,
+    /// Verification state when indexed (absent in older indexes)
+    #[serde(default)]
+    pub verification: Option<VerificationState>,
+    /// Commit the document was last verified against
+    #[serde(default)]
+    pub verified_commit: Option<String>,
 }
 
 /// A search hit with snippet and citation
@@ -62,6 +69,27 @@ pub struct SearchHit {
     /// Event IDs for provenance
     #[serde(default)]
     pub event_ids: Vec<String>,
+    /// Approximate token count of the whole document
+    #[serde(default)]
+    pub token_count: usize,
+    /// Verification state of the document (absent in older indexes)
+    #[serde(default)]
+    pub verification: Option<VerificationState>,
+    /// Commit the document was last verified against
+    #[serde(default)]
+    pub verified_commit: Option<String>,
+}
+
+impl SearchHit {
+    /// Re-check the indexed verification state against the repository's current HEAD
+    ///
+    /// The index records the state as of indexing; a document verified at an
+    /// older commit than `head` is reported as stale.
+    pub fn refresh_verification(&mut self, head: Option<&str>) {
+        self.verification = self
+            .verification
+            .map(|state| state.at_head(self.verified_commit.as_deref(), head));
+    }
 }
 
 /// Search filters for scoping queries
@@ -90,6 +118,19 @@ pub struct StoreStats {
     pub last_indexed: DateTime<Utc>,
 }
 
+/// One document in a memory export, written as a line of NDJSON
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemoryRecord {
+    /// Namespace the document is stored under
+    pub namespace: String,
+    /// Key within the namespace
+    pub key: String,
+    /// Document body
+    pub content: String,
+    /// Document metadata, including its ID
+    pub meta: MemoryMeta,
+}
+
 /// A handle to the memory store backed by SQLite FTS5
 ///
 /// The store provides:
@@ -140,17 +181,7 @@ impl MemoryStore {
 
         self.conn
             .call(move |conn| {
-                let mut stmt = conn.prepare_cached(
-                    r#"
-                    INSERT INTO memory_docs (id, namespace, key, content, meta_json)
-                    VALUES (?1, ?2, ?3, ?4, ?5)
-                    ON CONFLICT (namespace, key) DO UPDATE SET
-                        id = excluded.id,
-                        content = excluded.content,
-                        meta_json = excluded.meta_json,
-                        updated_at = datetime('now')
-                    "#,
-                )?;
+                let mut stmt = conn.prepare_cached(UPSERT_DOC_SQL)?;
 
                 stmt.execute(params![&meta.id, &namespace, &key, &content, &meta_json])?;
                 tracing::trace!("Document stored successfully");
@@ -191,6 +222,34 @@ impl MemoryStore {
         Ok(result)
     }
 
+    /// Retrieve a document by its document ID
+    #[instrument(skip(self), fields(id))]
+    pub async fn get_by_id(&self, id: &str) -> Result<Option<(String, MemoryMeta)>> {
+        tracing::trace!("Getting document by id: {}", id);
+
+        let id = id.to_owned();
+
+        let result = self
+            .conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare_cached("SELECT content, meta_json FROM memory_docs WHERE id = ?1")?;
+
+                let result = stmt
+                    .query_row(params![&id], |row| {
+                        let content: String = row.get(0)?;
+                        let meta_json: String = row.get(1)?;
+                        let meta: MemoryMeta = serde_json::from_str(&meta_json)
+                            .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+                        Ok((content, meta))
+                    })
+                    .optional()?;
+                Ok::<_, rusqlite::Error>(result)
+            })
+            .await?;
+
+        Ok(result)
+    }
+
     /// Delete a document from the store
     #[instrument(skip(self), fields(namespace, key))]
     pub async fn delete(&self, namespace: &str, key: &str) -> Result<bool> {
@@ -267,7 +326,10 @@ impl MemoryStore {
                 memory_fts.path,
                 snippet(memory_fts, 1, '<b>', '</b>', '...', 32) as snippet,
                 bm25(memory_fts) as score,
-                json_extract(memory_docs.meta_json, '$.event_ids') as event_ids
+                json_extract(memory_docs.meta_json, '$.event_ids') as event_ids,
+                json_extract(memory_docs.meta_json, '$.token_count') as token_count,
+                json_extract(memory_docs.meta_json, '$.verification') as verification,
+                json_extract(memory_docs.meta_json, '$.verified_commit') as verified_commit
             FROM memory_fts
             {}
             {}
@@ -309,6 +371,9 @@ impl MemoryStore {
                             snippet: row.get(4)?,
                             score: row.get(5)?,
                             event_ids,
+                            token_count: row.get::<_, Option<i64>>(7)?.unwrap_or(0) as usize,
+                            verification: parse_verification(row.get(8)?),
+                            verified_commit: row.get(9)?,
                         })
                     })?
                     .collect::<std::result::Result<Vec<_>, _>>()?;
@@ -359,7 +424,10 @@ impl MemoryStore {
                         json_extract(d.meta_json, '$.path') as path,
                         d.content,
                         json_extract(d.meta_json, '$.event_ids') as event_ids,
-                        json_extract(d.meta_json, '$.kind') as kind_raw
+                        json_extract(d.meta_json, '$.kind') as kind_raw,
+                        json_extract(d.meta_json, '$.token_count') as token_count,
+                        json_extract(d.meta_json, '$.verification') as verification,
+                        json_extract(d.meta_json, '$.verified_commit') as verified_commit
                     FROM memory_docs d
                     WHERE d.id IN ({})
                     "#,
@@ -381,6 +449,9 @@ impl MemoryStore {
                         let content: String = row.get(3)?;
                         let event_ids_raw: Option<String> = row.get(4)?;
                         let kind_raw: String = row.get(5)?;
+                        let token_count: Option<i64> = row.get(6)?;
+                        let verification = parse_verification(row.get(7)?);
+                        let verified_commit: Option<String> = row.get(8)?;
 
                         let event_ids: Vec<String> = match event_ids_raw {
                             Some(raw) => serde_json::from_str(&raw).unwrap_or_default(),
@@ -401,6 +472,9 @@ impl MemoryStore {
                                 snippet: content.chars().take(200).collect::<String>() + "...",
                                 score: 0.0,
                                 event_ids,
+                                token_count: token_count.unwrap_or(0) as usize,
+                                verification,
+                                verified_commit,
                             },
                         ))
                     })?
@@ -572,7 +646,9 @@ impl MemoryStore {
                         .with_timezone(&Utc)
                 };
 
-                let index_size = 0u64;
+                let page_count: i64 = conn.query_row("PRAGMA page_count", [], |row| row.get(0))?;
+                let page_size: i64 = conn.query_row("PRAGMA page_size", [], |row| row.get(0))?;
+                let index_size = (page_count * page_size) as u64;
 
                 Ok::<_, rusqlite::Error>(StoreStats {
                     doc_count: doc_count as usize,
@@ -585,6 +661,189 @@ impl MemoryStore {
 
         Ok(stats)
     }
+
+    /// Every stored document, ordered by namespace and key
+    #[instrument(skip(self))]
+    pub async fn records(&self) -> Result<Vec<MemoryRecord>> {
+        let rows = self
+            .conn
+            .call(|conn| {
+                let mut stmt =
+                    conn.prepare("SELECT namespace, key, content, meta_json FROM memory_docs ORDER BY namespace, key")?;
+                let rows = stmt
+                    .query_map([], |row| {
+                        Ok((
+                            row.get::<_, String>(0)?,
+                            row.get::<_, String>(1)?,
+                            row.get::<_, String>(2)?,
+                            row.get::<_, String>(3)?,
+                        ))
+                    })?
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                Ok::<_, rusqlite::Error>(rows)
+            })
+            .await?;
+
+        rows.into_iter()
+            .map(|(namespace, key, content, meta_json)| {
+                Ok(MemoryRecord { namespace, key, content, meta: serde_json::from_str(&meta_json)? })
+            })
+            .collect()
+    }
+
+    /// Write every document and its metadata to `path` as NDJSON
+    ///
+    /// The file is portable between machines and can be loaded into another
+    /// store with [`MemoryStore::import`]. Returns the number of documents written.
+    #[instrument(skip(self), fields(path = %path.display()))]
+    pub async fn export(&self, path: &Path) -> Result<usize> {
+        let records = self.records().await?;
+        let mut output = String::new();
+        for record in &records {
+            output.push_str(&serde_json::to_string(record)?);
+            output.push('\n');
+        }
+        tokio::fs::write(path, output).await?;
+
+        tracing::info!("Exported {} memory documents to {}", records.len(), path.display());
+        Ok(records.len())
+    }
+
+    /// Load documents written by [`MemoryStore::export`], upserting by document ID
+    ///
+    /// New IDs count as added and changed documents as updated; documents that
+    /// are already stored unchanged are skipped, so importing the same file
+    /// twice is a no-op. A document whose ID is stored under a different
+    /// namespace or key is moved. Malformed lines, and documents whose
+    /// namespace and key are taken by a different ID, are reported as errors
+    /// without stopping the import.
+    #[instrument(skip(self), fields(path = %path.display()))]
+    pub async fn import(&self, path: &Path) -> Result<IndexResult> {
+        let start = std::time::Instant::now();
+        let input = tokio::fs::read_to_string(path).await?;
+        let mut result = IndexResult::default();
+
+        for (index, line) in input.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let location = format!("{}:{}", path.display(), index + 1);
+            let record: MemoryRecord = match serde_json::from_str(line) {
+                Ok(record) => record,
+                Err(e) => {
+                    result.errors.push(IndexError::new(location, e.to_string()));
+                    continue;
+                }
+            };
+
+            match self.upsert_record(record).await {
+                Ok(Some(true)) => result.docs_added += 1,
+                Ok(Some(false)) => result.docs_updated += 1,
+                Ok(None) => {}
+                Err(e) => result.errors.push(IndexError::new(location, e.to_string())),
+            }
+        }
+
+        result.duration_ms = start.elapsed().as_millis() as u64;
+        tracing::info!(
+            "Imported memory: {} added, {} updated, {} errors",
+            result.docs_added,
+            result.docs_updated,
+            result.errors.len()
+        );
+        Ok(result)
+    }
+
+    /// Store a record unless it is already present unchanged
+    ///
+    /// The lookup, move and write share one transaction. Returns `Some(true)`
+    /// when added, `Some(false)` when updated and `None` when skipped; a record
+    /// whose namespace and key belong to a different ID is refused with
+    /// [`Error::AlreadyExists`] rather than overwriting that document.
+    async fn upsert_record(&self, record: MemoryRecord) -> Result<Option<bool>> {
+        let MemoryRecord { namespace, key, content, meta } = record;
+        let meta_value = serde_json::to_value(&meta)?;
+        let meta_json = serde_json::to_string(&meta)?;
+        let id = meta.id;
+
+        let (ns, k) = (namespace.clone(), key.clone());
+        let outcome = self
+            .conn
+            .call(move |conn| {
+                let tx = conn.transaction()?;
+                let occupant: Option<String> = tx
+                    .query_row(
+                        "SELECT id FROM memory_docs WHERE namespace = ?1 AND key = ?2",
+                        params![&ns, &k],
+                        |row| row.get(0),
+                    )
+                    .optional()?;
+                if occupant.as_ref().is_some_and(|occupant| *occupant != id) {
+                    return Ok(UpsertOutcome::Conflict);
+                }
+
+                let stored: Option<(String, String)> = tx
+                    .query_row(
+                        "SELECT content, meta_json FROM memory_docs WHERE id = ?1",
+                        params![&id],
+                        |row| Ok((row.get(0)?, row.get(1)?)),
+                    )
+                    .optional()?;
+                let outcome = match &stored {
+                    None => UpsertOutcome::Added,
+                    Some((stored_content, stored_meta)) if occupant.is_some() && *stored_content == content => {
+                        let stored_meta: MemoryMeta = serde_json::from_str(stored_meta)
+                            .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+                        let stored_value = serde_json::to_value(&stored_meta)
+                            .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+                        if stored_value == meta_value {
+                            return Ok(UpsertOutcome::Skipped);
+                        }
+                        UpsertOutcome::Updated
+                    }
+                    Some(_) => UpsertOutcome::Updated,
+                };
+
+                if stored.is_some() && occupant.is_none() {
+                    tx.execute("DELETE FROM memory_docs WHERE id = ?1", params![&id])?;
+                }
+                tx.execute(UPSERT_DOC_SQL, params![&id, &ns, &k, &content, &meta_json])?;
+                tx.commit()?;
+                Ok::<_, rusqlite::Error>(outcome)
+            })
+            .await?;
+
+        match outcome {
+            UpsertOutcome::Added => Ok(Some(true)),
+            UpsertOutcome::Updated => Ok(Some(false)),
+            UpsertOutcome::Skipped => Ok(None),
+            UpsertOutcome::Conflict => Err(Error::AlreadyExists { namespace, key }),
+        }
+    }
+}
+
+/// Insert a document, replacing whatever is stored under its namespace and key
+const UPSERT_DOC_SQL: &str = r#"
+    INSERT INTO memory_docs (id, namespace, key, content, meta_json)
+    VALUES (?1, ?2, ?3, ?4, ?5)
+    ON CONFLICT (namespace, key) DO UPDATE SET
+        id = excluded.id,
+        content = excluded.content,
+        meta_json = excluded.meta_json,
+        updated_at = datetime('now')
+"#;
+
+/// What [`MemoryStore::upsert_record`] did with a record
+enum UpsertOutcome {
+    Added,
+    Updated,
+    Skipped,
+    Conflict,
+}
+
+/// Parse a `verification` value extracted from `meta_json`
+fn parse_verification(raw: Option<String>) -> Option<VerificationState> {
+    serde_json::from_value(serde_json::Value::String(raw?)).ok()
 }
 
 #[cfg(test)]
@@ -631,6 +890,8 @@ mod tests {
             event_ids: vec!["evt-1".to_string()],
             patch_ids: vec![],
             token_count: 100,
+            verification: None,
+            verified_commit: None,
         };
 
         let content = "# Test Document\n\nThis is a test document.";
@@ -642,9 +903,51 @@ mod tests {
         assert_eq!(retrieved_content, content);
         assert_eq!(retrieved_meta.id, "test-doc-1");
         assert_eq!(retrieved_meta.kind, MemoryKind::Fact);
+
+        let by_id = store.get_by_id("test-doc-1").await.unwrap();
+        assert_eq!(by_id.map(|(c, _)| c), Some(content.to_string()));
+        assert!(store.get_by_id("missing").await.unwrap().is_none());
         let _ = temp_dir;
     }
 
+    #[tokio::test]
+    async fn test_search_hit_verification_checked_against_head() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let store = MemoryStore::open(&db_path).await.unwrap();
+
+        let meta = MemoryMeta {
+            id: "verified-doc".to_string(),
+            kind: MemoryKind::Fact,
+            title: "Verified".to_string(),
+            tags: vec![],
+            headings: vec![],
+            path: "semantic/FACTS/verified.md".to_string(),
+            updated: Utc::now(),
+            event_ids: vec![],
+            patch_ids: vec![],
+            token_count: 10,
+            verification: Some(VerificationState::Verified),
+            verified_commit: Some("abc123".to_string()),
+        };
+        store
+            .put("semantic/facts", "verified.md", "release checklist", meta)
+            .await
+            .unwrap();
+
+        let mut hit = store
+            .search("release", SearchFilters::default())
+            .await
+            .unwrap()
+            .remove(0);
+        assert_eq!(hit.verified_commit.as_deref(), Some("abc123"));
+
+        hit.refresh_verification(Some("abc123"));
+        assert_eq!(hit.verification, Some(VerificationState::Verified));
+        hit.refresh_verification(Some("def456"));
+        assert_eq!(hit.verification, Some(VerificationState::Stale));
+    }
+
     #[tokio::test]
     async fn test_get_not_found() {
         let temp_dir = TempDir::new().unwrap();
@@ -672,6 +975,8 @@ mod tests {
             event_ids: vec![],
             patch_ids: vec![],
             token_count: 50,
+            verification: None,
+            verified_commit: None,
         };
 
         store.put("core", "test.md", "content", meta).await.unwrap();
@@ -700,6 +1005,8 @@ mod tests {
             event_ids: vec![],
             patch_ids: vec![],
             token_count: 100,
+            verification: None,
+            verified_commit: None,
         };
 
         let content = "# Testing Coverage\n\nMinimum line coverage: 80%. Use cargo llvm-cov.";
@@ -728,6 +1035,8 @@ mod tests {
             event_ids: vec![],
             patch_ids: vec![],
             token_count: 50,
+            verification: None,
+            verified_commit: None,
         };
         store
             .put("semantic/facts", "test.md", "test content", fact_meta)
@@ -745,6 +1054,8 @@ mod tests {
             event_ids: vec![],
             patch_ids: vec![],
             token_count: 50,
+            verification: None,
+            verified_commit: None,
         };
         store
             .put("semantic/decisions", "adr-001.md", "test content", adr_meta)
@@ -778,12 +1089,125 @@ mod tests {
             event_ids: vec![],
             patch_ids: vec![],
             token_count: 75,
+            verification: None,
+            verified_commit: None,
         };
 
         store.put("core", "test.md", "content", meta).await.unwrap();
 
         let stats = store.stats().await.unwrap();
         assert_eq!(stats.doc_count, 1);
+        assert_eq!(stats.docs_by_kind.get("core"), Some(&1));
+        assert!(stats.index_size > 0);
+        let _ = temp_dir;
+    }
+
+    fn fact(id: &str, title: &str) -> MemoryMeta {
+        MemoryMeta {
+            id: id.to_string(),
+            kind: MemoryKind::Fact,
+            title: title.to_string(),
+            tags: vec!["test".to_string()],
+            headings: vec![],
+            path: format!("semantic/FACTS/{}.md", id),
+            updated: Utc::now(),
+            event_ids: vec![],
+            patch_ids: vec![],
+            token_count: 10,
+            verification: None,
+            verified_commit: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_export_import_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = MemoryStore::open(&temp_dir.path().join("source.db")).await.unwrap();
+        source
+            .put("semantic/facts", "a.md", "# A\n\nFirst fact.", fact("fact-a", "A"))
+            .await
+            .unwrap();
+        source
+            .put("semantic/facts", "b.md", "# B\n\nSecond fact.", fact("fact-b", "B"))
+            .await
+            .unwrap();
+
+        let export_path = temp_dir.path().join("memory.ndjson");
+        assert_eq!(source.export(&export_path).await.unwrap(), 2);
+        assert_eq!(std::fs::read_to_string(&export_path).unwrap().lines().count(), 2);
+
+        let target = MemoryStore::open(&temp_dir.path().join("target.db")).await.unwrap();
+        let result = target.import(&export_path).await.unwrap();
+        assert_eq!((result.docs_added, result.docs_updated), (2, 0));
+        assert!(result.errors.is_empty());
+
+        let (content, meta) = target.get_by_id("fact-b").await.unwrap().unwrap();
+        assert_eq!(content, "# B\n\nSecond fact.");
+        assert_eq!(meta.title, "B");
+        assert_eq!(target.stats().await.unwrap().doc_count, 2);
+
+        let again = target.import(&export_path).await.unwrap();
+        assert_eq!((again.docs_added, again.docs_updated), (0, 0));
+        let _ = temp_dir;
+    }
+
+    #[tokio::test]
+    async fn test_import_updates_changed_and_reports_malformed_lines() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = MemoryStore::open(&temp_dir.path().join("test.db")).await.unwrap();
+        store
+            .put("semantic/facts", "a.md", "Old content", fact("fact-a", "A"))
+            .await
+            .unwrap();
+
+        let changed = MemoryRecord {
+            namespace: "semantic/facts".to_string(),
+            key: "renamed.md".to_string(),
+            content: "New content".to_string(),
+            meta: fact("fact-a", "A"),
+        };
+        let import_path = temp_dir.path().join("memory.ndjson");
+        let input = format!("{}\nnot json\n", serde_json::to_string(&changed).unwrap());
+        std::fs::write(&import_path, input).unwrap();
+
+        let result = store.import(&import_path).await.unwrap();
+        assert_eq!((result.docs_added, result.docs_updated), (0, 1));
+        assert_eq!(result.errors.len(), 1);
+        assert!(result.errors[0].path.ends_with("memory.ndjson:2"));
+
+        assert!(store.get("semantic/facts", "a.md").await.unwrap().is_none());
+        let (content, _) = store.get("semantic/facts", "renamed.md").await.unwrap().unwrap();
+        assert_eq!(content, "New content");
+        assert_eq!(store.stats().await.unwrap().doc_count, 1);
+        let _ = temp_dir;
+    }
+
+    #[tokio::test]
+    async fn test_import_reports_key_taken_by_another_id() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = MemoryStore::open(&temp_dir.path().join("test.db")).await.unwrap();
+        store
+            .put("semantic/facts", "a.md", "Original", fact("fact-a", "A"))
+            .await
+            .unwrap();
+
+        let clash = MemoryRecord {
+            namespace: "semantic/facts".to_string(),
+            key: "a.md".to_string(),
+            content: "Imposter".to_string(),
+            meta: fact("fact-b", "B"),
+        };
+        let import_path = temp_dir.path().join("memory.ndjson");
+        std::fs::write(&import_path, serde_json::to_string(&clash).unwrap()).unwrap();
+
+        let result = store.import(&import_path).await.unwrap();
+        assert_eq!((result.docs_added, result.docs_updated), (0, 0));
+        assert_eq!(result.errors.len(), 1);
+        assert!(result.errors[0].message.contains("already exists"));
+
+        let (content, meta) = store.get("semantic/facts", "a.md").await.unwrap().unwrap();
+        assert_eq!(content, "Original");
+        assert_eq!(meta.id, "fact-a");
         let _ = temp_dir;
     }
 }
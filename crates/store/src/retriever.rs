@@ -7,7 +7,9 @@ use crate::memory_store::{self, SearchFilters, SearchHit};
 use std::pin::Pin;
 use std::sync::Arc;
 use std::time::Instant;
-use thunderus_core::memory::{MemoryKind, RetrievalPolicy, RetrievalResult, RetrievedChunk, STOP_WORDS};
+use thunderus_core::memory::{
+    MemoryKind, MemoryPaths, PinnedMemory, RetrievalPolicy, RetrievalResult, RetrievedChunk, STOP_WORDS,
+};
 use thunderus_core::memory::{MemoryRetriever, RetrievalError};
 
 /// Calculate cosine similarity between two vectors
@@ -32,12 +34,23 @@ fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
 pub struct StoreRetriever {
     store: Arc<memory_store::MemoryStore>,
     policy: RetrievalPolicy,
+    /// Memory paths used to read the persisted pin list and repository HEAD on each query
+    pin_paths: Option<MemoryPaths>,
 }
 
 impl StoreRetriever {
     /// Create a new store retriever with the given store and policy
     pub fn new(store: Arc<memory_store::MemoryStore>, policy: RetrievalPolicy) -> Self {
-        Self { store, policy }
+        Self { store, policy, pin_paths: None }
+    }
+
+    /// Read pinned documents from the persisted pin list under `paths`
+    ///
+    /// The pin list is re-read on every query so pins made mid-session take effect immediately.
+    /// The repository HEAD is read alongside it so verification staleness is judged per query.
+    pub fn with_pinned_memory(mut self, paths: MemoryPaths) -> Self {
+        self.pin_paths = Some(paths);
+        self
     }
 
     /// Create a new store retriever with default policy
@@ -50,6 +63,88 @@ impl StoreRetriever {
         &self.policy
     }
 
+    /// Collect pinned document IDs from the policy and the persisted pin list
+    fn pinned_ids(&self) -> Vec<String> {
+        let mut ids = self.policy.pinned_ids.clone();
+
+        if let Some(paths) = &self.pin_paths {
+            match PinnedMemory::load(paths) {
+                Ok(pins) => {
+                    for id in pins.ids {
+                        if !ids.contains(&id) {
+                            ids.push(id);
+                        }
+                    }
+                }
+                Err(e) => tracing::warn!("Failed to load pinned memory: {}", e),
+            }
+        }
+
+        ids
+    }
+
+    /// Fetch pinned documents regardless of query score
+    ///
+    /// Pinned chunks are capped by `max_pinned_tokens` so pinning cannot
+    /// crowd out query results entirely. A document too large for the room
+    /// left is skipped, so smaller pins after it still make it in. Each pin
+    /// injects its whole document, so it is costed by that content rather
+    /// than the stored token count, which may be stale.
+    async fn fetch_pinned(&self) -> Vec<RetrievedChunk> {
+        let mut chunks = Vec::new();
+        let mut token_count = 0;
+
+        for id in self.pinned_ids() {
+            let (content, meta) = match self.store.get_by_id(&id).await {
+                Ok(Some(doc)) => doc,
+                Ok(None) => continue,
+                Err(e) => {
+                    tracing::warn!("Failed to fetch pinned memory {}: {}", id, e);
+                    continue;
+                }
+            };
+
+            let injected = content.len() / 4;
+            let chunk = RetrievedChunk {
+                content,
+                path: meta.path,
+                anchor: None,
+                event_ids: meta.event_ids,
+                kind: meta.kind,
+                score: 0.0,
+                token_count: injected,
+            };
+
+            let cost = self.policy.chunk_cost(&chunk);
+            if token_count + cost > self.policy.max_pinned_tokens {
+                tracing::debug!("Pinned memory {} does not fit the pinned token budget", id);
+                continue;
+            }
+            token_count += cost;
+            chunks.push(chunk);
+        }
+
+        chunks
+    }
+
+    /// Append a chunk if it fits within `budget`, returning whether selection should continue
+    ///
+    /// With a `token_budget` set, chunks that overflow are skipped so smaller,
+    /// lower-ranked chunks can still fill the remaining room. Without one,
+    /// selection stops at the first chunk that overflows.
+    fn admit(
+        &self, chunk: RetrievedChunk, selected: &mut Vec<RetrievedChunk>, used: &mut usize, budget: usize,
+    ) -> bool {
+        let cost = self.policy.chunk_cost(&chunk);
+        if *used + cost > budget {
+            return self.policy.token_budget.is_some();
+        }
+
+        *used += cost;
+        selected.push(chunk);
+        true
+    }
+
     /// Extract searchable query terms from natural language task intent
     fn extract_query_terms(&self, intent: &str) -> String {
         intent
@@ -61,30 +156,39 @@ impl StoreRetriever {
     }
 
     /// Convert search hits to retrieved chunks with budget filtering
-    fn filter_and_budget(&self, hits: Vec<SearchHit>) -> Vec<RetrievedChunk> {
+    ///
+    /// The score threshold applies to the raw relevance score; the
+    /// verification penalty only changes the order hits are selected in.
+    fn filter_and_budget(&self, hits: Vec<SearchHit>, head: Option<&str>) -> Vec<RetrievedChunk> {
         let mut chunks = Vec::new();
         let mut token_count = 0;
 
-        for hit in hits {
-            if hit.score > self.policy.score_threshold {
-                continue;
-            }
+        let mut hits: Vec<(f64, SearchHit)> = hits
+            .into_iter()
+            .filter(|hit| hit.score <= self.policy.score_threshold)
+            .map(|mut hit| {
+                hit.refresh_verification(head);
+                (self.policy.adjusted_score(hit.score, hit.verification), hit)
+            })
+            .collect();
+        hits.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
 
-            let chunk_tokens = hit.snippet.len() / 4;
-            if token_count + chunk_tokens > self.policy.max_tokens {
-                break;
-            }
+        for (_, hit) in hits {
+            let score = hit.score;
 
-            chunks.push(RetrievedChunk {
+            let chunk = RetrievedChunk {
                 content: hit.snippet,
                 path: hit.path,
                 anchor: hit.anchor,
                 event_ids: hit.event_ids,
                 kind: hit.kind,
-                score: hit.score,
-            });
+                score,
+                token_count: hit.token_count,
+            };
 
-            token_count += chunk_tokens;
+            if !self.admit(chunk, &mut chunks, &mut token_count, self.policy.effective_budget()) {
+                break;
+            }
 
             if chunks.len() >= self.policy.max_chunks {
                 break;
@@ -139,6 +243,7 @@ impl StoreRetriever {
                 event_ids: hit.event_ids,
                 kind: hit.kind,
                 score: similarity as f64,
+                token_count: hit.token_count,
             })
             .collect()
     }
@@ -165,6 +270,7 @@ impl StoreRetriever {
                     event_ids: hit.event_ids,
                     kind: hit.kind,
                     score: hit.score,
+                    token_count: hit.token_count,
                 })
                 .collect(),
             Err(_) => Vec::new(),
@@ -184,19 +290,28 @@ impl MemoryRetriever for StoreRetriever {
             let start = Instant::now();
             let query = self.extract_query_terms(task_intent);
 
-            let mut chunks = Vec::new();
+            let budget = self.policy.effective_budget();
+            let mut chunks: Vec<RetrievedChunk> = Vec::new();
             let mut token_count = 0;
 
+            for chunk in self.fetch_pinned().await {
+                let cost = self.policy.chunk_cost(&chunk);
+                if token_count + cost <= budget {
+                    token_count += cost;
+                    chunks.push(chunk);
+                }
+            }
+
             for kind in &self.policy.always_include {
                 let always_chunks = self.fetch_by_kind(*kind).await;
 
                 for chunk in always_chunks {
-                    let chunk_tokens = chunk.content.len() / 4;
-                    if token_count + chunk_tokens > self.policy.max_tokens {
+                    if chunks.iter().any(|c| c.path == chunk.path) {
+                        continue;
+                    }
+                    if !self.admit(chunk, &mut chunks, &mut token_count, budget) {
                         break;
                     }
-                    token_count += chunk_tokens;
-                    chunks.push(chunk);
                 }
             }
 
@@ -208,7 +323,8 @@ impl MemoryRetriever for StoreRetriever {
                 .await
                 .map_err(|e| RetrievalError::Store(e.to_string()))?;
 
-            let mut search_chunks = self.filter_and_budget(hits.clone());
+            let head = self.pin_paths.as_ref().and_then(MemoryPaths::head_commit);
+            let mut search_chunks = self.filter_and_budget(hits.clone(), head.as_deref());
 
             if self.policy.enable_vector_fallback && self.should_use_vector_fallback(&search_chunks) {
                 let vector_chunks = self.vector_search_fallback(task_intent).await;
@@ -225,17 +341,18 @@ impl MemoryRetriever for StoreRetriever {
             }
 
             for chunk in search_chunks {
-                let chunk_tokens = chunk.content.len() / 4;
-                if token_count + chunk_tokens > self.policy.max_tokens {
+                if chunks.iter().any(|c| c.path == chunk.path && c.anchor == chunk.anchor) {
+                    continue;
+                }
+                if !self.admit(chunk, &mut chunks, &mut token_count, budget) {
                     break;
                 }
-                token_count += chunk_tokens;
-                chunks.push(chunk);
             }
 
             Ok(RetrievalResult {
                 chunks,
                 total_tokens: token_count,
+                token_budget: budget,
                 query,
                 search_time_ms: start.elapsed().as_millis() as u64,
             })
@@ -253,7 +370,25 @@ mod tests {
     use crate::memory_store::{MemoryMeta, MemoryStore};
     use chrono::Utc;
     use tempfile::TempDir;
-    use thunderus_core::memory::MemoryKind;
+    use thunderus_core::memory::{MemoryKind, VerificationState};
+
+    /// Fact metadata stored at `semantic/FACTS/<id>.md`, titled with its id
+    fn fact_meta(id: &str, token_count: usize) -> MemoryMeta {
+        MemoryMeta {
+            id: id.to_string(),
+            kind: MemoryKind::Fact,
+            title: id.to_string(),
+            tags: vec![],
+            headings: vec![],
+            path: format!("semantic/FACTS/{}.md", id),
+            updated: Utc::now(),
+            event_ids: vec![],
+            patch_ids: vec![],
+            token_count,
+            verification: None,
+            verified_commit: None,
+        }
+    }
 
     #[tokio::test]
     async fn test_store_retriever_impl_with_defaults() {
@@ -262,16 +397,11 @@ mod tests {
         let store = MemoryStore::open(&db_path).await.unwrap();
 
         let meta = MemoryMeta {
-            id: "test-1".to_string(),
-            kind: MemoryKind::Fact,
             title: "Test Fact About Coverage".to_string(),
             tags: vec!["test".to_string()],
-            headings: vec![],
             path: "semantic/FACTS/test.md".to_string(),
-            updated: Utc::now(),
             event_ids: vec!["evt-1".to_string(), "evt-2".to_string()],
-            patch_ids: vec![],
-            token_count: 100,
+            ..fact_meta("test-1", 100)
         };
 
         store
@@ -309,16 +439,13 @@ mod tests {
         let store = MemoryStore::open(&db_path).await.unwrap();
 
         let meta = MemoryMeta {
-            id: "test-events".to_string(),
             kind: MemoryKind::Core,
             title: "Core with Events".to_string(),
-            tags: vec![],
             headings: vec!["commands".to_string()],
             path: "core/CORE.md".to_string(),
-            updated: Utc::now(),
             event_ids: vec!["evt-abc".to_string(), "evt-def".to_string()],
             patch_ids: vec!["patch-123".to_string()],
-            token_count: 50,
+            ..fact_meta("test-events", 50)
         };
 
         store
@@ -346,16 +473,9 @@ mod tests {
 
         for i in 0..5 {
             let meta = MemoryMeta {
-                id: format!("test-{}", i),
-                kind: MemoryKind::Fact,
                 title: format!("Test Fact {}", i),
-                tags: vec![],
-                headings: vec![],
                 path: format!("semantic/FACTS/test{}.md", i),
-                updated: Utc::now(),
-                event_ids: vec![],
-                patch_ids: vec![],
-                token_count: 100,
+                ..fact_meta(&format!("test-{}", i), 100)
             };
 
             store
@@ -379,6 +499,180 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_store_retriever_includes_pinned_docs() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let store = MemoryStore::open(&db_path).await.unwrap();
+
+        for (id, content) in [
+            ("fact-pinned", "Release checklist lives in docs"),
+            ("fact-other", "Coverage target"),
+        ] {
+            store
+                .put("semantic/facts", &format!("{}.md", id), content, fact_meta(id, 10))
+                .await
+                .unwrap();
+        }
+
+        let paths = MemoryPaths::from_thunderus_root(temp_dir.path());
+        let mut pins = PinnedMemory::default();
+        pins.pin("fact-pinned").unwrap();
+        pins.save(&paths).unwrap();
+
+        let policy = RetrievalPolicy { always_include: vec![], ..Default::default() };
+        let retriever = StoreRetriever::new(std::sync::Arc::new(store), policy).with_pinned_memory(paths);
+        let result = retriever.query("coverage").await.unwrap();
+
+        assert_eq!(result.chunks[0].path, "semantic/FACTS/fact-pinned.md");
+        assert_eq!(
+            result
+                .chunks
+                .iter()
+                .filter(|c| c.path == "semantic/FACTS/fact-pinned.md")
+                .count(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_store_retriever_pinned_token_cap() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let store = MemoryStore::open(&db_path).await.unwrap();
+
+        let meta = MemoryMeta {
+            title: "Large".to_string(),
+            path: "semantic/FACTS/large.md".to_string(),
+            ..fact_meta("fact-large", 500)
+        };
+        store
+            .put("semantic/facts", "large.md", &"word ".repeat(400), meta)
+            .await
+            .unwrap();
+
+        let policy = RetrievalPolicy {
+            always_include: vec![],
+            pinned_ids: vec!["fact-large".to_string()],
+            max_pinned_tokens: 100,
+            ..Default::default()
+        };
+        let retriever = StoreRetriever::new(std::sync::Arc::new(store), policy);
+        let result = retriever.query("unrelated").await.unwrap();
+
+        assert!(result.chunks.iter().all(|c| c.path != "semantic/FACTS/large.md"));
+    }
+
+    #[tokio::test]
+    async fn test_store_retriever_pinned_skips_oversized_docs() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let store = MemoryStore::open(&db_path).await.unwrap();
+
+        for (id, content) in [("large", "word ".repeat(400)), ("small", "word ".repeat(40))] {
+            let meta = MemoryMeta {
+                title: id.to_string(),
+                path: format!("semantic/FACTS/{}.md", id),
+                ..fact_meta(&format!("fact-{}", id), 1)
+            };
+            store
+                .put("semantic/facts", &format!("{}.md", id), &content, meta)
+                .await
+                .unwrap();
+        }
+
+        let policy = RetrievalPolicy {
+            always_include: vec![],
+            pinned_ids: vec!["fact-large".to_string(), "fact-small".to_string()],
+            max_pinned_tokens: 100,
+            token_budget: Some(1000),
+            ..Default::default()
+        };
+        let retriever = StoreRetriever::new(std::sync::Arc::new(store), policy);
+        let result = retriever.query("unrelated").await.unwrap();
+
+        assert_eq!(result.chunks.len(), 1);
+        assert_eq!(result.chunks[0].path, "semantic/FACTS/small.md");
+        assert_eq!(result.total_tokens, 50);
+    }
+
+    #[tokio::test]
+    async fn test_store_retriever_greedy_token_budget() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let store = MemoryStore::open(&db_path).await.unwrap();
+
+        for (id, tokens) in [("large", 300), ("small-a", 50), ("small-b", 50)] {
+            let meta = MemoryMeta { title: format!("Deploy {}", id), ..fact_meta(id, tokens) };
+            let content = if id == "large" { "deploy deploy deploy pipeline" } else { "deploy pipeline" };
+            store
+                .put("semantic/facts", &format!("{}.md", id), content, meta)
+                .await
+                .unwrap();
+        }
+
+        let policy = RetrievalPolicy {
+            always_include: vec![],
+            score_threshold: 100.0,
+            token_budget: Some(120),
+            ..Default::default()
+        };
+        let retriever = StoreRetriever::new(std::sync::Arc::new(store), policy);
+        let result = retriever.query("deploy pipeline").await.unwrap();
+
+        assert_eq!(result.chunks.len(), 2);
+        assert!(result.chunks.iter().all(|c| c.token_count == 50));
+        assert_eq!(result.total_tokens, 100);
+        assert_eq!(result.token_budget, 120);
+        assert_eq!(result.remaining_budget(), 20);
+    }
+
+    #[tokio::test]
+    async fn test_store_retriever_down_ranks_never_verified() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let store = MemoryStore::open(&db_path).await.unwrap();
+
+        for (id, verification) in [
+            ("unverified", VerificationState::NeverVerified),
+            ("verified", VerificationState::Verified),
+        ] {
+            let meta = MemoryMeta {
+                title: "Release process".to_string(),
+                verification: Some(verification),
+                ..fact_meta(id, 10)
+            };
+            store
+                .put(
+                    "semantic/facts",
+                    &format!("{}.md", id),
+                    "release release checklist",
+                    meta,
+                )
+                .await
+                .unwrap();
+        }
+
+        let hits = store.search("release", SearchFilters::default()).await.unwrap();
+        let unverified = hits.iter().find(|h| h.id == "unverified").unwrap();
+        assert_eq!(unverified.verification, Some(VerificationState::NeverVerified));
+
+        let policy = RetrievalPolicy {
+            always_include: vec![],
+            score_threshold: 0.0,
+            never_verified_penalty: 10.0,
+            ..Default::default()
+        };
+        let retriever = StoreRetriever::new(std::sync::Arc::new(store), policy);
+        let result = retriever.query("release checklist").await.unwrap();
+
+        let paths: Vec<&str> = result.chunks.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec!["semantic/FACTS/verified.md", "semantic/FACTS/unverified.md"]
+        );
+    }
+
     #[tokio::test]
     async fn test_store_retriever_impl_policy() {
         let temp_dir = TempDir::new().unwrap();
@@ -390,6 +684,10 @@ mod tests {
             score_threshold: -2.0,
             always_include: vec![],
             enable_vector_fallback: true,
+            pinned_ids: vec![],
+            max_pinned_tokens: 500,
+            token_budget: None,
+            never_verified_penalty: 0.0,
         };
 
         let retriever = StoreRetriever::new(std::sync::Arc::new(store), policy.clone());
@@ -13,6 +13,9 @@ use thunderus_core::Result;
 
 use thunderus_core::Error;
 
+/// Backups kept per file when a tool does not configure its own cap
+pub const DEFAULT_MAX_BACKUPS: usize = 5;
+
 /// Configuration for backup behavior
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BackupMode {
@@ -159,7 +162,7 @@ impl BackupManager {
             }
 
             let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
-            let original_name = file_name.rsplit('.').nth(2).unwrap_or(file_name);
+            let original_name = original_name(file_name);
             backups_by_file.entry(original_name.to_string()).or_default().push(path);
         }
 
@@ -211,7 +214,7 @@ impl BackupManager {
                 .map(chrono::DateTime::<chrono::Utc>::from)
                 .unwrap_or_else(chrono::Utc::now);
 
-            let original_name = file_name.rsplit('.').nth(2).unwrap_or(file_name);
+            let original_name = original_name(file_name);
 
             backups.push(BackupMetadata {
                 original_path: PathBuf::from(original_name),
@@ -226,6 +229,14 @@ impl BackupManager {
     }
 }
 
+/// Recovers the original file name from a `<name>.<timestamp>.bak` backup name
+fn original_name(backup_name: &str) -> &str {
+    backup_name
+        .strip_suffix(".bak")
+        .and_then(|name| name.rsplit_once('.'))
+        .map_or(backup_name, |(name, _)| name)
+}
+
 /// Helper to determine if a shell command requires a backup
 pub fn command_requires_backup(command: &str) -> bool {
     let command_lower = command.to_lowercase();
@@ -416,6 +427,31 @@ mod tests {
         assert!(backups.is_empty());
     }
 
+    #[test]
+    fn test_cleanup_groups_by_original_name() {
+        let (temp, manager) = create_test_manager();
+        let a = temp.path().join("a.rs");
+        let b = temp.path().join("b.rs");
+        std::fs::write(&a, "a").unwrap();
+        std::fs::write(&b, "b").unwrap();
+
+        for _ in 0..5 {
+            manager.create_backup(&a, "edit").unwrap();
+            std::thread::sleep(std::time::Duration::from_millis(2));
+        }
+        manager.create_backup(&b, "edit").unwrap();
+
+        manager.cleanup_old_backups().unwrap();
+
+        let backups = manager.list_backups().unwrap();
+        assert_eq!(backups.len(), 6);
+        assert_eq!(
+            backups.iter().filter(|b| b.original_path == Path::new("b.rs")).count(),
+            1
+        );
+        assert_eq!(original_name("a.rs.20260101_120000_123.bak"), "a.rs");
+    }
+
     #[test]
     fn test_max_backups_zero_unlimited() {
         let temp = TempDir::new().unwrap();
@@ -23,6 +23,7 @@ impl Tool for EchoTool {
             thunderus_providers::ToolParameter::new_string("The message to echo back")
                 .with_description("Any string value"),
         )])
+        .with_required(&["message"])
     }
 
     fn risk_level(&self) -> thunderus_core::ToolRisk {
@@ -3,16 +3,42 @@ use std::path::{Path, PathBuf};
 use thunderus_core::{Result, ToolRisk};
 use thunderus_providers::{ToolParameter, ToolResult};
 
-use crate::Tool;
+use crate::patch_generator;
+use crate::read_history::{self, ReadHistory};
+use crate::result_formatting::counted;
+use crate::{FormattedResult, Tool};
 
 /// A tool that performs safe find-replace edits in files
 ///
 /// This tool provides atomic, exact string replacement in files with safety
 /// validations including uniqueness checks and read-before-edit enforcement.
-#[derive(Debug)]
-pub struct EditTool;
+#[derive(Debug, Default)]
+pub struct EditTool {
+    /// Reads made this session; when set, only lines already read may be edited
+    read_history: Option<ReadHistory>,
+}
 
 impl EditTool {
+    /// Creates an edit tool that rejects edits to lines not yet read
+    pub fn with_read_history(read_history: ReadHistory) -> Self {
+        Self { read_history: Some(read_history) }
+    }
+
+    /// Validates that the lines holding the replaced occurrences have been read
+    fn validate_read(&self, file_path: &str, content: &str, old_string: &str, replace_all: bool) -> Result<()> {
+        let Some(history) = &self.read_history else {
+            return Ok(());
+        };
+
+        read_history::validate_read_before_edit(history, file_path).map_err(thunderus_core::Error::Validation)?;
+        let occurrences = if replace_all { usize::MAX } else { 1 };
+        for lines in read_history::occurrence_lines(content, old_string).take(occurrences) {
+            read_history::validate_read_before_edit_lines(history, file_path, lines)
+                .map_err(thunderus_core::Error::Validation)?;
+        }
+        Ok(())
+    }
+
     /// Validates that the path exists and is a file
     fn validate_path(path: &Path) -> Result<()> {
         if !path.exists() {
@@ -54,27 +80,43 @@ impl EditTool {
         Ok(())
     }
 
-    /// Reads the file and performs the replacement
-    fn perform_edit(path: &Path, old_string: &str, new_string: &str, replace_all: bool) -> Result<String> {
-        let content = std::fs::read_to_string(path)
-            .map_err(|e| thunderus_core::Error::Tool(format!("Failed to read file '{}': {}", path.display(), e)))?;
-
-        Self::validate_uniqueness(&content, old_string, replace_all)?;
+    /// Applies the replacement to `content`, returning the new content and replacement count
+    fn edited_content(content: &str, old_string: &str, new_string: &str, replace_all: bool) -> Result<(String, usize)> {
+        Self::validate_uniqueness(content, old_string, replace_all)?;
 
+        let replacements = if replace_all { content.matches(old_string).count() } else { 1 };
         let new_content = if replace_all {
             content.replacen(old_string, new_string, usize::MAX)
         } else {
             content.replacen(old_string, new_string, 1)
         };
+        Ok((new_content, replacements))
+    }
+
+    /// Reads the file and performs the replacement
+    fn perform_edit(
+        &self, file_path: &str, old_string: &str, new_string: &str, replace_all: bool,
+    ) -> Result<FormattedResult> {
+        let path = Path::new(file_path);
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| thunderus_core::Error::Tool(format!("Failed to read file '{}': {}", path.display(), e)))?;
+
+        self.validate_read(file_path, &content, old_string, replace_all)?;
+
+        let (new_content, replacements) = Self::edited_content(&content, old_string, new_string, replace_all)?;
 
         std::fs::write(path, new_content)
             .map_err(|e| thunderus_core::Error::Tool(format!("Failed to write file '{}': {}", path.display(), e)))?;
 
-        Ok(format!(
-            "Successfully edited file: {}\nReplaced '{}' with '{}'",
-            path.display(),
-            old_string,
-            new_string
+        Ok(FormattedResult::success(
+            "edit",
+            counted(replacements, "replacement", "replacements"),
+            format!(
+                "Successfully edited file: {}\nReplaced '{}' with '{}'",
+                path.display(),
+                old_string,
+                new_string
+            ),
         ))
     }
 }
@@ -111,6 +153,7 @@ impl Tool for EditTool {
                     .with_description("If true, replace all occurrences of old_string. If false (default), old_string must be unique in the file"),
             ),
         ])
+        .with_required(&["file_path", "old_string", "new_string"])
     }
 
     fn risk_level(&self) -> ToolRisk {
@@ -124,6 +167,17 @@ impl Tool for EditTool {
         ))
     }
 
+    fn preview_diff(&self, arguments: &Value) -> Option<String> {
+        let path = PathBuf::from(arguments.get("file_path")?.as_str()?);
+        let old_string = arguments.get("old_string")?.as_str()?;
+        let new_string = arguments.get("new_string")?.as_str()?;
+        let replace_all = arguments.get("replace_all").and_then(|v| v.as_bool()).unwrap_or(false);
+
+        let content = std::fs::read_to_string(&path).ok()?;
+        let (new_content, _) = Self::edited_content(&content, old_string, new_string, replace_all).ok()?;
+        patch_generator::generate_unified_diff(&path, &content, &new_content, "HEAD").ok()
+    }
+
     fn execute(&self, tool_call_id: String, arguments: &Value) -> Result<ToolResult> {
         let file_path_str = arguments
             .get("file_path")
@@ -149,8 +203,10 @@ impl Tool for EditTool {
 
         Self::validate_path(&path)?;
 
-        let result = Self::perform_edit(&path, old_string, new_string, replace_all)?;
-        Ok(ToolResult::success(tool_call_id, result))
+        let result = self
+            .perform_edit(file_path_str, old_string, new_string, replace_all)?
+            .with_subject(file_path_str);
+        Ok(ToolResult::success(tool_call_id, result.to_text()))
     }
 }
 
@@ -161,7 +217,7 @@ mod tests {
 
     #[test]
     fn test_edit_tool_properties() {
-        let tool = EditTool;
+        let tool = EditTool::default();
         assert_eq!(tool.name(), "edit");
         assert_eq!(tool.risk_level(), ToolRisk::Risky);
         assert!(tool.risk_level().is_risky());
@@ -169,7 +225,7 @@ mod tests {
 
     #[test]
     fn test_edit_execute_missing_file_path() {
-        let tool = EditTool;
+        let tool = EditTool::default();
         let args = serde_json::json!({"old_string": "foo", "new_string": "bar"});
         let result = tool.execute("call_edit_1".to_string(), &args);
 
@@ -180,7 +236,7 @@ mod tests {
 
     #[test]
     fn test_edit_execute_empty_file_path() {
-        let tool = EditTool;
+        let tool = EditTool::default();
         let args = serde_json::json!({"file_path": "", "old_string": "foo", "new_string": "bar"});
         let result = tool.execute("call_edit_2".to_string(), &args);
 
@@ -191,7 +247,7 @@ mod tests {
 
     #[test]
     fn test_edit_execute_missing_old_string() {
-        let tool = EditTool;
+        let tool = EditTool::default();
         let args = serde_json::json!({"file_path": "/tmp/test.txt", "new_string": "bar"});
         let result = tool.execute("call_edit_3".to_string(), &args);
 
@@ -202,7 +258,7 @@ mod tests {
 
     #[test]
     fn test_edit_execute_missing_new_string() {
-        let tool = EditTool;
+        let tool = EditTool::default();
         let args = serde_json::json!({"file_path": "/tmp/test.txt", "old_string": "foo"});
         let result = tool.execute("call_edit_4".to_string(), &args);
 
@@ -213,7 +269,7 @@ mod tests {
 
     #[test]
     fn test_edit_execute_nonexistent_file() {
-        let tool = EditTool;
+        let tool = EditTool::default();
         let args = serde_json::json!({"file_path": "/tmp/nonexistent_file_12345.txt", "old_string": "foo", "new_string": "bar"});
         let result = tool.execute("call_edit_5".to_string(), &args);
 
@@ -224,7 +280,7 @@ mod tests {
 
     #[test]
     fn test_edit_execute_old_string_not_found() {
-        let tool = EditTool;
+        let tool = EditTool::default();
 
         let temp_dir = std::env::temp_dir();
         let temp_file = temp_dir.join("test_edit_not_found.txt");
@@ -246,7 +302,7 @@ mod tests {
 
     #[test]
     fn test_edit_execute_old_string_not_unique() {
-        let tool = EditTool;
+        let tool = EditTool::default();
 
         let temp_dir = std::env::temp_dir();
         let temp_file = temp_dir.join("test_edit_not_unique.txt");
@@ -269,7 +325,7 @@ mod tests {
 
     #[test]
     fn test_edit_execute_success() {
-        let tool = EditTool;
+        let tool = EditTool::default();
 
         let temp_dir = std::env::temp_dir();
         let temp_file = temp_dir.join("test_edit_success.txt");
@@ -292,9 +348,39 @@ mod tests {
         assert_eq!(content, "Hello Rust\n");
     }
 
+    #[test]
+    fn test_edit_preview_diff_leaves_file_untouched() {
+        let tool = EditTool::default();
+
+        let temp_dir = std::env::temp_dir();
+        let temp_file = temp_dir.join("test_edit_preview_diff.txt");
+        writeln!(std::fs::File::create(&temp_file).unwrap(), "Hello world").unwrap();
+
+        let args = serde_json::json!({
+            "file_path": temp_file.to_string_lossy().as_ref(),
+            "old_string": "world",
+            "new_string": "Rust"
+        });
+        let diff = tool.preview_diff(&args);
+        let missing = tool.preview_diff(&serde_json::json!({
+            "file_path": temp_file.to_string_lossy().as_ref(),
+            "old_string": "absent",
+            "new_string": "Rust"
+        }));
+
+        let content = std::fs::read_to_string(&temp_file).unwrap();
+        let _ = std::fs::remove_file(&temp_file);
+
+        let diff = diff.expect("edit should produce a diff");
+        assert!(diff.contains("-Hello world"));
+        assert!(diff.contains("+Hello Rust"));
+        assert!(missing.is_none());
+        assert_eq!(content, "Hello world\n");
+    }
+
     #[test]
     fn test_edit_execute_replace_all() {
-        let tool = EditTool;
+        let tool = EditTool::default();
 
         let temp_dir = std::env::temp_dir();
         let temp_file = temp_dir.join("test_edit_replace_all.txt");
@@ -319,7 +405,7 @@ mod tests {
 
     #[test]
     fn test_edit_execute_with_multiline_strings() {
-        let tool = EditTool;
+        let tool = EditTool::default();
 
         let temp_dir = std::env::temp_dir();
         let temp_file = temp_dir.join("test_edit_multiline.txt");
@@ -342,4 +428,32 @@ mod tests {
         assert!(content.contains("NewLine"));
         assert!(!content.contains("OldLine"));
     }
+
+    #[test]
+    fn test_edit_execute_rejects_lines_outside_read_range() {
+        let history = ReadHistory::new();
+        let tool = EditTool::with_read_history(history.clone());
+
+        let temp_dir = std::env::temp_dir();
+        let temp_file = temp_dir.join("test_edit_read_range.txt");
+        std::fs::write(&temp_file, "one\ntwo\nthree\nfour\n").unwrap();
+        let path = temp_file.to_string_lossy().to_string();
+
+        let args = serde_json::json!({"file_path": path, "old_string": "four", "new_string": "4"});
+        let unread = tool.execute("call_edit_read_1".to_string(), &args);
+
+        history.record_read(&path, 2, 0);
+        let outside = tool.execute("call_edit_read_2".to_string(), &args);
+
+        let inside_args = serde_json::json!({"file_path": path, "old_string": "two", "new_string": "2"});
+        let inside = tool.execute("call_edit_read_3".to_string(), &inside_args);
+
+        let content = std::fs::read_to_string(&temp_file).unwrap();
+        let _ = std::fs::remove_file(&temp_file);
+
+        assert!(unread.unwrap_err().to_string().contains("must be read before editing"));
+        assert!(outside.unwrap_err().to_string().contains("Cannot edit lines 4-4"));
+        assert!(inside.unwrap().is_success());
+        assert_eq!(content, "one\n2\nthree\nfour\n");
+    }
 }
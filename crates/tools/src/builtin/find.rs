@@ -0,0 +1,628 @@
+use chrono::{DateTime, Local};
+use ignore::WalkBuilder;
+use serde_json::Value;
+use std::cmp::Reverse;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use thunderus_core::{Result, ToolRisk};
+use thunderus_providers::{ToolParameter, ToolResult};
+
+use crate::result_formatting::counted;
+use crate::{FormattedResult, Tool};
+
+/// Maximum entries returned when the caller does not pass `limit`
+const DEFAULT_LIMIT: usize = 100;
+
+/// File type filter for the Find tool
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FindFileType {
+    /// Regular files
+    File,
+    /// Directories
+    Dir,
+    /// Symbolic links (not followed)
+    Symlink,
+}
+
+impl FindFileType {
+    pub fn parse_str(s: &str) -> Option<Self> {
+        match s {
+            "file" | "f" => Some(Self::File),
+            "dir" | "directory" | "d" => Some(Self::Dir),
+            "symlink" | "link" | "l" => Some(Self::Symlink),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::File => "file",
+            Self::Dir => "dir",
+            Self::Symlink => "symlink",
+        }
+    }
+}
+
+/// Sort order for find results
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FindSortOrder {
+    /// Sort by modification time (newest first)
+    ModifiedTime,
+    /// Sort by size (largest first)
+    Size,
+    /// Sort by path (alphabetical)
+    Path,
+    /// No sorting (walk order)
+    None,
+}
+
+impl FindSortOrder {
+    pub fn parse_str(s: &str) -> Option<Self> {
+        match s {
+            "modified" | "time" | "mtime" => Some(Self::ModifiedTime),
+            "size" => Some(Self::Size),
+            "path" | "name" | "alpha" => Some(Self::Path),
+            "none" | "unsorted" => Some(Self::None),
+            _ => None,
+        }
+    }
+}
+
+/// Options for the Find tool
+pub struct FindOptions<'a> {
+    pub path: &'a Path,
+    pub name: Option<glob::Pattern>,
+    pub file_type: Option<FindFileType>,
+    pub modified_within: Option<Duration>,
+    pub modified_before: Option<Duration>,
+    pub min_size: Option<u64>,
+    pub max_size: Option<u64>,
+    pub max_depth: Option<usize>,
+    pub sort_order: FindSortOrder,
+    pub respect_gitignore: bool,
+    pub limit: Option<usize>,
+}
+
+/// A single matching filesystem entry
+#[derive(Debug, Clone)]
+struct FindEntry {
+    path: PathBuf,
+    file_type: FindFileType,
+    size: u64,
+    modified: SystemTime,
+}
+
+/// A tool that finds files by name, type, size, and modification time
+///
+/// Complements [`super::GlobTool`] for searches that depend on file metadata
+/// rather than path shape, e.g. "files modified in the last day" or "files
+/// larger than 1MB". Uses the same .gitignore-aware walk as Glob.
+#[derive(Debug)]
+pub struct FindTool;
+
+impl FindTool {
+    /// Validates that the path exists and is accessible
+    fn validate_path(path: &Path) -> Result<()> {
+        if !path.exists() {
+            return Err(thunderus_core::Error::Validation(format!(
+                "Path does not exist: {}",
+                path.display()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Parses a duration like `30s`, `15m`, `2h`, `1d` or `1w`
+    ///
+    /// A bare number is treated as seconds.
+    pub fn parse_duration(s: &str) -> Option<Duration> {
+        let s = s.trim();
+        let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let (num, unit) = s.split_at(split);
+        let value: u64 = num.parse().ok()?;
+        let multiplier: u64 = match unit.trim() {
+            "" | "s" | "sec" | "secs" => 1,
+            "m" | "min" | "mins" => 60,
+            "h" | "hr" | "hrs" | "hour" | "hours" => 60 * 60,
+            "d" | "day" | "days" => 60 * 60 * 24,
+            "w" | "week" | "weeks" => 60 * 60 * 24 * 7,
+            _ => return None,
+        };
+        value.checked_mul(multiplier).map(Duration::from_secs)
+    }
+
+    /// Parses a size like `512`, `10K`, `1MB` or `2G` into bytes
+    ///
+    /// Units are binary (1K = 1024 bytes).
+    pub fn parse_size(s: &str) -> Option<u64> {
+        let s = s.trim();
+        let split = s.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(s.len());
+        let (num, unit) = s.split_at(split);
+        let value: f64 = num.parse().ok()?;
+        let multiplier: u64 = match unit.trim().to_ascii_lowercase().as_str() {
+            "" | "b" => 1,
+            "k" | "kb" | "kib" => 1024,
+            "m" | "mb" | "mib" => 1024 * 1024,
+            "g" | "gb" | "gib" => 1024 * 1024 * 1024,
+            _ => return None,
+        };
+        Some((value * multiplier as f64) as u64)
+    }
+
+    /// Formats a byte count for display
+    fn format_size(bytes: u64) -> String {
+        const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+        let mut size = bytes as f64;
+        let mut unit = 0;
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+        if unit == 0 { format!("{} B", bytes) } else { format!("{:.1} {}", size, UNITS[unit]) }
+    }
+
+    /// Returns true if the entry passes every filter in `options`
+    fn matches(entry: &FindEntry, options: &FindOptions, now: SystemTime) -> bool {
+        if let Some(file_type) = options.file_type
+            && entry.file_type != file_type
+        {
+            return false;
+        }
+
+        if let Some(ref pattern) = options.name {
+            let name = entry.path.file_name().map(|n| n.to_string_lossy()).unwrap_or_default();
+            if !pattern.matches(&name) {
+                return false;
+            }
+        }
+
+        if options.min_size.is_some_and(|min| entry.size < min) || options.max_size.is_some_and(|max| entry.size > max)
+        {
+            return false;
+        }
+
+        let age = now.duration_since(entry.modified).unwrap_or(Duration::ZERO);
+        if options.modified_within.is_some_and(|within| age > within)
+            || options.modified_before.is_some_and(|before| age < before)
+        {
+            return false;
+        }
+
+        true
+    }
+
+    /// Walks the tree and returns matching entries
+    fn execute_and_parse(options: &FindOptions) -> Result<FormattedResult> {
+        Self::validate_path(options.path)?;
+
+        let mut builder = if options.respect_gitignore {
+            thunderus_core::workspace_walker(options.path)
+        } else {
+            let mut builder = WalkBuilder::new(options.path);
+            builder.standard_filters(false);
+            builder
+        };
+        builder.max_depth(options.max_depth);
+
+        let now = SystemTime::now();
+        let mut results: Vec<FindEntry> = Vec::new();
+        let mut unreadable = 0;
+
+        for entry in builder.build() {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(e) => {
+                    tracing::warn!("find: skipping unreadable path: {}", e);
+                    unreadable += 1;
+                    continue;
+                }
+            };
+
+            if entry.depth() == 0 {
+                continue;
+            }
+
+            let Ok(metadata) = entry.path().symlink_metadata() else {
+                continue;
+            };
+
+            let file_type = if metadata.file_type().is_symlink() {
+                FindFileType::Symlink
+            } else if metadata.is_dir() {
+                FindFileType::Dir
+            } else {
+                FindFileType::File
+            };
+
+            let found = FindEntry {
+                path: entry.path().to_path_buf(),
+                file_type,
+                size: metadata.len(),
+                modified: metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+            };
+
+            if Self::matches(&found, options, now) {
+                results.push(found);
+            }
+        }
+
+        match options.sort_order {
+            FindSortOrder::ModifiedTime => results.sort_by_key(|found| Reverse(found.modified)),
+            FindSortOrder::Size => results.sort_by_key(|found| Reverse(found.size)),
+            FindSortOrder::Path => results.sort_by(|a, b| a.path.cmp(&b.path)),
+            FindSortOrder::None => (),
+        }
+
+        let total = results.len();
+        if let Some(limit) = options.limit {
+            results.truncate(limit);
+        }
+
+        let mut notes = Vec::new();
+        if results.len() < total {
+            notes.push(format!(
+                "[Showing {} of {} entries. Raise limit or narrow the filters to see the rest.]",
+                results.len(),
+                total
+            ));
+        }
+        if unreadable > 0 {
+            notes.push(format!(
+                "[Skipped {} that could not be read.]",
+                counted(unreadable, "path", "paths")
+            ));
+        }
+
+        if results.is_empty() {
+            let mut details = format!(
+                "No entries found in {} matching the given filters",
+                options.path.display()
+            );
+            for note in &notes {
+                details.push_str(&format!("\n\n{}", note));
+            }
+            return Ok(FormattedResult::success("find", "no entries", details));
+        }
+
+        let formatted: Vec<String> = results
+            .iter()
+            .map(|entry| {
+                let rel = entry.path.strip_prefix(options.path).unwrap_or(&entry.path);
+                let modified: DateTime<Local> = entry.modified.into();
+                format!(
+                    "{}\t{}\t{}\t{}",
+                    rel.display(),
+                    entry.file_type.as_str(),
+                    Self::format_size(entry.size),
+                    modified.format("%Y-%m-%d %H:%M:%S")
+                )
+            })
+            .collect();
+
+        let mut details = formatted.join("\n");
+        for note in &notes {
+            details.push_str(&format!("\n\n{}", note));
+        }
+        Ok(FormattedResult::success(
+            "find",
+            counted(formatted.len(), "entry", "entries"),
+            details,
+        ))
+    }
+}
+
+impl Tool for FindTool {
+    fn name(&self) -> &str {
+        "find"
+    }
+
+    fn description(&self) -> &str {
+        "Find files by name, type, size, and modification time. Returns paths with type, size, and mtime."
+    }
+
+    fn parameters(&self) -> ToolParameter {
+        ToolParameter::new_object(vec![
+            (
+                "path".to_string(),
+                ToolParameter::new_string("Directory to search")
+                    .with_description("Directory path to search in (defaults to current directory)"),
+            ),
+            (
+                "name".to_string(),
+                ToolParameter::new_string("Name pattern")
+                    .with_description("Glob pattern matched against the file name only (e.g., '*.log', 'Cargo.*')"),
+            ),
+            (
+                "type".to_string(),
+                ToolParameter::new_enum("Entry type", &["file", "dir", "symlink"])
+                    .with_description("Only return entries of this type: 'file', 'dir', or 'symlink'"),
+            ),
+            (
+                "modified_within".to_string(),
+                ToolParameter::new_string("Modified within")
+                    .with_description("Only entries modified within this duration (e.g., '30m', '2h', '1d', '1w')"),
+            ),
+            (
+                "modified_before".to_string(),
+                ToolParameter::new_string("Modified before")
+                    .with_description("Only entries last modified longer ago than this duration (e.g., '7d')"),
+            ),
+            (
+                "min_size".to_string(),
+                ToolParameter::new_string("Minimum size")
+                    .with_description("Only entries at least this large (e.g., '1MB', '500K', '1024')"),
+            ),
+            (
+                "max_size".to_string(),
+                ToolParameter::new_string("Maximum size")
+                    .with_description("Only entries at most this large (e.g., '10K')"),
+            ),
+            (
+                "max_depth".to_string(),
+                ToolParameter::new_number("Max depth")
+                    .with_description("Maximum directory depth to descend (default: unlimited)"),
+            ),
+            (
+                "sort_by".to_string(),
+                ToolParameter::new_enum("Sort order", &["modified", "size", "path", "none"]).with_description(
+                    "Sort order: 'modified' (default, newest first), 'size' (largest first), 'path', or 'none'",
+                ),
+            ),
+            (
+                "respect_gitignore".to_string(),
+                ToolParameter::new_boolean("Respect .gitignore")
+                    .with_description("Whether to respect .gitignore rules and skip hidden files (default: true)"),
+            ),
+            (
+                "limit".to_string(),
+                ToolParameter::new_number("Max results")
+                    .with_description("Maximum number of results to return (default: 100)")
+                    .with_minimum(1.0),
+            ),
+        ])
+        .with_required(&[])
+    }
+
+    fn risk_level(&self) -> ToolRisk {
+        ToolRisk::Safe
+    }
+
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
+    fn classification(&self) -> Option<thunderus_core::Classification> {
+        Some(thunderus_core::Classification::new(
+            ToolRisk::Safe,
+            "Find is a read-only file discovery operation. It only reads directory listings and file metadata.",
+        ))
+    }
+
+    fn execute(&self, tool_call_id: String, arguments: &Value) -> Result<ToolResult> {
+        let get_str = |key: &str| arguments.get(key).and_then(|v| v.as_str());
+        let invalid = |key: &str, value: &str| {
+            thunderus_core::Error::Validation(format!("Invalid '{}' parameter: '{}'", key, value))
+        };
+
+        let path_str = get_str("path").unwrap_or(".");
+
+        let name = get_str("name")
+            .map(|p| glob::Pattern::new(p).map_err(|_| invalid("name", p)))
+            .transpose()?;
+        let file_type = get_str("type")
+            .map(|t| FindFileType::parse_str(t).ok_or_else(|| invalid("type", t)))
+            .transpose()?;
+        let modified_within = get_str("modified_within")
+            .map(|d| Self::parse_duration(d).ok_or_else(|| invalid("modified_within", d)))
+            .transpose()?;
+        let modified_before = get_str("modified_before")
+            .map(|d| Self::parse_duration(d).ok_or_else(|| invalid("modified_before", d)))
+            .transpose()?;
+        let size_arg = |key: &str| -> Result<Option<u64>> {
+            match arguments.get(key) {
+                Some(Value::Number(n)) => Ok(n.as_u64()),
+                Some(Value::String(s)) => Self::parse_size(s).map(Some).ok_or_else(|| invalid(key, s)),
+                _ => Ok(None),
+            }
+        };
+        let min_size = size_arg("min_size")?;
+        let max_size = size_arg("max_size")?;
+        let sort_order = get_str("sort_by")
+            .map(|s| FindSortOrder::parse_str(s).ok_or_else(|| invalid("sort_by", s)))
+            .transpose()?
+            .unwrap_or(FindSortOrder::ModifiedTime);
+        let respect_gitignore = arguments
+            .get("respect_gitignore")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+        let max_depth = arguments.get("max_depth").and_then(|v| v.as_u64()).map(|v| v as usize);
+        let limit = arguments.get("limit").and_then(|v| v.as_u64()).map(|v| v as usize);
+
+        let path = PathBuf::from(path_str);
+        Self::validate_path(&path)?;
+
+        let options = FindOptions {
+            path: &path,
+            name,
+            file_type,
+            modified_within,
+            modified_before,
+            min_size,
+            max_size,
+            max_depth,
+            sort_order,
+            respect_gitignore,
+            limit: limit.or(Some(DEFAULT_LIMIT)),
+        };
+
+        let result = Self::execute_and_parse(&options)?.with_subject(path_str);
+
+        Ok(ToolResult::success(tool_call_id, result.to_text()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn setup() -> TempDir {
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir(temp.path().join("src")).unwrap();
+        std::fs::write(temp.path().join("small.txt"), "hi").unwrap();
+        std::fs::write(temp.path().join("src/big.rs"), "x".repeat(4096)).unwrap();
+        std::fs::write(temp.path().join("src/medium.rs"), "x".repeat(1024)).unwrap();
+        temp
+    }
+
+    fn run(temp: &TempDir, mut args: Value) -> String {
+        args["path"] = Value::String(temp.path().to_string_lossy().to_string());
+        let result = FindTool.execute("call_find".to_string(), &args).unwrap();
+        assert!(result.is_success());
+        let (header, body) = result.content.split_once("\n\n").unwrap_or((&result.content, ""));
+        assert!(header.starts_with("find "));
+        body.to_string()
+    }
+
+    fn paths(output: &str) -> Vec<&str> {
+        output.lines().map(|l| l.split('\t').next().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_find_tool_properties() {
+        let tool = FindTool;
+        assert_eq!(tool.name(), "find");
+        assert!(tool.risk_level().is_safe());
+        assert!(tool.is_read_only());
+        assert!(tool.classification().unwrap().reasoning.contains("read-only"));
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(FindTool::parse_duration("30"), Some(Duration::from_secs(30)));
+        assert_eq!(FindTool::parse_duration("15m"), Some(Duration::from_secs(900)));
+        assert_eq!(FindTool::parse_duration("2h"), Some(Duration::from_secs(7200)));
+        assert_eq!(FindTool::parse_duration("1d"), Some(Duration::from_secs(86400)));
+        assert_eq!(FindTool::parse_duration("1w"), Some(Duration::from_secs(604800)));
+        assert_eq!(FindTool::parse_duration("soon"), None);
+        assert_eq!(FindTool::parse_duration("3y"), None);
+        assert_eq!(FindTool::parse_duration("99999999999999999w"), None);
+    }
+
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(FindTool::parse_size("512"), Some(512));
+        assert_eq!(FindTool::parse_size("10K"), Some(10 * 1024));
+        assert_eq!(FindTool::parse_size("1MB"), Some(1024 * 1024));
+        assert_eq!(FindTool::parse_size("1.5kb"), Some(1536));
+        assert_eq!(FindTool::parse_size("2G"), Some(2 * 1024 * 1024 * 1024));
+        assert_eq!(FindTool::parse_size("lots"), None);
+    }
+
+    #[test]
+    fn test_find_by_type() {
+        let temp = setup();
+
+        let dirs = run(&temp, serde_json::json!({"type": "dir"}));
+        assert_eq!(paths(&dirs), vec!["src"]);
+
+        let files = run(&temp, serde_json::json!({"type": "file", "sort_by": "path"}));
+        assert_eq!(paths(&files), vec!["small.txt", "src/big.rs", "src/medium.rs"]);
+        assert!(files.lines().all(|l| l.contains("\tfile\t")));
+    }
+
+    #[test]
+    fn test_find_by_size_sorted() {
+        let temp = setup();
+
+        let output = run(
+            &temp,
+            serde_json::json!({"type": "file", "min_size": "1K", "sort_by": "size"}),
+        );
+        assert_eq!(paths(&output), vec!["src/big.rs", "src/medium.rs"]);
+        assert!(output.contains("4.0 KB"));
+
+        let output = run(&temp, serde_json::json!({"type": "file", "max_size": 100}));
+        assert_eq!(paths(&output), vec!["small.txt"]);
+    }
+
+    #[test]
+    fn test_find_by_name_and_mtime() {
+        let temp = setup();
+
+        let output = run(&temp, serde_json::json!({"name": "*.rs", "sort_by": "path"}));
+        assert_eq!(paths(&output), vec!["src/big.rs", "src/medium.rs"]);
+
+        let recent = run(&temp, serde_json::json!({"type": "file", "modified_within": "1d"}));
+        assert_eq!(recent.lines().count(), 3);
+
+        let old = run(&temp, serde_json::json!({"modified_before": "1d"}));
+        assert!(old.contains("No entries found"));
+    }
+
+    #[test]
+    fn test_find_respects_gitignore() {
+        let temp = setup();
+        std::fs::create_dir(temp.path().join(".git")).unwrap();
+        std::fs::write(temp.path().join(".gitignore"), "*.txt\n").unwrap();
+
+        let output = run(&temp, serde_json::json!({"type": "file", "sort_by": "path"}));
+        assert_eq!(paths(&output), vec!["src/big.rs", "src/medium.rs"]);
+
+        let output = run(&temp, serde_json::json!({"name": "*.txt", "respect_gitignore": false}));
+        assert_eq!(paths(&output), vec!["small.txt"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_find_symlinks() {
+        let temp = setup();
+        std::os::unix::fs::symlink(temp.path().join("small.txt"), temp.path().join("link.txt")).unwrap();
+
+        let output = run(&temp, serde_json::json!({"type": "symlink"}));
+        assert_eq!(paths(&output), vec!["link.txt"]);
+    }
+
+    #[test]
+    fn test_find_invalid_arguments() {
+        let temp = setup();
+        let path = temp.path().to_string_lossy().to_string();
+
+        let result = FindTool.execute("c".to_string(), &serde_json::json!({"path": path, "type": "socket"}));
+        assert!(result.unwrap_err().to_string().contains("Invalid 'type'"));
+
+        let result = FindTool.execute("c".to_string(), &serde_json::json!({"path": path, "min_size": "huge"}));
+        assert!(result.unwrap_err().to_string().contains("Invalid 'min_size'"));
+
+        let result = FindTool.execute("c".to_string(), &serde_json::json!({"path": "/nonexistent/path/xyz"}));
+        assert!(result.unwrap_err().to_string().contains("Path does not exist"));
+    }
+
+    #[test]
+    fn test_find_limit() {
+        let temp = setup();
+        let output = run(&temp, serde_json::json!({"limit": 2}));
+        let (entries, note) = output.split_once("\n\n").unwrap();
+        assert_eq!(entries.lines().count(), 2);
+        assert!(note.contains("Showing 2 of 4 entries"));
+
+        for i in 0..DEFAULT_LIMIT {
+            std::fs::write(temp.path().join(format!("extra-{i}.txt")), "").unwrap();
+        }
+        let output = run(&temp, serde_json::json!({"type": "file"}));
+        let (entries, note) = output.split_once("\n\n").unwrap();
+        assert_eq!(entries.lines().count(), DEFAULT_LIMIT);
+        assert!(note.contains(&format!("Showing {} of {} entries", DEFAULT_LIMIT, DEFAULT_LIMIT + 3)));
+    }
+
+    #[test]
+    fn test_find_spec_enums() {
+        let schema = FindTool.parameters();
+        let args = serde_json::json!({"type": "dir", "sort_by": "size"});
+        assert!(crate::dispatcher::validate_arguments("find", &schema, &args).is_ok());
+        let args = serde_json::json!({"type": "socket"});
+        assert!(crate::dispatcher::validate_arguments("find", &schema, &args).is_err());
+        let args = serde_json::json!({"sort_by": "random"});
+        assert!(crate::dispatcher::validate_arguments("find", &schema, &args).is_err());
+    }
+}
@@ -1,10 +1,13 @@
-use ignore::Walk;
 use serde_json::Value;
 use std::path::{Path, PathBuf};
 use thunderus_core::{Result, ToolRisk};
 use thunderus_providers::{ToolParameter, ToolResult};
 
-use crate::Tool;
+use crate::result_formatting::counted;
+use crate::{FormattedResult, GlobFormatter, Tool};
+
+/// Page size used when the caller does not pass `limit`
+const DEFAULT_LIMIT: usize = 100;
 
 /// Sort order for glob results
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -35,6 +38,8 @@ pub struct GlobOptions<'a> {
     pub sort_order: GlobSortOrder,
     pub respect_gitignore: bool,
     pub limit: Option<usize>,
+    /// Number of sorted matches to skip before the page starts
+    pub offset: usize,
 }
 
 /// A tool that finds files matching glob patterns
@@ -57,13 +62,13 @@ impl GlobTool {
     }
 
     /// Executes the glob search and returns matching files
-    fn execute_and_parse(options: &GlobOptions) -> Result<String> {
+    fn execute_and_parse(options: &GlobOptions) -> Result<FormattedResult> {
         Self::validate_path(options.path)?;
 
         let mut results: Vec<PathBuf> = Vec::new();
 
         if options.respect_gitignore {
-            let walk_builder = Walk::new(options.path);
+            let walk_builder = thunderus_core::workspace_walker(options.path).build();
             let pattern = options.pattern.trim_start_matches('/');
 
             for entry in walk_builder {
@@ -80,12 +85,6 @@ impl GlobTool {
 
                             if Self::matches_glob_pattern(&rel_str, pattern) {
                                 results.push(path.to_path_buf());
-
-                                if let Some(limit) = options.limit
-                                    && results.len() >= limit
-                                {
-                                    break;
-                                }
                             }
                         }
                     }
@@ -115,12 +114,6 @@ impl GlobTool {
                             eprintln!("Warning: error reading path: {}", e);
                         }
                     }
-
-                    if let Some(limit) = options.limit
-                        && results.len() >= limit
-                    {
-                        break;
-                    }
                 }
             }
         }
@@ -139,8 +132,19 @@ impl GlobTool {
         }
 
         if results.is_empty() {
-            Ok(format!("No files found matching pattern: {}", options.pattern))
+            Ok(FormattedResult::success(
+                "glob",
+                "no files",
+                format!("No files found matching pattern: {}", options.pattern),
+            ))
         } else {
+            let total = results.len();
+            let results: Vec<PathBuf> = results
+                .into_iter()
+                .skip(options.offset)
+                .take(options.limit.unwrap_or(usize::MAX))
+                .collect();
+
             let base_path = options
                 .path
                 .canonicalize()
@@ -169,7 +173,18 @@ impl GlobTool {
                 })
                 .collect();
 
-            Ok(formatted.join("\n"))
+            let files = counted(total, "file", "files");
+            if options.offset == 0 && formatted.len() == total {
+                return Ok(FormattedResult::success("glob", files, formatted.join("\n")));
+            }
+
+            let note = GlobFormatter::format_page_note(options.offset, formatted.len(), total);
+            let summary = match formatted.len() {
+                0 => format!("{}, none on this page", files),
+                shown => format!("{}–{} of {}", options.offset + 1, options.offset + shown, files),
+            };
+            let details = if formatted.is_empty() { note } else { format!("{}\n\n{}", formatted.join("\n"), note) };
+            Ok(FormattedResult::success("glob", summary, details))
         }
     }
 
@@ -179,7 +194,7 @@ impl GlobTool {
     /// - * matches any sequence of non-separator characters
     /// - ** matches any sequence of characters (including separators)
     /// - ? matches any single non-separator character
-    fn matches_glob_pattern(path: &str, pattern: &str) -> bool {
+    pub(crate) fn matches_glob_pattern(path: &str, pattern: &str) -> bool {
         if pattern == "*" || pattern == "**" {
             return true;
         }
@@ -237,9 +252,17 @@ impl Tool for GlobTool {
             (
                 "limit".to_string(),
                 ToolParameter::new_number("Max results")
-                    .with_description("Maximum number of results to return (default: unlimited)"),
+                    .with_description("Maximum number of results to return (default: 100)")
+                    .with_minimum(1.0),
+            ),
+            (
+                "offset".to_string(),
+                ToolParameter::new_number("Result offset")
+                    .with_description("Number of sorted results to skip, for paging through large result sets")
+                    .with_minimum(0.0),
             ),
         ])
+        .with_required(&["pattern"])
     }
 
     fn risk_level(&self) -> ToolRisk {
@@ -277,15 +300,23 @@ impl Tool for GlobTool {
             .and_then(|v| v.as_bool())
             .unwrap_or(true);
         let limit = arguments.get("limit").and_then(|v| v.as_u64()).map(|v| v as usize);
+        let offset = arguments.get("offset").and_then(|v| v.as_u64()).unwrap_or(0) as usize;
 
         let path = PathBuf::from(path_str);
         Self::validate_path(&path)?;
 
-        let options = GlobOptions { pattern, path: &path, sort_order, respect_gitignore, limit };
+        let options = GlobOptions {
+            pattern,
+            path: &path,
+            sort_order,
+            respect_gitignore,
+            limit: limit.or(Some(DEFAULT_LIMIT)),
+            offset,
+        };
 
-        let result = Self::execute_and_parse(&options)?;
+        let result = Self::execute_and_parse(&options)?.with_subject(format!("\"{}\"", pattern));
 
-        Ok(ToolResult::success(tool_call_id, result))
+        Ok(ToolResult::success(tool_call_id, result.to_text()))
     }
 }
 
@@ -392,10 +423,39 @@ mod tests {
         let tool_result = result.unwrap();
         assert!(tool_result.is_success());
 
-        let line_count = tool_result.content.lines().count();
+        let line_count = tool_result
+            .content
+            .lines()
+            .skip(2)
+            .take_while(|line| !line.is_empty())
+            .count();
         assert!(line_count <= 3);
     }
 
+    #[test]
+    fn test_glob_execute_pages_are_disjoint() {
+        let tool = GlobTool;
+        let page = |offset: usize| {
+            let args = serde_json::json!({
+                "pattern": "**/*.rs", "path": "src", "sort_order": "path", "limit": 2, "offset": offset
+            });
+            tool.execute("call_glob_7".to_string(), &args).unwrap().content
+        };
+
+        let first = page(0);
+        let second = page(2);
+        assert!(first.contains("Showing 1–2 of"));
+        assert!(first.contains("Use offset=2"));
+        assert!(second.contains("Showing 3–4 of"));
+
+        assert!(first.starts_with("glob \"**/*.rs\" — 1–2 of"));
+
+        let first_paths: Vec<&str> = first.lines().skip(2).take(2).collect();
+        let second_paths: Vec<&str> = second.lines().skip(2).take(2).collect();
+        assert!(first_paths.iter().all(|path| !second_paths.contains(path)));
+        assert!(first_paths[1] < second_paths[0]);
+    }
+
     #[test]
     fn test_glob_execute_no_results() {
         let tool = GlobTool;
@@ -1,10 +1,12 @@
 use serde_json::Value;
+use std::io::{BufRead, BufReader, Read};
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
-use thunderus_core::{Result, ToolRisk};
+use thunderus_core::{IGNORE_FILE_NAME, Result, ToolRisk};
 use thunderus_providers::{ToolParameter, ToolResult};
 
-use crate::Tool;
+use crate::result_formatting::counted;
+use crate::{FormattedResult, Tool};
 
 /// Output mode for the Grep tool
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -38,12 +40,28 @@ pub struct GrepOptions<'a> {
     pub context_after: Option<usize>,
     pub case_insensitive: bool,
     pub head_limit: Option<usize>,
+    /// Stop scanning once this many matches have been found
+    pub max_matches: Option<usize>,
+}
+
+/// Output lines collected from a grep scan
+#[derive(Debug, Default, PartialEq, Eq)]
+struct GrepScan {
+    lines: Vec<String>,
+    /// Matching lines seen, excluding context lines
+    matches: usize,
+    /// Set when the scan stopped at `max_matches` with more output pending
+    truncated: bool,
 }
 
 /// A tool that searches for patterns in files using ripgrep
 ///
 /// This tool provides fast, code-aware pattern search with structured output.
 /// It uses ripgrep (rg) when available, falling back to grep if needed.
+///
+/// With ripgrep, results are sorted by path and then line so that stopping
+/// early at `max_matches` always returns the same hits. The grep fallback
+/// streams results in directory order, so this guarantee is rg-only.
 #[derive(Debug)]
 pub struct GrepTool;
 
@@ -61,7 +79,28 @@ impl GrepTool {
 
     /// Builds the ripgrep or grep command with appropriate arguments
     fn build_command(options: &GrepOptions) -> Vec<String> {
-        let use_rg = Self::has_rg();
+        Self::command_args(options, Self::has_rg())
+    }
+
+    /// The `.thunderusignore` files that apply to a search of `path`
+    ///
+    /// The one in the working directory, plus one at the search root when
+    /// that is a different directory.
+    fn ignore_files(path: &Path) -> Vec<PathBuf> {
+        let mut files = vec![PathBuf::from(IGNORE_FILE_NAME)];
+        if path.is_dir() && path != Path::new(".") {
+            files.push(path.join(IGNORE_FILE_NAME));
+        }
+        files.retain(|file| file.is_file());
+        files
+    }
+
+    /// Arguments for rg when `use_rg` is set, or for the grep fallback
+    ///
+    /// rg is also told about `.thunderusignore` so search skips what the
+    /// rest of the workspace tooling skips; grep has no gitignore-style
+    /// filtering at all.
+    fn command_args(options: &GrepOptions, use_rg: bool) -> Vec<String> {
         let mut cmd = if use_rg { vec!["rg".to_string()] } else { vec!["grep".to_string()] };
 
         if options.case_insensitive {
@@ -79,7 +118,11 @@ impl GrepTool {
                 cmd.push("-l".to_string());
             }
             GrepOutputMode::Content => {
-                cmd.push(if use_rg { "-N" } else { "-n" }.to_string());
+                // `path\0LINE:text` marks a match and `path\0LINE-text` context,
+                // whatever characters the path contains
+                cmd.push("-n".to_string());
+                cmd.push("-H".to_string());
+                cmd.push("--null".to_string());
             }
             GrepOutputMode::Count => {
                 cmd.push("-c".to_string());
@@ -92,6 +135,12 @@ impl GrepTool {
                 cmd.push(g.to_string());
             }
             cmd.push("-.".to_string());
+            cmd.push("--sort".to_string());
+            cmd.push("path".to_string());
+            for file in Self::ignore_files(options.path) {
+                cmd.push("--ignore-file".to_string());
+                cmd.push(file.display().to_string());
+            }
         }
 
         if !use_rg {
@@ -109,17 +158,20 @@ impl GrepTool {
     }
 
     /// Executes the grep command and parses the output
-    fn execute_and_parse(options: &GrepOptions) -> Result<String> {
+    ///
+    /// Output is streamed so the process can be stopped as soon as
+    /// `head_limit` lines or `max_matches` matches have been read.
+    fn execute_and_parse(options: &GrepOptions) -> Result<FormattedResult> {
         let cmd_args = Self::build_command(options);
 
         let program = &cmd_args[0];
         let args = &cmd_args[1..];
 
-        let output = Command::new(program)
+        let mut child = Command::new(program)
             .args(args)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
-            .output()
+            .spawn()
             .map_err(|e| {
                 thunderus_core::Error::Tool(format!(
                     "Failed to execute {} command: {}",
@@ -128,27 +180,122 @@ impl GrepTool {
                 ))
             })?;
 
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            if output.stdout.is_empty() && !stderr.is_empty() {
-                return Ok(format!("No matches found. Search pattern: {}", options.pattern));
-            }
+        let stderr = child.stderr.take().map(|mut stderr| {
+            std::thread::spawn(move || {
+                let mut message = String::new();
+                let _ = stderr.read_to_string(&mut message);
+                message
+            })
+        });
+        let scan = match child.stdout.take() {
+            Some(stdout) => Self::scan_output(BufReader::new(stdout), options),
+            None => GrepScan::default(),
+        };
+
+        let _ = child.kill();
+        let status = child.wait();
+        let stderr = stderr.and_then(|reader| reader.join().ok()).unwrap_or_default();
+
+        // Exit code 1 only means no matches; 2 is a bad pattern, unreadable path and the like
+        if status.is_ok_and(|status| status.code() == Some(2)) {
+            return Err(thunderus_core::Error::Tool(format!(
+                "{} failed: {}",
+                program,
+                stderr.trim()
+            )));
         }
 
-        let stdout = String::from_utf8_lossy(&output.stdout);
+        if scan.lines.is_empty() {
+            return Ok(FormattedResult::success(
+                "grep",
+                "no matches",
+                format!("No matches found for pattern: {}", options.pattern),
+            ));
+        }
+
+        let mut summary = Self::summarize(options.output_mode, &scan);
+        let mut details = scan.lines.join("\n");
+        if scan.truncated {
+            summary.push_str(", stopped early");
+            details.push_str(&format!(
+                "\n\n[Stopped after {} matches; more matches may exist. Narrow the search or raise max_matches.]",
+                options.max_matches.unwrap_or_default()
+            ));
+        }
+        Ok(FormattedResult::success("grep", summary, details))
+    }
+
+    /// Summarizes a scan for the result header
+    fn summarize(output_mode: GrepOutputMode, scan: &GrepScan) -> String {
+        match output_mode {
+            GrepOutputMode::FilesWithMatches => counted(scan.lines.len(), "file", "files"),
+            GrepOutputMode::Content => counted(scan.matches, "matching line", "matching lines"),
+            GrepOutputMode::Count => {
+                let total: usize = scan
+                    .lines
+                    .iter()
+                    .filter_map(|line| line.rsplit(':').next()?.trim().parse::<usize>().ok())
+                    .sum();
+                format!(
+                    "{} in {}",
+                    counted(total, "match", "matches"),
+                    counted(scan.lines.len(), "file", "files")
+                )
+            }
+        }
+    }
 
-        let result = if let Some(limit) = options.head_limit {
-            let lines: Vec<&str> = stdout.lines().take(limit).collect();
-            lines.join("\n")
-        } else {
-            stdout.into_owned()
+    /// Splits a `--null` content line into its display form and whether it is a match
+    ///
+    /// Returns `None` for lines without the markers, such as `--` group separators.
+    fn parse_content_line(line: &str) -> Option<(String, bool)> {
+        let (path, rest) = line.split_once('\0')?;
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        let separator = rest[digits..].chars().next().filter(|_| digits > 0)?;
+        let is_match = match separator {
+            ':' => true,
+            '-' => false,
+            _ => return None,
         };
+        let text = &rest[digits + 1..];
+        Some((
+            format!("{path}{separator}{}{separator}{text}", &rest[..digits]),
+            is_match,
+        ))
+    }
+
+    /// Reads grep output until the line or match budget is spent
+    ///
+    /// In content mode only lines marked as matches count toward `max_matches`;
+    /// in the other modes every line is a hit.
+    fn scan_output(reader: impl BufRead, options: &GrepOptions) -> GrepScan {
+        let mut scan = GrepScan::default();
+
+        for line in reader.lines() {
+            let Ok(line) = line else { break };
+            if options.head_limit.is_some_and(|limit| scan.lines.len() >= limit) {
+                break;
+            }
 
-        if result.is_empty() {
-            Ok(format!("No matches found for pattern: {}", options.pattern))
-        } else {
-            Ok(result)
+            let (line, is_match) = if options.output_mode == GrepOutputMode::Content {
+                Self::parse_content_line(&line).unwrap_or((line, false))
+            } else {
+                (line, true)
+            };
+            if is_match {
+                if options.max_matches.is_some_and(|max| scan.matches >= max) {
+                    scan.truncated = true;
+                    break;
+                }
+                scan.matches += 1;
+            }
+            scan.lines.push(line);
         }
+
+        while scan.lines.last().is_some_and(|line| line == "--") {
+            scan.lines.pop();
+        }
+        scan
     }
 
     /// Validates that the path exists and is accessible
@@ -191,18 +338,20 @@ impl Tool for GrepTool {
             ),
             (
                 "output_mode".to_string(),
-                ToolParameter::new_string("Output format")
+                ToolParameter::new_enum("Output format", &["files_with_matches", "content", "count"])
                     .with_description("Output mode: 'files_with_matches' (default), 'content', or 'count'"),
             ),
             (
                 "context_before".to_string(),
                 ToolParameter::new_number("Lines before match")
-                    .with_description("Number of lines to show before each match (like grep -B)"),
+                    .with_description("Number of lines to show before each match (like grep -B)")
+                    .with_minimum(0.0),
             ),
             (
                 "context_after".to_string(),
                 ToolParameter::new_number("Lines after match")
-                    .with_description("Number of lines to show after each match (like grep -A)"),
+                    .with_description("Number of lines to show after each match (like grep -A)")
+                    .with_minimum(0.0),
             ),
             (
                 "case_insensitive".to_string(),
@@ -212,9 +361,20 @@ impl Tool for GrepTool {
             (
                 "head_limit".to_string(),
                 ToolParameter::new_number("Max results")
-                    .with_description("Maximum number of results to return (default: 100 files/lines)"),
+                    .with_description("Maximum number of results to return (default: 100 files/lines)")
+                    .with_minimum(1.0),
+            ),
+            (
+                "max_matches".to_string(),
+                ToolParameter::new_number("Max matching lines")
+                    .with_description(
+                        "Whole number of matching lines after which the search stops early. With ripgrep, \
+                         results are sorted by path so the same hits come back every time",
+                    )
+                    .with_minimum(1.0),
             ),
         ])
+        .with_required(&["pattern"])
     }
 
     fn risk_level(&self) -> ToolRisk {
@@ -262,6 +422,10 @@ impl Tool for GrepTool {
             .and_then(|v| v.as_bool())
             .unwrap_or(false);
         let head_limit = arguments.get("head_limit").and_then(|v| v.as_u64()).map(|v| v as usize);
+        let max_matches = arguments
+            .get("max_matches")
+            .and_then(|v| v.as_u64())
+            .map(|v| v as usize);
 
         let path = PathBuf::from(path_str);
         Self::validate_path(&path)?;
@@ -275,11 +439,12 @@ impl Tool for GrepTool {
             context_after,
             case_insensitive,
             head_limit: head_limit.or(Some(100)),
+            max_matches,
         };
 
-        let result = Self::execute_and_parse(&options)?;
+        let result = Self::execute_and_parse(&options)?.with_subject(format!("\"{}\"", pattern));
 
-        Ok(ToolResult::success(tool_call_id, result))
+        Ok(ToolResult::success(tool_call_id, result.to_text()))
     }
 }
 
@@ -318,6 +483,12 @@ mod tests {
 
         assert_eq!(spec.name(), "grep");
         assert!(spec.description().is_some());
+
+        let schema = tool.parameters();
+        let args = serde_json::json!({"pattern": "use", "max_matches": 0});
+        assert!(crate::dispatcher::validate_arguments("grep", &schema, &args).is_err());
+        let args = serde_json::json!({"pattern": "use", "max_matches": 5});
+        assert!(crate::dispatcher::validate_arguments("grep", &schema, &args).is_ok());
     }
 
     #[test]
@@ -390,7 +561,7 @@ mod tests {
         let tool_result = result.unwrap();
         assert!(tool_result.is_success());
 
-        let line_count = tool_result.content.lines().count();
+        let line_count = tool_result.content.lines().skip(2).count();
         assert!(line_count <= 5);
     }
 
@@ -415,4 +586,136 @@ mod tests {
         let tool_result = result.unwrap();
         assert!(tool_result.is_success());
     }
+
+    fn scan_options(max_matches: Option<usize>, head_limit: Option<usize>) -> GrepOptions<'static> {
+        GrepOptions {
+            pattern: "hit",
+            path: Path::new("."),
+            glob: None,
+            output_mode: GrepOutputMode::Content,
+            context_before: None,
+            context_after: Some(1),
+            case_insensitive: false,
+            head_limit,
+            max_matches,
+        }
+    }
+
+    #[test]
+    fn test_grep_rg_args_pass_thunderusignore() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut options = scan_options(None, None);
+        options.path = temp.path();
+
+        let args = GrepTool::command_args(&options, true);
+        let ignored = temp.path().join(IGNORE_FILE_NAME).display().to_string();
+        assert!(!args.contains(&ignored));
+
+        std::fs::write(temp.path().join(IGNORE_FILE_NAME), "vendor/\n").unwrap();
+        let args = GrepTool::command_args(&options, true);
+        let flag = args.iter().position(|arg| arg == "--ignore-file").unwrap();
+        assert!(args[flag + 1..].contains(&ignored));
+
+        let args = GrepTool::command_args(&options, false);
+        assert!(!args.contains(&"--ignore-file".to_string()));
+    }
+
+    #[test]
+    fn test_grep_only_rg_sorts_by_path() {
+        let options = scan_options(Some(1), None);
+
+        let args = GrepTool::command_args(&options, true);
+        let flag = args.iter().position(|arg| arg == "--sort").unwrap();
+        assert_eq!(args[flag + 1], "path");
+
+        let args = GrepTool::command_args(&options, false);
+        assert!(!args.contains(&"--sort".to_string()));
+    }
+
+    #[test]
+    fn test_grep_scan_stops_at_max_matches() {
+        let output =
+            "a.rs\x001:hit one\na.rs\x002-ctx\n--\nb.rs\x005:hit two\nb.rs\x006-ctx\n--\nc.rs\x001:hit three\n";
+        let options = scan_options(Some(2), None);
+
+        let scan = GrepTool::scan_output(std::io::Cursor::new(output), &options);
+        assert!(scan.truncated);
+        assert_eq!(scan.matches, 2);
+        assert_eq!(
+            scan.lines,
+            vec!["a.rs:1:hit one", "a.rs-2-ctx", "--", "b.rs:5:hit two", "b.rs-6-ctx"]
+        );
+    }
+
+    #[test]
+    fn test_grep_scan_uses_markers_not_pattern() {
+        // Context lines that happen to contain the pattern are still context
+        let output = "my-3-dir/a.rs\x001-hit in context\nmy-3-dir/a.rs\x002:hit\n";
+        let options = scan_options(Some(1), None);
+
+        let scan = GrepTool::scan_output(std::io::Cursor::new(output), &options);
+        assert!(!scan.truncated);
+        assert_eq!(scan.matches, 1);
+        assert_eq!(
+            scan.lines,
+            vec!["my-3-dir/a.rs-1-hit in context", "my-3-dir/a.rs:2:hit"]
+        );
+    }
+
+    #[test]
+    fn test_grep_scan_not_truncated_when_under_limit() {
+        let output = "a.rs\x001:hit one\nb.rs\x001:hit two\n";
+        let options = scan_options(Some(2), None);
+
+        let scan = GrepTool::scan_output(std::io::Cursor::new(output), &options);
+        assert!(!scan.truncated);
+        assert_eq!(scan.lines.len(), 2);
+
+        let options = scan_options(None, Some(1));
+        let scan = GrepTool::scan_output(std::io::Cursor::new(output), &options);
+        assert!(!scan.truncated);
+        assert_eq!(scan.lines, vec!["a.rs:1:hit one"]);
+    }
+
+    #[test]
+    fn test_grep_execute_reports_errors() {
+        let tool = GrepTool;
+        let args = serde_json::json!({"pattern": "[unclosed", "path": "src", "output_mode": "content"});
+        let err = tool.execute("call_grep_9".to_string(), &args).unwrap_err();
+        assert!(err.to_string().contains("failed"));
+    }
+
+    #[test]
+    fn test_grep_summarize_count_mode() {
+        let scan = GrepScan { lines: vec!["src/a.rs:3".to_string(), "src/b.rs:9".to_string()], ..GrepScan::default() };
+        assert_eq!(
+            GrepTool::summarize(GrepOutputMode::Count, &scan),
+            "12 matches in 2 files"
+        );
+        assert_eq!(GrepTool::summarize(GrepOutputMode::FilesWithMatches, &scan), "2 files");
+    }
+
+    #[test]
+    fn test_grep_execute_with_max_matches() {
+        let tool = GrepTool;
+        let args = serde_json::json!({"pattern": "use", "path": "src", "output_mode": "content", "max_matches": 2});
+        let result = tool.execute("call_grep_8".to_string(), &args).unwrap();
+
+        assert!(result.is_success());
+        assert!(
+            result
+                .content
+                .starts_with("grep \"use\" — 2 matching lines, stopped early")
+        );
+        assert!(result.content.contains("Stopped after 2 matches"));
+        assert_eq!(
+            result
+                .content
+                .lines()
+                .skip(2)
+                .take_while(|line| !line.is_empty())
+                .count(),
+            2
+        );
+    }
 }
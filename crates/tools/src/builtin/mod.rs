@@ -5,24 +5,30 @@
 
 mod echo;
 mod edit;
+mod find;
 mod glob;
 mod grep;
 mod multiedit;
 mod noop;
 mod patch;
 mod read;
+mod sed;
 mod shell;
+mod tree;
 mod write;
 
 pub use echo::EchoTool;
 pub use edit::EditTool;
+pub use find::{FindFileType, FindSortOrder, FindTool};
 pub use glob::{GlobSortOrder, GlobTool};
 pub use grep::{GrepOutputMode, GrepTool};
 pub use multiedit::{MultiEditOperation, MultiEditTool};
 pub use noop::NoopTool;
 pub use patch::PatchTool;
 pub use read::ReadTool;
-pub use shell::ShellTool;
+pub use sed::SedTool;
+pub use shell::{ReadOnlyShellTool, ShellTool};
+pub use tree::TreeTool;
 pub use write::WriteTool;
 
 #[cfg(test)]
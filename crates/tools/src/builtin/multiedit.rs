@@ -4,7 +4,10 @@ use std::path::{Path, PathBuf};
 use thunderus_core::{Result, ToolRisk};
 use thunderus_providers::{ToolParameter, ToolResult};
 
-use crate::Tool;
+use crate::patch_generator;
+use crate::read_history::{self, ReadHistory};
+use crate::result_formatting::counted;
+use crate::{FormattedResult, Tool};
 
 /// Represents a single edit operation for MultiEdit
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,10 +24,34 @@ pub struct MultiEditOperation {
 /// This tool applies multiple edits to a file in a single atomic operation.
 /// All edits are validated before any are applied, ensuring that either all
 /// succeed or none are applied.
-#[derive(Debug)]
-pub struct MultiEditTool;
+#[derive(Debug, Default)]
+pub struct MultiEditTool {
+    /// Reads made this session; when set, only lines already read may be edited
+    read_history: Option<ReadHistory>,
+}
 
 impl MultiEditTool {
+    /// Creates a multiedit tool that rejects edits to lines not yet read
+    pub fn with_read_history(read_history: ReadHistory) -> Self {
+        Self { read_history: Some(read_history) }
+    }
+
+    /// Validates that the lines each edit replaces have been read
+    fn validate_read(&self, file_path: &str, content: &str, edits: &[MultiEditOperation]) -> Result<()> {
+        let Some(history) = &self.read_history else {
+            return Ok(());
+        };
+
+        read_history::validate_read_before_edit(history, file_path).map_err(thunderus_core::Error::Validation)?;
+        for edit in edits {
+            if let Some(lines) = read_history::occurrence_lines(content, &edit.old_string).next() {
+                read_history::validate_read_before_edit_lines(history, file_path, lines)
+                    .map_err(thunderus_core::Error::Validation)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Validates that the path exists and is a file
     fn validate_path(path: &Path) -> Result<()> {
         if !path.exists() {
@@ -95,25 +122,38 @@ impl MultiEditTool {
         Ok(edit_count)
     }
 
+    /// Applies every edit to `content` in order, after validating them all
+    fn edited_content(content: &str, edits: &[MultiEditOperation]) -> Result<String> {
+        let _ = Self::validate_edits(content, edits)?;
+
+        let mut new_content = content.to_string();
+        for edit in edits {
+            new_content = new_content.replacen(&edit.old_string, &edit.new_string, 1);
+        }
+        Ok(new_content)
+    }
+
     /// Reads the file and performs all replacements atomically
-    fn perform_edits(path: &Path, edits: &[MultiEditOperation]) -> Result<String> {
+    fn perform_edits(&self, file_path: &str, edits: &[MultiEditOperation]) -> Result<FormattedResult> {
+        let path = Path::new(file_path);
         let content = std::fs::read_to_string(path)
             .map_err(|e| thunderus_core::Error::Tool(format!("Failed to read file '{}': {}", path.display(), e)))?;
 
-        let _ = Self::validate_edits(&content, edits)?;
+        self.validate_read(file_path, &content, edits)?;
 
-        let mut new_content = content;
-        for edit in edits {
-            new_content = new_content.replacen(&edit.old_string, &edit.new_string, 1);
-        }
+        let new_content = Self::edited_content(&content, edits)?;
 
         std::fs::write(path, new_content)
             .map_err(|e| thunderus_core::Error::Tool(format!("Failed to write file '{}': {}", path.display(), e)))?;
 
-        Ok(format!(
-            "Successfully edited file: {}\nApplied {} edit operation(s)",
-            path.display(),
-            edits.len()
+        Ok(FormattedResult::success(
+            "multiedit",
+            counted(edits.len(), "edit", "edits"),
+            format!(
+                "Successfully edited file: {}\nApplied {} edit operation(s)",
+                path.display(),
+                edits.len()
+            ),
         ))
     }
 
@@ -179,6 +219,7 @@ impl Tool for MultiEditTool {
                 .with_description("Array of edit operations. Each operation must have unique old_string values"),
             ),
         ])
+        .with_required(&["file_path", "edits"])
     }
 
     fn risk_level(&self) -> ToolRisk {
@@ -192,6 +233,15 @@ impl Tool for MultiEditTool {
         ))
     }
 
+    fn preview_diff(&self, arguments: &Value) -> Option<String> {
+        let path = PathBuf::from(arguments.get("file_path")?.as_str()?);
+        let edits = Self::parse_edits(arguments.get("edits")?).ok()?;
+
+        let content = std::fs::read_to_string(&path).ok()?;
+        let new_content = Self::edited_content(&content, &edits).ok()?;
+        patch_generator::generate_unified_diff(&path, &content, &new_content, "HEAD").ok()
+    }
+
     fn execute(&self, tool_call_id: String, arguments: &Value) -> Result<ToolResult> {
         let file_path_str = arguments
             .get("file_path")
@@ -214,9 +264,9 @@ impl Tool for MultiEditTool {
 
         Self::validate_path(&path)?;
 
-        let result = Self::perform_edits(&path, &edits)?;
+        let result = self.perform_edits(file_path_str, &edits)?.with_subject(file_path_str);
 
-        Ok(ToolResult::success(tool_call_id, result))
+        Ok(ToolResult::success(tool_call_id, result.to_text()))
     }
 }
 
@@ -227,7 +277,7 @@ mod tests {
 
     #[test]
     fn test_multiedit_tool_properties() {
-        let tool = MultiEditTool;
+        let tool = MultiEditTool::default();
         assert_eq!(tool.name(), "multiedit");
         assert_eq!(tool.risk_level(), ToolRisk::Risky);
         assert!(tool.risk_level().is_risky());
@@ -235,7 +285,7 @@ mod tests {
 
     #[test]
     fn test_multiedit_execute_missing_file_path() {
-        let tool = MultiEditTool;
+        let tool = MultiEditTool::default();
         let args = serde_json::json!({"edits": []});
         let result = tool.execute("call_multiedit_1".to_string(), &args);
 
@@ -246,7 +296,7 @@ mod tests {
 
     #[test]
     fn test_multiedit_execute_empty_file_path() {
-        let tool = MultiEditTool;
+        let tool = MultiEditTool::default();
         let args = serde_json::json!({"file_path": "", "edits": []});
         let result = tool.execute("call_multiedit_2".to_string(), &args);
 
@@ -257,7 +307,7 @@ mod tests {
 
     #[test]
     fn test_multiedit_execute_missing_edits() {
-        let tool = MultiEditTool;
+        let tool = MultiEditTool::default();
         let args = serde_json::json!({"file_path": "/tmp/test.txt"});
         let result = tool.execute("call_multiedit_3".to_string(), &args);
 
@@ -268,7 +318,7 @@ mod tests {
 
     #[test]
     fn test_multiedit_execute_edits_not_array() {
-        let tool = MultiEditTool;
+        let tool = MultiEditTool::default();
         let args = serde_json::json!({"file_path": "/tmp/test.txt", "edits": "not an array"});
         let result = tool.execute("call_multiedit_4".to_string(), &args);
 
@@ -279,7 +329,7 @@ mod tests {
 
     #[test]
     fn test_multiedit_execute_empty_edits_array() {
-        let tool = MultiEditTool;
+        let tool = MultiEditTool::default();
 
         let temp_dir = std::env::temp_dir();
         let temp_file = temp_dir.join("test_multiedit_empty.txt");
@@ -300,7 +350,7 @@ mod tests {
 
     #[test]
     fn test_multiedit_execute_missing_old_string() {
-        let tool = MultiEditTool;
+        let tool = MultiEditTool::default();
 
         let temp_dir = std::env::temp_dir();
         let temp_file = temp_dir.join("test_multiedit_missing_old.txt");
@@ -321,7 +371,7 @@ mod tests {
 
     #[test]
     fn test_multiedit_execute_missing_new_string() {
-        let tool = MultiEditTool;
+        let tool = MultiEditTool::default();
 
         let temp_dir = std::env::temp_dir();
         let temp_file = temp_dir.join("test_multiedit_missing_new.txt");
@@ -342,7 +392,7 @@ mod tests {
 
     #[test]
     fn test_multiedit_execute_nonexistent_file() {
-        let tool = MultiEditTool;
+        let tool = MultiEditTool::default();
         let args = serde_json::json!({
             "file_path": "/tmp/nonexistent_multiedit_12345.txt",
             "edits": [{"old_string": "foo", "new_string": "bar"}]
@@ -356,7 +406,7 @@ mod tests {
 
     #[test]
     fn test_multiedit_execute_old_string_not_found() {
-        let tool = MultiEditTool;
+        let tool = MultiEditTool::default();
 
         let temp_dir = std::env::temp_dir();
         let temp_file = temp_dir.join("test_multiedit_not_found.txt");
@@ -377,7 +427,7 @@ mod tests {
 
     #[test]
     fn test_multiedit_execute_duplicate_old_strings() {
-        let tool = MultiEditTool;
+        let tool = MultiEditTool::default();
 
         let temp_dir = std::env::temp_dir();
         let temp_file = temp_dir.join("test_multiedit_duplicate.txt");
@@ -401,7 +451,7 @@ mod tests {
 
     #[test]
     fn test_multiedit_execute_success_single_edit() {
-        let tool = MultiEditTool;
+        let tool = MultiEditTool::default();
 
         let temp_dir = std::env::temp_dir();
         let temp_file = temp_dir.join("test_multiedit_single.txt");
@@ -424,9 +474,33 @@ mod tests {
         assert_eq!(content, "Hello Rust\n");
     }
 
+    #[test]
+    fn test_multiedit_preview_diff() {
+        let tool = MultiEditTool::default();
+
+        let temp_dir = std::env::temp_dir();
+        let temp_file = temp_dir.join("test_multiedit_preview_diff.txt");
+        writeln!(std::fs::File::create(&temp_file).unwrap(), "Hello world").unwrap();
+
+        let args = serde_json::json!({
+            "file_path": temp_file.to_string_lossy().as_ref(),
+            "edits": [
+                {"old_string": "Hello", "new_string": "Hi"},
+                {"old_string": "world", "new_string": "Rust"}
+            ]
+        });
+        let diff = tool.preview_diff(&args);
+
+        let content = std::fs::read_to_string(&temp_file).unwrap();
+        let _ = std::fs::remove_file(&temp_file);
+
+        assert!(diff.unwrap().contains("+Hi Rust"));
+        assert_eq!(content, "Hello world\n");
+    }
+
     #[test]
     fn test_multiedit_execute_success_multiple_edits() {
-        let tool = MultiEditTool;
+        let tool = MultiEditTool::default();
 
         let temp_dir = std::env::temp_dir();
         let temp_file = temp_dir.join("test_multiedit_multiple.txt");
@@ -458,7 +532,7 @@ mod tests {
 
     #[test]
     fn test_multiedit_execute_atomic_failure_on_second_edit() {
-        let tool = MultiEditTool;
+        let tool = MultiEditTool::default();
 
         let temp_dir = std::env::temp_dir();
         let temp_file = temp_dir.join("test_multiedit_atomic.txt");
@@ -481,4 +555,36 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(content_after, original_content);
     }
+
+    #[test]
+    fn test_multiedit_execute_rejects_lines_outside_read_range() {
+        let history = ReadHistory::new();
+        let tool = MultiEditTool::with_read_history(history.clone());
+
+        let temp_dir = std::env::temp_dir();
+        let temp_file = temp_dir.join("test_multiedit_read_range.txt");
+        std::fs::write(&temp_file, "one\ntwo\nthree\nfour\n").unwrap();
+        let path = temp_file.to_string_lossy().to_string();
+        history.record_read(&path, 2, 0);
+
+        let args = serde_json::json!({
+            "file_path": path,
+            "edits": [
+                {"old_string": "one", "new_string": "1"},
+                {"old_string": "four", "new_string": "4"}
+            ]
+        });
+        let outside = tool.execute("call_multiedit_read_1".to_string(), &args);
+        let unchanged = std::fs::read_to_string(&temp_file).unwrap();
+
+        history.record_read(&path, 2, 2);
+        let covered = tool.execute("call_multiedit_read_2".to_string(), &args);
+        let content = std::fs::read_to_string(&temp_file).unwrap();
+        let _ = std::fs::remove_file(&temp_file);
+
+        assert!(outside.unwrap_err().to_string().contains("Cannot edit lines 4-4"));
+        assert_eq!(unchanged, "one\ntwo\nthree\nfour\n");
+        assert!(covered.unwrap().is_success());
+        assert_eq!(content, "1\ntwo\nthree\n4\n");
+    }
 }
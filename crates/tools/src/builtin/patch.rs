@@ -3,8 +3,9 @@ use std::path::{Path, PathBuf};
 use thunderus_core::{Result, ToolRisk};
 use thunderus_providers::{ToolParameter, ToolResult};
 
-use crate::Tool;
 use crate::patch_generator;
+use crate::result_formatting::counted;
+use crate::{FormattedResult, Tool};
 
 /// Tool for generating unified diff patches (patch-first editing approach)
 ///
@@ -81,6 +82,7 @@ impl Tool for PatchTool {
                     .with_description("The git commit hash of the base snapshot (defaults to 'HEAD')"),
             ),
         ])
+        .with_required(&["file_path", "new_content"])
     }
 
     fn risk_level(&self) -> ToolRisk {
@@ -94,6 +96,18 @@ impl Tool for PatchTool {
         ).with_suggestion("Always prefer PatchTool over WriteTool for file edits. Patches are safer because they're reviewable and reversible."))
     }
 
+    fn preview_diff(&self, arguments: &Value) -> Option<String> {
+        let path = PathBuf::from(arguments.get("file_path")?.as_str()?);
+        let new_content = arguments.get("new_content")?.as_str()?;
+        let base_snapshot = arguments
+            .get("base_snapshot")
+            .and_then(|v| v.as_str())
+            .unwrap_or("HEAD");
+
+        Self::validate_path(&path).ok()?;
+        Self::generate_patch(&path, new_content, base_snapshot).ok()
+    }
+
     fn execute(&self, tool_call_id: String, arguments: &Value) -> Result<ToolResult> {
         let file_path_str = arguments
             .get("file_path")
@@ -120,10 +134,14 @@ impl Tool for PatchTool {
         Self::validate_path(&path)?;
 
         let patch = Self::generate_patch(&path, new_content, base_snapshot)?;
-
-        Ok(ToolResult::success(
-            tool_call_id,
+        let hunks = patch.lines().filter(|line| line.starts_with("@@")).count();
+        let result = FormattedResult::success(
+            "patch",
+            counted(hunks, "hunk", "hunks"),
             format!("Generated patch for file: {}\n\n{}", path.display(), patch),
-        ))
+        )
+        .with_subject(file_path_str);
+
+        Ok(ToolResult::success(tool_call_id, result.to_text()).with_metadata(serde_json::json!({ "diff": patch })))
     }
 }
@@ -1,10 +1,11 @@
 use serde_json::Value;
 use std::io::Read as StdIoRead;
 use std::path::{Path, PathBuf};
-use thunderus_core::{Result, ToolRisk};
+use thunderus_core::{ImageInfo, Result, ToolRisk};
 use thunderus_providers::{ToolParameter, ToolResult};
 
-use crate::Tool;
+use crate::result_formatting::counted;
+use crate::{FormattedResult, Tool};
 
 /// Maximum line length for Read tool output
 const MAX_LINE_LENGTH: usize = 2000;
@@ -18,6 +19,7 @@ const DEFAULT_LINE_LIMIT: usize = 2000;
 /// - Line numbers for easy navigation
 /// - Offset/limit for reading large files in chunks
 /// - Character truncation for long lines
+/// - Image detection, reporting format and dimensions instead of bytes
 /// - Binary file detection and rejection
 #[derive(Debug)]
 pub struct ReadTool;
@@ -57,10 +59,20 @@ impl ReadTool {
     }
 
     /// Reads the file and formats the output with line numbers
-    fn read_and_format(path: &Path, offset: Option<usize>, limit: Option<usize>) -> Result<String> {
+    fn read_and_format(path: &Path, offset: Option<usize>, limit: Option<usize>) -> Result<FormattedResult> {
         let offset = offset.unwrap_or(0);
         let limit = limit.unwrap_or(DEFAULT_LINE_LIMIT);
 
+        let image = ImageInfo::from_path(path)
+            .map_err(|e| thunderus_core::Error::Tool(format!("Failed to read file '{}': {}", path.display(), e)))?;
+        if let Some(info) = image {
+            return Ok(FormattedResult::success(
+                "read",
+                format!("{}x{} {} image", info.width, info.height, info.format),
+                info.note(),
+            ));
+        }
+
         if Self::is_binary(path)? {
             return Err(thunderus_core::Error::Tool(format!(
                 "Cannot read binary file: {}.\n\nBinary files are not supported. This tool only supports text files including source code, markdown, and other text-based formats.",
@@ -73,12 +85,18 @@ impl ReadTool {
 
         let lines: Vec<&str> = content.lines().collect();
 
-        if offset >= lines.len() && !lines.is_empty() {
-            return Ok(format!(
-                "Offset {} is beyond file length ({} lines). File: {}",
-                offset,
-                lines.len(),
-                path.display()
+        let total = lines.len();
+
+        if offset >= total && total > 0 {
+            return Ok(FormattedResult::success(
+                "read",
+                format!("offset {} past end of {}", offset, counted(total, "line", "lines")),
+                format!(
+                    "Offset {} is beyond file length ({} lines). File: {}",
+                    offset,
+                    total,
+                    path.display()
+                ),
             ));
         }
 
@@ -104,10 +122,19 @@ impl ReadTool {
             .collect();
 
         if formatted.is_empty() {
-            Ok(format!("File is empty: {}", path.display()))
-        } else {
-            Ok(formatted.join("\n"))
+            return Ok(FormattedResult::success(
+                "read",
+                "empty file",
+                format!("File is empty: {}", path.display()),
+            ));
         }
+
+        let summary = if start == 0 && end == total {
+            counted(total, "line", "lines")
+        } else {
+            format!("lines {}–{} of {}", start + 1, end, total)
+        };
+        Ok(FormattedResult::success("read", summary, formatted.join("\n")))
     }
 }
 
@@ -117,7 +144,7 @@ impl Tool for ReadTool {
     }
 
     fn description(&self) -> &str {
-        "Read file contents with line numbers. Use this to view source code, config files, and other text files."
+        "Read file contents with line numbers. Use this to view source code, config files, and other text files. Image files report their format and dimensions."
     }
 
     fn parameters(&self) -> ToolParameter {
@@ -138,6 +165,7 @@ impl Tool for ReadTool {
                     .with_description("Maximum number of lines to read (default: 2000)"),
             ),
         ])
+        .with_required(&["file_path"])
     }
 
     fn risk_level(&self) -> ToolRisk {
@@ -173,9 +201,9 @@ impl Tool for ReadTool {
 
         let offset = arguments.get("offset").and_then(|v| v.as_u64()).map(|v| v as usize);
         let limit = arguments.get("limit").and_then(|v| v.as_u64()).map(|v| v as usize);
-        let result = Self::read_and_format(&path, offset, limit)?;
+        let result = Self::read_and_format(&path, offset, limit)?.with_subject(file_path_str);
 
-        Ok(ToolResult::success(tool_call_id, result))
+        Ok(ToolResult::success(tool_call_id, result.to_text()))
     }
 }
 
@@ -190,7 +218,7 @@ mod tests {
         assert_eq!(tool.name(), "read");
         assert_eq!(
             tool.description(),
-            "Read file contents with line numbers. Use this to view source code, config files, and other text files."
+            "Read file contents with line numbers. Use this to view source code, config files, and other text files. Image files report their format and dimensions."
         );
     }
 
@@ -334,8 +362,13 @@ mod tests {
         let tool_result = result.unwrap();
         assert!(tool_result.is_success());
 
-        let line_count = tool_result.content.lines().count();
+        let line_count = tool_result.content.lines().skip(2).count();
         assert!(line_count <= 5);
+        assert!(
+            tool_result
+                .content
+                .starts_with(&format!("read {} — lines 1–5 of 10", temp_file.display()))
+        );
         assert!(tool_result.content.contains("1→Line 1"));
         assert!(tool_result.content.contains("5→Line 5"));
     }
@@ -380,4 +413,30 @@ mod tests {
         assert!(tool_result.is_success());
         assert!(tool_result.content.contains("Offset 10 is beyond file length"));
     }
+
+    #[test]
+    fn test_read_execute_image_file() {
+        let tool = ReadTool;
+
+        let temp_file = std::env::temp_dir().join("test_read_image.png");
+        let mut header = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+        header.extend_from_slice(&800u32.to_be_bytes());
+        header.extend_from_slice(&600u32.to_be_bytes());
+        header.extend_from_slice(&[8, 6, 0, 0, 0, 0, 0, 0, 0]);
+        std::fs::write(&temp_file, &header).unwrap();
+
+        let args = serde_json::json!({"file_path": temp_file.to_string_lossy().as_ref()});
+        let result = tool.execute("call_read_image".to_string(), &args);
+
+        let _ = std::fs::remove_file(&temp_file);
+
+        let tool_result = result.unwrap();
+        assert!(tool_result.is_success());
+        assert!(tool_result.content.contains("800x600 PNG image"));
+        assert!(tool_result.content.contains("[image: 800x600 PNG]"));
+        assert_eq!(
+            ImageInfo::parse_note(&tool_result.content).map(|i| (i.width, i.height)),
+            Some((800, 600))
+        );
+    }
 }
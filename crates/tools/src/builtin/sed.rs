@@ -0,0 +1,539 @@
+use regex_lite::Regex;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use thunderus_core::{AgentDir, Result, ToolRisk};
+use thunderus_providers::{ToolParameter, ToolResult};
+
+use super::GlobTool;
+use crate::backup::{BackupManager, BackupMetadata, BackupMode, DEFAULT_MAX_BACKUPS};
+use crate::patch_generator;
+use crate::read_history::{self, ReadHistory};
+use crate::result_formatting::counted;
+use crate::{FormattedResult, Tool};
+
+/// Most files a single substitution may touch
+pub const MAX_SED_FILES: usize = 200;
+
+/// A file the substitution would change
+#[derive(Debug, Clone)]
+struct FileChange {
+    path: PathBuf,
+    matches: usize,
+    old_content: String,
+    new_content: String,
+}
+
+/// A tool that applies a regex substitution across one or more files
+///
+/// The stream-edit counterpart to Edit and MultiEdit: one pattern, every
+/// match, in a single file or every file under a directory matching a glob.
+/// Replacements may reference capture groups (`$1`, `${name}`). Every file is
+/// backed up before it is rewritten, and `dry_run` reports the per-file match
+/// counts and combined diff without writing anything.
+///
+/// Writes are all-or-nothing: every new file is staged next to its original
+/// before any original is replaced, and a failed replace restores the files
+/// already rewritten from their backups.
+#[derive(Debug, Default)]
+pub struct SedTool {
+    /// Where backups go; defaults to `.agent/backups` under the working directory
+    backup_dir: Option<PathBuf>,
+    /// Reads made this session; when set, only lines already read may be rewritten
+    read_history: Option<ReadHistory>,
+}
+
+impl SedTool {
+    /// Create a sed tool that keeps backups in `backup_dir`
+    pub fn with_backup_dir(backup_dir: impl Into<PathBuf>) -> Self {
+        Self { backup_dir: Some(backup_dir.into()), ..Self::default() }
+    }
+
+    /// Rejects rewrites of lines that have not been read yet
+    pub fn with_read_history(mut self, read_history: ReadHistory) -> Self {
+        self.read_history = Some(read_history);
+        self
+    }
+
+    fn backup_manager(&self) -> Result<BackupManager> {
+        let backup_dir = match &self.backup_dir {
+            Some(dir) => dir.clone(),
+            None => AgentDir::from_current_dir()?.backups_dir(),
+        };
+        Ok(BackupManager::new(backup_dir, BackupMode::Always, DEFAULT_MAX_BACKUPS))
+    }
+
+    /// Validates that every line holding a match has been read
+    ///
+    /// Files found through a glob are looked up both as joined (`./src/a.rs`)
+    /// and without the leading `./`, since reads record the path as given.
+    fn validate_read(&self, regex: &Regex, changes: &[FileChange]) -> Result<()> {
+        let Some(history) = &self.read_history else {
+            return Ok(());
+        };
+
+        for change in changes {
+            let joined = change.path.to_string_lossy();
+            let file_path = joined
+                .strip_prefix("./")
+                .filter(|_| history.was_read(&joined).is_none())
+                .unwrap_or(&joined);
+            read_history::validate_read_before_edit(history, file_path).map_err(thunderus_core::Error::Validation)?;
+            for found in regex.find_iter(&change.old_content) {
+                let lines = read_history::span_lines(&change.old_content, found.start(), found.as_str());
+                read_history::validate_read_before_edit_lines(history, file_path, lines)
+                    .map_err(thunderus_core::Error::Validation)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn compile(pattern: &str) -> Result<Regex> {
+        if pattern.is_empty() {
+            return Err(thunderus_core::Error::Validation("pattern cannot be empty".to_string()));
+        }
+        Regex::new(pattern)
+            .map_err(|e| thunderus_core::Error::Validation(format!("Invalid regex pattern '{}': {}", pattern, e)))
+    }
+
+    /// Files named by `path`, filtered by `glob` when `path` is a directory
+    fn target_files(path: &Path, glob: Option<&str>) -> Result<Vec<PathBuf>> {
+        if !path.exists() {
+            return Err(thunderus_core::Error::Validation(format!(
+                "Path does not exist: {}",
+                path.display()
+            )));
+        }
+        if path.is_file() {
+            return Ok(vec![path.to_path_buf()]);
+        }
+
+        let glob = glob.ok_or_else(|| {
+            thunderus_core::Error::Validation(format!(
+                "Path is a directory: {}. Pass a glob (e.g. \"**/*.rs\") to choose files under it",
+                path.display()
+            ))
+        })?;
+        let mut files: Vec<PathBuf> = thunderus_core::workspace_walker(path)
+            .build()
+            .flatten()
+            .filter(|entry| entry.file_type().is_some_and(|t| t.is_file()))
+            .map(|entry| entry.into_path())
+            .filter(|file| {
+                file.strip_prefix(path)
+                    .is_ok_and(|rel| GlobTool::matches_glob_pattern(&rel.to_string_lossy(), glob))
+            })
+            .collect();
+        files.sort();
+        Ok(files)
+    }
+
+    /// Computes the change for every target file that has at least one match
+    ///
+    /// Files that aren't valid UTF-8 are skipped.
+    fn plan(regex: &Regex, replacement: &str, files: Vec<PathBuf>) -> Result<Vec<FileChange>> {
+        let mut changes = Vec::new();
+        for path in files {
+            let Ok(old_content) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            let matches = regex.find_iter(&old_content).count();
+            if matches == 0 {
+                continue;
+            }
+            if changes.len() == MAX_SED_FILES {
+                return Err(thunderus_core::Error::Validation(format!(
+                    "Pattern matches in more than {} files. Narrow the path or glob",
+                    MAX_SED_FILES
+                )));
+            }
+            let new_content = regex.replace_all(&old_content, replacement).into_owned();
+            changes.push(FileChange { path, matches, old_content, new_content });
+        }
+        Ok(changes)
+    }
+
+    /// Parses the arguments and works out what the substitution would change
+    ///
+    /// Returns the compiled pattern alongside the changes so the caller can
+    /// locate the matched lines.
+    fn plan_from_arguments(arguments: &Value) -> Result<(Regex, Vec<FileChange>)> {
+        let pattern = arguments
+            .get("pattern")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| thunderus_core::Error::Validation("Missing or invalid 'pattern' parameter".to_string()))?;
+        let replacement = arguments.get("replacement").and_then(|v| v.as_str()).ok_or_else(|| {
+            thunderus_core::Error::Validation("Missing or invalid 'replacement' parameter".to_string())
+        })?;
+        let path = arguments.get("path").and_then(|v| v.as_str()).unwrap_or(".");
+        let glob = arguments.get("glob").and_then(|v| v.as_str());
+
+        let regex = Self::compile(pattern)?;
+        let files = Self::target_files(Path::new(path), glob)?;
+        let changes = Self::plan(&regex, replacement, files)?;
+        Ok((regex, changes))
+    }
+
+    /// Unified diffs for every change, one after another
+    fn combined_diff(changes: &[FileChange]) -> String {
+        changes
+            .iter()
+            .filter_map(|change| {
+                patch_generator::generate_unified_diff(&change.path, &change.old_content, &change.new_content, "HEAD")
+                    .ok()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Where the new content of `path` is staged before it replaces the original
+    fn staged_path(path: &Path) -> PathBuf {
+        let name = path.file_name().map(|name| name.to_string_lossy()).unwrap_or_default();
+        path.with_file_name(format!(".{}.sed-{}", name, std::process::id()))
+    }
+
+    /// Writes the new content next to the original, keeping its permissions
+    fn stage(change: &FileChange) -> Result<PathBuf> {
+        let staged = Self::staged_path(&change.path);
+        let written = std::fs::write(&staged, &change.new_content)
+            .and_then(|_| std::fs::metadata(&change.path))
+            .and_then(|metadata| std::fs::set_permissions(&staged, metadata.permissions()));
+        if let Err(e) = written {
+            let _ = std::fs::remove_file(&staged);
+            return Err(thunderus_core::Error::Tool(format!(
+                "Failed to write file '{}': {}",
+                change.path.display(),
+                e
+            )));
+        }
+        Ok(staged)
+    }
+
+    /// Stages and backs up every change, or removes whatever was staged on failure
+    fn prepare(backups: &BackupManager, changes: &[FileChange]) -> Result<(Vec<PathBuf>, Vec<BackupMetadata>)> {
+        backups.cleanup_old_backups()?;
+        let mut staged = Vec::new();
+        let prepared = changes
+            .iter()
+            .try_for_each(|change| Self::stage(change).map(|path| staged.push(path)))
+            .and_then(|_| {
+                changes
+                    .iter()
+                    .map(|change| backups.create_backup(&change.path, "sed"))
+                    .collect::<Result<Vec<_>>>()
+            });
+        match prepared {
+            Ok(made) => Ok((staged, made)),
+            Err(e) => {
+                for path in &staged {
+                    let _ = std::fs::remove_file(path);
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Stages and backs up every change, then swaps the staged files into place
+    ///
+    /// Nothing is replaced until every file is staged and backed up. If a
+    /// replace fails, the files already replaced are restored from their backups.
+    /// Old backups are pruned first, so this run's backups are never pruned.
+    fn commit(backups: &BackupManager, changes: &[FileChange]) -> Result<Vec<BackupMetadata>> {
+        let (staged, made) = Self::prepare(backups, changes)?;
+
+        for (done, (change, file)) in changes.iter().zip(&staged).enumerate() {
+            if let Err(e) = std::fs::rename(file, &change.path) {
+                for backup in &made[..done] {
+                    let _ = backups.restore_backup(backup);
+                }
+                for path in &staged[done..] {
+                    let _ = std::fs::remove_file(path);
+                }
+                return Err(thunderus_core::Error::Tool(format!(
+                    "Failed to write file '{}': {}. No files were changed",
+                    change.path.display(),
+                    e
+                )));
+            }
+        }
+
+        Ok(made)
+    }
+
+    /// Backs up and rewrites every changed file, or only describes the changes on a dry run
+    fn apply(&self, changes: &[FileChange], dry_run: bool) -> Result<FormattedResult> {
+        let total: usize = changes.iter().map(|change| change.matches).sum();
+        let summary = format!(
+            "{} in {}{}",
+            counted(total, "match", "matches"),
+            counted(changes.len(), "file", "files"),
+            if dry_run { " (dry run)" } else { "" }
+        );
+        if changes.is_empty() {
+            return Ok(FormattedResult::success("sed", summary, "No matches; nothing changed"));
+        }
+
+        let made = if dry_run { Vec::new() } else { Self::commit(&self.backup_manager()?, changes)? };
+        let mut lines = Vec::new();
+        for (i, change) in changes.iter().enumerate() {
+            let mut line = format!(
+                "{}: {}",
+                change.path.display(),
+                counted(change.matches, "match", "matches")
+            );
+            if let Some(backup) = made.get(i) {
+                line.push_str(&format!(" (backup: {})", backup.backup_path.display()));
+            }
+            lines.push(line);
+        }
+
+        if dry_run {
+            lines.push(String::new());
+            lines.push(Self::combined_diff(changes));
+        }
+        Ok(FormattedResult::success("sed", summary, lines.join("\n")))
+    }
+}
+
+impl Tool for SedTool {
+    fn name(&self) -> &str {
+        "sed"
+    }
+
+    fn description(&self) -> &str {
+        "Apply a regex substitution to every match in a file, or in every file under a directory matching a glob. The replacement can use capture groups ($1, ${name}). Each file is backed up before it is rewritten; set dry_run to preview match counts and the diff."
+    }
+
+    fn parameters(&self) -> ToolParameter {
+        ToolParameter::new_object(vec![
+            (
+                "pattern".to_string(),
+                ToolParameter::new_string("Regex pattern").with_description("Regular expression to replace"),
+            ),
+            (
+                "replacement".to_string(),
+                ToolParameter::new_string("Replacement text")
+                    .with_description("Text to substitute for each match; $1 or ${name} insert capture groups"),
+            ),
+            (
+                "path".to_string(),
+                ToolParameter::new_string("File or directory")
+                    .with_description("File to edit, or directory to search with glob (defaults to current directory)"),
+            ),
+            (
+                "glob".to_string(),
+                ToolParameter::new_string("File pattern")
+                    .with_description("Files to edit under a directory path, e.g. \"**/*.rs\". Respects .gitignore"),
+            ),
+            (
+                "dry_run".to_string(),
+                ToolParameter::new_boolean("Dry run")
+                    .with_description("Report matches and the diff without writing files (default: false)"),
+            ),
+        ])
+        .with_required(&["pattern", "replacement"])
+    }
+
+    fn risk_level(&self) -> ToolRisk {
+        ToolRisk::Risky
+    }
+
+    fn classification(&self) -> Option<thunderus_core::Classification> {
+        Some(thunderus_core::Classification::new(
+            ToolRisk::Risky,
+            "Sed rewrites every match of a pattern, possibly across many files. Each file is backed up first, but the change can be broad. Requires approval.",
+        ))
+    }
+
+    fn classify_execution(&self, arguments: &Value) -> Option<thunderus_core::Classification> {
+        if arguments.get("dry_run").and_then(|v| v.as_bool()).unwrap_or(false) {
+            return Some(thunderus_core::Classification::new(
+                ToolRisk::Safe,
+                "A sed dry run only reports matches and the diff. No files are written.",
+            ));
+        }
+        self.classification()
+    }
+
+    fn preview_diff(&self, arguments: &Value) -> Option<String> {
+        let (_, changes) = Self::plan_from_arguments(arguments).ok()?;
+        let diff = Self::combined_diff(&changes);
+        (!diff.is_empty()).then_some(diff)
+    }
+
+    fn execute(&self, tool_call_id: String, arguments: &Value) -> Result<ToolResult> {
+        let dry_run = arguments.get("dry_run").and_then(|v| v.as_bool()).unwrap_or(false);
+        let path = arguments.get("path").and_then(|v| v.as_str()).unwrap_or(".");
+
+        let subject = match arguments.get("glob").and_then(|v| v.as_str()) {
+            Some(glob) => format!("{} {}", path, glob),
+            None => path.to_string(),
+        };
+
+        let (regex, changes) = Self::plan_from_arguments(arguments)?;
+        if !dry_run {
+            self.validate_read(&regex, &changes)?;
+        }
+        let result = self.apply(&changes, dry_run)?.with_subject(subject);
+
+        Ok(ToolResult::success(tool_call_id, result.to_text()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn setup() -> (TempDir, SedTool) {
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir(temp.path().join("src")).unwrap();
+        std::fs::write(temp.path().join("src/a.rs"), "let foo_1 = 1;\nlet foo_2 = 2;\n").unwrap();
+        std::fs::write(temp.path().join("src/b.rs"), "let foo_3 = 3;\n").unwrap();
+        std::fs::write(temp.path().join("src/notes.md"), "foo_4\n").unwrap();
+        let tool = SedTool::with_backup_dir(temp.path().join("backups"));
+        (temp, tool)
+    }
+
+    fn run(tool: &SedTool, args: Value) -> Result<String> {
+        tool.execute("call_sed".to_string(), &args).map(|result| result.content)
+    }
+
+    #[test]
+    fn test_sed_tool_properties() {
+        let tool = SedTool::default();
+        assert_eq!(tool.name(), "sed");
+        assert!(tool.risk_level().is_risky());
+        assert!(!tool.is_read_only());
+        assert!(tool.classification().unwrap().reasoning.contains("backed up"));
+
+        let args = serde_json::json!({"pattern": "a", "replacement": "b"});
+        assert!(tool.classify_execution(&args).unwrap().risk.is_risky());
+        let dry_run = serde_json::json!({"pattern": "a", "replacement": "b", "dry_run": true});
+        assert!(tool.classify_execution(&dry_run).unwrap().risk.is_safe());
+    }
+
+    #[test]
+    fn test_sed_requires_matched_lines_read() {
+        let (temp, tool) = setup();
+        let history = ReadHistory::new();
+        let tool = tool.with_read_history(history.clone());
+        let file = temp.path().join("src/a.rs");
+        let file_path = file.to_string_lossy().to_string();
+        let args = serde_json::json!({"pattern": "foo_2", "replacement": "bar", "path": file_path});
+
+        let err = run(&tool, args.clone()).unwrap_err();
+        assert!(err.to_string().contains("must be read"), "{}", err);
+
+        history.record_read(&file_path, 1, 0);
+        let err = run(&tool, args.clone()).unwrap_err();
+        assert!(err.to_string().contains("Cannot edit lines 2-2"), "{}", err);
+
+        history.record_read(&file_path, 1, 1);
+        run(&tool, args).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&file).unwrap(),
+            "let foo_1 = 1;\nlet bar = 2;\n"
+        );
+        assert!(!temp.path().join("src").read_dir().unwrap().any(|entry| {
+            let name = entry.unwrap().file_name();
+            name.to_string_lossy().contains(".sed-")
+        }));
+    }
+
+    #[test]
+    fn test_sed_single_file_with_capture_groups() {
+        let (temp, tool) = setup();
+        let file = temp.path().join("src/a.rs");
+
+        let output = run(
+            &tool,
+            serde_json::json!({"pattern": r"foo_(\d)", "replacement": "bar_${1}x", "path": file.to_string_lossy()}),
+        )
+        .unwrap();
+
+        assert!(output.contains("2 matches in 1 file"), "{}", output);
+        assert_eq!(
+            std::fs::read_to_string(&file).unwrap(),
+            "let bar_1x = 1;\nlet bar_2x = 2;\n"
+        );
+        let backups: Vec<_> = std::fs::read_dir(temp.path().join("backups")).unwrap().collect();
+        assert_eq!(backups.len(), 1);
+    }
+
+    #[test]
+    fn test_sed_glob_reports_per_file_counts() {
+        let (temp, tool) = setup();
+
+        let output = run(
+            &tool,
+            serde_json::json!({"pattern": "foo", "replacement": "baz", "path": temp.path().to_string_lossy(), "glob": "**/*.rs"}),
+        )
+        .unwrap();
+
+        assert!(output.contains("3 matches in 2 files"), "{}", output);
+        assert!(output.contains("a.rs: 2 matches"));
+        assert!(output.contains("b.rs: 1 match"));
+        assert_eq!(
+            std::fs::read_to_string(temp.path().join("src/notes.md")).unwrap(),
+            "foo_4\n"
+        );
+        assert_eq!(std::fs::read_dir(temp.path().join("backups")).unwrap().count(), 2);
+    }
+
+    #[test]
+    fn test_sed_dry_run_and_preview_leave_files_untouched() {
+        let (temp, tool) = setup();
+        let args = serde_json::json!({
+            "pattern": "foo",
+            "replacement": "baz",
+            "path": temp.path().to_string_lossy(),
+            "glob": "**/*.rs",
+            "dry_run": true,
+        });
+
+        let output = run(&tool, args.clone()).unwrap();
+        assert!(output.contains("(dry run)"));
+        assert!(output.contains("+let baz_3 = 3;"));
+
+        let diff = tool.preview_diff(&args).unwrap();
+        assert!(diff.contains("-let foo_1 = 1;"));
+        assert!(diff.contains("+let baz_3 = 3;"));
+
+        assert!(
+            std::fs::read_to_string(temp.path().join("src/a.rs"))
+                .unwrap()
+                .contains("foo_1")
+        );
+        assert!(!temp.path().join("backups").exists());
+    }
+
+    #[test]
+    fn test_sed_validation_errors() {
+        let (temp, tool) = setup();
+        let dir = temp.path().to_string_lossy();
+
+        let err = run(
+            &tool,
+            serde_json::json!({"pattern": "(", "replacement": "", "path": dir}),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Invalid regex"));
+
+        let err = run(
+            &tool,
+            serde_json::json!({"pattern": "foo", "replacement": "", "path": dir}),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Pass a glob"));
+
+        let err = run(&tool, serde_json::json!({"pattern": "foo"})).unwrap_err();
+        assert!(err.to_string().contains("replacement"));
+
+        let output = run(
+            &tool,
+            serde_json::json!({"pattern": "absent", "replacement": "", "path": dir, "glob": "**/*"}),
+        )
+        .unwrap();
+        assert!(output.contains("No matches"));
+    }
+}
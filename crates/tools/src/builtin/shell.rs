@@ -1,16 +1,36 @@
 use serde_json::Value;
-use std::process::{Command, Stdio};
+use std::io::Read;
+use std::process::{Child, Command, Stdio};
+use std::thread::JoinHandle;
+use std::time::Duration;
 use thunderus_core::{Classification, Result, ToolRisk};
-use thunderus_providers::ToolResult;
+use thunderus_providers::{CancelToken, ToolResult};
 
-use crate::Tool;
-use crate::classification::CommandClassifier;
+use crate::classification::{CommandClassifier, is_read_only_command};
+use crate::result_formatting::counted;
+use crate::{FormattedResult, Tool};
 
 /// A tool that executes shell commands with approval gating
 /// Provides shell command execution for the composer's !cmd functionality
 #[derive(Debug)]
 pub struct ShellTool;
 
+/// Longest command shown in a result header before it is shortened
+const COMMAND_LABEL_WIDTH: usize = 60;
+
+impl ShellTool {
+    /// First line of the command, shortened for the result header
+    fn command_label(command: &str) -> String {
+        let first_line = command.lines().next().unwrap_or_default();
+        if first_line.chars().count() > COMMAND_LABEL_WIDTH || command.lines().nth(1).is_some() {
+            let short: String = first_line.chars().take(COMMAND_LABEL_WIDTH).collect();
+            format!("{}…", short.trim_end())
+        } else {
+            first_line.to_string()
+        }
+    }
+}
+
 impl Tool for ShellTool {
     fn name(&self) -> &str {
         "shell"
@@ -26,6 +46,7 @@ impl Tool for ShellTool {
             thunderus_providers::ToolParameter::new_string("The shell command to execute")
                 .with_description("Any valid shell command"),
         )])
+        .with_required(&["command"])
     }
 
     fn risk_level(&self) -> ToolRisk {
@@ -46,46 +67,173 @@ impl Tool for ShellTool {
     }
 
     fn execute(&self, tool_call_id: String, arguments: &Value) -> Result<ToolResult> {
+        self.execute_cancellable(tool_call_id, arguments, &CancelToken::new())
+    }
+
+    /// Runs the command, killing its whole process group if `cancel_token` is cancelled
+    fn execute_cancellable(
+        &self, tool_call_id: String, arguments: &Value, cancel_token: &CancelToken,
+    ) -> Result<ToolResult> {
         let command = arguments
             .get("command")
             .and_then(|v| v.as_str())
             .ok_or_else(|| thunderus_core::Error::Tool("Missing or invalid 'command' parameter".to_string()))?;
 
-        let output = Command::new("sh")
-            .arg("-c")
-            .arg(command)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .output();
-
-        match output {
-            Ok(result) => {
-                let stdout = String::from_utf8_lossy(&result.stdout).to_string();
-                let stderr = String::from_utf8_lossy(&result.stderr).to_string();
-                let exit_code = result.status.code().unwrap_or(-1);
-
-                let content = if !stderr.is_empty() && exit_code != 0 {
-                    format!(
-                        "Command failed with exit code {}\n\nSTDERR:\n{}\n\nSTDOUT:\n{}",
-                        exit_code, stderr, stdout
-                    )
-                } else if !stderr.is_empty() {
-                    format!(
-                        "Command completed with warnings\n\nSTDERR:\n{}\n\nSTDOUT:\n{}",
-                        stderr, stdout
-                    )
-                } else {
-                    stdout
-                };
-
-                Ok(ToolResult::success(tool_call_id, content))
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c").arg(command).stdout(Stdio::piped()).stderr(Stdio::piped());
+        #[cfg(unix)]
+        std::os::unix::process::CommandExt::process_group(&mut cmd, 0);
+
+        let mut child = match cmd.spawn() {
+            Ok(child) => child,
+            Err(e) => {
+                return Ok(ToolResult::error(
+                    tool_call_id,
+                    format!("Failed to execute command '{}': {}", command, e),
+                ));
             }
-            Err(e) => Ok(ToolResult::error(
+        };
+
+        let stdout = read_pipe(child.stdout.take());
+        let stderr = read_pipe(child.stderr.take());
+
+        let status = loop {
+            if cancel_token.is_cancelled() {
+                kill_process_group(&mut child);
+                return Ok(ToolResult::cancelled(tool_call_id));
+            }
+
+            match child.try_wait() {
+                Ok(Some(status)) => break status,
+                Ok(None) => std::thread::sleep(CANCEL_POLL_INTERVAL),
+                Err(e) => {
+                    kill_process_group(&mut child);
+                    return Ok(ToolResult::error(
+                        tool_call_id,
+                        format!("Failed to execute command '{}': {}", command, e),
+                    ));
+                }
+            }
+        };
+
+        let stdout = stdout.join().unwrap_or_default();
+        let stderr = stderr.join().unwrap_or_default();
+        let exit_code = status.code().unwrap_or(-1);
+
+        let summary = format!(
+            "exit {}, {} of output",
+            exit_code,
+            counted(stdout.lines().count(), "line", "lines")
+        );
+        let content = if !stderr.is_empty() && exit_code != 0 {
+            format!(
+                "Command failed with exit code {}\n\nSTDERR:\n{}\n\nSTDOUT:\n{}",
+                exit_code, stderr, stdout
+            )
+        } else if !stderr.is_empty() {
+            format!(
+                "Command completed with warnings\n\nSTDERR:\n{}\n\nSTDOUT:\n{}",
+                stderr, stdout
+            )
+        } else {
+            stdout
+        };
+        let result = FormattedResult::with_exit_code("shell", summary, content, exit_code)
+            .with_subject(format!("`{}`", Self::command_label(command)));
+
+        Ok(ToolResult::success(tool_call_id, result.to_text()))
+    }
+}
+
+/// Shell tool for read-only sessions
+///
+/// Registered as `shell` in place of [ShellTool] so the agent can still
+/// inspect the workspace, but any command that is not provably read-only
+/// (see [is_read_only_command]) is refused without being run.
+#[derive(Debug)]
+pub struct ReadOnlyShellTool;
+
+impl Tool for ReadOnlyShellTool {
+    fn name(&self) -> &str {
+        "shell"
+    }
+
+    fn description(&self) -> &str {
+        "Execute read-only shell commands locally (ls, cat, grep, rg, find, git log/diff/status, ...). \
+         Commands that could modify files, including redirection, are refused."
+    }
+
+    fn parameters(&self) -> thunderus_providers::ToolParameter {
+        ShellTool.parameters()
+    }
+
+    fn risk_level(&self) -> ToolRisk {
+        ToolRisk::Safe
+    }
+
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
+    fn classification(&self) -> Option<Classification> {
+        Some(Classification::new(
+            ToolRisk::Safe,
+            "Read-only shell: only allowlisted inspection commands run; anything that could write is refused.",
+        ))
+    }
+
+    fn classify_execution(&self, arguments: &Value) -> Option<Classification> {
+        ShellTool.classify_execution(arguments)
+    }
+
+    fn execute(&self, tool_call_id: String, arguments: &Value) -> Result<ToolResult> {
+        self.execute_cancellable(tool_call_id, arguments, &CancelToken::new())
+    }
+
+    fn execute_cancellable(
+        &self, tool_call_id: String, arguments: &Value, cancel_token: &CancelToken,
+    ) -> Result<ToolResult> {
+        let command = arguments
+            .get("command")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| thunderus_core::Error::Tool("Missing or invalid 'command' parameter".to_string()))?;
+
+        if !is_read_only_command(command) {
+            return Ok(ToolResult::error(
                 tool_call_id,
-                format!("Failed to execute command '{}': {}", command, e),
-            )),
+                format!(
+                    "Command '{}' refused: this session is read-only and only runs commands that cannot modify files",
+                    command
+                ),
+            ));
+        }
+
+        ShellTool.execute_cancellable(tool_call_id, arguments, cancel_token)
+    }
+}
+
+/// How often a running command checks for cancellation
+const CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Drain a child pipe on a background thread so the child never blocks on a full pipe
+fn read_pipe<R: Read + Send + 'static>(pipe: Option<R>) -> JoinHandle<String> {
+    std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buf);
         }
+        String::from_utf8_lossy(&buf).to_string()
+    })
+}
+
+/// Kill the command and anything it spawned, then reap it
+fn kill_process_group(child: &mut Child) {
+    #[cfg(unix)]
+    unsafe {
+        libc::kill(-(child.id() as libc::pid_t), libc::SIGKILL);
     }
+    let _ = child.kill();
+    let _ = child.wait();
 }
 
 #[cfg(test)]
@@ -126,7 +274,10 @@ mod tests {
         let tool_result = result.unwrap();
         assert_eq!(tool_result.tool_call_id, "call_shell_123");
         assert!(tool_result.is_success());
-        assert_eq!(tool_result.content, "Hello, shell!\n");
+        assert_eq!(
+            tool_result.content,
+            "shell `echo 'Hello, shell!'` — exit 0, 1 line of output\n\nHello, shell!\n"
+        );
     }
 
     #[test]
@@ -169,7 +320,46 @@ mod tests {
         let tool_result = result.unwrap();
         assert_eq!(tool_result.tool_call_id, "call_shell_fail");
         assert!(tool_result.is_success());
-        assert!(tool_result.content.trim().is_empty() || tool_result.content.contains("exit code"));
+        assert_eq!(
+            tool_result.content,
+            "shell `exit 42` failed — exit 42, 0 lines of output"
+        );
+    }
+
+    #[test]
+    fn test_shell_execute_cancelled() {
+        let tool = ShellTool;
+        let args = serde_json::json!({"command": "sleep 30; echo done"});
+        let cancel_token = CancelToken::new();
+
+        let canceller = cancel_token.clone();
+        let handle = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(200));
+            canceller.cancel();
+        });
+
+        let start = std::time::Instant::now();
+        let result = tool
+            .execute_cancellable("call_shell_cancel".to_string(), &args, &cancel_token)
+            .unwrap();
+        handle.join().unwrap();
+
+        assert!(result.is_cancelled());
+        assert!(!result.is_success());
+        assert!(start.elapsed() < Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_shell_execute_already_cancelled() {
+        let tool = ShellTool;
+        let args = serde_json::json!({"command": "echo never"});
+        let cancel_token = CancelToken::new();
+        cancel_token.cancel();
+
+        let result = tool
+            .execute_cancellable("call_shell_precancel".to_string(), &args, &cancel_token)
+            .unwrap();
+        assert!(result.is_cancelled());
     }
 
     #[test]
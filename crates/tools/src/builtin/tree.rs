@@ -0,0 +1,315 @@
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use thunderus_core::{Result, ToolRisk};
+use thunderus_providers::{ToolParameter, ToolResult};
+
+use crate::result_formatting::counted;
+use crate::{FormattedResult, Tool};
+
+/// Depth used when the caller does not pass `max_depth`
+const DEFAULT_MAX_DEPTH: usize = 3;
+
+/// Most entries a single tree will list before it is truncated
+pub const MAX_TREE_ENTRIES: usize = 500;
+
+/// A single directory or file below the tree root
+#[derive(Debug, Clone)]
+struct TreeEntry {
+    depth: usize,
+    name: String,
+    is_dir: bool,
+}
+
+/// A tool that renders a depth-limited directory tree
+///
+/// Gives the agent a bird's-eye view of a directory in one call instead of a
+/// series of globs. Uses the same .gitignore-aware walk as Glob and Find, and
+/// keeps at most [`MAX_TREE_ENTRIES`] entries, shallowest first, so a large
+/// repository can't flood the context.
+#[derive(Debug)]
+pub struct TreeTool;
+
+impl TreeTool {
+    /// Validates that the path exists and is a directory
+    fn validate_path(path: &Path) -> Result<()> {
+        if !path.exists() {
+            return Err(thunderus_core::Error::Validation(format!(
+                "Path does not exist: {}",
+                path.display()
+            )));
+        }
+        if !path.is_dir() {
+            return Err(thunderus_core::Error::Validation(format!(
+                "Path is a file, not a directory: {}",
+                path.display()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Walks `root` in name order, returning the entries and whether any were dropped
+    ///
+    /// Over the cap, whole levels are kept from the top down, so one deep
+    /// subtree early in name order can't crowd out its siblings.
+    fn collect_entries(root: &Path, max_depth: usize, show_files: bool) -> (Vec<TreeEntry>, bool) {
+        let mut builder = thunderus_core::workspace_walker(root);
+        builder.max_depth(Some(max_depth)).sort_by_file_name(|a, b| a.cmp(b));
+
+        let mut entries = Vec::new();
+        for entry in builder.build().flatten() {
+            if entry.depth() == 0 {
+                continue;
+            }
+            let is_dir = entry.file_type().is_some_and(|t| t.is_dir());
+            if !is_dir && !show_files {
+                continue;
+            }
+            entries.push(TreeEntry {
+                depth: entry.depth(),
+                name: entry.file_name().to_string_lossy().to_string(),
+                is_dir,
+            });
+        }
+        if entries.len() <= MAX_TREE_ENTRIES {
+            return (entries, false);
+        }
+
+        let mut order: Vec<usize> = (0..entries.len()).collect();
+        order.sort_by_key(|&index| entries[index].depth);
+        let mut keep = vec![false; entries.len()];
+        for &index in &order[..MAX_TREE_ENTRIES] {
+            keep[index] = true;
+        }
+        let mut keep = keep.into_iter();
+        entries.retain(|_| keep.next().unwrap_or(false));
+        (entries, true)
+    }
+
+    /// Draws entries with box-drawing connectors, one per line
+    fn render(entries: &[TreeEntry]) -> Vec<String> {
+        let is_last = |index: usize| {
+            let depth = entries[index].depth;
+            entries[index + 1..]
+                .iter()
+                .find(|entry| entry.depth <= depth)
+                .is_none_or(|entry| entry.depth < depth)
+        };
+
+        let mut open_levels: Vec<bool> = Vec::new();
+        entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                open_levels.truncate(entry.depth - 1);
+                let last = is_last(index);
+                let indent: String = open_levels
+                    .iter()
+                    .map(|open| if *open { "│   " } else { "    " })
+                    .collect();
+                open_levels.push(!last);
+
+                let connector = if last { "└── " } else { "├── " };
+                let suffix = if entry.is_dir { "/" } else { "" };
+                format!("{}{}{}{}", indent, connector, entry.name, suffix)
+            })
+            .collect()
+    }
+
+    /// Walks the directory and formats the tree
+    fn execute_and_parse(root: &Path, max_depth: usize, show_files: bool) -> Result<FormattedResult> {
+        Self::validate_path(root)?;
+
+        let (entries, truncated) = Self::collect_entries(root, max_depth, show_files);
+        let dirs = entries.iter().filter(|entry| entry.is_dir).count();
+        let files = entries.len() - dirs;
+
+        let mut summary = counted(dirs, "directory", "directories");
+        if show_files {
+            summary.push_str(&format!(", {}", counted(files, "file", "files")));
+        }
+
+        let mut lines = vec![format!("{}/", root.display().to_string().trim_end_matches('/'))];
+        lines.extend(Self::render(&entries));
+        if truncated {
+            lines.push(format!(
+                "... truncated at {} entries; use a smaller max_depth or a subdirectory path",
+                MAX_TREE_ENTRIES
+            ));
+        }
+
+        Ok(FormattedResult::success("tree", summary, lines.join("\n")))
+    }
+}
+
+impl Tool for TreeTool {
+    fn name(&self) -> &str {
+        "tree"
+    }
+
+    fn description(&self) -> &str {
+        "Show the directory structure as a depth-limited tree. Respects .gitignore. Use it for an overview of a repository or directory before searching."
+    }
+
+    fn parameters(&self) -> ToolParameter {
+        ToolParameter::new_object(vec![
+            (
+                "path".to_string(),
+                ToolParameter::new_string("Root directory")
+                    .with_description("Directory to show (defaults to current directory)"),
+            ),
+            (
+                "max_depth".to_string(),
+                ToolParameter::new_number("Max depth")
+                    .with_description("How many levels below the root to show (default: 3)")
+                    .with_minimum(1.0),
+            ),
+            (
+                "show_files".to_string(),
+                ToolParameter::new_boolean("Show files")
+                    .with_description("Whether to list files as well as directories (default: true)"),
+            ),
+        ])
+    }
+
+    fn risk_level(&self) -> ToolRisk {
+        ToolRisk::Safe
+    }
+
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
+    fn classification(&self) -> Option<thunderus_core::Classification> {
+        Some(thunderus_core::Classification::new(
+            ToolRisk::Safe,
+            "Tree is a read-only directory listing. It only reads directory entries.",
+        ))
+    }
+
+    fn execute(&self, tool_call_id: String, arguments: &Value) -> Result<ToolResult> {
+        let path_str = arguments.get("path").and_then(|v| v.as_str()).unwrap_or(".");
+        let max_depth = arguments
+            .get("max_depth")
+            .and_then(|v| v.as_u64())
+            .map(|v| v as usize)
+            .unwrap_or(DEFAULT_MAX_DEPTH);
+        let show_files = arguments.get("show_files").and_then(|v| v.as_bool()).unwrap_or(true);
+
+        let path = PathBuf::from(path_str);
+        let result = Self::execute_and_parse(&path, max_depth, show_files)?.with_subject(path_str);
+
+        Ok(ToolResult::success(tool_call_id, result.to_text()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn setup() -> TempDir {
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join("src/bin")).unwrap();
+        std::fs::create_dir(temp.path().join("docs")).unwrap();
+        std::fs::write(temp.path().join("Cargo.toml"), "").unwrap();
+        std::fs::write(temp.path().join("src/lib.rs"), "").unwrap();
+        std::fs::write(temp.path().join("src/bin/main.rs"), "").unwrap();
+        std::fs::write(temp.path().join("docs/guide.md"), "").unwrap();
+        temp
+    }
+
+    fn run(temp: &TempDir, mut args: Value) -> (String, String) {
+        args["path"] = Value::String(temp.path().to_string_lossy().to_string());
+        let result = TreeTool.execute("call_tree".to_string(), &args).unwrap();
+        assert!(result.is_success());
+        let (header, body) = result.content.split_once("\n\n").unwrap_or((&result.content, ""));
+        (header.to_string(), body.lines().skip(1).collect::<Vec<_>>().join("\n"))
+    }
+
+    #[test]
+    fn test_tree_tool_properties() {
+        let tool = TreeTool;
+        assert_eq!(tool.name(), "tree");
+        assert!(tool.risk_level().is_safe());
+        assert!(tool.is_read_only());
+        assert!(tool.classification().unwrap().reasoning.contains("read-only"));
+    }
+
+    #[test]
+    fn test_tree_renders_structure() {
+        let temp = setup();
+        let (header, tree) = run(&temp, serde_json::json!({}));
+        assert!(header.contains("3 directories, 4 files"), "{}", header);
+        assert_eq!(
+            tree,
+            [
+                "├── Cargo.toml",
+                "├── docs/",
+                "│   └── guide.md",
+                "└── src/",
+                "    ├── bin/",
+                "    │   └── main.rs",
+                "    └── lib.rs",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_tree_depth_and_dirs_only() {
+        let temp = setup();
+        let (_, tree) = run(&temp, serde_json::json!({"max_depth": 1}));
+        assert_eq!(tree, "├── Cargo.toml\n├── docs/\n└── src/");
+
+        let (header, tree) = run(&temp, serde_json::json!({"show_files": false}));
+        assert!(header.contains("3 directories"));
+        assert!(!header.contains("files"));
+        assert_eq!(tree, "├── docs/\n└── src/\n    └── bin/");
+    }
+
+    #[test]
+    fn test_tree_respects_gitignore() {
+        let temp = setup();
+        std::fs::create_dir(temp.path().join(".git")).unwrap();
+        std::fs::write(temp.path().join(".gitignore"), "docs/\n").unwrap();
+
+        let (_, tree) = run(&temp, serde_json::json!({"max_depth": 1}));
+        assert!(!tree.contains("docs"));
+    }
+
+    #[test]
+    fn test_tree_truncates_large_directories() {
+        let temp = TempDir::new().unwrap();
+        for i in 0..MAX_TREE_ENTRIES + 10 {
+            std::fs::write(temp.path().join(format!("file_{:04}.txt", i)), "").unwrap();
+        }
+
+        let (header, tree) = run(&temp, serde_json::json!({}));
+        assert!(header.contains(&format!("{} files", MAX_TREE_ENTRIES)));
+        assert!(tree.lines().last().unwrap().contains("truncated"));
+    }
+
+    #[test]
+    fn test_tree_truncation_keeps_shallow_entries() {
+        let temp = TempDir::new().unwrap();
+        let deep = temp.path().join("a_deep");
+        std::fs::create_dir(&deep).unwrap();
+        for i in 0..MAX_TREE_ENTRIES {
+            std::fs::write(deep.join(format!("file_{:04}.txt", i)), "").unwrap();
+        }
+        std::fs::create_dir(temp.path().join("b_sibling")).unwrap();
+        std::fs::write(temp.path().join("c_top.txt"), "").unwrap();
+
+        let (_, tree) = run(&temp, serde_json::json!({}));
+        assert!(tree.contains("a_deep/"));
+        assert!(tree.contains("b_sibling/"));
+        assert!(tree.contains("└── c_top.txt"));
+        assert!(tree.lines().last().unwrap().contains("truncated"));
+    }
+
+    #[test]
+    fn test_tree_invalid_path() {
+        let result = TreeTool.execute("c".to_string(), &serde_json::json!({"path": "/nonexistent/path/xyz"}));
+        assert!(result.unwrap_err().to_string().contains("Path does not exist"));
+    }
+}
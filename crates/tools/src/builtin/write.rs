@@ -3,7 +3,9 @@ use std::path::{Path, PathBuf};
 use thunderus_core::{Result, ToolRisk};
 use thunderus_providers::{ToolParameter, ToolResult};
 
-use crate::Tool;
+use crate::patch_generator;
+use crate::result_formatting::counted;
+use crate::{FormattedResult, Tool};
 
 /// Tool for direct file writing (escape hatch, heavily gated)
 ///
@@ -39,13 +41,17 @@ impl WriteTool {
     }
 
     /// Writes content directly to the file
-    fn write_file(path: &Path, content: &str) -> Result<String> {
+    fn write_file(path: &Path, content: &str) -> Result<FormattedResult> {
         std::fs::write(path, content)
             .map_err(|e| thunderus_core::Error::Tool(format!("Failed to write file '{}': {}", path.display(), e)))?;
 
-        Ok(format!(
-            "Successfully wrote file: {}\n\n⚠️  WARNING: Direct file writes bypass the patch system and cannot be rolled back through the patch queue.",
-            path.display()
+        Ok(FormattedResult::success(
+            "write",
+            format!("{} written", counted(content.lines().count(), "line", "lines")),
+            format!(
+                "Successfully wrote file: {}\n\n⚠️  WARNING: Direct file writes bypass the patch system and cannot be rolled back through the patch queue.",
+                path.display()
+            ),
         ))
     }
 }
@@ -77,6 +83,7 @@ impl Tool for WriteTool {
                     .with_description("Explanation of why patch-based editing cannot be used for this operation"),
             ),
         ])
+        .with_required(&["file_path", "content"])
     }
 
     fn risk_level(&self) -> ToolRisk {
@@ -90,6 +97,17 @@ impl Tool for WriteTool {
         ).with_suggestion("Use PatchTool instead for all regular file edits. Patches are reviewable, reversible, and conflict-aware."))
     }
 
+    fn preview_diff(&self, arguments: &Value) -> Option<String> {
+        let path = PathBuf::from(arguments.get("file_path")?.as_str()?);
+        let content = arguments.get("content")?.as_str()?;
+        if path.is_dir() {
+            return None;
+        }
+
+        let old_content = std::fs::read_to_string(&path).unwrap_or_default();
+        patch_generator::generate_unified_diff(&path, &old_content, content, "HEAD").ok()
+    }
+
     fn execute(&self, tool_call_id: String, arguments: &Value) -> Result<ToolResult> {
         let file_path_str = arguments
             .get("file_path")
@@ -113,8 +131,8 @@ impl Tool for WriteTool {
 
         Self::validate_path(&path)?;
 
-        let result = Self::write_file(&path, content)?;
+        let result = Self::write_file(&path, content)?.with_subject(file_path_str);
 
-        Ok(ToolResult::success(tool_call_id, result))
+        Ok(ToolResult::success(tool_call_id, result.to_text()))
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use thunderus_core::{Classification, ToolRisk};
+use thunderus_core::{Classification, RiskCategory, ToolRisk};
 
 /// Commands that run tests
 ///
@@ -37,6 +37,18 @@ const TEXT_TOOLS_WITH_FLAGS: &[(&str, &[(&str, ToolRisk)])] = &[
 /// Git read operations
 const SAFE_GIT_READ_COMMANDS: &[&str] = &["git log", "git show", "git diff", "git status"];
 
+/// Additional inspection commands allowed in read-only sessions
+const READ_ONLY_INSPECTION_COMMANDS: &[&str] = &["wc", "diff", "stat", "file", "du", "cut", "nl"];
+
+/// Git subcommands allowed in read-only sessions
+const READ_ONLY_GIT_SUBCOMMANDS: &[&str] = &["log", "show", "diff", "status", "blame", "ls-files", "rev-parse"];
+
+/// Arguments that make an otherwise read-only command write files or run other programs
+const READ_ONLY_FORBIDDEN_ARGS: &[&str] = &["-delete", "-exec", "-execdir", "-ok", "-okdir", "--output", "--pre"];
+
+/// Argument families that write files, matched by prefix (`find -fprint0`, `-fprintf`, `-fls`)
+const READ_ONLY_FORBIDDEN_ARG_PREFIXES: &[&str] = &["-fprint", "-fls"];
+
 /// Check and verify commands
 const SAFE_VERIFY_COMMANDS: &[&str] = &["check", "verify", "validate"];
 
@@ -102,6 +114,8 @@ const RISKY_GIT_WRITE_PATTERNS: &[(&str, Pattern)] = &[
     ("push", Pattern::Contains("push")),
     ("commit", Pattern::Contains("commit")),
     ("rebase", Pattern::Contains("rebase")),
+    ("reset", Pattern::Contains("git reset")),
+    ("clean", Pattern::Contains("git clean")),
 ];
 
 /// Patterns for blocked commands (always denied)
@@ -173,8 +187,22 @@ impl CommandClassifier {
     pub fn classify_with_reasoning(&self, command: &str) -> Classification {
         let command_lower = command.to_lowercase();
 
-        if let Some(reasoning) = self.check_blocked_reasoning(&command_lower) {
-            return Classification::new(ToolRisk::Blocked, reasoning);
+        if let Some(classification) = self.check_blocked_reasoning(&command_lower) {
+            return classification;
+        }
+
+        let first_word = command_lower.split_whitespace().next().unwrap_or("");
+        if let Some(classification) = shell_side_effect_classification(&command_lower) {
+            if (first_word == "sed" || first_word == "awk")
+                && let Some((text_tool, suggestion)) = self.classify_text_tool_with_flags(&command_lower)
+                && text_tool.risk.is_risky()
+            {
+                return match suggestion {
+                    Some(suggestion_text) => text_tool.with_suggestion(suggestion_text),
+                    None => text_tool,
+                };
+            }
+            return classification;
         }
 
         if command_lower.contains('|') {
@@ -189,19 +217,19 @@ impl CommandClassifier {
                         "Pipeline contains risky command '{}': {}",
                         first_word, classification.reasoning
                     );
-                    return Classification::new(ToolRisk::Risky, reasoning)
-                        .with_suggestion(suggestion.unwrap_or_default());
+                    return Classification {
+                        reasoning,
+                        suggestion: Some(suggestion.unwrap_or_default()),
+                        ..classification
+                    };
                 }
             }
 
-            let first_word = command_lower.split_whitespace().next().unwrap_or("");
-            if let Some(reasoning) = self.check_safe_reasoning(first_word, &command_lower) {
-                return Classification::new(ToolRisk::Safe, reasoning);
+            if let Some(classification) = self.check_safe_reasoning(first_word, &command_lower) {
+                return classification;
             }
         }
 
-        let first_word = command_lower.split_whitespace().next().unwrap_or("");
-
         if (first_word == "sed" || first_word == "awk")
             && let Some((classification, suggestion)) = self.classify_text_tool_with_flags(&command_lower)
         {
@@ -211,12 +239,12 @@ impl CommandClassifier {
             return classification;
         }
 
-        if let Some(reasoning) = self.check_safe_reasoning(first_word, &command_lower) {
-            return Classification::new(ToolRisk::Safe, reasoning);
+        if let Some(classification) = self.check_safe_reasoning(first_word, &command_lower) {
+            return classification;
         }
 
-        if let Some(reasoning) = self.check_risky_reasoning(first_word, &command_lower) {
-            return Classification::new(ToolRisk::Risky, reasoning);
+        if let Some(classification) = self.check_risky_reasoning(first_word, &command_lower) {
+            return classification;
         }
 
         Classification::new(
@@ -226,6 +254,7 @@ impl CommandClassifier {
                 first_word
             ),
         )
+        .with_basis(RiskCategory::Unknown, None)
     }
 
     /// Classifies a shell command string
@@ -240,130 +269,165 @@ impl CommandClassifier {
     }
 
     /// Checks if command is safe and returns reasoning
-    fn check_safe_reasoning(&self, first_word: &str, command_lower: &str) -> Option<String> {
-        if SAFE_TEST_COMMANDS.iter().any(|cmd| command_lower.contains(cmd)) {
-            return Some("Test commands are read-only and have no side effects on files or system state".to_string());
+    fn check_safe_reasoning(&self, first_word: &str, command_lower: &str) -> Option<Classification> {
+        let safe = |category, pattern: &str, reasoning: String| {
+            Some(Classification::new(ToolRisk::Safe, reasoning).with_basis(category, Some(pattern)))
+        };
+
+        if let Some(cmd) = SAFE_TEST_COMMANDS.iter().find(|cmd| command_lower.contains(*cmd)) {
+            return safe(
+                RiskCategory::Test,
+                cmd,
+                "Test commands are read-only and have no side effects on files or system state".to_string(),
+            );
         }
 
-        if SAFE_FORMATTER_COMMANDS.iter().any(|cmd| command_lower.contains(cmd)) {
-            return Some("Formatters and linters only modify code style, not behavior or functionality".to_string());
+        if let Some(cmd) = SAFE_FORMATTER_COMMANDS.iter().find(|cmd| command_lower.contains(*cmd)) {
+            return safe(
+                RiskCategory::Formatter,
+                cmd,
+                "Formatters and linters only modify code style, not behavior or functionality".to_string(),
+            );
         }
         if SAFE_TEXT_READONLY_COMMANDS.contains(&first_word) {
-            return Some(format!(
-                "Command '{}' is a text search tool that only reads and matches patterns; it does not modify files",
-                first_word
-            ));
+            return safe(
+                RiskCategory::ReadOnly,
+                first_word,
+                format!(
+                    "Command '{}' is a text search tool that only reads and matches patterns; it does not modify files",
+                    first_word
+                ),
+            );
         }
 
         if SAFE_READONLY_COMMANDS.contains(&first_word) {
-            return Some(format!(
-                "Command '{}' only reads files or displays information; it does not modify anything",
-                first_word
-            ));
+            return safe(
+                RiskCategory::ReadOnly,
+                first_word,
+                format!(
+                    "Command '{}' only reads files or displays information; it does not modify anything",
+                    first_word
+                ),
+            );
         }
 
-        if SAFE_GIT_READ_COMMANDS.iter().any(|cmd| command_lower.contains(cmd)) {
-            return Some(
+        if let Some(cmd) = SAFE_GIT_READ_COMMANDS.iter().find(|cmd| command_lower.contains(*cmd)) {
+            return safe(
+                RiskCategory::GitRead,
+                cmd,
                 "Git read-only operations (log, diff, show, status) do not modify repository state".to_string(),
             );
         }
 
         if SAFE_VERIFY_COMMANDS.contains(&first_word) {
-            return Some(format!(
-                "Command '{}' only checks or validates; it does not make any changes",
-                first_word
-            ));
+            return safe(
+                RiskCategory::Verify,
+                first_word,
+                format!(
+                    "Command '{}' only checks or validates; it does not make any changes",
+                    first_word
+                ),
+            );
         }
 
         if (first_word == "sed" || first_word == "awk")
             && let Some((classification, _suggestion)) = self.classify_text_tool_with_flags(command_lower)
             && classification.risk.is_safe()
         {
-            return Some(classification.reasoning);
+            return Some(classification);
         }
 
         None
     }
 
     /// Checks if command is risky and returns reasoning
-    fn check_risky_reasoning(&self, first_word: &str, command_lower: &str) -> Option<String> {
+    fn check_risky_reasoning(&self, first_word: &str, command_lower: &str) -> Option<Classification> {
         if (first_word == "sed" || first_word == "awk")
             && let Some((classification, _suggestion)) = self.classify_text_tool_with_flags(command_lower)
             && classification.risk.is_risky()
         {
-            return Some(classification.reasoning);
+            return Some(classification);
         }
 
         for (desc, pattern) in &self.risky_patterns {
             match pattern {
                 Pattern::Exact(cmd) if first_word == *cmd => {
-                    return Some(match desc {
-                        &"rm" | &"rmdir" | &"del" | &"shred" => {
-                            format!(
-                                "Command '{}' permanently deletes files or directories (destructive operation)",
-                                first_word
-                            )
-                        }
-                        _ => format!("Command '{}' is classified as risky because: {}", first_word, desc),
-                    });
+                    return Some(risky_classification(
+                        desc,
+                        match desc {
+                            &"rm" | &"rmdir" | &"del" | &"shred" => {
+                                format!(
+                                    "Command '{}' permanently deletes files or directories (destructive operation)",
+                                    first_word
+                                )
+                            }
+                            _ => format!("Command '{}' is classified as risky because: {}", first_word, desc),
+                        },
+                    ));
                 }
                 Pattern::Prefix(prefix) if first_word.starts_with(*prefix) => {
-                    return Some(match desc {
-                        &"rm" | &"rmdir" | &"del" | &"shred" => {
-                            format!(
-                                "Command '{}' permanently deletes files or directories (destructive operation)",
-                                first_word
-                            )
-                        }
-                        &"curl" | &"wget" | &"nc" | &"telnet" | &"ssh" | &"rsync" | &"scp" => {
-                            format!(
-                                "Command '{}' performs network operations which may transfer data to/from external systems",
-                                first_word
-                            )
-                        }
-                        &"mv" | &"cp" | &"chmod" | &"chown" | &"touch" | &"mkdir" => {
-                            format!(
-                                "Command '{}' modifies the file system structure or permissions",
-                                first_word
-                            )
-                        }
-                        &"apt-get" | &"apt" | &"yum" | &"dnf" | &"brew" => {
-                            format!(
-                                "Command '{}' is a package manager that may install software or modify system state",
-                                first_word
-                            )
-                        }
-                        &"bash" | &"zsh" | &"sh" | &"fish" | &"shell" => {
-                            format!(
-                                "Command '{}' opens an interactive shell which could execute arbitrary commands",
-                                first_word
-                            )
-                        }
-                        _ => format!("Command '{}' is classified as risky because: {}", first_word, desc),
-                    });
+                    return Some(risky_classification(
+                        desc,
+                        match desc {
+                            &"rm" | &"rmdir" | &"del" | &"shred" => {
+                                format!(
+                                    "Command '{}' permanently deletes files or directories (destructive operation)",
+                                    first_word
+                                )
+                            }
+                            &"curl" | &"wget" | &"nc" | &"telnet" | &"ssh" | &"rsync" | &"scp" => {
+                                format!(
+                                    "Command '{}' performs network operations which may transfer data to/from external systems",
+                                    first_word
+                                )
+                            }
+                            &"mv" | &"cp" | &"chmod" | &"chown" | &"touch" | &"mkdir" => {
+                                format!(
+                                    "Command '{}' modifies the file system structure or permissions",
+                                    first_word
+                                )
+                            }
+                            &"apt-get" | &"apt" | &"yum" | &"dnf" | &"brew" => {
+                                format!(
+                                    "Command '{}' is a package manager that may install software or modify system state",
+                                    first_word
+                                )
+                            }
+                            &"bash" | &"zsh" | &"sh" | &"fish" | &"shell" => {
+                                format!(
+                                    "Command '{}' opens an interactive shell which could execute arbitrary commands",
+                                    first_word
+                                )
+                            }
+                            _ => format!("Command '{}' is classified as risky because: {}", first_word, desc),
+                        },
+                    ));
                 }
                 Pattern::Contains(substr) if command_lower.contains(*substr) => {
-                    return Some(match desc {
-                        &"install" | &"uninstall" => {
-                            format!(
-                                "Command '{}' installs or removes packages which may modify dependencies or system state",
-                                first_word
-                            )
-                        }
-                        &"add" | &"remove" | &"require" | &"get" => {
-                            format!(
-                                "Command '{}' modifies dependencies (adds or removes packages)",
-                                first_word
-                            )
-                        }
-                        &"push" | &"commit" | &"rebase" => {
-                            format!(
-                                "Git command '{}' modifies repository history or pushes changes to remote",
-                                substr
-                            )
-                        }
-                        _ => format!("Command '{}' is classified as risky because it: {}", first_word, desc),
-                    });
+                    return Some(risky_classification(
+                        desc,
+                        match desc {
+                            &"install" | &"uninstall" => {
+                                format!(
+                                    "Command '{}' installs or removes packages which may modify dependencies or system state",
+                                    first_word
+                                )
+                            }
+                            &"add" | &"remove" | &"require" | &"get" => {
+                                format!(
+                                    "Command '{}' modifies dependencies (adds or removes packages)",
+                                    first_word
+                                )
+                            }
+                            &"push" | &"commit" | &"rebase" | &"reset" | &"clean" => {
+                                format!(
+                                    "Git command '{}' modifies repository history or pushes changes to remote",
+                                    substr
+                                )
+                            }
+                            _ => format!("Command '{}' is classified as risky because it: {}", first_word, desc),
+                        },
+                    ));
                 }
                 _ => {}
             }
@@ -373,65 +437,71 @@ impl CommandClassifier {
     }
 
     /// Checks if command is blocked and returns reasoning
-    fn check_blocked_reasoning(&self, command_lower: &str) -> Option<String> {
+    fn check_blocked_reasoning(&self, command_lower: &str) -> Option<Classification> {
         let first_word = command_lower.split_whitespace().next().unwrap_or("");
 
         for (desc, pattern) in &self.blocked_patterns {
             match pattern {
                 Pattern::Exact(cmd) if first_word == *cmd => {
-                    return Some(match *desc {
-                        "sudo" => format!(
-                            "Command '{}' provides superuser privileges and is blocked for security reasons",
-                            first_word
-                        ),
-                        "dd" => format!(
-                            "Command '{}' can destroy data and filesystem structure and is permanently blocked",
-                            first_word
-                        ),
-                        "mkfs" => format!(
-                            "Command '{}' creates filesystems and can destroy existing data and is permanently blocked",
-                            first_word
-                        ),
-                        "format" => format!(
-                            "Command '{}' formats disks and destroys all data and is permanently blocked",
-                            first_word
-                        ),
-                        "fdisk" => format!(
-                            "Command '{}' modifies disk partitions and can destroy data and is permanently blocked",
-                            first_word
-                        ),
-                        _ => format!("Command '{}' is blocked for security reasons: {}", first_word, desc),
-                    });
+                    return Some(blocked_classification(
+                        desc,
+                        match *desc {
+                            "sudo" => format!(
+                                "Command '{}' provides superuser privileges and is blocked for security reasons",
+                                first_word
+                            ),
+                            "dd" => format!(
+                                "Command '{}' can destroy data and filesystem structure and is permanently blocked",
+                                first_word
+                            ),
+                            "mkfs" => format!(
+                                "Command '{}' creates filesystems and can destroy existing data and is permanently blocked",
+                                first_word
+                            ),
+                            "format" => format!(
+                                "Command '{}' formats disks and destroys all data and is permanently blocked",
+                                first_word
+                            ),
+                            "fdisk" => format!(
+                                "Command '{}' modifies disk partitions and can destroy data and is permanently blocked",
+                                first_word
+                            ),
+                            _ => format!("Command '{}' is blocked for security reasons: {}", first_word, desc),
+                        },
+                    ));
                 }
                 Pattern::Prefix(prefix) if first_word.starts_with(*prefix) => {
-                    return Some(match *desc {
-                        "sudo" => format!(
-                            "Command '{}' provides superuser privileges and is blocked for security reasons",
-                            first_word
-                        ),
-                        "dd" => format!(
-                            "Command '{}' can destroy data and filesystem structure and is permanently blocked",
-                            first_word
-                        ),
-                        "mkfs" => format!(
-                            "Command '{}' creates filesystems and can destroy existing data and is permanently blocked",
-                            first_word
-                        ),
-                        "format" => format!(
-                            "Command '{}' formats disks and destroys all data and is permanently blocked",
-                            first_word
-                        ),
-                        "fdisk" => format!(
-                            "Command '{}' modifies disk partitions and can destroy data and is permanently blocked",
-                            first_word
-                        ),
-                        _ => format!("Command '{}' is blocked for security reasons: {}", first_word, desc),
-                    });
+                    return Some(blocked_classification(
+                        desc,
+                        match *desc {
+                            "sudo" => format!(
+                                "Command '{}' provides superuser privileges and is blocked for security reasons",
+                                first_word
+                            ),
+                            "dd" => format!(
+                                "Command '{}' can destroy data and filesystem structure and is permanently blocked",
+                                first_word
+                            ),
+                            "mkfs" => format!(
+                                "Command '{}' creates filesystems and can destroy existing data and is permanently blocked",
+                                first_word
+                            ),
+                            "format" => format!(
+                                "Command '{}' formats disks and destroys all data and is permanently blocked",
+                                first_word
+                            ),
+                            "fdisk" => format!(
+                                "Command '{}' modifies disk partitions and can destroy data and is permanently blocked",
+                                first_word
+                            ),
+                            _ => format!("Command '{}' is blocked for security reasons: {}", first_word, desc),
+                        },
+                    ));
                 }
                 Pattern::Contains(substr) if command_lower.contains(*substr) => {
-                    return Some(format!(
-                        "Command '{}' is blocked for security reasons: {}",
-                        first_word, desc
+                    return Some(blocked_classification(
+                        desc,
+                        format!("Command '{}' is blocked for security reasons: {}", first_word, desc),
                     ));
                 }
                 _ => {}
@@ -491,8 +561,13 @@ impl CommandClassifier {
                 }
             })
             .collect();
+        let matched = if risky_flags.is_empty() {
+            first_word.to_string()
+        } else {
+            format!("{} {}", first_word, risky_flags.join("/"))
+        };
 
-        match *first_word {
+        let classified = match *first_word {
             "sed" => {
                 if !risky_flags.is_empty() {
                     Some((
@@ -543,10 +618,99 @@ impl CommandClassifier {
                 }
             }
             _ => None,
-        }
+        };
+        classified.map(|(classification, suggestion)| {
+            (
+                classification.with_basis(RiskCategory::TextTool, Some(&matched)),
+                suggestion,
+            )
+        })
     }
 }
 
+/// Classification for a matched risky pattern, tagged with the list the pattern came from
+fn risky_classification(desc: &str, reasoning: String) -> Classification {
+    let lists = [
+        (RISKY_DELETION_PATTERNS, RiskCategory::Deletion),
+        (RISKY_PACKAGE_PATTERNS, RiskCategory::Package),
+        (RISKY_FILEMODIFY_PATTERNS, RiskCategory::FileModify),
+        (RISKY_NETWORK_PATTERNS, RiskCategory::Network),
+        (RISKY_SHELL_PATTERNS, RiskCategory::Shell),
+        (RISKY_GIT_WRITE_PATTERNS, RiskCategory::GitWrite),
+    ];
+    let category = lists
+        .iter()
+        .find(|(patterns, _)| patterns.iter().any(|(name, _)| *name == desc))
+        .map(|(_, category)| *category)
+        .unwrap_or(RiskCategory::Custom);
+    Classification::new(ToolRisk::Risky, reasoning).with_basis(category, Some(desc))
+}
+
+/// Redirections that neither write files nor discard meaningful output
+const HARMLESS_REDIRECTIONS: &[&str] = &[
+    "2>&1",
+    "1>&2",
+    ">&2",
+    ">&1",
+    "2>/dev/null",
+    "2> /dev/null",
+    ">/dev/null",
+    "> /dev/null",
+];
+
+/// Classify shell syntax that writes files or runs hidden commands, whatever the program
+///
+/// Output redirection and `tee` write files, command substitution runs commands
+/// the pattern lists never see, and `&` starts one in the background, so any of
+/// them makes an otherwise safe command risky.
+fn shell_side_effect_classification(command: &str) -> Option<Classification> {
+    let stripped = HARMLESS_REDIRECTIONS
+        .iter()
+        .fold(command.to_string(), |stripped, redirection| {
+            stripped.replace(redirection, "")
+        });
+
+    let tees = stripped
+        .split(['|', ';'])
+        .flat_map(|part| part.split("&&"))
+        .any(|segment| segment.split_whitespace().next() == Some("tee"));
+
+    let (category, pattern, reasoning) = if stripped.contains('>') {
+        (
+            RiskCategory::FileModify,
+            ">",
+            "Command redirects output into a file, which creates or overwrites it",
+        )
+    } else if tees {
+        (
+            RiskCategory::FileModify,
+            "tee",
+            "Command pipes output through 'tee', which writes it to files",
+        )
+    } else if stripped.contains("$(") || stripped.contains('`') {
+        (
+            RiskCategory::Shell,
+            "$(",
+            "Command substitution runs nested commands that are not classified",
+        )
+    } else if stripped.replace("&&", "").contains('&') {
+        (
+            RiskCategory::Shell,
+            "&",
+            "Command starts a background process that keeps running unsupervised",
+        )
+    } else {
+        return None;
+    };
+
+    Some(Classification::new(ToolRisk::Risky, reasoning.to_string()).with_basis(category, Some(pattern)))
+}
+
+/// Classification for a matched blocked pattern
+fn blocked_classification(desc: &str, reasoning: String) -> Classification {
+    Classification::new(ToolRisk::Blocked, reasoning).with_basis(RiskCategory::Blocked, Some(desc))
+}
+
 impl Default for CommandClassifier {
     fn default() -> Self {
         Self::new()
@@ -569,6 +733,98 @@ pub fn classify_shell_command_risk(command: &str) -> ToolRisk {
     classify_shell_command(command).risk
 }
 
+/// Check whether a shell command is guaranteed not to modify anything
+///
+/// Unlike [CommandClassifier], which defaults unknown commands to safe, this is
+/// a strict allowlist used when a session must not mutate the workspace:
+/// every command in a pipeline or `&&`/`||`/`;` chain must be a known
+/// read-only command, and redirection, command substitution and backgrounding
+/// are rejected outright. Words are split the way the shell would for plain
+/// and quoted text; escapes and variable expansion can't be checked and are
+/// rejected.
+pub fn is_read_only_command(command: &str) -> bool {
+    if ['>', '`', '&'].iter().any(|c| command.replace("&&", "").contains(*c)) || command.contains("$(") {
+        return false;
+    }
+
+    command
+        .split(['|', ';', '\n'])
+        .flat_map(|part| part.split("&&"))
+        .map(str::trim)
+        .filter(|segment| !segment.is_empty())
+        .all(|segment| {
+            let Some(words) = shell_words(segment) else {
+                return false;
+            };
+            if words.iter().any(|word| {
+                READ_ONLY_FORBIDDEN_ARGS
+                    .iter()
+                    .any(|arg| word == arg || word.starts_with(&format!("{}=", arg)))
+                    || READ_ONLY_FORBIDDEN_ARG_PREFIXES
+                        .iter()
+                        .any(|prefix| word.starts_with(prefix))
+            }) {
+                return false;
+            }
+
+            let words: Vec<&str> = words.iter().map(String::as_str).collect();
+            match words.as_slice() {
+                ["git", subcommand, ..] => READ_ONLY_GIT_SUBCOMMANDS.contains(subcommand),
+                [first, ..] => {
+                    SAFE_READONLY_COMMANDS.contains(first)
+                        || SAFE_TEXT_READONLY_COMMANDS.contains(first)
+                        || READ_ONLY_INSPECTION_COMMANDS.contains(first)
+                }
+                [] => true,
+            }
+        })
+}
+
+/// Split a command segment into words with quotes removed, as the shell would
+///
+/// Returns `None` for anything whose final words can't be known statically:
+/// backslash escapes, `$` expansion outside single quotes, or an unterminated
+/// quote.
+fn shell_words(segment: &str) -> Option<Vec<String>> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut in_word = false;
+    let mut quote: Option<char> = None;
+
+    for c in segment.chars() {
+        match quote {
+            Some('\'') if c == '\'' => quote = None,
+            Some('\'') => current.push(c),
+            Some(_) if c == '"' => quote = None,
+            Some(_) if c == '\\' || c == '$' => return None,
+            Some(_) => current.push(c),
+            None if c == '\\' || c == '$' => return None,
+            None if c == '\'' || c == '"' => {
+                quote = Some(c);
+                in_word = true;
+            }
+            None if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut current));
+                    in_word = false;
+                }
+            }
+            None => {
+                current.push(c);
+                in_word = true;
+            }
+        }
+    }
+
+    if quote.is_some() {
+        return None;
+    }
+    if in_word {
+        words.push(current);
+    }
+    Some(words)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1065,10 +1321,104 @@ mod tests {
         assert!(result.reasoning.contains("superuser"));
     }
 
+    #[test]
+    fn test_classify_shell_command_shell_side_effects() {
+        for command in [
+            "echo x > Cargo.toml",
+            "cat a > b",
+            "ls >> files.txt",
+            "cat notes | tee out.txt",
+            "echo $(rm -rf target)",
+            "ls `touch x`",
+            "sleep 100 & rm x",
+        ] {
+            assert_eq!(
+                classify_shell_command_risk(command),
+                ToolRisk::Risky,
+                "{command} should be risky"
+            );
+        }
+
+        for command in ["cargo test 2>&1 | tail -20", "ls 2>/dev/null", "git status && git log"] {
+            assert_eq!(
+                classify_shell_command_risk(command),
+                ToolRisk::Safe,
+                "{command} should be safe"
+            );
+        }
+    }
+
     #[test]
     fn test_classify_shell_command_risk() {
         assert_eq!(classify_shell_command_risk("cargo test"), ToolRisk::Safe);
         assert_eq!(classify_shell_command_risk("npm install lodash"), ToolRisk::Risky);
         assert_eq!(classify_shell_command_risk("sudo rm file"), ToolRisk::Blocked);
     }
+
+    #[test]
+    fn test_is_read_only_command() {
+        for command in [
+            "ls -la",
+            "cat src/main.rs | grep fn | wc -l",
+            "git log --oneline -5 && git status",
+            "rg TODO crates; find . -name '*.rs'",
+        ] {
+            assert!(is_read_only_command(command), "{command} should be read-only");
+        }
+
+        for command in [
+            "rm -rf target",
+            "echo hi > notes.txt",
+            "cat a.txt >> b.txt",
+            "cargo fmt",
+            "sed -i s/a/b/ file",
+            "find . -name '*.tmp' -delete",
+            "find . -exec touch {} ;",
+            "ls && git commit -am wip",
+            "echo $(touch x)",
+            "git diff --output=patch.diff",
+            "ls & touch x",
+            "find . -fprint0 out.txt",
+            "find . -fprintf out.txt %p",
+            "find . -fls listing.txt",
+            "git grep -Oless TODO",
+            "git grep --open-files-in-pager=vim TODO",
+            "find . '-delete'",
+            "find . -\"exec\" touch {} ;",
+            "find . \\-delete",
+            "cat \"unterminated",
+            "ls $HOME",
+        ] {
+            assert!(!is_read_only_command(command), "{command} should not be read-only");
+        }
+    }
+
+    #[test]
+    fn test_classify_with_reasoning_records_basis() {
+        let classifier = CommandClassifier::new();
+        let basis = |command: &str| {
+            let basis = classifier.classify_with_reasoning(command).basis.unwrap();
+            (basis.category, basis.pattern)
+        };
+
+        assert_eq!(
+            basis("curl https://example.com"),
+            (RiskCategory::Network, Some("curl".to_string()))
+        );
+        assert_eq!(basis("rm -rf target"), (RiskCategory::Deletion, Some("rm".to_string())));
+        assert_eq!(basis("sudo ls"), (RiskCategory::Blocked, Some("sudo".to_string())));
+        assert_eq!(
+            basis("sed -i s/a/b/ f.txt"),
+            (RiskCategory::TextTool, Some("sed -i".to_string()))
+        );
+        assert_eq!(basis("cargo test"), (RiskCategory::Test, Some("test".to_string())));
+        assert_eq!(
+            basis("git reset --hard"),
+            (RiskCategory::GitWrite, Some("reset".to_string()))
+        );
+        assert_eq!(basis("frobnicate"), (RiskCategory::Unknown, None));
+
+        let explanation = classifier.classify_with_reasoning("scp a.txt host:").explain();
+        assert!(explanation.starts_with("risky (network, matched 'scp'):"));
+    }
 }
@@ -1,8 +1,12 @@
+use serde_json::Value;
 use thunderus_core::Result;
+use thunderus_providers::CancelToken;
 use thunderus_providers::ToolCall;
+use thunderus_providers::ToolParameter;
 use thunderus_providers::ToolResult;
 
 use super::ToolRegistry;
+use super::teaching_errors::{ArgumentErrors, TeachingError};
 
 /// Executes a tool call from a provider
 ///
@@ -31,9 +35,21 @@ impl ToolDispatcher {
         let arguments = tool_call.arguments();
         let tool_call_id = tool_call.id.clone();
 
+        self.validate(tool_call)?;
         self.registry.execute(tool_name, tool_call_id, arguments)
     }
 
+    /// Executes a single tool call, aborting it if `cancel_token` is cancelled
+    pub fn execute_cancellable(&self, tool_call: &ToolCall, cancel_token: &CancelToken) -> Result<ToolResult> {
+        self.validate(tool_call)?;
+        self.registry.execute_cancellable(
+            tool_call.name(),
+            tool_call.id.clone(),
+            tool_call.arguments(),
+            cancel_token,
+        )
+    }
+
     /// Executes multiple tool calls in order
     ///
     /// Returns a vector of results, one for each tool call
@@ -48,6 +64,18 @@ impl ToolDispatcher {
         Ok(results)
     }
 
+    /// Checks the call's arguments against the tool's parameter schema
+    ///
+    /// Unknown tools pass through so the registry reports them as usual.
+    fn validate(&self, tool_call: &ToolCall) -> Result<()> {
+        let Some(spec) = self.registry.spec(tool_call.name()) else {
+            return Ok(());
+        };
+
+        validate_arguments(tool_call.name(), &spec.function.parameters, tool_call.arguments())
+            .map_err(|err| thunderus_core::Error::Validation(err.format()))
+    }
+
     /// Gets a reference to the underlying registry
     pub fn registry(&self) -> &ToolRegistry {
         &self.registry
@@ -65,6 +93,90 @@ impl From<ToolRegistry> for ToolDispatcher {
     }
 }
 
+/// Validate tool arguments against a parameter schema
+///
+/// Checks that required fields are present and that top-level values have
+/// the declared JSON type and satisfy any enum or range constraint. Nested
+/// values are left to the tool itself.
+pub fn validate_arguments(
+    tool: &str, schema: &ToolParameter, arguments: &Value,
+) -> std::result::Result<(), TeachingError> {
+    let ToolParameter::Object { properties, required, .. } = schema else {
+        return Ok(());
+    };
+
+    let Some(arguments) = arguments.as_object() else {
+        return Err(ArgumentErrors::not_an_object(tool, json_type_name(arguments)));
+    };
+
+    for field in required.iter().flatten() {
+        if arguments.get(field).is_none_or(Value::is_null) {
+            return Err(ArgumentErrors::missing_parameter(tool, field));
+        }
+    }
+
+    for (name, parameter) in properties {
+        let Some(value) = arguments.get(name).filter(|value| !value.is_null()) else {
+            continue;
+        };
+        let matches = match parameter {
+            ToolParameter::String { variants: Some(variants), .. } => {
+                let Some(value) = value.as_str() else {
+                    return Err(ArgumentErrors::mistyped_parameter(
+                        tool,
+                        name,
+                        "string",
+                        json_type_name(value),
+                    ));
+                };
+                if !variants.iter().any(|variant| variant == value) {
+                    return Err(ArgumentErrors::invalid_choice(tool, name, value, variants));
+                }
+                true
+            }
+            ToolParameter::String { .. } => value.is_string(),
+            ToolParameter::Number { minimum, maximum, .. } => {
+                let Some(number) = value.as_f64() else {
+                    return Err(ArgumentErrors::mistyped_parameter(
+                        tool,
+                        name,
+                        "number",
+                        json_type_name(value),
+                    ));
+                };
+                if minimum.is_some_and(|min| number < min) || maximum.is_some_and(|max| number > max) {
+                    return Err(ArgumentErrors::out_of_range(tool, name, number, *minimum, *maximum));
+                }
+                true
+            }
+            ToolParameter::Boolean { .. } => value.is_boolean(),
+            ToolParameter::Array { .. } => value.is_array(),
+            ToolParameter::Object { .. } => value.is_object(),
+        };
+        if !matches {
+            return Err(ArgumentErrors::mistyped_parameter(
+                tool,
+                name,
+                parameter.type_name(),
+                json_type_name(value),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -105,6 +217,60 @@ mod tests {
         assert_eq!(tool_result.content, "Hello");
     }
 
+    #[test]
+    fn test_execute_missing_required_parameter() {
+        let dispatcher = setup_dispatcher();
+        let tool_call = ToolCall::new("call_1", "echo", serde_json::json!({}));
+
+        let err = dispatcher.execute(&tool_call).unwrap_err().to_string();
+        assert!(err.contains("Missing required parameter 'message'"));
+    }
+
+    #[test]
+    fn test_execute_mistyped_parameter() {
+        let dispatcher = setup_dispatcher();
+        let tool_call = ToolCall::new("call_1", "echo", serde_json::json!({"message": 42}));
+
+        let err = dispatcher.execute(&tool_call).unwrap_err().to_string();
+        assert!(err.contains("Parameter 'message' has the wrong type"));
+        assert!(err.contains("received a number"));
+    }
+
+    #[test]
+    fn test_validate_arguments_constraints() {
+        let schema = ToolParameter::new_object(vec![
+            (
+                "mode".to_string(),
+                ToolParameter::new_enum("Mode", &["content", "count"]),
+            ),
+            (
+                "limit".to_string(),
+                ToolParameter::new_number("Limit").with_minimum(1.0).with_maximum(50.0),
+            ),
+        ]);
+
+        assert!(validate_arguments("grep", &schema, &serde_json::json!({"mode": "count", "limit": 50})).is_ok());
+
+        let err = validate_arguments("grep", &schema, &serde_json::json!({"mode": "lines"})).unwrap_err();
+        assert!(err.format().contains("'content', 'count'"));
+
+        let err = validate_arguments("grep", &schema, &serde_json::json!({"limit": 0})).unwrap_err();
+        assert!(err.summary.contains("out of range"));
+    }
+
+    #[test]
+    fn test_validate_arguments_allows_optional_null() {
+        let schema = ToolParameter::new_object(vec![
+            ("pattern".to_string(), ToolParameter::new_string("Pattern")),
+            ("limit".to_string(), ToolParameter::new_number("Limit")),
+        ])
+        .with_required(&["pattern"]);
+
+        assert!(validate_arguments("grep", &schema, &serde_json::json!({"pattern": "x", "limit": null})).is_ok());
+        assert!(validate_arguments("grep", &schema, &serde_json::json!({"pattern": null})).is_err());
+        assert!(validate_arguments("grep", &schema, &serde_json::json!("x")).is_err());
+    }
+
     #[test]
     fn test_execute_batch() {
         let dispatcher = setup_dispatcher();
@@ -11,7 +11,7 @@ use crate::classification::CommandClassifier;
 use std::path::PathBuf;
 use thunderus_core::{
     Classification, Result,
-    teaching::{get_hint_for_concept, suggest_concept},
+    teaching::{DismissedHints, get_hint_for_concept, suggest_concept},
 };
 
 /// Full-access mode policy for sed/awk commands
@@ -118,7 +118,7 @@ pub fn create_backups_for_command(command: &str, backup_manager: &BackupManager)
 /// Returns a teaching hint message if this is the first time the command pattern
 /// is encountered, or None if it has already been taught.
 pub fn get_teaching_hint_for_command(command: &str, classification: &Classification) -> Option<String> {
-    let concept = suggest_concept("shell", classification.risk, command);
+    let concept = suggest_concept("shell", classification.risk, command, &DismissedHints::load_default());
     concept.and_then(|c| get_hint_for_concept(&c))
 }
 
@@ -0,0 +1,109 @@
+//! Lazily instantiated plugin runtimes
+//!
+//! Plugin skills are registered from their parsed manifests at startup, but
+//! their runtime (a Lua state or a compiled WASM module) is only created when
+//! the skill is first called, then reused for later calls. A failed load is
+//! reported on the call that triggered it and retried on the next one.
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use thunderus_core::Result;
+
+/// A plugin runtime created on first use and shared by clones of its tool
+pub(crate) struct LazyEngine<E> {
+    engine: Arc<Mutex<Option<E>>>,
+}
+
+impl<E> LazyEngine<E> {
+    pub(crate) fn new() -> Self {
+        Self { engine: Arc::new(Mutex::new(None)) }
+    }
+
+    /// Whether the runtime has been instantiated
+    pub(crate) fn is_loaded(&self) -> bool {
+        self.engine.lock().unwrap_or_else(|e| e.into_inner()).is_some()
+    }
+
+    /// Run `call` against the runtime, instantiating it with `load` first if needed
+    pub(crate) fn with<T>(
+        &self, load: impl FnOnce() -> Result<E>, call: impl FnOnce(&mut E) -> Result<T>,
+    ) -> Result<T> {
+        let mut guard = self.engine.lock().unwrap_or_else(|e| e.into_inner());
+        let engine = match guard.take() {
+            Some(engine) => guard.insert(engine),
+            None => guard.insert(load()?),
+        };
+        call(engine)
+    }
+}
+
+impl<E> Clone for LazyEngine<E> {
+    fn clone(&self) -> Self {
+        Self { engine: Arc::clone(&self.engine) }
+    }
+}
+
+impl<E> fmt::Debug for LazyEngine<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LazyEngine").field("loaded", &self.is_loaded()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_lazy_engine_loads_once() {
+        let engine: LazyEngine<Vec<u32>> = LazyEngine::new();
+        assert!(!engine.is_loaded());
+
+        let loads = Cell::new(0);
+        let load = || {
+            loads.set(loads.get() + 1);
+            Ok(Vec::new())
+        };
+        engine
+            .with(load, |calls| {
+                calls.push(1);
+                Ok(())
+            })
+            .unwrap();
+        engine
+            .clone()
+            .with(
+                || panic!("already loaded"),
+                |calls| {
+                    calls.push(2);
+                    Ok(())
+                },
+            )
+            .unwrap();
+
+        assert_eq!(loads.get(), 1);
+        assert!(engine.is_loaded());
+        assert_eq!(
+            engine.with(|| unreachable!(), |calls| Ok(calls.clone())).unwrap(),
+            vec![1, 2]
+        );
+    }
+
+    #[test]
+    fn test_lazy_engine_reports_and_retries_failed_load() {
+        let engine: LazyEngine<u32> = LazyEngine::new();
+
+        let result = engine.with(
+            || {
+                Err(thunderus_core::Error::extension(
+                    "Failed to load Lua plugin 'broken': syntax error",
+                ))
+            },
+            |_| Ok(()),
+        );
+        assert!(result.unwrap_err().to_string().contains("'broken'"));
+        assert!(!engine.is_loaded());
+
+        assert_eq!(engine.with(|| Ok(7), |value| Ok(*value)).unwrap(), 7);
+    }
+}
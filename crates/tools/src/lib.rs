@@ -5,6 +5,8 @@ pub mod classification;
 pub mod dispatcher;
 pub mod full_access;
 pub mod hunk_labeler;
+#[cfg(any(feature = "lua", feature = "wasm", test))]
+mod lazy_engine;
 pub mod patch_generator;
 pub mod read_history;
 pub mod registry;
@@ -24,10 +26,12 @@ pub mod lua_tool;
 pub use apply_engine::{ApplyEngine, ApplyResult, ConflictInfo, ConflictType};
 pub use backup::{BackupManager, BackupMetadata, BackupMode, command_requires_backup};
 pub use builtin::{
-    EchoTool, EditTool, GlobTool, GrepTool, MultiEditOperation, MultiEditTool, NoopTool, PatchTool, ReadTool,
-    ShellTool, WriteTool,
+    EchoTool, EditTool, FindTool, GlobTool, GrepTool, MultiEditOperation, MultiEditTool, NoopTool, PatchTool,
+    ReadOnlyShellTool, ReadTool, SedTool, ShellTool, TreeTool, WriteTool,
+};
+pub use classification::{
+    CommandClassifier, Pattern, classify_shell_command, classify_shell_command_risk, is_read_only_command,
 };
-pub use classification::{CommandClassifier, Pattern, classify_shell_command, classify_shell_command_risk};
 pub use dispatcher::ToolDispatcher;
 pub use full_access::{
     FullAccessPolicy, check_full_access_policy, classify_and_get_hint, create_backups_for_command,
@@ -37,7 +41,7 @@ pub use hunk_labeler::{HunkLabel, HunkLabeler};
 #[cfg(feature = "lua")]
 pub use lua_tool::LuaTool;
 pub use patch_generator::{PatchGenerator, generate_unified_diff};
-pub use read_history::{ReadHistory, validate_read_before_edit};
+pub use read_history::{ReadHistory, validate_read_before_edit, validate_read_before_edit_lines};
 pub use registry::ToolRegistry;
 pub use result_formatting::{
     EditFormatter, FormattedResult, GlobFormatter, GrepFormatter, MultiEditFormatter, ReadFormatter,
@@ -46,7 +50,7 @@ pub use scope_extraction::{ScopeInfo, extract_scope};
 pub use session_dispatcher::{SessionToolDispatcher, validate_read_before_edit as validate_session_read_before_edit};
 pub use skill_tool::SkillTool;
 pub use teaching_errors::{
-    EditErrors, ErrorCategory, GlobErrors, GrepErrors, MultiEditErrors, ReadErrors, TeachingError,
+    ArgumentErrors, EditErrors, ErrorCategory, GlobErrors, GrepErrors, MultiEditErrors, ReadErrors, TeachingError,
 };
 pub use thunderus_core::ToolRisk;
 pub use tool::Tool;
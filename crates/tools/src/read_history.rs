@@ -4,8 +4,11 @@
 //! enabling validation for edit operations (ensuring files are read before editing).
 
 use std::collections::HashMap;
+use std::ops::Range;
 use std::sync::{Arc, RwLock};
 
+use thunderus_core::Event;
+
 /// Tracks which files have been read in the current session
 ///
 /// This in-memory tracker provides fast lookups for edit validation.
@@ -22,18 +25,54 @@ struct ReadHistoryInner {
     /// Map of file path to (line_count, offset) tuples
     /// Only tracks successful reads
     reads: HashMap<String, (usize, usize)>,
+    /// Map of file path to the merged, sorted 0-indexed line ranges read so far
+    ranges: HashMap<String, Vec<Range<usize>>>,
 }
 
 impl ReadHistory {
     /// Creates a new empty read history
     pub fn new() -> Self {
-        Self { inner: Arc::new(RwLock::new(ReadHistoryInner { reads: HashMap::new() })) }
+        Self {
+            inner: Arc::new(RwLock::new(ReadHistoryInner {
+                reads: HashMap::new(),
+                ranges: HashMap::new(),
+            })),
+        }
+    }
+
+    /// Rebuilds read history from logged session events
+    ///
+    /// Replays `FileRead` events in order, so a resumed session keeps the
+    /// reads it made before it was interrupted.
+    pub fn from_events<'a>(events: impl IntoIterator<Item = &'a Event>) -> Self {
+        let history = Self::new();
+        for event in events {
+            history.apply_event(event);
+        }
+        history
+    }
+
+    /// Applies a single session event to the history
+    ///
+    /// Events other than `FileRead` are ignored.
+    pub fn apply_event(&self, event: &Event) {
+        if let Event::FileRead { file_path, line_count, offset, success } = event {
+            if *success {
+                self.record_read(file_path, *line_count, *offset);
+            } else {
+                self.record_failed_read(file_path);
+            }
+        }
     }
 
     /// Records a successful file read
+    ///
+    /// The lines read are merged with any earlier reads of the same file.
     pub fn record_read(&self, file_path: &str, line_count: usize, offset: usize) {
         if let Ok(mut inner) = self.inner.write() {
             inner.reads.insert(file_path.to_string(), (line_count, offset));
+            let ranges = inner.ranges.entry(file_path.to_string()).or_default();
+            merge_range(ranges, offset..offset + line_count);
         }
     }
 
@@ -44,14 +83,42 @@ impl ReadHistory {
     pub fn record_failed_read(&self, file_path: &str) {
         if let Ok(mut inner) = self.inner.write() {
             inner.reads.remove(file_path);
+            inner.ranges.remove(file_path);
         }
     }
 
     /// Checks if a file has been successfully read
+    ///
+    /// Returns the (line_count, offset) of the most recent read.
     pub fn was_read(&self, file_path: &str) -> Option<(usize, usize)> {
         if let Ok(inner) = self.inner.read() { inner.reads.get(file_path).copied() } else { None }
     }
 
+    /// Checks if every line in `lines` (0-indexed, end-exclusive) has been read
+    pub fn covers_lines(&self, file_path: &str, lines: Range<usize>) -> bool {
+        let Ok(inner) = self.inner.read() else {
+            return false;
+        };
+        let Some(ranges) = inner.ranges.get(file_path) else {
+            return false;
+        };
+        if lines.is_empty() {
+            return true;
+        }
+        ranges
+            .iter()
+            .any(|range| range.start <= lines.start && lines.end <= range.end)
+    }
+
+    /// Returns the merged 0-indexed line ranges read for a file
+    pub fn read_ranges(&self, file_path: &str) -> Vec<Range<usize>> {
+        if let Ok(inner) = self.inner.read() {
+            inner.ranges.get(file_path).cloned().unwrap_or_default()
+        } else {
+            Vec::new()
+        }
+    }
+
     /// Returns all files that have been successfully read
     pub fn read_files(&self) -> Vec<(String, usize, usize)> {
         if let Ok(inner) = self.inner.read() {
@@ -71,6 +138,7 @@ impl ReadHistory {
     pub fn clear(&self) {
         if let Ok(mut inner) = self.inner.write() {
             inner.reads.clear();
+            inner.ranges.clear();
         }
     }
 
@@ -91,6 +159,26 @@ impl Default for ReadHistory {
     }
 }
 
+/// Inserts `new` into a sorted list of disjoint ranges, coalescing overlapping or adjacent ones
+fn merge_range(ranges: &mut Vec<Range<usize>>, new: Range<usize>) {
+    if new.is_empty() {
+        return;
+    }
+
+    let mut merged = new;
+    ranges.retain(|range| {
+        if range.end < merged.start || merged.end < range.start {
+            true
+        } else {
+            merged = merged.start.min(range.start)..merged.end.max(range.end);
+            false
+        }
+    });
+
+    let idx = ranges.partition_point(|range| range.start < merged.start);
+    ranges.insert(idx, merged);
+}
+
 /// Validates that a file has been read before editing
 ///
 /// This function is intended to be called by the Edit tool before performing edits.
@@ -106,6 +194,41 @@ pub fn validate_read_before_edit(history: &ReadHistory, file_path: &str) -> Resu
     }
 }
 
+/// Validates that the lines about to be edited have been read
+///
+/// `lines` is 0-indexed and end-exclusive. A partial read only satisfies the
+/// check for the lines it actually returned.
+pub fn validate_read_before_edit_lines(
+    history: &ReadHistory, file_path: &str, lines: Range<usize>,
+) -> Result<(), String> {
+    validate_read_before_edit(history, file_path)?;
+
+    if history.covers_lines(file_path, lines.clone()) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Cannot edit lines {}-{} of '{}': Only part of the file has been read. Use the Read tool with an offset/limit covering these lines first.",
+            lines.start + 1,
+            lines.end,
+            file_path
+        ))
+    }
+}
+
+/// Returns the 0-indexed, end-exclusive line range spanned by each occurrence of `needle`
+pub(crate) fn occurrence_lines<'a>(content: &'a str, needle: &'a str) -> impl Iterator<Item = Range<usize>> + 'a {
+    content
+        .match_indices(needle)
+        .filter(|(_, matched)| !matched.is_empty())
+        .map(|(pos, matched)| span_lines(content, pos, matched))
+}
+
+/// Returns the 0-indexed, end-exclusive line range spanned by `matched` starting at byte `pos`
+pub(crate) fn span_lines(content: &str, pos: usize, matched: &str) -> Range<usize> {
+    let start = content[..pos].matches('\n').count();
+    start..start + matched.trim_end_matches('\n').matches('\n').count() + 1
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -231,6 +354,63 @@ mod tests {
         assert_eq!(history1.len(), 2);
     }
 
+    #[test]
+    fn test_partial_reads_cover_only_lines_read() {
+        let history = ReadHistory::new();
+        history.record_read("/path/to/file.txt", 10, 20);
+
+        assert!(history.covers_lines("/path/to/file.txt", 20..30));
+        assert!(history.covers_lines("/path/to/file.txt", 25..26));
+        assert!(!history.covers_lines("/path/to/file.txt", 0..5));
+        assert!(!history.covers_lines("/path/to/file.txt", 25..35));
+        assert!(!history.covers_lines("/other/file.txt", 0..1));
+
+        history.record_read("/path/to/file.txt", 20, 0);
+        assert_eq!(history.read_ranges("/path/to/file.txt"), vec![0..30]);
+        assert!(history.covers_lines("/path/to/file.txt", 0..30));
+
+        assert!(validate_read_before_edit_lines(&history, "/path/to/file.txt", 5..25).is_ok());
+        let content = "a\nb\nc\nb\n";
+        assert_eq!(occurrence_lines(content, "b").collect::<Vec<_>>(), vec![1..2, 3..4]);
+        assert_eq!(occurrence_lines(content, "a\nb\n").collect::<Vec<_>>(), vec![0..2]);
+        let err = validate_read_before_edit_lines(&history, "/path/to/file.txt", 25..40).unwrap_err();
+        assert!(err.contains("Only part of the file"));
+    }
+
+    #[test]
+    fn test_merge_range_keeps_disjoint_ranges_sorted() {
+        let mut ranges = Vec::new();
+        merge_range(&mut ranges, 50..60);
+        merge_range(&mut ranges, 0..10);
+        merge_range(&mut ranges, 20..30);
+        assert_eq!(ranges, vec![0..10, 20..30, 50..60]);
+
+        merge_range(&mut ranges, 10..20);
+        assert_eq!(ranges, vec![0..30, 50..60]);
+
+        merge_range(&mut ranges, 5..55);
+        assert_eq!(ranges, vec![0..60]);
+    }
+
+    #[test]
+    fn test_from_events_replays_file_reads() {
+        let events = vec![
+            Event::FileRead { file_path: "/a.rs".to_string(), line_count: 100, offset: 0, success: true },
+            Event::UserMessage { content: "hello".to_string() },
+            Event::FileRead { file_path: "/b.rs".to_string(), line_count: 5, offset: 10, success: true },
+            Event::FileRead { file_path: "/c.rs".to_string(), line_count: 10, offset: 0, success: true },
+            Event::FileRead { file_path: "/c.rs".to_string(), line_count: 0, offset: 0, success: false },
+        ];
+
+        let history = ReadHistory::from_events(&events);
+
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.was_read("/a.rs"), Some((100, 0)));
+        assert!(history.covers_lines("/b.rs", 10..15));
+        assert!(!history.covers_lines("/b.rs", 0..10));
+        assert!(history.was_read("/c.rs").is_none());
+    }
+
     #[test]
     fn test_default() {
         let history: ReadHistory = Default::default();
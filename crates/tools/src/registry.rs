@@ -1,9 +1,11 @@
 use super::Tool;
 use super::builtin::{
-    EchoTool, EditTool, GlobTool, GrepTool, MultiEditTool, NoopTool, PatchTool, ReadTool, ShellTool, WriteTool,
+    EchoTool, EditTool, FindTool, GlobTool, GrepTool, MultiEditTool, NoopTool, PatchTool, ReadOnlyShellTool, ReadTool,
+    SedTool, ShellTool, TreeTool, WriteTool,
 };
 #[cfg(feature = "lua")]
 use super::lua_tool::LuaTool;
+use super::read_history::ReadHistory;
 use super::skill_tool::SkillTool;
 #[cfg(feature = "wasm")]
 use super::wasm_tool::WasmTool;
@@ -13,8 +15,8 @@ use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
 use thunderus_core::config::PathAccessResult;
 use thunderus_core::{ApprovalGate, ApprovalMode, Profile, Result};
-use thunderus_providers::{ToolResult, ToolSpec};
-use thunderus_skills::{SkillDriver, SkillLoader};
+use thunderus_providers::{CancelToken, ToolResult, ToolSpec};
+use thunderus_skills::{Skill, SkillDriver, SkillLoader, SkillMeta};
 
 /// Registry that holds all available tools
 #[derive(Debug, Clone)]
@@ -26,6 +28,8 @@ pub struct ToolRegistry {
     profile: Option<Profile>,
     /// Workspace root directories for edit tool validation (legacy, kept for compatibility)
     workspace_roots: Vec<PathBuf>,
+    /// Skills registered as tools, keyed by the tool name they were registered under
+    skills: Arc<RwLock<Vec<(String, SkillMeta)>>>,
 }
 
 impl ToolRegistry {
@@ -36,6 +40,7 @@ impl ToolRegistry {
             approval_gate: None,
             profile: None,
             workspace_roots: Vec::new(),
+            skills: Arc::new(RwLock::new(Vec::new())),
         }
     }
 
@@ -46,6 +51,7 @@ impl ToolRegistry {
             approval_gate: Some(approval_gate),
             profile: None,
             workspace_roots,
+            skills: Arc::new(RwLock::new(Vec::new())),
         }
     }
 
@@ -57,6 +63,7 @@ impl ToolRegistry {
             approval_gate: None,
             profile: Some(profile),
             workspace_roots,
+            skills: Arc::new(RwLock::new(Vec::new())),
         }
     }
 
@@ -67,12 +74,33 @@ impl ToolRegistry {
         registry.register(EchoTool).unwrap();
         registry.register(GrepTool).unwrap();
         registry.register(GlobTool).unwrap();
+        registry.register(FindTool).unwrap();
+        registry.register(TreeTool).unwrap();
         registry.register(ReadTool).unwrap();
         registry.register(ShellTool).unwrap();
         registry.register(PatchTool).unwrap();
         registry.register(WriteTool).unwrap();
-        registry.register(EditTool).unwrap();
-        registry.register(MultiEditTool).unwrap();
+        registry.register(EditTool::default()).unwrap();
+        registry.register(MultiEditTool::default()).unwrap();
+        registry.register(SedTool::default()).unwrap();
+        registry
+    }
+
+    /// Creates a tool registry that cannot modify the workspace
+    ///
+    /// Only read-only built-in tools are registered: the file-editing tools
+    /// (edit, multiedit, sed, write, patch) are left out entirely, and `shell` is
+    /// [ReadOnlyShellTool], which refuses any command that could write.
+    pub fn with_read_only_tools() -> Self {
+        let registry = Self::new();
+        registry.register(NoopTool).unwrap();
+        registry.register(EchoTool).unwrap();
+        registry.register(GrepTool).unwrap();
+        registry.register(GlobTool).unwrap();
+        registry.register(FindTool).unwrap();
+        registry.register(TreeTool).unwrap();
+        registry.register(ReadTool).unwrap();
+        registry.register(ReadOnlyShellTool).unwrap();
         registry
     }
 
@@ -88,6 +116,31 @@ impl ToolRegistry {
         self.workspace_roots = workspace_roots;
     }
 
+    /// Makes the registered edit tools enforce read-before-edit against `read_history`
+    ///
+    /// Only tools already present are replaced, so a read-only registry stays read-only.
+    pub fn set_read_history(&self, read_history: &ReadHistory) {
+        let mut tools = self.tools.write().unwrap();
+        if tools.contains_key("edit") {
+            tools.insert(
+                "edit".to_string(),
+                Box::new(EditTool::with_read_history(read_history.clone())),
+            );
+        }
+        if tools.contains_key("multiedit") {
+            tools.insert(
+                "multiedit".to_string(),
+                Box::new(MultiEditTool::with_read_history(read_history.clone())),
+            );
+        }
+        if tools.contains_key("sed") {
+            tools.insert(
+                "sed".to_string(),
+                Box::new(SedTool::default().with_read_history(read_history.clone())),
+            );
+        }
+    }
+
     /// Gets the approval gate
     pub fn approval_gate(&self) -> Option<&ApprovalGate> {
         self.approval_gate.as_ref()
@@ -114,12 +167,52 @@ impl ToolRegistry {
         tools.get(tool_name).map(|tool| tool.risk_level())
     }
 
+    /// Get the risk level for one call to a tool, taking its arguments into account
+    pub fn call_risk(&self, tool_name: &str, arguments: &serde_json::Value) -> Option<thunderus_core::ToolRisk> {
+        let tools = self.tools.read().ok()?;
+        let tool = tools.get(tool_name)?;
+        Some(
+            tool.classify_execution(arguments)
+                .map_or_else(|| tool.risk_level(), |c| c.risk),
+        )
+    }
+
+    /// Preview the diff a tool call would make, if the tool edits files
+    pub fn preview_diff(&self, tool_name: &str, arguments: &serde_json::Value) -> Option<String> {
+        let tools = self.tools.read().ok()?;
+        tools.get(tool_name)?.preview_diff(arguments)
+    }
+
     /// Check if a tool is read-only by name
     pub fn tool_is_read_only(&self, tool_name: &str) -> Option<bool> {
         let tools = self.tools.read().ok()?;
         tools.get(tool_name).map(|tool| tool.is_read_only())
     }
 
+    /// Check whether the profile offers a tool to the model
+    ///
+    /// Without a profile every tool is available.
+    pub fn tool_allowed(&self, tool_name: &str) -> bool {
+        self.profile
+            .as_ref()
+            .is_none_or(|profile| profile.tools.allows(tool_name))
+    }
+
+    /// Tool names in the profile's allow/deny lists that no registered tool answers to
+    ///
+    /// A misspelled `deny` entry otherwise leaves the tool available without any notice.
+    pub fn unknown_profile_tools(&self) -> Vec<String> {
+        let Some(profile) = &self.profile else { return Vec::new() };
+        let tools = self.tools.read().unwrap();
+        let mut unknown: Vec<String> = Vec::new();
+        for name in profile.tools.allow.iter().chain(&profile.tools.deny) {
+            if !tools.contains_key(name) && !unknown.contains(name) {
+                unknown.push(name.clone());
+            }
+        }
+        unknown
+    }
+
     /// Check if a path is within workspace boundaries
     pub fn is_within_workspace(&self, path: &Path) -> bool {
         match self.workspace_roots.is_empty() {
@@ -249,6 +342,7 @@ impl ToolRegistry {
                 .get("file_path")
                 .and_then(|v| v.as_str())
                 .map(|s| s.to_string()),
+            "sed" => arguments.get("path").and_then(|v| v.as_str()).map(|s| s.to_string()),
             _ => None,
         }
     }
@@ -291,9 +385,60 @@ impl ToolRegistry {
     }
 
     /// Returns all tool specs (for sending to providers)
+    ///
+    /// Tools withheld by the profile's `tools` allowlist/denylist are left out.
     pub fn specs(&self) -> Vec<ToolSpec> {
         let tools = self.tools.read().unwrap();
-        tools.values().map(|tool| tool.spec()).collect()
+        tools
+            .iter()
+            .filter(|(name, _)| self.tool_allowed(name))
+            .map(|(_, tool)| tool.spec())
+            .collect()
+    }
+
+    /// Returns the tool specs relevant to a user message
+    ///
+    /// Built-in tools and skills without triggers are always included. A skill
+    /// that declares triggers only enters the tool set when one of them matches
+    /// the message, which keeps the schema sent to the provider small.
+    pub fn specs_for_message(&self, message: &str) -> Vec<ToolSpec> {
+        let dormant: Vec<String> = self
+            .skills
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(_, meta)| !meta.triggers.is_empty() && meta.matching_trigger(message).is_none())
+            .map(|(tool_name, _)| tool_name.clone())
+            .collect();
+
+        let tools = self.tools.read().unwrap();
+        tools
+            .iter()
+            .filter(|(name, _)| !dormant.contains(name) && self.tool_allowed(name))
+            .map(|(_, tool)| tool.spec())
+            .collect()
+    }
+
+    /// Skills whose triggers match a user message, with the trigger that matched
+    pub fn triggered_skills(&self, message: &str) -> Vec<(SkillMeta, String)> {
+        self.skills
+            .read()
+            .unwrap()
+            .iter()
+            .filter_map(|(_, meta)| {
+                let trigger = meta.matching_trigger(message)?.to_string();
+                Some((meta.clone(), trigger))
+            })
+            .collect()
+    }
+
+    /// Returns the spec of a single tool by name
+    pub fn spec(&self, name: &str) -> Option<ToolSpec> {
+        let tools = self.tools.read().unwrap();
+        tools
+            .get(name)
+            .filter(|_| self.tool_allowed(name))
+            .map(|tool| tool.spec())
     }
 
     /// Returns the number of registered tools
@@ -308,48 +453,34 @@ impl ToolRegistry {
     /// - `.thunderus/skills/` (project-local, higher priority)
     /// - `~/.thunderus/skills/` (global, lower priority)
     ///
+    /// Only manifests are parsed here; Lua and WASM runtimes are instantiated
+    /// on each skill's first call, so a broken plugin fails that call instead
+    /// of startup.
+    ///
     /// Returns the number of skills successfully loaded.
     pub fn load_skills(&self) -> Result<usize> {
-        let mut skill_loader = SkillLoader::new(thunderus_skills::SkillsConfig::default())?;
+        let mut skill_loader = SkillLoader::new(self.skills_config())?;
         self.load_skills_from_loader(&mut skill_loader)
     }
 
     /// Load and register skills from a custom SkillLoader.
     ///
+    /// A skill whose tool name is already taken (by a built-in tool or an
+    /// earlier skill) is skipped with a warning; built-ins always keep their
+    /// name.
+    ///
     /// Returns the number of skills successfully loaded.
     pub fn load_skills_from_loader(&self, skill_loader: &mut SkillLoader) -> Result<usize> {
         let skills = skill_loader.discover()?;
+        let namespaced = skill_loader.config().namespace_tools;
         let mut loaded = 0;
 
         for skill_meta in skills {
             if let Ok(skill) = skill_loader.load(&skill_meta.name) {
-                let skill = (*skill).clone();
-                match skill.meta.driver {
-                    SkillDriver::Shell | SkillDriver::Mcp => {
-                        if self.register(SkillTool::new(skill)).is_ok() {
-                            loaded += 1;
-                        }
-                    }
-                    SkillDriver::Wasm => {
-                        #[cfg(feature = "wasm")]
-                        {
-                            if let Some(tool) = WasmTool::new(skill)
-                                && self.register(tool).is_ok()
-                            {
-                                loaded += 1;
-                            }
-                        }
-                    }
-                    SkillDriver::Lua => {
-                        #[cfg(feature = "lua")]
-                        {
-                            if let Some(tool) = LuaTool::new(skill)
-                                && self.register(tool).is_ok()
-                            {
-                                loaded += 1;
-                            }
-                        }
-                    }
+                match self.register_skill((*skill).clone(), namespaced) {
+                    Ok(true) => loaded += 1,
+                    Ok(false) => {}
+                    Err(e) => tracing::warn!("Skipping skill '{}': {}", skill_meta.name, e),
                 }
             }
         }
@@ -357,36 +488,98 @@ impl ToolRegistry {
         Ok(loaded)
     }
 
+    /// Skill loader settings from the profile's `skills` table, or the defaults without a profile
+    fn skills_config(&self) -> thunderus_skills::SkillsConfig {
+        match &self.profile {
+            Some(profile) => thunderus_skills::SkillsConfig {
+                enabled: profile.skills.enabled,
+                skills_dir: profile.skills.skills_dir.clone(),
+                auto_discovery: profile.skills.auto_discovery,
+                namespace_tools: profile.skills.namespace_tools,
+            },
+            None => thunderus_skills::SkillsConfig::default(),
+        }
+    }
+
+    /// Metadata for the skills registered in this registry, in load order
+    pub fn loaded_skills(&self) -> Vec<SkillMeta> {
+        self.skills
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(_, meta)| meta.clone())
+            .collect()
+    }
+
     /// Load and register a specific skill by name.
     ///
-    /// Returns error if the skill is not found.
+    /// Returns error if the skill is not found or its tool name is already taken.
     pub fn load_skill(&self, name: &str) -> Result<()> {
-        let mut skill_loader = SkillLoader::new(thunderus_skills::SkillsConfig::default())?;
-        let skill = skill_loader.load(name)?;
-        let skill = (*skill).clone();
+        let mut skill_loader = SkillLoader::new(self.skills_config())?;
+        let namespaced = skill_loader.config().namespace_tools;
+        let skill = (*skill_loader.load(name)?).clone();
+        self.register_skill(skill, namespaced)?;
+        Ok(())
+    }
+
+    /// Wrap a skill in the tool for its driver and register it
+    ///
+    /// Returns `Ok(false)` when the driver's runtime isn't compiled in.
+    fn register_skill(&self, skill: Skill, namespaced: bool) -> Result<bool> {
+        let meta = skill.meta.clone();
         match skill.meta.driver {
             SkillDriver::Shell | SkillDriver::Mcp => {
-                let tool = SkillTool::new(skill);
-                self.register(tool)?;
+                let tool = SkillTool::new(skill).with_namespace(namespaced);
+                self.register_skill_tool(tool.tool_name(), tool, meta)?;
+                Ok(true)
             }
             SkillDriver::Wasm => {
                 #[cfg(feature = "wasm")]
                 {
                     if let Some(tool) = WasmTool::new(skill) {
-                        self.register(tool)?;
+                        let tool = tool.with_namespace(namespaced);
+                        self.register_skill_tool(tool.tool_name(), tool, meta)?;
+                        return Ok(true);
                     }
                 }
+                Ok(false)
             }
             SkillDriver::Lua => {
                 #[cfg(feature = "lua")]
                 {
                     if let Some(tool) = LuaTool::new(skill) {
-                        self.register(tool)?;
+                        let tool = tool.with_namespace(namespaced);
+                        self.register_skill_tool(tool.tool_name(), tool, meta)?;
+                        return Ok(true);
                     }
                 }
+                Ok(false)
             }
         }
+    }
 
+    /// Register a skill's tool under `tool_name`, refusing to shadow an existing tool
+    fn register_skill_tool<T: Tool + 'static>(&self, tool_name: String, tool: T, meta: SkillMeta) -> Result<()> {
+        let mut tools = self.tools.write().unwrap();
+        let mut skills = self.skills.write().unwrap();
+
+        if tools.contains_key(&tool_name) {
+            let owner = skills.iter().find(|(name, _)| *name == tool_name);
+            return Err(thunderus_core::Error::Validation(match owner {
+                Some((_, existing)) => format!(
+                    "Skill '{}' uses tool name '{}', already registered by skill '{}'",
+                    meta.name, tool_name, existing.name
+                ),
+                None => format!(
+                    "Skill '{}' collides with built-in tool '{}'; rename the skill or enable \
+                     namespace_tools to register it as 'skill:{}'",
+                    meta.name, tool_name, meta.name
+                ),
+            }));
+        }
+
+        tools.insert(tool_name.clone(), Box::new(tool));
+        skills.push((tool_name, meta));
         Ok(())
     }
 
@@ -419,15 +612,36 @@ impl ToolRegistry {
     /// - Read-only tools (grep, glob, read) always bypass approval
     /// - Edit tools check approval mode and workspace boundaries
     pub fn execute(&self, tool_name: &str, tool_call_id: String, arguments: &serde_json::Value) -> Result<ToolResult> {
+        self.execute_cancellable(tool_name, tool_call_id, arguments, &CancelToken::new())
+    }
+
+    /// Executes a tool by name, aborting if `cancel_token` is cancelled
+    ///
+    /// A call cancelled before it starts is never run.
+    pub fn execute_cancellable(
+        &self, tool_name: &str, tool_call_id: String, arguments: &serde_json::Value, cancel_token: &CancelToken,
+    ) -> Result<ToolResult> {
+        if let Some(profile) = &self.profile
+            && !profile.tools.allows(tool_name)
+        {
+            return Err(thunderus_core::Error::Tool(
+                profile.tools.denial_message(tool_name, &profile.name),
+            ));
+        }
+
         let tools = self.tools.read().unwrap();
 
         match tools.get(tool_name) {
             Some(tool) => {
                 self.check_approval_required(tool.as_ref(), arguments)?;
 
+                if cancel_token.is_cancelled() {
+                    return Ok(ToolResult::cancelled(tool_call_id));
+                }
+
                 let classification = tool.classify_execution(arguments);
 
-                let mut result = tool.execute(tool_call_id.clone(), arguments)?;
+                let mut result = tool.execute_cancellable(tool_call_id.clone(), arguments, cancel_token)?;
                 if let Some(classification) = classification {
                     result = result.with_classification(classification);
                 }
@@ -495,6 +709,75 @@ mod tests {
         assert_eq!(specs[0].name(), "noop");
     }
 
+    #[test]
+    fn test_profile_tool_allowlist() {
+        let config = thunderus_core::Config::from_toml_str(
+            r#"
+default_profile = "review"
+
+[profiles.review]
+name = "review"
+working_root = "/workspace"
+
+[profiles.review.tools]
+allow = ["noop"]
+
+[profiles.review.provider]
+provider = "gemini"
+api_key = "key"
+model = "gemini-3-flash-preview"
+"#,
+        )
+        .unwrap();
+        let registry = ToolRegistry::with_profile(config.profile("review").unwrap().clone());
+        registry.register(NoopTool).unwrap();
+        registry.register(EchoTool).unwrap();
+
+        let specs = registry.specs();
+        assert_eq!(specs.len(), 1);
+        assert_eq!(specs[0].name(), "noop");
+        assert!(registry.spec("echo").is_none());
+        assert!(registry.tool_allowed("noop"));
+
+        let err = registry
+            .execute("echo", "call_1".to_string(), &serde_json::json!({"message": "hi"}))
+            .unwrap_err();
+        assert!(err.to_string().contains("not available in profile 'review'"));
+        assert!(err.to_string().contains("Available tools: noop"));
+    }
+
+    #[test]
+    fn test_unknown_profile_tools() {
+        let config = thunderus_core::Config::from_toml_str(
+            r#"
+default_profile = "review"
+
+[profiles.review]
+name = "review"
+working_root = "/workspace"
+
+[profiles.review.tools]
+allow = ["noop", "raed"]
+deny = ["shel", "raed", "echo"]
+
+[profiles.review.provider]
+provider = "gemini"
+api_key = "key"
+model = "gemini-3-flash-preview"
+"#,
+        )
+        .unwrap();
+        let registry = ToolRegistry::with_profile(config.profile("review").unwrap().clone());
+        registry.register(NoopTool).unwrap();
+        registry.register(EchoTool).unwrap();
+
+        assert_eq!(
+            registry.unknown_profile_tools(),
+            vec!["raed".to_string(), "shel".to_string()]
+        );
+        assert!(ToolRegistry::new().unknown_profile_tools().is_empty());
+    }
+
     #[test]
     fn test_execute_tool() {
         let registry = ToolRegistry::new();
@@ -593,7 +876,7 @@ mod tests {
         let mut registry = ToolRegistry::new();
         let gate = ApprovalGate::new(ApprovalMode::ReadOnly, false);
         registry.set_approval_gate(gate);
-        registry.register(EditTool).unwrap();
+        registry.register(EditTool::default()).unwrap();
 
         let edit_args = serde_json::json!({
             "file_path": "/tmp/test.txt",
@@ -609,7 +892,7 @@ mod tests {
         let mut registry = ToolRegistry::new();
         let gate = ApprovalGate::new(ApprovalMode::FullAccess, false);
         registry.set_approval_gate(gate);
-        registry.register(EditTool).unwrap();
+        registry.register(EditTool::default()).unwrap();
 
         let edit_args = serde_json::json!({
             "file_path": "/tmp/test.txt",
@@ -626,7 +909,7 @@ mod tests {
         let gate = ApprovalGate::new(ApprovalMode::Auto, false);
         registry.set_approval_gate(gate);
         registry.set_workspace_roots(vec![PathBuf::from("/workspace")]);
-        registry.register(EditTool).unwrap();
+        registry.register(EditTool::default()).unwrap();
 
         let workspace_args = serde_json::json!({
             "file_path": "/workspace/src/main.rs",
@@ -651,7 +934,7 @@ mod tests {
     #[test]
     fn test_no_approval_gate_allows_all() {
         let registry = ToolRegistry::new();
-        registry.register(EditTool).unwrap();
+        registry.register(EditTool::default()).unwrap();
 
         let edit_args = serde_json::json!({
             "file_path": "/any/path/test.txt",
@@ -709,12 +992,196 @@ mod tests {
         assert!(tools.contains(&"echo".to_string()));
         assert!(tools.contains(&"grep".to_string()));
         assert!(tools.contains(&"glob".to_string()));
+        assert!(tools.contains(&"find".to_string()));
+        assert!(tools.contains(&"tree".to_string()));
         assert!(tools.contains(&"read".to_string()));
         assert!(tools.contains(&"shell".to_string()));
         assert!(tools.contains(&"patch".to_string()));
         assert!(tools.contains(&"write".to_string()));
         assert!(tools.contains(&"edit".to_string()));
         assert!(tools.contains(&"multiedit".to_string()));
-        assert_eq!(tools.len(), 10);
+        assert!(tools.contains(&"sed".to_string()));
+        assert_eq!(tools.len(), 13);
+    }
+
+    #[test]
+    fn test_read_only_registry_excludes_mutating_tools() {
+        let registry = ToolRegistry::with_read_only_tools();
+
+        for name in ["edit", "multiedit", "sed", "write", "patch"] {
+            assert!(!registry.has(name), "{name} should not be registered");
+        }
+        assert!(registry.has("shell"));
+        assert_eq!(registry.tool_is_read_only("shell"), Some(true));
+        assert!(
+            registry
+                .list()
+                .iter()
+                .all(|name| registry.tool_risk(name) == Some(thunderus_core::ToolRisk::Safe))
+        );
+
+        let result = registry
+            .execute(
+                "shell",
+                "call_1".to_string(),
+                &serde_json::json!({"command": "touch should_not_exist"}),
+            )
+            .unwrap();
+        assert!(result.error.unwrap().contains("read-only"));
+
+        let result = registry
+            .execute(
+                "shell",
+                "call_2".to_string(),
+                &serde_json::json!({"command": "echo advisor"}),
+            )
+            .unwrap();
+        assert!(result.is_success());
+        assert!(result.content.contains("advisor"));
+    }
+
+    #[test]
+    fn test_loaded_skills_metadata() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let skill_dir = temp_dir.path().join("lint-check");
+        std::fs::create_dir_all(&skill_dir).unwrap();
+        std::fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: lint-check\ndescription: Run the linter\nrisk_level: moderate\n---\n\n# lint-check\n",
+        )
+        .unwrap();
+        std::fs::write(skill_dir.join("run.sh"), "#!/bin/sh\necho ok\n").unwrap();
+
+        let mut loader = SkillLoader::with_paths(
+            temp_dir.path().to_path_buf(),
+            PathBuf::new(),
+            thunderus_skills::SkillsConfig::default(),
+        )
+        .unwrap();
+        let registry = ToolRegistry::new();
+        assert!(registry.loaded_skills().is_empty());
+
+        assert_eq!(registry.load_skills_from_loader(&mut loader).unwrap(), 1);
+        let skills = registry.loaded_skills();
+        assert_eq!(skills.len(), 1);
+        assert_eq!(skills[0].name, "lint-check");
+        assert_eq!(skills[0].driver, SkillDriver::Shell);
+        assert_eq!(skills[0].risk_level, thunderus_skills::SkillRisk::Moderate);
+    }
+
+    #[test]
+    fn test_specs_for_message_filters_triggered_skills() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        for (name, frontmatter) in [
+            ("db-migrate", "triggers:\n  - migration\n  - /schema (change|update)/\n"),
+            ("always-on", ""),
+        ] {
+            let skill_dir = temp_dir.path().join(name);
+            std::fs::create_dir_all(&skill_dir).unwrap();
+            std::fs::write(
+                skill_dir.join("SKILL.md"),
+                format!("---\nname: {name}\ndescription: Skill {name}\n{frontmatter}---\n\n# {name}\n"),
+            )
+            .unwrap();
+            std::fs::write(skill_dir.join("run.sh"), "#!/bin/sh\necho ok\n").unwrap();
+        }
+
+        let mut loader = SkillLoader::with_paths(
+            temp_dir.path().to_path_buf(),
+            PathBuf::new(),
+            thunderus_skills::SkillsConfig::default(),
+        )
+        .unwrap();
+        let registry = ToolRegistry::with_builtin_tools();
+        assert_eq!(registry.load_skills_from_loader(&mut loader).unwrap(), 2);
+
+        let names = |message: &str| -> Vec<String> {
+            registry
+                .specs_for_message(message)
+                .iter()
+                .map(|spec| spec.name().to_string())
+                .collect()
+        };
+
+        let unrelated = names("fix the login page");
+        assert!(unrelated.contains(&"always-on".to_string()));
+        assert!(!unrelated.contains(&"db-migrate".to_string()));
+        assert!(unrelated.contains(&"read".to_string()));
+
+        assert!(names("add a Migration for the users table").contains(&"db-migrate".to_string()));
+        assert!(names("plan the schema update").contains(&"db-migrate".to_string()));
+
+        let triggered = registry.triggered_skills("add a migration");
+        assert_eq!(triggered.len(), 1);
+        assert_eq!(triggered[0].0.name, "db-migrate");
+        assert_eq!(triggered[0].1, "migration");
+    }
+
+    #[test]
+    fn test_skill_names_never_shadow_builtins() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let skill_dir = temp_dir.path().join("read");
+        std::fs::create_dir_all(&skill_dir).unwrap();
+        std::fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: read\ndescription: Read from the wiki\n---\n\n# read\n",
+        )
+        .unwrap();
+        std::fs::write(skill_dir.join("run.sh"), "#!/bin/sh\necho wiki\n").unwrap();
+
+        let loader = |namespace_tools: bool| {
+            let config = thunderus_skills::SkillsConfig { namespace_tools, ..Default::default() };
+            SkillLoader::with_paths(temp_dir.path().to_path_buf(), PathBuf::new(), config).unwrap()
+        };
+
+        let registry = ToolRegistry::with_builtin_tools();
+        assert_eq!(registry.load_skills_from_loader(&mut loader(true)).unwrap(), 1);
+        assert!(registry.has("read"));
+        assert!(registry.has("skill:read"));
+
+        let registry = ToolRegistry::with_builtin_tools();
+        assert_eq!(registry.load_skills_from_loader(&mut loader(false)).unwrap(), 0);
+        assert!(registry.loaded_skills().is_empty());
+        assert_eq!(
+            registry.spec("read").unwrap().description(),
+            Some(ReadTool.description())
+        );
+
+        let skill = thunderus_skills::parse_skill(&skill_dir).unwrap();
+        let err = registry.register_skill(skill, false).unwrap_err();
+        assert!(err.to_string().contains("collides with built-in tool 'read'"));
+    }
+
+    #[test]
+    fn test_skills_config_follows_profile() {
+        let registry = ToolRegistry::new();
+        assert!(!registry.skills_config().namespace_tools);
+
+        let config = thunderus_core::Config::from_toml_str(
+            r#"
+default_profile = "default"
+
+[profiles.default]
+name = "default"
+working_root = "/workspace"
+
+[profiles.default.skills]
+enabled = false
+skills_dir = "/workspace/skills"
+namespace_tools = true
+
+[profiles.default.provider]
+provider = "gemini"
+api_key = "key"
+model = "gemini-3-flash-preview"
+"#,
+        )
+        .unwrap();
+        let registry = ToolRegistry::with_profile(config.profile("default").unwrap().clone());
+        let skills = registry.skills_config();
+        assert!(skills.namespace_tools);
+        assert!(!skills.enabled);
+        assert!(skills.auto_discovery);
+        assert_eq!(skills.skills_dir, Some(PathBuf::from("/workspace/skills")));
     }
 }
@@ -14,6 +14,9 @@ use serde::{Deserialize, Serialize};
 pub struct FormattedResult {
     /// The tool that was executed
     pub tool: String,
+    /// What the tool acted on, such as a file path or search pattern (optional)
+    #[serde(default)]
+    pub subject: Option<String>,
     /// Whether the operation succeeded
     pub success: bool,
     /// A brief, human-readable summary of the result
@@ -33,6 +36,7 @@ impl FormattedResult {
     pub fn success(tool: impl Into<String>, summary: impl Into<String>, details: impl Into<String>) -> Self {
         Self {
             tool: tool.into(),
+            subject: None,
             success: true,
             summary: summary.into(),
             details: details.into(),
@@ -46,6 +50,7 @@ impl FormattedResult {
     pub fn error(tool: impl Into<String>, summary: impl Into<String>, details: impl Into<String>) -> Self {
         Self {
             tool: tool.into(),
+            subject: None,
             success: false,
             summary: summary.into(),
             details: details.into(),
@@ -61,6 +66,7 @@ impl FormattedResult {
     ) -> Self {
         Self {
             tool: tool.into(),
+            subject: None,
             success: exit_code == 0,
             summary: summary.into(),
             details: details.into(),
@@ -70,6 +76,12 @@ impl FormattedResult {
         }
     }
 
+    /// Set what the tool acted on
+    pub fn with_subject(mut self, subject: impl Into<String>) -> Self {
+        self.subject = Some(subject.into());
+        self
+    }
+
     /// Add next steps to the result
     pub fn with_next_steps(mut self, steps: Vec<String>) -> Self {
         self.next_steps = Some(steps);
@@ -112,6 +124,27 @@ impl FormattedResult {
         output
     }
 
+    /// One-line header shared by every tool, e.g. `read src/main.rs — 240 lines`
+    pub fn header(&self) -> String {
+        let status = if self.success { "" } else { " failed" };
+        match &self.subject {
+            Some(subject) => format!("{} {}{} — {}", self.tool, subject, status, self.summary),
+            None => format!("{}{} — {}", self.tool, status, self.summary),
+        }
+    }
+
+    /// Format as the header line followed by the detailed body
+    ///
+    /// This is the shape tool results are returned in, so the first line of
+    /// any result is enough to describe it in a collapsed card.
+    pub fn to_text(&self) -> String {
+        if self.details.is_empty() {
+            self.header()
+        } else {
+            format!("{}\n\n{}", self.header(), self.details)
+        }
+    }
+
     /// Format as a compact single-line output
     pub fn to_compact(&self) -> String {
         if self.success {
@@ -122,6 +155,11 @@ impl FormattedResult {
     }
 }
 
+/// Format a count with the right noun form, e.g. `1 line` or `3 lines`
+pub(crate) fn counted(count: usize, singular: &str, plural: &str) -> String {
+    format!("{} {}", count, if count == 1 { singular } else { plural })
+}
+
 /// Formatter for Grep tool results
 pub struct GrepFormatter;
 
@@ -160,6 +198,20 @@ impl GlobFormatter {
             )
         }
     }
+
+    /// Note describing which slice of the matches a page holds
+    pub fn format_page_note(offset: usize, shown: usize, total: usize) -> String {
+        if shown == 0 {
+            return format!("Offset {} is past the end of {} matching file(s).", offset, total);
+        }
+
+        let end = offset + shown;
+        let mut note = format!("Showing {}–{} of {} matching file(s).", offset + 1, end, total);
+        if end < total {
+            note.push_str(&format!(" Use offset={} to see the next page.", end));
+        }
+        note
+    }
 }
 
 /// Formatter for Read tool results
@@ -244,6 +296,16 @@ mod tests {
         assert!(result.to_compact().contains("Found 3 matches"));
     }
 
+    #[test]
+    fn test_formatted_result_header() {
+        let result = FormattedResult::success("read", "240 lines", "1→fn main() {}").with_subject("src/main.rs");
+        assert_eq!(result.header(), "read src/main.rs — 240 lines");
+        assert_eq!(result.to_text(), "read src/main.rs — 240 lines\n\n1→fn main() {}");
+
+        let result = FormattedResult::with_exit_code("shell", "exit 2", "", 2).with_subject("`false`");
+        assert_eq!(result.to_text(), "shell `false` failed — exit 2");
+    }
+
     #[test]
     fn test_formatted_result_error() {
         let result = FormattedResult::error("edit", "Edit failed", "old_string not found");
@@ -289,6 +351,19 @@ mod tests {
         assert!(output.contains("src/lib.rs"));
     }
 
+    #[test]
+    fn test_glob_formatter_page_note() {
+        assert_eq!(
+            GlobFormatter::format_page_note(0, 100, 3421),
+            "Showing 1–100 of 3421 matching file(s). Use offset=100 to see the next page."
+        );
+        assert_eq!(
+            GlobFormatter::format_page_note(3400, 21, 3421),
+            "Showing 3401–3421 of 3421 matching file(s)."
+        );
+        assert!(GlobFormatter::format_page_note(50, 0, 10).contains("past the end"));
+    }
+
     #[test]
     fn test_read_formatter() {
         let content = "line 1\nline 2\nline 3";
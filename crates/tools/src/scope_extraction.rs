@@ -161,7 +161,7 @@ pub fn extract_scope(tool_name: &str, arguments: &Value) -> ScopeInfo {
         t if t.contains("delete") || t.contains("remove") => extract_delete_scope(arguments),
 
         t if t.contains("grep") || t.contains("search") => extract_search_scope(arguments),
-        t if t.contains("glob") || t.contains("find") => extract_glob_scope(arguments),
+        t if t.contains("glob") || t.contains("find") || t.contains("tree") => extract_glob_scope(arguments),
 
         t if t.contains("shell") || t.contains("exec") || t.contains("command") => extract_shell_scope(arguments),
 
@@ -5,6 +5,7 @@
 
 use thunderus_core::Result;
 use thunderus_core::{BlockedCommandError, PatchQueueManager, Session};
+use thunderus_providers::CancelToken;
 use thunderus_providers::ToolCall;
 use thunderus_providers::ToolResult;
 
@@ -28,10 +29,14 @@ pub struct SessionToolDispatcher {
 
 impl SessionToolDispatcher {
     /// Creates a new session-aware dispatcher
+    ///
+    /// The edit tools in `dispatcher` are bound to `read_history`, so they only
+    /// edit lines read earlier in the session.
     pub fn new(
         dispatcher: ToolDispatcher, session: Session, read_history: ReadHistory,
         patch_queue_manager: Option<PatchQueueManager>,
     ) -> Self {
+        dispatcher.registry().set_read_history(&read_history);
         Self { dispatcher, session, read_history, patch_queue_manager }
     }
 
@@ -51,8 +56,16 @@ impl SessionToolDispatcher {
 
     /// Executes a tool call and logs to session
     pub fn execute(&mut self, tool_call: &ToolCall) -> Result<ToolResult> {
+        self.execute_cancellable(tool_call, &CancelToken::new())
+    }
+
+    /// Executes a tool call and logs to session, aborting it if `cancel_token` is cancelled
+    ///
+    /// A cancelled call is logged as a failed result with a "cancelled" error.
+    pub fn execute_cancellable(&mut self, tool_call: &ToolCall, cancel_token: &CancelToken) -> Result<ToolResult> {
         let tool_name = tool_call.name();
         let arguments = tool_call.arguments();
+        let call_id = tool_call.id.as_str();
 
         if tool_name == "shell"
             && let Some(command) = arguments.get("command").and_then(|v| v.as_str())
@@ -60,8 +73,11 @@ impl SessionToolDispatcher {
             let classification = classify_shell_command(command);
             if classification.risk.is_blocked() {
                 let blocked_error = Self::create_blocked_error(command, &classification.reasoning);
-                let _ = self.session.append_tool_call(tool_name, arguments.clone());
-                let _ = self.session.append_tool_result(
+                let _ = self
+                    .session
+                    .append_tool_call_with_id(call_id, tool_name, arguments.clone());
+                let _ = self.session.append_tool_result_with_id(
+                    call_id,
                     tool_name,
                     serde_json::json!(null),
                     false,
@@ -79,20 +95,38 @@ impl SessionToolDispatcher {
                 "Write blocked! File '{}' has been modified by the user and is protected. Use ReadTool to re-sync or ask user for permission.",
                 path.display()
             );
-            let _ = self.session.append_tool_call(tool_name, arguments.clone());
             let _ = self
                 .session
-                .append_tool_result(tool_name, serde_json::json!(null), false, Some(error_msg.clone()));
+                .append_tool_call_with_id(call_id, tool_name, arguments.clone());
+            let _ = self.session.append_tool_result_with_id(
+                call_id,
+                tool_name,
+                serde_json::json!(null),
+                false,
+                Some(error_msg.clone()),
+            );
             return Err(thunderus_core::Error::Tool(error_msg));
         }
 
-        let _ = self.session.append_tool_call(tool_name, arguments.clone());
+        let _ = self
+            .session
+            .append_tool_call_with_id(call_id, tool_name, arguments.clone());
 
-        let result = self.dispatcher.execute(tool_call);
+        let result = self.dispatcher.execute_cancellable(tool_call, cancel_token);
 
         match &result {
+            Ok(tool_result) if tool_result.is_cancelled() => {
+                let _ = self.session.append_tool_result_with_id(
+                    call_id,
+                    tool_name,
+                    serde_json::json!(null),
+                    false,
+                    tool_result.error.clone(),
+                );
+            }
             Ok(tool_result) => {
-                let _ = self.session.append_tool_result(
+                let _ = self.session.append_tool_result_with_id(
+                    call_id,
                     tool_name,
                     serde_json::json!({ "content": tool_result.content }),
                     true,
@@ -115,9 +149,13 @@ impl SessionToolDispatcher {
                 }
             }
             Err(e) => {
-                let _ = self
-                    .session
-                    .append_tool_result(tool_name, serde_json::json!(null), false, Some(e.to_string()));
+                let _ = self.session.append_tool_result_with_id(
+                    call_id,
+                    tool_name,
+                    serde_json::json!(null),
+                    false,
+                    Some(e.to_string()),
+                );
             }
         }
 
@@ -162,7 +200,7 @@ impl SessionToolDispatcher {
         };
 
         if tool_result.is_success() {
-            let line_count = tool_result.content.lines().count();
+            let line_count = count_numbered_lines(&tool_result.content);
             let offset = arguments.get("offset").and_then(|v| v.as_u64()).unwrap_or(0) as usize;
 
             self.read_history.record_read(file_path, line_count, offset);
@@ -225,17 +263,19 @@ impl SessionToolDispatcher {
 
     /// Enqueues a patch from a successful patch tool result
     ///
-    /// Parses the patch tool output and creates a Patch object in the queue.
+    /// Reads the diff from the result metadata and creates a Patch object in the queue.
     fn enqueue_patch_from_result(&mut self, tool_result: &ToolResult, arguments: &serde_json::Value) {
         let Some(ref mut queue_manager) = self.patch_queue_manager else {
             return;
         };
 
-        let content = &tool_result.content;
-        let diff_start = content.find("\n\n");
-        let diff = match diff_start {
-            Some(idx) => content[idx + 2..].trim(),
-            None => return,
+        let Some(diff) = tool_result
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get("diff"))
+            .and_then(|diff| diff.as_str())
+        else {
+            return;
         };
 
         let file_path = match arguments.get("file_path").and_then(|v| v.as_str()) {
@@ -285,7 +325,7 @@ impl SessionToolDispatcher {
 
     /// Returns true if the tool name corresponds to a write-related tool
     fn is_write_tool(&self, name: &str) -> bool {
-        matches!(name, "write" | "patch" | "edit" | "multiedit")
+        matches!(name, "write" | "patch" | "edit" | "multiedit" | "sed")
     }
 
     /// Extracts the target file path from tool arguments
@@ -297,6 +337,7 @@ impl SessionToolDispatcher {
             "patch" => arguments.get("file").and_then(|v| v.as_str()),
             "edit" => arguments.get("path").and_then(|v| v.as_str()),
             "multiedit" => None,
+            "sed" if arguments.get("glob").is_none() => arguments.get("path").and_then(|v| v.as_str()),
             _ => None,
         };
 
@@ -304,6 +345,20 @@ impl SessionToolDispatcher {
     }
 }
 
+/// Counts the file lines in Read tool output
+///
+/// Only `N→text` lines are counted, so truncation notices and "offset beyond
+/// file" messages don't widen the range recorded as read.
+fn count_numbered_lines(content: &str) -> usize {
+    content
+        .lines()
+        .filter(|line| {
+            line.split_once('\u{2192}')
+                .is_some_and(|(num, _)| !num.is_empty() && num.bytes().all(|b| b.is_ascii_digit()))
+        })
+        .count()
+}
+
 /// Validates that a file has been read before allowing edits
 pub fn validate_read_before_edit(dispatcher: &SessionToolDispatcher, file_path: &str) -> Result<()> {
     read_history::validate_read_before_edit(dispatcher.read_history(), file_path)
@@ -370,6 +425,33 @@ mod tests {
         assert!(has_file_read);
     }
 
+    #[test]
+    fn test_patch_result_queues_raw_diff() {
+        let temp = TempDir::new().unwrap();
+        let agent_dir = thunderus_core::AgentDir::new(temp.path());
+        let session = Session::new(agent_dir.clone()).unwrap();
+        let queue = PatchQueueManager::new(session.id.clone(), agent_dir);
+
+        let registry = ToolRegistry::new();
+        registry.register(builtin::PatchTool).unwrap();
+        let mut dispatcher =
+            SessionToolDispatcher::with_history_and_queue(ToolDispatcher::new(registry), session, queue);
+
+        let test_file = temp.path().join("lib.rs");
+        std::fs::write(&test_file, "fn a() {}\n").unwrap();
+        let tool_call = thunderus_providers::ToolCall::new(
+            "call_1",
+            "patch",
+            serde_json::json!({"file_path": test_file.to_string_lossy().as_ref(), "new_content": "fn b() {}\n"}),
+        );
+        dispatcher.execute(&tool_call).unwrap();
+
+        let patches = dispatcher.patch_queue_manager().unwrap().patches();
+        assert_eq!(patches.len(), 1);
+        assert!(patches[0].diff.starts_with("---") || patches[0].diff.starts_with("diff"));
+        assert!(patches[0].diff.contains("+fn b() {}"));
+    }
+
     #[test]
     fn test_validate_read_before_edit() {
         let (temp, mut dispatcher) = create_test_dispatcher();
@@ -394,6 +476,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_read_history_rebuilt_from_session_events() {
+        let (temp, mut dispatcher) = create_test_dispatcher();
+
+        let test_file = temp.path().join("test.txt");
+        let content: Vec<String> = (1..=20).map(|i| format!("Line {}", i)).collect();
+        std::fs::write(&test_file, content.join("\n")).unwrap();
+        let path = test_file.to_str().unwrap();
+
+        let tool_call = thunderus_providers::ToolCall::new(
+            "call_1",
+            "read",
+            serde_json::json!({"file_path": path, "offset": 5, "limit": 5}),
+        );
+        dispatcher.execute(&tool_call).unwrap();
+
+        let events = dispatcher.session().read_events().unwrap();
+        let history = ReadHistory::from_events(events.iter().map(|e| &e.event));
+
+        assert_eq!(history.was_read(path), Some((5, 5)));
+        assert!(history.covers_lines(path, 5..10));
+        assert!(!history.covers_lines(path, 0..5));
+        assert!(!history.covers_lines(path, 9..11));
+    }
+
+    #[test]
+    fn test_count_numbered_lines() {
+        assert_eq!(count_numbered_lines("1\u{2192}a\n2\u{2192}b"), 2);
+        assert_eq!(
+            count_numbered_lines("1\u{2192}long\n[Line truncated at 2000 characters]\n2\u{2192}b"),
+            2
+        );
+        assert_eq!(
+            count_numbered_lines("Offset 10 is beyond file length (3 lines). File: x"),
+            0
+        );
+    }
+
     #[test]
     fn test_execute_batch() {
         let (temp, mut dispatcher) = create_test_dispatcher();
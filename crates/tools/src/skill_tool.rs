@@ -20,6 +20,9 @@ pub struct SkillTool {
 
     /// The main script to execute
     script: Arc<SkillScript>,
+
+    /// Name the tool is registered and advertised under
+    name: String,
 }
 
 impl SkillTool {
@@ -33,12 +36,24 @@ impl SkillTool {
 
         let script = Arc::new(script);
 
-        Self { meta, script }
+        let name = Self::registered_name(&meta.name, false);
+
+        Self { meta, script, name }
+    }
+
+    /// Expose the tool as "skill:name" instead of the bare skill name.
+    pub fn with_namespace(mut self, namespaced: bool) -> Self {
+        self.name = Self::registered_name(&self.meta.name, namespaced);
+        self
+    }
+
+    fn registered_name(skill: &str, namespaced: bool) -> String {
+        if namespaced { format!("skill:{skill}") } else { skill.to_string() }
     }
 
-    /// Get the skill name formatted as a tool name ("skill:name").
+    /// Get the name the tool is registered and advertised under ("skill:name" when namespaced).
     pub fn tool_name(&self) -> String {
-        format!("skill:{}", self.meta.name)
+        self.name.clone()
     }
 
     /// Execute the skill's main script with the given arguments.
@@ -78,6 +93,12 @@ impl SkillTool {
                 }
                 cmd.output()?
             }
+            thunderus_skills::ScriptType::Wasm => {
+                return Err(thunderus_core::Error::Tool(format!(
+                    "{} is a WASM module and needs the wasm driver",
+                    self.script.name
+                )));
+            }
             thunderus_skills::ScriptType::Unknown => {
                 return Err(thunderus_core::Error::Tool(format!(
                     "Unknown script type for {}",
@@ -99,6 +120,7 @@ impl SkillTool {
             error,
             risk_level: Some(self.risk_level()),
             classification_reasoning: self.classification().map(|c| c.reasoning),
+            metadata: None,
         })
     }
 
@@ -149,7 +171,7 @@ impl SkillTool {
 
 impl Tool for SkillTool {
     fn name(&self) -> &str {
-        &self.meta.name
+        &self.name
     }
 
     fn description(&self) -> &str {
@@ -266,7 +288,12 @@ echo "Skill executed successfully"
         let temp_dir = TempDir::new().unwrap();
         let skill = create_test_skill(temp_dir.path(), "my-skill");
         let tool = SkillTool::new(skill);
+        assert_eq!(tool.tool_name(), "my-skill");
+        assert_eq!(tool.name(), tool.tool_name());
+
+        let tool = tool.with_namespace(true);
         assert_eq!(tool.tool_name(), "skill:my-skill");
+        assert_eq!(tool.name(), "skill:my-skill");
     }
 
     #[test]
@@ -289,7 +316,7 @@ echo "Skill executed successfully"
 
         let tool = SkillTool::new(skill);
         let spec = tool.spec();
-        assert_eq!(spec.name(), "skill:test-skill");
+        assert_eq!(spec.name(), "test-skill");
         assert!(spec.description().is_some_and(|d| d.contains("Skill:")));
     }
 }
@@ -21,6 +21,33 @@ pub enum ErrorCategory {
     System,
 }
 
+/// Marker that precedes the category in [`TeachingError::format`] output
+const CATEGORY_MARKER: &str = "**Error Type**: ";
+
+impl ErrorCategory {
+    /// Whether the model can plausibly fix this error by calling the tool again
+    ///
+    /// Usage, not-found and ambiguity errors come from bad arguments; safety and
+    /// system errors would fail the same way on a retry.
+    pub fn is_retryable(self) -> bool {
+        matches!(self, Self::Usage | Self::NotFound | Self::Ambiguity)
+    }
+
+    /// Recover the category from a formatted teaching message
+    pub fn from_message(message: &str) -> Option<Self> {
+        let start = message.find(CATEGORY_MARKER)? + CATEGORY_MARKER.len();
+        let name = message[start..].split_whitespace().next()?;
+        match name {
+            "Usage" => Some(Self::Usage),
+            "Safety" => Some(Self::Safety),
+            "NotFound" => Some(Self::NotFound),
+            "Ambiguity" => Some(Self::Ambiguity),
+            "System" => Some(Self::System),
+            _ => None,
+        }
+    }
+}
+
 /// A teaching error with context and guidance
 #[derive(Debug, Clone)]
 pub struct TeachingError {
@@ -50,7 +77,7 @@ impl TeachingError {
         let mut output = String::new();
 
         output.push_str(&format!("## {}: {}\n", self.tool, self.summary));
-        output.push_str(&format!("**Error Type**: {:?}\n\n", self.category));
+        output.push_str(&format!("{}{:?}\n\n", CATEGORY_MARKER, self.category));
         output.push_str(&format!("**Explanation**:\n{}\n\n", self.explanation));
 
         if !self.next_steps.is_empty() {
@@ -288,6 +315,88 @@ impl GrepErrors {
     }
 }
 
+/// Builder for argument errors caught before a tool runs
+pub struct ArgumentErrors;
+
+impl ArgumentErrors {
+    /// Error: arguments are not a JSON object
+    pub fn not_an_object(tool: &str, actual: &str) -> TeachingError {
+        TeachingError::new(
+            tool,
+            ErrorCategory::Usage,
+            "Arguments must be an object",
+            format!(
+                "The {} tool expects its arguments as a JSON object, but received {}.",
+                tool, actual
+            ),
+            vec!["Pass arguments as named fields, e.g. {\"name\": \"value\"}".to_string()],
+        )
+    }
+
+    /// Error: a required parameter is missing
+    pub fn missing_parameter(tool: &str, param: &str) -> TeachingError {
+        TeachingError::new(
+            tool,
+            ErrorCategory::Usage,
+            format!("Missing required parameter '{}'", param),
+            format!("The {} tool cannot run without the '{}' parameter.", tool, param),
+            vec![
+                format!("Add the '{}' parameter to the tool call", param),
+                "Check the tool's schema for the full list of required parameters".to_string(),
+            ],
+        )
+    }
+
+    /// Error: a parameter has the wrong JSON type
+    pub fn mistyped_parameter(tool: &str, param: &str, expected: &str, actual: &str) -> TeachingError {
+        TeachingError::new(
+            tool,
+            ErrorCategory::Usage,
+            format!("Parameter '{}' has the wrong type", param),
+            format!(
+                "The '{}' parameter must be of type {}, but received {}.",
+                param, expected, actual
+            ),
+            vec![format!("Pass '{}' as a JSON {}", param, expected)],
+        )
+    }
+
+    /// Error: a string parameter is not one of its allowed values
+    pub fn invalid_choice(tool: &str, param: &str, actual: &str, variants: &[String]) -> TeachingError {
+        let allowed = variants
+            .iter()
+            .map(|v| format!("'{}'", v))
+            .collect::<Vec<_>>()
+            .join(", ");
+        TeachingError::new(
+            tool,
+            ErrorCategory::Usage,
+            format!("Invalid value for parameter '{}'", param),
+            format!("'{}' is not an allowed value for '{}'.", actual, param),
+            vec![format!("Use one of: {}", allowed)],
+        )
+    }
+
+    /// Error: a number parameter is outside its allowed range
+    pub fn out_of_range(
+        tool: &str, param: &str, actual: f64, minimum: Option<f64>, maximum: Option<f64>,
+    ) -> TeachingError {
+        let bounds = match (minimum, maximum) {
+            (Some(min), Some(max)) => format!("between {} and {}", min, max),
+            (Some(min), None) => format!("at least {}", min),
+            (None, Some(max)) => format!("at most {}", max),
+            (None, None) => "within range".to_string(),
+        };
+        TeachingError::new(
+            tool,
+            ErrorCategory::Usage,
+            format!("Parameter '{}' is out of range", param),
+            format!("The '{}' parameter must be {}, but received {}.", param, bounds, actual),
+            vec![format!("Pass a value for '{}' {}", param, bounds)],
+        )
+    }
+}
+
 /// Builder for common Glob tool errors
 pub struct GlobErrors;
 
@@ -376,6 +485,23 @@ impl ReadErrors {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_error_category_from_message() {
+        let err = EditErrors::old_string_not_unique("/path/to/file.rs", 3, "function foo()");
+        let message = format!("Validation error: {}", err.format());
+        assert_eq!(ErrorCategory::from_message(&message), Some(err.category));
+        assert_eq!(ErrorCategory::from_message("plain failure"), None);
+    }
+
+    #[test]
+    fn test_error_category_retryable() {
+        assert!(ErrorCategory::Usage.is_retryable());
+        assert!(ErrorCategory::NotFound.is_retryable());
+        assert!(ErrorCategory::Ambiguity.is_retryable());
+        assert!(!ErrorCategory::Safety.is_retryable());
+        assert!(!ErrorCategory::System.is_retryable());
+    }
+
     #[test]
     fn test_edit_error_not_unique() {
         let err = EditErrors::old_string_not_unique("/path/to/file.rs", 3, "function foo()");
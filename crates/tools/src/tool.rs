@@ -1,6 +1,6 @@
 use serde_json::Value;
 use thunderus_core::{Classification, Result, ToolRisk};
-use thunderus_providers::{ToolParameter, ToolResult, ToolSpec};
+use thunderus_providers::{CancelToken, ToolParameter, ToolResult, ToolSpec};
 
 /// The core trait that all tools must implement
 pub trait Tool: Send + Sync + std::fmt::Debug {
@@ -43,11 +43,31 @@ pub trait Tool: Send + Sync + std::fmt::Debug {
         self.classification()
     }
 
+    /// Returns the unified diff this call would make, without touching the filesystem
+    ///
+    /// File-mutating tools override this so approval prompts can show the
+    /// concrete change. Returns `None` for tools that do not edit files or
+    /// when the arguments do not describe a valid edit.
+    fn preview_diff(&self, _: &Value) -> Option<String> {
+        None
+    }
+
     /// Executes the tool with the given arguments
     ///
     /// Returns a [ToolResult] containing the tool call ID and output or error
     fn execute(&self, tool_call_id: String, arguments: &Value) -> Result<ToolResult>;
 
+    /// Executes the tool, stopping early if `cancel_token` is cancelled
+    ///
+    /// Long-running tools should override this and return
+    /// [ToolResult::cancelled] once cancellation is observed. The default
+    /// ignores the token and runs [Tool::execute] to completion.
+    fn execute_cancellable(
+        &self, tool_call_id: String, arguments: &Value, _cancel_token: &CancelToken,
+    ) -> Result<ToolResult> {
+        self.execute(tool_call_id, arguments)
+    }
+
     /// Returns the full [ToolSpec] for this tool (for provider communication)
     fn spec(&self) -> ToolSpec {
         ToolSpec::new(self.name(), self.description(), self.parameters())
@@ -11,6 +11,7 @@ use thunderus_providers::{ToolParameter, ToolResult, ToolSpec};
 use thunderus_skills::{HostContext, PluginFunction, Skill, SkillMeta, SkillPermissions, SkillRisk};
 
 use crate::Tool;
+use crate::lazy_engine::LazyEngine;
 
 /// A WASM plugin wrapped as a Tool for integration with the tool registry.
 #[derive(Debug, Clone)]
@@ -26,12 +27,19 @@ pub struct WasmTool {
 
     /// Available functions for this plugin
     functions: Vec<PluginFunction>,
+
+    /// Name the tool is registered and advertised under
+    name: String,
+
+    /// Plugin runtime, instantiated on the first call
+    engine: LazyEngine<thunderus_skills::WasmEngine>,
 }
 
 impl WasmTool {
     /// Create a new WasmTool from a Skill.
     ///
-    /// Returns None if the skill doesn't have a WASM driver.
+    /// Returns None if the skill doesn't have a WASM driver. The plugin is not
+    /// loaded until the tool is first executed.
     pub fn new(skill: Skill) -> Option<Self> {
         if skill.meta.driver != thunderus_skills::SkillDriver::Wasm {
             return None;
@@ -44,11 +52,6 @@ impl WasmTool {
         };
 
         let wasm_path = skill.meta.path.join(&entry);
-
-        if !wasm_path.exists() {
-            return None;
-        }
-
         let permissions = skill.meta.permissions.clone();
 
         Some(Self {
@@ -56,12 +59,24 @@ impl WasmTool {
             wasm_path,
             permissions,
             functions: skill.meta.functions.clone(),
+            name: Self::registered_name(&skill.meta.name, false),
+            engine: LazyEngine::new(),
         })
     }
 
-    /// Get the tool name with "skill:" prefix.
+    /// Expose the tool as "skill:name" instead of the bare skill name.
+    pub fn with_namespace(mut self, namespaced: bool) -> Self {
+        self.name = Self::registered_name(&self.meta.name, namespaced);
+        self
+    }
+
+    fn registered_name(skill: &str, namespaced: bool) -> String {
+        if namespaced { format!("skill:{skill}") } else { skill.to_string() }
+    }
+
+    /// Get the name the tool is registered and advertised under ("skill:name" when namespaced).
     pub fn tool_name(&self) -> String {
-        format!("skill:{}", self.meta.name)
+        self.name.clone()
     }
 
     /// Get the risk level as a ToolRisk.
@@ -72,25 +87,40 @@ impl WasmTool {
         }
     }
 
+    /// Instantiate the WASM runtime for this skill.
+    #[cfg(feature = "wasm")]
+    fn load_engine(&self) -> Result<thunderus_skills::WasmEngine> {
+        let load_error = |reason: String| {
+            thunderus_core::Error::extension(format!("Failed to load WASM plugin '{}': {}", self.meta.name, reason))
+        };
+        if !self.wasm_path.exists() {
+            return Err(load_error(format!("module '{}' not found", self.wasm_path.display())));
+        }
+
+        let workspace_root = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
+        let context = HostContext::new(self.meta.name.clone(), workspace_root, self.permissions.clone());
+
+        let mut engine = thunderus_skills::WasmEngine::new();
+        engine
+            .load(self.meta.name.clone(), &self.wasm_path, context, self.functions.clone())
+            .map_err(|e| load_error(e.to_string()))?;
+        Ok(engine)
+    }
+
     /// Execute the WASM plugin.
     fn execute_wasm_plugin(&self, arguments: &Value) -> Result<ToolResult> {
         #[cfg(feature = "wasm")]
         {
-            let mut engine = thunderus_skills::WasmEngine::new();
-
-            let workspace_root = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
-            let context = HostContext::new(self.meta.name.clone(), workspace_root, self.permissions.clone());
-
-            let functions = self.functions.clone();
-            engine
-                .load(self.meta.name.clone(), &self.wasm_path, context, functions)
-                .map_err(|e| thunderus_core::Error::extension(e.to_string()))?;
-
             let function_name = self.resolve_function(arguments)?;
             let input = self.prepare_input(arguments);
-            let output = engine
-                .execute(&self.meta.name, &function_name, &input)
-                .map_err(|e| thunderus_core::Error::extension(e.to_string()))?;
+            let output = self.engine.with(
+                || self.load_engine(),
+                |engine| {
+                    engine
+                        .execute(&self.meta.name, &function_name, &input)
+                        .map_err(|e| thunderus_core::Error::extension(e.to_string()))
+                },
+            )?;
 
             Ok(ToolResult {
                 tool_call_id: String::new(),
@@ -98,6 +128,7 @@ impl WasmTool {
                 error: None,
                 risk_level: Some(self.risk_level()),
                 classification_reasoning: self.classification().map(|c| c.reasoning),
+                metadata: None,
             })
         }
 
@@ -109,6 +140,7 @@ impl WasmTool {
                 error: Some("Feature not enabled".to_string()),
                 risk_level: Some(ToolRisk::Safe),
                 classification_reasoning: None,
+                metadata: None,
             })
         }
     }
@@ -116,7 +148,7 @@ impl WasmTool {
 
 impl Tool for WasmTool {
     fn name(&self) -> &str {
-        &self.meta.name
+        &self.name
     }
 
     fn description(&self) -> &str {
@@ -253,12 +285,22 @@ fn tool_parameter_from_schema(schema: &Value) -> ToolParameter {
                 .and_then(|o| o.get("description"))
                 .and_then(|d| d.as_str())
                 .map(|s| s.to_string()),
+            variants: schema_obj
+                .and_then(|o| o.get("enum"))
+                .and_then(|v| v.as_array())
+                .map(|arr| {
+                    arr.iter()
+                        .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                        .collect::<Vec<_>>()
+                }),
         },
         Some("number") | Some("integer") => ToolParameter::Number {
             description: schema_obj
                 .and_then(|o| o.get("description"))
                 .and_then(|d| d.as_str())
                 .map(|s| s.to_string()),
+            minimum: schema_obj.and_then(|o| o.get("minimum")).and_then(|v| v.as_f64()),
+            maximum: schema_obj.and_then(|o| o.get("maximum")).and_then(|v| v.as_f64()),
         },
         Some("boolean") => ToolParameter::Boolean {
             description: schema_obj
@@ -338,4 +380,33 @@ Test skill
         let skill = thunderus_skills::parse_skill(&skill_dir).unwrap();
         assert!(WasmTool::new(skill).is_none());
     }
+
+    #[test]
+    fn test_wasm_tool_defers_loading_until_called() {
+        let temp_dir = TempDir::new().unwrap();
+        let skill_dir = temp_dir.path().join("broken-skill");
+        fs::create_dir_all(&skill_dir).unwrap();
+
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            r#"---
+name: broken-skill
+description: A skill whose entry is missing
+driver: wasm
+---
+Broken skill
+"#,
+        )
+        .unwrap();
+
+        let skill = thunderus_skills::parse_skill(&skill_dir).unwrap();
+        let tool = WasmTool::new(skill).expect("WASM skills register from their manifest");
+        assert_eq!(tool.name(), "broken-skill");
+        assert_eq!(tool.name(), tool.tool_name());
+        assert!(!tool.engine.is_loaded());
+
+        let err = tool.execute("call-1".to_string(), &serde_json::json!({})).unwrap_err();
+        assert!(err.to_string().contains("Failed to load WASM plugin 'broken-skill'"));
+        assert!(!tool.engine.is_loaded());
+    }
 }
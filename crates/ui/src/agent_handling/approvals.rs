@@ -1,6 +1,8 @@
 use crate::app::App;
+use crate::state::Activity;
+use crate::transcript::StatusType;
 
-use thunderus_core::{ActionType, ApprovalDecision, ToolRisk};
+use thunderus_core::{ActionType, ApprovalDecision, ApprovalRule, ToolRisk};
 
 impl App {
     /// Handle an approval request from the agent
@@ -40,18 +42,103 @@ impl App {
                 action_type_for_hint,
                 request.risk_level,
                 request.description.as_str(),
+                &self.dismissed_hints,
             ) && let Ok(Some(hint)) = session.get_hint_for_concept(&concept)
             {
-                self.state_mut().show_hint(hint);
+                self.state_mut().show_concept_hint(concept, hint);
             }
         }
 
-        self.transcript_mut()
-            .add_approval_prompt(format!("{}:{}", action_type_str, request.description), risk_str);
-        self.state_mut().approval_ui.pending_approval = Some(
-            crate::state::ApprovalState::pending(request.description.clone(), risk_str.to_string())
-                .with_request_id(request.id),
+        self.state_mut().stats_mut().increment_approval_gate();
+        let mut prompt = crate::transcript::TranscriptEntry::approval_prompt(
+            format!("{}:{}", action_type_str, request.description),
+            risk_str,
         );
+        if let Some(diff) = &request.context.diff {
+            prompt = prompt.with_approval_diff(diff.clone());
+        }
+        self.transcript_mut().add(prompt);
+
+        let mut approval_state =
+            crate::state::ApprovalState::pending(request.description.clone(), risk_str.to_string())
+                .with_request_id(request.id);
+        if !request.risk_level.is_blocked() {
+            approval_state = approval_state.with_rule(ApprovalRule::from_request(&request));
+        }
+        if let Some(config) = self.profile().map(|profile| &profile.approval)
+            && let Some(timeout) = config.timeout()
+        {
+            approval_state = approval_state.with_timeout(timeout, config.on_timeout.decision());
+        }
+        self.state_mut().approval_ui.pending_approval = Some(approval_state);
+        self.state_mut()
+            .set_activity(Activity::new("waiting for approval", StatusType::WaitingApproval));
+    }
+
+    /// Resolve the pending approval with its timeout decision once the deadline passes
+    ///
+    /// Returns true while a countdown is running (or just expired) so the caller can redraw.
+    pub fn check_approval_timeout(&mut self) -> bool {
+        let Some(approval_state) = self.state().approval_ui.pending_approval.as_ref() else {
+            return false;
+        };
+        let Some(timeout) = approval_state.timeout else {
+            return false;
+        };
+
+        if approval_state.is_timed_out() {
+            self.transcript_mut().add_system_message(format!(
+                "No response to the approval prompt within {}s.",
+                timeout.duration.as_secs()
+            ));
+            self.send_approval_response(timeout.decision);
+        }
+        true
+    }
+
+    /// Approve the pending action and auto-approve matching actions for the rest of the session
+    pub fn approve_and_remember(&mut self) {
+        let rule = self
+            .state()
+            .approval_ui
+            .pending_approval
+            .as_ref()
+            .and_then(|approval| approval.rule.clone());
+
+        match rule {
+            Some(rule) if self.remember_approval_rule(rule.clone()) => {
+                self.transcript_mut().add_system_message(format!(
+                    "Approving matching actions for this session: {}. Use /approvals clear to forget.",
+                    rule
+                ));
+            }
+            _ => self
+                .transcript_mut()
+                .add_system_message("This action can't be remembered; approving it once."),
+        }
+        self.send_approval_response(ApprovalDecision::Approved);
+    }
+
+    /// Stop showing the teaching hint for `concept` in this and future sessions
+    pub fn dismiss_hint_permanently(&mut self, concept: &str) {
+        self.dismissed_hints.dismiss(concept);
+        let Some(path) = thunderus_core::DismissedHints::default_path() else {
+            self.transcript_mut()
+                .add_system_message("Could not locate the user state directory; hint dismissed for now.");
+            return;
+        };
+
+        // Re-read so dismissals saved by other sessions since startup are kept
+        let mut dismissed = thunderus_core::DismissedHints::load(&path);
+        dismissed.dismiss(concept);
+        match dismissed.save(&path) {
+            Ok(()) => self
+                .transcript_mut()
+                .add_system_message("Hint dismissed. It won't be shown again."),
+            Err(e) => self
+                .transcript_mut()
+                .add_system_message(format!("Failed to save hint dismissal: {}", e)),
+        }
     }
 
     /// Send approval response back to agent
@@ -61,6 +148,18 @@ impl App {
     ///
     /// Also handles direct shell command approvals (!cmd) by executing approved commands.
     pub fn send_approval_response(&mut self, decision: ApprovalDecision) {
+        self.resolve_approval(decision, None);
+    }
+
+    /// Reject the pending action and pass the user's reason back to the agent
+    ///
+    /// The reason reaches the model with the rejected tool result and is kept
+    /// on the approval record and in the session log.
+    pub fn reject_with_reason(&mut self, reason: String) {
+        self.resolve_approval(ApprovalDecision::Rejected, Some(reason));
+    }
+
+    fn resolve_approval(&mut self, decision: ApprovalDecision, reason: Option<String>) {
         self.transcript_mut().set_approval_decision(decision);
 
         let pending_command = self.state_mut().approval_ui.pending_command.take();
@@ -68,7 +167,14 @@ impl App {
         match self.state_mut().approval_ui.pending_approval.take() {
             Some(approval_state) => {
                 let approved = matches!(decision, ApprovalDecision::Approved);
-                self.persist_approval(&approval_state.action, approved);
+                if self.state().is_generating() {
+                    let activity = match approval_state.rule.as_ref().and_then(|rule| rule.tool.as_deref()) {
+                        Some(tool) if approved => Activity::tool(tool),
+                        _ => Activity::new("thinking", StatusType::Generating),
+                    };
+                    self.state_mut().set_activity(activity);
+                }
+                self.persist_approval(&approval_state.action, approved, reason.as_deref());
 
                 if approved && let Some(command) = pending_command {
                     let registry = thunderus_tools::ToolRegistry::with_builtin_tools();
@@ -79,6 +185,7 @@ impl App {
                         ApprovalDecision::Approved => "approved",
                         ApprovalDecision::Rejected => "rejected",
                         ApprovalDecision::Cancelled => "cancelled",
+                        ApprovalDecision::AutoApproved => "auto-approved",
                     };
                     self.transcript_mut()
                         .add_system_message(format!("Shell command {}.", decision_str));
@@ -88,14 +195,22 @@ impl App {
                 if let Some(request_id) = approval_state.request_id
                     && let Some(ref handle) = self.approval_handle
                 {
-                    if handle.respond(request_id, decision) {
+                    let delivered = match &reason {
+                        Some(reason) => handle.reject_with_reason(request_id, reason.clone()),
+                        None => handle.respond(request_id, decision),
+                    };
+                    if delivered {
                         let decision_str = match decision {
                             ApprovalDecision::Approved => "approved",
                             ApprovalDecision::Rejected => "rejected",
                             ApprovalDecision::Cancelled => "cancelled",
+                            ApprovalDecision::AutoApproved => "auto-approved",
                         };
-                        self.transcript_mut()
-                            .add_system_message(format!("Action {}.", decision_str));
+                        let message = match &reason {
+                            Some(reason) => format!("Action {}: {}", decision_str, reason),
+                            None => format!("Action {}.", decision_str),
+                        };
+                        self.transcript_mut().add_system_message(message);
                     } else {
                         self.transcript_mut()
                             .add_system_message("Approval request timed out or was already cancelled.");
@@ -143,6 +258,7 @@ mod tests {
     use crate::app::create_test_app;
     use crate::state::ApprovalState;
     use crate::tui_approval::{TuiApprovalHandle, TuiApprovalProtocol};
+    use std::time::{Duration, Instant};
     use thunderus_core::ApprovalDecision;
 
     #[test]
@@ -192,6 +308,39 @@ mod tests {
         assert!(app.state().approval_ui.pending_approval.is_none());
     }
 
+    #[test]
+    fn test_reject_with_reason_reaches_protocol() {
+        let mut app = create_test_app();
+        let (tui_approval, _rx) = TuiApprovalProtocol::new();
+        app.approval_handle = Some(TuiApprovalHandle::from_protocol(&tui_approval));
+        let tui_approval = std::sync::Arc::new(tui_approval);
+
+        let request = thunderus_core::ApprovalRequest::new(
+            11,
+            thunderus_core::ActionType::Shell,
+            "Execute tool: shell",
+            thunderus_core::ApprovalContext::new(),
+            thunderus_core::ToolRisk::Risky,
+        );
+        let protocol = std::sync::Arc::clone(&tui_approval);
+        let waiter = std::thread::spawn(move || {
+            thunderus_core::ApprovalProtocol::request_response(protocol.as_ref(), &request).unwrap()
+        });
+        while tui_approval.pending_count() == 0 {
+            std::thread::sleep(Duration::from_millis(5));
+        }
+
+        app.state_mut().approval_ui.pending_approval =
+            Some(ApprovalState::pending("Execute tool: shell".to_string(), "risky".to_string()).with_request_id(11));
+        app.transcript_mut().add_approval_prompt("shell", "risky");
+        app.reject_with_reason("run it in the sandbox".to_string());
+
+        let response = waiter.join().unwrap();
+        assert_eq!(response.decision, ApprovalDecision::Rejected);
+        assert_eq!(response.message.as_deref(), Some("run it in the sandbox"));
+        assert!(app.state().approval_ui.pending_approval.is_none());
+    }
+
     #[test]
     fn test_send_approval_response_cancel() {
         let mut app = create_test_app();
@@ -208,4 +357,66 @@ mod tests {
 
         assert!(app.state().approval_ui.pending_approval.is_none());
     }
+
+    #[test]
+    fn test_check_approval_timeout_rejects_expired_prompt() {
+        let mut app = create_test_app();
+        let (tui_approval, _rx) = TuiApprovalProtocol::new();
+        app.approval_handle = Some(TuiApprovalHandle::from_protocol(&tui_approval));
+
+        let mut approval = ApprovalState::pending("write.file".to_string(), "risky".to_string())
+            .with_request_id(7)
+            .with_timeout(Duration::from_secs(60), ApprovalDecision::Rejected);
+        app.state_mut().approval_ui.pending_approval = Some(approval.clone());
+        app.transcript_mut().add_approval_prompt("write.file", "risky");
+
+        assert!(app.check_approval_timeout());
+        assert!(app.state().approval_ui.pending_approval.is_some());
+
+        approval.timeout.as_mut().unwrap().deadline = Instant::now() - Duration::from_secs(1);
+        app.state_mut().approval_ui.pending_approval = Some(approval);
+
+        assert!(app.check_approval_timeout());
+        assert!(app.state().approval_ui.pending_approval.is_none());
+        assert!(!app.check_approval_timeout());
+    }
+
+    #[test]
+    fn test_approve_and_remember_records_session_rule() {
+        let mut app = create_test_app();
+        let (tui_approval, _rx) = TuiApprovalProtocol::new();
+        app.approval_handle = Some(TuiApprovalHandle::from_protocol(&tui_approval));
+        let gate = std::sync::Arc::new(std::sync::RwLock::new(thunderus_core::ApprovalGate::new(
+            thunderus_core::ApprovalMode::Auto,
+            false,
+        )));
+        app.set_approval_gate_handle(std::sync::Arc::clone(&gate));
+
+        let request = thunderus_core::ApprovalRequest::new(
+            3,
+            thunderus_core::ActionType::FileWrite,
+            "Execute tool: edit",
+            thunderus_core::ApprovalContext::new()
+                .with_name("edit")
+                .with_arguments(serde_json::json!({"file_path": "src/app.rs"})),
+            thunderus_core::ToolRisk::Risky,
+        );
+        app.handle_approval_request(request);
+        app.approve_and_remember();
+
+        assert!(app.state().approval_ui.pending_approval.is_none());
+        let rules = gate.read().unwrap().session_rules().to_vec();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].scope, "src/*.rs");
+    }
+
+    #[test]
+    fn test_check_approval_timeout_without_timeout() {
+        let mut app = create_test_app();
+        app.state_mut().approval_ui.pending_approval =
+            Some(ApprovalState::pending("write.file".to_string(), "risky".to_string()).with_request_id(8));
+
+        assert!(!app.check_approval_timeout());
+        assert!(app.state().approval_ui.pending_approval.is_some());
+    }
 }
@@ -61,10 +61,10 @@ impl App {
         }
 
         match event {
-            thunderus_core::DriftEvent::FileSystemChange(paths) => {
+            thunderus_core::DriftEvent::FileSystemChange { paths, diffs } => {
                 let paths_str = paths
                     .iter()
-                    .map(|p| p.file_name().and_then(|n| n.to_str()).unwrap_or("unknown"))
+                    .map(|p| describe_changed_file(p, &diffs))
                     .collect::<Vec<_>>()
                     .join(", ");
                 self.transcript_mut()
@@ -181,12 +181,51 @@ impl App {
     }
 
     /// Discard user changes - reverts to last agent state (DESTRUCTIVE)
+    ///
+    /// Restores the git snapshot taken when the agent last started, stashing
+    /// the discarded changes, and records a checkpoint pointing at it. Without
+    /// a snapshot, falls back to `git restore .`.
     pub fn reconcile_discard(&mut self) {
         self.transcript_mut()
             .add_system_message("[!] Discarding user changes...");
         self.transcript_mut()
             .add_system_message("Reverting to last agent snapshot state...");
 
+        if let Some(snapshot) = self.last_git_snapshot.clone()
+            && let Some(ref sm) = self.snapshot_manager
+        {
+            match sm.restore_git(&snapshot) {
+                Ok(stash) => {
+                    if let Some(stash) = stash {
+                        self.transcript_mut().add_system_message(format!(
+                            "✓ Your changes were stashed ({}). Recover them with: git stash apply",
+                            stash
+                        ));
+                    }
+                    if let Some(ref mut session) = self.session {
+                        session.file_ownership.clear();
+                        let _ = session.append_checkpoint(
+                            "Discarded user changes",
+                            "Restored the workspace to the agent's last snapshot",
+                            Some(snapshot),
+                        );
+                    }
+
+                    self.capture_snapshot_state();
+
+                    self.state_mut().stop_generation();
+                    self.transcript_mut()
+                        .add_system_message("Ready. Workspace is now at the last agent state.");
+                }
+                Err(e) => {
+                    self.transcript_mut()
+                        .add_system_message(format!("Failed to restore snapshot {}: {}", snapshot, e));
+                    self.state_mut().stop_generation();
+                }
+            }
+            return;
+        }
+
         let result = std::process::Command::new("git")
             .args(["restore", "."])
             .current_dir(self.state().cwd())
@@ -235,3 +274,30 @@ impl App {
             .add_system_message("Agent stopped. You can start fresh with a new message.");
     }
 }
+
+/// File name with its diff stat, e.g. "config.rs +3 −1"
+fn describe_changed_file(path: &std::path::Path, diffs: &[thunderus_core::GitDiff]) -> String {
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("unknown");
+    match diffs.iter().find(|diff| path.ends_with(&diff.path)) {
+        Some(diff) => format!("{} +{} −{}", name, diff.added, diff.deleted),
+        None => name.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::describe_changed_file;
+    use std::path::Path;
+    use thunderus_core::GitDiff;
+
+    #[test]
+    fn test_describe_changed_file_includes_diff_stat() {
+        let diffs = vec![GitDiff { path: "src/config.rs".to_string(), added: 3, deleted: 1 }];
+
+        assert_eq!(
+            describe_changed_file(Path::new("/repo/src/config.rs"), &diffs),
+            "config.rs +3 −1"
+        );
+        assert_eq!(describe_changed_file(Path::new("/repo/src/main.rs"), &diffs), "main.rs");
+    }
+}
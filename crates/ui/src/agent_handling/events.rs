@@ -1,6 +1,10 @@
 use crate::app::App;
-use crate::transcript;
+use crate::inline_image::ImageAttachment;
+use crate::state::Activity;
+use crate::transcript::{self, StatusType};
 use thunderus_agent::AgentEvent;
+use thunderus_core::ImageInfo;
+use thunderus_providers::FinishReason;
 
 impl App {
     /// Check if a tool should be blocked due to file ownership
@@ -47,6 +51,17 @@ impl App {
     pub fn handle_agent_event(&mut self, event: thunderus_agent::AgentEvent) {
         match event {
             AgentEvent::Token(text) => {
+                if let Some(reasoning) = text
+                    .strip_prefix("<thinking>")
+                    .and_then(|t| t.strip_suffix("</thinking>"))
+                {
+                    self.state_mut()
+                        .set_activity(Activity::new("thinking", StatusType::Generating));
+                    self.transcript_mut().add_reasoning_token(reasoning);
+                    return;
+                }
+                self.state_mut()
+                    .set_activity(Activity::new("writing response", StatusType::Generating));
                 if self.streaming_model_content.is_none() {
                     self.streaming_model_content = Some(String::new());
                 }
@@ -55,7 +70,14 @@ impl App {
                 }
                 self.transcript_mut().add_streaming_token(&text);
             }
+            AgentEvent::ToolCallStarted { name } => {
+                self.state_mut()
+                    .set_activity(Activity::new(format!("calling tool: {}", name), StatusType::Generating));
+                self.state_mut().session.pending_tool_call = Some(name);
+            }
             AgentEvent::ToolCall { name, args, risk, description, task_context, scope, classification_reasoning } => {
+                self.state_mut().session.pending_tool_call = None;
+                self.state_mut().set_activity(Activity::tool(&name));
                 if self.should_block_tool_for_ownership(&name, &args) {
                     self.transcript_mut()
                         .add_system_message("⛔ Write blocked: File is currently owned by user after manual edits.");
@@ -67,6 +89,8 @@ impl App {
                     return;
                 }
 
+                self.state_mut().stats_mut().record_tool_call(&name);
+
                 let args_str = serde_json::to_string_pretty(&args).unwrap_or_default();
                 let risk_str = risk.as_str();
                 self.transcript_mut().add_tool_call(&name, &args_str, risk_str);
@@ -97,7 +121,22 @@ impl App {
                 }
             }
             AgentEvent::ToolResult { name, result, success, error, metadata } => {
+                self.state_mut()
+                    .set_activity(Activity::new("thinking", StatusType::Generating));
+                if metadata.attempt > 1 {
+                    self.transcript_mut()
+                        .add_system_message(format!("Retried {} with corrected arguments", name));
+                }
                 self.transcript_mut().add_tool_result(&name, &result, success);
+                if name == "read"
+                    && success
+                    && let Some(info) = ImageInfo::parse_note(&result)
+                    && let Some(path) = metadata.affected_paths.first()
+                    && let Some(entry) = self.transcript_mut().last_mut()
+                    && let transcript::TranscriptEntry::ToolResult { image, .. } = entry
+                {
+                    *image = Some(ImageAttachment::new(path, info));
+                }
                 let error_message = error.clone();
                 if let Some(err) = error
                     && let Some(entry) = self.transcript_mut().last_mut()
@@ -130,6 +169,9 @@ impl App {
                 eprintln!("Unexpected approval request via agent event: {:?}", request.id)
             }
             AgentEvent::ApprovalResponse(_response) => self.state_mut().approval_ui.pending_approval = None,
+            AgentEvent::AutoApproved(record) => {
+                self.persist_approval(&record.request.description, true, record.reason.as_deref())
+            }
             AgentEvent::Error(msg) => {
                 let error_type = match msg.as_str() {
                     m if m.contains("cancelled") => transcript::ErrorType::Cancelled,
@@ -143,29 +185,48 @@ impl App {
                 self.transcript_mut().add_error(msg, error_type);
                 self.state_mut().stop_generation();
             }
+            AgentEvent::Usage(usage) => {
+                let tokens = thunderus_core::TokensUsed::new(usage.prompt_tokens, usage.completion_tokens);
+                self.state_mut().session.stats.add_tokens(&tokens);
+                self.state_mut()
+                    .session_header
+                    .update_tokens(usage.total_tokens as usize);
+            }
+            AgentEvent::Finish(reason) => self.state_mut().session.last_finish_reason = Some(reason),
             AgentEvent::Done => {
+                self.state_mut().session.pending_tool_call = None;
                 self.transcript_mut().finish_streaming();
                 self.state_mut().stop_generation();
 
                 if let Some(content) = self.streaming_model_content.take() {
                     self.persist_model_message(&content);
                 }
+
+                let finish_reason = self.state_mut().session.last_finish_reason.take();
+                let truncated = finish_reason.as_deref() == Some(FinishReason::Length.as_str());
+                self.state_mut().session.response_truncated = truncated;
+                if truncated {
+                    self.transcript_mut().add_system_message(
+                        "⚠ Response truncated: the model hit its max_tokens limit. Use /continue to resume it.",
+                    );
+                }
             }
             AgentEvent::ApprovalModeChanged { from, to } => self.transcript_mut().add_system_message(format!(
                 "Approval mode changed: {} → {}",
                 from.as_str(),
                 to.as_str()
             )),
-            AgentEvent::MemoryRetrieval { query: _, chunks, total_tokens, search_time_ms } => {
+            AgentEvent::MemoryRetrieval { query: _, chunks, total_tokens, token_budget, search_time_ms } => {
                 let time_str = if search_time_ms < 1000 {
                     format!("{}ms", search_time_ms)
                 } else {
                     format!("{:.2}s", search_time_ms as f64 / 1000.0)
                 };
                 self.transcript_mut().add_system_message(format!(
-                    "Memory retrieval: {} chunks ({} tokens) in {}",
+                    "Memory retrieval: {} chunks ({}/{} tokens) in {}",
                     chunks.len(),
                     total_tokens,
+                    token_budget,
                     time_str
                 ));
             }
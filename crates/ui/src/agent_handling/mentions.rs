@@ -0,0 +1,61 @@
+use crate::app::App;
+
+impl App {
+    /// Expand `@path` mentions in a submitted message into the prompt sent to the agent
+    ///
+    /// Each inlined file is recorded as a full read, in the read history and
+    /// as a `FileRead` session event, so the agent can edit it without reading
+    /// it again. Mentions that could not be inlined are shown as warnings.
+    pub fn expand_file_mentions(&mut self, message: &str) -> String {
+        let expanded = thunderus_core::expand_mentions(message, self.state().cwd());
+
+        for file in &expanded.files {
+            let path = file.path.display().to_string();
+            self.read_history.record_read(&path, file.line_count, 0);
+            if let Some(ref mut session) = self.session
+                && let Err(e) = session.append_file_read(&path, file.line_count, 0, true)
+            {
+                eprintln!("Failed to log mention read: {}", e);
+            }
+        }
+        for warning in &expanded.warnings {
+            self.transcript_mut().add_system_message(format!("⚠ {}", warning));
+        }
+
+        expanded.prompt
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::create_test_app;
+    use crate::transcript;
+    use tempfile::TempDir;
+    use thunderus_core::{AgentDir, Event, Session};
+
+    #[test]
+    fn test_expand_file_mentions_records_reads_and_warnings() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(temp.path().join("lib.rs"), "fn main() {}\n").unwrap();
+        let session = Session::new(AgentDir::new(temp.path())).unwrap();
+        let mut app = create_test_app().with_session(session);
+        app.state.config.cwd = temp.path().to_path_buf();
+
+        let prompt = app.expand_file_mentions("Fix @lib.rs and @gone.rs");
+        assert!(prompt.contains("fn main() {}"));
+
+        let path = temp.path().join("lib.rs").display().to_string();
+        assert_eq!(app.read_history.was_read(&path), Some((1, 0)));
+        let events = app.session.as_ref().unwrap().read_events().unwrap();
+        assert!(
+            events
+                .iter()
+                .any(|e| matches!(&e.event, Event::FileRead { file_path, success: true, .. } if *file_path == path))
+        );
+
+        match app.transcript().last().unwrap() {
+            transcript::TranscriptEntry::SystemMessage { content } => assert!(content.contains("@gone.rs")),
+            other => panic!("Expected warning, got {:?}", other),
+        }
+    }
+}
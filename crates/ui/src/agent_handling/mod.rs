@@ -1,5 +1,6 @@
 mod approvals;
 mod drift;
 mod events;
+mod mentions;
 mod snapshot;
 mod spawn;
@@ -17,4 +17,16 @@ impl App {
             }
         }
     }
+
+    /// Capture the working tree as a git snapshot that reconcile-discard can restore
+    ///
+    /// The drift monitor measures later changes against the same snapshot.
+    /// Outside a git repository there is nothing to snapshot and the previous
+    /// snapshot is cleared.
+    pub fn capture_git_snapshot(&mut self) {
+        self.last_git_snapshot = self.snapshot_manager.as_ref().and_then(|sm| sm.snapshot_git().ok());
+        if let Some(ref monitor) = self.drift_monitor {
+            monitor.set_baseline(self.last_git_snapshot.clone());
+        }
+    }
 }
@@ -13,8 +13,25 @@ impl App {
     /// Captures snapshot state before spawning for drift detection.
     pub fn spawn_agent_for_message(
         &mut self, message: String, provider: &std::sync::Arc<dyn thunderus_providers::Provider>,
+    ) {
+        self.spawn_agent(message, provider, Default::default());
+    }
+
+    /// Spawn agent for a follow-up to the previous turn, reusing its conversation history
+    pub fn spawn_agent_continuing(
+        &mut self, message: String, provider: &std::sync::Arc<dyn thunderus_providers::Provider>,
+    ) {
+        let conversation = std::sync::Arc::clone(&self.conversation);
+        self.spawn_agent(message, provider, conversation);
+    }
+
+    fn spawn_agent(
+        &mut self, message: String, provider: &std::sync::Arc<dyn thunderus_providers::Provider>,
+        conversation: std::sync::Arc<std::sync::Mutex<Vec<thunderus_providers::ChatMessage>>>,
     ) {
         self.capture_snapshot_state();
+        self.capture_git_snapshot();
+        self.state_mut().session.last_finish_reason = None;
 
         let (tui_approval, approval_request_rx) = crate::tui_approval::TuiApprovalProtocol::new();
         self.approval_request_rx = Some(approval_request_rx);
@@ -26,22 +43,43 @@ impl App {
         let session_id = SessionId::new();
         let cancel_token = self.cancel_token.clone();
         let provider_clone = std::sync::Arc::clone(provider);
-        let approval_gate = ApprovalGate::new(self.state().config.approval_mode, self.state().config.allow_network);
+        if let Some(limit) = provider.context_window() {
+            self.state_mut().session_header.update_context_limit(limit);
+        }
+        let approval_gate = self.next_approval_gate();
 
-        let mut agent = Agent::new(provider_clone, approval_protocol, approval_gate, session_id);
+        self.conversation = std::sync::Arc::clone(&conversation);
+        let mut agent =
+            Agent::new(provider_clone, approval_protocol, approval_gate, session_id).with_conversation(conversation);
         self.set_approval_gate_handle(agent.approval_gate());
 
         let tool_specs = if let Some(profile) = self.profile() {
             let mut registry = ToolRegistry::with_builtin_tools();
+            registry.set_profile(profile.clone());
             if let Err(e) = registry.load_skills() {
                 eprintln!("{} Failed to load skills: {}", "Warning:".yellow(), e);
             }
-            registry.set_profile(profile.clone());
             registry.set_approval_gate(ApprovalGate::new(
                 ApprovalMode::FullAccess,
                 profile.is_network_allowed(),
             ));
-            let specs = registry.specs();
+            if !self.profile_tools_checked {
+                self.profile_tools_checked = true;
+                for name in registry.unknown_profile_tools() {
+                    self.transcript_mut().add_system_message(format!(
+                        "Profile tools list names unknown tool '{}'; check it for typos",
+                        name
+                    ));
+                }
+            }
+            self.state_mut().session.skills = registry.loaded_skills();
+            for (skill, trigger) in registry.triggered_skills(&message) {
+                self.transcript_mut().add_system_message(format!(
+                    "Skill '{}' enabled for this request (matched '{}')",
+                    skill.name, trigger
+                ));
+            }
+            let specs = registry.specs_for_message(&message);
             if let Some(ref session) = self.session {
                 let dispatcher = ToolDispatcher::new(registry);
 
@@ -54,11 +92,12 @@ impl App {
                     self.patch_queue_manager = Some(patch_queue_manager);
                 }
 
-                let session_dispatcher = if let Some(ref pqm) = self.patch_queue_manager {
-                    SessionToolDispatcher::with_history_and_queue(dispatcher, session.clone(), pqm.clone())
-                } else {
-                    SessionToolDispatcher::with_new_history(dispatcher, session.clone())
-                };
+                let session_dispatcher = SessionToolDispatcher::new(
+                    dispatcher,
+                    session.clone(),
+                    self.read_history.clone(),
+                    self.patch_queue_manager.clone(),
+                );
 
                 agent = agent.with_tool_dispatcher(std::sync::Arc::new(std::sync::Mutex::new(session_dispatcher)));
             }
@@ -72,6 +111,8 @@ impl App {
             agent = agent.with_profile(profile.clone());
         }
 
+        agent = agent.with_task_context(self.task_context.clone());
+
         if let Some(retriever) = self.memory_retriever() {
             agent = agent.with_memory_retriever(std::sync::Arc::clone(&retriever));
         }
@@ -1,3 +1,4 @@
+use crate::inline_image::{ImagePlacement, ImageProtocol};
 use crate::snapshot_capture::{SnapshotCapture, SnapshotMode};
 use crate::state::AppState;
 use crate::transcript::Transcript as TranscriptState;
@@ -7,10 +8,12 @@ use ratatui::{Terminal, backend::CrosstermBackend};
 use std::io::Result;
 use std::sync::Arc;
 use thunderus_core::{
-    ApprovalGate, ApprovalMode, ApprovalRequest, Config, DriftEvent, DriftMonitor, PatchQueueManager, Profile, Session,
-    SnapshotManager, memory::MemoryRetriever,
+    ApprovalGate, ApprovalMode, ApprovalRequest, ApprovalRule, ApprovalStats, Config, DismissedHints, DriftEvent,
+    DriftMonitor, MaterializedViews, PatchQueueManager, Profile, Session, SnapshotManager, TaskContextTracker,
+    memory::MemoryRetriever,
 };
-use thunderus_providers::{CancelToken, Provider};
+use thunderus_providers::{CancelToken, ChatMessage, Provider};
+use thunderus_tools::ReadHistory;
 use tokio::sync::mpsc;
 
 mod event_loop;
@@ -36,7 +39,7 @@ pub struct App {
     /// Cancellation token for stopping agent operations
     pub(crate) cancel_token: CancelToken,
     /// Provider for agent operations
-    provider: Option<Arc<dyn Provider>>,
+    pub(crate) provider: Option<Arc<dyn Provider>>,
     /// Profile for sandbox policy and tool configuration
     profile: Option<Profile>,
     /// Memory retriever for agent context
@@ -48,7 +51,7 @@ pub struct App {
     /// Buffer for accumulating streaming model response content
     pub(crate) streaming_model_content: Option<String>,
     /// Drift monitor for workspace changes
-    pub(crate) _drift_monitor: Option<DriftMonitor>,
+    pub(crate) drift_monitor: Option<DriftMonitor>,
     /// Snapshot manager for workspace state
     pub(crate) snapshot_manager: Option<SnapshotManager>,
     /// Receiver for drift events
@@ -57,10 +60,28 @@ pub struct App {
     pub(crate) pause_token: tokio_util::sync::CancellationToken,
     /// Last captured snapshot state for drift detection
     pub(crate) last_snapshot_state: Option<String>,
+    /// Git snapshot of the workspace taken when the agent last started, restored on discard
+    pub(crate) last_git_snapshot: Option<String>,
     /// Patch queue manager for diff-first editing workflow
     pub(crate) patch_queue_manager: Option<PatchQueueManager>,
+    /// Files read by the agent, shared across turns and rebuilt on resume
+    pub(crate) read_history: ReadHistory,
+    /// Active task, shared with each agent turn and shown in the header
+    pub(crate) task_context: TaskContextTracker,
+    /// Views from the last materialization, updated incrementally
+    pub(crate) materialized_views: Option<MaterializedViews>,
     /// Snapshot capture for regression testing
     pub(crate) snapshot_capture: Option<SnapshotCapture>,
+    /// Conversation of the most recent agent turn, reused by /continue
+    pub(crate) conversation: Arc<std::sync::Mutex<Vec<ChatMessage>>>,
+    /// Graphics protocol used to draw images inline in the transcript
+    pub(crate) image_protocol: ImageProtocol,
+    /// Images on screen after the last draw, so unchanged frames don't resend them
+    pub(crate) drawn_images: Vec<ImagePlacement>,
+    /// Teaching hints the user dismissed for good, loaded once at startup
+    pub(crate) dismissed_hints: DismissedHints,
+    /// Whether the profile's tool allow/deny lists were checked against the registry
+    pub(crate) profile_tools_checked: bool,
 }
 
 impl App {
@@ -96,13 +117,22 @@ impl App {
             approval_gate_handle: None,
             session: None,
             streaming_model_content: None,
-            _drift_monitor: drift_monitor,
+            drift_monitor,
             snapshot_manager,
             drift_rx,
             pause_token: tokio_util::sync::CancellationToken::new(),
             last_snapshot_state: None,
+            last_git_snapshot: None,
             patch_queue_manager: None,
+            read_history: ReadHistory::new(),
+            task_context: TaskContextTracker::new(),
+            materialized_views: None,
             snapshot_capture,
+            conversation: Default::default(),
+            image_protocol: ImageProtocol::detect(),
+            drawn_images: Vec::new(),
+            dismissed_hints: DismissedHints::load_default(),
+            profile_tools_checked: false,
         }
     }
 
@@ -138,13 +168,22 @@ impl App {
             approval_gate_handle: None,
             session: None,
             streaming_model_content: None,
-            _drift_monitor: drift_monitor,
+            drift_monitor,
             snapshot_manager,
             drift_rx,
             pause_token: tokio_util::sync::CancellationToken::new(),
             last_snapshot_state: None,
+            last_git_snapshot: None,
             patch_queue_manager: None,
+            read_history: ReadHistory::new(),
+            task_context: TaskContextTracker::new(),
+            materialized_views: None,
             snapshot_capture,
+            conversation: Default::default(),
+            image_protocol: ImageProtocol::detect(),
+            drawn_images: Vec::new(),
+            dismissed_hints: DismissedHints::load_default(),
+            profile_tools_checked: false,
         }
     }
 
@@ -165,10 +204,16 @@ impl App {
 
     /// Attach a profile for sandbox and tool configuration
     pub fn with_profile(mut self, profile: Profile) -> Self {
-        self.profile = Some(profile);
+        self.set_profile(profile);
         self
     }
 
+    /// Replace the active profile, used for tools on the next agent turn
+    pub fn set_profile(&mut self, profile: Profile) {
+        self.profile = Some(profile);
+        self.profile_tools_checked = false;
+    }
+
     /// Attach a memory retriever for agent context
     pub fn with_memory_retriever(mut self, retriever: Arc<dyn MemoryRetriever>) -> Self {
         self.memory_retriever = Some(retriever);
@@ -219,6 +264,73 @@ impl App {
         }
     }
 
+    /// Approval decision counts from the live agent gate (if active)
+    pub fn approval_stats(&self) -> Option<ApprovalStats> {
+        let gate = self.approval_gate_handle.as_ref()?;
+        gate.read().ok().map(|guard| guard.stats())
+    }
+
+    /// Remember an approval rule in the live agent gate, returning false without one
+    pub fn remember_approval_rule(&mut self, rule: ApprovalRule) -> bool {
+        match self.approval_gate_handle.as_ref().and_then(|gate| gate.write().ok()) {
+            Some(mut guard) => {
+                guard.remember(rule);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Approval rules remembered this session
+    pub fn session_approval_rules(&self) -> Vec<ApprovalRule> {
+        self.approval_gate_handle
+            .as_ref()
+            .and_then(|gate| gate.read().ok().map(|guard| guard.session_rules().to_vec()))
+            .unwrap_or_default()
+    }
+
+    /// Forget every approval rule remembered this session, returning how many were cleared
+    pub fn clear_session_approval_rules(&mut self) -> usize {
+        self.approval_gate_handle
+            .as_ref()
+            .and_then(|gate| gate.write().ok().map(|mut guard| guard.clear_session_rules()))
+            .unwrap_or(0)
+    }
+
+    /// Build the approval gate for the next agent turn
+    ///
+    /// The previous turn's gate is carried forward so its decision history
+    /// accumulates over the whole session.
+    pub(crate) fn next_approval_gate(&self) -> ApprovalGate {
+        let mode = self.state.config.approval_mode;
+        let allow_network = self.state.config.allow_network;
+        let mut gate = match self
+            .approval_gate_handle
+            .as_ref()
+            .and_then(|gate| gate.read().ok().map(|g| g.clone()))
+        {
+            Some(mut gate) => {
+                gate.set_mode(mode);
+                gate.set_allow_network(allow_network);
+                gate
+            }
+            None => ApprovalGate::new(mode, allow_network),
+        };
+        if let Some(ref profile) = self.profile {
+            gate.set_allowed_hosts(profile.network.allow_domains.clone());
+        }
+        gate
+    }
+
+    /// Update network access in the live agent gate (if active)
+    pub fn update_network_access(&mut self, allow_network: bool) {
+        if let Some(ref gate) = self.approval_gate_handle
+            && let Ok(mut guard) = gate.write()
+        {
+            guard.set_allow_network(allow_network);
+        }
+    }
+
     /// Get the transcript
     pub fn transcript(&self) -> &TranscriptState {
         &self.transcript
@@ -242,6 +354,17 @@ impl App {
         }
     }
 
+    /// Label the header with a task title inferred from the first user message
+    ///
+    /// Does nothing once a title is set, whether inferred or set with `/task`.
+    pub(crate) fn update_task_title_from_message(&mut self, message: &str) {
+        if self.state.session_header.task_title.is_some() {
+            return;
+        }
+        let title = TaskContextTracker::infer_title(message).unwrap_or_else(|| message.to_string());
+        self.state.session_header.set_task_title_from_message(&title);
+    }
+
     /// Persist a model response to the session log
     ///
     /// Handles write failures gracefully by warning the user and logging to stderr
@@ -285,10 +408,16 @@ impl App {
 
     /// Persist an approval decision to the session log
     ///
+    /// With `approved`, a `reason` marks a decision made automatically by policy;
+    /// without it, the reason is the user's explanation for rejecting the action.
     /// Handles write failures gracefully by warning the user and logging to stderr
-    pub(crate) fn persist_approval(&mut self, action: &str, approved: bool) {
+    pub(crate) fn persist_approval(&mut self, action: &str, approved: bool, reason: Option<&str>) {
         if let Some(ref mut session) = self.session
-            && let Err(e) = session.append_approval(action, approved)
+            && let Err(e) = match (approved, reason) {
+                (true, Some(reason)) => session.append_auto_approval(action, reason),
+                (false, Some(reason)) => session.append_rejection(action, reason),
+                (_, None) => session.append_approval(action, approved),
+            }
         {
             let warning = format!("Warning: Failed to persist approval: {}", e);
             eprintln!("{}", warning);
@@ -298,39 +427,29 @@ impl App {
 
     /// Persist the selected theme variant back to config.toml if available.
     fn persist_theme_variant(&mut self) {
-        let Some(config_path) = self.state.config.config_path.clone() else {
-            return;
-        };
-
-        let profile_name = self.state.config.profile.clone();
         let variant = self.state.theme_variant();
+        self.persist_profile_update("theme", &[("options.theme", variant.as_str().into())]);
+    }
 
-        let mut config = match Config::from_file(&config_path) {
-            Ok(config) => config,
-            Err(e) => {
-                let warning = format!("Warning: Failed to load config for theme update: {}", e);
-                eprintln!("{}", warning);
-                self.transcript_mut().add_system_message(warning);
-                return;
-            }
+    /// Write `values` into the active profile's table in config.toml
+    ///
+    /// Only the given keys are written, so inherited settings stay inherited.
+    /// Returns false when there is no config file to write to or the update
+    /// failed; failures are reported in the transcript.
+    pub(crate) fn persist_profile_update(&mut self, setting: &str, values: &[(&str, toml::Value)]) -> bool {
+        let Some(config_path) = self.state.config.config_path.clone() else {
+            return false;
         };
 
-        if let Some(profile) = config.profiles.get_mut(&profile_name) {
-            profile
-                .options
-                .insert("theme".to_string(), variant.as_str().to_string());
-        } else {
-            let warning = format!("Warning: Profile '{}' not found for theme update", profile_name);
-            eprintln!("{}", warning);
-            self.transcript_mut().add_system_message(warning);
-            return;
-        }
+        let profile_name = self.state.config.profile.clone();
 
-        if let Err(e) = config.save_to_file(&config_path) {
-            let warning = format!("Warning: Failed to save theme selection: {}", e);
+        if let Err(e) = Config::update_profile_file(&config_path, &profile_name, values) {
+            let warning = format!("Warning: Failed to save {} selection: {}", setting, e);
             eprintln!("{}", warning);
             self.transcript_mut().add_system_message(warning);
+            return false;
         }
+        true
     }
 
     /// Reconstruct transcript from session events
@@ -375,6 +494,11 @@ impl App {
         external_editor::open_external_editor(self);
     }
 
+    /// Open a file in the external editor
+    fn open_file_in_editor(&mut self, path: &str) {
+        external_editor::open_file_in_editor(self, path);
+    }
+
     /// Quit the application and restore terminal
     pub fn quit(&mut self) -> Result<()> {
         self.should_exit = true;
@@ -398,13 +522,22 @@ impl Default for App {
             approval_gate_handle: None,
             session: None,
             streaming_model_content: None,
-            _drift_monitor: None,
+            drift_monitor: None,
             snapshot_manager: None,
             drift_rx: None,
             pause_token: tokio_util::sync::CancellationToken::new(),
             last_snapshot_state: None,
+            last_git_snapshot: None,
             patch_queue_manager: None,
+            read_history: ReadHistory::new(),
+            task_context: TaskContextTracker::new(),
+            materialized_views: None,
             snapshot_capture: None,
+            conversation: Default::default(),
+            image_protocol: ImageProtocol::None,
+            drawn_images: Vec::new(),
+            dismissed_hints: DismissedHints::default(),
+            profile_tools_checked: false,
         }
     }
 }
@@ -478,6 +611,48 @@ mod tests {
         assert!(app.state().ui.sidebar_visible);
     }
 
+    #[test]
+    fn test_usage_event_updates_context_usage() {
+        let mut app = create_test_app();
+        app.handle_agent_event(thunderus_agent::AgentEvent::Usage(thunderus_providers::Usage::new(
+            90_000, 2_000,
+        )));
+        app.handle_agent_event(thunderus_agent::AgentEvent::Usage(thunderus_providers::Usage::new(
+            100_000, 1_000,
+        )));
+
+        assert_eq!(app.state().session.stats.input_tokens, 190_000);
+        assert_eq!(app.state().session.stats.output_tokens, 3_000);
+        assert_eq!(app.state().session_header.tokens_used, 101_000);
+        assert!(app.state().session_header.is_context_warning());
+    }
+
+    #[test]
+    fn test_agent_events_update_activity() {
+        let mut app = create_test_app();
+        app.state_mut().start_generation();
+
+        app.handle_agent_event(thunderus_agent::AgentEvent::ToolCallStarted { name: "grep".to_string() });
+        assert_eq!(app.state().session.activity.message, "calling tool: grep");
+
+        app.handle_agent_event(thunderus_agent::AgentEvent::ToolCall {
+            name: "grep".to_string(),
+            args: serde_json::json!({"pattern": "fn main"}),
+            risk: thunderus_core::ToolRisk::Safe,
+            description: None,
+            task_context: None,
+            scope: None,
+            classification_reasoning: None,
+        });
+        assert_eq!(app.state().session.activity.message, "running tool: grep");
+
+        app.handle_agent_event(thunderus_agent::AgentEvent::Token("Found it".to_string()));
+        assert_eq!(app.state().session.activity.message, "writing response");
+
+        app.handle_agent_event(thunderus_agent::AgentEvent::Done);
+        assert!(app.state().session.activity.is_ready());
+    }
+
     #[test]
     fn test_generation_state() {
         let mut app = create_test_app();
@@ -1,14 +1,25 @@
 use super::App;
 use crate::event_handler::EventHandler;
 use crossterm;
+use crossterm::event::{KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags};
 use ratatui::{Terminal, backend::CrosstermBackend};
 use std::io::Result;
-use std::{panic, time::Duration};
+use std::panic;
+use std::time::{Duration, Instant};
 
 pub async fn run(app: &mut App) -> Result<()> {
     crossterm::terminal::enable_raw_mode()?;
     crossterm::execute!(std::io::stdout(), crossterm::terminal::EnterAlternateScreen)?;
 
+    // Lets terminals that support it report Shift+Enter distinctly from Enter
+    let keyboard_enhanced = matches!(crossterm::terminal::supports_keyboard_enhancement(), Ok(true));
+    if keyboard_enhanced {
+        crossterm::execute!(
+            std::io::stdout(),
+            PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES)
+        )?;
+    }
+
     let backend = CrosstermBackend::new(std::io::stdout());
     let mut terminal = Terminal::new(backend)?;
 
@@ -18,6 +29,9 @@ pub async fn run(app: &mut App) -> Result<()> {
         if let Ok(mut terminal) = Terminal::new(backend) {
             let _ = terminal.show_cursor();
         }
+        if keyboard_enhanced {
+            let _ = crossterm::execute!(std::io::stdout(), PopKeyboardEnhancementFlags);
+        }
         let _ = crossterm::terminal::disable_raw_mode();
         let _ = crossterm::execute!(std::io::stdout(), crossterm::terminal::LeaveAlternateScreen);
         original_hook(panic_info);
@@ -35,8 +49,16 @@ pub async fn run(app: &mut App) -> Result<()> {
         tokio::select! {
             maybe_event = tui_poll => {
                 if let Some(event) = maybe_event {
+                    if let crossterm::event::Event::Resize(..) = event {
+                        terminal.autoresize()?;
+                    }
                     app.handle_event(event).await;
                     app.draw(&mut terminal)?;
+                } else if app.check_approval_timeout()
+                    || app.state.ui.redraw_due(Instant::now())
+                    || app.state.activity_timer_due(Instant::now())
+                {
+                    app.draw(&mut terminal)?;
                 }
             }
             maybe_drift = async {
@@ -61,7 +83,9 @@ pub async fn run(app: &mut App) -> Result<()> {
                 match maybe_agent {
                     Some(event) => {
                         app.handle_agent_event(event);
-                        app.draw(&mut terminal)?;
+                        if app.state.ui.should_redraw(Instant::now()) {
+                            app.draw(&mut terminal)?;
+                        }
                     }
                     None => {
                         app.agent_event_rx = None;
@@ -88,6 +112,9 @@ pub async fn run(app: &mut App) -> Result<()> {
     app.state_mut().stop_generation();
 
     terminal.show_cursor()?;
+    if keyboard_enhanced {
+        crossterm::execute!(std::io::stdout(), PopKeyboardEnhancementFlags)?;
+    }
     crossterm::terminal::disable_raw_mode()?;
     crossterm::execute!(std::io::stdout(), crossterm::terminal::LeaveAlternateScreen)?;
 
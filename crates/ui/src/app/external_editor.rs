@@ -1,15 +1,15 @@
 use super::App;
 use crossterm;
 use std::io::{self, Result, Write};
-use std::{env, fs, process::Command};
+use std::path::Path;
+use std::process::{Command, ExitStatus};
+use std::{env, fs};
 use uuid;
 
 pub fn open_external_editor(app: &mut App) {
     app.transcript_mut().add_system_message("External editor invoked");
 
-    let editor_cmd = env::var("VISUAL")
-        .or_else(|_| env::var("EDITOR"))
-        .unwrap_or_else(|_| "vi".to_string());
+    let editor_cmd = editor_command();
 
     let temp_dir = env::temp_dir();
     let temp_file_path = temp_dir.join(format!("thunderus_input_{}.md", uuid::Uuid::new_v4()));
@@ -20,14 +20,7 @@ pub fn open_external_editor(app: &mut App) {
         return;
     }
 
-    let _ = crossterm::terminal::disable_raw_mode();
-    let _ = crossterm::execute!(std::io::stdout(), crossterm::terminal::LeaveAlternateScreen);
-    let _ = std::io::stdout().flush();
-
-    let result = Command::new(&editor_cmd).arg(&temp_file_path).status();
-
-    let _ = crossterm::terminal::enable_raw_mode();
-    let _ = crossterm::execute!(std::io::stdout(), crossterm::terminal::EnterAlternateScreen);
+    let result = run_editor(&editor_cmd, &temp_file_path);
 
     match result {
         Ok(status) if status.success() => match fs::read_to_string(&temp_file_path) {
@@ -54,6 +47,45 @@ pub fn open_external_editor(app: &mut App) {
     let _ = redraw_screen();
 }
 
+/// Open a workspace file in the external editor, e.g. from inspector evidence
+pub fn open_file_in_editor(app: &mut App, path: &str) {
+    let editor_cmd = editor_command();
+
+    match run_editor(&editor_cmd, Path::new(path)) {
+        Ok(status) if status.success() => app
+            .transcript_mut()
+            .add_system_message(format!("Opened {} in {}", path, editor_cmd)),
+        Ok(status) => app
+            .transcript_mut()
+            .add_system_message(format!("Editor exited with non-zero status: {}", status)),
+        Err(e) => app
+            .transcript_mut()
+            .add_system_message(format!("Failed to launch editor '{}': {}", editor_cmd, e)),
+    }
+
+    let _ = redraw_screen();
+}
+
+/// The user's editor from `$VISUAL` or `$EDITOR`, defaulting to vi
+fn editor_command() -> String {
+    env::var("VISUAL")
+        .or_else(|_| env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string())
+}
+
+/// Hand the terminal to the editor until it exits
+fn run_editor(editor_cmd: &str, path: &Path) -> Result<ExitStatus> {
+    let _ = crossterm::terminal::disable_raw_mode();
+    let _ = crossterm::execute!(std::io::stdout(), crossterm::terminal::LeaveAlternateScreen);
+    let _ = std::io::stdout().flush();
+
+    let result = Command::new(editor_cmd).arg(path).status();
+
+    let _ = crossterm::terminal::enable_raw_mode();
+    let _ = crossterm::execute!(std::io::stdout(), crossterm::terminal::EnterAlternateScreen);
+    result
+}
+
 pub fn redraw_screen() -> Result<()> {
     let backend = ratatui::backend::CrosstermBackend::new(io::stdout());
     if let Ok(mut terminal) = ratatui::Terminal::new(backend) {
@@ -1,13 +1,24 @@
 use super::App;
-use crate::components::Inspector;
+use crate::RecentFiles;
 use crate::event_handler::{EventHandler, KeyAction};
 use crate::state::MainView;
 use crate::transcript::{self, CardDetailLevel};
 
 use std::path::PathBuf;
-use thunderus_core::{ApprovalDecision, ApprovalMode, MemoryDoc, TrajectoryWalker};
+use thunderus_core::{ApprovalDecision, ApprovalMode, Event, MemoryDoc, MemoryPaths, TrajectoryWalker};
 
 pub async fn handle_event(app: &mut App, event: crossterm::event::Event) {
+    if matches!(event, crossterm::event::Event::Key(_))
+        && let Some(timeout) = app
+            .state_mut()
+            .approval_ui
+            .pending_approval
+            .as_mut()
+            .and_then(|approval| approval.timeout.as_mut())
+    {
+        timeout.reset();
+    }
+
     if let Some(action) = EventHandler::handle_event(&event, app.state_mut()) {
         match action {
             KeyAction::SendMessage { message } => {
@@ -37,10 +48,14 @@ pub async fn handle_event(app: &mut App, event: crossterm::event::Event) {
                 app.state_mut().session.last_message = Some(message.clone());
                 app.transcript_mut().add_user_message(&message);
                 app.persist_user_message(&message);
+                app.update_task_title_from_message(&message);
                 app.state_mut().exit_first_session();
 
                 match app.provider.clone() {
-                    Some(provider) => app.spawn_agent_for_message(message, &provider),
+                    Some(provider) => {
+                        let prompt = app.expand_file_mentions(&message);
+                        app.spawn_agent_for_message(prompt, &provider)
+                    }
                     None => app
                         .transcript_mut()
                         .add_system_message("No provider configured. Cannot process message."),
@@ -48,8 +63,13 @@ pub async fn handle_event(app: &mut App, event: crossterm::event::Event) {
             }
             KeyAction::ExecuteShellCommand { command } => app.execute_shell_command(command),
             KeyAction::Approve { action: _, risk: _ } => app.send_approval_response(ApprovalDecision::Approved),
+            KeyAction::ApproveAndRemember { action: _, risk: _ } => app.approve_and_remember(),
             KeyAction::Reject { action: _, risk: _ } => app.send_approval_response(ApprovalDecision::Rejected),
+            KeyAction::RejectWithReason { reason } => app.reject_with_reason(reason),
             KeyAction::Cancel { action: _, risk: _ } => app.send_approval_response(ApprovalDecision::Cancelled),
+            KeyAction::ExpandApprovalDiff => {
+                app.transcript_mut().expand_pending_approval();
+            }
             KeyAction::CancelGeneration => {
                 app.cancel_token.cancel();
                 app.pause_token.cancel();
@@ -90,7 +110,8 @@ pub async fn handle_event(app: &mut App, event: crossterm::event::Event) {
                             app.transcript_mut().add_user_message(last_message.clone());
 
                             if let Some(provider) = app.provider.clone() {
-                                app.spawn_agent_for_message(last_message.clone(), &provider);
+                                let prompt = app.expand_file_mentions(&last_message);
+                                app.spawn_agent_for_message(prompt, &provider);
                             }
                         }
                         false => app
@@ -133,6 +154,15 @@ pub async fn handle_event(app: &mut App, event: crossterm::event::Event) {
             }
             KeyAction::SelectFileInFinder { path } => {
                 app.state_mut().exit_fuzzy_finder();
+                if let Some(recent_path) = RecentFiles::default_path() {
+                    let mut recent = RecentFiles::load(&recent_path);
+                    recent.record(app.state().cwd(), &path);
+                    if let Err(e) = recent.save(&recent_path) {
+                        app.transcript_mut()
+                            .add_system_message(format!("Failed to save recent files: {}", e));
+                    }
+                }
+
                 let input = app.state_mut().input.buffer.clone();
                 let cursor = app.state_mut().input.cursor;
 
@@ -149,18 +179,27 @@ pub async fn handle_event(app: &mut App, event: crossterm::event::Event) {
             | KeyAction::ToggleFinderSort
             | KeyAction::CancelFuzzyFinder => (),
             KeyAction::SlashCommandModel { model } => app.handle_model_command(model),
-            KeyAction::SlashCommandApprovals { mode } => app.handle_approvals_command(mode),
-            KeyAction::SlashCommandVerbosity { level } => app.handle_verbosity_command(level),
+            KeyAction::SlashCommandProfile { name } => app.handle_profile_command(name),
+            KeyAction::SlashCommandApprovals { mode, persist } => app.handle_approvals_command(mode, persist),
+            KeyAction::SlashCommandVerbosity { level, persist } => app.handle_verbosity_command(level, persist),
             KeyAction::SlashCommandStatus => app.handle_status_command(),
+            KeyAction::SlashCommandStats => app.handle_stats_command(),
+            KeyAction::SlashCommandTask { title } => app.handle_task_command(title),
+            KeyAction::DismissHintPermanently { concept } => app.dismiss_hint_permanently(&concept),
             KeyAction::SlashCommandPlan => app.handle_plan_command(),
             KeyAction::SlashCommandPlanAdd { item } => app.handle_plan_add_command(item),
             KeyAction::SlashCommandPlanDone { index } => app.handle_plan_done_command(index),
+            KeyAction::SlashCommandPlanRemove { index } => app.handle_plan_remove_command(index),
+            KeyAction::SlashCommandPlanMove { from, to } => app.handle_plan_move_command(from, to),
             KeyAction::SlashCommandReview => app.handle_review_command(),
             KeyAction::SlashCommandMemory => app.handle_memory_command(),
             KeyAction::SlashCommandMemoryAdd { fact } => app.handle_memory_add_command(fact),
             KeyAction::SlashCommandMemorySearch { query } => app.handle_memory_search_command(query),
             KeyAction::SlashCommandMemoryPin { id } => app.handle_memory_pin_command(id),
-            KeyAction::SlashCommandSearch { query, scope } => app.handle_search_command(query, scope),
+            KeyAction::SlashCommandSearch { query, scope, all_sessions } => {
+                app.handle_search_command(query, scope, all_sessions)
+            }
+            KeyAction::SlashCommandContinue => app.handle_continue_command(),
             KeyAction::SlashCommandClear => {
                 app.transcript_mut().clear();
                 app.transcript_mut()
@@ -172,6 +211,7 @@ pub async fn handle_event(app: &mut App, event: crossterm::event::Event) {
             KeyAction::SlashCommandGardenHygiene => app.handle_garden_hygiene_command(),
             KeyAction::SlashCommandGardenDrift => app.handle_garden_drift_command(),
             KeyAction::SlashCommandGardenVerify { doc_id } => app.handle_garden_verify_command(doc_id),
+            KeyAction::SlashCommandGardenVerifyAll => app.handle_garden_verify_all_command(),
             KeyAction::SlashCommandGardenStats => app.handle_garden_stats_command(),
             KeyAction::NavigateCardNext => {
                 if !app.transcript_mut().focus_next_card() {
@@ -221,11 +261,11 @@ pub async fn handle_event(app: &mut App, event: crossterm::event::Event) {
             }
             KeyAction::ScrollToTop => {
                 app.transcript_mut().scroll_up(usize::MAX);
-                app.state_mut().ui.scroll_vertical = 0;
+                app.state_mut().ui.scroll_transcript_to_top();
             }
             KeyAction::ScrollToBottom => {
                 app.transcript_mut().scroll_to_bottom();
-                app.state_mut().ui.scroll_vertical = 0;
+                app.state_mut().ui.scroll_transcript_to_bottom();
             }
             KeyAction::CollapseSidebarSection => app.state_mut().ui.sidebar_collapse_state.collapse_prev(),
             KeyAction::ExpandSidebarSection => app.state_mut().ui.sidebar_collapse_state.expand_next(),
@@ -317,19 +357,25 @@ pub async fn handle_event(app: &mut App, event: crossterm::event::Event) {
 
                 if patch_idx >= file_patch_count {
                     let mem_idx = patch_idx - file_patch_count;
+                    let cwd = app.state().config.cwd.clone();
                     let result = app
                         .state_mut()
                         .memory_patches_mut()
                         .get_mut(mem_idx)
                         .ok_or_else(|| "Memory patch not found".to_string())
                         .and_then(|patch| {
+                            let lint = patch.apply(&cwd)?;
                             patch.approve();
-                            patch.apply().map_err(|e| format!("Failed to apply: {}", e))?;
-                            Ok(format!("Applied memory patch: {}", patch.doc_id))
+                            Ok((format!("Applied memory patch: {}", patch.doc_id), lint))
                         });
 
                     match result {
-                        Ok(msg) => app.transcript_mut().add_system_message(msg),
+                        Ok((msg, lint)) => {
+                            app.transcript_mut().add_system_message(msg);
+                            if let Some(lint) = lint {
+                                app.transcript_mut().add_system_message(format!("⚠ {}", lint.message));
+                            }
+                        }
                         Err(e) => app
                             .transcript_mut()
                             .add_system_message(format!("Failed to apply: {}", e)),
@@ -412,14 +458,7 @@ pub async fn handle_event(app: &mut App, event: crossterm::event::Event) {
                     .add_system_message(format!("Opening memory document: {}", path));
                 app.state_mut().memory_hits.clear();
             }
-            KeyAction::MemoryHitsPin { id } => {
-                let is_pinned = app.state().memory_hits.is_pinned(&id);
-                app.transcript_mut().add_system_message(format!(
-                    "{}: {}",
-                    if is_pinned { "Unpinned" } else { "Pinned" },
-                    id
-                ));
-            }
+            KeyAction::MemoryHitsPin { id } => app.handle_memory_pin_command(id),
             KeyAction::MemoryHitsClose => app.transcript_mut().add_system_message("Memory panel closed"),
             KeyAction::ToggleInspector => app.state_mut().ui.toggle_inspector(),
             KeyAction::InspectMemory { path } => {
@@ -433,6 +472,11 @@ pub async fn handle_event(app: &mut App, event: crossterm::event::Event) {
                                 match walker.walk(&doc).await {
                                     Ok(nodes) => {
                                         app.state_mut().evidence.set_nodes(nodes);
+                                        let head =
+                                            MemoryPaths::from_thunderus_root(&app.state().config.cwd).head_commit();
+                                        let verification = doc.frontmatter.verification.state_at(head.as_deref());
+                                        app.state_mut().evidence.set_verification(verification);
+                                        app.state_mut().evidence.set_sources(doc.frontmatter.provenance.sources);
                                         app.state_mut().ui.active_view = MainView::Inspector;
                                         app.transcript_mut().add_system_message(format!("Inspecting: {}", path));
                                     }
@@ -458,15 +502,30 @@ pub async fn handle_event(app: &mut App, event: crossterm::event::Event) {
                 }
             }
             KeyAction::InspectorNavigate => {}
-            KeyAction::InspectorOpenFile { .. } => {
-                let inspector = Inspector::new(&app.state);
-                let files = inspector.affected_files();
-                if let Some(path) = files.first() {
-                    app.transcript_mut()
-                        .add_system_message(format!("Opening affected file: {}", path));
-                } else {
-                    app.transcript_mut()
-                        .add_system_message("No affected files for this event.");
+            KeyAction::ToggleDiffReview => app.state_mut().toggle_diff_review(),
+            KeyAction::DiffReviewNavigate => {}
+            KeyAction::InspectorOpenFile { path } => {
+                let patch_event = app
+                    .state()
+                    .evidence
+                    .selected_node()
+                    .and_then(|node| match &node.event.event {
+                        Event::Patch { name, .. } => Some((node.event.seq, name.clone())),
+                        _ => None,
+                    });
+                let file = Some(path.as_str()).filter(|path| !path.is_empty());
+
+                match patch_event {
+                    Some((seq, name)) if app.state_mut().review_patch_event(seq, &name, file) => {
+                        app.transcript_mut()
+                            .add_system_message(format!("Reviewing patch '{}'", name));
+                    }
+                    _ => match file {
+                        Some(path) => app.open_file_in_editor(path),
+                        None => app
+                            .transcript_mut()
+                            .add_system_message("No affected files for this event."),
+                    },
                 }
             }
             KeyAction::NoOp => (),
@@ -511,7 +570,7 @@ pub async fn handle_event(app: &mut App, event: crossterm::event::Event) {
 #[cfg(test)]
 mod tests {
     use crate::app::create_test_app;
-    use crate::state::ApprovalState;
+    use crate::state::{ApprovalState, MainView};
     use crate::transcript;
     use thunderus_core::ApprovalMode;
 
@@ -731,4 +790,79 @@ mod tests {
         assert_ne!(app.state().config.approval_mode, original_mode);
         assert_eq!(app.transcript().len(), 1);
     }
+
+    fn evidence_node(seq: u64, event: thunderus_core::Event) -> thunderus_core::TrajectoryNode {
+        thunderus_core::TrajectoryNode {
+            event: thunderus_core::LoggedEvent {
+                seq,
+                session_id: "test-session".to_string(),
+                timestamp: "2026-01-23T22:20:00Z".to_string(),
+                event,
+            },
+            impact: "Change".to_string(),
+            causal_links: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_inspector_patch_evidence_opens_diff_review() {
+        let mut app = create_test_app();
+        let diff = "diff --git a/src/a.rs b/src/a.rs\n@@ -1,1 +1,1 @@\n-old\n+new\n\
+                    diff --git a/src/b.rs b/src/b.rs\n@@ -1,1 +1,1 @@\n-old\n+new";
+        let patch = thunderus_core::Patch::new(
+            thunderus_core::PatchId::new("patch1"),
+            "Rename helpers".to_string(),
+            "HEAD".to_string(),
+            diff.to_string(),
+            thunderus_core::SessionId::new(),
+            7,
+        )
+        .unwrap();
+        app.state_mut().patches_mut().push(patch);
+        app.state_mut().evidence.set_nodes(vec![evidence_node(
+            7,
+            thunderus_core::Event::Patch {
+                name: "Rename helpers".to_string(),
+                status: thunderus_core::PatchStatus::Applied,
+                files: vec!["src/a.rs".to_string(), "src/b.rs".to_string()],
+                diff: diff.to_string(),
+            },
+        )]);
+        app.state_mut().exit_first_session();
+        app.state_mut().ui.active_view = MainView::Inspector;
+
+        let event = crossterm::event::Event::Key(crossterm::event::KeyEvent::new(
+            crossterm::event::KeyCode::Char('f'),
+            crossterm::event::KeyModifiers::NONE,
+        ));
+        app.handle_event(event).await;
+
+        assert_eq!(app.state().ui.active_view, MainView::DiffReview);
+        assert_eq!(app.state().selected_patch_index(), Some(0));
+        assert_eq!(app.state().selected_file_path().map(String::as_str), Some("src/a.rs"));
+        assert_eq!(app.state().selected_hunk_index(), Some(0));
+    }
+
+    #[tokio::test]
+    async fn test_inspector_without_file_evidence_reports_it() {
+        let mut app = create_test_app();
+        app.state_mut().evidence.set_nodes(vec![evidence_node(
+            3,
+            thunderus_core::Event::UserMessage { content: "Fix the bug".to_string() },
+        )]);
+        app.state_mut().exit_first_session();
+        app.state_mut().ui.active_view = MainView::Inspector;
+
+        let event = crossterm::event::Event::Key(crossterm::event::KeyEvent::new(
+            crossterm::event::KeyCode::Char('f'),
+            crossterm::event::KeyModifiers::NONE,
+        ));
+        app.handle_event(event).await;
+
+        assert_eq!(app.state().ui.active_view, MainView::Inspector);
+        assert!(app.transcript().entries().iter().any(|entry| matches!(
+            entry,
+            transcript::TranscriptEntry::SystemMessage { content } if content == "No affected files for this event."
+        )));
+    }
 }
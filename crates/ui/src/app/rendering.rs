@@ -1,27 +1,45 @@
 use super::App;
 use crate::components::{
-    ConfigEditorComponent, Footer, FuzzyFinderComponent, Header, Inspector, MemoryHitsPanel, Sidebar,
+    ConfigEditorComponent, DiffView, Footer, FuzzyFinderComponent, Header, Inspector, MemoryHitsPanel, Sidebar,
     TeachingHintPopup, Transcript as TranscriptComponent, WelcomeView,
 };
+use crate::inline_image::{ImagePlacement, MAX_INLINE_IMAGE_BYTES};
 use crate::layout::{LayoutMode, TuiLayout};
 use crate::state::MainView;
 use crate::theme::Theme;
 use crate::transcript::RenderOptions;
 use ratatui::{Terminal, backend::CrosstermBackend};
-use std::io::Result;
+use std::io::{Result, Write};
 
 pub fn draw(app: &mut App, terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>) -> Result<()> {
     if app.state.is_test_mode() {
         capture_tui_snapshot(app, "draw", "TUI state update");
     }
 
-    if app.state.is_generating() || app.state.approval_ui.pending_approval.is_some() {
+    if app.state.ui.animations && (app.state.is_generating() || app.state.approval_ui.pending_approval.is_some()) {
         app.state.advance_animation_frame();
     }
     if app.state.ui.sidebar_animation.is_some() {
         app.state.ui.advance_sidebar_animation();
     }
 
+    let mut images = render_frame(app, terminal)?;
+    if images != app.drawn_images && !app.drawn_images.is_empty() && app.image_protocol.clear_sequence().is_none() {
+        // Without a delete command, stale images are only erased by repainting every cell
+        terminal.clear()?;
+        app.drawn_images.clear();
+        images = render_frame(app, terminal)?;
+    }
+    app.state.ui.mark_drawn(std::time::Instant::now());
+
+    draw_inline_images(app, terminal, images)
+}
+
+/// Draw one frame, returning the inline images the transcript placed
+fn render_frame(
+    app: &mut App, terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
+) -> Result<Vec<ImagePlacement>> {
+    let mut images = Vec::new();
     terminal.draw(|frame| {
         let size = frame.area();
         let theme = Theme::palette(app.state.theme_variant());
@@ -32,6 +50,11 @@ pub fn draw(app: &mut App, terminal: &mut Terminal<CrosstermBackend<std::io::Std
             size,
         );
 
+        if TuiLayout::is_too_small(size) {
+            render_too_small(frame, size, &theme);
+            return;
+        }
+
         if app.state.is_first_session() {
             let welcome = WelcomeView::new(&app.state, content_area);
             welcome.render(frame);
@@ -45,11 +68,14 @@ pub fn draw(app: &mut App, terminal: &mut Terminal<CrosstermBackend<std::io::Std
 
         let layout = if matches!(app.state.ui.active_view, MainView::Inspector) {
             TuiLayout::calculate_inspector(content_area)
+        } else if app.state.ui.is_diff_review() {
+            TuiLayout::calculate_review(content_area)
         } else {
-            TuiLayout::calculate(
+            TuiLayout::calculate_with_footer(
                 content_area,
                 app.state.ui.sidebar_visible,
                 app.state.ui.sidebar_width_override(),
+                Footer::height(&app.state),
             )
         };
         let header = Header::with_theme(&app.state.session_header, app.state.theme_variant());
@@ -62,12 +88,20 @@ pub fn draw(app: &mut App, terminal: &mut Terminal<CrosstermBackend<std::io::Std
                 layout.evidence_list.unwrap_or_default(),
                 layout.evidence_detail.unwrap_or_default(),
             );
+        } else if app.state.ui.is_diff_review() {
+            let diff_view = DiffView::with_memory_patches(&app.state, app.state.patches(), app.state.memory_patches());
+            diff_view.render_review(frame, layout.review.unwrap_or_default());
         } else {
             let theme = Theme::palette(app.state.theme_variant());
             let options = RenderOptions {
                 centered: false,
                 max_bubble_width: if layout.mode == LayoutMode::Full { None } else { Some(60) },
                 animation_frame: app.state.ui.animation_frame,
+                tool_output_collapse_lines: app.state.ui.tool_output_collapse_lines,
+                follow_bottom: app.state.ui.follow_transcript,
+                new_content_below: app.state.ui.new_content_below,
+                approval_timeout: app.state.pending_approval().and_then(|approval| approval.timeout),
+                inline_images: app.image_protocol.is_supported(),
             };
             let ellipsis = app.state.streaming_ellipsis();
             let transcript_component = if app.state.is_generating() {
@@ -81,7 +115,9 @@ pub fn draw(app: &mut App, terminal: &mut Terminal<CrosstermBackend<std::io::Std
             } else {
                 TranscriptComponent::with_vertical_scroll(&app.transcript, app.state.ui.scroll_vertical, theme, options)
             };
-            transcript_component.render(frame, layout.transcript);
+            let (content_height, max_scroll) = transcript_component.render(frame, layout.transcript);
+            images = transcript_component.image_placements();
+            app.state.ui.update_transcript_viewport(content_height, max_scroll);
 
             if let Some(sidebar_area) = layout.sidebar {
                 let sidebar = Sidebar::new(&app.state);
@@ -98,7 +134,7 @@ pub fn draw(app: &mut App, terminal: &mut Terminal<CrosstermBackend<std::io::Std
             fuzzy_finder.render(frame);
         }
 
-        if app.state.memory_hits.is_visible() {
+        if app.state.config.memory_enabled && app.state.memory_hits.is_visible() {
             let panel_area = ratatui::layout::Rect {
                 x: size.width / 4,
                 y: size.height / 8,
@@ -111,7 +147,8 @@ pub fn draw(app: &mut App, terminal: &mut Terminal<CrosstermBackend<std::io::Std
 
         if let Some(ref hint) = app.state.approval_ui.pending_hint {
             let theme = Theme::palette(app.state.theme_variant());
-            let hint_popup = TeachingHintPopup::new(hint, theme);
+            let hint_popup =
+                TeachingHintPopup::new(hint, theme).dismissible(app.state.approval_ui.pending_hint_concept.is_some());
             hint_popup.render(frame, content_area);
         }
 
@@ -121,6 +158,51 @@ pub fn draw(app: &mut App, terminal: &mut Terminal<CrosstermBackend<std::io::Std
         }
     })?;
 
+    Ok(images)
+}
+
+/// Write image escapes over the cells the transcript reserved for them
+///
+/// Only runs when the set of visible images changes; ratatui leaves the
+/// reserved blank cells alone between frames, so drawn images stay put.
+fn draw_inline_images(
+    app: &mut App, terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>, images: Vec<ImagePlacement>,
+) -> Result<()> {
+    if images == app.drawn_images {
+        return Ok(());
+    }
+
+    let protocol = app.image_protocol;
+    let backend = terminal.backend_mut();
+    if let Some(clear) = protocol.clear_sequence() {
+        backend.write_all(clear.as_bytes())?;
+    }
+    for placement in &images {
+        let bytes = match std::fs::metadata(&placement.image.path) {
+            Ok(meta) if meta.len() <= MAX_INLINE_IMAGE_BYTES => std::fs::read(&placement.image.path).ok(),
+            _ => None,
+        };
+        let Some(sequence) = bytes.and_then(|bytes| {
+            protocol.escape_sequence(
+                placement.image.info.format,
+                &bytes,
+                placement.area.width,
+                placement.area.height,
+            )
+        }) else {
+            continue;
+        };
+        crossterm::queue!(
+            backend,
+            crossterm::cursor::SavePosition,
+            crossterm::cursor::MoveTo(placement.area.x, placement.area.y)
+        )?;
+        backend.write_all(sequence.as_bytes())?;
+        crossterm::queue!(backend, crossterm::cursor::RestorePosition)?;
+    }
+    backend.flush()?;
+
+    app.drawn_images = images;
     Ok(())
 }
 
@@ -175,6 +257,17 @@ fn render_sidebar_divider(
     }
 }
 
+/// Draw the minimum-size notice centered in an otherwise empty terminal
+fn render_too_small(frame: &mut ratatui::Frame<'_>, size: ratatui::layout::Rect, theme: &crate::theme::ThemePalette) {
+    let height = size.height.min(2);
+    let area = ratatui::layout::Rect { x: size.x, y: size.y + (size.height - height) / 2, width: size.width, height };
+    let notice = ratatui::widgets::Paragraph::new(TuiLayout::too_small_message(size))
+        .style(ratatui::style::Style::default().fg(theme.yellow).bg(theme.bg))
+        .alignment(ratatui::layout::Alignment::Center)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+    frame.render_widget(notice, area);
+}
+
 fn inset_area(area: ratatui::layout::Rect, left: u16, right: u16, top: u16, bottom: u16) -> ratatui::layout::Rect {
     let width = area.width.saturating_sub(left + right);
     let height = area.height.saturating_sub(top + bottom);
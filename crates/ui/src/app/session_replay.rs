@@ -7,17 +7,22 @@ pub fn reconstruct_transcript_from_session(app: &mut App) -> thunderus_core::Res
         return Ok(());
     };
 
-    let events = session.read_events()?;
+    let events = session.read_chain_events()?;
 
     for logged_event in events {
+        app.read_history.apply_event(&logged_event.event);
+
         match logged_event.event {
-            Event::UserMessage { content } => app.transcript_mut().add_user_message(&content),
+            Event::UserMessage { content } => {
+                app.update_task_title_from_message(&content);
+                app.transcript_mut().add_user_message(&content)
+            }
             Event::ModelMessage { content, tokens_used: _ } => app.transcript_mut().add_model_response(&content),
-            Event::ToolCall { tool, arguments } => {
+            Event::ToolCall { tool, arguments, .. } => {
                 let args_str = serde_json::to_string_pretty(&arguments).unwrap_or_default();
                 app.transcript_mut().add_tool_call(&tool, &args_str, "safe");
             }
-            Event::ToolResult { tool, result, success, error } => {
+            Event::ToolResult { tool, result, success, error, .. } => {
                 let result_str = serde_json::to_string_pretty(&result).unwrap_or_else(|_| "Invalid JSON".to_string());
                 app.transcript_mut().add_tool_result(&tool, &result_str, success);
 
@@ -26,7 +31,8 @@ pub fn reconstruct_transcript_from_session(app: &mut App) -> thunderus_core::Res
                         .add_system_message(format!("Tool error: {}", error_msg));
                 }
             }
-            Event::Approval { action, approved } => {
+            Event::Approval { approved: true, reason: Some(_), .. } => {}
+            Event::Approval { action, approved, reason } => {
                 let decision = if approved {
                     transcript::ApprovalDecision::Approved
                 } else {
@@ -35,6 +41,10 @@ pub fn reconstruct_transcript_from_session(app: &mut App) -> thunderus_core::Res
 
                 app.transcript_mut().add_approval_prompt(&action, "safe");
                 let _ = app.transcript_mut().set_approval_decision(decision);
+                if let Some(reason) = reason {
+                    app.transcript_mut()
+                        .add_system_message(format!("Action rejected: {}", reason));
+                }
             }
             Event::Patch { name, status, files, diff } => {
                 let status_str = format!("{:?}", status);
@@ -88,7 +98,7 @@ pub fn reconstruct_transcript_from_session(app: &mut App) -> thunderus_core::Res
             Event::Checkpoint { label, description, .. } => app
                 .transcript_mut()
                 .add_system_message(format!("Checkpoint: {} - {}", label, description)),
-            Event::PlanUpdate { action, item, reason } => {
+            Event::PlanUpdate { action, item, reason, .. } => {
                 let reason_str = reason.as_ref().map(|r| format!(" (reason: {})", r)).unwrap_or_default();
                 app.transcript_mut()
                     .add_system_message(format!("Plan {}: {}{}", action, item, reason_str));
@@ -96,6 +106,11 @@ pub fn reconstruct_transcript_from_session(app: &mut App) -> thunderus_core::Res
             Event::MemoryUpdate { kind, path, operation, .. } => app
                 .transcript_mut()
                 .add_system_message(format!("Memory {}: {} ({})", operation, path, kind)),
+            Event::TaskUpdate { title } => {
+                app.task_context.pin(title.clone());
+                app.state_mut().session_header.set_task_title(&title);
+                app.transcript_mut().add_system_message(format!("Task: {}", title));
+            }
         }
     }
 
@@ -104,3 +119,46 @@ pub fn reconstruct_transcript_from_session(app: &mut App) -> thunderus_core::Res
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::app::create_test_app;
+    use thunderus_core::{AgentDir, Session};
+
+    #[test]
+    fn test_reconstruct_restores_read_history() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut session = Session::new(AgentDir::new(temp.path())).unwrap();
+        session.append_file_read("/src/lib.rs", 40, 0, true).unwrap();
+        session.append_file_read("/src/main.rs", 10, 100, true).unwrap();
+        session.append_file_read("/src/gone.rs", 5, 0, true).unwrap();
+        session.append_file_read("/src/gone.rs", 0, 0, false).unwrap();
+
+        let mut app = create_test_app();
+        app.session = Some(session);
+        app.reconstruct_transcript_from_session().unwrap();
+
+        assert!(app.read_history.covers_lines("/src/lib.rs", 0..40));
+        assert!(app.read_history.covers_lines("/src/main.rs", 100..110));
+        assert!(!app.read_history.covers_lines("/src/main.rs", 0..10));
+        assert!(app.read_history.was_read("/src/gone.rs").is_none());
+    }
+
+    #[test]
+    fn test_reconstruct_restores_task_title() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut session = Session::new(AgentDir::new(temp.path())).unwrap();
+        session.append_user_message("Fix the login bug").unwrap();
+        session.append_task_update("Harden auth flow").unwrap();
+
+        let mut app = create_test_app();
+        app.session = Some(session);
+        app.reconstruct_transcript_from_session().unwrap();
+
+        assert_eq!(
+            app.state().session_header.task_title.as_deref(),
+            Some("Harden auth flow")
+        );
+        assert!(app.task_context.is_pinned());
+    }
+}
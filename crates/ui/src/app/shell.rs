@@ -1,7 +1,7 @@
 use super::App;
 use crate::state;
-use thunderus_core::{ActionType, ApprovalContext, ApprovalGate, ToolRisk};
-use thunderus_tools::ToolRegistry;
+use thunderus_core::{ActionType, ApprovalContext, ApprovalGate};
+use thunderus_tools::{ToolRegistry, classify_shell_command};
 use uuid;
 
 pub fn execute_shell_command(app: &mut App, command: String) {
@@ -12,28 +12,13 @@ pub fn execute_shell_command(app: &mut App, command: String) {
     app.transcript_mut().add_user_message(&user_message);
 
     let mut approval_gate = ApprovalGate::new(app.state().config.approval_mode, app.state().config.allow_network);
+    if let Some(profile) = app.profile() {
+        approval_gate.set_allowed_hosts(profile.network.allow_domains.clone());
+    }
 
-    let command_lower = command.to_lowercase();
-    let is_network_command = command_lower.contains("curl ")
-        || command_lower.contains("wget ")
-        || command_lower.starts_with("curl")
-        || command_lower.starts_with("wget")
-        || command_lower.contains("ssh ")
-        || command_lower.starts_with("ssh")
-        || command_lower.contains("http://")
-        || command_lower.contains("https://");
-
-    let is_destructive = command_lower.contains("rm ")
-        || command_lower.contains("rmdir ")
-        || command_lower.contains("del ")
-        || command_lower.contains("mv ")
-        || command_lower.contains("> ")
-        || command_lower.contains("git clean")
-        || command_lower.contains("git reset")
-        || command_lower.contains("git rebase");
-
-    let risk_level = if is_destructive || is_network_command { ToolRisk::Risky } else { ToolRisk::Safe };
-    let requires_approval = approval_gate.requires_approval(risk_level, is_network_command);
+    let classification = classify_shell_command(&command);
+    let risk_level = classification.risk;
+    let requires_approval = approval_gate.check_shell_command(risk_level, &command);
 
     if requires_approval {
         let request_id = approval_gate.create_request(
@@ -42,21 +27,11 @@ pub fn execute_shell_command(app: &mut App, command: String) {
             ApprovalContext::new()
                 .with_name("shell")
                 .with_arguments(serde_json::json!({"command": &command}))
-                .with_classification_reasoning(format!(
-                    "Command classified as {}: {}",
-                    if risk_level.is_risky() { "risky" } else { "safe" },
-                    if is_destructive {
-                        "destructive operation"
-                    } else if is_network_command {
-                        "network access"
-                    } else {
-                        "local command"
-                    }
-                )),
+                .with_classification_reasoning(classification.explain()),
             risk_level,
         );
 
-        let risk_str = if risk_level.is_risky() { "risky" } else { "safe" };
+        let risk_str = risk_level.as_str();
         app.transcript_mut()
             .add_approval_prompt(format!("shell:{}", command), risk_str);
         app.state_mut().approval_ui.pending_approval =
@@ -114,4 +89,16 @@ mod tests {
         assert!(event.message.contains("Executed: pwd"));
         assert!(!event.timestamp.is_empty());
     }
+
+    #[test]
+    fn test_execute_shell_command_redirection_requires_approval() {
+        let mut app = create_test_app();
+
+        app.execute_shell_command("echo x > f".to_string());
+
+        let pending = app.state().approval_ui.pending_approval.as_ref().unwrap();
+        assert_eq!(pending.risk, "risky");
+        assert_eq!(app.state().approval_ui.pending_command.as_deref(), Some("echo x > f"));
+        assert!(!std::path::Path::new("f").exists());
+    }
 }
@@ -2,6 +2,7 @@ use crate::state::AppState;
 use crate::theme::{Theme, ThemePalette};
 use ratatui::{
     Frame,
+    layout::{Constraint, Direction, Layout},
     style::{Style, Stylize},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Wrap},
@@ -62,6 +63,28 @@ impl<'a> DiffView<'a> {
 
     /// Render summary view of patches (including memory patches)
     fn render_summary(&self, frame: &mut Frame<'_>, area: ratatui::layout::Rect, theme: ThemePalette) {
+        let mut lines = self.summary_lines(theme);
+
+        let help_text = Line::from(vec![
+            Span::styled("N", Style::default().fg(theme.blue)),
+            Span::raw("/"),
+            Span::styled("P", Style::default().fg(theme.blue)),
+            Span::raw(": prev/next patch | "),
+            Span::styled("Enter", Style::default().fg(theme.blue)),
+            Span::raw(": view details"),
+        ]);
+
+        lines.push(help_text);
+
+        let mut all_lines = vec![Line::from(Span::styled(" Patches", Style::default().fg(theme.muted)))];
+        all_lines.extend(lines);
+
+        let paragraph = Paragraph::new(all_lines).wrap(Wrap { trim: true });
+        frame.render_widget(paragraph, area);
+    }
+
+    /// One line per queued patch, marking the selected one
+    fn summary_lines(&self, theme: ThemePalette) -> Vec<Line<'a>> {
         let mut lines = Vec::new();
         let nav = self.state.diff_navigation();
 
@@ -100,22 +123,146 @@ impl<'a> DiffView<'a> {
             ]));
         }
 
-        let help_text = Line::from(vec![
-            Span::styled("N", Style::default().fg(theme.blue)),
+        lines
+    }
+
+    /// Render the full-screen review: the patch list beside every hunk of the selected patch
+    pub fn render_review(&self, frame: &mut Frame<'_>, area: ratatui::layout::Rect) {
+        let theme = Theme::palette(self.state.theme_variant());
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(25), Constraint::Percentage(75)])
+            .split(area);
+
+        let list_lines = if self.has_patches() {
+            self.summary_lines(theme)
+        } else {
+            vec![Line::from(Span::styled(
+                " No patches queued",
+                Style::default().fg(theme.muted),
+            ))]
+        };
+        let list = Paragraph::new(list_lines)
+            .block(self.review_block("Patches", theme))
+            .wrap(Wrap { trim: true });
+        frame.render_widget(list, chunks[0]);
+
+        let (lines, focus) = self.review_lines(theme);
+        let visible = chunks[1].height.saturating_sub(2) as usize;
+        let scroll = if focus + 2 > visible { focus.saturating_sub(visible / 3) } else { 0 };
+
+        let help = Line::from(vec![
+            Span::styled(" j/k", Style::default().fg(theme.blue)),
+            Span::raw(": hunk | "),
+            Span::styled("Tab/Shift+Tab", Style::default().fg(theme.blue)),
+            Span::raw(": patch | "),
+            Span::styled("a", Style::default().fg(theme.green)),
             Span::raw("/"),
-            Span::styled("P", Style::default().fg(theme.blue)),
-            Span::raw(": prev/next patch | "),
-            Span::styled("Enter", Style::default().fg(theme.blue)),
-            Span::raw(": view details"),
+            Span::styled("r", Style::default().fg(theme.red)),
+            Span::raw(": approve/reject | "),
+            Span::styled("Esc", Style::default().fg(theme.blue)),
+            Span::raw(": close "),
         ]);
+        let detail = Paragraph::new(lines)
+            .block(self.review_block("Review", theme).title_bottom(help))
+            .scroll((scroll as u16, 0));
+        frame.render_widget(detail, chunks[1]);
+    }
 
-        lines.push(help_text);
+    fn review_block(&self, title: &'static str, theme: ThemePalette) -> Block<'a> {
+        Block::default()
+            .title(Span::styled(title, Style::default().fg(theme.blue).bold()))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.border))
+            .bg(theme.panel_bg)
+    }
 
-        let mut all_lines = vec![Line::from(Span::styled(" Patches", Style::default().fg(theme.muted)))];
-        all_lines.extend(lines);
+    /// Full content of the selected patch, with the line index of the selected hunk
+    fn review_lines(&self, theme: ThemePalette) -> (Vec<Line<'a>>, usize) {
+        let nav = self.state.diff_navigation();
+        let mut lines = Vec::new();
+        let mut focus = 0;
 
-        let paragraph = Paragraph::new(all_lines).wrap(Wrap { trim: true });
-        frame.render_widget(paragraph, area);
+        let Some(patch_idx) = nav.selected_patch_index else {
+            lines.push(Line::from(Span::styled(
+                "Select a patch with Tab",
+                Style::default().fg(theme.muted),
+            )));
+            return (lines, focus);
+        };
+
+        if let Some(patch) = self.patches.get(patch_idx) {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "Patch #{}: {} ({})",
+                    patch_idx + 1,
+                    patch.name,
+                    self.status_text(&patch.status)
+                ),
+                Style::default().fg(theme.blue).bold(),
+            )));
+
+            for file_path in &patch.files {
+                let path = file_path.to_string_lossy().to_string();
+                let is_selected_file = nav.selected_file_path.as_deref() == Some(path.as_str());
+                lines.push(Line::default());
+                lines.push(Line::from(Span::styled(path, Style::default().fg(theme.fg).bold())));
+
+                for (hunk_idx, hunk) in patch.hunks.get(file_path).into_iter().flatten().enumerate() {
+                    let is_selected = is_selected_file && nav.selected_hunk_index == Some(hunk_idx);
+                    if is_selected {
+                        focus = lines.len();
+                    }
+                    let marker_style =
+                        if is_selected { Style::default().bg(theme.blue).fg(theme.black) } else { Style::default() };
+                    let (status, status_style) = if hunk.approved {
+                        (" [APPROVED]", Style::default().fg(theme.green))
+                    } else {
+                        (" [PENDING]", Style::default().fg(theme.yellow))
+                    };
+
+                    let mut header = vec![
+                        Span::styled(if is_selected { ">" } else { " " }, marker_style),
+                        Span::raw(" "),
+                        Span::styled(hunk.header(), Style::default().fg(theme.cyan)),
+                        Span::styled(status, status_style),
+                    ];
+                    if let Some(ref intent) = hunk.intent {
+                        header.push(Span::styled(format!("  {}", intent), Style::default().fg(theme.muted)));
+                    }
+                    lines.push(Line::from(header));
+
+                    for hunk_line in hunk.content.lines() {
+                        lines.push(Line::from(vec![
+                            Span::styled(if is_selected { "▎" } else { " " }, Style::default().fg(theme.blue)),
+                            Span::raw(" "),
+                            Span::styled(hunk_line.to_string(), Self::line_style(hunk_line, theme)),
+                        ]));
+                    }
+                }
+            }
+        } else if let Some(patch) = self.memory_patches.get(patch_idx - self.patches.len()) {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "Memory Patch #{}: {} ({})",
+                    patch_idx + 1,
+                    patch.doc_id,
+                    self.status_text(&patch.status)
+                ),
+                Style::default().fg(theme.cyan).bold(),
+            )));
+            lines.push(Line::from(Span::styled(
+                patch.description.clone(),
+                Style::default().fg(theme.muted),
+            )));
+            lines.push(Line::default());
+            for line in patch.review_lines() {
+                let style = Self::line_style(line, theme);
+                lines.push(Line::from(Span::styled(line.clone(), style)));
+            }
+        }
+
+        (lines, focus)
     }
 
     /// Render detailed view of a patch with hunks
@@ -209,13 +356,7 @@ impl<'a> DiffView<'a> {
 
                     if is_selected_hunk {
                         for hunk_line in hunk.content.lines().take(3) {
-                            let line_style = if hunk_line.starts_with('-') {
-                                Style::default().fg(theme.red)
-                            } else if hunk_line.starts_with('+') {
-                                Style::default().fg(theme.green)
-                            } else {
-                                Style::default().fg(theme.fg)
-                            };
+                            let line_style = Self::line_style(hunk_line, theme);
 
                             lines.push(Line::from(vec![
                                 Span::styled("        ", hunk_style),
@@ -298,23 +439,24 @@ impl<'a> DiffView<'a> {
 
         lines.push(Line::from(""));
 
-        for line in patch.diff.lines().take(8) {
-            let line_style = if line.starts_with("+++ ") || line.starts_with("--- ") {
-                Style::default().fg(theme.cyan)
-            } else if line.starts_with('+') && !line.starts_with("+++") {
+        let changed: Vec<&String> = patch
+            .review_lines()
+            .iter()
+            .filter(|line| !line.starts_with(' '))
+            .collect();
+        for line in changed.iter().take(8) {
+            let line_style = if line.starts_with('+') {
                 Style::default().fg(theme.green)
-            } else if line.starts_with('-') && !line.starts_with("---") {
-                Style::default().fg(theme.red)
             } else {
-                Style::default().fg(theme.fg)
+                Style::default().fg(theme.red)
             };
 
-            lines.push(Line::from(vec![Span::styled(line, line_style)]));
+            lines.push(Line::from(vec![Span::styled(line.as_str(), line_style)]));
         }
 
-        if patch.diff.lines().count() > 8 {
+        if changed.len() > 8 {
             lines.push(Line::from(vec![Span::styled(
-                format!("(+ {} more lines)", patch.diff.lines().count() - 8),
+                format!("(+ {} more lines)", changed.len() - 8),
                 Style::default().fg(theme.muted),
             )]));
         }
@@ -347,6 +489,21 @@ impl<'a> DiffView<'a> {
         frame.render_widget(paragraph, area);
     }
 
+    /// Style for one line of a unified diff
+    pub fn line_style(line: &str, theme: ThemePalette) -> Style {
+        if line.starts_with("@@") {
+            Style::default().fg(theme.cyan)
+        } else if line.starts_with("---") || line.starts_with("+++") || line.starts_with("diff --git") {
+            Style::default().fg(theme.muted)
+        } else if line.starts_with('-') {
+            Style::default().fg(theme.red)
+        } else if line.starts_with('+') {
+            Style::default().fg(theme.green)
+        } else {
+            Style::default().fg(theme.fg)
+        }
+    }
+
     /// Get color for patch status
     fn status_color(&self, status: &PatchStatus) -> Style {
         let theme = Theme::palette(self.state.theme_variant());
@@ -1,6 +1,8 @@
 use crate::{
+    layout::FOOTER_HEIGHT,
     state::AppState,
     theme::{Theme, ThemePalette},
+    transcript::TranscriptEntry,
 };
 
 use ratatui::{
@@ -11,10 +13,13 @@ use ratatui::{
     widgets::{Block, Paragraph},
 };
 
+/// Most composer lines shown at once before the input scrolls
+pub const MAX_COMPOSER_LINES: u16 = 8;
+
 /// Footer component displaying input composer, model selector, and hints
 ///
 /// OpenCode-style layout:
-/// - Row 1: Divider
+/// - Row 1: Status line (current agent phase and elapsed time) on the divider
 /// - Row 2: Input card with blue accent bar
 /// - Row 3: Model selector (left) + hints (right)
 pub struct Footer<'a> {
@@ -26,6 +31,22 @@ impl<'a> Footer<'a> {
         Self { state }
     }
 
+    /// Number of input lines the composer shows
+    ///
+    /// A single line unless multi-line input is on, in which case the
+    /// composer grows with the buffer up to [`MAX_COMPOSER_LINES`].
+    pub fn visible_lines(state: &AppState) -> u16 {
+        if !state.is_multiline_input() {
+            return 1;
+        }
+        (state.input.line_count() as u16).clamp(1, MAX_COMPOSER_LINES)
+    }
+
+    /// Footer height needed for the current composer contents
+    pub fn height(state: &AppState) -> u16 {
+        FOOTER_HEIGHT + Self::visible_lines(state) - 1
+    }
+
     /// Render footer to the given frame with horizontal padding
     pub fn render(&self, frame: &mut Frame<'_>, area: Rect) {
         let theme = Theme::palette(self.state.theme_variant());
@@ -40,21 +61,35 @@ impl<'a> Footer<'a> {
 
         let rows = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Length(1), Constraint::Length(3), Constraint::Length(1)])
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Length(Self::visible_lines(self.state) + 2),
+                Constraint::Length(1),
+            ])
             .split(padded_area);
 
-        frame.render_widget(
-            Paragraph::new(Line::from(vec![Span::styled(
-                "─".repeat(padded_area.width as usize),
-                Style::default().fg(theme.muted),
-            )])),
-            rows[0],
-        );
+        frame.render_widget(Paragraph::new(self.status_line(theme, padded_area.width)), rows[0]);
 
         self.render_input_card(frame, rows[1], theme);
         self.render_bottom_row(frame, rows[2], theme, padded_area.width);
     }
 
+    /// Divider carrying the agent's current phase, e.g. "::: running tool: grep · 12s ────"
+    fn status_line(&self, theme: ThemePalette, width: u16) -> Line<'static> {
+        let activity = &self.state.session.activity;
+        let label = format!("{} ", activity.label());
+        let fill = (width as usize).saturating_sub(4 + label.chars().count());
+
+        Line::from(vec![
+            Span::styled("::: ", Style::default().fg(theme.muted)),
+            Span::styled(
+                label,
+                Style::default().fg(TranscriptEntry::status_type_color(theme, activity.status_type)),
+            ),
+            Span::styled("─".repeat(fill), Style::default().fg(theme.muted)),
+        ])
+    }
+
     /// Render input card with blue accent bar (like welcome screen)
     fn render_input_card(&self, frame: &mut Frame<'_>, area: Rect, theme: ThemePalette) {
         if area.width < 10 || area.height < 1 {
@@ -69,58 +104,117 @@ impl<'a> Footer<'a> {
         let accent_block = Block::default().style(Style::default().bg(theme.blue));
         frame.render_widget(accent_block, accent_area);
 
+        let visible_lines = Self::visible_lines(self.state);
         let input_area = Rect {
             x: area.x + accent_width + 1,
             y: area.y + 1,
             width: area.width.saturating_sub(accent_width + 2),
-            height: 1,
+            height: visible_lines.min(area.height.saturating_sub(1)),
         };
 
-        let mut spans = Vec::new();
-
-        if self.state.input.buffer.is_empty() {
-            spans.push(Span::styled("█", Style::default().bg(theme.fg).fg(theme.fg)));
-            let placeholder = if self.state.input.is_in_fork_mode() {
-                format!(
-                    "[FORK] Edit message #{}",
-                    self.state.input.history_index.map(|i| i + 1).unwrap_or(1)
-                )
-            } else if self.state.input.is_navigating_history() {
-                "<no message>".to_string()
-            } else {
-                "Type a message...".to_string()
-            };
-            let placeholder_style = if self.state.input.is_in_fork_mode() {
-                Style::default().fg(theme.green).bg(theme.bg)
-            } else if self.state.input.is_navigating_history() {
-                Style::default().fg(theme.yellow).bg(theme.bg)
-            } else {
-                Style::default().fg(theme.muted).bg(theme.bg)
-            };
-            spans.push(Span::styled(placeholder, placeholder_style));
+        let (cursor_line, cursor_column) = self.state.input.cursor_line_col();
+        let lines = if self.state.input.is_searching_history() {
+            vec![self.history_search_line(theme)]
+        } else if self.state.input.buffer.is_empty() {
+            vec![self.placeholder_line(theme)]
         } else {
-            let input_style = Style::default().fg(theme.fg).bg(theme.bg);
-            let cursor_pos = self.state.input.cursor.min(self.state.input.buffer.len());
-            let before_cursor = &self.state.input.buffer[..cursor_pos];
-            let after_cursor = &self.state.input.buffer[cursor_pos..];
-
-            if !before_cursor.is_empty() {
-                spans.push(Span::styled(before_cursor.to_string(), input_style));
-            }
-            spans.push(Span::styled("█", Style::default().bg(theme.fg).fg(theme.fg)));
-            if !after_cursor.is_empty() {
-                spans.push(Span::styled(after_cursor.to_string(), input_style));
-            }
-        }
+            self.buffer_lines(theme, cursor_line)
+        };
+        let scroll = (cursor_line as u16).saturating_sub(visible_lines - 1);
 
-        let input_paragraph = Paragraph::new(Line::from(spans));
+        let input_paragraph = Paragraph::new(lines).scroll((scroll, 0));
         frame.render_widget(input_paragraph, input_area);
 
-        let cursor_text = format!("1:{} ", self.state.input.cursor + 1);
+        let cursor_text = format!("{}:{} ", cursor_line + 1, cursor_column + 1);
+        let cursor_area = Rect { height: 1, ..input_area };
         let cursor_paragraph =
             Paragraph::new(Span::styled(cursor_text, Style::default().fg(theme.muted))).alignment(Alignment::Right);
 
-        frame.render_widget(cursor_paragraph, input_area);
+        frame.render_widget(cursor_paragraph, cursor_area);
+    }
+
+    /// Search prompt shown while searching message history
+    fn history_search_line(&self, theme: ThemePalette) -> Line<'static> {
+        let query = self
+            .state
+            .input
+            .history_search
+            .as_ref()
+            .map(|search| search.query.clone())
+            .unwrap_or_default();
+        let (label, found) = match self.state.input.history_search_match() {
+            Some(found) => ("(reverse-i-search)", found.replace('\n', " ⏎ ")),
+            None => ("(failed reverse-i-search)", String::new()),
+        };
+
+        Line::from(vec![
+            Span::styled(format!("{}`", label), Style::default().fg(theme.yellow).bg(theme.bg)),
+            Span::styled(query, Style::default().fg(theme.fg).bg(theme.bg).bold()),
+            Span::styled("': ", Style::default().fg(theme.yellow).bg(theme.bg)),
+            Span::styled(found, Style::default().fg(theme.fg).bg(theme.bg)),
+            Span::styled("█", Style::default().bg(theme.fg).fg(theme.fg)),
+        ])
+    }
+
+    /// Placeholder shown in an empty composer
+    fn placeholder_line(&self, theme: ThemePalette) -> Line<'static> {
+        let placeholder = if self.state.input.is_in_fork_mode() {
+            format!(
+                "[FORK] Edit message #{}",
+                self.state.input.history_index.map(|i| i + 1).unwrap_or(1)
+            )
+        } else if self.state.input.is_navigating_history() {
+            "<no message>".to_string()
+        } else if self.state.is_multiline_input() {
+            "Type a message... (shift+enter for a newline)".to_string()
+        } else {
+            "Type a message...".to_string()
+        };
+        let placeholder_style = if self.state.input.is_in_fork_mode() {
+            Style::default().fg(theme.green).bg(theme.bg)
+        } else if self.state.input.is_navigating_history() {
+            Style::default().fg(theme.yellow).bg(theme.bg)
+        } else {
+            Style::default().fg(theme.muted).bg(theme.bg)
+        };
+
+        Line::from(vec![
+            Span::styled("█", Style::default().bg(theme.fg).fg(theme.fg)),
+            Span::styled(placeholder, placeholder_style),
+        ])
+    }
+
+    /// Buffer contents split into lines, with the cursor drawn on `cursor_line`
+    fn buffer_lines(&self, theme: ThemePalette, cursor_line: usize) -> Vec<Line<'static>> {
+        let input_style = Style::default().fg(theme.fg).bg(theme.bg);
+        let cursor_style = Style::default().bg(theme.fg).fg(theme.fg);
+        let cursor_pos = self.state.input.cursor.min(self.state.input.buffer.len());
+        let mut line_start = 0;
+
+        self.state
+            .input
+            .buffer
+            .split('\n')
+            .enumerate()
+            .map(|(index, text)| {
+                let start = line_start;
+                line_start += text.len() + 1;
+                if index != cursor_line {
+                    return Line::from(Span::styled(text.to_string(), input_style));
+                }
+
+                let (before_cursor, after_cursor) = text.split_at(cursor_pos - start);
+                let mut spans = Vec::new();
+                if !before_cursor.is_empty() {
+                    spans.push(Span::styled(before_cursor.to_string(), input_style));
+                }
+                spans.push(Span::styled("█", cursor_style));
+                if !after_cursor.is_empty() {
+                    spans.push(Span::styled(after_cursor.to_string(), input_style));
+                }
+                Line::from(spans)
+            })
+            .collect()
     }
 
     /// Render model/agent selector row (left-aligned)
@@ -192,22 +286,46 @@ impl<'a> Footer<'a> {
             return hints;
         }
 
-        if self.state.approval_ui.pending_approval.is_some() {
+        if let Some(approval) = &self.state.approval_ui.pending_approval {
+            if let Some(reason) = &approval.reason {
+                hints.push(Span::styled("reason: ", hint_style));
+                hints.push(Span::styled(format!("{}▏ ", reason), Style::default().fg(theme.fg)));
+                hints.push(Span::styled("enter", key_style));
+                hints.push(Span::styled(" reject • ", hint_style));
+                hints.push(Span::styled("esc", key_style));
+                hints.push(Span::styled(" back", hint_style));
+                return hints;
+            }
             hints.push(Span::styled("y", key_style));
             hints.push(Span::styled(" approve • ", hint_style));
             hints.push(Span::styled("n", key_style));
             hints.push(Span::styled(" reject • ", hint_style));
+            hints.push(Span::styled("r", key_style));
+            hints.push(Span::styled(" reject with reason • ", hint_style));
             hints.push(Span::styled("c", key_style));
             hints.push(Span::styled(" cancel", hint_style));
             return hints;
         }
 
         if self.state.is_generating() {
+            if let Some(ref tool) = self.state.session.pending_tool_call {
+                hints.push(Span::styled(format!("calling {} • ", tool), hint_style));
+            }
             hints.push(Span::styled("ctrl+c", key_style));
             hints.push(Span::styled(" cancel", hint_style));
             return hints;
         }
 
+        if self.state.input.is_searching_history() {
+            hints.push(Span::styled("ctrl+r", key_style));
+            hints.push(Span::styled(" older • ", hint_style));
+            hints.push(Span::styled("enter", key_style));
+            hints.push(Span::styled(" insert • ", hint_style));
+            hints.push(Span::styled("esc", key_style));
+            hints.push(Span::styled(" cancel", hint_style));
+            return hints;
+        }
+
         let is_narrow = width < 80;
         let is_medium = (80..120).contains(&width);
         let is_wide = width >= 120;
@@ -219,6 +337,12 @@ impl<'a> Footer<'a> {
             return hints;
         }
 
+        if self.state.is_multiline_input() {
+            hints.insert(0, Span::styled(" • ", hint_style));
+            hints.insert(0, Span::styled(" newline", hint_style));
+            hints.insert(0, Span::styled("shift+enter", key_style));
+        }
+
         hints.insert(0, Span::styled(" • ", hint_style));
         hints.insert(
             0,
@@ -257,7 +381,7 @@ impl<'a> Footer<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::state::ApprovalState;
+    use crate::state::{Activity, ApprovalState};
 
     use std::path::PathBuf;
     use thunderus_core::{ApprovalMode, ProviderConfig, SandboxMode};
@@ -303,6 +427,56 @@ mod tests {
         assert!(hints.iter().any(|s| s.content.contains("ctrl+s")));
     }
 
+    #[test]
+    fn test_footer_grows_with_multiline_input() {
+        let mut state = create_test_state();
+        state.input.buffer = "one\ntwo\nthree".to_string();
+        assert_eq!(Footer::height(&state), FOOTER_HEIGHT);
+
+        state.composer.toggle_multiline();
+        assert_eq!(Footer::visible_lines(&state), 3);
+        assert_eq!(Footer::height(&state), FOOTER_HEIGHT + 2);
+
+        state.input.buffer = "line\n".repeat(20);
+        assert_eq!(Footer::visible_lines(&state), MAX_COMPOSER_LINES);
+    }
+
+    #[test]
+    fn test_buffer_lines_place_cursor() {
+        let mut state = create_test_state();
+        state.input.buffer = "ab\ncd".to_string();
+        state.input.cursor = 4;
+
+        let footer = Footer::new(&state);
+        let theme = Theme::palette(state.theme_variant());
+        let lines = footer.buffer_lines(theme, 1);
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].spans.len(), 1);
+        assert_eq!(lines[1].spans[0].content, "c");
+        assert_eq!(lines[1].spans[1].content, "█");
+        assert_eq!(lines[1].spans[2].content, "d");
+    }
+
+    #[test]
+    fn test_history_search_line() {
+        let mut state = create_test_state();
+        state.input.add_to_history("run cargo test".to_string());
+        state.input.start_history_search();
+        state.input.history_search_push('c');
+
+        let footer = Footer::new(&state);
+        let theme = Theme::palette(state.theme_variant());
+        let line = footer.history_search_line(theme);
+        assert_eq!(line.spans[0].content, "(reverse-i-search)`");
+        assert_eq!(line.spans[1].content, "c");
+        assert_eq!(line.spans[3].content, "run cargo test");
+
+        state.input.history_search_push('z');
+        let footer = Footer::new(&state);
+        let line = footer.history_search_line(theme);
+        assert_eq!(line.spans[0].content, "(failed reverse-i-search)`");
+    }
+
     #[test]
     fn test_get_hints_generating_state() {
         let mut state = create_test_state();
@@ -315,6 +489,37 @@ mod tests {
         assert!(hints.iter().any(|s| s.content.contains("ctrl+c")));
     }
 
+    #[test]
+    fn test_get_hints_pending_tool_call() {
+        let mut state = create_test_state();
+        state.ui.set_first_session(false);
+        state.start_generation();
+        state.session.pending_tool_call = Some("edit".to_string());
+
+        let footer = Footer::new(&state);
+        let theme = Theme::palette(state.theme_variant());
+        let hints = footer.get_hints(theme);
+        assert_eq!(hints[0].content, "calling edit • ");
+    }
+
+    #[test]
+    fn test_status_line_shows_activity() {
+        let mut state = create_test_state();
+        let theme = Theme::palette(state.theme_variant());
+
+        let line = Footer::new(&state).status_line(theme, 40);
+        assert_eq!(line.spans[1].content, "ready ");
+        assert_eq!(line.width(), 40);
+
+        state.start_generation();
+        state.set_activity(Activity::tool("grep"));
+        let line = Footer::new(&state).status_line(theme, 40);
+        assert_eq!(line.spans[1].content, "running tool: grep · 0s ");
+
+        state.stop_generation();
+        assert!(state.session.activity.is_ready());
+    }
+
     #[test]
     fn test_get_hints_with_pending_approval() {
         let mut state = create_test_state();
@@ -327,6 +532,24 @@ mod tests {
         let hints = _footer.get_hints(theme);
         assert!(hints.iter().any(|s| s.content == "y"));
         assert!(hints.iter().any(|s| s.content == "n"));
+        assert!(hints.iter().any(|s| s.content == "r"));
+    }
+
+    #[test]
+    fn test_get_hints_while_entering_rejection_reason() {
+        let mut state = create_test_state();
+        state.ui.set_first_session(false);
+        let mut approval = ApprovalState::pending("test.action".to_string(), "risky".to_string());
+        approval.start_reason();
+        approval.push_reason_char('x');
+        state.approval_ui.pending_approval = Some(approval);
+
+        let footer = Footer::new(&state);
+        let theme = Theme::palette(state.theme_variant());
+        let hints = footer.get_hints(theme);
+        assert_eq!(hints[0].content, "reason: ");
+        assert_eq!(hints[1].content, "x▏ ");
+        assert!(hints.iter().any(|s| s.content == "esc"));
     }
 
     #[test]
@@ -104,10 +104,22 @@ impl<'a> FuzzyFinderComponent<'a> {
                     Span::raw("")
                 };
 
+                let marker = if finder.is_recent(file) {
+                    Span::styled("● ", Style::default().fg(theme.yellow))
+                } else {
+                    Span::raw("  ")
+                };
+                let recent_span = if finder.is_recent(file) {
+                    Span::styled(" recent", Style::default().fg(theme.muted))
+                } else {
+                    Span::raw("")
+                };
+
                 let line = Line::from(vec![
-                    Span::raw("  "),
+                    marker,
                     Span::styled(&file.relative_path, style),
                     language_span,
+                    recent_span,
                 ]);
 
                 ListItem::new(line)
@@ -12,10 +12,13 @@ use ratatui::{
     widgets::Paragraph,
 };
 
+/// Number of cells in the context usage gauge
+const CONTEXT_GAUGE_WIDTH: usize = 5;
+
 /// Minimal session header displaying task title and usage statistics
 ///
 /// - Left: # Task title (from first user message)
-/// - Right: tokens % ($cost) version
+/// - Right: tokens context-gauge % ($cost) version
 pub struct Header<'a> {
     state: &'a HeaderState,
     theme_variant: ThemeVariant,
@@ -54,13 +57,22 @@ impl<'a> Header<'a> {
         let title_spans = vec![Span::styled(task_title, Style::default().fg(theme.fg).bg(theme.bg))];
 
         let tokens = self.state.tokens_display();
+        let gauge = self.state.context_gauge(CONTEXT_GAUGE_WIDTH);
         let percent = self.state.context_percentage();
+        let gauge_color = if self.state.is_context_critical() {
+            theme.red
+        } else if self.state.is_context_warning() {
+            theme.yellow
+        } else {
+            theme.cyan
+        };
         let cost = self.state.cost_display();
         let version = env!("CARGO_PKG_VERSION");
 
         let stats_spans = vec![
             Span::styled(format!("{} ", tokens), Style::default().fg(theme.fg).bg(theme.bg)),
-            Span::styled(format!("{}%", percent), Style::default().fg(theme.cyan).bg(theme.bg)),
+            Span::styled(format!("{} ", gauge), Style::default().fg(gauge_color).bg(theme.bg)),
+            Span::styled(format!("{}%", percent), Style::default().fg(gauge_color).bg(theme.bg)),
             Span::styled(format!(" ({})", cost), Style::default().fg(theme.muted).bg(theme.bg)),
             Span::styled(format!(" v{}", version), Style::default().fg(theme.muted).bg(theme.bg)),
         ];
@@ -36,9 +36,15 @@ impl<'a> Inspector<'a> {
             })
             .collect();
 
+        let mut list_title = vec![Span::raw(" Chain of Evidence ")];
+        if let Some(state) = evidence.verification {
+            list_title.push(Span::raw("· "));
+            list_title.push(Theme::verification_span(theme, state));
+            list_title.push(Span::raw(" "));
+        }
         let list_block = Block::default()
             .borders(Borders::ALL)
-            .title(" Chain of Evidence ")
+            .title(Line::from(list_title))
             .border_style(Style::default().fg(theme.blue));
 
         let list = List::new(items)
@@ -66,9 +72,15 @@ impl<'a> Inspector<'a> {
                     Span::styled("Timestamp: ", Style::default().fg(theme.muted)),
                     Span::styled(&selected_node.event.timestamp, Style::default().fg(theme.fg)),
                 ]),
-                Line::from(""),
-                Line::from(Span::styled("Content:", Style::default().fg(theme.muted))),
             ];
+            if let Some(source) = evidence.selected_source() {
+                lines.push(Line::from(vec![
+                    Span::styled("Source: ", Style::default().fg(theme.muted)),
+                    Span::styled(source.describe(), Style::default().fg(theme.fg)),
+                ]));
+            }
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled("Content:", Style::default().fg(theme.muted))));
 
             let highlighter = SyntaxHighlighter::new();
             let event_lines = self.highlight_event_details(&selected_node.event.event, &highlighter);
@@ -111,7 +123,7 @@ impl<'a> Inspector<'a> {
     fn highlight_event_details(&self, event: &Event, highlighter: &SyntaxHighlighter) -> Vec<Line<'static>> {
         match event {
             Event::UserMessage { content } => vec![Line::from(highlighter.highlight_code(content, "markdown"))],
-            Event::ToolCall { tool, arguments } => vec![
+            Event::ToolCall { tool, arguments, .. } => vec![
                 Line::from(format!("Tool: {}", tool)),
                 Line::from("Arguments:"),
                 Line::from(
@@ -18,7 +18,8 @@ use thunderus_core::memory::MemoryKind;
 ///
 /// Displays search results from the memory store with:
 /// - Title, kind, path, and snippet for each hit
-/// - BM25 score and execution time
+/// - BM25 score, token badge and execution time
+/// - Running token total for all hits and pinned hits
 /// - Keyboard navigation (up/down, Enter to open, 'p' to pin)
 pub struct MemoryHitsPanel<'a> {
     state: &'a MemoryHitsState,
@@ -44,8 +45,9 @@ impl<'a> MemoryHitsPanel<'a> {
         );
 
         let title = format!(
-            "Memory Hits ({}) - {:.0}ms",
+            "Memory Hits ({}) · {} pinned - {:.0}ms",
             self.state.hits.len(),
+            self.state.pinned_count(),
             self.state.search_time_ms
         );
 
@@ -72,14 +74,21 @@ impl<'a> MemoryHitsPanel<'a> {
 
             let score_display = format!("{:.1}", hit.score);
 
-            let title_line = Line::from(vec![
+            let mut title_spans = vec![
                 pin_indicator,
                 select_indicator,
                 Span::styled(kind_display, kind_style),
                 Span::styled(" ", Style::default()),
                 Span::styled(&hit.title, Style::default().fg(theme.blue).bold()),
                 Span::styled(format!(" [{}]", score_display), Style::default().fg(theme.muted)),
-            ]);
+                Span::styled(" ", Style::default()),
+                Span::styled(format_tokens(hit.token_count), Style::default().fg(theme.cyan)),
+            ];
+            if let Some(state) = hit.verification {
+                title_spans.push(Span::styled(" · ", Style::default().fg(theme.muted)));
+                title_spans.push(Theme::verification_span(theme, state));
+            }
+            let title_line = Line::from(title_spans);
 
             let path_line = Line::from(vec![
                 Span::styled("   ", Style::default()),
@@ -115,6 +124,17 @@ impl<'a> MemoryHitsPanel<'a> {
                             Style::default().fg(theme.muted),
                         ),
                     ]))
+                    .title_bottom(
+                        Line::from(Span::styled(
+                            format!(
+                                " {} total · {} pinned ",
+                                format_tokens(self.state.total_tokens()),
+                                format_tokens(self.state.pinned_tokens())
+                            ),
+                            Style::default().fg(theme.muted),
+                        ))
+                        .right_aligned(),
+                    )
                     .borders(Borders::ALL)
                     .border_style(Style::default().fg(theme.border))
                     .bg(theme.panel_bg),
@@ -149,6 +169,15 @@ impl<'a> MemoryHitsPanel<'a> {
     }
 }
 
+/// Format an approximate token count as a compact badge, e.g. `~1.2k tok`
+fn format_tokens(tokens: usize) -> String {
+    if tokens >= 1000 {
+        format!("~{:.1}k tok", tokens as f64 / 1000.0)
+    } else {
+        format!("~{} tok", tokens)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -164,6 +193,9 @@ mod tests {
             snippet: format!("Test snippet for {}", title),
             score: -5.0,
             event_ids: vec![],
+            token_count: 0,
+            verification: None,
+            verified_commit: None,
         }
     }
 
@@ -184,6 +216,13 @@ mod tests {
         assert_eq!(panel.state.hits.len(), 0);
     }
 
+    #[test]
+    fn test_format_tokens() {
+        assert_eq!(format_tokens(0), "~0 tok");
+        assert_eq!(format_tokens(120), "~120 tok");
+        assert_eq!(format_tokens(1240), "~1.2k tok");
+    }
+
     #[test]
     fn test_memory_hits_panel_with_hits() {
         let state = create_test_state();
@@ -4,6 +4,8 @@ use crate::{
     theme::Theme,
 };
 
+use thunderus_skills::{SkillDriver, SkillRisk};
+
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
@@ -44,6 +46,7 @@ impl<'a> Sidebar<'a> {
                 Constraint::Length(4),
                 Constraint::Length(4),
                 Constraint::Length(5),
+                Constraint::Length(5),
                 Constraint::Min(0),
             ])
             .split(sidebar_area);
@@ -90,13 +93,23 @@ impl<'a> Sidebar<'a> {
             self.render_git_diff_queue(frame, pad_rect(sections[3]));
         }
 
+        if !self
+            .state
+            .ui
+            .sidebar_collapse_state
+            .is_collapsed(SidebarSection::Skills)
+            && !self.state.session.skills.is_empty()
+        {
+            self.render_skills(frame, pad_rect(sections[5]));
+        }
+
         if !self
             .state
             .ui
             .sidebar_collapse_state
             .is_collapsed(SidebarSection::Context)
         {
-            self.render_context(frame, pad_rect(sections[5]));
+            self.render_context(frame, pad_rect(sections[6]));
         }
     }
 
@@ -219,17 +232,58 @@ impl<'a> Sidebar<'a> {
         diff_view.render(frame, area);
     }
 
+    fn render_skills(&self, frame: &mut Frame<'_>, area: Rect) {
+        let skills = &self.state.session.skills;
+        let theme = Theme::palette(self.state.theme_variant());
+        let mut lines = vec![Line::from(vec![Span::styled(
+            " Skills",
+            Style::default().fg(theme.muted),
+        )])];
+
+        for skill in skills.iter().take(2) {
+            let driver = match skill.driver {
+                SkillDriver::Shell => "sh",
+                SkillDriver::Wasm => "wasm",
+                SkillDriver::Lua => "lua",
+                SkillDriver::Mcp => "mcp",
+            };
+            let (risk, risk_color) = match skill.risk_level {
+                SkillRisk::Safe => ("safe", theme.green),
+                SkillRisk::Moderate => ("mod", theme.yellow),
+                SkillRisk::Risky => ("risky", theme.red),
+            };
+            lines.push(Line::from(vec![
+                Span::styled(" ", Style::default()),
+                Span::styled(&skill.name, Style::default().fg(theme.fg)),
+                Span::styled(format!(" {} ", driver), Style::default().fg(theme.purple)),
+                Span::styled(risk, Style::default().fg(risk_color)),
+            ]));
+        }
+
+        if skills.len() > 2 {
+            lines.push(Line::from(Span::styled(
+                format!(" +{}", skills.len() - 2),
+                Style::default().fg(theme.muted),
+            )));
+        }
+        lines.push(Line::default());
+
+        let paragraph = Paragraph::new(lines).wrap(Wrap { trim: true });
+        frame.render_widget(paragraph, area);
+    }
+
     fn render_context(&self, frame: &mut Frame<'_>, area: Rect) {
         let theme = Theme::palette(self.state.theme_variant());
+        let mut sources = vec![Span::styled(" ", Style::default())];
+        if self.state.config.memory_enabled {
+            sources.push(Span::styled("M", Style::default().fg(theme.green)));
+            sources.push(Span::styled(" memory  ", Style::default().fg(theme.fg)));
+        }
+        sources.push(Span::styled("P", Style::default().fg(theme.cyan)));
+        sources.push(Span::styled(" plan", Style::default().fg(theme.fg)));
         let lines = vec![
             Line::from(vec![Span::styled(" Context", Style::default().fg(theme.muted))]),
-            Line::from(vec![
-                Span::styled(" ", Style::default()),
-                Span::styled("M", Style::default().fg(theme.green)),
-                Span::styled(" memory  ", Style::default().fg(theme.fg)),
-                Span::styled("P", Style::default().fg(theme.cyan)),
-                Span::styled(" plan", Style::default().fg(theme.fg)),
-            ]),
+            Line::from(sources),
             Line::default(),
         ];
 
@@ -316,4 +370,44 @@ mod tests {
         assert_eq!(sidebar.state.session.stats.input_tokens, 100);
         assert_eq!(sidebar.state.session.stats.output_tokens, 200);
     }
+
+    #[test]
+    fn test_sidebar_skills_section_collapse() {
+        let mut state = create_test_state();
+        let skill: thunderus_skills::SkillMeta = serde_json::from_value(serde_json::json!({
+            "name": "lint-check",
+            "description": "Run the linter",
+            "driver": "lua",
+            "risk_level": "moderate",
+        }))
+        .unwrap();
+        assert_eq!(skill.driver, SkillDriver::Lua);
+        assert_eq!(skill.risk_level, SkillRisk::Moderate);
+        state.session.skills.push(skill);
+
+        let rendered = |state: &AppState| {
+            let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(40, 40)).unwrap();
+            terminal
+                .draw(|frame| Sidebar::new(state).render(frame, frame.area()))
+                .unwrap();
+            let buffer = terminal.backend().buffer();
+            (0..buffer.area.height)
+                .map(|y| {
+                    (0..buffer.area.width)
+                        .map(|x| buffer[(x, y)].symbol())
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        assert!(!state.ui.sidebar_collapse_state.is_collapsed(SidebarSection::Skills));
+        let expanded = rendered(&state);
+        assert!(expanded.contains("Skills"));
+        assert!(expanded.contains("lint-check lua mod"));
+
+        state.ui.sidebar_collapse_state.toggle(SidebarSection::Skills);
+        assert!(state.ui.sidebar_collapse_state.is_collapsed(SidebarSection::Skills));
+        assert!(!rendered(&state).contains("lint-check"));
+    }
 }
@@ -1,12 +1,14 @@
 //! Teaching hint popup component
 //!
 //! Displays a one-time hint when a concept is encountered for the first time.
+//! Concept hints can be dismissed permanently by pressing `d`.
 //! These hints are educational and help users understand the system's behavior.
 
 use ratatui::{
     Frame,
     layout::{Alignment, Rect},
     style::Style,
+    text::{Line, Span},
     widgets::{Block, Borders, Clear, Padding, Paragraph, Wrap},
 };
 
@@ -18,6 +20,8 @@ pub struct TeachingHintPopup<'a> {
     pub hint: &'a str,
     /// Optional title for the hint (defaults to "First Time")
     pub title: Option<&'a str>,
+    /// Whether the hint can be dismissed permanently with `d`
+    pub dismissible: bool,
     /// Theme palette for styling
     pub theme: ThemePalette,
 }
@@ -25,7 +29,13 @@ pub struct TeachingHintPopup<'a> {
 impl<'a> TeachingHintPopup<'a> {
     /// Create a new teaching hint popup
     pub fn new(hint: &'a str, theme: ThemePalette) -> Self {
-        Self { hint, title: None, theme }
+        Self { hint, title: None, dismissible: false, theme }
+    }
+
+    /// Advertise the "don't show again" action in the popup border
+    pub fn dismissible(mut self, dismissible: bool) -> Self {
+        self.dismissible = dismissible;
+        self
     }
 
     /// Set a custom title for the hint
@@ -57,12 +67,21 @@ impl<'a> TeachingHintPopup<'a> {
 
         frame.render_widget(Clear, popup_area);
 
-        let block = Block::default()
+        let mut block = Block::default()
             .title(self.title.unwrap_or("First Time"))
             .borders(Borders::ALL)
             .border_style(Style::default().fg(self.theme.yellow))
             .style(Style::default().bg(self.theme.panel_bg))
             .padding(Padding::new(1, 1, 1, 1));
+        if self.dismissible {
+            block = block.title_bottom(
+                Line::from(Span::styled(
+                    " d: don't show again ",
+                    Style::default().fg(self.theme.muted),
+                ))
+                .right_aligned(),
+            );
+        }
 
         frame.render_widget(
             Paragraph::new(self.hint)
@@ -100,4 +119,11 @@ mod tests {
         let theme = Theme::palette(ThemeVariant::Iceberg);
         assert_eq!(TeachingHintPopup::new("Test", theme).title, None);
     }
+
+    #[test]
+    fn test_teaching_hint_dismissible() {
+        let theme = Theme::palette(ThemeVariant::Iceberg);
+        assert!(!TeachingHintPopup::new("Test", theme).dismissible);
+        assert!(TeachingHintPopup::new("Test", theme).dismissible(true).dismissible);
+    }
 }
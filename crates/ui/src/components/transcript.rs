@@ -1,3 +1,4 @@
+use crate::inline_image::ImagePlacement;
 use crate::theme::ThemePalette;
 use crate::transcript::{RenderOptions, Transcript as TranscriptState, TranscriptRenderer};
 
@@ -35,8 +36,15 @@ impl<'a> Transcript<'a> {
     }
 
     /// Render transcript to the given frame
-    pub fn render(&self, frame: &mut Frame<'_>, area: Rect) {
-        self.renderer.render(frame, area);
+    ///
+    /// Returns the rendered content height and the largest valid scroll offset.
+    pub fn render(&self, frame: &mut Frame<'_>, area: Rect) -> (usize, u16) {
+        self.renderer.render(frame, area)
+    }
+
+    /// Images placed by the last render, in screen cells
+    pub fn image_placements(&self) -> Vec<ImagePlacement> {
+        self.renderer.image_placements()
     }
 
     /// Get the underlying transcript
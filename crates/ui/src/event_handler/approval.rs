@@ -5,23 +5,80 @@ use super::KeyAction;
 
 /// Handle keys when there's a pending approval
 pub fn handle_approval_key(event: KeyEvent, state: &mut AppState) -> Option<KeyAction> {
+    if state
+        .approval_ui
+        .pending_approval
+        .as_ref()
+        .is_some_and(|approval| approval.is_entering_reason())
+    {
+        return handle_reason_key(event, state);
+    }
+
     match event.code {
         KeyCode::Char('y') | KeyCode::Char('Y') => state
             .approval_ui
             .pending_approval
             .as_ref()
             .map(|approval| KeyAction::Approve { action: approval.action.clone(), risk: approval.risk.clone() }),
+        KeyCode::Char('a') | KeyCode::Char('A') => {
+            state
+                .approval_ui
+                .pending_approval
+                .as_ref()
+                .map(|approval| KeyAction::ApproveAndRemember {
+                    action: approval.action.clone(),
+                    risk: approval.risk.clone(),
+                })
+        }
         KeyCode::Char('n') | KeyCode::Char('N') => state
             .approval_ui
             .pending_approval
             .as_ref()
             .map(|approval| KeyAction::Reject { action: approval.action.clone(), risk: approval.risk.clone() }),
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            if let Some(approval) = state.approval_ui.pending_approval.as_mut() {
+                approval.start_reason();
+            }
+            None
+        }
         KeyCode::Char('c') | KeyCode::Char('C') => state
             .approval_ui
             .pending_approval
             .as_ref()
             .map(|approval| KeyAction::Cancel { action: approval.action.clone(), risk: approval.risk.clone() }),
+        KeyCode::Char('v') | KeyCode::Char('V') => Some(KeyAction::ExpandApprovalDiff),
         KeyCode::Esc => Some(KeyAction::CancelGeneration),
         _ => None,
     }
 }
+
+/// Handle keys while typing a rejection reason
+///
+/// Enter rejects with the reason (a blank reason is a plain rejection) and
+/// Esc goes back to the approval prompt.
+fn handle_reason_key(event: KeyEvent, state: &mut AppState) -> Option<KeyAction> {
+    let approval = state.approval_ui.pending_approval.as_mut()?;
+    match event.code {
+        KeyCode::Enter => {
+            let reason = approval.reason.take().unwrap_or_default().trim().to_string();
+            if reason.is_empty() {
+                Some(KeyAction::Reject { action: approval.action.clone(), risk: approval.risk.clone() })
+            } else {
+                Some(KeyAction::RejectWithReason { reason })
+            }
+        }
+        KeyCode::Esc => {
+            approval.cancel_reason();
+            None
+        }
+        KeyCode::Backspace => {
+            approval.pop_reason_char();
+            None
+        }
+        KeyCode::Char(c) => {
+            approval.push_reason_char(c);
+            None
+        }
+        _ => None,
+    }
+}
@@ -7,10 +7,16 @@ pub enum KeyAction {
     ExecuteShellCommand { command: String },
     /// User approves an action
     Approve { action: String, risk: String },
+    /// User approves an action and every matching action for the rest of the session
+    ApproveAndRemember { action: String, risk: String },
     /// User rejects an action
     Reject { action: String, risk: String },
+    /// User rejects an action and tells the agent why
+    RejectWithReason { reason: String },
     /// User cancels an action
     Cancel { action: String, risk: String },
+    /// Show the full diff on the pending approval prompt
+    ExpandApprovalDiff,
     /// User wants to cancel generation
     CancelGeneration,
     /// Toggle sidebar
@@ -30,6 +36,10 @@ pub enum KeyAction {
     InspectMemory { path: String },
     /// Navigate within inspector
     InspectorNavigate,
+    /// Open or close the full-screen diff review
+    ToggleDiffReview,
+    /// Navigate within the diff review (handled in state)
+    DiffReviewNavigate,
     /// Open external editor for current input
     OpenExternalEditor,
     /// Navigate message history (handled internally by InputState)
@@ -48,19 +58,31 @@ pub enum KeyAction {
     CancelFuzzyFinder,
     /// Slash command: switch provider/model
     SlashCommandModel { model: String },
-    /// Slash command: change approval mode
-    SlashCommandApprovals { mode: String },
-    /// Slash command: change verbosity level
-    SlashCommandVerbosity { level: String },
+    /// Slash command: switch the active profile (`None` lists the profiles)
+    SlashCommandProfile { name: Option<String> },
+    /// Slash command: change approval mode, optionally saving it to the profile
+    SlashCommandApprovals { mode: String, persist: bool },
+    /// Slash command: change verbosity level, optionally saving it to the profile
+    SlashCommandVerbosity { level: String, persist: bool },
     /// Slash command: show session stats
     SlashCommandStatus,
+    /// Slash command: show approval, tool and token statistics
+    SlashCommandStats,
+    /// Permanently dismiss the teaching hint for a concept
+    DismissHintPermanently { concept: String },
+    /// Slash command: show or set the active task title
+    SlashCommandTask { title: String },
     /// Slash command: display PLAN.md content
     SlashCommandPlan,
     /// Slash command: add item to plan
     SlashCommandPlanAdd { item: String },
     /// Slash command: mark plan item as done
     SlashCommandPlanDone { index: usize },
-    /// Slash command: trigger review pass
+    /// Slash command: remove plan item by index
+    SlashCommandPlanRemove { index: usize },
+    /// Slash command: move plan item to another index
+    SlashCommandPlanMove { from: usize, to: usize },
+    /// Slash command: open the full-screen diff review
     SlashCommandReview,
     /// Slash command: display MEMORY.md content
     SlashCommandMemory,
@@ -72,6 +94,8 @@ pub enum KeyAction {
     SlashCommandMemoryPin { id: String },
     /// Slash command: clear transcript (keep session history)
     SlashCommandClear,
+    /// Slash command: resume a response truncated by max_tokens
+    SlashCommandContinue,
     /// Slash command: garden consolidate session
     SlashCommandGardenConsolidate { session_id: String },
     /// Slash command: garden hygiene check
@@ -80,12 +104,16 @@ pub enum KeyAction {
     SlashCommandGardenDrift,
     /// Slash command: garden verify document
     SlashCommandGardenVerify { doc_id: String },
+    /// Slash command: garden verify every stale document
+    SlashCommandGardenVerifyAll,
     /// Slash command: garden statistics
     SlashCommandGardenStats,
     /// Slash command: search session with ripgrep
     SlashCommandSearch {
         query: String,
         scope: thunderus_core::SearchScope,
+        /// Search every session instead of only the current one
+        all_sessions: bool,
     },
     /// Memory hits panel navigation
     MemoryHitsNavigate,
@@ -143,7 +171,8 @@ pub enum KeyAction {
     ToggleHunkDetails,
     /// No action (e.g., navigation in input)
     NoOp,
-    /// Open a file from the inspector
+    /// Open the file behind the selected inspector evidence: patches open in
+    /// the diff review, other files in the external editor
     InspectorOpenFile { path: String },
     /// Start the reconcile ritual after drift/interruption
     StartReconcileRitual,
@@ -12,7 +12,7 @@ use self::{
 };
 use crate::state::AppState;
 
-use crossterm::event::{Event, KeyEvent, KeyEventKind};
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind};
 
 /// Event handler for the TUI application
 pub struct EventHandler;
@@ -47,7 +47,14 @@ impl EventHandler {
         }
 
         if state.approval_ui.pending_hint.is_some() {
-            state.approval_ui.pending_hint = None;
+            let concept = state.approval_ui.pending_hint_concept.take();
+            state.dismiss_hint();
+            if let Some(concept) = concept
+                && event.code == KeyCode::Char('d')
+                && event.modifiers.is_empty()
+            {
+                return Some(KeyAction::DismissHintPermanently { concept });
+            }
         }
 
         if state.is_fuzzy_finder_active() {
@@ -80,7 +87,7 @@ impl EventHandler {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crossterm::event::{KeyCode, KeyModifiers};
+    use crossterm::event::KeyModifiers;
     use std::path::PathBuf;
     use thunderus_core::{ApprovalMode, ProviderConfig, SandboxMode};
 
@@ -130,6 +137,35 @@ mod tests {
         assert_eq!(state.input.buffer, "x");
     }
 
+    #[test]
+    fn test_hint_dismissed_permanently_with_d() {
+        let mut state = create_test_state();
+        state.show_concept_hint("file_destruction", "Deletion is permanent");
+
+        let event = KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE);
+        let action = EventHandler::handle_key_event(event, &mut state);
+
+        assert_eq!(
+            action,
+            Some(KeyAction::DismissHintPermanently { concept: "file_destruction".to_string() })
+        );
+        assert!(!state.has_pending_hint());
+        assert_eq!(state.input.buffer, "");
+    }
+
+    #[test]
+    fn test_hint_without_concept_passes_key_through() {
+        let mut state = create_test_state();
+        state.show_hint("Press Ctrl+C again to exit");
+
+        let event = KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE);
+        let action = EventHandler::handle_key_event(event, &mut state);
+
+        assert!(action.is_none());
+        assert!(!state.has_pending_hint());
+        assert_eq!(state.input.buffer, "d");
+    }
+
     #[test]
     fn test_handle_event_focus_gained_refreshes_git_branch() {
         let mut state = create_test_state();
@@ -0,0 +1,144 @@
+use crate::state::AppState;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::KeyAction;
+
+/// Handle keys while the full-screen diff review is open
+pub fn handle_diff_review_key(event: KeyEvent, state: &mut AppState) -> Option<KeyAction> {
+    if !state.ui.is_diff_review() {
+        return None;
+    }
+
+    let control = event.modifiers.contains(KeyModifiers::CONTROL);
+    match event.code {
+        KeyCode::Esc | KeyCode::Char('q') => Some(KeyAction::ToggleDiffReview),
+        KeyCode::Char('o') if control => Some(KeyAction::ToggleDiffReview),
+        _ if control => None,
+        KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('n') => {
+            state.review_step_hunk(true);
+            Some(KeyAction::DiffReviewNavigate)
+        }
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('p') => {
+            state.review_step_hunk(false);
+            Some(KeyAction::DiffReviewNavigate)
+        }
+        KeyCode::Tab | KeyCode::Char('J') | KeyCode::Char('N') => {
+            state.review_step_patch(true);
+            Some(KeyAction::DiffReviewNavigate)
+        }
+        KeyCode::BackTab | KeyCode::Char('K') | KeyCode::Char('P') => {
+            state.review_step_patch(false);
+            Some(KeyAction::DiffReviewNavigate)
+        }
+        KeyCode::Char('a') => Some(KeyAction::ApproveHunk),
+        KeyCode::Char('r') => Some(KeyAction::RejectHunk),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::MainView;
+    use std::path::PathBuf;
+    use thunderus_core::{ApprovalMode, Patch, PatchId, ProviderConfig, SandboxMode, SessionId};
+
+    fn create_review_state() -> AppState {
+        let mut state = AppState::new(
+            PathBuf::from("."),
+            "test".to_string(),
+            ProviderConfig::Glm {
+                api_key: "test".to_string(),
+                model: "glm-4.7".to_string(),
+                base_url: "https://api.example.com".to_string(),
+                thinking: Default::default(),
+                options: Default::default(),
+            },
+            ApprovalMode::Auto,
+            SandboxMode::Policy,
+            false,
+        );
+        let diff = "diff --git a/src/a.rs b/src/a.rs\n@@ -1,1 +1,1 @@\n-old\n+new\n\
+                    diff --git a/src/b.rs b/src/b.rs\n@@ -1,1 +1,1 @@\n-old\n+new";
+        let patch = Patch::new(
+            PatchId::new("patch1"),
+            "Rename helpers".to_string(),
+            "HEAD".to_string(),
+            diff.to_string(),
+            SessionId::new(),
+            0,
+        )
+        .unwrap();
+        state.patches_mut().push(patch);
+        state
+    }
+
+    #[test]
+    fn test_diff_review_keys_ignored_outside_review() {
+        let mut state = create_review_state();
+        let event = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE);
+        assert!(handle_diff_review_key(event, &mut state).is_none());
+    }
+
+    #[test]
+    fn test_diff_review_navigates_hunks_across_files() {
+        let mut state = create_review_state();
+        state.enter_diff_review();
+        assert_eq!(state.ui.active_view, MainView::DiffReview);
+        assert_eq!(state.selected_patch_index(), Some(0));
+        assert_eq!(state.selected_file_path().map(String::as_str), Some("src/a.rs"));
+        assert_eq!(state.selected_hunk_index(), Some(0));
+
+        let event = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE);
+        assert!(matches!(
+            handle_diff_review_key(event, &mut state),
+            Some(KeyAction::DiffReviewNavigate)
+        ));
+        assert_eq!(state.selected_file_path().map(String::as_str), Some("src/b.rs"));
+        assert_eq!(state.selected_hunk_index(), Some(0));
+
+        let event = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE);
+        handle_diff_review_key(event, &mut state);
+        assert_eq!(state.selected_file_path().map(String::as_str), Some("src/b.rs"));
+
+        let event = KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE);
+        handle_diff_review_key(event, &mut state);
+        assert_eq!(state.selected_file_path().map(String::as_str), Some("src/a.rs"));
+    }
+
+    #[test]
+    fn test_diff_review_approve_reject_and_exit() {
+        let mut state = create_review_state();
+        state.enter_diff_review();
+
+        let event = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE);
+        assert!(matches!(
+            handle_diff_review_key(event, &mut state),
+            Some(KeyAction::ApproveHunk)
+        ));
+
+        let event = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE);
+        assert!(matches!(
+            handle_diff_review_key(event, &mut state),
+            Some(KeyAction::RejectHunk)
+        ));
+
+        let event = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+        assert!(matches!(
+            handle_diff_review_key(event, &mut state),
+            Some(KeyAction::ToggleDiffReview)
+        ));
+    }
+
+    #[test]
+    fn test_exit_diff_review_restores_previous_view() {
+        let mut state = create_review_state();
+        state.ui.active_view = MainView::Inspector;
+
+        state.toggle_diff_review();
+        assert_eq!(state.ui.active_view, MainView::DiffReview);
+
+        state.toggle_diff_review();
+        assert_eq!(state.ui.active_view, MainView::Inspector);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::state::AppState;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::KeyAction;
+
+/// Handle keys while a reverse-incremental history search is active
+pub fn handle_history_search_key(event: KeyEvent, state: &mut AppState) -> Option<KeyAction> {
+    if !state.input.is_searching_history() {
+        return None;
+    }
+
+    match event.code {
+        KeyCode::Char('r') if event.modifiers.contains(KeyModifiers::CONTROL) => state.input.history_search_older(),
+        KeyCode::Char('c') if event.modifiers.contains(KeyModifiers::CONTROL) => state.input.cancel_history_search(),
+        KeyCode::Char(c) => state.input.history_search_push(c),
+        KeyCode::Backspace => state.input.history_search_pop(),
+        KeyCode::Up => state.input.history_search_older(),
+        KeyCode::Down => state.input.history_search_newer(),
+        KeyCode::Enter | KeyCode::Tab => state.input.accept_history_search(),
+        KeyCode::Esc => state.input.cancel_history_search(),
+        _ => {}
+    }
+
+    Some(KeyAction::NoOp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use thunderus_core::{ApprovalMode, ProviderConfig, SandboxMode};
+
+    fn create_test_state() -> AppState {
+        let mut state = AppState::new(
+            PathBuf::from("."),
+            "test".to_string(),
+            ProviderConfig::Glm {
+                api_key: "test".to_string(),
+                model: "glm-4.7".to_string(),
+                base_url: "https://api.example.com".to_string(),
+                thinking: Default::default(),
+                options: Default::default(),
+            },
+            ApprovalMode::Auto,
+            SandboxMode::Policy,
+            false,
+        );
+        state.set_first_session(false);
+        state.input.add_to_history("refactor the lexer".to_string());
+        state.input.add_to_history("run the tests".to_string());
+        state.input.add_to_history("refactor the parser".to_string());
+        state
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_inactive_search_passes_through() {
+        let mut state = create_test_state();
+        assert!(handle_history_search_key(key(KeyCode::Char('a')), &mut state).is_none());
+    }
+
+    #[test]
+    fn test_search_filters_cycles_and_accepts() {
+        let mut state = create_test_state();
+        state.input.start_history_search();
+
+        for c in "refactor".chars() {
+            handle_history_search_key(key(KeyCode::Char(c)), &mut state);
+        }
+        assert_eq!(state.input.history_search_match(), Some("refactor the parser"));
+
+        let ctrl_r = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL);
+        handle_history_search_key(ctrl_r, &mut state);
+        assert_eq!(state.input.history_search_match(), Some("refactor the lexer"));
+
+        let action = handle_history_search_key(key(KeyCode::Enter), &mut state);
+        assert!(matches!(action, Some(KeyAction::NoOp)));
+        assert!(!state.input.is_searching_history());
+        assert_eq!(state.input.buffer, "refactor the lexer");
+    }
+
+    #[test]
+    fn test_search_escape_cancels() {
+        let mut state = create_test_state();
+        state.input.start_history_search();
+        handle_history_search_key(key(KeyCode::Char('r')), &mut state);
+        handle_history_search_key(key(KeyCode::Esc), &mut state);
+
+        assert!(!state.input.is_searching_history());
+        assert!(state.input.buffer.is_empty());
+    }
+}
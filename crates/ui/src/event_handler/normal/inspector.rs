@@ -1,3 +1,4 @@
+use crate::components::Inspector;
 use crate::state::{AppState, MainView};
 use crossterm::event::{KeyCode, KeyEvent};
 
@@ -29,7 +30,14 @@ pub fn handle_inspector_key(event: KeyEvent, state: &mut AppState) -> Option<Key
             state.ui.toggle_inspector();
             Some(KeyAction::ToggleInspector)
         }
-        KeyCode::Char('f') | KeyCode::Char('F') => Some(KeyAction::InspectorOpenFile { path: String::new() }),
+        KeyCode::Char('f') | KeyCode::Char('F') => {
+            let path = Inspector::new(state)
+                .affected_files()
+                .into_iter()
+                .next()
+                .unwrap_or_default();
+            Some(KeyAction::InspectorOpenFile { path })
+        }
         _ => None,
     }
 }
@@ -73,6 +73,10 @@ pub(super) fn handle_action_keys(event: &KeyEvent, state: &mut AppState) -> KeyH
             if state.ui.is_reconciling() {
                 return KeyHandling::Handled(Some(KeyAction::ReconcileContinue));
             }
+            if state.is_multiline_input() && event.modifiers.intersects(KeyModifiers::SHIFT | KeyModifiers::ALT) {
+                state.input.insert_char('\n');
+                return KeyHandling::Handled(None);
+            }
             if !state.input.buffer.is_empty() {
                 let message = state.input.take();
 
@@ -99,7 +103,10 @@ pub(super) fn handle_action_keys(event: &KeyEvent, state: &mut AppState) -> KeyH
             KeyHandling::Handled(None)
         }
         KeyCode::Char('j') | KeyCode::Char('J') => {
-            if state.input.buffer.is_empty() {
+            // Ctrl+J is a line feed, for terminals that can't report Shift+Enter
+            if state.is_multiline_input() && event.modifiers.contains(KeyModifiers::CONTROL) {
+                state.input.insert_char('\n');
+            } else if state.input.buffer.is_empty() {
                 return KeyHandling::Handled(Some(KeyAction::NavigateCardNext));
             } else {
                 state.input.insert_char('j');
@@ -166,6 +173,59 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_handle_normal_key_shift_enter_multiline() {
+        let mut state = create_test_state();
+        state.input.buffer = "first".to_string();
+        state.input.cursor = 5;
+
+        let event = KeyEvent::new(KeyCode::Enter, KeyModifiers::SHIFT);
+        state.composer.toggle_multiline();
+        let action = handle_action_keys(&event, &mut state);
+        assert!(matches!(action, KeyHandling::Handled(None)));
+        assert_eq!(state.input.buffer, "first\n");
+
+        let event = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        let action = handle_action_keys(&event, &mut state);
+        assert!(matches!(
+            action,
+            KeyHandling::Handled(Some(KeyAction::SendMessage { message })) if message == "first\n"
+        ));
+    }
+
+    #[test]
+    fn test_handle_normal_key_newline_fallbacks() {
+        let mut state = create_test_state();
+        state.composer.toggle_multiline();
+        state.input.buffer = "a".to_string();
+        state.input.cursor = 1;
+
+        let event = KeyEvent::new(KeyCode::Enter, KeyModifiers::ALT);
+        assert!(matches!(
+            handle_action_keys(&event, &mut state),
+            KeyHandling::Handled(None)
+        ));
+        let event = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::CONTROL);
+        assert!(matches!(
+            handle_action_keys(&event, &mut state),
+            KeyHandling::Handled(None)
+        ));
+        assert_eq!(state.input.buffer, "a\n\n");
+    }
+
+    #[test]
+    fn test_handle_normal_key_shift_enter_single_line_sends() {
+        let mut state = create_test_state();
+        state.input.buffer = "first".to_string();
+
+        let event = KeyEvent::new(KeyCode::Enter, KeyModifiers::SHIFT);
+        let action = handle_action_keys(&event, &mut state);
+        assert!(matches!(
+            action,
+            KeyHandling::Handled(Some(KeyAction::SendMessage { .. }))
+        ));
+    }
+
     #[test]
     fn test_handle_normal_key_enter_empty() {
         let mut state = create_test_state();
@@ -33,9 +33,22 @@ pub(super) fn handle_char_keys(event: &KeyEvent, state: &mut AppState) -> KeyHan
     } else if event.modifiers.contains(KeyModifiers::CONTROL) && c == 'a' {
         return KeyHandling::Handled(Some(KeyAction::ToggleAdvisorMode));
     } else if event.modifiers.contains(KeyModifiers::CONTROL) && c == 'r' {
-        return KeyHandling::Handled(Some(KeyAction::RetryLastFailedAction));
+        if !state.input.start_history_search() {
+            state.show_hint("No message history to search");
+        }
+        return KeyHandling::Handled(None);
+    } else if event.modifiers.contains(KeyModifiers::CONTROL) && c == 'o' {
+        return KeyHandling::Handled(Some(KeyAction::ToggleDiffReview));
     } else if event.modifiers.contains(KeyModifiers::CONTROL) && c == 'l' {
         return KeyHandling::Handled(Some(KeyAction::ClearTranscriptView));
+    } else if event.modifiers.contains(KeyModifiers::CONTROL) && c == 'e' {
+        state.composer.toggle_multiline();
+        if state.is_multiline_input() {
+            state.show_hint("Multi-line input: Shift+Enter (or Alt+Enter, Ctrl+J) for a newline, Enter to send");
+        } else {
+            state.show_hint("Single-line input");
+        }
+        return KeyHandling::Handled(None);
     } else if !event.modifiers.contains(KeyModifiers::CONTROL) && state.input.buffer.is_empty() && c == 'd' {
         if state.selected_patch_index().is_some() {
             return KeyHandling::Handled(Some(KeyAction::ToggleHunkDetails));
@@ -237,16 +250,17 @@ mod tests {
     }
 
     #[test]
-    fn test_handle_normal_key_ctrl_r_retry() {
+    fn test_handle_normal_key_ctrl_r_history_search() {
         let mut state = create_test_state();
-
         let event = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL);
+
         let action = handle_char_keys(&event, &mut state);
+        assert!(matches!(action, KeyHandling::Handled(None)));
+        assert!(!state.input.is_searching_history());
 
-        assert!(matches!(
-            action,
-            KeyHandling::Handled(Some(KeyAction::RetryLastFailedAction))
-        ));
+        state.input.add_to_history("hello".to_string());
+        handle_char_keys(&event, &mut state);
+        assert!(state.input.is_searching_history());
     }
 
     #[test]
@@ -288,6 +302,20 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_handle_normal_key_ctrl_e_toggles_multiline() {
+        let mut state = create_test_state();
+
+        let event = KeyEvent::new(KeyCode::Char('e'), KeyModifiers::CONTROL);
+        let action = handle_char_keys(&event, &mut state);
+        assert!(matches!(action, KeyHandling::Handled(None)));
+        assert!(state.is_multiline_input());
+        assert_eq!(state.input.buffer, "");
+
+        handle_char_keys(&event, &mut state);
+        assert!(!state.is_multiline_input());
+    }
+
     #[test]
     fn test_handle_normal_key_g_with_input() {
         let mut state = create_test_state();
@@ -6,11 +6,15 @@ use crossterm::event::{KeyCode, KeyEvent};
 pub(super) fn handle_edit_keys(event: &KeyEvent, state: &mut AppState) -> KeyHandling {
     match event.code {
         KeyCode::Up => {
-            state.input.navigate_up();
+            if !(state.is_multiline_input() && state.input.move_line_up()) {
+                state.input.navigate_up();
+            }
             KeyHandling::Handled(None)
         }
         KeyCode::Down => {
-            state.input.navigate_down();
+            if !(state.is_multiline_input() && state.input.move_line_down()) {
+                state.input.navigate_down();
+            }
             KeyHandling::Handled(None)
         }
         KeyCode::Backspace => {
@@ -21,14 +21,10 @@ pub fn handle_memory_hits_key(event: KeyEvent, state: &mut AppState) -> Option<K
             .memory_hits
             .selected_hit()
             .map(|hit| KeyAction::MemoryHitsOpen { path: hit.path.clone() }),
-        KeyCode::Char('p') | KeyCode::Char('P') => match state.memory_hits.selected_hit() {
-            Some(hit) => {
-                let id = hit.id.clone();
-                state.memory_hits.toggle_pin(&id);
-                Some(KeyAction::MemoryHitsPin { id })
-            }
-            None => None,
-        },
+        KeyCode::Char('p') | KeyCode::Char('P') => state
+            .memory_hits
+            .selected_hit()
+            .map(|hit| KeyAction::MemoryHitsPin { id: hit.id.clone() }),
         KeyCode::Char('i') | KeyCode::Char('I') => state
             .memory_hits
             .selected_hit()
@@ -1,3 +1,5 @@
+mod diff_review;
+mod history_search;
 mod inspector;
 mod keys;
 mod memory_hits;
@@ -17,6 +19,14 @@ pub fn handle_normal_key(event: KeyEvent, state: &mut AppState) -> Option<KeyAct
         }
     }
 
+    if let Some(action) = history_search::handle_history_search_key(event, state) {
+        return Some(action);
+    }
+
+    if let Some(action) = diff_review::handle_diff_review_key(event, state) {
+        return Some(action);
+    }
+
     if let Some(action) = inspector::handle_inspector_key(event, state) {
         return Some(action);
     }
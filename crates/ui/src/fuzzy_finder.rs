@@ -1,11 +1,18 @@
-use ignore::WalkBuilder;
 use nucleo_matcher::{
     Config, Matcher, Utf32Str,
     pattern::{CaseMatching, Normalization, Pattern},
 };
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
+/// Number of recently selected files remembered per workspace
+const MAX_RECENT_FILES: usize = 20;
+
+/// Queries up to this many characters still float recent files to the top
+const SHORT_QUERY_LEN: usize = 2;
+
 /// File entry for fuzzy finder
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FileEntry {
@@ -49,6 +56,8 @@ pub enum SortMode {
     ModifiedTime,
     /// Sort by path alphabetically
     Path,
+    /// Recently selected files first, then by relevance
+    Recent,
 }
 
 impl SortMode {
@@ -56,11 +65,73 @@ impl SortMode {
         *self = match self {
             SortMode::Relevance => SortMode::ModifiedTime,
             SortMode::ModifiedTime => SortMode::Path,
-            SortMode::Path => SortMode::Relevance,
+            SortMode::Path => SortMode::Recent,
+            SortMode::Recent => SortMode::Relevance,
         }
     }
 }
 
+/// Recently `@`-mentioned files, most recent first, keyed by workspace root
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RecentFiles {
+    #[serde(default)]
+    workspaces: BTreeMap<String, Vec<String>>,
+}
+
+impl RecentFiles {
+    /// Default location of the user state file
+    pub fn default_path() -> Option<PathBuf> {
+        let home = std::env::var("HOME").or_else(|_| std::env::var("USERPROFILE")).ok()?;
+        Some(
+            PathBuf::from(home)
+                .join(".thunderus")
+                .join("state")
+                .join("recent_files.json"),
+        )
+    }
+
+    /// Load recent files from `path`, yielding an empty list if it is missing or unreadable
+    pub fn load(path: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Load recent files from the default user state file
+    pub fn load_default() -> Self {
+        Self::default_path().map(|path| Self::load(&path)).unwrap_or_default()
+    }
+
+    /// Write recent files to `path`, creating parent directories as needed
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        std::fs::write(path, content)
+    }
+
+    /// Record a selection, moving it to the front of the workspace's list
+    pub fn record(&mut self, workspace_root: &Path, relative_path: &str) {
+        let recent = self
+            .workspaces
+            .entry(workspace_root.to_string_lossy().to_string())
+            .or_default();
+        recent.retain(|path| path != relative_path);
+        recent.insert(0, relative_path.to_string());
+        recent.truncate(MAX_RECENT_FILES);
+    }
+
+    /// Recent files for a workspace, most recent first
+    pub fn for_workspace(&self, workspace_root: &Path) -> &[String] {
+        self.workspaces
+            .get(workspace_root.to_string_lossy().as_ref())
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+}
+
 /// Fuzzy finder state
 #[derive(Debug, Clone)]
 pub struct FuzzyFinder {
@@ -84,6 +155,8 @@ pub struct FuzzyFinder {
     sort_mode: SortMode,
     /// Whether hidden files should be shown
     show_hidden: bool,
+    /// Recently selected relative paths, most recent first
+    recent: Vec<String>,
 }
 
 impl FuzzyFinder {
@@ -102,19 +175,27 @@ impl FuzzyFinder {
             original_cursor,
             sort_mode: SortMode::default(),
             show_hidden: false,
+            recent: Vec::new(),
         }
     }
 
+    /// Set the recently selected files to surface first
+    pub fn set_recent(&mut self, recent: Vec<String>) {
+        self.recent = recent;
+        self.update_results();
+    }
+
+    /// Check if a file was recently selected
+    pub fn is_recent(&self, file: &FileEntry) -> bool {
+        self.recent.contains(&file.relative_path)
+    }
+
     /// Initialize file discovery
     pub fn discover_files(&mut self) -> std::io::Result<()> {
         self.files.clear();
 
-        let walker = WalkBuilder::new(&self.workspace_root)
+        let walker = thunderus_core::workspace_walker(&self.workspace_root)
             .hidden(!self.show_hidden)
-            .git_ignore(true)
-            .git_global(true)
-            .git_exclude(true)
-            .ignore(true)
             .follow_links(false)
             .build();
 
@@ -187,6 +268,17 @@ impl FuzzyFinder {
                 (None, None) => a.relative_path.cmp(&b.relative_path),
             }),
             SortMode::Path => self.results.sort_by(|a, b| a.relative_path.cmp(&b.relative_path)),
+            SortMode::Recent => (),
+        }
+
+        if self.sort_mode == SortMode::Recent || self.pattern.chars().count() <= SHORT_QUERY_LEN {
+            let recent = &self.recent;
+            self.results.sort_by_key(|file| {
+                recent
+                    .iter()
+                    .position(|path| *path == file.relative_path)
+                    .unwrap_or(usize::MAX)
+            });
         }
     }
 
@@ -397,6 +489,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_fuzzy_finder_honours_thunderusignore() {
+        let temp = create_test_workspace();
+        fs::write(temp.path().join(thunderus_core::IGNORE_FILE_NAME), "tests/\n").unwrap();
+
+        let mut finder = FuzzyFinder::new(temp.path().to_path_buf(), String::new(), 0);
+        finder.discover_files().unwrap();
+
+        assert!(finder.results().iter().all(|f| !f.relative_path.starts_with("tests")));
+        assert!(finder.results().iter().any(|f| f.relative_path == "config.rs"));
+    }
+
     #[test]
     fn test_fuzzy_finder_toggle_sort() {
         let temp = create_test_workspace();
@@ -410,10 +514,71 @@ mod tests {
         finder.toggle_sort();
         assert_eq!(finder.sort_mode(), SortMode::Path);
 
+        finder.toggle_sort();
+        assert_eq!(finder.sort_mode(), SortMode::Recent);
+
         finder.toggle_sort();
         assert_eq!(finder.sort_mode(), SortMode::Relevance);
     }
 
+    #[test]
+    fn test_fuzzy_finder_recent_files_float_for_short_queries() {
+        let temp = create_test_workspace();
+        let mut finder = FuzzyFinder::new(temp.path().to_path_buf(), String::new(), 0);
+        finder.discover_files().unwrap();
+        finder.set_recent(vec!["tests/test.rs".to_string(), "config.rs".to_string()]);
+
+        let paths: Vec<&str> = finder.results().iter().map(|f| f.relative_path.as_str()).collect();
+        assert_eq!(&paths[..2], &["tests/test.rs", "config.rs"]);
+        assert!(finder.is_recent(&finder.results()[0]));
+        assert!(!finder.is_recent(&finder.results()[2]));
+
+        finder.set_pattern("main".to_string());
+        assert_eq!(finder.results()[0].relative_path, "src/main.rs");
+
+        finder.set_pattern("rs".to_string());
+        assert_eq!(finder.results()[0].relative_path, "tests/test.rs");
+    }
+
+    #[test]
+    fn test_fuzzy_finder_recent_sort_mode() {
+        let temp = create_test_workspace();
+        let mut finder = FuzzyFinder::new(temp.path().to_path_buf(), String::new(), 0);
+        finder.discover_files().unwrap();
+        finder.set_recent(vec!["src/lib.rs".to_string()]);
+        finder.set_pattern("src/".to_string());
+        while finder.sort_mode() != SortMode::Recent {
+            finder.toggle_sort();
+        }
+
+        assert_eq!(finder.results()[0].relative_path, "src/lib.rs");
+    }
+
+    #[test]
+    fn test_recent_files_record_and_persist() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("state").join("recent_files.json");
+        let workspace = Path::new("/work/project");
+
+        let mut recent = RecentFiles::default();
+        recent.record(workspace, "a.rs");
+        recent.record(workspace, "b.rs");
+        recent.record(workspace, "a.rs");
+        recent.record(Path::new("/work/other"), "c.rs");
+        for i in 0..MAX_RECENT_FILES {
+            recent.record(Path::new("/work/busy"), &format!("{}.rs", i));
+        }
+        recent.record(Path::new("/work/busy"), "latest.rs");
+        recent.save(&path).unwrap();
+
+        let loaded = RecentFiles::load(&path);
+        assert_eq!(loaded.for_workspace(workspace), &["a.rs", "b.rs"]);
+        assert_eq!(loaded.for_workspace(Path::new("/work/other")), &["c.rs"]);
+        assert_eq!(loaded.for_workspace(Path::new("/work/busy")).len(), MAX_RECENT_FILES);
+        assert_eq!(loaded.for_workspace(Path::new("/work/busy"))[0], "latest.rs");
+        assert!(loaded.for_workspace(Path::new("/nowhere")).is_empty());
+    }
+
     #[test]
     fn test_fuzzy_finder_toggle_hidden() {
         let temp = create_test_workspace();
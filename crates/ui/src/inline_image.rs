@@ -0,0 +1,225 @@
+//! Inline image display for terminals with a graphics protocol
+//!
+//! Images read by the agent are drawn inside the transcript on terminals that
+//! speak the Kitty graphics protocol or iTerm2's inline image protocol (also
+//! understood by WezTerm). The transcript reserves blank rows under the tool
+//! result card and reports where they landed on screen; the escape sequences
+//! are written after ratatui has flushed the frame. Everywhere else the
+//! "[image: 800x600 PNG]" note in the tool result stands in for the picture.
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use ratatui::layout::Rect;
+use std::path::PathBuf;
+use thunderus_core::{ImageFormat, ImageInfo};
+
+/// Largest image file that will be sent to the terminal
+pub const MAX_INLINE_IMAGE_BYTES: u64 = 8 * 1024 * 1024;
+
+/// Widest an inline image is drawn, in cells
+pub const MAX_IMAGE_COLUMNS: u16 = 60;
+
+/// Tallest an inline image is drawn, in rows
+pub const MAX_IMAGE_ROWS: u16 = 16;
+
+/// Base64 payload bytes per Kitty graphics escape
+const KITTY_CHUNK_BYTES: usize = 4096;
+
+/// Graphics protocol the terminal supports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImageProtocol {
+    /// Kitty graphics protocol (PNG payloads only)
+    Kitty,
+    /// iTerm2 inline images, also supported by WezTerm
+    Iterm,
+    /// No inline images; the textual note is shown instead
+    #[default]
+    None,
+}
+
+impl ImageProtocol {
+    /// Detect the protocol from the process environment
+    pub fn detect() -> Self {
+        let var = |name: &str| std::env::var(name).ok();
+        Self::from_env(
+            var("TERM").as_deref(),
+            var("TERM_PROGRAM").as_deref(),
+            var("KITTY_WINDOW_ID").is_some(),
+            var("TMUX").is_some(),
+        )
+    }
+
+    /// Pick a protocol from the relevant environment variables
+    ///
+    /// Multiplexers swallow graphics escapes, so tmux always gets `None`.
+    pub fn from_env(term: Option<&str>, term_program: Option<&str>, kitty_window: bool, tmux: bool) -> Self {
+        if tmux || term.is_some_and(|t| t.starts_with("screen") || t.starts_with("tmux")) {
+            Self::None
+        } else if kitty_window || term.is_some_and(|t| t.contains("kitty")) {
+            Self::Kitty
+        } else if matches!(term_program, Some("iTerm.app" | "WezTerm")) {
+            Self::Iterm
+        } else {
+            Self::None
+        }
+    }
+
+    pub fn is_supported(&self) -> bool {
+        !matches!(self, Self::None)
+    }
+
+    /// Escape sequence that draws `bytes` at the cursor, scaled into `cols` x `rows` cells
+    ///
+    /// Returns `None` when the protocol can't display the format.
+    pub fn escape_sequence(&self, format: ImageFormat, bytes: &[u8], cols: u16, rows: u16) -> Option<String> {
+        let payload = STANDARD.encode(bytes);
+        match self {
+            Self::Iterm => Some(format!(
+                "\x1b]1337;File=inline=1;size={};width={};height={};preserveAspectRatio=1:{}\x07",
+                bytes.len(),
+                cols,
+                rows,
+                payload
+            )),
+            Self::Kitty if format == ImageFormat::Png => {
+                let chunks: Vec<&str> = payload
+                    .as_bytes()
+                    .chunks(KITTY_CHUNK_BYTES)
+                    .map(|chunk| std::str::from_utf8(chunk).unwrap_or_default())
+                    .collect();
+                let mut sequence = String::new();
+                for (index, chunk) in chunks.iter().enumerate() {
+                    let more = u8::from(index + 1 < chunks.len());
+                    if index == 0 {
+                        sequence.push_str(&format!("\x1b_Ga=T,f=100,q=2,C=1,c={},r={},m={};", cols, rows, more));
+                    } else {
+                        sequence.push_str(&format!("\x1b_Gm={};", more));
+                    }
+                    sequence.push_str(chunk);
+                    sequence.push_str("\x1b\\");
+                }
+                Some(sequence)
+            }
+            Self::Kitty | Self::None => None,
+        }
+    }
+
+    /// Escape sequence that removes previously drawn images, where the protocol needs one
+    pub fn clear_sequence(&self) -> Option<&'static str> {
+        match self {
+            Self::Kitty => Some("\x1b_Ga=d,q=2\x1b\\"),
+            Self::Iterm | Self::None => None,
+        }
+    }
+}
+
+/// An image file attached to a transcript entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageAttachment {
+    pub path: PathBuf,
+    pub info: ImageInfo,
+}
+
+impl ImageAttachment {
+    pub fn new(path: impl Into<PathBuf>, info: ImageInfo) -> Self {
+        Self { path: path.into(), info }
+    }
+
+    /// Cells to draw the image in, keeping its aspect ratio within `max_cols`
+    ///
+    /// Terminal cells are roughly twice as tall as they are wide.
+    pub fn cell_size(&self, max_cols: u16) -> (u16, u16) {
+        let cols = max_cols.clamp(1, MAX_IMAGE_COLUMNS);
+        let (width, height) = (self.info.width.max(1) as u64, self.info.height as u64);
+        let rows = (cols as u64 * height / width / 2).clamp(1, MAX_IMAGE_ROWS as u64) as u16;
+        let cols = ((rows as u64 * 2 * width / height.max(1)) as u16).clamp(1, cols);
+        (cols, rows)
+    }
+}
+
+/// An image and the screen cells it should be drawn into
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImagePlacement {
+    pub image: ImageAttachment,
+    pub area: Rect,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attachment(width: u32, height: u32) -> ImageAttachment {
+        ImageAttachment::new("shot.png", ImageInfo { format: ImageFormat::Png, width, height })
+    }
+
+    #[test]
+    fn test_protocol_from_env() {
+        assert_eq!(
+            ImageProtocol::from_env(Some("xterm-kitty"), None, false, false),
+            ImageProtocol::Kitty
+        );
+        assert_eq!(
+            ImageProtocol::from_env(Some("xterm-256color"), None, true, false),
+            ImageProtocol::Kitty
+        );
+        assert_eq!(
+            ImageProtocol::from_env(None, Some("iTerm.app"), false, false),
+            ImageProtocol::Iterm
+        );
+        assert_eq!(
+            ImageProtocol::from_env(None, Some("WezTerm"), false, false),
+            ImageProtocol::Iterm
+        );
+        assert_eq!(
+            ImageProtocol::from_env(Some("xterm-kitty"), None, true, true),
+            ImageProtocol::None
+        );
+        assert_eq!(
+            ImageProtocol::from_env(Some("screen-256color"), Some("iTerm.app"), false, false),
+            ImageProtocol::None
+        );
+        assert_eq!(
+            ImageProtocol::from_env(Some("xterm-256color"), Some("Apple_Terminal"), false, false),
+            ImageProtocol::None
+        );
+    }
+
+    #[test]
+    fn test_iterm_escape_sequence() {
+        let sequence = ImageProtocol::Iterm
+            .escape_sequence(ImageFormat::Jpeg, b"hello", 20, 5)
+            .unwrap();
+        assert_eq!(
+            sequence,
+            "\x1b]1337;File=inline=1;size=5;width=20;height=5;preserveAspectRatio=1:aGVsbG8=\x07"
+        );
+    }
+
+    #[test]
+    fn test_kitty_escape_sequence_chunks_png_only() {
+        let bytes = vec![0u8; KITTY_CHUNK_BYTES];
+        let sequence = ImageProtocol::Kitty
+            .escape_sequence(ImageFormat::Png, &bytes, 30, 8)
+            .unwrap();
+        assert!(sequence.starts_with("\x1b_Ga=T,f=100,q=2,C=1,c=30,r=8,m=1;"));
+        assert!(sequence.contains("\x1b\\\x1b_Gm=0;"));
+        assert_eq!(sequence.matches("\x1b_G").count(), 2);
+        assert!(sequence.ends_with("\x1b\\"));
+
+        assert_eq!(
+            ImageProtocol::Kitty.escape_sequence(ImageFormat::Gif, &bytes, 30, 8),
+            None
+        );
+        assert_eq!(
+            ImageProtocol::None.escape_sequence(ImageFormat::Png, &bytes, 30, 8),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cell_size_keeps_aspect_ratio() {
+        assert_eq!(attachment(800, 400).cell_size(40), (40, 10));
+        assert_eq!(attachment(100, 1000).cell_size(40), (3, 16));
+        assert_eq!(attachment(4000, 10).cell_size(200), (60, 1));
+    }
+}
@@ -17,6 +17,8 @@ pub enum LayoutMode {
     Compact,
     /// Inspector layout (Provenance & Trajectory)
     Inspector,
+    /// Full-screen diff review layout
+    Review,
 }
 
 impl From<u16> for LayoutMode {
@@ -49,20 +51,58 @@ pub struct TuiLayout {
     pub evidence_list: Option<Rect>,
     /// Evidence Detail area (in Inspector mode)
     pub evidence_detail: Option<Rect>,
+    /// Diff review area (in Review mode)
+    pub review: Option<Rect>,
     /// Left sidebar (only in Full mode)
     pub sidebar: Option<Rect>,
-    /// Footer area (5 lines)
+    /// Footer area (5 lines, taller while a multi-line composer grows)
     pub footer: Rect,
 }
 
+/// Footer height with a single-line composer
+pub const FOOTER_HEIGHT: u16 = 5;
+
+/// Narrowest terminal the layout is drawn in; anything smaller shows a notice instead
+pub const MIN_TERMINAL_WIDTH: u16 = 40;
+
+/// Shortest terminal the layout is drawn in; anything smaller shows a notice instead
+pub const MIN_TERMINAL_HEIGHT: u16 = 10;
+
 impl TuiLayout {
+    /// Check whether the terminal is below the minimum size for any layout
+    ///
+    /// Below this the header, transcript and footer can't all get a row, so the
+    /// caller renders [`TuiLayout::too_small_message`] until the terminal grows.
+    pub fn is_too_small(area: Rect) -> bool {
+        area.width < MIN_TERMINAL_WIDTH || area.height < MIN_TERMINAL_HEIGHT
+    }
+
+    /// Notice shown in place of the layout on a terminal below the minimum size
+    pub fn too_small_message(area: Rect) -> String {
+        format!(
+            "Terminal too small (need at least {}x{}, have {}x{})",
+            MIN_TERMINAL_WIDTH, MIN_TERMINAL_HEIGHT, area.width, area.height
+        )
+    }
+
     /// Calculate layout based on terminal size and sidebar visibility preference
     pub fn calculate(area: Rect, sidebar_visible: bool, sidebar_width_override: Option<u16>) -> Self {
+        Self::calculate_with_footer(area, sidebar_visible, sidebar_width_override, FOOTER_HEIGHT)
+    }
+
+    /// Calculate layout with an explicit footer height (for a grown composer)
+    pub fn calculate_with_footer(
+        area: Rect, sidebar_visible: bool, sidebar_width_override: Option<u16>, footer_height: u16,
+    ) -> Self {
         let mode = LayoutMode::from(area.width);
 
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Length(1), Constraint::Min(0), Constraint::Length(5)])
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Min(0),
+                Constraint::Length(footer_height),
+            ])
             .split(area);
 
         let header = chunks[0];
@@ -90,14 +130,18 @@ impl TuiLayout {
             (None, main)
         };
 
-        Self { mode, header, transcript, evidence_list: None, evidence_detail: None, sidebar, footer }
+        Self { mode, header, transcript, evidence_list: None, evidence_detail: None, review: None, sidebar, footer }
     }
 
     /// Calculate Inspector layout
     pub fn calculate_inspector(area: Rect) -> Self {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Length(1), Constraint::Min(0), Constraint::Length(5)])
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Min(0),
+                Constraint::Length(FOOTER_HEIGHT),
+            ])
             .split(area);
 
         let header = chunks[0];
@@ -115,11 +159,35 @@ impl TuiLayout {
             transcript: Rect::default(),
             evidence_list: Some(main_chunks[0]),
             evidence_detail: Some(main_chunks[1]),
+            review: None,
             sidebar: None,
             footer,
         }
     }
 
+    /// Calculate diff review layout, giving the whole main area to the diff
+    pub fn calculate_review(area: Rect) -> Self {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Min(0),
+                Constraint::Length(FOOTER_HEIGHT),
+            ])
+            .split(area);
+
+        Self {
+            mode: LayoutMode::Review,
+            header: chunks[0],
+            transcript: Rect::default(),
+            evidence_list: None,
+            evidence_detail: None,
+            review: Some(chunks[1]),
+            sidebar: None,
+            footer: chunks[2],
+        }
+    }
+
     /// Get footer input area (single line)
     pub fn footer_input(&self) -> Rect {
         let chunks = Layout::default()
@@ -321,7 +389,7 @@ impl WelcomeLayout {
     /// Calculate layout based on terminal size and layout mode
     pub fn calculate(area: Rect, mode: LayoutMode) -> Self {
         let content_width = match mode {
-            LayoutMode::Full | LayoutMode::Inspector => 80.min(area.width.saturating_sub(4)),
+            LayoutMode::Full | LayoutMode::Inspector | LayoutMode::Review => 80.min(area.width.saturating_sub(4)),
             LayoutMode::Medium => 80.min(area.width.saturating_sub(4)),
             LayoutMode::Compact => area.width.saturating_sub(4),
         };
@@ -390,6 +458,20 @@ mod tests {
         assert_eq!(LayoutMode::from(60), LayoutMode::Compact);
     }
 
+    #[test]
+    fn test_terminal_too_small() {
+        assert!(!TuiLayout::is_too_small(Rect::new(0, 0, 40, 10)));
+        assert!(!TuiLayout::is_too_small(Rect::new(0, 0, 120, 40)));
+        assert!(TuiLayout::is_too_small(Rect::new(0, 0, 39, 30)));
+        assert!(TuiLayout::is_too_small(Rect::new(0, 0, 120, 9)));
+        assert!(TuiLayout::is_too_small(Rect::new(0, 0, 0, 0)));
+
+        assert_eq!(
+            TuiLayout::too_small_message(Rect::new(0, 0, 30, 8)),
+            "Terminal too small (need at least 40x10, have 30x8)"
+        );
+    }
+
     #[test]
     fn test_layout_mode_has_sidebar() {
         assert!(LayoutMode::Full.has_sidebar());
@@ -441,6 +523,18 @@ mod tests {
         assert_eq!(layout.transcript.width, 100);
     }
 
+    #[test]
+    fn test_tui_layout_review_mode() {
+        let area = Rect::new(0, 0, 120, 40);
+        let layout = TuiLayout::calculate_review(area);
+
+        assert_eq!(layout.mode, LayoutMode::Review);
+        assert!(layout.sidebar.is_none());
+        let review = layout.review.unwrap();
+        assert_eq!(review.width, 120);
+        assert_eq!(review.height, 40 - 1 - FOOTER_HEIGHT);
+    }
+
     #[test]
     fn test_footer_sections() {
         let area = Rect::new(0, 0, 90, 30);
@@ -3,6 +3,7 @@ pub mod app;
 pub mod components;
 pub mod event_handler;
 pub mod fuzzy_finder;
+pub mod inline_image;
 pub mod layout;
 pub mod slash;
 pub mod snapshot_capture;
@@ -14,7 +15,8 @@ pub mod tui_approval;
 
 pub use app::App;
 pub use event_handler::{EventHandler, KeyAction};
-pub use fuzzy_finder::{FileEntry, FuzzyFinder, SortMode};
+pub use fuzzy_finder::{FileEntry, FuzzyFinder, RecentFiles, SortMode};
+pub use inline_image::{ImageAttachment, ImagePlacement, ImageProtocol};
 pub use snapshot_capture::{SnapshotCapture, SnapshotMode};
 pub use state::{AppState, ComposerMode, InputState};
 pub use syntax::SyntaxHighlighter;
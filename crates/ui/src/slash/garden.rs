@@ -7,9 +7,9 @@ impl App {
     pub fn handle_garden_consolidate_command(&mut self, session_id: String) {
         let memory_paths = MemoryPaths::from_thunderus_root(&self.state.config.cwd);
 
-        let events_file = if session_id == "latest" {
+        let (session_id, events_file) = if session_id == "latest" {
             match &self.session {
-                Some(session) => session.events_file(),
+                Some(session) => (session.id.to_string(), session.events_file()),
                 None => {
                     return self
                         .transcript_mut()
@@ -18,18 +18,22 @@ impl App {
             }
         } else {
             let agent_dir = thunderus_core::AgentDir::new(&self.state.config.cwd);
-            let session_dir = agent_dir.sessions_dir().join(&session_id);
-            std::path::PathBuf::from(&session_dir).join("events.jsonl")
+            let events_file = agent_dir.sessions_dir().join(&session_id).join("events.jsonl");
+            (session_id, events_file)
         };
 
         let gardener = thunderus_core::memory::Gardener::new(memory_paths);
+        let consolidation = gardener.consolidate_session(&session_id, &events_file);
         let result = match tokio::runtime::Handle::try_current() {
-            Ok(handle) => handle.block_on(gardener.consolidate_session(&session_id, &events_file)),
-            Err(_) => {
-                return self
-                    .transcript_mut()
-                    .add_system_message("No tokio runtime available for consolidation");
-            }
+            Ok(handle) => tokio::task::block_in_place(|| handle.block_on(consolidation)),
+            Err(_) => match tokio::runtime::Runtime::new() {
+                Ok(runtime) => runtime.block_on(consolidation),
+                Err(e) => {
+                    return self
+                        .transcript_mut()
+                        .add_system_message(format!("No tokio runtime available for consolidation: {}", e));
+                }
+            },
         };
 
         match result {
@@ -53,13 +57,8 @@ impl App {
                     msg.push_str(&format!("  • [{}] {}\n", patch.kind, patch.description));
                 }
 
-                if !memory_patches.is_empty() {
-                    msg.push_str(&format!(
-                        "\n[M] Added {} memory patch(es) to review queue\n",
-                        patch_count
-                    ));
-                    self.state_mut().memory_patches_mut().extend(memory_patches);
-                }
+                msg.push_str(&format!("\n[M] Queued {} memory patch(es) for review\n", patch_count));
+                self.state_mut().memory_patches_mut().extend(memory_patches);
 
                 if let Some(ref path) = recap_path {
                     msg.push_str(&format!("\nRecap: {}\n", path));
@@ -168,6 +167,54 @@ impl App {
         }
     }
 
+    /// Handle /garden verify --all command
+    pub fn handle_garden_verify_all_command(&mut self) {
+        let memory_paths = MemoryPaths::from_thunderus_root(&self.state.config.cwd);
+        let gardener = thunderus_core::memory::Gardener::new(memory_paths);
+
+        match gardener.verify_all_stale() {
+            Ok(result) => {
+                if result.verified.is_empty() && result.needs_review.is_empty() {
+                    return self
+                        .transcript_mut()
+                        .add_system_message("Verification: No stale documents");
+                }
+
+                let mut msg = format!(
+                    "✓ Verified {} document(s) at {}\n",
+                    result.verified.len(),
+                    result.current_commit
+                );
+                for doc_id in &result.verified {
+                    msg.push_str(&format!("  • {}\n", doc_id));
+                }
+
+                if !result.needs_review.is_empty() {
+                    msg.push_str(&format!(
+                        "\n[!] {} document(s) need review (referenced files changed):\n",
+                        result.needs_review.len()
+                    ));
+                    for doc in &result.needs_review {
+                        let files: Vec<String> = doc.changed_files.iter().map(|f| f.display().to_string()).collect();
+                        msg.push_str(&format!(
+                            "  • {} ({:?}): {}\n",
+                            doc.doc_id,
+                            doc.severity,
+                            files.join(", ")
+                        ));
+                    }
+                    msg.push_str("\nReview them, then run /garden verify <doc-id>\n");
+                }
+
+                self.transcript_mut().add_system_message(msg);
+            }
+            Err(e) => {
+                self.transcript_mut()
+                    .add_system_message(format!("Verification failed: {}", e));
+            }
+        }
+    }
+
     /// Handle /garden stats command
     pub fn handle_garden_stats_command(&mut self) {
         let memory_paths = MemoryPaths::from_thunderus_root(&self.state.config.cwd);
@@ -1,28 +1,64 @@
 use crate::app::App;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
-use thunderus_core::{MemoryPaths, ViewKind, ViewMaterializer};
+use thunderus_core::memory::MAX_PINNED_DOCS;
+use thunderus_core::{CORE_MEMORY_HARD_LIMIT, CORE_MEMORY_SOFT_LIMIT, CoreMemory, MemoryPaths, ViewKind};
 
 impl App {
+    /// Report that memory is off for this profile, returning `true` if so
+    fn memory_disabled_notice(&mut self) -> bool {
+        if self.state.config.memory_enabled {
+            return false;
+        }
+        self.transcript_mut()
+            .add_system_message("Memory is disabled for this profile (memory.enabled = false)");
+        true
+    }
+
     /// Handle /memory command
     pub fn handle_memory_command(&mut self) {
-        match self.session {
-            Some(ref session) => match ViewMaterializer::new(session).materialize(ViewKind::Memory) {
-                Ok(content) => self
-                    .transcript_mut()
-                    .add_system_message(format!("## Project Memory\n\n{}", content)),
-                Err(e) => self
-                    .transcript_mut()
-                    .add_system_message(format!("Failed to materialize memory: {}", e)),
-            },
-            None => self
-                .transcript_mut()
-                .add_system_message("No active session to materialize memory from"),
+        if self.memory_disabled_notice() {
+            return;
         }
+        let usage = self.core_memory_usage();
+        let message = match self.view_content(ViewKind::Memory) {
+            Some(Ok(content)) => format!("## Project Memory\n\n{}\n\n{}", usage, content),
+            Some(Err(e)) => format!("{}\n\nFailed to materialize memory: {}", usage, e),
+            None => format!("{}\n\nNo active session to materialize memory from", usage),
+        };
+        self.transcript_mut().add_system_message(message);
+    }
+
+    /// Summarize core memory size against its soft and hard token limits
+    fn core_memory_usage(&self) -> String {
+        let cwd = &self.state.config.cwd;
+        let core = match CoreMemory::load(&MemoryPaths::from_thunderus_root(cwd), cwd) {
+            Ok(core) => core,
+            Err(e) => return format!("Core memory: failed to load ({})", e),
+        };
+
+        let status = if core.is_over_hard_limit() {
+            " ✗ over hard limit"
+        } else if core.is_over_soft_limit() {
+            " ⚠ over soft limit"
+        } else {
+            ""
+        };
+        format!(
+            "Core memory: {} tokens (soft limit {}, hard limit {}, {:.0}% of soft){}",
+            core.token_count,
+            CORE_MEMORY_SOFT_LIMIT,
+            CORE_MEMORY_HARD_LIMIT,
+            core.soft_limit_ratio() * 100.0,
+            status
+        )
     }
 
     /// Handle /memory add <fact> command
     pub fn handle_memory_add_command(&mut self, fact: String) {
+        if self.memory_disabled_notice() {
+            return;
+        }
         if let Some(ref mut session) = self.session {
             let mut hasher = DefaultHasher::new();
             fact.hash(&mut hasher);
@@ -48,6 +84,9 @@ impl App {
     ///
     /// Searches the memory store and displays results in the memory hits panel.
     pub fn handle_memory_search_command(&mut self, query: String) {
+        if self.memory_disabled_notice() {
+            return;
+        }
         let memory_paths = MemoryPaths::from_thunderus_root(&self.state.config.cwd);
         let db_path = memory_paths.indexes.join("memory.db");
 
@@ -67,7 +106,7 @@ impl App {
             }
         };
 
-        let hits = match tokio::runtime::Handle::try_current() {
+        let mut hits = match tokio::runtime::Handle::try_current() {
             Ok(handle) => match handle.block_on(store.search(&query, thunderus_store::SearchFilters::default())) {
                 Ok(hits) => hits,
                 Err(e) => {
@@ -83,6 +122,11 @@ impl App {
             }
         };
 
+        let head = memory_paths.head_commit();
+        for hit in &mut hits {
+            hit.refresh_verification(head.as_deref());
+        }
+
         if hits.is_empty() {
             self.transcript_mut()
                 .add_system_message(format!("No memory results found for '{}'", query));
@@ -100,28 +144,47 @@ impl App {
 
     /// Handle /memory pin <id> command
     ///
-    /// Pins a memory document to the current context set.
+    /// Pins a memory document so it is always included in retrieved context.
     pub fn handle_memory_pin_command(&mut self, id: String) {
+        if self.memory_disabled_notice() {
+            return;
+        }
         if self.state().memory_hits.is_pinned(&id) {
             self.state_mut().memory_hits.unpin(&id);
             self.transcript_mut()
                 .add_system_message(format!("Unpinned memory: {}", id));
-        } else {
-            self.state_mut().memory_hits.pin(id.clone());
+        } else if self.state_mut().memory_hits.pin(id.clone()) {
             self.transcript_mut()
                 .add_system_message(format!("Pinned memory: {}", id));
+        } else {
+            return self.transcript_mut().add_system_message(format!(
+                "Cannot pin {}: at most {} memory documents can be pinned",
+                id, MAX_PINNED_DOCS
+            ));
         }
 
+        self.persist_memory_pins();
+
         let pinned_count = self.state().memory_hits.pinned_count();
         if pinned_count > 0 {
             self.transcript_mut()
                 .add_system_message(format!("Total pinned: {}", pinned_count));
         }
     }
+
+    /// Persist the pin list under `.thunderus/memory` so retrieval picks it up
+    pub(crate) fn persist_memory_pins(&mut self) {
+        let memory_paths = MemoryPaths::from_thunderus_root(&self.state.config.cwd);
+        if let Err(e) = self.state().memory_hits.save_pins(&memory_paths) {
+            self.transcript_mut()
+                .add_system_message(format!("Failed to save pinned memory: {}", e));
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::MAX_PINNED_DOCS;
     use crate::app::create_test_app;
     use crate::transcript;
 
@@ -138,4 +201,60 @@ mod tests {
             panic!("Expected SystemMessage");
         }
     }
+
+    #[test]
+    fn test_memory_commands_when_disabled() {
+        let mut app = create_test_app();
+        app.state.config.memory_enabled = false;
+
+        app.handle_memory_command();
+        app.handle_memory_search_command("build".to_string());
+        app.handle_memory_pin_command("fact.build".to_string());
+
+        assert_eq!(app.transcript().len(), 3);
+        if let transcript::TranscriptEntry::SystemMessage { content } = app.transcript().last().unwrap() {
+            assert!(content.contains("Memory is disabled"));
+        } else {
+            panic!("Expected SystemMessage");
+        }
+        assert!(!app.state().memory_hits.is_pinned("fact.build"));
+    }
+
+    #[test]
+    fn test_memory_pin_reports_limit() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut app = create_test_app();
+        app.state.config.cwd = temp.path().to_path_buf();
+        for i in 0..MAX_PINNED_DOCS {
+            assert!(app.state_mut().memory_hits.pin(format!("fact.{}", i)));
+        }
+
+        app.handle_memory_pin_command("fact.overflow".to_string());
+
+        assert!(!app.state().memory_hits.is_pinned("fact.overflow"));
+        if let transcript::TranscriptEntry::SystemMessage { content } = app.transcript().last().unwrap() {
+            assert!(content.contains("Cannot pin fact.overflow"));
+        } else {
+            panic!("Expected SystemMessage");
+        }
+    }
+
+    #[test]
+    fn test_handle_memory_command_shows_core_usage() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let paths = thunderus_core::MemoryPaths::from_thunderus_root(temp.path());
+        std::fs::create_dir_all(&paths.core).unwrap();
+        std::fs::write(paths.core_local_memory_file(), "a".repeat(20000)).unwrap();
+
+        let mut app = create_test_app();
+        app.state.config.cwd = temp.path().to_path_buf();
+        app.handle_memory_command();
+
+        if let transcript::TranscriptEntry::SystemMessage { content } = app.transcript().last().unwrap() {
+            assert!(content.contains("soft limit 4000, hard limit 8000"));
+            assert!(content.contains("over soft limit"));
+        } else {
+            panic!("Expected SystemMessage");
+        }
+    }
 }
@@ -7,16 +7,32 @@ pub use parser::parse_slash_command;
 use crate::app::App;
 use crate::state::VerbosityLevel;
 
-use thunderus_core::{ApprovalMode, ProviderConfig, SearchScope, ViewKind, ViewMaterializer};
+use thunderus_core::{
+    ApprovalMode, Config, MaterializedViews, ProviderConfig, SearchScope, ViewKind, ViewMaterializer,
+};
 use thunderus_providers::ProviderFactory;
 
 impl App {
-    /// Auto-materialize and save all views after session updates
-    pub(super) fn materialize_views(&mut self) {
-        if let Some(ref session) = self.session {
-            let materializer = ViewMaterializer::new(session);
-            let _ = materializer.materialize_all();
-        }
+    /// Auto-materialize views after session updates
+    ///
+    /// The first call builds every view; later calls only re-render views
+    /// affected by events appended since.
+    ///
+    /// Returns `None` without an active session.
+    pub(super) fn materialize_views(&mut self) -> Option<thunderus_core::Result<&MaterializedViews>> {
+        let session = self.session.as_ref()?;
+        let materializer = ViewMaterializer::new(session);
+        let views = match self.materialized_views.take() {
+            Some(previous) => materializer.materialize_incremental(&previous).map(|(views, _)| views),
+            None => materializer.materialize_all(),
+        };
+        Some(views.map(|views| &*self.materialized_views.insert(views)))
+    }
+
+    /// Current content of one view, brought up to date with the session
+    pub(super) fn view_content(&mut self, view: ViewKind) -> Option<thunderus_core::Result<String>> {
+        self.materialize_views()
+            .map(|views| views.map(|views| views.get(view).to_string()))
     }
 
     /// Handle /model command
@@ -31,42 +47,30 @@ impl App {
                 ))
             }
             _ => {
-                let new_provider = match &self.state.config.provider {
-                    ProviderConfig::Glm { api_key, base_url, thinking, .. } => {
-                        if !model.starts_with("glm") {
-                            self.transcript_mut().add_system_message(
-                                "Cannot switch to a Gemini model while using a GLM provider. Update your profile to change providers.",
-                            );
-                            return;
-                        }
-                        ProviderConfig::Glm {
-                            api_key: api_key.clone(),
-                            model: model.clone(),
-                            base_url: base_url.clone(),
-                            thinking: thinking.clone(),
-                            options: Default::default(),
+                let current_provider = self.state.provider_name();
+                let new_provider = match provider_with_model(&self.state.config.provider, &model) {
+                    Some(provider) => provider,
+                    None => match self.configured_provider_for_model(&model) {
+                        Some((profile_name, provider)) => {
+                            let target_provider = model_provider_name(&model).unwrap_or("unknown");
+                            self.transcript_mut().add_system_message(format!(
+                                "Switching provider {} → {} using credentials from profile '{}'",
+                                current_provider, target_provider, profile_name
+                            ));
+                            provider
                         }
-                    }
-                    ProviderConfig::Gemini { api_key, base_url, thinking, .. } => {
-                        if !model.starts_with("gemini") {
-                            self.transcript_mut().add_system_message(
-                                "Cannot switch to a GLM model while using a Gemini provider. Update your profile to change providers.",
-                            );
+                        None => {
+                            let message = match model_provider_name(&model) {
+                                Some(target_provider) => format!(
+                                    "Cannot switch to a {} model while using a {} provider: no configured profile uses a {} provider. Add one to your config to change providers.",
+                                    target_provider, current_provider, target_provider
+                                ),
+                                None => format!("Unknown model: {}. Use /model list to see available models.", model),
+                            };
+                            self.transcript_mut().add_system_message(message);
                             return;
                         }
-                        ProviderConfig::Gemini {
-                            api_key: api_key.clone(),
-                            model: model.clone(),
-                            base_url: base_url.clone(),
-                            thinking: thinking.clone(),
-                            options: Default::default(),
-                        }
-                    }
-                    ProviderConfig::Mock { .. } => {
-                        self.transcript_mut()
-                            .add_system_message("Cannot switch models while using Mock provider.");
-                        return;
-                    }
+                    },
                 };
 
                 match ProviderFactory::create_from_config(&new_provider) {
@@ -85,38 +89,160 @@ impl App {
         }
     }
 
-    /// Handle /verbosity command
-    pub fn handle_verbosity_command(&mut self, level: String) {
-        match level.as_str() {
-            "list" => {
-                let current_level = self.state.verbosity();
-                self.transcript_mut().add_system_message(format!(
-                    "Available verbosity levels:\n  Current: {}\n  Available: quiet, default, verbose",
-                    current_level.as_str()
-                ))
-            }
-            "quiet" => {
-                let old_level = self.state.verbosity();
-                self.state.config.verbosity = VerbosityLevel::Quiet;
+    /// Find a provider config from another configured profile that can serve `model`
+    ///
+    /// The config is re-read from disk so credentials are resolved from the
+    /// environment. Profiles are searched in name order, returning the first
+    /// match along with the name of the profile it came from.
+    fn configured_provider_for_model(&self, model: &str) -> Option<(String, ProviderConfig)> {
+        let config_path = self.state.config.config_path.as_ref()?;
+        let config = Config::from_file(config_path).ok()?;
+
+        let mut profile_names = config.profile_names();
+        profile_names.sort();
+        profile_names.into_iter().find_map(|name| {
+            let profile = config.profile(&name).ok()?;
+            provider_with_model(&profile.provider, model).map(|provider| (name, provider))
+        })
+    }
+
+    /// Handle /profile command
+    ///
+    /// Switches the provider, approval gate, and tool configuration to another
+    /// configured profile while keeping the current session and transcript.
+    /// Without a name, lists the configured profiles.
+    pub fn handle_profile_command(&mut self, name: Option<String>) {
+        let Some(config_path) = self.state.config.config_path.clone() else {
+            self.transcript_mut()
+                .add_system_message("No config file loaded; cannot switch profiles.");
+            return;
+        };
+
+        let config = match Config::from_file(&config_path) {
+            Ok(config) => config,
+            Err(e) => {
                 self.transcript_mut()
-                    .add_system_message(format!("Verbosity changed: {} → quiet", old_level.as_str()));
+                    .add_system_message(format!("Failed to load config: {}", e));
+                return;
             }
-            "default" => {
-                let old_level = self.state.verbosity();
-                self.state.config.verbosity = VerbosityLevel::Default;
-                self.transcript_mut()
-                    .add_system_message(format!("Verbosity changed: {} → default", old_level.as_str()));
+        };
+
+        let mut profile_names = config.profile_names();
+        profile_names.sort();
+
+        let Some(name) = name else {
+            let current = self.state.config.profile.clone();
+            let listing = profile_names
+                .iter()
+                .map(|profile_name| {
+                    let marker = if *profile_name == current { "*" } else { " " };
+                    format!("  {} {}", marker, profile_name)
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            self.transcript_mut()
+                .add_system_message(format!("Available profiles:\n{}", listing));
+            return;
+        };
+
+        let profile = match config.profile(&name) {
+            Ok(profile) => profile.clone(),
+            Err(_) => {
+                self.transcript_mut().add_system_message(format!(
+                    "Unknown profile: {}. Available profiles: {}",
+                    name,
+                    profile_names.join(", ")
+                ));
+                return;
             }
-            "verbose" => {
-                let old_level = self.state.verbosity();
-                self.state.config.verbosity = VerbosityLevel::Verbose;
+        };
+
+        if self.state.is_generating() {
+            self.transcript_mut()
+                .add_system_message("Cannot switch profiles while the agent is running.");
+            return;
+        }
+
+        let provider = match ProviderFactory::create_from_config(&profile.provider) {
+            Ok(provider) => provider,
+            Err(e) => {
                 self.transcript_mut()
-                    .add_system_message(format!("Verbosity changed: {} → verbose", old_level.as_str()));
+                    .add_system_message(format!("Failed to switch profile: {}", e));
+                return;
             }
-            _ => self.transcript_mut().add_system_message(format!(
+        };
+
+        let old_profile = std::mem::replace(&mut self.state.config.profile, name.clone());
+        self.state.config.provider = profile.provider.clone();
+        self.state.config.approval_mode = profile.approval_mode;
+        self.state.config.sandbox_mode = profile.sandbox_mode;
+        self.state.config.allow_network = profile.allow_network;
+        self.state.model_selector.current_model = self.state.model_name();
+
+        self.set_provider(provider);
+        self.update_approval_gate(profile.approval_mode);
+        self.update_network_access(profile.allow_network);
+        self.set_profile(profile);
+
+        let provider_name = self.state.provider_name();
+        let model_name = self.state.model_name();
+        let approval_mode = self.state.config.approval_mode;
+        self.transcript_mut().add_system_message(format!(
+            "Profile switched: {} → {}\n  Provider: {} ({})\n  Approval Mode: {}",
+            old_profile, name, provider_name, model_name, approval_mode
+        ));
+    }
+
+    /// Handle /verbosity command
+    ///
+    /// With `persist`, the new level is also saved to the active profile.
+    pub fn handle_verbosity_command(&mut self, level: String, persist: bool) {
+        if level == "list" {
+            let current_level = self.state.verbosity();
+            self.transcript_mut().add_system_message(format!(
+                "Available verbosity levels:\n  Current: {}\n  Available: quiet, default, verbose",
+                current_level.as_str()
+            ));
+            return;
+        }
+
+        let Some(new_level) = VerbosityLevel::parse_str(&level) else {
+            self.transcript_mut().add_system_message(format!(
                 "Unknown verbosity level: {}. Use /verbosity list to see available levels.",
                 level
-            )),
+            ));
+            return;
+        };
+
+        let old_level = self.state.verbosity();
+        self.state.config.verbosity = new_level;
+        self.transcript_mut().add_system_message(format!(
+            "Verbosity changed: {} → {}",
+            old_level.as_str(),
+            new_level.as_str()
+        ));
+
+        if persist {
+            self.persist_setting("verbosity", "options.verbosity", new_level.as_str().into());
+        }
+    }
+
+    /// Save a runtime setting to the active profile, reporting the outcome
+    ///
+    /// Without a config file the change stays local to this session.
+    fn persist_setting(&mut self, setting: &str, key: &str, value: toml::Value) {
+        if self.state.config.config_path.is_none() {
+            self.transcript_mut().add_system_message(format!(
+                "No config file loaded; {} change applies to this session only.",
+                setting
+            ));
+            return;
+        }
+
+        if self.persist_profile_update(setting, &[(key, value)]) {
+            let profile = self.state.config.profile.clone();
+            self.transcript_mut()
+                .add_system_message(format!("Saved {} to profile '{}'", setting, profile));
         }
     }
 
@@ -158,28 +284,87 @@ impl App {
         self.transcript_mut().add_system_message(status);
     }
 
+    /// Handle /stats command
+    ///
+    /// Summarizes approval decisions from the live approval gate alongside the
+    /// tool, token and duration counters kept in session tracking state.
+    pub fn handle_stats_command(&mut self) {
+        let approvals = self.approval_stats();
+        let stats = self.state.stats().clone();
+        let duration = format_duration(self.state.session.started_at.elapsed().as_secs());
+
+        let mut report = String::from("Session Stats:\n");
+        report.push_str(&format!("Duration: {}\n", duration));
+
+        report.push_str("Approvals:\n");
+        match approvals {
+            Some(approvals) => report.push_str(&format!(
+                "  Approved: {}\n  Auto-approved: {}\n  Rejected: {}\n  Cancelled: {}\n  Pending: {}\n",
+                approvals.approved, approvals.auto_approved, approvals.rejected, approvals.cancelled, approvals.pending
+            )),
+            None => report.push_str("  No agent turns yet\n"),
+        }
+        report.push_str(&format!("  Prompts shown: {}\n", stats.approval_gates));
+
+        report.push_str(&format!("Tool Calls: {}\n", stats.tools_executed));
+        for (name, count) in &stats.tool_calls {
+            report.push_str(&format!("  {}: {}\n", name, count));
+        }
+
+        report.push_str(&format!(
+            "Tokens: {} (input {}, output {})",
+            stats.total_tokens(),
+            stats.input_tokens,
+            stats.output_tokens
+        ));
+
+        self.transcript_mut().add_system_message(report);
+    }
+
+    /// Handle /continue command
+    ///
+    /// Asks the model to finish a response cut off by `max_tokens`, reusing the
+    /// truncated turn's conversation and streaming into its transcript entry.
+    pub fn handle_continue_command(&mut self) {
+        if !self.state.session.response_truncated {
+            self.transcript_mut()
+                .add_system_message("Nothing to continue: the last response was not truncated.");
+            return;
+        }
+        let Some(provider) = self.provider.clone() else {
+            self.transcript_mut()
+                .add_system_message("No provider configured. Cannot process message.");
+            return;
+        };
+
+        self.state_mut().session.response_truncated = false;
+        self.transcript_mut().reopen_last_response();
+        self.persist_user_message(CONTINUE_PROMPT);
+        self.spawn_agent_continuing(CONTINUE_PROMPT.to_string(), &provider);
+    }
+
     /// Handle /review command
+    ///
+    /// Opens the full-screen diff review over the queued patches.
     pub fn handle_review_command(&mut self) {
-        let patches = self.state.patches();
-        if patches.is_empty() {
+        if self.state.patches().is_empty() && self.state.memory_patches().is_empty() {
             self.transcript_mut()
                 .add_system_message("No pending patches to review.");
-        } else {
-            let mut review_text = String::from("## Pending Patches for Review\n\n");
-            for (idx, patch) in patches.iter().enumerate() {
-                review_text.push_str(&format!("### Patch {} - {}\n\n", idx + 1, patch.name));
-                let files_str: Vec<String> = patch.files.iter().map(|p| p.display().to_string()).collect();
-                review_text.push_str(&format!("Files: {}\n\n", files_str.join(", ")));
-                review_text.push_str("```diff\n");
-                review_text.push_str(&patch.diff);
-                review_text.push_str("\n```\n\n");
-            }
-            self.transcript_mut().add_system_message(review_text);
+            return;
         }
+        self.state.enter_diff_review();
     }
 
     /// Handle /search <query> command
-    pub fn handle_search_command(&mut self, query: String, scope: SearchScope) {
+    ///
+    /// With `all_sessions`, every session under the agent directory is searched
+    /// and results are grouped by session.
+    pub fn handle_search_command(&mut self, query: String, scope: SearchScope, all_sessions: bool) {
+        if all_sessions {
+            self.search_all_sessions(&query, scope);
+            return;
+        }
+
         match self.session {
             Some(ref session) => match thunderus_core::search_session(&session.session_dir(), &query, scope) {
                 Ok(hits) => {
@@ -187,13 +372,8 @@ impl App {
                         self.transcript_mut()
                             .add_system_message(format!("No results found for '{}'", query));
                     } else {
-                        let scope_str = match scope {
-                            SearchScope::All => "all files",
-                            SearchScope::Events => "events",
-                            SearchScope::Views => "views",
-                        };
-
-                        let mut results_text = format!("## Search Results for '{}' in {}\n\n", query, scope_str);
+                        let mut results_text =
+                            format!("## Search Results for '{}' in {}\n\n", query, search_scope_label(scope));
                         results_text.push_str(&format!("Found {} match(es):\n\n", hits.len()));
 
                         for hit in hits.iter().take(20) {
@@ -216,55 +396,146 @@ impl App {
         }
     }
 
-    /// Handle /approvals command
-    pub fn handle_approvals_command(&mut self, mode: String) {
-        match mode.as_str() {
-            "list" => {
-                let current_mode = self.state.config.approval_mode;
-                self.transcript_mut().add_system_message(format!(
-                    "Available approval modes:\n  Current: {}\n  Available: read-only, auto, full-access",
-                    current_mode
-                ))
-            }
-            "read-only" => {
-                let old_mode = self.state.config.approval_mode;
-                self.state.config.approval_mode = ApprovalMode::ReadOnly;
-                self.update_approval_gate(ApprovalMode::ReadOnly);
+    /// Search every session, showing at most [`SEARCH_HITS_PER_SESSION`] hits per session
+    fn search_all_sessions(&mut self, query: &str, scope: SearchScope) {
+        let agent_dir = match self.session {
+            Some(ref session) => session.agent_dir(),
+            None => thunderus_core::AgentDir::new(&self.state.config.cwd),
+        };
+
+        let results = match thunderus_core::search_all_sessions(&agent_dir, query, scope) {
+            Ok(results) => results,
+            Err(e) => {
                 self.transcript_mut()
-                    .add_system_message(format!("Approval mode changed: {} → read-only", old_mode));
+                    .add_system_message(format!("Search failed: {}", e));
+                return;
             }
-            "auto" => {
-                let old_mode = self.state.config.approval_mode;
-                self.state.config.approval_mode = ApprovalMode::Auto;
-                self.update_approval_gate(ApprovalMode::Auto);
-                self.transcript_mut()
-                    .add_system_message(format!("Approval mode changed: {} → auto", old_mode));
+        };
+
+        if results.is_empty() {
+            self.transcript_mut()
+                .add_system_message(format!("No results found for '{}' in any session", query));
+            return;
+        }
+
+        let total: usize = results.iter().map(|session| session.hits.len()).sum();
+        let mut results_text = format!(
+            "## Search Results for '{}' in {} across sessions\n\n",
+            query,
+            search_scope_label(scope)
+        );
+        results_text.push_str(&format!(
+            "Found {} match(es) in {} session(s):\n\n",
+            total,
+            results.len()
+        ));
+
+        for session in &results {
+            results_text.push_str(&format!("### {}\n\n", session.session_id.as_str()));
+            for hit in session.hits.iter().take(SEARCH_HITS_PER_SESSION) {
+                results_text.push_str(&format!("**{}:{}**\n", hit.file, hit.line));
+                results_text.push_str(&format!("```\n{}\n```\n\n", hit.content));
             }
-            "full-access" => {
-                let old_mode = self.state.config.approval_mode;
-                self.state.config.approval_mode = ApprovalMode::FullAccess;
-                self.update_approval_gate(ApprovalMode::FullAccess);
-                self.transcript_mut()
-                    .add_system_message(format!("Approval mode changed: {} → full-access", old_mode));
+            if session.hits.len() > SEARCH_HITS_PER_SESSION {
+                results_text.push_str(&format!(
+                    "... and {} more in this session\n\n",
+                    session.hits.len() - SEARCH_HITS_PER_SESSION
+                ));
             }
-            _ => self.transcript_mut().add_system_message(format!(
+        }
+
+        self.transcript_mut().add_system_message(results_text);
+    }
+
+    /// Handle /approvals command
+    ///
+    /// With `persist`, the new mode is also saved to the active profile.
+    pub fn handle_approvals_command(&mut self, mode: String, persist: bool) {
+        if mode == "rules" {
+            let rules = self.session_approval_rules();
+            let message = if rules.is_empty() {
+                "No approvals remembered this session. Press 'a' on an approval prompt to remember one.".to_string()
+            } else {
+                let lines: Vec<String> = rules.iter().map(|rule| format!("  {}", rule)).collect();
+                format!("Remembered approvals (this session only):\n{}", lines.join("\n"))
+            };
+            self.transcript_mut().add_system_message(message);
+            return;
+        }
+
+        if mode == "clear" {
+            let cleared = self.clear_session_approval_rules();
+            self.transcript_mut()
+                .add_system_message(format!("Cleared {} remembered approval(s).", cleared));
+            return;
+        }
+
+        if mode == "list" {
+            let current_mode = self.state.config.approval_mode;
+            self.transcript_mut().add_system_message(format!(
+                "Available approval modes:\n  Current: {}\n  Available: read-only, auto, full-access\n  Session rules: /approvals rules, /approvals clear",
+                current_mode
+            ));
+            return;
+        }
+
+        let Ok(new_mode) = mode.parse::<ApprovalMode>() else {
+            self.transcript_mut().add_system_message(format!(
                 "Unknown approval mode: {}. Use /approvals list to see available modes.",
                 mode
-            )),
+            ));
+            return;
+        };
+
+        let old_mode = self.state.config.approval_mode;
+        self.state.config.approval_mode = new_mode;
+        self.update_approval_gate(new_mode);
+        self.transcript_mut()
+            .add_system_message(format!("Approval mode changed: {} → {}", old_mode, new_mode));
+
+        if persist {
+            self.persist_setting("approval mode", "approval_mode", new_mode.as_str().into());
         }
     }
 
+    /// Handle /task command
+    ///
+    /// Without a title, shows the current task. With one, pins it as the
+    /// active task, relabels the header and logs the change to the session.
+    pub fn handle_task_command(&mut self, title: String) {
+        let title = title.trim().to_string();
+        if title.is_empty() {
+            let message = match self.task_context.title() {
+                Some(task) => format!("Current task: {}", task),
+                None => "No active task. Use /task <title> to set one.".to_string(),
+            };
+            self.transcript_mut().add_system_message(message);
+            return;
+        }
+
+        self.task_context.pin(title.clone());
+        self.state.session_header.set_task_title(&title);
+
+        if let Some(ref mut session) = self.session
+            && let Err(e) = session.append_task_update(&title)
+        {
+            let warning = format!("Warning: Failed to persist task update: {}", e);
+            eprintln!("{}", warning);
+            self.transcript_mut().add_system_message(warning);
+        }
+
+        self.transcript_mut().add_system_message(format!("Task set: {}", title));
+    }
+
     /// Handle /plan command
     pub fn handle_plan_command(&mut self) {
-        match self.session {
-            Some(ref session) => match ViewMaterializer::new(session).materialize(ViewKind::Plan) {
-                Ok(content) => self
-                    .transcript_mut()
-                    .add_system_message(format!("## Current Plan\n\n{}", content)),
-                Err(e) => self
-                    .transcript_mut()
-                    .add_system_message(format!("Failed to materialize plan: {}", e)),
-            },
+        match self.view_content(ViewKind::Plan) {
+            Some(Ok(content)) => self
+                .transcript_mut()
+                .add_system_message(format!("## Current Plan\n\n{}", content)),
+            Some(Err(e)) => self
+                .transcript_mut()
+                .add_system_message(format!("Failed to materialize plan: {}", e)),
             None => self
                 .transcript_mut()
                 .add_system_message("No active session to materialize plan from"),
@@ -292,55 +563,191 @@ impl App {
 
     /// Handle /plan done <n> command
     pub fn handle_plan_done_command(&mut self, index: usize) {
-        match self.session {
-            Some(ref mut session) => match ViewMaterializer::new(session).materialize(ViewKind::Plan) {
-                Ok(content) => {
-                    let lines: Vec<&str> = content.lines().collect();
-                    let mut task_count = 0;
-                    let mut target_item = None;
-
-                    for line in lines {
-                        if line.trim().starts_with("- [ ]") {
-                            task_count += 1;
-                            if task_count == index {
-                                target_item = Some(line.trim().trim_start_matches("- [ ]").trim().to_string());
-                                break;
-                            }
-                        }
-                    }
+        let Some((_, item)) = self.resolve_plan_task(index) else {
+            return;
+        };
+        if let Some(ref mut session) = self.session {
+            match session.append_plan_update("complete", &item, None) {
+                Ok(_) => {
+                    self.transcript_mut()
+                        .add_system_message(format!("Marked as done: {}", item));
+                    self.materialize_views();
+                }
+                Err(e) => self
+                    .transcript_mut()
+                    .add_system_message(format!("Failed to mark item as done: {}", e)),
+            }
+        }
+    }
 
-                    match target_item {
-                        Some(item) => match session.append_plan_update("complete", &item, None) {
-                            Ok(_) => {
-                                self.transcript_mut()
-                                    .add_system_message(format!("Marked as done: {}", item));
-                                self.materialize_views();
-                            }
-                            Err(e) => self
-                                .transcript_mut()
-                                .add_system_message(format!("Failed to mark item as done: {}", e)),
-                        },
-                        None => self
-                            .transcript_mut()
-                            .add_system_message(format!("No task found at index {}", index)),
-                    }
+    /// Handle /plan remove <n> command
+    pub fn handle_plan_remove_command(&mut self, index: usize) {
+        let Some((_, item)) = self.resolve_plan_task(index) else {
+            return;
+        };
+        if let Some(ref mut session) = self.session {
+            match session.append_plan_update("remove", &item, None) {
+                Ok(_) => {
+                    self.transcript_mut()
+                        .add_system_message(format!("Removed from plan: {}", item));
+                    self.materialize_views();
                 }
                 Err(e) => self
                     .transcript_mut()
-                    .add_system_message(format!("Failed to read plan: {}", e)),
-            },
-            None => self
-                .transcript_mut()
-                .add_system_message("No active session to update plan in"),
+                    .add_system_message(format!("Failed to remove plan item: {}", e)),
+            }
+        }
+    }
+
+    /// Handle /plan move <from> <to> command
+    pub fn handle_plan_move_command(&mut self, from: usize, to: usize) {
+        let Some((_, item)) = self.resolve_plan_task(from) else {
+            return;
+        };
+        let Some((position, _)) = self.resolve_plan_task(to) else {
+            return;
+        };
+        if let Some(ref mut session) = self.session {
+            match session.append_plan_move(&item, position) {
+                Ok(_) => {
+                    self.transcript_mut()
+                        .add_system_message(format!("Moved to position {}: {}", to, item));
+                    self.materialize_views();
+                }
+                Err(e) => self
+                    .transcript_mut()
+                    .add_system_message(format!("Failed to move plan item: {}", e)),
+            }
+        }
+    }
+
+    /// Resolve the `index`-th open task (1-based) in the current plan
+    ///
+    /// Returns the task's position among all plan tasks and its text, or reports
+    /// why it could not be resolved in the transcript.
+    fn resolve_plan_task(&mut self, index: usize) -> Option<(usize, String)> {
+        let Some(plan) = self.view_content(ViewKind::Plan) else {
+            self.transcript_mut()
+                .add_system_message("No active session to update plan in");
+            return None;
+        };
+
+        match plan {
+            Ok(content) => {
+                let task = find_open_plan_task(&content, index);
+                if task.is_none() {
+                    self.transcript_mut()
+                        .add_system_message(format!("No task found at index {}", index));
+                }
+                task
+            }
+            Err(e) => {
+                self.transcript_mut()
+                    .add_system_message(format!("Failed to read plan: {}", e));
+                None
+            }
+        }
+    }
+}
+
+/// Find the `index`-th open task (1-based) in a materialized plan
+///
+/// Returns the task's position among all task lines (open and completed) along
+/// with the task text.
+fn find_open_plan_task(content: &str, index: usize) -> Option<(usize, String)> {
+    let mut task_count = 0;
+
+    for (position, line) in content
+        .lines()
+        .map(str::trim)
+        .filter(|l| l.starts_with("- ["))
+        .enumerate()
+    {
+        if line.starts_with("- [ ]") {
+            task_count += 1;
+            if task_count == index {
+                let item = line.trim_start_matches("- [ ]").trim_start_matches('>').trim();
+                return Some((position, item.to_string()));
+            }
+        }
+    }
+
+    None
+}
+
+/// Maximum hits shown for each session in `/search --all`
+const SEARCH_HITS_PER_SESSION: usize = 5;
+
+/// Human-readable name of a search scope
+fn search_scope_label(scope: SearchScope) -> &'static str {
+    match scope {
+        SearchScope::All => "all files",
+        SearchScope::Events => "events",
+        SearchScope::Views => "views",
+    }
+}
+
+/// Format a duration in seconds as e.g. "1h 02m 03s"
+fn format_duration(total_secs: u64) -> String {
+    let hours = total_secs / 3600;
+    let minutes = (total_secs % 3600) / 60;
+    let seconds = total_secs % 60;
+    if hours > 0 {
+        format!("{}h {:02}m {:02}s", hours, minutes, seconds)
+    } else if minutes > 0 {
+        format!("{}m {:02}s", minutes, seconds)
+    } else {
+        format!("{}s", seconds)
+    }
+}
+
+/// Name of the provider family a model belongs to, judged by its prefix
+fn model_provider_name(model: &str) -> Option<&'static str> {
+    if model.starts_with("glm") {
+        Some("GLM")
+    } else if model.starts_with("gemini") {
+        Some("Gemini")
+    } else {
+        None
+    }
+}
+
+/// Copy of `provider` targeting `model`, if the model belongs to that provider
+fn provider_with_model(provider: &ProviderConfig, model: &str) -> Option<ProviderConfig> {
+    match provider {
+        ProviderConfig::Glm { api_key, base_url, thinking, options, .. } if model.starts_with("glm") => {
+            Some(ProviderConfig::Glm {
+                api_key: api_key.clone(),
+                model: model.to_string(),
+                base_url: base_url.clone(),
+                thinking: thinking.clone(),
+                options: options.clone(),
+            })
+        }
+        ProviderConfig::Gemini { api_key, base_url, thinking, options, .. } if model.starts_with("gemini") => {
+            Some(ProviderConfig::Gemini {
+                api_key: api_key.clone(),
+                model: model.to_string(),
+                base_url: base_url.clone(),
+                thinking: thinking.clone(),
+                options: options.clone(),
+            })
         }
+        _ => None,
     }
 }
 
+/// Follow-up message asking the model to finish a truncated response
+const CONTINUE_PROMPT: &str = "Your previous response was cut off by the output token limit. \
+Continue exactly where it stopped, without repeating what was already written.";
+
 #[cfg(test)]
 mod tests {
-    use thunderus_core::ApprovalMode;
+    use thunderus_core::{ApprovalMode, ProviderConfig, ViewKind, ViewMaterializer};
 
+    use super::find_open_plan_task;
     use crate::app::create_test_app;
+    use crate::state::VerbosityLevel;
     use crate::transcript;
 
     #[test]
@@ -374,6 +781,131 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_handle_model_command_switches_to_configured_provider() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let config_path = temp.path().join("config.toml");
+        std::fs::write(
+            &config_path,
+            r#"
+default_profile = "work"
+
+[profiles.work]
+name = "work"
+working_root = "/tmp"
+
+[profiles.work.provider]
+provider = "glm"
+api_key = "glm-key"
+model = "glm-4.7"
+
+[profiles.google]
+name = "google"
+working_root = "/tmp"
+
+[profiles.google.provider]
+provider = "gemini"
+api_key = "gemini-key"
+model = "gemini-2.5-flash"
+"#,
+        )
+        .unwrap();
+
+        let mut app = create_test_app();
+        app.state.config.config_path = Some(config_path);
+        app.handle_model_command("gemini-2.5-pro".to_string());
+
+        match &app.state.config.provider {
+            ProviderConfig::Gemini { api_key, model, .. } => {
+                assert_eq!(api_key, "gemini-key");
+                assert_eq!(model, "gemini-2.5-pro");
+            }
+            other => panic!("Expected Gemini provider, got {:?}", other),
+        }
+        if let transcript::TranscriptEntry::SystemMessage { content } = app.transcript().last().unwrap() {
+            assert!(content.contains("Model switched to gemini-2.5-pro"));
+        } else {
+            panic!("Expected SystemMessage");
+        }
+    }
+
+    #[test]
+    fn test_handle_model_command_keeps_guardrail_without_configured_provider() {
+        let mut app = create_test_app();
+        app.handle_model_command("gemini-2.5-flash".to_string());
+
+        assert!(matches!(app.state.config.provider, ProviderConfig::Glm { .. }));
+        if let transcript::TranscriptEntry::SystemMessage { content } = app.transcript().last().unwrap() {
+            assert!(content.contains("Cannot switch to a Gemini model"));
+        } else {
+            panic!("Expected SystemMessage");
+        }
+    }
+
+    #[test]
+    fn test_handle_profile_command_switches_profile() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let config_path = temp.path().join("config.toml");
+        std::fs::write(
+            &config_path,
+            r#"
+default_profile = "test"
+
+[profiles.test]
+name = "test"
+working_root = "/tmp"
+
+[profiles.test.provider]
+provider = "glm"
+api_key = "glm-key"
+model = "glm-4.7"
+
+[profiles.strict]
+name = "strict"
+working_root = "/tmp"
+approval_mode = "read-only"
+
+[profiles.strict.provider]
+provider = "gemini"
+api_key = "gemini-key"
+model = "gemini-2.5-flash"
+"#,
+        )
+        .unwrap();
+
+        let mut app = create_test_app();
+        app.state.config.config_path = Some(config_path);
+        app.transcript_mut().add_user_message("keep me");
+        app.handle_profile_command(Some("strict".to_string()));
+
+        assert_eq!(app.state.config.profile, "strict");
+        assert_eq!(app.state.config.approval_mode, ApprovalMode::ReadOnly);
+        assert!(matches!(app.state.config.provider, ProviderConfig::Gemini { .. }));
+        assert_eq!(app.profile().map(|p| p.name.as_str()), Some("strict"));
+        assert_eq!(app.transcript().len(), 2);
+        if let transcript::TranscriptEntry::SystemMessage { content } = app.transcript().last().unwrap() {
+            assert!(content.contains("Profile switched: test → strict"));
+        } else {
+            panic!("Expected SystemMessage");
+        }
+
+        app.handle_profile_command(Some("missing".to_string()));
+        assert_eq!(app.state.config.profile, "strict");
+        if let transcript::TranscriptEntry::SystemMessage { content } = app.transcript().last().unwrap() {
+            assert!(content.contains("Unknown profile: missing"));
+            assert!(content.contains("strict, test"));
+        } else {
+            panic!("Expected SystemMessage");
+        }
+
+        app.handle_profile_command(None);
+        if let transcript::TranscriptEntry::SystemMessage { content } = app.transcript().last().unwrap() {
+            assert_eq!(content, "Available profiles:\n  * strict\n    test");
+        } else {
+            panic!("Expected SystemMessage");
+        }
+    }
+
     #[test]
     fn test_handle_status_command() {
         let mut app = create_test_app();
@@ -392,6 +924,82 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_handle_stats_command() {
+        let mut app = create_test_app();
+        app.state_mut().stats_mut().record_tool_call("read");
+        app.state_mut().stats_mut().record_tool_call("read");
+        app.state_mut().stats_mut().record_tool_call("grep");
+
+        let gate = std::sync::Arc::new(std::sync::RwLock::new(thunderus_core::ApprovalGate::new(
+            ApprovalMode::Auto,
+            false,
+        )));
+        {
+            let mut guard = gate.write().unwrap();
+            let id = guard.create_request(
+                thunderus_core::ActionType::Shell,
+                "rm -rf target",
+                thunderus_core::ApprovalContext::new(),
+                thunderus_core::ToolRisk::Risky,
+            );
+            guard.reject(id).unwrap();
+        }
+        app.set_approval_gate_handle(gate);
+
+        app.handle_stats_command();
+
+        if let transcript::TranscriptEntry::SystemMessage { content } = app.transcript().last().unwrap() {
+            assert!(content.contains("Session Stats"));
+            assert!(content.contains("Rejected: 1"));
+            assert!(content.contains("Tool Calls: 3"));
+            assert!(content.contains("read: 2"));
+            assert!(content.contains("grep: 1"));
+            assert!(content.contains("Duration:"));
+        } else {
+            panic!("Expected SystemMessage");
+        }
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(super::format_duration(5), "5s");
+        assert_eq!(super::format_duration(65), "1m 05s");
+        assert_eq!(super::format_duration(3723), "1h 02m 03s");
+    }
+
+    #[test]
+    fn test_handle_continue_command_requires_truncation() {
+        let mut app = create_test_app();
+        app.handle_continue_command();
+
+        if let transcript::TranscriptEntry::SystemMessage { content } = app.transcript().last().unwrap() {
+            assert!(content.contains("Nothing to continue"));
+        } else {
+            panic!("Expected SystemMessage");
+        }
+    }
+
+    #[test]
+    fn test_truncated_response_offers_continue() {
+        let mut app = create_test_app();
+        app.handle_agent_event(thunderus_agent::AgentEvent::Token("Partial answer".to_string()));
+        app.handle_agent_event(thunderus_agent::AgentEvent::Finish("length".to_string()));
+        app.handle_agent_event(thunderus_agent::AgentEvent::Done);
+
+        assert!(app.state().session.response_truncated);
+        if let transcript::TranscriptEntry::SystemMessage { content } = app.transcript().last().unwrap() {
+            assert!(content.contains("/continue"));
+        } else {
+            panic!("Expected SystemMessage");
+        }
+
+        app.handle_agent_event(thunderus_agent::AgentEvent::Token("Whole answer".to_string()));
+        app.handle_agent_event(thunderus_agent::AgentEvent::Finish("stop".to_string()));
+        app.handle_agent_event(thunderus_agent::AgentEvent::Done);
+        assert!(!app.state().session.response_truncated);
+    }
+
     #[test]
     fn test_handle_review_command() {
         let mut app = create_test_app();
@@ -409,7 +1017,7 @@ mod tests {
     #[test]
     fn test_handle_approvals_command_list() {
         let mut app = create_test_app();
-        app.handle_approvals_command("list".to_string());
+        app.handle_approvals_command("list".to_string(), false);
 
         assert_eq!(app.transcript().len(), 1);
         if let transcript::TranscriptEntry::SystemMessage { content } = app.transcript().last().unwrap() {
@@ -420,12 +1028,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_handle_approvals_command_rules_and_clear() {
+        let mut app = create_test_app();
+        let gate = std::sync::Arc::new(std::sync::RwLock::new(thunderus_core::ApprovalGate::new(
+            ApprovalMode::Auto,
+            false,
+        )));
+        app.set_approval_gate_handle(std::sync::Arc::clone(&gate));
+
+        app.handle_approvals_command("rules".to_string(), false);
+        if let transcript::TranscriptEntry::SystemMessage { content } = app.transcript().last().unwrap() {
+            assert!(content.contains("No approvals remembered"));
+        } else {
+            panic!("Expected SystemMessage");
+        }
+
+        gate.write().unwrap().remember(thunderus_core::ApprovalRule {
+            action_type: thunderus_core::ActionType::FileWrite,
+            tool: Some("edit".to_string()),
+            scope: "src/*.rs".to_string(),
+        });
+        app.handle_approvals_command("rules".to_string(), false);
+        if let transcript::TranscriptEntry::SystemMessage { content } = app.transcript().last().unwrap() {
+            assert!(content.contains("FileWrite edit src/*.rs"));
+        } else {
+            panic!("Expected SystemMessage");
+        }
+
+        app.handle_approvals_command("clear".to_string(), false);
+        assert!(gate.read().unwrap().session_rules().is_empty());
+        assert_eq!(app.state.config.approval_mode, ApprovalMode::Auto);
+    }
+
     #[test]
     fn test_handle_approvals_command_read_only() {
         let mut app = create_test_app();
         app.state_mut().config.approval_mode = ApprovalMode::Auto;
 
-        app.handle_approvals_command("read-only".to_string());
+        app.handle_approvals_command("read-only".to_string(), false);
 
         assert_eq!(app.state.config.approval_mode, ApprovalMode::ReadOnly);
         assert_eq!(app.transcript().len(), 1);
@@ -436,7 +1077,7 @@ mod tests {
         let mut app = create_test_app();
         app.state_mut().config.approval_mode = ApprovalMode::ReadOnly;
 
-        app.handle_approvals_command("auto".to_string());
+        app.handle_approvals_command("auto".to_string(), false);
 
         assert_eq!(app.state.config.approval_mode, ApprovalMode::Auto);
         assert_eq!(app.transcript().len(), 1);
@@ -447,7 +1088,7 @@ mod tests {
         let mut app = create_test_app();
         app.state_mut().config.approval_mode = ApprovalMode::Auto;
 
-        app.handle_approvals_command("full-access".to_string());
+        app.handle_approvals_command("full-access".to_string(), false);
 
         assert_eq!(app.state.config.approval_mode, ApprovalMode::FullAccess);
         assert_eq!(app.transcript().len(), 1);
@@ -458,7 +1099,7 @@ mod tests {
         let mut app = create_test_app();
         let original_mode = app.state.config.approval_mode;
 
-        app.handle_approvals_command("unknown-mode".to_string());
+        app.handle_approvals_command("unknown-mode".to_string(), false);
 
         assert_eq!(app.state.config.approval_mode, original_mode);
         assert_eq!(app.transcript().len(), 1);
@@ -469,6 +1110,84 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_handle_approvals_command_persist() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let config_path = temp.path().join("config.toml");
+        std::fs::write(
+            &config_path,
+            r#"
+default_profile = "test"
+
+[profiles.test]
+name = "test"
+working_root = "/tmp"
+approval_mode = "auto"
+
+[profiles.test.provider]
+provider = "glm"
+api_key = "${THUNDERUS_TEST_UNSET_KEY}"
+model = "glm-4.7"
+"#,
+        )
+        .unwrap();
+
+        let mut app = create_test_app();
+        app.state.config.config_path = Some(config_path.clone());
+        app.handle_approvals_command("read-only".to_string(), true);
+        app.handle_verbosity_command("verbose".to_string(), true);
+
+        let config = thunderus_core::Config::from_file_unresolved(&config_path).unwrap();
+        let profile = &config.profiles["test"];
+        assert_eq!(profile.approval_mode, ApprovalMode::ReadOnly);
+        assert_eq!(profile.options.get("verbosity").map(String::as_str), Some("verbose"));
+        assert!(
+            std::fs::read_to_string(&config_path)
+                .unwrap()
+                .contains("${THUNDERUS_TEST_UNSET_KEY}")
+        );
+        if let transcript::TranscriptEntry::SystemMessage { content } = app.transcript().last().unwrap() {
+            assert!(content.contains("Saved verbosity to profile 'test'"));
+        } else {
+            panic!("Expected SystemMessage");
+        }
+    }
+
+    #[test]
+    fn test_handle_verbosity_command_persist_without_config() {
+        let mut app = create_test_app();
+        app.handle_verbosity_command("quiet".to_string(), true);
+
+        assert_eq!(app.state.verbosity(), VerbosityLevel::Quiet);
+        if let transcript::TranscriptEntry::SystemMessage { content } = app.transcript().last().unwrap() {
+            assert!(content.contains("applies to this session only"));
+        } else {
+            panic!("Expected SystemMessage");
+        }
+    }
+
+    #[test]
+    fn test_handle_task_command() {
+        let mut app = create_test_app();
+        app.handle_task_command(String::new());
+        if let transcript::TranscriptEntry::SystemMessage { content } = app.transcript().last().unwrap() {
+            assert!(content.contains("No active task"));
+        } else {
+            panic!("Expected SystemMessage");
+        }
+
+        app.update_task_title_from_message("Fix the login bug");
+        assert_eq!(app.state.session_header.task_title.as_deref(), Some("Fixing login bug"));
+
+        app.handle_task_command("Ship the release".to_string());
+        assert_eq!(app.state.session_header.task_title.as_deref(), Some("Ship the release"));
+        assert_eq!(app.task_context.title().as_deref(), Some("Ship the release"));
+        assert!(app.task_context.is_pinned());
+
+        app.update_task_title_from_message("Something else entirely");
+        assert_eq!(app.state.session_header.task_title.as_deref(), Some("Ship the release"));
+    }
+
     #[test]
     fn test_handle_plan_command() {
         let mut app = create_test_app();
@@ -482,4 +1201,79 @@ mod tests {
             panic!("Expected SystemMessage");
         }
     }
+
+    #[test]
+    fn test_find_open_plan_task() {
+        let plan = "# Current Plan\n\n## Tasks\n\n- [x] Setup\n- [ ]> Write parser\n- [ ]> Add tests\n";
+
+        assert_eq!(find_open_plan_task(plan, 1), Some((1, "Write parser".to_string())));
+        assert_eq!(find_open_plan_task(plan, 2), Some((2, "Add tests".to_string())));
+        assert_eq!(find_open_plan_task(plan, 3), None);
+    }
+
+    #[test]
+    fn test_handle_garden_consolidate_live_session() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut session = thunderus_core::Session::new(thunderus_core::AgentDir::new(temp.path())).unwrap();
+        session.append_user_message("Run the tests").unwrap();
+        session
+            .append_shell_command(
+                "cargo",
+                vec!["test".to_string()],
+                temp.path().to_path_buf(),
+                Some(0),
+                None,
+            )
+            .unwrap();
+        let session_id = session.id.to_string();
+        let mut app = create_test_app().with_session(session);
+        app.state.config.cwd = temp.path().to_path_buf();
+
+        app.handle_garden_consolidate_command("latest".to_string());
+
+        if let transcript::TranscriptEntry::SystemMessage { content } = app.transcript().last().unwrap() {
+            assert!(
+                content.contains(&format!("Consolidated session {}", session_id)),
+                "{}",
+                content
+            );
+            assert!(content.contains(&format!("Queued {} memory patch(es)", app.state.memory_patches().len())));
+        } else {
+            panic!("Expected SystemMessage");
+        }
+    }
+
+    #[test]
+    fn test_handle_plan_move_and_remove() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut session = thunderus_core::Session::new(thunderus_core::AgentDir::new(temp.path())).unwrap();
+        for item in ["Task 1", "Task 2", "Task 3"] {
+            session.append_plan_update("add", item, None).unwrap();
+        }
+        let mut app = create_test_app().with_session(session);
+
+        app.handle_plan_move_command(3, 1);
+        app.handle_plan_remove_command(2);
+
+        let session = app.session.as_ref().unwrap();
+        let plan = ViewMaterializer::new(session).materialize(ViewKind::Plan).unwrap();
+        assert!(plan.find("Task 3").unwrap() < plan.find("Task 2").unwrap());
+        assert!(!plan.contains("Task 1"));
+
+        let events = session.read_events().unwrap();
+        assert!(matches!(
+            &events.last().unwrap().event,
+            thunderus_core::Event::PlanUpdate { action, item, .. } if action == "remove" && item == "Task 1"
+        ));
+
+        app.handle_plan_remove_command(5);
+        if let transcript::TranscriptEntry::SystemMessage { content } = app.transcript().last().unwrap() {
+            assert_eq!(content, "No task found at index 5");
+        } else {
+            panic!("Expected SystemMessage");
+        }
+
+        assert_eq!(app.materialized_views.as_ref().unwrap().plan, plan);
+        assert_eq!(app.view_content(ViewKind::Plan).unwrap().unwrap(), plan);
+    }
 }
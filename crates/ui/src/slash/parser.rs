@@ -15,21 +15,21 @@ pub fn parse_slash_command(cmd: String) -> Option<KeyAction> {
                 Some(KeyAction::SlashCommandModel { model: "list".to_string() })
             }
         }
+        "profile" => {
+            // A flag rather than a `list` keyword, so a profile named "list" stays selectable
+            let name = parts.get(1).filter(|arg| **arg != "--list").map(|arg| arg.to_string());
+            Some(KeyAction::SlashCommandProfile { name })
+        }
         "approvals" => {
-            if parts.len() > 1 {
-                Some(KeyAction::SlashCommandApprovals { mode: parts[1].to_string() })
-            } else {
-                Some(KeyAction::SlashCommandApprovals { mode: "list".to_string() })
-            }
+            let (mode, persist) = parse_persistable_arg(&parts[1..]);
+            Some(KeyAction::SlashCommandApprovals { mode, persist })
         }
         "verbosity" => {
-            if parts.len() > 1 {
-                Some(KeyAction::SlashCommandVerbosity { level: parts[1].to_string() })
-            } else {
-                Some(KeyAction::SlashCommandVerbosity { level: "list".to_string() })
-            }
+            let (level, persist) = parse_persistable_arg(&parts[1..]);
+            Some(KeyAction::SlashCommandVerbosity { level, persist })
         }
         "status" => Some(KeyAction::SlashCommandStatus),
+        "stats" => Some(KeyAction::SlashCommandStats),
         "plan" => {
             if parts.len() > 1 {
                 match parts[1] {
@@ -52,12 +52,24 @@ pub fn parse_slash_command(cmd: String) -> Option<KeyAction> {
                             None
                         }
                     }
+                    "remove" => parts
+                        .get(2)
+                        .and_then(|index| index.parse::<usize>().ok())
+                        .map(|index| KeyAction::SlashCommandPlanRemove { index }),
+                    "move" => match (
+                        parts.get(2).and_then(|from| from.parse::<usize>().ok()),
+                        parts.get(3).and_then(|to| to.parse::<usize>().ok()),
+                    ) {
+                        (Some(from), Some(to)) => Some(KeyAction::SlashCommandPlanMove { from, to }),
+                        _ => None,
+                    },
                     _ => Some(KeyAction::SlashCommandPlan),
                 }
             } else {
                 Some(KeyAction::SlashCommandPlan)
             }
         }
+        "task" => Some(KeyAction::SlashCommandTask { title: parts[1..].join(" ") }),
         "review" => Some(KeyAction::SlashCommandReview),
         "memory" => {
             if parts.len() > 1 {
@@ -93,6 +105,7 @@ pub fn parse_slash_command(cmd: String) -> Option<KeyAction> {
             }
         }
         "clear" => Some(KeyAction::SlashCommandClear),
+        "continue" => Some(KeyAction::SlashCommandContinue),
         "config" => Some(KeyAction::SlashCommandConfig),
         "garden" => {
             if parts.len() > 1 {
@@ -107,7 +120,9 @@ pub fn parse_slash_command(cmd: String) -> Option<KeyAction> {
                     "hygiene" => Some(KeyAction::SlashCommandGardenHygiene),
                     "drift" => Some(KeyAction::SlashCommandGardenDrift),
                     "verify" => {
-                        if parts.len() > 2 {
+                        if parts.get(2) == Some(&"--all") {
+                            Some(KeyAction::SlashCommandGardenVerifyAll)
+                        } else if parts.len() > 2 {
                             Some(KeyAction::SlashCommandGardenVerify { doc_id: parts[2].to_string() })
                         } else {
                             None
@@ -121,21 +136,22 @@ pub fn parse_slash_command(cmd: String) -> Option<KeyAction> {
             }
         }
         "search" => {
-            if parts.len() > 1 {
-                let (scope, query_start) = if parts[1] == "--events" {
-                    (thunderus_core::SearchScope::Events, 2)
-                } else if parts[1] == "--views" {
-                    (thunderus_core::SearchScope::Views, 2)
-                } else {
-                    (thunderus_core::SearchScope::All, 1)
-                };
-
-                if parts.len() > query_start {
-                    let query = parts[query_start..].join(" ");
-                    Some(KeyAction::SlashCommandSearch { query, scope })
-                } else {
-                    None
+            let mut scope = thunderus_core::SearchScope::All;
+            let mut all_sessions = false;
+            let mut query_start = 1;
+            for flag in parts.iter().skip(1) {
+                match *flag {
+                    "--events" => scope = thunderus_core::SearchScope::Events,
+                    "--views" => scope = thunderus_core::SearchScope::Views,
+                    "--all" => all_sessions = true,
+                    _ => break,
                 }
+                query_start += 1;
+            }
+
+            if parts.len() > query_start {
+                let query = parts[query_start..].join(" ");
+                Some(KeyAction::SlashCommandSearch { query, scope, all_sessions })
             } else {
                 None
             }
@@ -144,6 +160,17 @@ pub fn parse_slash_command(cmd: String) -> Option<KeyAction> {
     }
 }
 
+/// Split a setting argument from an optional `--persist` flag, defaulting to "list"
+fn parse_persistable_arg(args: &[&str]) -> (String, bool) {
+    let persist = args.contains(&"--persist");
+    let value = args
+        .iter()
+        .find(|arg| **arg != "--persist")
+        .map(|arg| arg.to_string())
+        .unwrap_or_else(|| "list".to_string());
+    (value, persist)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -157,6 +184,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_slash_command_profile() {
+        let action = parse_slash_command("profile work".to_string());
+        assert!(matches!(action, Some(KeyAction::SlashCommandProfile { name: Some(ref name) }) if name == "work"));
+
+        let action = parse_slash_command("profile list".to_string());
+        assert!(matches!(action, Some(KeyAction::SlashCommandProfile { name: Some(ref name) }) if name == "list"));
+
+        let action = parse_slash_command("profile".to_string());
+        assert!(matches!(action, Some(KeyAction::SlashCommandProfile { name: None })));
+
+        let action = parse_slash_command("profile --list".to_string());
+        assert!(matches!(action, Some(KeyAction::SlashCommandProfile { name: None })));
+    }
+
     #[test]
     fn test_parse_slash_command_model_list() {
         let action = parse_slash_command("model".to_string());
@@ -170,7 +212,7 @@ mod tests {
     fn test_parse_slash_command_approvals() {
         let action = parse_slash_command("approvals read-only".to_string());
         assert!(matches!(action, Some(KeyAction::SlashCommandApprovals { .. })));
-        if let Some(KeyAction::SlashCommandApprovals { mode }) = action {
+        if let Some(KeyAction::SlashCommandApprovals { mode, .. }) = action {
             assert_eq!(mode, "read-only");
         }
     }
@@ -179,7 +221,7 @@ mod tests {
     fn test_parse_slash_command_approvals_list() {
         let action = parse_slash_command("approvals".to_string());
         assert!(matches!(action, Some(KeyAction::SlashCommandApprovals { .. })));
-        if let Some(KeyAction::SlashCommandApprovals { mode }) = action {
+        if let Some(KeyAction::SlashCommandApprovals { mode, .. }) = action {
             assert_eq!(mode, "list");
         }
     }
@@ -188,16 +230,37 @@ mod tests {
     fn test_parse_slash_command_verbosity() {
         let action = parse_slash_command("verbosity verbose".to_string());
         assert!(matches!(action, Some(KeyAction::SlashCommandVerbosity { .. })));
-        if let Some(KeyAction::SlashCommandVerbosity { level }) = action {
+        if let Some(KeyAction::SlashCommandVerbosity { level, .. }) = action {
             assert_eq!(level, "verbose");
         }
     }
 
+    #[test]
+    fn test_parse_slash_command_persist_flag() {
+        let action = parse_slash_command("verbosity verbose --persist".to_string());
+        assert!(matches!(
+            action,
+            Some(KeyAction::SlashCommandVerbosity { ref level, persist: true }) if level == "verbose"
+        ));
+
+        let action = parse_slash_command("approvals --persist full-access".to_string());
+        assert!(matches!(
+            action,
+            Some(KeyAction::SlashCommandApprovals { ref mode, persist: true }) if mode == "full-access"
+        ));
+
+        let action = parse_slash_command("approvals auto".to_string());
+        assert!(matches!(
+            action,
+            Some(KeyAction::SlashCommandApprovals { persist: false, .. })
+        ));
+    }
+
     #[test]
     fn test_parse_slash_command_verbosity_list() {
         let action = parse_slash_command("verbosity".to_string());
         assert!(matches!(action, Some(KeyAction::SlashCommandVerbosity { .. })));
-        if let Some(KeyAction::SlashCommandVerbosity { level }) = action {
+        if let Some(KeyAction::SlashCommandVerbosity { level, .. }) = action {
             assert_eq!(level, "list");
         }
     }
@@ -205,17 +268,17 @@ mod tests {
     #[test]
     fn test_parse_slash_command_verbosity_all_levels() {
         let action_quiet = parse_slash_command("verbosity quiet".to_string());
-        if let Some(KeyAction::SlashCommandVerbosity { level }) = action_quiet {
+        if let Some(KeyAction::SlashCommandVerbosity { level, .. }) = action_quiet {
             assert_eq!(level, "quiet");
         }
 
         let action_default = parse_slash_command("verbosity default".to_string());
-        if let Some(KeyAction::SlashCommandVerbosity { level }) = action_default {
+        if let Some(KeyAction::SlashCommandVerbosity { level, .. }) = action_default {
             assert_eq!(level, "default");
         }
 
         let action_verbose = parse_slash_command("verbosity verbose".to_string());
-        if let Some(KeyAction::SlashCommandVerbosity { level }) = action_verbose {
+        if let Some(KeyAction::SlashCommandVerbosity { level, .. }) = action_verbose {
             assert_eq!(level, "verbose");
         }
     }
@@ -226,12 +289,62 @@ mod tests {
         assert!(matches!(action, Some(KeyAction::SlashCommandStatus)));
     }
 
+    #[test]
+    fn test_parse_slash_command_task() {
+        let action = parse_slash_command("task Ship the release".to_string());
+        assert!(matches!(action, Some(KeyAction::SlashCommandTask { ref title }) if title == "Ship the release"));
+
+        let action = parse_slash_command("task".to_string());
+        assert!(matches!(action, Some(KeyAction::SlashCommandTask { ref title }) if title.is_empty()));
+    }
+
+    #[test]
+    fn test_parse_slash_command_stats() {
+        let action = parse_slash_command("stats".to_string());
+        assert!(matches!(action, Some(KeyAction::SlashCommandStats)));
+    }
+
     #[test]
     fn test_parse_slash_command_plan() {
         let action = parse_slash_command("plan".to_string());
         assert!(matches!(action, Some(KeyAction::SlashCommandPlan)));
     }
 
+    #[test]
+    fn test_parse_slash_command_plan_move_and_remove() {
+        assert_eq!(
+            parse_slash_command("plan move 3 1".to_string()),
+            Some(KeyAction::SlashCommandPlanMove { from: 3, to: 1 })
+        );
+        assert_eq!(
+            parse_slash_command("plan remove 2".to_string()),
+            Some(KeyAction::SlashCommandPlanRemove { index: 2 })
+        );
+        assert_eq!(parse_slash_command("plan move 3".to_string()), None);
+        assert_eq!(parse_slash_command("plan remove two".to_string()), None);
+    }
+
+    #[test]
+    fn test_parse_slash_command_search_flags() {
+        assert_eq!(
+            parse_slash_command("search --all --events deploy script".to_string()),
+            Some(KeyAction::SlashCommandSearch {
+                query: "deploy script".to_string(),
+                scope: thunderus_core::SearchScope::Events,
+                all_sessions: true,
+            })
+        );
+        assert_eq!(
+            parse_slash_command("search todo".to_string()),
+            Some(KeyAction::SlashCommandSearch {
+                query: "todo".to_string(),
+                scope: thunderus_core::SearchScope::All,
+                all_sessions: false,
+            })
+        );
+        assert_eq!(parse_slash_command("search --all".to_string()), None);
+    }
+
     #[test]
     fn test_parse_slash_command_review() {
         let action = parse_slash_command("review".to_string());
@@ -250,6 +363,12 @@ mod tests {
         assert!(matches!(action, Some(KeyAction::SlashCommandClear)));
     }
 
+    #[test]
+    fn test_parse_slash_command_continue() {
+        let action = parse_slash_command("continue".to_string());
+        assert!(matches!(action, Some(KeyAction::SlashCommandContinue)));
+    }
+
     #[test]
     fn test_parse_slash_command_config() {
         let action = parse_slash_command("config".to_string());
@@ -262,6 +381,17 @@ mod tests {
         assert!(action.is_none());
     }
 
+    #[test]
+    fn test_parse_slash_command_garden_verify() {
+        let action = parse_slash_command("garden verify fact.build".to_string());
+        assert!(matches!(action, Some(KeyAction::SlashCommandGardenVerify { ref doc_id }) if doc_id == "fact.build"));
+
+        let action = parse_slash_command("garden verify --all".to_string());
+        assert!(matches!(action, Some(KeyAction::SlashCommandGardenVerifyAll)));
+
+        assert!(parse_slash_command("garden verify".to_string()).is_none());
+    }
+
     #[test]
     fn test_parse_slash_command_empty() {
         let action = parse_slash_command("".to_string());
@@ -0,0 +1,85 @@
+use crate::transcript::StatusType;
+
+use std::time::{Duration, Instant};
+
+/// What the agent is doing right now, shown in the footer status line
+#[derive(Debug, Clone, PartialEq)]
+pub struct Activity {
+    /// Phase label, e.g. "thinking" or "running tool: grep"
+    pub message: String,
+    pub status_type: StatusType,
+    /// When this phase began
+    pub since: Instant,
+}
+
+impl Activity {
+    pub fn new(message: impl Into<String>, status_type: StatusType) -> Self {
+        Self { message: message.into(), status_type, since: Instant::now() }
+    }
+
+    /// Nothing in progress
+    pub fn ready() -> Self {
+        Self::new("ready", StatusType::Ready)
+    }
+
+    /// The phase for running a tool; patch tools read as "applying patch"
+    pub fn tool(name: &str) -> Self {
+        if name.contains("patch") {
+            Self::new("applying patch", StatusType::Building)
+        } else {
+            Self::new(format!("running tool: {}", name), StatusType::Building)
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.status_type == StatusType::Ready
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.since.elapsed()
+    }
+
+    /// Status line text, e.g. "running tool: grep · 12s"; ready has no timer
+    pub fn label(&self) -> String {
+        if self.is_ready() {
+            return self.message.clone();
+        }
+        format!("{} · {}", self.message, format_elapsed(self.elapsed()))
+    }
+}
+
+impl Default for Activity {
+    fn default() -> Self {
+        Self::ready()
+    }
+}
+
+/// Elapsed time as "42s" or "3m 05s"
+fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    if secs < 60 { format!("{}s", secs) } else { format!("{}m {:02}s", secs / 60, secs % 60) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_activity_tool_phases() {
+        assert_eq!(Activity::tool("grep").message, "running tool: grep");
+        assert_eq!(Activity::tool("apply_patch").message, "applying patch");
+        assert_eq!(Activity::tool("grep").status_type, StatusType::Building);
+    }
+
+    #[test]
+    fn test_activity_label_includes_elapsed_time() {
+        assert_eq!(Activity::ready().label(), "ready");
+
+        let mut activity = Activity::new("thinking", StatusType::Generating);
+        activity.since = Instant::now() - Duration::from_secs(185);
+        assert_eq!(activity.label(), "thinking · 3m 05s");
+
+        activity.since = Instant::now() - Duration::from_secs(7);
+        assert_eq!(activity.label(), "thinking · 7s");
+    }
+}
@@ -1,13 +1,15 @@
 use crate::{ThemeVariant, fuzzy_finder::FuzzyFinder};
 
+use crate::transcript::StatusType;
 use std::path::PathBuf;
+use std::time::{Duration, Instant};
 use thunderus_core::{ApprovalMode, ProviderConfig, SandboxMode};
 
 use super::{
-    ApprovalState, ApprovalUIState, ComposerMode, ComposerState, ConfigEditorState, ConfigState,
+    Activity, ApprovalState, ApprovalUIState, ComposerMode, ComposerState, ConfigEditorState, ConfigState,
     DiffNavigationState, EvidenceState, ExitState, GitDiff, HeaderState, InputState, MemoryHitsState,
-    ModelSelectorState, ModifiedFile, SessionEvent, SessionStats, SessionTrackingState, SidebarCollapseState,
-    UIState, VerbosityLevel, WelcomeState,
+    ModelSelectorState, ModifiedFile, SessionEvent, SessionStats, SessionTrackingState, SidebarCollapseState, UIState,
+    VerbosityLevel, WelcomeState,
 };
 
 /// Main application state
@@ -194,10 +196,31 @@ impl AppState {
 
     pub fn start_generation(&mut self) {
         self.ui.start_generation();
+        self.set_activity(Activity::new("thinking", StatusType::Generating));
     }
 
     pub fn stop_generation(&mut self) {
         self.ui.stop_generation();
+        self.set_activity(Activity::ready());
+    }
+
+    /// Show a new phase in the status line
+    ///
+    /// Repeating the current phase keeps its timer running.
+    pub fn set_activity(&mut self, activity: Activity) {
+        let current = &self.session.activity;
+        if current.message != activity.message || current.status_type != activity.status_type {
+            self.session.activity = activity;
+        }
+    }
+
+    /// Whether the status line timer needs a redraw to tick over
+    pub fn activity_timer_due(&self, now: Instant) -> bool {
+        !self.session.activity.is_ready()
+            && self
+                .ui
+                .last_draw
+                .is_none_or(|last| now.saturating_duration_since(last) >= Duration::from_secs(1))
     }
 
     pub fn is_generating(&self) -> bool {
@@ -210,6 +233,7 @@ impl AppState {
 
     pub fn pause_generation(&mut self) {
         self.ui.pause_generation();
+        self.set_activity(Activity::new("paused", StatusType::Interrupted));
     }
 
     pub fn start_reconcile(&mut self) {
@@ -281,6 +305,10 @@ impl AppState {
         &self.composer.composer_mode
     }
 
+    pub fn is_multiline_input(&self) -> bool {
+        self.composer.is_multiline()
+    }
+
     pub fn pending_approval(&self) -> Option<&ApprovalState> {
         self.approval_ui.pending_approval.as_ref()
     }
@@ -293,6 +321,10 @@ impl AppState {
         self.approval_ui.show_hint(hint);
     }
 
+    pub fn show_concept_hint(&mut self, concept: impl Into<String>, hint: impl Into<String>) {
+        self.approval_ui.show_concept_hint(concept, hint);
+    }
+
     pub fn dismiss_hint(&mut self) {
         self.approval_ui.dismiss_hint();
     }
@@ -362,6 +394,113 @@ impl AppState {
         self.ui.diff_navigation.selected_file_path.as_ref()
     }
 
+    /// Open the full-screen diff review, selecting the first hunk if nothing is selected
+    pub fn enter_diff_review(&mut self) {
+        self.ui.enter_diff_review();
+        if self.selected_patch_index().is_none() {
+            self.next_patch(self.patches().len() + self.memory_patches().len());
+        }
+        self.select_first_review_hunk();
+    }
+
+    /// Toggle the full-screen diff review
+    pub fn toggle_diff_review(&mut self) {
+        if self.ui.is_diff_review() {
+            self.ui.exit_diff_review();
+        } else {
+            self.enter_diff_review();
+        }
+    }
+
+    /// Move to the next or previous patch in the diff review
+    pub fn review_step_patch(&mut self, forward: bool) {
+        let total = self.patches().len() + self.memory_patches().len();
+        if forward {
+            self.next_patch(total);
+        } else {
+            self.prev_patch(total);
+        }
+        self.select_first_review_hunk();
+    }
+
+    /// Move to the next or previous hunk of the selected patch, crossing file boundaries
+    pub fn review_step_hunk(&mut self, forward: bool) {
+        let hunks = self.review_hunks();
+        if hunks.is_empty() {
+            return;
+        }
+
+        let current = self
+            .selected_file_path()
+            .zip(self.selected_hunk_index())
+            .and_then(|(file, hunk)| hunks.iter().position(|(path, idx)| path == file && *idx == hunk));
+        let target = match current {
+            Some(pos) if forward => (pos + 1).min(hunks.len() - 1),
+            Some(pos) => pos.saturating_sub(1),
+            None => 0,
+        };
+
+        let (file, hunk) = hunks[target].clone();
+        self.set_selected_file(file);
+        self.ui.diff_navigation.selected_hunk_index = Some(hunk);
+    }
+
+    /// Select the first hunk of the selected file patch when no file is selected yet
+    fn select_first_review_hunk(&mut self) {
+        if self.selected_file_path().is_none()
+            && let Some((file, hunk)) = self.review_hunks().into_iter().next()
+        {
+            self.set_selected_file(file);
+            self.ui.diff_navigation.selected_hunk_index = Some(hunk);
+        }
+    }
+
+    /// Open the diff review on the patch recorded by a session's `Patch` event
+    ///
+    /// The patch is matched by event sequence number, then by name. When
+    /// `file` is one of the patch's files its first hunk is selected. Returns
+    /// false if no loaded patch matches.
+    pub fn review_patch_event(&mut self, seq: u64, name: &str, file: Option<&str>) -> bool {
+        let patches = self.patches();
+        let Some(index) = patches
+            .iter()
+            .position(|patch| patch.seq == seq)
+            .or_else(|| patches.iter().position(|patch| patch.name == name))
+        else {
+            return false;
+        };
+
+        self.reset_diff_navigation();
+        self.ui.diff_navigation.selected_patch_index = Some(index);
+        if let Some((file, hunk)) = self
+            .review_hunks()
+            .into_iter()
+            .find(|(path, _)| Some(path.as_str()) == file)
+        {
+            self.set_selected_file(file);
+            self.ui.diff_navigation.selected_hunk_index = Some(hunk);
+        }
+        self.select_first_review_hunk();
+        self.ui.enter_diff_review();
+        true
+    }
+
+    /// Every (file, hunk index) pair of the selected file patch, in file order
+    fn review_hunks(&self) -> Vec<(String, usize)> {
+        let Some(patch) = self.selected_patch_index().and_then(|idx| self.patches().get(idx)) else {
+            return Vec::new();
+        };
+        patch
+            .files
+            .iter()
+            .flat_map(|file| {
+                let count = patch.hunks.get(file).map_or(0, Vec::len);
+                let path = file.to_string_lossy().to_string();
+                (0..count).map(move |idx| (path.clone(), idx))
+            })
+            .collect()
+    }
+
     /// Reset diff navigation state
     pub fn reset_diff_navigation(&mut self) {
         self.ui.diff_navigation.reset();
@@ -450,6 +589,33 @@ mod tests {
         assert!(!state.is_generating());
     }
 
+    #[test]
+    fn test_app_state_activity_follows_generation() {
+        let mut state = AppState::default();
+        assert!(state.session.activity.is_ready());
+        assert!(!state.activity_timer_due(Instant::now()));
+
+        state.start_generation();
+        assert_eq!(state.session.activity.message, "thinking");
+        assert!(state.activity_timer_due(Instant::now()));
+
+        let started = Instant::now() - Duration::from_secs(30);
+        state.session.activity.since = started;
+        state.set_activity(Activity::new("thinking", StatusType::Generating));
+        assert_eq!(state.session.activity.since, started);
+
+        state.set_activity(Activity::tool("grep"));
+        assert_ne!(state.session.activity.since, started);
+
+        state.ui.mark_drawn(Instant::now());
+        assert!(!state.activity_timer_due(Instant::now()));
+
+        state.pause_generation();
+        assert_eq!(state.session.activity.status_type, StatusType::Interrupted);
+        state.stop_generation();
+        assert!(state.session.activity.is_ready());
+    }
+
     #[test]
     fn test_app_state_toggle_sidebar() {
         let mut state = AppState::default();
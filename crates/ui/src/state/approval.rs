@@ -1,3 +1,9 @@
+use std::time::{Duration, Instant};
+use thunderus_core::{ApprovalDecision, ApprovalRule};
+
+/// Longest rejection reason the inline input accepts, in characters
+pub const MAX_REJECTION_REASON_CHARS: usize = 200;
+
 /// Approval state for pending approvals
 #[derive(Debug, Clone)]
 pub struct ApprovalState {
@@ -11,11 +17,65 @@ pub struct ApprovalState {
     pub description: Option<String>,
     /// User's decision (Some(true) = approved, Some(false) = rejected, None = pending)
     pub decision: Option<bool>,
+    /// Deadline after which the prompt resolves on its own
+    pub timeout: Option<ApprovalTimeout>,
+    /// Rule remembered if the user approves for the rest of the session
+    pub rule: Option<ApprovalRule>,
+    /// Rejection reason being typed; `Some` while the reason input is open
+    pub reason: Option<String>,
+}
+
+/// Countdown for a pending approval configured with a timeout
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ApprovalTimeout {
+    /// Full timeout, restored whenever the user presses a key
+    pub duration: Duration,
+    /// When the prompt resolves if left unanswered
+    pub deadline: Instant,
+    /// Decision sent to the agent on expiry
+    pub decision: ApprovalDecision,
+}
+
+impl ApprovalTimeout {
+    pub fn new(duration: Duration, decision: ApprovalDecision) -> Self {
+        Self { duration, deadline: Instant::now() + duration, decision }
+    }
+
+    /// Restart the countdown from the full duration
+    pub fn reset(&mut self) {
+        self.deadline = Instant::now() + self.duration;
+    }
+
+    pub fn remaining(&self) -> Duration {
+        self.deadline.saturating_duration_since(Instant::now())
+    }
+
+    pub fn is_expired(&self) -> bool {
+        Instant::now() >= self.deadline
+    }
+
+    /// Countdown label such as "auto-reject in 42s"
+    pub fn label(&self) -> String {
+        let action = match self.decision {
+            ApprovalDecision::Cancelled => "auto-cancel",
+            _ => "auto-reject",
+        };
+        format!("{} in {}s", action, self.remaining().as_secs_f64().ceil() as u64)
+    }
 }
 
 impl ApprovalState {
     pub fn pending(action: String, risk: String) -> Self {
-        Self { request_id: None, action, risk, description: None, decision: None }
+        Self {
+            request_id: None,
+            action,
+            risk,
+            description: None,
+            decision: None,
+            timeout: None,
+            rule: None,
+            reason: None,
+        }
     }
 
     pub fn with_request_id(mut self, request_id: u64) -> Self {
@@ -23,6 +83,21 @@ impl ApprovalState {
         self
     }
 
+    pub fn with_rule(mut self, rule: ApprovalRule) -> Self {
+        self.rule = Some(rule);
+        self
+    }
+
+    pub fn with_timeout(mut self, duration: Duration, decision: ApprovalDecision) -> Self {
+        self.timeout = Some(ApprovalTimeout::new(duration, decision));
+        self
+    }
+
+    /// Whether the prompt is still unanswered and its timeout has expired
+    pub fn is_timed_out(&self) -> bool {
+        self.is_pending() && self.timeout.is_some_and(|timeout| timeout.is_expired())
+    }
+
     pub fn is_pending(&self) -> bool {
         self.decision.is_none()
     }
@@ -34,6 +109,35 @@ impl ApprovalState {
     pub fn reject(&mut self) {
         self.decision = Some(false);
     }
+
+    /// Open the inline input for a rejection reason
+    pub fn start_reason(&mut self) {
+        self.reason.get_or_insert_with(String::new);
+    }
+
+    /// Close the reason input without rejecting
+    pub fn cancel_reason(&mut self) {
+        self.reason = None;
+    }
+
+    pub fn is_entering_reason(&self) -> bool {
+        self.reason.is_some()
+    }
+
+    /// Append a character to the reason, up to [`MAX_REJECTION_REASON_CHARS`]
+    pub fn push_reason_char(&mut self, c: char) {
+        if let Some(reason) = self.reason.as_mut()
+            && reason.chars().count() < MAX_REJECTION_REASON_CHARS
+        {
+            reason.push(c);
+        }
+    }
+
+    pub fn pop_reason_char(&mut self) {
+        if let Some(reason) = self.reason.as_mut() {
+            reason.pop();
+        }
+    }
 }
 
 #[cfg(test)]
@@ -57,6 +161,31 @@ mod tests {
         assert_eq!(approval2.decision, Some(false));
     }
 
+    #[test]
+    fn test_approval_state_reason_input() {
+        let mut approval = ApprovalState::pending("shell".to_string(), "risky".to_string());
+        assert!(!approval.is_entering_reason());
+        approval.push_reason_char('x');
+        assert_eq!(approval.reason, None);
+
+        approval.start_reason();
+        "no".chars().for_each(|c| approval.push_reason_char(c));
+        approval.pop_reason_char();
+        assert_eq!(approval.reason.as_deref(), Some("n"));
+
+        for _ in 0..MAX_REJECTION_REASON_CHARS {
+            approval.push_reason_char('a');
+        }
+        assert_eq!(
+            approval.reason.as_ref().unwrap().chars().count(),
+            MAX_REJECTION_REASON_CHARS
+        );
+
+        approval.cancel_reason();
+        assert!(!approval.is_entering_reason());
+        assert!(approval.is_pending());
+    }
+
     #[test]
     fn test_approval_state_with_request_id() {
         let approval = ApprovalState::pending("test.action".to_string(), "safe".to_string()).with_request_id(123);
@@ -65,4 +194,28 @@ mod tests {
         assert_eq!(approval.action, "test.action");
         assert_eq!(approval.risk, "safe");
     }
+
+    #[test]
+    fn test_approval_state_timeout() {
+        let approval = ApprovalState::pending("test.action".to_string(), "risky".to_string());
+        assert!(!approval.is_timed_out());
+
+        let mut approval = approval.with_timeout(Duration::from_secs(30), ApprovalDecision::Rejected);
+        assert!(!approval.is_timed_out());
+        assert_eq!(approval.timeout.unwrap().label(), "auto-reject in 30s");
+
+        let timeout = approval.timeout.as_mut().unwrap();
+        timeout.deadline = Instant::now() - Duration::from_secs(1);
+        assert!(approval.is_timed_out());
+        assert_eq!(approval.timeout.unwrap().label(), "auto-reject in 0s");
+
+        approval.timeout.as_mut().unwrap().reset();
+        assert!(!approval.is_timed_out());
+    }
+
+    #[test]
+    fn test_approval_timeout_label_cancel() {
+        let timeout = ApprovalTimeout::new(Duration::from_secs(5), ApprovalDecision::Cancelled);
+        assert_eq!(timeout.label(), "auto-cancel in 5s");
+    }
 }
@@ -1,13 +1,15 @@
 use std::path::PathBuf;
 
-use crate::FuzzyFinder;
+use crate::{FuzzyFinder, RecentFiles};
 
 /// Composer mode for input handling
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum ComposerMode {
-    /// Normal text input
+    /// Single-line text input, Enter submits
     #[default]
     Normal,
+    /// Multi-line text input, Shift+Enter inserts a newline and Enter submits
+    MultiLine,
     /// Fuzzy file finder active
     FuzzyFinder,
 }
@@ -19,17 +21,24 @@ pub struct ComposerState {
     pub composer_mode: ComposerMode,
     /// Active fuzzy finder (if any)
     pub fuzzy_finder: Option<FuzzyFinder>,
+    /// Line mode to return to when the fuzzy finder closes
+    resume_mode: ComposerMode,
 }
 
 impl ComposerState {
     pub fn new() -> Self {
-        Self { composer_mode: ComposerMode::default(), fuzzy_finder: None }
+        Self { composer_mode: ComposerMode::default(), fuzzy_finder: None, resume_mode: ComposerMode::default() }
     }
 
     /// Enter fuzzy finder mode
     pub fn enter_fuzzy_finder(&mut self, cwd: PathBuf, original_input: String, original_cursor: usize) {
+        if !self.is_fuzzy_finder_active() {
+            self.resume_mode = self.composer_mode.clone();
+        }
         self.composer_mode = ComposerMode::FuzzyFinder;
+        let recent = RecentFiles::load_default().for_workspace(&cwd).to_vec();
         let mut finder = FuzzyFinder::new(cwd, original_input, original_cursor);
+        finder.set_recent(recent);
         if let Ok(()) = finder.discover_files() {
             self.fuzzy_finder = Some(finder);
         }
@@ -37,10 +46,30 @@ impl ComposerState {
 
     /// Exit fuzzy finder mode
     pub fn exit_fuzzy_finder(&mut self) {
-        self.composer_mode = ComposerMode::Normal;
+        self.composer_mode = std::mem::take(&mut self.resume_mode);
         self.fuzzy_finder = None;
     }
 
+    /// Switch between single-line and multi-line input
+    ///
+    /// Has no effect while the fuzzy finder is open.
+    pub fn toggle_multiline(&mut self) {
+        self.composer_mode = match self.composer_mode {
+            ComposerMode::Normal => ComposerMode::MultiLine,
+            ComposerMode::MultiLine => ComposerMode::Normal,
+            ComposerMode::FuzzyFinder => return,
+        };
+    }
+
+    /// Check if the composer accepts multi-line input
+    pub fn is_multiline(&self) -> bool {
+        match self.composer_mode {
+            ComposerMode::MultiLine => true,
+            ComposerMode::FuzzyFinder => self.resume_mode == ComposerMode::MultiLine,
+            ComposerMode::Normal => false,
+        }
+    }
+
     /// Check if fuzzy finder is active
     pub fn is_fuzzy_finder_active(&self) -> bool {
         matches!(self.composer_mode, ComposerMode::FuzzyFinder)
@@ -62,3 +91,37 @@ impl Default for ComposerState {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle_multiline() {
+        let mut composer = ComposerState::new();
+        assert!(!composer.is_multiline());
+
+        composer.toggle_multiline();
+        assert_eq!(composer.composer_mode, ComposerMode::MultiLine);
+        assert!(composer.is_multiline());
+
+        composer.toggle_multiline();
+        assert_eq!(composer.composer_mode, ComposerMode::Normal);
+    }
+
+    #[test]
+    fn test_fuzzy_finder_restores_multiline() {
+        let mut composer = ComposerState::new();
+        composer.toggle_multiline();
+
+        composer.enter_fuzzy_finder(PathBuf::from("."), String::new(), 0);
+        assert!(composer.is_fuzzy_finder_active());
+        assert!(composer.is_multiline());
+
+        composer.toggle_multiline();
+        assert!(composer.is_fuzzy_finder_active());
+
+        composer.exit_fuzzy_finder();
+        assert_eq!(composer.composer_mode, ComposerMode::MultiLine);
+    }
+}
@@ -24,6 +24,15 @@ impl VerbosityLevel {
         }
     }
 
+    pub fn parse_str(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "quiet" => Some(VerbosityLevel::Quiet),
+            "default" => Some(VerbosityLevel::Default),
+            "verbose" => Some(VerbosityLevel::Verbose),
+            _ => None,
+        }
+    }
+
     pub fn toggle(&mut self) {
         *self = match self {
             VerbosityLevel::Quiet => VerbosityLevel::Default,
@@ -53,17 +62,6 @@ pub struct ModifiedFile {
     pub mod_type: String,
 }
 
-/// Git diff entry for sidebar display
-#[derive(Debug, Clone)]
-pub struct GitDiff {
-    /// File path
-    pub path: String,
-    /// Number of lines added
-    pub added: usize,
-    /// Number of lines deleted
-    pub deleted: usize,
-}
-
 /// Configuration and settings for the application
 #[derive(Debug, Clone)]
 pub struct ConfigState {
@@ -85,6 +83,8 @@ pub struct ConfigState {
     pub git_branch: Option<String>,
     /// Path to config.toml (if provided by CLI)
     pub config_path: Option<PathBuf>,
+    /// Whether the memory system is enabled for this profile
+    pub memory_enabled: bool,
 }
 
 impl ConfigState {
@@ -102,6 +102,7 @@ impl ConfigState {
             verbosity: VerbosityLevel::Quiet,
             git_branch: None,
             config_path: None,
+            memory_enabled: true,
         }
     }
 
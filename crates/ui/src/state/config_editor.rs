@@ -160,23 +160,16 @@ impl ConfigEditorState {
             return Err("No config path set".to_string());
         };
 
-        let mut config = if config_path.exists() {
-            Config::from_file(config_path).map_err(|e| format!("Failed to load config: {}", e))?
-        } else {
+        if !config_path.exists() {
             return Err("Config file does not exist".to_string());
-        };
-
-        let profile = config
-            .profiles
-            .get_mut(&self.profile_name)
-            .ok_or_else(|| format!("Profile '{}' not found", self.profile_name))?;
-
-        profile.approval_mode = self.approval_mode;
-        profile.sandbox_mode = self.sandbox_mode;
-        profile.allow_network = self.network_access;
+        }
 
-        config
-            .save_to_file(config_path)
+        let values = [
+            ("approval_mode", self.approval_mode.as_str().into()),
+            ("sandbox_mode", self.sandbox_mode.as_str().into()),
+            ("allow_network", self.network_access.into()),
+        ];
+        Config::update_profile_file(config_path, &self.profile_name, &values)
             .map_err(|e| format!("Failed to save config: {}", e))?;
 
         Ok(format!("Config saved to {}", config_path.display()))
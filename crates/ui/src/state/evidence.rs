@@ -3,6 +3,7 @@
 //! Manages the trajectory of events that lead to a memory assertion.
 
 use thunderus_core::trajectory::TrajectoryNode;
+use thunderus_core::{EvidenceSource, VerificationState};
 
 /// State for the Inspector evidence view
 #[derive(Debug, Clone, Default)]
@@ -13,6 +14,10 @@ pub struct EvidenceState {
     pub selected_index: usize,
     /// Vertical scroll offset for detail view
     pub detail_scroll: u16,
+    /// Tool and files recorded for each provenance event
+    pub sources: Vec<EvidenceSource>,
+    /// Verification state of the inspected document
+    pub verification: Option<VerificationState>,
 }
 
 impl EvidenceState {
@@ -28,9 +33,21 @@ impl EvidenceState {
         self.detail_scroll = 0;
     }
 
+    /// Set the recorded sources for the inspected document's events
+    pub fn set_sources(&mut self, sources: Vec<EvidenceSource>) {
+        self.sources = sources;
+    }
+
+    /// Set the verification state of the inspected document
+    pub fn set_verification(&mut self, state: VerificationState) {
+        self.verification = Some(state);
+    }
+
     /// Clear evidence
     pub fn clear(&mut self) {
         self.nodes.clear();
+        self.sources.clear();
+        self.verification = None;
         self.selected_index = 0;
         self.detail_scroll = 0;
     }
@@ -40,6 +57,19 @@ impl EvidenceState {
         self.nodes.get(self.selected_index)
     }
 
+    /// Get the recorded source for the currently selected node
+    ///
+    /// Provenance events are stored as `{session}_{seq}`; older documents may
+    /// use the bare `evt_{seq}` form.
+    pub fn selected_source(&self) -> Option<&EvidenceSource> {
+        let event = &self.selected_node()?.event;
+        let id = format!("{}_{}", event.session_id, event.seq);
+        let short_id = format!("evt_{}", event.seq);
+        self.sources
+            .iter()
+            .find(|source| source.event == id || source.event == short_id)
+    }
+
     /// Select the next node
     pub fn select_next(&mut self) {
         if !self.nodes.is_empty() {
@@ -1,3 +1,9 @@
+/// Context usage percentage at which the header gauge turns to a warning color
+pub const CONTEXT_WARNING_PERCENT: u8 = 70;
+
+/// Context usage percentage at which the header gauge turns to an error color
+pub const CONTEXT_CRITICAL_PERCENT: u8 = 90;
+
 /// State for the session header display
 ///
 /// Tracks task title derived from first user message and token/cost statistics.
@@ -26,6 +32,23 @@ impl HeaderState {
         ((self.tokens_used as f64 / self.context_limit as f64) * 100.0).min(100.0) as u8
     }
 
+    /// Render context usage as a gauge of `width` cells (e.g., "▰▰▰▱▱")
+    pub fn context_gauge(&self, width: usize) -> String {
+        let percent = self.context_percentage() as usize;
+        let filled = (percent * width).div_ceil(100).min(width);
+        format!("{}{}", "▰".repeat(filled), "▱".repeat(width - filled))
+    }
+
+    /// Whether context usage has crossed the warning threshold
+    pub fn is_context_warning(&self) -> bool {
+        self.context_percentage() >= CONTEXT_WARNING_PERCENT
+    }
+
+    /// Whether context usage has crossed the critical threshold
+    pub fn is_context_critical(&self) -> bool {
+        self.context_percentage() >= CONTEXT_CRITICAL_PERCENT
+    }
+
     /// Set task title from the first user message
     ///
     /// Takes first 50 chars, truncates at word boundary if needed.
@@ -38,6 +61,11 @@ impl HeaderState {
         self.task_title = Some(title);
     }
 
+    /// Replace the task title, e.g. from a `/task` command
+    pub fn set_task_title(&mut self, title: &str) {
+        self.task_title = Some(derive_task_title(title));
+    }
+
     /// Format tokens for display (e.g., "14,295" or "14.3k")
     pub fn tokens_display(&self) -> String {
         if self.tokens_used >= 1000 {
@@ -57,6 +85,11 @@ impl HeaderState {
         self.tokens_used = tokens;
     }
 
+    /// Update the context window limit, e.g. from the provider's model
+    pub fn update_context_limit(&mut self, limit: usize) {
+        self.context_limit = limit;
+    }
+
     /// Update estimated cost
     pub fn update_cost(&mut self, cost: f64) {
         self.estimated_cost = cost;
@@ -113,6 +146,27 @@ mod tests {
         assert_eq!(state.context_percentage(), 100);
     }
 
+    #[test]
+    fn test_context_gauge_and_thresholds() {
+        let mut state = HeaderState::new();
+        state.update_context_limit(200_000);
+        assert_eq!(state.context_gauge(5), "▱▱▱▱▱");
+        assert!(!state.is_context_warning());
+
+        state.update_tokens(50_000);
+        assert_eq!(state.context_gauge(5), "▰▰▱▱▱");
+        assert!(!state.is_context_warning());
+
+        state.update_tokens(150_000);
+        assert_eq!(state.context_gauge(5), "▰▰▰▰▱");
+        assert!(state.is_context_warning());
+        assert!(!state.is_context_critical());
+
+        state.update_tokens(190_000);
+        assert_eq!(state.context_gauge(5), "▰▰▰▰▰");
+        assert!(state.is_context_critical());
+    }
+
     #[test]
     fn test_set_task_title_from_message_short() {
         let mut state = HeaderState::new();
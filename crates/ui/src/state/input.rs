@@ -1,3 +1,29 @@
+use std::{
+    collections::VecDeque,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+/// Message history file, relative to the working directory
+pub const HISTORY_FILE: &str = ".thunderus/history";
+
+/// Default number of messages kept in the history file
+pub const DEFAULT_HISTORY_SIZE: usize = 1000;
+
+/// Substrings that mark a message as too sensitive to write to disk
+const SENSITIVE_MARKERS: &[&str] = &[
+    "api_key",
+    "apikey",
+    "api-key",
+    "password",
+    "passwd",
+    "secret",
+    "access_token",
+    "auth_token",
+    "bearer ",
+    "private key",
+];
+
 /// State for the input composer
 #[derive(Debug, Clone, Default)]
 pub struct InputState {
@@ -15,6 +41,102 @@ pub struct InputState {
     pub is_fork_mode: bool,
     /// Fork point index (when forking from a specific point in history)
     pub fork_point_index: Option<usize>,
+    /// Active reverse-incremental history search (Ctrl+R)
+    pub history_search: Option<HistorySearch>,
+    /// File that sent messages are appended to, if history is persisted
+    pub history_file: Option<HistoryFile>,
+}
+
+/// Per-working-directory message history persisted across sessions
+///
+/// Each line holds one JSON-encoded message, so multi-line prompts survive
+/// the round trip. The file is trimmed to `max_entries` as it grows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryFile {
+    path: PathBuf,
+    max_entries: usize,
+    /// Lines in the file, read on the first append and kept in step afterwards
+    lines: Option<VecDeque<String>>,
+}
+
+impl HistoryFile {
+    /// History file for the given working directory
+    pub fn for_workspace(working_dir: &Path) -> Self {
+        Self::new(working_dir.join(HISTORY_FILE))
+    }
+
+    pub fn new(path: PathBuf) -> Self {
+        Self { path, max_entries: DEFAULT_HISTORY_SIZE, lines: None }
+    }
+
+    /// Cap the number of messages kept in the file
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Load saved messages, oldest first, yielding nothing if the file is missing or unreadable
+    pub fn load(&self) -> Vec<String> {
+        let Ok(content) = std::fs::read_to_string(&self.path) else {
+            return Vec::new();
+        };
+        let messages: Vec<String> = content
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect();
+        let skip = messages.len().saturating_sub(self.max_entries);
+        messages.into_iter().skip(skip).collect()
+    }
+
+    /// Append a message, trimming the file once it exceeds the cap
+    ///
+    /// Messages that look like they contain credentials are not written.
+    pub fn append(&mut self, message: &str) -> std::io::Result<()> {
+        if self.max_entries == 0 || is_sensitive(message) {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let line = serde_json::to_string(message).map_err(std::io::Error::other)?;
+        let path = &self.path;
+        let lines = self.lines.get_or_insert_with(|| {
+            std::fs::read_to_string(path)
+                .map(|content| content.lines().map(str::to_string).collect())
+                .unwrap_or_default()
+        });
+        lines.push_back(line);
+
+        if lines.len() > self.max_entries {
+            let excess = lines.len() - self.max_entries;
+            lines.drain(..excess);
+            let content: String = lines.iter().map(|line| format!("{}\n", line)).collect();
+            return std::fs::write(path, content);
+        }
+
+        let mut file = std::fs::OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", lines.back().map(String::as_str).unwrap_or_default())
+    }
+}
+
+/// Check whether a message looks like it contains credentials
+pub fn is_sensitive(message: &str) -> bool {
+    let lower = message.to_lowercase();
+    SENSITIVE_MARKERS.iter().any(|marker| lower.contains(marker))
+}
+
+/// Reverse-incremental search over message history
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HistorySearch {
+    /// Text typed so far
+    pub query: String,
+    /// Position in the match list (0 = most recent match)
+    pub selected: usize,
 }
 
 impl InputState {
@@ -60,6 +182,57 @@ impl InputState {
         self.cursor = self.buffer.len();
     }
 
+    /// Number of lines in the buffer (an empty buffer has one line)
+    pub fn line_count(&self) -> usize {
+        self.buffer.split('\n').count()
+    }
+
+    /// Zero-based (line, column) of the cursor within the buffer
+    pub fn cursor_line_col(&self) -> (usize, usize) {
+        let before = &self.buffer[..self.cursor.min(self.buffer.len())];
+        let line = before.matches('\n').count();
+        let column = before
+            .rfind('\n')
+            .map_or(before.len(), |newline| before.len() - newline - 1);
+        (line, column)
+    }
+
+    /// Move the cursor to the previous line, keeping the column where possible
+    ///
+    /// Returns false when the cursor is already on the first line.
+    pub fn move_line_up(&mut self) -> bool {
+        let (line, column) = self.cursor_line_col();
+        if line == 0 {
+            return false;
+        }
+        self.cursor = self.line_offset(line - 1, column);
+        true
+    }
+
+    /// Move the cursor to the next line, keeping the column where possible
+    ///
+    /// Returns false when the cursor is already on the last line.
+    pub fn move_line_down(&mut self) -> bool {
+        let (line, column) = self.cursor_line_col();
+        if line + 1 >= self.line_count() {
+            return false;
+        }
+        self.cursor = self.line_offset(line + 1, column);
+        true
+    }
+
+    /// Byte offset of `column` on `line`, clamped to the line's length
+    fn line_offset(&self, line: usize, column: usize) -> usize {
+        let mut offset = 0;
+        for (index, text) in self.buffer.split('\n').enumerate() {
+            if index == line {
+                return offset + column.min(text.len());
+            }
+            offset += text.len() + 1;
+        }
+        self.buffer.len()
+    }
+
     pub fn clear(&mut self) {
         self.buffer.clear();
         self.cursor = 0;
@@ -71,6 +244,14 @@ impl InputState {
         buffer
     }
 
+    /// Load persisted history and append future messages to `file`
+    pub fn attach_history_file(&mut self, file: HistoryFile) {
+        let mut history = file.load();
+        history.append(&mut self.message_history);
+        self.message_history = history;
+        self.history_file = Some(file);
+    }
+
     /// Add a message to history (typically called after sending a message)
     pub fn add_to_history(&mut self, message: String) {
         if let Some(last) = self.message_history.last()
@@ -78,6 +259,11 @@ impl InputState {
         {
             return;
         }
+        if let Some(file) = &mut self.history_file
+            && let Err(e) = file.append(&message)
+        {
+            tracing::warn!("Failed to save message history to {}: {}", file.path().display(), e);
+        }
         self.message_history.push(message);
         self.reset_history_navigation();
     }
@@ -148,6 +334,96 @@ impl InputState {
         })
     }
 
+    /// Start a reverse-incremental search over message history
+    ///
+    /// Returns false when there is no history to search.
+    pub fn start_history_search(&mut self) -> bool {
+        if self.message_history.is_empty() {
+            return false;
+        }
+        self.history_search = Some(HistorySearch::default());
+        true
+    }
+
+    /// Check if a history search is active
+    pub fn is_searching_history(&self) -> bool {
+        self.history_search.is_some()
+    }
+
+    /// History indices matching the search query, most recent first
+    ///
+    /// Matching is case-insensitive and repeated messages appear once.
+    pub fn history_search_matches(&self) -> Vec<usize> {
+        let Some(search) = &self.history_search else {
+            return Vec::new();
+        };
+        let query = search.query.to_lowercase();
+        let mut seen = std::collections::HashSet::new();
+
+        self.message_history
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, message)| message.to_lowercase().contains(&query))
+            .filter(|(_, message)| seen.insert(message.as_str()))
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    /// The currently selected search match
+    pub fn history_search_match(&self) -> Option<&str> {
+        let selected = self.history_search.as_ref()?.selected;
+        let idx = *self.history_search_matches().get(selected)?;
+        self.message_history.get(idx).map(String::as_str)
+    }
+
+    /// Append a character to the search query
+    pub fn history_search_push(&mut self, c: char) {
+        if let Some(search) = &mut self.history_search {
+            search.query.push(c);
+            search.selected = 0;
+        }
+    }
+
+    /// Remove the last character from the search query
+    pub fn history_search_pop(&mut self) {
+        if let Some(search) = &mut self.history_search {
+            search.query.pop();
+            search.selected = 0;
+        }
+    }
+
+    /// Cycle to the next older match
+    pub fn history_search_older(&mut self) {
+        let count = self.history_search_matches().len();
+        if let Some(search) = &mut self.history_search
+            && search.selected + 1 < count
+        {
+            search.selected += 1;
+        }
+    }
+
+    /// Cycle to the next newer match
+    pub fn history_search_newer(&mut self) {
+        if let Some(search) = &mut self.history_search {
+            search.selected = search.selected.saturating_sub(1);
+        }
+    }
+
+    /// Insert the selected match into the buffer and end the search
+    pub fn accept_history_search(&mut self) {
+        if let Some(message) = self.history_search_match().map(str::to_string) {
+            self.buffer = message;
+            self.cursor = self.buffer.len();
+        }
+        self.history_search = None;
+    }
+
+    /// End the search, leaving the buffer untouched
+    pub fn cancel_history_search(&mut self) {
+        self.history_search = None;
+    }
+
     /// Enter fork mode at the current history index
     ///
     /// This marks that we're editing history and will replace the entry
@@ -242,6 +518,140 @@ mod tests {
         assert_eq!(input.cursor, 0);
     }
 
+    #[test]
+    fn test_input_state_multiline_cursor() {
+        let mut input = InputState::new();
+        input.buffer = "first\nsecond line\nend".to_string();
+        input.cursor = input.buffer.len();
+
+        assert_eq!(input.line_count(), 3);
+        assert_eq!(input.cursor_line_col(), (2, 3));
+
+        assert!(input.move_line_up());
+        assert_eq!(input.cursor_line_col(), (1, 3));
+
+        assert!(input.move_line_up());
+        assert_eq!(input.cursor_line_col(), (0, 3));
+        assert!(!input.move_line_up());
+
+        input.cursor = 17;
+        assert_eq!(input.cursor_line_col(), (1, 11));
+        assert!(input.move_line_down());
+        assert_eq!(input.cursor_line_col(), (2, 3));
+        assert!(!input.move_line_down());
+    }
+
+    #[test]
+    fn test_input_state_history_search() {
+        let mut input = InputState::new();
+        assert!(!input.start_history_search());
+
+        input.add_to_history("cargo build".to_string());
+        input.add_to_history("fix the parser".to_string());
+        input.add_to_history("cargo test".to_string());
+        input.add_to_history("Cargo build".to_string());
+
+        assert!(input.start_history_search());
+        for c in "cargo".chars() {
+            input.history_search_push(c);
+        }
+        assert_eq!(input.history_search_matches(), vec![3, 2, 0]);
+        assert_eq!(input.history_search_match(), Some("Cargo build"));
+
+        input.history_search_older();
+        assert_eq!(input.history_search_match(), Some("cargo test"));
+        input.history_search_older();
+        input.history_search_older();
+        assert_eq!(input.history_search_match(), Some("cargo build"));
+        input.history_search_newer();
+        assert_eq!(input.history_search_match(), Some("cargo test"));
+
+        input.history_search_push('x');
+        assert_eq!(input.history_search_match(), None);
+        input.history_search_pop();
+        assert_eq!(input.history_search_match(), Some("Cargo build"));
+
+        input.accept_history_search();
+        assert!(!input.is_searching_history());
+        assert_eq!(input.buffer, "Cargo build");
+        assert_eq!(input.cursor, 11);
+    }
+
+    #[test]
+    fn test_input_state_history_search_cancel_keeps_buffer() {
+        let mut input = InputState::new();
+        input.add_to_history("hello".to_string());
+        input.buffer = "draft".to_string();
+
+        input.start_history_search();
+        input.history_search_push('h');
+        input.cancel_history_search();
+
+        assert!(!input.is_searching_history());
+        assert_eq!(input.buffer, "draft");
+    }
+
+    #[test]
+    fn test_history_file_round_trip() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let file = HistoryFile::for_workspace(temp.path()).with_max_entries(3);
+
+        let mut input = InputState::new();
+        input.attach_history_file(file.clone());
+        assert!(input.message_history.is_empty());
+
+        input.add_to_history("first".to_string());
+        input.add_to_history("second\nwith a newline".to_string());
+        input.add_to_history("export API_KEY=abc123".to_string());
+        input.add_to_history("third".to_string());
+        input.add_to_history("fourth".to_string());
+        assert_eq!(input.message_history.len(), 5);
+
+        let mut restored = InputState::new();
+        restored.attach_history_file(file);
+        assert_eq!(
+            restored.message_history,
+            vec!["second\nwith a newline", "third", "fourth"]
+        );
+        assert_eq!(
+            std::fs::read_to_string(temp.path().join(HISTORY_FILE))
+                .unwrap()
+                .lines()
+                .count(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_history_file_disabled_with_zero_cap() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut file = HistoryFile::for_workspace(temp.path()).with_max_entries(0);
+        file.append("hello").unwrap();
+        assert!(!file.path().exists());
+    }
+
+    #[test]
+    fn test_history_file_reads_file_once() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut file = HistoryFile::for_workspace(temp.path()).with_max_entries(2);
+        std::fs::create_dir_all(file.path().parent().unwrap()).unwrap();
+        std::fs::write(file.path(), "\"old\"\n").unwrap();
+
+        file.append("first").unwrap();
+        std::fs::write(file.path(), "\"old\"\n\"written elsewhere\"\n\"first\"\n").unwrap();
+        file.append("second").unwrap();
+
+        assert_eq!(file.load(), vec!["first", "second"]);
+    }
+
+    #[test]
+    fn test_is_sensitive() {
+        assert!(is_sensitive("my api_key is 123"));
+        assert!(is_sensitive("Authorization: Bearer abc"));
+        assert!(is_sensitive("the PASSWORD is hunter2"));
+        assert!(!is_sensitive("refactor the token counter"));
+    }
+
     #[test]
     fn test_input_state_navigation() {
         let mut input = InputState::new();
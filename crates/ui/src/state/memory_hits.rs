@@ -3,6 +3,7 @@
 //! Manages the state for displaying memory search results in the TUI.
 
 use std::collections::HashSet;
+use thunderus_core::memory::{MAX_PINNED_DOCS, MemoryPaths, PinnedMemory};
 use thunderus_store::SearchHit;
 
 /// State for the memory hits panel
@@ -76,8 +77,15 @@ impl MemoryHitsState {
     }
 
     /// Pin a document
-    pub fn pin(&mut self, id: String) {
+    ///
+    /// Returns `false` if the document could not be pinned because
+    /// [`MAX_PINNED_DOCS`] documents are already pinned.
+    pub fn pin(&mut self, id: String) -> bool {
+        if !self.pinned_ids.contains(&id) && self.pinned_ids.len() >= MAX_PINNED_DOCS {
+            return false;
+        }
         self.pinned_ids.insert(id);
+        true
     }
 
     /// Unpin a document
@@ -86,14 +94,32 @@ impl MemoryHitsState {
     }
 
     /// Toggle pin state for a document
-    pub fn toggle_pin(&mut self, id: &str) {
+    ///
+    /// Returns `false` if the document could not be pinned because
+    /// [`MAX_PINNED_DOCS`] documents are already pinned.
+    pub fn toggle_pin(&mut self, id: &str) -> bool {
         if self.is_pinned(id) {
             self.unpin(id);
+            true
         } else {
-            self.pin(id.to_string());
+            self.pin(id.to_string())
         }
     }
 
+    /// Load pinned document IDs from the persisted pin list
+    pub fn load_pins(&mut self, paths: &MemoryPaths) -> thunderus_core::Result<()> {
+        let pins = PinnedMemory::load(paths)?;
+        self.pinned_ids = pins.ids.into_iter().collect();
+        Ok(())
+    }
+
+    /// Persist pinned document IDs so retrieval and future sessions see them
+    pub fn save_pins(&self, paths: &MemoryPaths) -> thunderus_core::Result<()> {
+        let mut ids: Vec<String> = self.pinned_ids.iter().cloned().collect();
+        ids.sort();
+        PinnedMemory { ids }.save(paths)
+    }
+
     /// Get the number of pinned documents
     pub fn pinned_count(&self) -> usize {
         self.pinned_ids.len()
@@ -103,6 +129,20 @@ impl MemoryHitsState {
     pub fn pinned_ids(&self) -> &HashSet<String> {
         &self.pinned_ids
     }
+
+    /// Total approximate tokens across all current hits
+    pub fn total_tokens(&self) -> usize {
+        self.hits.iter().map(|hit| hit.token_count).sum()
+    }
+
+    /// Approximate tokens of the current hits that are pinned
+    pub fn pinned_tokens(&self) -> usize {
+        self.hits
+            .iter()
+            .filter(|hit| self.is_pinned(&hit.id))
+            .map(|hit| hit.token_count)
+            .sum()
+    }
 }
 
 #[cfg(test)]
@@ -120,6 +160,9 @@ mod tests {
             snippet: format!("Test snippet for {}", title),
             score: -5.0,
             event_ids: vec![],
+            token_count: 0,
+            verification: None,
+            verified_commit: None,
         }
     }
 
@@ -143,6 +186,21 @@ mod tests {
         assert_eq!(state.selected_index, 0);
     }
 
+    #[test]
+    fn test_token_totals() {
+        let mut state = MemoryHitsState::new();
+        let mut hits = vec![create_test_hit("test-1", "Test 1"), create_test_hit("test-2", "Test 2")];
+        hits[0].token_count = 120;
+        hits[1].token_count = 80;
+        state.set_hits(hits, "query".to_string(), 10);
+        assert_eq!(state.total_tokens(), 200);
+        assert_eq!(state.pinned_tokens(), 0);
+
+        state.pin("test-2".to_string());
+        state.pin("elsewhere".to_string());
+        assert_eq!(state.pinned_tokens(), 80);
+    }
+
     #[test]
     fn test_clear() {
         let mut state = MemoryHitsState::new();
@@ -229,6 +287,34 @@ mod tests {
         assert!(state.pinned_ids().contains("test-2"));
     }
 
+    #[test]
+    fn test_pin_limit() {
+        let mut state = MemoryHitsState::new();
+        for i in 0..MAX_PINNED_DOCS {
+            assert!(state.pin(format!("test-{}", i)));
+        }
+
+        assert!(!state.pin("overflow".to_string()));
+        assert!(!state.toggle_pin("overflow"));
+        assert!(!state.is_pinned("overflow"));
+        assert!(state.pin("test-0".to_string()));
+        assert_eq!(state.pinned_count(), MAX_PINNED_DOCS);
+    }
+
+    #[test]
+    fn test_pins_persist() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let paths = MemoryPaths::from_thunderus_root(temp.path());
+
+        let mut state = MemoryHitsState::new();
+        state.pin("test-1".to_string());
+        state.save_pins(&paths).unwrap();
+
+        let mut restored = MemoryHitsState::new();
+        restored.load_pins(&paths).unwrap();
+        assert!(restored.is_pinned("test-1"));
+    }
+
     #[test]
     fn test_empty_hits_not_visible() {
         let mut state = MemoryHitsState::new();
@@ -1,3 +1,4 @@
+mod activity;
 mod app_state;
 mod approval;
 mod composer;
@@ -14,18 +15,20 @@ mod sidebar;
 mod ui;
 mod welcome;
 
+pub use activity::Activity;
 pub use app_state::AppState;
-pub use approval::ApprovalState;
+pub use approval::{ApprovalState, ApprovalTimeout};
 pub use composer::{ComposerMode, ComposerState};
-pub use config::{ConfigState, GitDiff, ModifiedFile, SessionEvent, VerbosityLevel};
+pub use config::{ConfigState, ModifiedFile, SessionEvent, VerbosityLevel};
 pub use config_editor::{ConfigEditorField, ConfigEditorState};
 pub use evidence::EvidenceState;
 pub use exit::ExitState;
 pub use header::HeaderState;
-pub use input::InputState;
+pub use input::{DEFAULT_HISTORY_SIZE, HISTORY_FILE, HistoryFile, HistorySearch, InputState};
 pub use memory_hits::MemoryHitsState;
 pub use model_selector::ModelSelectorState;
 pub use session::{SessionStats, SessionTrackingState};
 pub use sidebar::{SidebarCollapseState, SidebarSection};
+pub use thunderus_core::GitDiff;
 pub use ui::{ApprovalUIState, DiffNavigationState, MainView, UIState};
 pub use welcome::{RecentSessionInfo, WELCOME_TIPS, WelcomeState};
@@ -1,4 +1,7 @@
+use std::collections::BTreeMap;
+use std::time::Instant;
 use thunderus_core::{MemoryPatch, Patch, TokensUsed};
+use thunderus_skills::SkillMeta;
 
 /// Session statistics for the UI
 #[derive(Debug, Clone, Default)]
@@ -11,6 +14,8 @@ pub struct SessionStats {
     pub approval_gates: u32,
     /// Number of tools executed
     pub tools_executed: u32,
+    /// Tool calls made, keyed by tool name
+    pub tool_calls: BTreeMap<String, u32>,
 }
 
 impl SessionStats {
@@ -30,6 +35,12 @@ impl SessionStats {
     pub fn increment_tools_executed(&mut self) {
         self.tools_executed += 1;
     }
+
+    /// Count a tool call, both in total and per tool name
+    pub fn record_tool_call(&mut self, name: &str) {
+        self.increment_tools_executed();
+        *self.tool_calls.entry(name.to_string()).or_default() += 1;
+    }
 }
 
 /// Session tracking data
@@ -49,8 +60,20 @@ pub struct SessionTrackingState {
     pub patches: Vec<Patch>,
     /// Memory patches in the queue
     pub memory_patches: Vec<MemoryPatch>,
+    /// Skills registered with the agent's tool registry
+    pub skills: Vec<SkillMeta>,
     /// Last user message sent (for retry functionality)
     pub last_message: Option<String>,
+    /// Finish reason reported for the response currently streaming
+    pub last_finish_reason: Option<String>,
+    /// Tool the model has started calling while its arguments are still streaming
+    pub pending_tool_call: Option<String>,
+    /// Whether the last response was cut off by `max_tokens` (for /continue)
+    pub response_truncated: bool,
+    /// When this session was opened in the TUI
+    pub started_at: Instant,
+    /// Current agent phase for the footer status line
+    pub activity: super::Activity,
 }
 
 impl SessionTrackingState {
@@ -63,7 +86,13 @@ impl SessionTrackingState {
             git_diff_queue: Vec::new(),
             patches: Vec::new(),
             memory_patches: Vec::new(),
+            skills: Vec::new(),
             last_message: None,
+            last_finish_reason: None,
+            pending_tool_call: None,
+            response_truncated: false,
+            started_at: Instant::now(),
+            activity: super::Activity::ready(),
         }
     }
 }
@@ -94,5 +123,12 @@ mod tests {
 
         stats.increment_tools_executed();
         assert_eq!(stats.tools_executed, 1);
+
+        stats.record_tool_call("read");
+        stats.record_tool_call("read");
+        stats.record_tool_call("grep");
+        assert_eq!(stats.tools_executed, 4);
+        assert_eq!(stats.tool_calls.get("read"), Some(&2));
+        assert_eq!(stats.tool_calls.get("grep"), Some(&1));
     }
 }
@@ -6,18 +6,20 @@ pub enum SidebarSection {
     Modified,
     Diffs,
     Integrations,
+    Skills,
     Context,
     Files,
 }
 
 impl SidebarSection {
-    pub fn all() -> [SidebarSection; 7] {
+    pub fn all() -> [SidebarSection; 8] {
         [
             Self::TokenUsage,
             Self::Events,
             Self::Modified,
             Self::Diffs,
             Self::Integrations,
+            Self::Skills,
             Self::Context,
             Self::Files,
         ]
@@ -30,6 +32,7 @@ impl SidebarSection {
             SidebarSection::Modified => "Modified",
             SidebarSection::Diffs => "Diffs",
             SidebarSection::Integrations => "Integrations",
+            SidebarSection::Skills => "Skills",
             SidebarSection::Context => "Context",
             SidebarSection::Files => "Files",
         }
@@ -44,6 +47,7 @@ pub struct SidebarCollapseState {
     modified_collapsed: bool,
     diffs_collapsed: bool,
     integrations_collapsed: bool,
+    skills_collapsed: bool,
     context_collapsed: bool,
     files_collapsed: bool,
 }
@@ -56,6 +60,7 @@ impl SidebarCollapseState {
             SidebarSection::Modified => self.modified_collapsed,
             SidebarSection::Diffs => self.diffs_collapsed,
             SidebarSection::Integrations => self.integrations_collapsed,
+            SidebarSection::Skills => self.skills_collapsed,
             SidebarSection::Context => self.context_collapsed,
             SidebarSection::Files => self.files_collapsed,
         }
@@ -68,6 +73,7 @@ impl SidebarCollapseState {
             SidebarSection::Modified => self.modified_collapsed = !self.modified_collapsed,
             SidebarSection::Diffs => self.diffs_collapsed = !self.diffs_collapsed,
             SidebarSection::Integrations => self.integrations_collapsed = !self.integrations_collapsed,
+            SidebarSection::Skills => self.skills_collapsed = !self.skills_collapsed,
             SidebarSection::Context => self.context_collapsed = !self.context_collapsed,
             SidebarSection::Files => self.files_collapsed = !self.files_collapsed,
         }
@@ -1,4 +1,5 @@
 use crate::theme::ThemeVariant;
+use std::time::{Duration, Instant};
 
 /// Main view modes for the TUI
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -8,6 +9,8 @@ pub enum MainView {
     Transcript,
     /// Inspector view (Trajectory/Provenance)
     Inspector,
+    /// Full-screen diff review
+    DiffReview,
 }
 
 /// Agent execution status
@@ -167,6 +170,26 @@ pub struct UIState {
     pub theme_variant: ThemeVariant,
     /// Currently active main view
     pub active_view: MainView,
+    /// View to restore when leaving the diff review
+    pub review_return_view: MainView,
+    /// Line count above which tool result cards render collapsed (0 disables)
+    pub tool_output_collapse_lines: usize,
+    /// Whether the transcript view is pinned to the bottom and follows new output
+    pub follow_transcript: bool,
+    /// Whether output arrived below the viewport while the user was scrolled up
+    pub new_content_below: bool,
+    /// Largest vertical scroll offset for the last drawn transcript
+    pub transcript_max_scroll: u16,
+    /// Rendered transcript height (in lines) at the last draw
+    pub transcript_content_height: usize,
+    /// Minimum time between agent-driven redraws; events arriving sooner are coalesced (zero disables)
+    pub redraw_interval: Duration,
+    /// Whether the streaming ellipsis, spinner and sidebar slide animate
+    pub animations: bool,
+    /// When the last frame was drawn
+    pub last_draw: Option<Instant>,
+    /// Whether a throttled redraw is waiting for the interval to pass
+    pub redraw_pending: bool,
 }
 
 impl UIState {
@@ -183,9 +206,47 @@ impl UIState {
             animation_frame: 0,
             theme_variant: ThemeVariant::Iceberg,
             active_view: MainView::Transcript,
+            review_return_view: MainView::Transcript,
+            tool_output_collapse_lines: crate::transcript::DEFAULT_TOOL_OUTPUT_COLLAPSE_LINES,
+            follow_transcript: true,
+            new_content_below: false,
+            transcript_max_scroll: 0,
+            transcript_content_height: 0,
+            redraw_interval: Duration::ZERO,
+            animations: true,
+            last_draw: None,
+            redraw_pending: false,
         }
     }
 
+    /// Whether a throttled redraw may happen at `now`
+    ///
+    /// When it may not, the redraw is remembered so [`UIState::redraw_due`]
+    /// picks it up once the interval has passed.
+    pub fn should_redraw(&mut self, now: Instant) -> bool {
+        let ready = self
+            .last_draw
+            .is_none_or(|last| now.saturating_duration_since(last) >= self.redraw_interval);
+        if !ready {
+            self.redraw_pending = true;
+        }
+        ready
+    }
+
+    /// Whether a coalesced redraw is pending and its interval has passed
+    pub fn redraw_due(&self, now: Instant) -> bool {
+        self.redraw_pending
+            && self
+                .last_draw
+                .is_none_or(|last| now.saturating_duration_since(last) >= self.redraw_interval)
+    }
+
+    /// Record that a frame was drawn at `now`
+    pub fn mark_drawn(&mut self, now: Instant) {
+        self.last_draw = Some(now);
+        self.redraw_pending = false;
+    }
+
     /// Advance animation frame for streaming ellipsis (cycles 0-3)
     pub fn advance_animation_frame(&mut self) {
         self.animation_frame = (self.animation_frame + 1) % 4;
@@ -217,16 +278,18 @@ impl UIState {
 
         if self.sidebar_visible {
             self.sidebar_visible = false;
-            self.sidebar_animation = Some(SidebarAnimation::Hiding { width: SIDEBAR_WIDTH });
+            self.sidebar_animation = self
+                .animations
+                .then_some(SidebarAnimation::Hiding { width: SIDEBAR_WIDTH });
         } else {
             self.sidebar_visible = true;
-            self.sidebar_animation = Some(SidebarAnimation::Showing { width: 0 });
+            self.sidebar_animation = self.animations.then_some(SidebarAnimation::Showing { width: 0 });
         }
     }
 
     pub fn sidebar_width_override(&self) -> Option<u16> {
         match self.active_view {
-            MainView::Inspector => None,
+            MainView::Inspector | MainView::DiffReview => None,
             _ => match self.sidebar_animation {
                 Some(SidebarAnimation::Showing { width }) => Some(width),
                 Some(SidebarAnimation::Hiding { width }) => Some(width),
@@ -323,9 +386,48 @@ impl UIState {
     }
 
     /// Scroll transcript vertically
+    ///
+    /// Scrolling up leaves follow mode; scrolling back down to the bottom re-enters it.
     pub fn scroll_vertical(&mut self, delta: i16) {
-        let new_offset = self.scroll_vertical as i16 + delta;
-        self.scroll_vertical = new_offset.max(0) as u16;
+        let new_offset = self.scroll_vertical as i32 + delta as i32;
+        self.scroll_vertical = new_offset.clamp(0, u16::MAX as i32) as u16;
+
+        if delta < 0 && self.transcript_max_scroll > 0 {
+            self.follow_transcript = false;
+        } else if delta > 0 && self.scroll_vertical >= self.transcript_max_scroll {
+            self.follow_transcript = true;
+            self.new_content_below = false;
+        }
+    }
+
+    /// Jump to the top of the transcript and stop following new output
+    pub fn scroll_transcript_to_top(&mut self) {
+        self.scroll_vertical = 0;
+        self.follow_transcript = self.transcript_max_scroll == 0;
+    }
+
+    /// Jump to the bottom of the transcript and follow new output
+    pub fn scroll_transcript_to_bottom(&mut self) {
+        self.scroll_vertical = self.transcript_max_scroll;
+        self.follow_transcript = true;
+        self.new_content_below = false;
+    }
+
+    /// Reconcile scroll state with the transcript as just drawn
+    ///
+    /// While following, the view stays pinned to the bottom. Otherwise the
+    /// position is kept and growth below the viewport is flagged; reaching the
+    /// bottom again (e.g. after the content shrinks) resumes following.
+    pub fn update_transcript_viewport(&mut self, content_height: usize, max_scroll: u16) {
+        let grew = content_height > self.transcript_content_height;
+        self.transcript_content_height = content_height;
+        self.transcript_max_scroll = max_scroll;
+
+        if self.follow_transcript || self.scroll_vertical >= max_scroll {
+            self.scroll_transcript_to_bottom();
+        } else if grew {
+            self.new_content_below = true;
+        }
     }
 
     /// Reset scroll to top-left
@@ -337,10 +439,29 @@ impl UIState {
     /// Toggle Inspector view
     pub fn toggle_inspector(&mut self) {
         self.active_view = match self.active_view {
-            MainView::Transcript => MainView::Inspector,
+            MainView::Transcript | MainView::DiffReview => MainView::Inspector,
             MainView::Inspector => MainView::Transcript,
         };
     }
+
+    /// Open the full-screen diff review, remembering the view to return to
+    pub fn enter_diff_review(&mut self) {
+        if self.active_view != MainView::DiffReview {
+            self.review_return_view = self.active_view;
+            self.active_view = MainView::DiffReview;
+        }
+    }
+
+    /// Leave the diff review and restore the previous view
+    pub fn exit_diff_review(&mut self) {
+        if self.active_view == MainView::DiffReview {
+            self.active_view = self.review_return_view;
+        }
+    }
+
+    pub fn is_diff_review(&self) -> bool {
+        self.active_view == MainView::DiffReview
+    }
 }
 
 impl Default for UIState {
@@ -356,6 +477,8 @@ pub struct ApprovalUIState {
     pub pending_approval: Option<super::ApprovalState>,
     /// Pending teaching hint (if any)
     pub pending_hint: Option<String>,
+    /// Concept ID of the pending teaching hint, when it can be dismissed permanently
+    pub pending_hint_concept: Option<String>,
     /// Pending shell command (for !cmd execution after approval)
     pub pending_command: Option<String>,
 }
@@ -367,11 +490,19 @@ impl ApprovalUIState {
     /// presses any key to dismiss it.
     pub fn show_hint(&mut self, hint: impl Into<String>) {
         self.pending_hint = Some(hint.into());
+        self.pending_hint_concept = None;
+    }
+
+    /// Show a teaching hint for a concept that the user can dismiss permanently
+    pub fn show_concept_hint(&mut self, concept: impl Into<String>, hint: impl Into<String>) {
+        self.pending_hint = Some(hint.into());
+        self.pending_hint_concept = Some(concept.into());
     }
 
     /// Dismiss the current teaching hint if one is shown
     pub fn dismiss_hint(&mut self) {
         self.pending_hint = None;
+        self.pending_hint_concept = None;
     }
 
     /// Check if a teaching hint is currently shown
@@ -384,6 +515,37 @@ impl ApprovalUIState {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_redraw_throttle_coalesces() {
+        let mut state = UIState::default();
+        let start = Instant::now();
+        assert!(state.should_redraw(start));
+        state.mark_drawn(start);
+        assert!(state.should_redraw(start), "zero interval never throttles");
+
+        state.redraw_interval = Duration::from_millis(100);
+        assert!(!state.should_redraw(start + Duration::from_millis(30)));
+        assert!(!state.should_redraw(start + Duration::from_millis(60)));
+        assert!(state.redraw_pending);
+        assert!(!state.redraw_due(start + Duration::from_millis(90)));
+        assert!(state.redraw_due(start + Duration::from_millis(100)));
+
+        state.mark_drawn(start + Duration::from_millis(100));
+        assert!(!state.redraw_due(start + Duration::from_millis(500)));
+        assert!(state.should_redraw(start + Duration::from_millis(200)));
+    }
+
+    #[test]
+    fn test_sidebar_toggle_without_animations() {
+        let mut state = UIState { animations: false, ..UIState::default() };
+        state.toggle_sidebar();
+        assert!(!state.sidebar_visible);
+        assert!(state.sidebar_animation.is_none());
+        state.toggle_sidebar();
+        assert!(state.sidebar_visible);
+        assert!(state.sidebar_animation.is_none());
+    }
+
     #[test]
     fn test_scroll_horizontal() {
         let mut state = UIState::default();
@@ -415,6 +577,55 @@ mod tests {
         assert_eq!(state.scroll_vertical, 0);
     }
 
+    #[test]
+    fn test_transcript_follow_mode() {
+        let mut state = UIState::default();
+        assert!(state.follow_transcript);
+
+        state.update_transcript_viewport(100, 60);
+        assert_eq!(state.scroll_vertical, 60);
+        assert!(state.follow_transcript);
+
+        state.update_transcript_viewport(110, 70);
+        assert_eq!(state.scroll_vertical, 70);
+        assert!(!state.new_content_below);
+
+        state.scroll_vertical(-10);
+        assert!(!state.follow_transcript);
+        assert_eq!(state.scroll_vertical, 60);
+
+        state.update_transcript_viewport(120, 80);
+        assert_eq!(state.scroll_vertical, 60);
+        assert!(state.new_content_below);
+
+        state.scroll_vertical(10);
+        assert!(!state.follow_transcript);
+        state.scroll_vertical(10);
+        assert!(state.follow_transcript);
+        assert!(!state.new_content_below);
+
+        state.update_transcript_viewport(120, 80);
+        assert_eq!(state.scroll_vertical, 80);
+    }
+
+    #[test]
+    fn test_transcript_jump_to_top_and_bottom() {
+        let mut state = UIState::default();
+        state.update_transcript_viewport(100, 60);
+
+        state.scroll_transcript_to_top();
+        assert_eq!(state.scroll_vertical, 0);
+        assert!(!state.follow_transcript);
+
+        state.update_transcript_viewport(130, 90);
+        assert!(state.new_content_below);
+
+        state.scroll_transcript_to_bottom();
+        assert_eq!(state.scroll_vertical, 90);
+        assert!(state.follow_transcript);
+        assert!(!state.new_content_below);
+    }
+
     #[test]
     fn test_reset_scroll() {
         let mut state = UIState::default();
@@ -1,13 +1,90 @@
 use ratatui::style::{Color, Style};
 use ratatui::text::Span;
+use std::sync::OnceLock;
 use syntect::easy::HighlightLines;
 use syntect::highlighting::{Theme, ThemeSet};
-use syntect::parsing::SyntaxSet;
+use syntect::parsing::{SyntaxDefinition, SyntaxReference, SyntaxSet};
 use syntect::util::LinesWithEndings;
 
+/// Syntax definitions for languages missing from syntect's default set
+const EXTRA_SYNTAXES: &[&str] = &[TOML_SYNTAX, DOCKERFILE_SYNTAX];
+
+const TOML_SYNTAX: &str = r#"%YAML 1.2
+---
+name: TOML
+file_extensions: [toml, tml]
+scope: source.toml
+contexts:
+  main:
+    - match: '#.*$'
+      scope: comment.line.number-sign.toml
+    - match: '^\s*(\[\[?)([^\]]*)(\]\]?)'
+      captures:
+        1: punctuation.definition.table.toml
+        2: entity.name.section.toml
+        3: punctuation.definition.table.toml
+    - match: '([A-Za-z0-9_.\-]+|"[^"]*")\s*(=)'
+      captures:
+        1: entity.name.tag.toml
+        2: keyword.operator.assignment.toml
+    - match: '"""'
+      push: multiline_string
+    - match: '"(?:[^"\\]|\\.)*"'
+      scope: string.quoted.double.toml
+    - match: "'[^']*'"
+      scope: string.quoted.single.toml
+    - match: '\b(true|false)\b'
+      scope: constant.language.boolean.toml
+    - match: '\b\d{4}-\d{2}-\d{2}(?:[T ]\d{2}:\d{2}(?::\d{2}(?:\.\d+)?)?)?(?:Z|[+-]\d{2}:\d{2})?'
+      scope: constant.other.datetime.toml
+    - match: '[+-]?(?:0x[0-9A-Fa-f_]+|0o[0-7_]+|0b[01_]+|\d[\d_]*(?:\.[\d_]+)?(?:[eE][+-]?\d+)?|inf|nan)\b'
+      scope: constant.numeric.toml
+  multiline_string:
+    - meta_scope: string.quoted.triple.toml
+    - match: '"""'
+      pop: true
+"#;
+
+const DOCKERFILE_SYNTAX: &str = r#"%YAML 1.2
+---
+name: Dockerfile
+file_extensions: [dockerfile, containerfile]
+scope: source.dockerfile
+contexts:
+  main:
+    - match: '^\s*#.*$'
+      scope: comment.line.number-sign.dockerfile
+    - match: '(?i)^\s*(FROM|RUN|CMD|LABEL|MAINTAINER|EXPOSE|ENV|ADD|COPY|ENTRYPOINT|VOLUME|USER|WORKDIR|ARG|ONBUILD|STOPSIGNAL|HEALTHCHECK|SHELL)\b'
+      scope: keyword.other.instruction.dockerfile
+    - match: '(?i)\bAS\b'
+      scope: keyword.other.dockerfile
+    - match: '"(?:[^"\\]|\\.)*"'
+      scope: string.quoted.double.dockerfile
+    - match: "'[^']*'"
+      scope: string.quoted.single.dockerfile
+    - match: '\$\{?[A-Za-z_][A-Za-z0-9_]*\}?'
+      scope: variable.other.dockerfile
+    - match: '--[a-z][a-z-]*'
+      scope: variable.parameter.dockerfile
+"#;
+
+/// Shared syntax set: syntect defaults plus [`EXTRA_SYNTAXES`], built once
+fn syntax_set() -> &'static SyntaxSet {
+    static SYNTAX_SET: OnceLock<SyntaxSet> = OnceLock::new();
+    SYNTAX_SET.get_or_init(|| {
+        let mut builder = SyntaxSet::load_defaults_newlines().into_builder();
+        for source in EXTRA_SYNTAXES {
+            if let Ok(definition) = SyntaxDefinition::load_from_str(source, true, None) {
+                builder.add(definition);
+            }
+        }
+        builder.build()
+    })
+}
+
 /// Syntax highlighter for code blocks
 pub struct SyntaxHighlighter {
-    syntax_set: SyntaxSet,
+    syntax_set: &'static SyntaxSet,
     theme: Theme,
 }
 
@@ -15,20 +92,37 @@ impl SyntaxHighlighter {
     /// Create a new syntax highlighter with default settings
     pub fn new() -> Self {
         let theme_set = ThemeSet::load_defaults();
-        Self {
-            syntax_set: SyntaxSet::load_defaults_newlines(),
-            theme: theme_set.themes["base16-ocean.dark"].clone(),
-        }
+        Self { syntax_set: syntax_set(), theme: theme_set.themes["base16-ocean.dark"].clone() }
+    }
+
+    /// Find the syntax for a code fence language tag
+    ///
+    /// Matching is case-insensitive and accepts common aliases (`yml`,
+    /// `docker`, `psql`, ...). Unknown tags resolve to plain text.
+    pub fn find_syntax(&self, lang: &str) -> &SyntaxReference {
+        let lang = lang.trim().to_ascii_lowercase();
+        let token = match lang.as_str() {
+            "yml" => "yaml",
+            "docker" => "dockerfile",
+            "postgres" | "postgresql" | "psql" | "mysql" | "sqlite" | "plsql" => "sql",
+            other => other,
+        };
+
+        self.syntax_set
+            .find_syntax_by_token(token)
+            .or_else(|| self.syntax_set.find_syntax_by_extension(token))
+            .or_else(|| {
+                self.syntax_set
+                    .syntaxes()
+                    .iter()
+                    .find(|syntax| syntax.name.eq_ignore_ascii_case(token))
+            })
+            .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text())
     }
 
     /// Highlight a code block and return styled spans
     pub fn highlight_code(&self, code: &str, lang: &str) -> Vec<Span<'static>> {
-        let syntax = self
-            .syntax_set
-            .find_syntax_by_token(lang)
-            .or_else(|| self.syntax_set.find_syntax_by_name(lang))
-            .or_else(|| self.syntax_set.find_syntax_by_extension(lang))
-            .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text());
+        let syntax = self.find_syntax(lang);
 
         let mut highlighter = HighlightLines::new(syntax, &self.theme);
 
@@ -36,7 +130,7 @@ impl SyntaxHighlighter {
         let mut current_color = self.text_color();
 
         for line in LinesWithEndings::from(code) {
-            if let Ok(ranges) = highlighter.highlight_line(line, &self.syntax_set) {
+            if let Ok(ranges) = highlighter.highlight_line(line, self.syntax_set) {
                 for (style, text) in ranges {
                     let color = self.syntect_to_ratatui_color(&style.foreground);
                     let span_text = text.to_string();
@@ -59,6 +153,36 @@ impl SyntaxHighlighter {
         spans
     }
 
+    /// Highlight a code block and return styled spans grouped per source line
+    ///
+    /// Line endings are stripped, so each entry can be rendered as its own
+    /// `Line` without breaking surrounding layout.
+    pub fn highlight_lines(&self, code: &str, lang: &str) -> Vec<Vec<Span<'static>>> {
+        let syntax = self.find_syntax(lang);
+        let mut highlighter = HighlightLines::new(syntax, &self.theme);
+        let fallback = Style::default().fg(self.text_color());
+
+        LinesWithEndings::from(code)
+            .map(|line| {
+                let stripped = line.trim_end_matches(['\n', '\r']);
+                match highlighter.highlight_line(line, self.syntax_set) {
+                    Ok(ranges) => ranges
+                        .into_iter()
+                        .map(|(style, text)| (style, text.trim_end_matches(['\n', '\r'])))
+                        .filter(|(_, text)| !text.is_empty())
+                        .map(|(style, text)| {
+                            Span::styled(
+                                text.to_string(),
+                                Style::default().fg(self.syntect_to_ratatui_color(&style.foreground)),
+                            )
+                        })
+                        .collect(),
+                    Err(_) => vec![Span::styled(stripped.to_string(), fallback)],
+                }
+            })
+            .collect()
+    }
+
     /// Convert syntect color to ratatui color
     fn syntect_to_ratatui_color(&self, color: &syntect::highlighting::Color) -> Color {
         Color::Rgb(color.r, color.g, color.b)
@@ -123,6 +247,72 @@ mod tests {
         assert!(!spans.is_empty());
     }
 
+    fn distinct_colors(spans: &[Span<'static>]) -> usize {
+        let mut colors: Vec<Color> = spans.iter().filter_map(|s| s.style.fg).collect();
+        colors.sort_by_key(|c| format!("{:?}", c));
+        colors.dedup();
+        colors.len()
+    }
+
+    #[test]
+    fn test_find_syntax_for_config_languages() {
+        let highlighter = SyntaxHighlighter::new();
+        assert_eq!(highlighter.find_syntax("toml").name, "TOML");
+        assert_eq!(highlighter.find_syntax("yaml").name, "YAML");
+        assert_eq!(highlighter.find_syntax("yml").name, "YAML");
+        assert_eq!(highlighter.find_syntax("Dockerfile").name, "Dockerfile");
+        assert_eq!(highlighter.find_syntax("sql").name, "SQL");
+    }
+
+    #[test]
+    fn test_find_syntax_case_insensitive() {
+        let highlighter = SyntaxHighlighter::new();
+        assert_eq!(highlighter.find_syntax("TOML").name, "TOML");
+        assert_eq!(highlighter.find_syntax("YAML").name, "YAML");
+        assert_eq!(highlighter.find_syntax("DOCKERFILE").name, "Dockerfile");
+        assert_eq!(highlighter.find_syntax("SQL").name, "SQL");
+        assert_eq!(highlighter.find_syntax(" Rust ").name, "Rust");
+    }
+
+    #[test]
+    fn test_find_syntax_unknown_is_plain_text() {
+        let highlighter = SyntaxHighlighter::new();
+        assert_eq!(highlighter.find_syntax("unknownlangxyz").name, "Plain Text");
+        assert_eq!(highlighter.find_syntax("").name, "Plain Text");
+    }
+
+    #[test]
+    fn test_highlight_new_languages() {
+        let highlighter = SyntaxHighlighter::new();
+        let samples = [
+            ("toml", "[package]\nname = \"thunderus\" # crate\nversion = 1\n"),
+            ("yaml", "name: thunderus\nsteps:\n  - run: cargo test # ci\n"),
+            ("dockerfile", "FROM rust:1.85 AS build\nRUN cargo build --release\n"),
+            ("sql", "SELECT id, name FROM users WHERE id = 42;\n"),
+        ];
+
+        for (lang, code) in samples {
+            let spans = highlighter.highlight_code(code, lang);
+            assert!(distinct_colors(&spans) > 1, "{} should be highlighted", lang);
+        }
+    }
+
+    #[test]
+    fn test_highlight_lines_strips_newlines() {
+        let highlighter = SyntaxHighlighter::new();
+        let lines = highlighter.highlight_lines("a = 1\n\nb = \"x\"\n", "toml");
+
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].is_empty());
+        for line in &lines {
+            assert!(line.iter().all(|span| !span.content.contains('\n')));
+        }
+
+        let plain = highlighter.highlight_lines("one\ntwo", "unknownlangxyz");
+        assert_eq!(plain.len(), 2);
+        assert_eq!(plain[1].iter().map(|s| s.content.as_ref()).collect::<String>(), "two");
+    }
+
     #[test]
     fn test_fallback_color() {
         let highlighter = SyntaxHighlighter::new();
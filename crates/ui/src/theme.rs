@@ -2,6 +2,7 @@ use ratatui::style::{Color, Style};
 use ratatui::text::Span;
 use std::fmt::Display;
 use std::str::FromStr;
+use thunderus_core::memory::VerificationState;
 
 /// Theme variant options supported by Thunderus.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -191,6 +192,21 @@ impl Theme {
     pub fn verbosity_span(palette: ThemePalette, level: &str) -> Span<'_> {
         Span::styled(level, Style::default().fg(Self::verbosity_color(palette, level)))
     }
+
+    pub fn verification_color(palette: ThemePalette, state: VerificationState) -> Color {
+        match state {
+            VerificationState::Verified => palette.green,
+            VerificationState::Stale => palette.yellow,
+            VerificationState::NeverVerified => palette.muted,
+        }
+    }
+
+    pub fn verification_span(palette: ThemePalette, state: VerificationState) -> Span<'static> {
+        Span::styled(
+            state.label(),
+            Style::default().fg(Self::verification_color(palette, state)),
+        )
+    }
 }
 
 impl Default for ThemePalette {
@@ -229,6 +245,27 @@ mod tests {
         assert_eq!(Theme::risk_level_color(palette, "unknown"), palette.muted);
     }
 
+    #[test]
+    fn test_verification_colors() {
+        let palette = Theme::palette(ThemeVariant::Iceberg);
+        assert_eq!(
+            Theme::verification_color(palette, VerificationState::Verified),
+            palette.green
+        );
+        assert_eq!(
+            Theme::verification_color(palette, VerificationState::Stale),
+            palette.yellow
+        );
+        assert_eq!(
+            Theme::verification_color(palette, VerificationState::NeverVerified),
+            palette.muted
+        );
+        assert_eq!(
+            Theme::verification_span(palette, VerificationState::NeverVerified).content,
+            "never verified"
+        );
+    }
+
     #[test]
     fn test_styles() {
         let palette = Theme::palette(ThemeVariant::Iceberg);
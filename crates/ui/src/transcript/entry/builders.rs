@@ -1,3 +1,4 @@
+use crate::inline_image::ImageAttachment;
 use thunderus_core::ApprovalDecision;
 
 use super::{CardDetailLevel, ErrorType, StatusType, TranscriptEntry};
@@ -86,10 +87,19 @@ impl TranscriptEntry {
             error: None,
             exit_code: None,
             next_steps: None,
+            image: None,
             detail_level: CardDetailLevel::default(),
         }
     }
 
+    /// Attach an image to a tool result
+    pub fn with_image(mut self, attachment: ImageAttachment) -> Self {
+        if let Self::ToolResult { image, .. } = &mut self {
+            *image = Some(attachment);
+        }
+        self
+    }
+
     /// Add error to a tool result
     pub fn with_error(mut self, error: impl Into<String>) -> Self {
         if let Self::ToolResult { error: err, .. } = &mut self {
@@ -137,6 +147,7 @@ impl TranscriptEntry {
             task_context: None,
             scope: None,
             risk_reasoning: None,
+            diff: None,
             decision: None,
             detail_level: CardDetailLevel::default(),
         }
@@ -174,6 +185,14 @@ impl TranscriptEntry {
         self
     }
 
+    /// Add the proposed diff to an approval prompt (DIFF field)
+    pub fn with_approval_diff(mut self, diff: impl Into<String>) -> Self {
+        if let Self::ApprovalPrompt { diff: d, .. } = &mut self {
+            *d = Some(diff.into());
+        }
+        self
+    }
+
     /// Set approval decision
     pub fn with_decision(mut self, decision: ApprovalDecision) -> Self {
         if let Self::ApprovalPrompt { decision: dec, .. } = &mut self {
@@ -199,7 +218,22 @@ impl TranscriptEntry {
 
     /// Create a thinking indicator entry
     pub fn thinking_indicator(duration_secs: f32) -> Self {
-        Self::ThinkingIndicator { duration_secs }
+        Self::ThinkingIndicator {
+            duration_secs,
+            content: String::new(),
+            streaming: false,
+            detail_level: CardDetailLevel::default(),
+        }
+    }
+
+    /// Create a streaming reasoning entry
+    pub fn streaming_reasoning(content: impl Into<String>) -> Self {
+        Self::ThinkingIndicator {
+            duration_secs: 0.0,
+            content: content.into(),
+            streaming: true,
+            detail_level: CardDetailLevel::default(),
+        }
     }
 
     /// Create a status line entry
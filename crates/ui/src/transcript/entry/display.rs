@@ -17,8 +17,13 @@ impl fmt::Display for TranscriptEntry {
             Self::ToolCall { tool, risk, .. } => {
                 write!(f, "[{}] {} [{}]", tool, risk, Self::risk_emoji(risk))
             }
-            Self::ToolResult { tool, success, .. } => {
-                write!(f, "[{}] {}", tool, if *success { "OK" } else { "FAIL" })
+            Self::ToolResult { tool, success, error, .. } => {
+                let status = match (success, error.as_deref()) {
+                    (true, _) => "OK",
+                    (false, Some(thunderus_providers::TOOL_CANCELLED_ERROR)) => "CANCELLED",
+                    (false, _) => "FAIL",
+                };
+                write!(f, "[{}] {}", tool, status)
             }
             Self::PatchDisplay { patch_name, file_path, .. } => {
                 write!(f, "[Patch] {} @ {}", patch_name, file_path)
@@ -29,6 +34,7 @@ impl fmt::Display for TranscriptEntry {
                     Some(ApprovalDecision::Approved) => "APPROVED",
                     Some(ApprovalDecision::Rejected) => "REJECTED",
                     Some(ApprovalDecision::Cancelled) => "CANCELLED",
+                    Some(ApprovalDecision::AutoApproved) => "AUTO-APPROVED",
                 };
                 write!(f, "[Approval] {} {} [{}]", action, status, risk)
             }
@@ -45,7 +51,7 @@ impl fmt::Display for TranscriptEntry {
                 let retry_hint = if *can_retry { " (Press R to retry)" } else { "" };
                 write!(f, "[Error: {}] {}{}", type_str, message, retry_hint)
             }
-            Self::ThinkingIndicator { duration_secs } => {
+            Self::ThinkingIndicator { duration_secs, .. } => {
                 write!(f, "Thought for {:.0}s", duration_secs)
             }
             Self::StatusLine { message, .. } => {
@@ -107,6 +113,16 @@ mod tests {
         assert!(display.contains("OK"));
     }
 
+    #[test]
+    fn test_transcript_entry_tool_result_cancelled_display() {
+        let entry =
+            TranscriptEntry::tool_result("shell", "", false).with_error(thunderus_providers::TOOL_CANCELLED_ERROR);
+        assert_eq!(entry.to_string(), "[shell] CANCELLED");
+
+        let entry = TranscriptEntry::tool_result("shell", "", false).with_error("boom");
+        assert_eq!(entry.to_string(), "[shell] FAIL");
+    }
+
     #[test]
     fn test_transcript_entry_approval_prompt_display() {
         let entry = TranscriptEntry::approval_prompt("patch.feature", "risky");
@@ -1,4 +1,4 @@
-use super::{CardDetailLevel, TranscriptEntry};
+use super::{CardDetailLevel, StatusType, TranscriptEntry};
 use crate::theme::ThemePalette;
 
 use ratatui::style::Color;
@@ -35,6 +35,18 @@ impl TranscriptEntry {
         }
     }
 
+    /// Get status line color as ratatui Color
+    pub fn status_type_color(palette: ThemePalette, status_type: StatusType) -> Color {
+        match status_type {
+            StatusType::Ready => palette.green,
+            StatusType::Building => palette.blue,
+            StatusType::Generating => palette.cyan,
+            StatusType::WaitingApproval => palette.yellow,
+            StatusType::Interrupted => palette.red,
+            StatusType::Idle => palette.muted,
+        }
+    }
+
     /// Check if this is a tool-related entry
     pub fn is_tool_entry(&self) -> bool {
         matches!(self, Self::ToolCall { .. } | Self::ToolResult { .. })
@@ -49,7 +61,11 @@ impl TranscriptEntry {
     pub fn is_action_card(&self) -> bool {
         matches!(
             self,
-            Self::ToolCall { .. } | Self::ToolResult { .. } | Self::ApprovalPrompt { .. } | Self::PatchDisplay { .. }
+            Self::ToolCall { .. }
+                | Self::ToolResult { .. }
+                | Self::ApprovalPrompt { .. }
+                | Self::PatchDisplay { .. }
+                | Self::ThinkingIndicator { .. }
         )
     }
 
@@ -59,7 +75,8 @@ impl TranscriptEntry {
             Self::ToolCall { detail_level, .. }
             | Self::ToolResult { detail_level, .. }
             | Self::ApprovalPrompt { detail_level, .. }
-            | Self::PatchDisplay { detail_level, .. } => *detail_level,
+            | Self::PatchDisplay { detail_level, .. }
+            | Self::ThinkingIndicator { detail_level, .. } => *detail_level,
             _ => CardDetailLevel::Brief,
         }
     }
@@ -70,7 +87,8 @@ impl TranscriptEntry {
             Self::ToolCall { detail_level, .. }
             | Self::ToolResult { detail_level, .. }
             | Self::ApprovalPrompt { detail_level, .. }
-            | Self::PatchDisplay { detail_level, .. } => {
+            | Self::PatchDisplay { detail_level, .. }
+            | Self::ThinkingIndicator { detail_level, .. } => {
                 *detail_level = level;
             }
             _ => {}
@@ -83,7 +101,8 @@ impl TranscriptEntry {
             Self::ToolCall { detail_level, .. }
             | Self::ToolResult { detail_level, .. }
             | Self::ApprovalPrompt { detail_level, .. }
-            | Self::PatchDisplay { detail_level, .. } => {
+            | Self::PatchDisplay { detail_level, .. }
+            | Self::ThinkingIndicator { detail_level, .. } => {
                 detail_level.toggle();
             }
             _ => {}
@@ -1,3 +1,4 @@
+use crate::inline_image::ImageAttachment;
 use thunderus_core::ApprovalDecision;
 
 /// Detail level for action cards (progressive disclosure)
@@ -108,6 +109,8 @@ pub enum TranscriptEntry {
         exit_code: Option<i32>,
         /// RESULT: Next steps or follow-up actions
         next_steps: Option<Vec<String>>,
+        /// Image read by the tool, drawn inline on supporting terminals
+        image: Option<ImageAttachment>,
         detail_level: CardDetailLevel,
     },
     /// Patch display with hunk-level intent labels
@@ -131,6 +134,8 @@ pub enum TranscriptEntry {
         scope: Option<String>,
         /// RISK: Classification with reasoning
         risk_reasoning: Option<String>,
+        /// DIFF: Unified diff of the proposed change, for file edits
+        diff: Option<String>,
         decision: Option<ApprovalDecision>,
         detail_level: CardDetailLevel,
     },
@@ -143,10 +148,15 @@ pub enum TranscriptEntry {
         can_retry: bool,
         context: Option<String>,
     },
-    /// Thinking indicator showing elapsed time
+    /// Model reasoning, collapsed to its elapsed time until expanded
     ThinkingIndicator {
         /// Duration in seconds
         duration_secs: f32,
+        /// Accumulated reasoning trace
+        content: String,
+        /// Whether reasoning is still streaming
+        streaming: bool,
+        detail_level: CardDetailLevel,
     },
     /// Status line for current state
     StatusLine { message: String, status_type: StatusType },
@@ -3,6 +3,6 @@ mod renderer;
 mod state;
 
 pub use entry::{CardDetailLevel, ErrorType, StatusType, TranscriptEntry};
-pub use renderer::{RenderOptions, TranscriptRenderer};
-pub use state::Transcript;
+pub use renderer::{DEFAULT_TOOL_OUTPUT_COLLAPSE_LINES, RenderOptions, TranscriptRenderer};
+pub use state::{DEFAULT_MAX_TRANSCRIPT_ENTRIES, Transcript};
 pub use thunderus_core::ApprovalDecision;
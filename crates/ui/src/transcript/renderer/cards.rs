@@ -1,5 +1,9 @@
 use super::context::{ApprovalPromptContext, PatchDisplayContext, ToolCallContext, ToolResultContext};
-use crate::{TranscriptEntry, transcript::entry::CardDetailLevel};
+use super::wrap::{split_line_to_width, truncate_to_width};
+use crate::{
+    TranscriptEntry, components::DiffView, inline_image::ImageAttachment, theme::ThemePalette,
+    transcript::entry::CardDetailLevel,
+};
 
 use ratatui::{
     style::{Color, Style},
@@ -8,6 +12,12 @@ use ratatui::{
 use thunderus_core::ApprovalDecision;
 use unicode_width::UnicodeWidthStr;
 
+/// Lines kept from each end of a collapsed tool result
+const COLLAPSED_CONTEXT_LINES: usize = 5;
+
+/// Diff lines shown in an approval card before it is expanded
+const APPROVAL_DIFF_PREVIEW_LINES: usize = 20;
+
 impl<'a> super::TranscriptRenderer<'a> {
     pub(super) fn render_card(
         &self, title: &str, border_color: Color, width: usize, content: Vec<Line<'static>>,
@@ -18,27 +28,33 @@ impl<'a> super::TranscriptRenderer<'a> {
         let content_bg = Style::default().bg(self.theme.panel_bg);
         let padding = 2usize;
         let prefix = "┌─ ";
+        let title = truncate_to_width(title, card_width.saturating_sub(prefix.width() + 2));
         let title_width = title.width();
         let base_len = prefix.width() + title_width + 1;
         let fill_len = card_width.saturating_sub(base_len + 1);
 
         lines.push(Line::from(vec![
             Span::styled(prefix, border_style),
-            Span::styled(title.to_string(), border_style),
+            Span::styled(title, border_style),
             Span::styled(" ", border_style),
             Span::styled("─".repeat(fill_len), border_style),
             Span::styled("┐", border_style),
         ]));
 
+        let inner_width = card_width.saturating_sub(2);
+        let content_width = inner_width.saturating_sub(padding * 2);
+
         let mut padded_content = Vec::with_capacity(content.len() + 2);
         padded_content.push(Line::default());
-        padded_content.extend(content);
+        padded_content.extend(
+            content
+                .into_iter()
+                .flat_map(|line| split_line_to_width(line, content_width)),
+        );
         padded_content.push(Line::default());
 
         for line in padded_content {
-            let line_width = line.to_string().width();
-            let inner_width = card_width.saturating_sub(2);
-            let content_width = inner_width.saturating_sub(padding * 2);
+            let line_width = line.width();
             let padding_needed = content_width.saturating_sub(line_width);
             let mut spans = Vec::new();
             spans.push(Span::styled("│  ", border_style));
@@ -76,16 +92,16 @@ impl<'a> super::TranscriptRenderer<'a> {
         if matches!(rendering.detail_level, CardDetailLevel::Brief) {
             let info = description
                 .or(scope)
-                .map(|s| if s.len() > 50 { format!("{}...", &s[..47]) } else { s.to_string() })
+                .map(|s| truncate_to_width(s, 50))
                 .unwrap_or_else(|| {
                     if arguments.contains("path") {
                         arguments
                             .split('"')
                             .nth(1)
                             .map(|s| s.to_string())
-                            .unwrap_or_else(|| arguments.chars().take(40).collect())
+                            .unwrap_or_else(|| truncate_to_width(arguments, 40))
                     } else {
-                        arguments.chars().take(40).collect()
+                        truncate_to_width(arguments, 40)
                     }
                 });
 
@@ -154,27 +170,23 @@ impl<'a> super::TranscriptRenderer<'a> {
     ///
     /// Format: `-> Result summary` or `✓ ToolName` / `× ToolName error`
     pub(super) fn render_tool_result(&self, ctx: ToolResultContext) {
-        let ToolResultContext { tool, result, success, error, exit_code, next_steps, rendering } = ctx;
+        let ToolResultContext { tool, result, success, error, exit_code, next_steps, image, rendering } = ctx;
 
         let theme = rendering.theme;
 
         if matches!(rendering.detail_level, CardDetailLevel::Brief) {
-            let (symbol, color) = if success { ("✓", theme.green) } else { ("×", theme.red) };
+            let cancelled = error == Some(thunderus_providers::TOOL_CANCELLED_ERROR);
+            let (symbol, color) = match (success, cancelled) {
+                (true, _) => ("✓", theme.green),
+                (false, true) => ("⊘", theme.yellow),
+                (false, false) => ("×", theme.red),
+            };
 
             let preview = if let Some(err) = error {
-                format!(
-                    " {}",
-                    if err.len() > 50 { format!("{}...", &err[..47]) } else { err.to_string() }
-                )
+                format!(" {}", truncate_to_width(err, 50))
             } else if let Some(first_line) = result.lines().next() {
                 let line = first_line.trim();
-                if line.is_empty() {
-                    String::new()
-                } else if line.len() > 50 {
-                    format!(" ({}...)", &line[..47])
-                } else {
-                    format!(" ({})", line)
-                }
+                if line.is_empty() { String::new() } else { format!(" ({})", truncate_to_width(line, 50)) }
             } else {
                 String::new()
             };
@@ -184,6 +196,7 @@ impl<'a> super::TranscriptRenderer<'a> {
                 Span::styled(tool.to_string(), Style::default().fg(theme.fg)),
                 Span::styled(preview, Style::default().fg(theme.muted)),
             ]));
+            self.render_inline_image(image, rendering.width.saturating_sub(6), rendering.lines);
             return;
         }
 
@@ -194,7 +207,12 @@ impl<'a> super::TranscriptRenderer<'a> {
         let muted_style = Style::default().fg(theme.muted).bg(theme.panel_bg);
         let label_style = Style::default().fg(theme.cyan).bg(theme.panel_bg).bold();
 
-        let (status_symbol, status_color) = if success { ("✓", theme.green) } else { ("✗", theme.red) };
+        let cancelled = error == Some(thunderus_providers::TOOL_CANCELLED_ERROR);
+        let (status_symbol, status_color) = match (success, cancelled) {
+            (true, _) => ("✓", theme.green),
+            (false, true) => ("⊘ cancelled", theme.yellow),
+            (false, false) => ("✗", theme.red),
+        };
 
         let mut content_lines: Vec<Line<'static>> = Vec::new();
         content_lines.push(Line::from(vec![
@@ -212,7 +230,28 @@ impl<'a> super::TranscriptRenderer<'a> {
             ]));
         }
 
-        self.render_with_code_highlighting(result, theme.fg, content_width, &mut content_lines);
+        let threshold = self.options.tool_output_collapse_lines;
+        let line_count = result.lines().count();
+        let collapsed = threshold > 0
+            && line_count > threshold
+            && line_count > COLLAPSED_CONTEXT_LINES * 2
+            && !matches!(rendering.detail_level, CardDetailLevel::Verbose);
+
+        if collapsed {
+            let result_lines: Vec<&str> = result.lines().collect();
+            let head = result_lines[..COLLAPSED_CONTEXT_LINES].join("\n");
+            let tail = result_lines[line_count - COLLAPSED_CONTEXT_LINES..].join("\n");
+            let hidden = line_count - COLLAPSED_CONTEXT_LINES * 2;
+
+            self.wrap_text_styled(&head, content_style, content_width, &mut content_lines);
+            content_lines.push(Line::from(vec![Span::styled(
+                format!("··· {} lines hidden (space to expand) ···", hidden),
+                Style::default().fg(theme.muted).bg(theme.panel_bg).italic(),
+            )]));
+            self.wrap_text_styled(&tail, content_style, content_width, &mut content_lines);
+        } else {
+            self.render_with_code_highlighting(result, theme.fg, content_width, &mut content_lines);
+        }
 
         if let Some(code) = exit_code {
             let color = if code == 0 { theme.green } else { theme.red };
@@ -237,6 +276,17 @@ impl<'a> super::TranscriptRenderer<'a> {
         let title = format!("Result: {}", tool);
         let border_color = if success { theme.green } else { theme.red };
         self.render_card(&title, border_color, rendering.width, content_lines, rendering.lines);
+        self.render_inline_image(image, content_width, rendering.lines);
+    }
+
+    /// Leave blank rows for the terminal to draw an image into, when inline images are on
+    fn render_inline_image(&self, image: Option<&ImageAttachment>, width: usize, lines: &mut Vec<Line<'static>>) {
+        let Some(image) = image.filter(|_| self.options.inline_images) else {
+            return;
+        };
+        let (cols, rows) = image.cell_size(width.min(u16::MAX as usize) as u16);
+        self.reserve_image(image, cols, rows, lines.len());
+        lines.extend((0..rows).map(|_| Line::default()));
     }
 
     /// Render approval prompt card with teaching context (WHAT, WHY, SCOPE, RISK)
@@ -248,7 +298,9 @@ impl<'a> super::TranscriptRenderer<'a> {
             task_context,
             scope,
             risk_reasoning,
+            diff,
             decision,
+            timeout,
             rendering,
         } = ctx;
 
@@ -268,8 +320,14 @@ impl<'a> super::TranscriptRenderer<'a> {
                 Span::raw(" "),
                 Span::styled(action_preview.clone(), Style::default().fg(risk_color)),
                 Span::raw(" | "),
-                Span::styled("[y/n/c]", Style::default().fg(theme.muted)),
+                Span::styled("[y/a/n/r/c]", Style::default().fg(theme.muted)),
             ]));
+            if let Some(timeout) = timeout {
+                rendering.lines.push(Line::from(vec![Span::styled(
+                    format!("  {}", timeout.label()),
+                    Style::default().fg(theme.yellow),
+                )]));
+            }
             return;
         }
 
@@ -342,6 +400,11 @@ impl<'a> super::TranscriptRenderer<'a> {
             }
         }
 
+        if let Some(diff) = diff {
+            let expanded = matches!(rendering.detail_level, CardDetailLevel::Verbose);
+            self.render_approval_diff(diff, expanded, theme, &mut content_lines);
+        }
+
         match decision {
             None => {
                 let blink = rendering.animation_frame % 2 == 0;
@@ -357,13 +420,26 @@ impl<'a> super::TranscriptRenderer<'a> {
                     Span::styled("y", focus_style),
                     Span::styled("] approve  ", muted_style),
                     Span::styled("[", muted_style),
+                    Span::styled("a", Style::default().fg(theme.green).bg(theme.panel_bg).bold()),
+                    Span::styled("] always (session)  ", muted_style),
+                    Span::styled("[", muted_style),
                     Span::styled("n", Style::default().fg(theme.red).bg(theme.panel_bg).bold()),
                     Span::styled("] reject  ", muted_style),
                     Span::styled("[", muted_style),
+                    Span::styled("r", Style::default().fg(theme.red).bg(theme.panel_bg).bold()),
+                    Span::styled("] reject with reason  ", muted_style),
+                    Span::styled("[", muted_style),
                     Span::styled("c", Style::default().fg(theme.yellow).bg(theme.panel_bg).bold()),
                     Span::styled("] cancel", muted_style),
                 ]));
 
+                if let Some(timeout) = timeout {
+                    content_lines.push(Line::from(vec![Span::styled(
+                        format!("{} (any key resets)", timeout.label()),
+                        Style::default().fg(theme.yellow).bg(theme.panel_bg),
+                    )]));
+                }
+
                 match rendering.detail_level {
                     CardDetailLevel::Brief => {}
                     CardDetailLevel::Detailed => {
@@ -386,6 +462,12 @@ impl<'a> super::TranscriptRenderer<'a> {
                     Span::styled(" Approved", Style::default().fg(theme.green).bg(theme.panel_bg)),
                 ]));
             }
+            Some(ApprovalDecision::AutoApproved) => {
+                content_lines.push(Line::from(vec![
+                    Span::styled("✓", Style::default().fg(theme.green).bg(theme.panel_bg)),
+                    Span::styled(" Auto-approved", Style::default().fg(theme.green).bg(theme.panel_bg)),
+                ]));
+            }
             Some(ApprovalDecision::Rejected) => {
                 content_lines.push(Line::from(vec![
                     Span::styled("✗", Style::default().fg(theme.red).bg(theme.panel_bg)),
@@ -417,6 +499,35 @@ impl<'a> super::TranscriptRenderer<'a> {
         );
     }
 
+    /// Render the proposed diff inside an approval card, truncated unless `expanded`
+    fn render_approval_diff(
+        &self, diff: &str, expanded: bool, theme: ThemePalette, content_lines: &mut Vec<Line<'static>>,
+    ) {
+        let diff_lines: Vec<&str> = diff
+            .lines()
+            .filter(|line| !line.starts_with("diff --git") && !line.starts_with("index "))
+            .collect();
+        let shown = if expanded { diff_lines.len() } else { diff_lines.len().min(APPROVAL_DIFF_PREVIEW_LINES) };
+
+        content_lines.push(Line::from(vec![Span::styled(
+            "DIFF:",
+            Style::default().fg(theme.cyan).bg(theme.panel_bg).bold(),
+        )]));
+        for line in &diff_lines[..shown] {
+            content_lines.push(Line::from(vec![Span::styled(
+                line.to_string(),
+                DiffView::line_style(line, theme).bg(theme.panel_bg),
+            )]));
+        }
+
+        if shown < diff_lines.len() {
+            content_lines.push(Line::from(vec![Span::styled(
+                format!("··· {} more lines (v to expand) ···", diff_lines.len() - shown),
+                Style::default().fg(theme.muted).bg(theme.panel_bg).italic(),
+            )]));
+        }
+    }
+
     /// Render patch display with hunk-level intent labels
     pub(super) fn render_patch_display(&self, ctx: PatchDisplayContext) {
         let PatchDisplayContext { patch_name, file_path, diff_content, hunk_labels, rendering } = ctx;
@@ -53,13 +53,18 @@ impl<'a> super::TranscriptRenderer<'a> {
     /// Render a code block with syntax highlighting and boxed frame
     fn render_code_block(&self, code: &str, lang: &str, width: usize, lines: &mut Vec<Line<'static>>) {
         let highlighter = SyntaxHighlighter::new();
-        let highlighted = highlighter.highlight_code(code, lang.trim());
-        let mut code_lines = Vec::new();
-
-        for span in &highlighted {
-            let styled = Span::styled(span.content.to_string(), span.style.bg(self.theme.panel_bg));
-            code_lines.push(Line::from(vec![styled]));
-        }
+        let code_lines: Vec<Line<'static>> = highlighter
+            .highlight_lines(code, lang)
+            .into_iter()
+            .map(|spans| {
+                Line::from(
+                    spans
+                        .into_iter()
+                        .map(|span| Span::styled(span.content, span.style.bg(self.theme.panel_bg)))
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect();
 
         let title = if lang.is_empty() { " Code ".to_string() } else { format!(" {} ", lang.to_uppercase()) };
 
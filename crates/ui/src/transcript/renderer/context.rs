@@ -1,3 +1,5 @@
+use crate::inline_image::ImageAttachment;
+use crate::state::ApprovalTimeout;
 use crate::theme::ThemePalette;
 use crate::transcript::entry::CardDetailLevel;
 use ratatui::text::Line;
@@ -29,6 +31,8 @@ pub(super) struct ToolResultContext<'a> {
     pub(super) exit_code: Option<i32>,
     /// RESULT: Next steps
     pub(super) next_steps: Option<&'a Vec<String>>,
+    /// Image to draw under the card
+    pub(super) image: Option<&'a ImageAttachment>,
     pub(super) rendering: RenderContext<'a>,
 }
 
@@ -44,7 +48,11 @@ pub(super) struct ApprovalPromptContext<'a> {
     pub(super) scope: Option<&'a str>,
     /// RISK: Risk reasoning
     pub(super) risk_reasoning: Option<&'a str>,
+    /// DIFF: Proposed change for file edits
+    pub(super) diff: Option<&'a str>,
     pub(super) decision: Option<ApprovalDecision>,
+    /// Countdown until the prompt resolves on its own
+    pub(super) timeout: Option<ApprovalTimeout>,
     pub(super) rendering: RenderContext<'a>,
 }
 
@@ -32,22 +32,25 @@ impl<'a> super::TranscriptRenderer<'a> {
                 classification_reasoning: classification_reasoning.as_deref(),
                 rendering: RenderContext::new(width, *detail_level, lines, self.theme, self.options.animation_frame),
             }),
-            TranscriptEntry::ToolResult { tool, result, success, error, exit_code, next_steps, detail_level } => self
-                .render_tool_result(ToolResultContext {
-                    tool,
-                    result,
-                    success: *success,
-                    error: error.as_deref(),
-                    exit_code: *exit_code,
-                    next_steps: next_steps.as_ref(),
-                    rendering: RenderContext::new(
-                        width,
-                        *detail_level,
-                        lines,
-                        self.theme,
-                        self.options.animation_frame,
-                    ),
-                }),
+            TranscriptEntry::ToolResult {
+                tool,
+                result,
+                success,
+                error,
+                exit_code,
+                next_steps,
+                image,
+                detail_level,
+            } => self.render_tool_result(ToolResultContext {
+                tool,
+                result,
+                success: *success,
+                error: error.as_deref(),
+                exit_code: *exit_code,
+                next_steps: next_steps.as_ref(),
+                image: image.as_ref(),
+                rendering: RenderContext::new(width, *detail_level, lines, self.theme, self.options.animation_frame),
+            }),
             TranscriptEntry::PatchDisplay { patch_name, file_path, diff_content, hunk_labels, detail_level } => {
                 self.render_patch_display(PatchDisplayContext {
                     patch_name,
@@ -70,6 +73,7 @@ impl<'a> super::TranscriptRenderer<'a> {
                 task_context,
                 scope,
                 risk_reasoning,
+                diff,
                 decision,
                 detail_level,
             } => self.render_approval_prompt(ApprovalPromptContext {
@@ -79,15 +83,17 @@ impl<'a> super::TranscriptRenderer<'a> {
                 task_context: task_context.as_deref(),
                 scope: scope.as_deref(),
                 risk_reasoning: risk_reasoning.as_deref(),
+                diff: diff.as_deref(),
                 decision: *decision,
+                timeout: self.options.approval_timeout.filter(|_| decision.is_none()),
                 rendering: RenderContext::new(width, *detail_level, lines, self.theme, self.options.animation_frame),
             }),
             TranscriptEntry::SystemMessage { content } => self.render_system_message(content, width, lines),
             TranscriptEntry::ErrorEntry { message, error_type, can_retry, context } => {
                 self.render_error_entry(message, *error_type, *can_retry, context.as_deref(), width, lines)
             }
-            TranscriptEntry::ThinkingIndicator { duration_secs } => {
-                self.render_thinking_indicator(*duration_secs, lines)
+            TranscriptEntry::ThinkingIndicator { duration_secs, content, streaming, detail_level } => {
+                self.render_thinking_indicator(*duration_secs, content, *streaming, *detail_level, width, lines)
             }
             TranscriptEntry::StatusLine { message, status_type } => {
                 self.render_status_line(message, *status_type, lines)
@@ -1,4 +1,4 @@
-use crate::transcript::{ErrorType, StatusType};
+use crate::transcript::{CardDetailLevel, ErrorType, StatusType, TranscriptEntry};
 use ratatui::{
     style::{Color, Style},
     text::{Line, Span},
@@ -98,29 +98,42 @@ impl<'a> super::TranscriptRenderer<'a> {
     }
 
     /// Render thinking indicator (muted, indented)
-    pub(super) fn render_thinking_indicator(&self, duration_secs: f32, lines: &mut Vec<Line<'static>>) {
-        let text = if duration_secs < 1.0 {
-            "Thinking...".to_string()
+    ///
+    /// Collapsed by default; expanding the card shows the full reasoning trace dimmed.
+    pub(super) fn render_thinking_indicator(
+        &self, duration_secs: f32, content: &str, streaming: bool, detail_level: CardDetailLevel, width: usize,
+        lines: &mut Vec<Line<'static>>,
+    ) {
+        let style = Style::default().fg(self.theme.muted).italic();
+        let text = if streaming {
+            format!("💭 thinking... ({:.0}s)", duration_secs)
         } else {
-            format!("Thought for {:.0}s", duration_secs)
+            format!("💭 thinking ({:.0}s)", duration_secs)
         };
 
         lines.push(Line::from(vec![
             Span::styled("  ", Style::default()),
-            Span::styled(text, Style::default().fg(self.theme.muted).italic()),
+            Span::styled(text, style),
         ]));
+
+        if detail_level == CardDetailLevel::Brief || content.trim().is_empty() {
+            return;
+        }
+
+        let content_width = width.saturating_sub(4);
+        for source_line in content.lines().filter(|line| !line.trim().is_empty()) {
+            for wrapped_line in self.wrap_text_to_width(source_line, content_width) {
+                lines.push(Line::from(vec![
+                    Span::styled("  │ ", Style::default().fg(self.theme.muted)),
+                    Span::styled(wrapped_line, style.dim()),
+                ]));
+            }
+        }
     }
 
     /// Render status line with triple colon prefix
     pub(super) fn render_status_line(&self, message: &str, status_type: StatusType, lines: &mut Vec<Line<'static>>) {
-        let status_color = match status_type {
-            StatusType::Ready => self.theme.green,
-            StatusType::Building => self.theme.blue,
-            StatusType::Generating => self.theme.cyan,
-            StatusType::WaitingApproval => self.theme.yellow,
-            StatusType::Interrupted => self.theme.red,
-            StatusType::Idle => self.theme.muted,
-        };
+        let status_color = TranscriptEntry::status_type_color(self.theme, status_type);
 
         lines.push(Line::from(vec![
             Span::styled("::: ", Style::default().fg(self.theme.muted)),
@@ -6,7 +6,12 @@ mod messages;
 mod scrollbar;
 mod wrap;
 
-use crate::{theme::ThemePalette, transcript::Transcript};
+use crate::{
+    inline_image::{ImageAttachment, ImagePlacement},
+    state::ApprovalTimeout,
+    theme::ThemePalette,
+    transcript::Transcript,
+};
 
 use ratatui::{
     Frame,
@@ -15,12 +20,55 @@ use ratatui::{
     text::{Line, Span, Text},
     widgets::{Block, Paragraph, Wrap},
 };
+use std::cell::RefCell;
+use unicode_width::UnicodeWidthStr;
+
+/// Default number of output lines above which tool result cards collapse
+pub const DEFAULT_TOOL_OUTPUT_COLLAPSE_LINES: usize = 40;
 
-#[derive(Debug, Clone, Copy, Default)]
+/// Columns between the transcript edge and an inline image, matching card content
+const IMAGE_INDENT: u16 = 3;
+
+#[derive(Debug, Clone, Copy)]
 pub struct RenderOptions {
     pub centered: bool,
     pub max_bubble_width: Option<usize>,
     pub animation_frame: u8,
+    /// Tool results longer than this many lines render collapsed until fully expanded (0 disables)
+    pub tool_output_collapse_lines: usize,
+    /// Pin the view to the bottom, ignoring the scroll offset
+    pub follow_bottom: bool,
+    /// Show a "new content below" indicator when not at the bottom
+    pub new_content_below: bool,
+    /// Countdown shown on the pending approval prompt
+    pub approval_timeout: Option<ApprovalTimeout>,
+    /// Reserve space under image tool results for the terminal to draw them
+    pub inline_images: bool,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            centered: false,
+            max_bubble_width: None,
+            animation_frame: 0,
+            tool_output_collapse_lines: DEFAULT_TOOL_OUTPUT_COLLAPSE_LINES,
+            follow_bottom: false,
+            new_content_below: false,
+            approval_timeout: None,
+            inline_images: false,
+        }
+    }
+}
+
+/// Rows reserved under a tool result for an inline image
+#[derive(Debug, Clone)]
+struct ImageSlot {
+    /// Index of the first reserved line in the rendered transcript
+    line: usize,
+    cols: u16,
+    rows: u16,
+    image: ImageAttachment,
 }
 
 /// Renders transcript entries to frame
@@ -30,36 +78,49 @@ pub struct TranscriptRenderer<'a> {
     streaming_ellipsis: &'a str,
     theme: ThemePalette,
     options: RenderOptions,
+    image_slots: RefCell<Vec<ImageSlot>>,
+    image_placements: RefCell<Vec<ImagePlacement>>,
 }
 
 impl<'a> TranscriptRenderer<'a> {
     /// Create a new renderer for given transcript
     pub fn new(transcript: &'a Transcript, theme: ThemePalette) -> Self {
-        Self { transcript, scroll_vertical: 0, streaming_ellipsis: "", theme, options: RenderOptions::default() }
+        Self::with_streaming_ellipsis(transcript, 0, "", theme, RenderOptions::default())
     }
 
     /// Create a new renderer with scroll offset
     pub fn with_vertical_scroll(
         transcript: &'a Transcript, scroll: u16, theme: ThemePalette, options: RenderOptions,
     ) -> Self {
-        Self { transcript, scroll_vertical: scroll, streaming_ellipsis: "", theme, options }
+        Self::with_streaming_ellipsis(transcript, scroll, "", theme, options)
     }
 
     /// Create a new renderer with streaming ellipsis animation
     pub fn with_streaming_ellipsis(
         transcript: &'a Transcript, scroll: u16, ellipsis: &'a str, theme: ThemePalette, options: RenderOptions,
     ) -> Self {
-        Self { transcript, scroll_vertical: scroll, streaming_ellipsis: ellipsis, theme, options }
+        Self {
+            transcript,
+            scroll_vertical: scroll,
+            streaming_ellipsis: ellipsis,
+            theme,
+            options,
+            image_slots: RefCell::default(),
+            image_placements: RefCell::default(),
+        }
     }
 
     /// Render transcript to the given area with scrollbar indicator
-    pub fn render(&self, frame: &mut Frame<'_>, area: Rect) {
+    ///
+    /// Returns the rendered content height and the largest valid scroll offset.
+    pub fn render(&self, frame: &mut Frame<'_>, area: Rect) -> (usize, u16) {
         let entries = self.transcript.render_entries();
         let mut text_lines = Vec::new();
         let padding_x = 1usize;
         let padding_y = 0usize;
         let scrollbar_width = 1usize;
         let content_width = area.width.saturating_sub((padding_x * 2 + scrollbar_width) as u16) as usize;
+        self.image_slots.borrow_mut().clear();
 
         for (idx, entry) in entries.iter().enumerate() {
             if idx > 0 {
@@ -90,18 +151,86 @@ impl<'a> TranscriptRenderer<'a> {
 
         frame.render_widget(Block::default().style(Style::default().bg(self.theme.bg)), area);
 
+        let content_height = padded_lines.len();
+        let max_scroll = content_height
+            .saturating_sub(area.height as usize)
+            .min(u16::MAX as usize) as u16;
+        let scroll = if self.options.follow_bottom { max_scroll } else { self.scroll_vertical.min(max_scroll) };
+
         let paragraph = Paragraph::new(Text::from(padded_lines))
             .wrap(Wrap { trim: true })
-            .scroll((0, self.scroll_vertical));
+            .scroll((0, scroll));
 
         frame.render_widget(paragraph, area);
+        *self.image_placements.borrow_mut() = self.place_images(area, padding_x as u16, padding_y, scroll);
+
+        if self.options.new_content_below && scroll < max_scroll {
+            self.render_new_content_indicator(frame, area);
+        }
+
+        (content_height, max_scroll)
+    }
+
+    /// Images drawn by the last [`TranscriptRenderer::render`], in screen cells
+    pub fn image_placements(&self) -> Vec<ImagePlacement> {
+        self.image_placements.borrow().clone()
+    }
+
+    /// Record rows just added to `lines` as the place to draw `image`
+    pub(super) fn reserve_image(&self, image: &ImageAttachment, cols: u16, rows: u16, line: usize) {
+        self.image_slots
+            .borrow_mut()
+            .push(ImageSlot { line, cols, rows, image: image.clone() });
+    }
+
+    /// Map reserved image rows to screen cells, keeping only images fully in view
+    fn place_images(&self, area: Rect, padding_x: u16, padding_y: usize, scroll: u16) -> Vec<ImagePlacement> {
+        let top = scroll as usize;
+        let bottom = top + area.height as usize;
+        self.image_slots
+            .borrow()
+            .iter()
+            .filter_map(|slot| {
+                let line = slot.line + padding_y;
+                if line < top || line + slot.rows as usize > bottom {
+                    return None;
+                }
+                let x = area.x + padding_x + IMAGE_INDENT;
+                let cols = slot.cols.min((area.x + area.width).saturating_sub(x));
+                Some(ImagePlacement {
+                    image: slot.image.clone(),
+                    area: Rect::new(x, area.y + (line - top) as u16, cols, slot.rows),
+                })
+            })
+            .collect()
+    }
+
+    /// Render the "new content below" indicator on the bottom row of the transcript
+    fn render_new_content_indicator(&self, frame: &mut Frame<'_>, area: Rect) {
+        if area.height == 0 {
+            return;
+        }
+
+        let label = " ↓ new content below (G to follow) ";
+        let width = (label.width() as u16).min(area.width);
+        let indicator_area = Rect::new(
+            area.x + area.width.saturating_sub(width) / 2,
+            area.y + area.height - 1,
+            width,
+            1,
+        );
+        let style = Style::default().fg(self.theme.bg).bg(self.theme.cyan).bold();
+        frame.render_widget(Paragraph::new(Line::from(Span::styled(label, style))), indicator_area);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Theme, ThemeVariant, transcript::Transcript};
+    use crate::{
+        Theme, ThemeVariant,
+        transcript::{CardDetailLevel, Transcript},
+    };
 
     #[test]
     fn test_renderer_new() {
@@ -118,4 +247,259 @@ mod tests {
         let theme = Theme::palette(ThemeVariant::Iceberg);
         let _ = TranscriptRenderer::new(&transcript, theme);
     }
+
+    fn render_tool_result_text(
+        detail_level: CardDetailLevel, line_count: usize, options: RenderOptions, exit_code: Option<i32>,
+    ) -> String {
+        let transcript = Transcript::new();
+        let theme = Theme::palette(ThemeVariant::Iceberg);
+        let renderer = TranscriptRenderer::with_vertical_scroll(&transcript, 0, theme, options);
+
+        let output: Vec<String> = (1..=line_count).map(|i| format!("output line {}", i)).collect();
+        let mut entry = crate::TranscriptEntry::tool_result("shell", output.join("\n"), exit_code == Some(0));
+        entry.set_detail_level(detail_level);
+        if let crate::TranscriptEntry::ToolResult { exit_code: code, .. } = &mut entry {
+            *code = exit_code;
+        }
+
+        let mut lines = Vec::new();
+        renderer.render_entry(&entry, 100, "", &mut lines);
+        lines.iter().map(|l| l.to_string()).collect::<Vec<_>>().join("\n")
+    }
+
+    fn render_lines(transcript: &Transcript, width: usize) -> Vec<Line<'static>> {
+        let theme = Theme::palette(ThemeVariant::Iceberg);
+        let renderer = TranscriptRenderer::new(transcript, theme);
+        let mut lines = Vec::new();
+        for entry in transcript.render_entries() {
+            renderer.render_entry(entry, width, "", &mut lines);
+        }
+        lines
+    }
+
+    #[test]
+    fn test_thinking_block_collapsed_until_expanded() {
+        let mut transcript = Transcript::new();
+        transcript.add_reasoning_token("Check the failing test first.");
+        transcript.finish_streaming();
+
+        let collapsed: Vec<String> = render_lines(&transcript, 80).iter().map(|l| l.to_string()).collect();
+        assert_eq!(collapsed.len(), 1);
+        assert!(collapsed[0].contains("💭 thinking (0s)"));
+
+        let mut entry = transcript.last().unwrap().clone();
+        entry.toggle_detail_level();
+        let theme = Theme::palette(ThemeVariant::Iceberg);
+        let renderer = TranscriptRenderer::new(&transcript, theme);
+        let mut lines = Vec::new();
+        renderer.render_entry(&entry, 80, "", &mut lines);
+        let expanded = lines.iter().map(|l| l.to_string()).collect::<Vec<_>>().join("\n");
+        assert!(expanded.contains("Check the failing test first."));
+    }
+
+    #[test]
+    fn test_wrap_mixed_width_text_fits() {
+        let mut transcript = Transcript::new();
+        transcript.add_user_message(
+            "こんにちは世界、これは長い日本語のテキストです 🚀🚀🚀 and some ASCII words 中文字符测试",
+        );
+        transcript.add_model_response("🎉🎉🎉🎉🎉🎉🎉🎉🎉🎉🎉🎉🎉🎉🎉🎉 混合 width テキスト");
+        transcript.add_system_message("/path/到/非常に長い/ディレクトリ/名前/file.rs");
+
+        let width = 20;
+        for line in render_lines(&transcript, width) {
+            assert!(
+                line.width() <= width,
+                "line {:?} is wider than {}",
+                line.to_string(),
+                width
+            );
+        }
+    }
+
+    #[test]
+    fn test_card_borders_align_with_wide_characters() {
+        let mut transcript = Transcript::new();
+        let mut entry = crate::TranscriptEntry::tool_result(
+            "read",
+            "日本語の出力🚀とASCII mixed content that keeps going\n```rust\nlet 変数 = \"絵文字🎉🎉🎉🎉🎉🎉🎉🎉🎉🎉🎉🎉🎉🎉\";\n```",
+            true,
+        );
+        entry.set_detail_level(CardDetailLevel::Detailed);
+        transcript.add(entry);
+
+        let width = 30;
+        let lines = render_lines(&transcript, width);
+        let card_lines: Vec<&Line> = lines
+            .iter()
+            .filter(|l| {
+                let text = l.to_string();
+                text.starts_with('┌') || text.starts_with('│') || text.starts_with('└')
+            })
+            .collect();
+
+        assert!(!card_lines.is_empty());
+        for line in card_lines {
+            assert_eq!(line.width(), width, "misaligned card line {:?}", line.to_string());
+        }
+    }
+
+    #[test]
+    fn test_brief_previews_truncate_by_display_width() {
+        let mut transcript = Transcript::new();
+        let long = "错误".repeat(40);
+        transcript.add(crate::TranscriptEntry::tool_result("shell", long.clone(), true));
+        transcript.add_tool_call("read", format!("{{\"path\": \"{}\"}}", long), "safe");
+
+        for line in render_lines(&transcript, 200) {
+            assert!(line.width() <= 80, "preview {:?} was not truncated", line.to_string());
+        }
+    }
+
+    #[test]
+    fn test_split_to_width_handles_wide_characters() {
+        assert_eq!(
+            wrap::split_to_width("日本語テキスト", 4),
+            vec!["日本", "語テ", "キス", "ト"]
+        );
+        assert_eq!(wrap::split_to_width("a日b", 2), vec!["a", "日", "b"]);
+        assert_eq!(wrap::split_to_width("🚀", 1), vec!["🚀"]);
+        assert_eq!(wrap::truncate_to_width("日本語テキスト", 9), "日本語...");
+        assert_eq!(wrap::truncate_to_width("short", 9), "short");
+    }
+
+    #[test]
+    fn test_large_tool_result_collapses() {
+        let text = render_tool_result_text(CardDetailLevel::Detailed, 100, RenderOptions::default(), Some(1));
+
+        assert!(text.contains("output line 1"));
+        assert!(text.contains("output line 5"));
+        assert!(!text.contains("output line 6 "));
+        assert!(!text.contains("output line 50"));
+        assert!(text.contains("output line 96"));
+        assert!(text.contains("output line 100"));
+        assert!(text.contains("90 lines hidden"));
+        assert!(text.contains("Status: ✗"));
+        assert!(text.contains("Exit code: 1"));
+    }
+
+    #[test]
+    fn test_large_tool_result_expands_when_verbose() {
+        let text = render_tool_result_text(CardDetailLevel::Verbose, 100, RenderOptions::default(), Some(0));
+
+        assert!(text.contains("output line 50"));
+        assert!(!text.contains("lines hidden"));
+        assert!(text.contains("Exit code: 0"));
+    }
+
+    #[test]
+    fn test_image_result_reserves_rows_when_inline_images_enabled() {
+        let transcript = Transcript::new();
+        let theme = Theme::palette(ThemeVariant::Iceberg);
+        let info = thunderus_core::ImageInfo { format: thunderus_core::ImageFormat::Png, width: 800, height: 400 };
+        let entry = crate::TranscriptEntry::tool_result("read", info.note(), true)
+            .with_image(ImageAttachment::new("/tmp/shot.png", info));
+
+        let plain = TranscriptRenderer::new(&transcript, theme);
+        let mut plain_lines = Vec::new();
+        plain.render_entry(&entry, 100, "", &mut plain_lines);
+        assert!(plain.image_slots.borrow().is_empty());
+        assert!(
+            plain_lines
+                .iter()
+                .any(|l| l.to_string().contains("[image: 800x400 PNG]"))
+        );
+
+        let options = RenderOptions { inline_images: true, ..Default::default() };
+        let renderer = TranscriptRenderer::with_vertical_scroll(&transcript, 0, theme, options);
+        let mut lines = Vec::new();
+        renderer.render_entry(&entry, 100, "", &mut lines);
+        assert_eq!(lines.len(), plain_lines.len() + 15);
+
+        let area = Rect::new(0, 2, 100, lines.len() as u16);
+        let placements = renderer.place_images(area, 1, 0, 0);
+        assert_eq!(placements.len(), 1);
+        assert_eq!(placements[0].area, Rect::new(4, 2 + plain_lines.len() as u16, 60, 15));
+        assert_eq!(placements[0].image.path, std::path::PathBuf::from("/tmp/shot.png"));
+
+        let clipped = Rect { height: area.height - 1, ..area };
+        assert!(renderer.place_images(clipped, 1, 0, 0).is_empty());
+        assert!(
+            renderer
+                .place_images(area, 1, 0, plain_lines.len() as u16 + 1)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_approval_prompt_shows_truncated_diff() {
+        let transcript = Transcript::new();
+        let theme = Theme::palette(ThemeVariant::Iceberg);
+        let renderer = TranscriptRenderer::new(&transcript, theme);
+
+        let body: Vec<String> = (1..=30).map(|i| format!("+added line {}", i)).collect();
+        let diff = format!(
+            "diff --git a/src/lib.rs b/src/lib.rs\n--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -1,0 +1,30 @@\n{}",
+            body.join("\n")
+        );
+        let mut entry =
+            crate::TranscriptEntry::approval_prompt("tool:Execute tool: edit", "risky").with_approval_diff(diff);
+
+        let mut lines = Vec::new();
+        renderer.render_entry(&entry, 100, "", &mut lines);
+        let text = lines.iter().map(|l| l.to_string()).collect::<Vec<_>>().join("\n");
+        assert!(text.contains("DIFF:"));
+        assert!(text.contains("+++ b/src/lib.rs"));
+        assert!(text.contains("+added line 17"));
+        assert!(!text.contains("+added line 18"));
+        assert!(text.contains("13 more lines (v to expand)"));
+        assert!(!text.contains("diff --git"));
+
+        entry.set_detail_level(CardDetailLevel::Verbose);
+        let mut lines = Vec::new();
+        renderer.render_entry(&entry, 100, "", &mut lines);
+        let text = lines.iter().map(|l| l.to_string()).collect::<Vec<_>>().join("\n");
+        assert!(text.contains("+added line 30"));
+        assert!(!text.contains("more lines"));
+    }
+
+    #[test]
+    fn test_pending_approval_prompt_shows_countdown() {
+        let transcript = Transcript::new();
+        let theme = Theme::palette(ThemeVariant::Iceberg);
+        let timeout = crate::state::ApprovalTimeout::new(
+            std::time::Duration::from_secs(45),
+            thunderus_core::ApprovalDecision::Rejected,
+        );
+        let options = RenderOptions { approval_timeout: Some(timeout), ..Default::default() };
+        let renderer = TranscriptRenderer::with_vertical_scroll(&transcript, 0, theme, options);
+
+        let mut entry = crate::TranscriptEntry::approval_prompt("shell:rm -rf build", "risky");
+        let mut lines = Vec::new();
+        renderer.render_entry(&entry, 100, "", &mut lines);
+        let text = lines.iter().map(|l| l.to_string()).collect::<Vec<_>>().join("\n");
+        assert!(text.contains("auto-reject in 45s"));
+
+        if let crate::TranscriptEntry::ApprovalPrompt { decision, .. } = &mut entry {
+            *decision = Some(thunderus_core::ApprovalDecision::Approved);
+        }
+        let mut lines = Vec::new();
+        renderer.render_entry(&entry, 100, "", &mut lines);
+        let text = lines.iter().map(|l| l.to_string()).collect::<Vec<_>>().join("\n");
+        assert!(!text.contains("auto-reject"));
+    }
+
+    #[test]
+    fn test_tool_result_collapse_threshold_configurable() {
+        let small = render_tool_result_text(CardDetailLevel::Detailed, 30, RenderOptions::default(), None);
+        assert!(!small.contains("lines hidden"));
+
+        let options = RenderOptions { tool_output_collapse_lines: 20, ..Default::default() };
+        let collapsed = render_tool_result_text(CardDetailLevel::Detailed, 30, options, None);
+        assert!(collapsed.contains("20 lines hidden"));
+
+        let options = RenderOptions { tool_output_collapse_lines: 0, ..Default::default() };
+        let disabled = render_tool_result_text(CardDetailLevel::Detailed, 100, options, None);
+        assert!(!disabled.contains("lines hidden"));
+    }
 }
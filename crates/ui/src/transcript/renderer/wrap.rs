@@ -69,25 +69,8 @@ impl<'a> super::TranscriptRenderer<'a> {
                 }
 
                 if word_width > max_width {
-                    let chars = word.chars().peekable();
-                    let mut chunk_width = 0;
-                    let mut chunk = String::new();
-
-                    for ch in chars {
-                        let ch_width = ch.width().unwrap_or(0);
-
-                        if chunk_width + ch_width > max_width {
-                            lines.push(Line::from(vec![Span::styled(chunk.clone(), style)]));
-                            chunk.clear();
-                            chunk_width = 0;
-                        }
-
-                        chunk.push(ch);
-                        chunk_width += ch_width;
-                    }
-
-                    if !chunk.is_empty() {
-                        lines.push(Line::from(vec![Span::styled(chunk.clone(), style)]));
+                    for chunk in split_to_width(word, max_width) {
+                        lines.push(Line::from(vec![Span::styled(chunk, style)]));
                     }
                     continue;
                 }
@@ -132,26 +115,7 @@ impl<'a> super::TranscriptRenderer<'a> {
                 }
 
                 if word_width > max_width {
-                    let chars = word.chars().peekable();
-                    let mut chunk_width = 0;
-                    let mut chunk = String::new();
-
-                    for ch in chars {
-                        let ch_width = ch.width().unwrap_or(0);
-
-                        if chunk_width + ch_width > max_width {
-                            result.push(chunk.clone());
-                            chunk.clear();
-                            chunk_width = 0;
-                        }
-
-                        chunk.push(ch);
-                        chunk_width += ch_width;
-                    }
-
-                    if !chunk.is_empty() {
-                        result.push(chunk);
-                    }
+                    result.extend(split_to_width(word, max_width));
                     continue;
                 }
             }
@@ -183,14 +147,15 @@ impl<'a> super::TranscriptRenderer<'a> {
     }
 
     /// Find a good break point in path/URL (prefer /, ., etc.)
+    ///
+    /// Returns a byte index whose prefix fits in `max_width` display columns.
     fn find_break_point(&self, text: &str, max_width: usize) -> Option<usize> {
         let mut break_idx = None;
+        let mut width = 0;
         for (i, ch) in text.char_indices() {
-            if i > 0
-                && i % max_width == 0
-                && let Some(idx) = break_idx
-            {
-                return Some(idx);
+            width += ch.width().unwrap_or(0);
+            if width > max_width {
+                return break_idx.or_else(|| (i > 0).then_some(i));
             }
             if matches!(ch, '/' | '.' | '-' | '_') {
                 break_idx = Some(i + ch.len_utf8());
@@ -199,3 +164,88 @@ impl<'a> super::TranscriptRenderer<'a> {
         break_idx
     }
 }
+
+/// Split `text` into chunks of at most `max_width` display columns
+///
+/// A character wider than `max_width` gets a chunk of its own rather than
+/// producing an empty one.
+pub(super) fn split_to_width(text: &str, max_width: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut chunk = String::new();
+    let mut chunk_width = 0;
+
+    for ch in text.chars() {
+        let ch_width = ch.width().unwrap_or(0);
+
+        if chunk_width + ch_width > max_width && !chunk.is_empty() {
+            chunks.push(std::mem::take(&mut chunk));
+            chunk_width = 0;
+        }
+
+        chunk.push(ch);
+        chunk_width += ch_width;
+    }
+
+    if !chunk.is_empty() {
+        chunks.push(chunk);
+    }
+    chunks
+}
+
+/// Truncate `text` to `max_width` display columns, ending with "..." when cut
+pub(super) fn truncate_to_width(text: &str, max_width: usize) -> String {
+    if text.width() <= max_width {
+        return text.to_string();
+    }
+
+    let budget = max_width.saturating_sub(3);
+    let mut out = String::new();
+    let mut width = 0;
+    for ch in text.chars() {
+        let ch_width = ch.width().unwrap_or(0);
+        if width + ch_width > budget {
+            break;
+        }
+        out.push(ch);
+        width += ch_width;
+    }
+    out.push_str("...");
+    out
+}
+
+/// Split a styled line into lines no wider than `max_width` display columns
+///
+/// Span styles are preserved across the split points.
+pub(super) fn split_line_to_width(line: Line<'static>, max_width: usize) -> Vec<Line<'static>> {
+    if max_width == 0 || line.width() <= max_width {
+        return vec![line];
+    }
+
+    let mut lines = Vec::new();
+    let mut current: Vec<Span<'static>> = Vec::new();
+    let mut current_width = 0;
+
+    for span in line.spans {
+        let mut text = String::new();
+        for ch in span.content.chars() {
+            let ch_width = ch.width().unwrap_or(0);
+            if current_width + ch_width > max_width && current_width > 0 {
+                if !text.is_empty() {
+                    current.push(Span::styled(std::mem::take(&mut text), span.style));
+                }
+                lines.push(Line::from(std::mem::take(&mut current)));
+                current_width = 0;
+            }
+            text.push(ch);
+            current_width += ch_width;
+        }
+        if !text.is_empty() {
+            current.push(Span::styled(text, span.style));
+        }
+    }
+
+    if !current.is_empty() {
+        lines.push(Line::from(current));
+    }
+    lines
+}
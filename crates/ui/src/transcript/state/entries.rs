@@ -1,17 +1,73 @@
 use super::Transcript;
-use crate::transcript::{ErrorType, TranscriptEntry};
+use crate::transcript::{CardDetailLevel, ErrorType, TranscriptEntry};
+use std::time::Instant;
 use thunderus_core::ApprovalDecision;
 
 impl Transcript {
     /// Add an entry to the transcript
+    ///
+    /// Once `max_entries` is reached the oldest entries are evicted (pending
+    /// approval prompts are never evicted) and replaced by a marker at the top.
     pub fn add(&mut self, entry: TranscriptEntry) {
-        if self.entries.len() >= self.max_entries {
-            self.entries.pop_front();
-        }
+        self.finish_reasoning();
         self.entries.push_back(entry);
+        self.evict_overflow();
         self.scroll_to_bottom();
     }
 
+    /// Maximum number of entries kept before older ones are hidden
+    pub fn max_entries(&self) -> usize {
+        self.max_entries
+    }
+
+    /// Change the entry cap, evicting immediately if the transcript is over it
+    pub fn set_max_entries(&mut self, max_entries: usize) {
+        self.max_entries = max_entries.max(1);
+        self.evict_overflow();
+    }
+
+    /// Number of entries hidden from the transcript by the entry cap
+    pub fn hidden_entries(&self) -> usize {
+        self.hidden_entries
+    }
+
+    /// Evict the oldest evictable entries until the transcript fits `max_entries`
+    ///
+    /// The hidden-history marker doesn't count toward the cap.
+    fn evict_overflow(&mut self) {
+        let hidden_before = self.hidden_entries;
+
+        loop {
+            let marker = usize::from(self.hidden_entries > 0);
+            if self.entries.len() - marker <= self.max_entries {
+                break;
+            }
+
+            let Some(index) = (marker..self.entries.len()).find(|&i| !self.entries[i].is_pending()) else {
+                break;
+            };
+
+            self.entries.remove(index);
+            self.focused_card_index = match self.focused_card_index {
+                Some(focused) if focused == index => None,
+                Some(focused) if focused > index => Some(focused - 1),
+                other => other,
+            };
+
+            if self.hidden_entries == 0 {
+                self.entries.push_front(TranscriptEntry::system_message(String::new()));
+                self.focused_card_index = self.focused_card_index.map(|i| i + 1);
+            }
+            self.hidden_entries += 1;
+        }
+
+        if self.hidden_entries != hidden_before
+            && let Some(TranscriptEntry::SystemMessage { content }) = self.entries.front_mut()
+        {
+            *content = hidden_entries_marker(self.hidden_entries);
+        }
+    }
+
     /// Add a user message
     pub fn add_user_message(&mut self, content: impl Into<String>) {
         self.add(TranscriptEntry::user_message(content));
@@ -32,8 +88,35 @@ impl Transcript {
         }
     }
 
+    /// Append reasoning to the streaming thinking block, starting one if needed
+    pub fn add_reasoning_token(&mut self, token: &str) {
+        if let Some(TranscriptEntry::ThinkingIndicator { content, streaming: true, duration_secs, .. }) =
+            self.entries.back_mut()
+        {
+            content.push_str(token);
+            if let Some(started) = self.reasoning_started_at {
+                *duration_secs = started.elapsed().as_secs_f32();
+            }
+        } else {
+            self.add(TranscriptEntry::streaming_reasoning(token));
+            self.reasoning_started_at = Some(Instant::now());
+        }
+    }
+
+    /// Close the streaming thinking block, fixing its duration to the elapsed reasoning time
+    fn finish_reasoning(&mut self) {
+        if let Some(started) = self.reasoning_started_at.take()
+            && let Some(TranscriptEntry::ThinkingIndicator { streaming, duration_secs, .. }) = self.entries.back_mut()
+            && *streaming
+        {
+            *streaming = false;
+            *duration_secs = started.elapsed().as_secs_f32();
+        }
+    }
+
     /// Mark current streaming response as complete
     pub fn finish_streaming(&mut self) {
+        self.finish_reasoning();
         if let Some(last) = self.entries.back_mut()
             && let TranscriptEntry::ModelResponse { streaming, .. } = last
         {
@@ -41,6 +124,38 @@ impl Transcript {
         }
     }
 
+    /// Reopen the last model response so further tokens stream into it
+    ///
+    /// The response moves below any trailing system messages (such as a truncation
+    /// notice), which are kept. Returns false if there is no response to reopen.
+    pub fn reopen_last_response(&mut self) -> bool {
+        let Some(index) = self
+            .entries
+            .iter()
+            .rposition(|entry| !matches!(entry, TranscriptEntry::SystemMessage { .. }))
+        else {
+            return false;
+        };
+        if !matches!(self.entries[index], TranscriptEntry::ModelResponse { .. }) {
+            return false;
+        }
+
+        if let Some(mut response) = self.entries.remove(index) {
+            if let TranscriptEntry::ModelResponse { streaming, .. } = &mut response {
+                *streaming = true;
+            }
+            self.entries.push_back(response);
+        }
+        let last = self.entries.len() - 1;
+        self.focused_card_index = match self.focused_card_index {
+            Some(focused) if focused == index => Some(last),
+            Some(focused) if focused > index => Some(focused - 1),
+            other => other,
+        };
+        self.scroll_to_bottom();
+        true
+    }
+
     /// Mark current streaming response as cancelled, preserving partial output.
     pub fn mark_streaming_cancelled(&mut self, message: impl Into<String>) {
         if let Some(TranscriptEntry::ModelResponse { content, streaming }) = self.entries.back_mut()
@@ -84,6 +199,17 @@ impl Transcript {
         false
     }
 
+    /// Show the pending approval prompt at full detail, including its whole diff
+    pub fn expand_pending_approval(&mut self) -> bool {
+        for entry in self.entries.iter_mut().rev() {
+            if let TranscriptEntry::ApprovalPrompt { decision: None, detail_level, .. } = entry {
+                *detail_level = CardDetailLevel::Verbose;
+                return true;
+            }
+        }
+        false
+    }
+
     /// Add a system message
     pub fn add_system_message(&mut self, content: impl Into<String>) {
         self.add(TranscriptEntry::system_message(content));
@@ -148,6 +274,7 @@ impl Transcript {
         self.entries.clear();
         self.scroll_offset = 0;
         self.focused_card_index = None;
+        self.hidden_entries = 0;
     }
 
     /// Truncate entries from a specific index (for fork mode)
@@ -159,6 +286,9 @@ impl Transcript {
             self.entries.truncate(index);
             self.scroll_offset = 0;
             self.focused_card_index = None;
+            if self.entries.is_empty() {
+                self.hidden_entries = 0;
+            }
         }
     }
 
@@ -177,9 +307,19 @@ impl Transcript {
     }
 }
 
+/// Text of the marker entry shown in place of evicted history
+fn hidden_entries_marker(count: usize) -> String {
+    format!(
+        "{} earlier {} hidden — scroll or /search to search the session",
+        count,
+        if count == 1 { "entry" } else { "entries" }
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::transcript::CardDetailLevel;
 
     #[test]
     fn test_transcript_new() {
@@ -209,6 +349,36 @@ mod tests {
         assert_eq!(transcript.len(), 1);
     }
 
+    #[test]
+    fn test_reopen_last_response_streams_into_prior_entry() {
+        let mut transcript = Transcript::new();
+        transcript.add_user_message("Write it");
+        transcript.add_streaming_token("Part one");
+        transcript.finish_streaming();
+        transcript.add_system_message("Response truncated");
+
+        assert!(transcript.reopen_last_response());
+        transcript.add_streaming_token(", part two");
+
+        assert_eq!(transcript.len(), 3);
+        assert!(matches!(
+            transcript.entries().get(1),
+            Some(TranscriptEntry::SystemMessage { content }) if content == "Response truncated"
+        ));
+        assert!(matches!(
+            transcript.last(),
+            Some(TranscriptEntry::ModelResponse { content, streaming: true }) if content == "Part one, part two"
+        ));
+    }
+
+    #[test]
+    fn test_reopen_last_response_without_response() {
+        let mut transcript = Transcript::new();
+        transcript.add_user_message("Hello");
+        assert!(!transcript.reopen_last_response());
+        assert_eq!(transcript.len(), 1);
+    }
+
     #[test]
     fn test_add_tool_call() {
         let mut transcript = Transcript::new();
@@ -259,6 +429,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_expand_pending_approval() {
+        let mut transcript = Transcript::new();
+        assert!(!transcript.expand_pending_approval());
+
+        transcript.add(TranscriptEntry::approval_prompt("tool:edit", "risky").with_approval_diff("+new"));
+        assert!(transcript.expand_pending_approval());
+        assert!(matches!(
+            transcript.last(),
+            Some(TranscriptEntry::ApprovalPrompt { detail_level: CardDetailLevel::Verbose, diff: Some(_), .. })
+        ));
+
+        transcript.set_approval_decision(ApprovalDecision::Approved);
+        assert!(!transcript.expand_pending_approval());
+    }
+
+    #[test]
+    fn test_reasoning_tokens_collapse_into_thinking_block() {
+        let mut transcript = Transcript::new();
+        transcript.add_reasoning_token("Let me ");
+        transcript.add_reasoning_token("check the tests.");
+        assert_eq!(transcript.len(), 1);
+
+        if let TranscriptEntry::ThinkingIndicator { content, streaming, detail_level, .. } = transcript.last().unwrap()
+        {
+            assert_eq!(content, "Let me check the tests.");
+            assert!(*streaming);
+            assert_eq!(*detail_level, CardDetailLevel::Brief);
+        } else {
+            panic!("Expected ThinkingIndicator");
+        }
+
+        transcript.add_streaming_token("Done");
+        assert_eq!(transcript.len(), 2);
+        assert!(matches!(
+            transcript.entries()[0],
+            TranscriptEntry::ThinkingIndicator { streaming: false, .. }
+        ));
+        assert!(transcript.entries()[0].is_action_card());
+    }
+
     #[test]
     fn test_streaming_after_other_entry() {
         let mut transcript = Transcript::new();
@@ -300,12 +511,59 @@ mod tests {
             transcript.add_user_message(format!("Message {}", i));
         }
 
-        assert_eq!(transcript.len(), 5);
-        if let TranscriptEntry::UserMessage { content } = transcript.entries().front().unwrap() {
+        assert_eq!(transcript.len(), 6);
+        assert_eq!(transcript.hidden_entries(), 5);
+        match &transcript.entries()[0] {
+            TranscriptEntry::SystemMessage { content } => {
+                assert!(content.contains("5 earlier entries hidden"));
+                assert!(content.contains("/search"));
+            }
+            other => panic!("expected hidden entries marker, got {:?}", other),
+        }
+        if let TranscriptEntry::UserMessage { content } = &transcript.entries()[1] {
             assert_eq!(content, "Message 5");
         }
     }
 
+    #[test]
+    fn test_eviction_keeps_pending_approval() {
+        let mut transcript = Transcript::with_capacity(3);
+        transcript.add_approval_prompt("shell: rm -rf build", "risky");
+        for i in 0..5 {
+            transcript.add_user_message(format!("Message {}", i));
+        }
+
+        assert!(transcript.has_pending_approval());
+        assert!(matches!(
+            transcript.entries()[1],
+            TranscriptEntry::ApprovalPrompt { .. }
+        ));
+        assert_eq!(transcript.hidden_entries(), 3);
+        assert_eq!(transcript.len(), 4);
+
+        transcript.set_approval_decision(ApprovalDecision::Approved);
+        transcript.add_user_message("Message 5");
+        assert!(!transcript.has_pending_approval());
+        assert_eq!(transcript.hidden_entries(), 4);
+    }
+
+    #[test]
+    fn test_set_max_entries_evicts() {
+        let mut transcript = Transcript::new();
+        assert_eq!(transcript.max_entries(), 1000);
+        for i in 0..10 {
+            transcript.add_user_message(format!("Message {}", i));
+        }
+
+        transcript.set_max_entries(4);
+        assert_eq!(transcript.max_entries(), 4);
+        assert_eq!(transcript.hidden_entries(), 6);
+        assert_eq!(transcript.get_user_messages().first().unwrap(), "Message 6");
+
+        transcript.clear();
+        assert_eq!(transcript.hidden_entries(), 0);
+    }
+
     #[test]
     fn test_entry_order() {
         let mut transcript = Transcript::new();
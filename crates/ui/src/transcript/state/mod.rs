@@ -5,6 +5,7 @@ mod scroll;
 use crate::transcript::entry::TranscriptEntry;
 
 use std::collections::VecDeque;
+use std::time::Instant;
 
 /// Transcript manages a conversation history with entries
 ///
@@ -20,21 +21,32 @@ pub struct Transcript {
     max_entries: usize,
     scroll_offset: usize,
     focused_card_index: Option<usize>,
+    /// Number of entries evicted to stay under `max_entries`
+    ///
+    /// When non-zero, the front entry is a marker noting the hidden history.
+    hidden_entries: usize,
+    /// When the currently streaming reasoning block started
+    reasoning_started_at: Option<Instant>,
 }
 
+/// Default cap on transcript entries kept in memory
+pub const DEFAULT_MAX_TRANSCRIPT_ENTRIES: usize = 1000;
+
 impl Transcript {
     /// Create a new transcript with default max entries
     pub fn new() -> Self {
-        Self { entries: VecDeque::with_capacity(100), max_entries: 1000, scroll_offset: 0, focused_card_index: None }
+        Self::with_capacity(DEFAULT_MAX_TRANSCRIPT_ENTRIES)
     }
 
     /// Create a new transcript with custom max entries
     pub fn with_capacity(max_entries: usize) -> Self {
         Self {
             entries: VecDeque::with_capacity(max_entries.min(100)),
-            max_entries,
+            max_entries: max_entries.max(1),
             scroll_offset: 0,
             focused_card_index: None,
+            hidden_entries: 0,
+            reasoning_started_at: None,
         }
     }
 }
@@ -4,9 +4,21 @@
 //! user input handling, using channels to communicate across the async/sync boundary.
 
 use std::sync::{Arc, Mutex};
-use thunderus_core::{ApprovalDecision, ApprovalProtocol, ApprovalRequest, Error, Result};
+use thunderus_core::{ApprovalDecision, ApprovalProtocol, ApprovalRequest, ApprovalResponse, Error, Result};
 use tokio::sync::{mpsc, oneshot};
 
+/// Response senders for requests awaiting a decision, keyed by request ID
+type PendingResponses = Arc<Mutex<std::collections::HashMap<u64, oneshot::Sender<ApprovalResponse>>>>;
+
+/// Deliver `response` to whoever is waiting on its request
+fn deliver(pending_responses: &PendingResponses, response: ApprovalResponse) -> bool {
+    let mut responses = pending_responses.lock().unwrap();
+    match responses.remove(&response.request_id) {
+        Some(tx) => tx.send(response).is_ok(),
+        None => false,
+    }
+}
+
 /// TUI approval protocol that bridges async agent and sync TUI
 ///
 /// When the agent requests approval, it sends the request to a channel and
@@ -15,7 +27,7 @@ pub struct TuiApprovalProtocol {
     /// Sender for approval requests (agent → TUI)
     request_tx: mpsc::UnboundedSender<ApprovalRequest>,
     /// Pending approval responses (request_id → response sender)
-    pending_responses: Arc<Mutex<std::collections::HashMap<u64, oneshot::Sender<ApprovalDecision>>>>,
+    pending_responses: PendingResponses,
 }
 
 impl TuiApprovalProtocol {
@@ -33,8 +45,7 @@ impl TuiApprovalProtocol {
     ///
     /// Called by the TUI event loop when the user responds to an approval prompt.
     pub fn respond(&self, request_id: u64, decision: ApprovalDecision) -> bool {
-        let mut responses = self.pending_responses.lock().unwrap();
-        if let Some(tx) = responses.remove(&request_id) { tx.send(decision).is_ok() } else { false }
+        deliver(&self.pending_responses, ApprovalResponse::new(request_id, decision))
     }
 
     /// Get the number of pending approval responses
@@ -45,6 +56,10 @@ impl TuiApprovalProtocol {
 
 impl ApprovalProtocol for TuiApprovalProtocol {
     fn request_approval(&self, request: &ApprovalRequest) -> Result<ApprovalDecision> {
+        self.request_response(request).map(|response| response.decision)
+    }
+
+    fn request_response(&self, request: &ApprovalRequest) -> Result<ApprovalResponse> {
         self.request_tx
             .send(request.clone())
             .map_err(|e| Error::Approval(format!("{:?}", e)))?;
@@ -57,8 +72,8 @@ impl ApprovalProtocol for TuiApprovalProtocol {
         }
 
         match rx.blocking_recv() {
-            Ok(decision) => Ok(decision),
-            Err(_) => Ok(ApprovalDecision::Cancelled),
+            Ok(response) => Ok(response),
+            Err(_) => Ok(ApprovalResponse::cancelled(request.id)),
         }
     }
 
@@ -70,7 +85,7 @@ impl ApprovalProtocol for TuiApprovalProtocol {
 /// Cloneable handle to the TUI approval protocol for sending responses
 #[derive(Clone)]
 pub struct TuiApprovalHandle {
-    pending_responses: Arc<Mutex<std::collections::HashMap<u64, oneshot::Sender<ApprovalDecision>>>>,
+    pending_responses: PendingResponses,
 }
 
 impl TuiApprovalHandle {
@@ -81,8 +96,15 @@ impl TuiApprovalHandle {
 
     /// Send an approval response
     pub fn respond(&self, request_id: u64, decision: ApprovalDecision) -> bool {
-        let mut responses = self.pending_responses.lock().unwrap();
-        if let Some(tx) = responses.remove(&request_id) { tx.send(decision).is_ok() } else { false }
+        deliver(&self.pending_responses, ApprovalResponse::new(request_id, decision))
+    }
+
+    /// Reject a request, passing the user's reason along to the agent
+    pub fn reject_with_reason(&self, request_id: u64, reason: impl Into<String>) -> bool {
+        deliver(
+            &self.pending_responses,
+            ApprovalResponse::rejected(request_id).with_message(reason),
+        )
     }
 }
 
@@ -146,6 +168,29 @@ mod tests {
         assert_eq!(decision, ApprovalDecision::Rejected);
     }
 
+    #[test]
+    fn test_tui_approval_rejection_with_reason() {
+        let (protocol, mut request_rx) = TuiApprovalProtocol::new();
+
+        let request = ApprovalRequest::new(
+            6,
+            ActionType::Shell,
+            "Execute tool: shell",
+            ApprovalContext::new(),
+            ToolRisk::Risky,
+        );
+
+        let handle = TuiApprovalHandle::from_protocol(&protocol);
+        thread::spawn(move || {
+            let _ = request_rx.blocking_recv();
+            handle.reject_with_reason(6, "use cargo nextest");
+        });
+
+        let response = protocol.request_response(&request).unwrap();
+        assert_eq!(response.decision, ApprovalDecision::Rejected);
+        assert_eq!(response.message.as_deref(), Some("use cargo nextest"));
+    }
+
     #[test]
     fn test_tui_approval_cancellation() {
         let (protocol, mut request_rx) = TuiApprovalProtocol::new();
@@ -206,6 +206,35 @@ fn test_keyboard_approval_flow() {
     assert!(matches!(action, Some(KeyAction::CancelGeneration)));
 }
 
+#[test]
+fn test_keyboard_rejection_with_reason() {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use thunderus_ui::EventHandler;
+
+    let mut app = create_test_app();
+    let state = app.state_mut();
+    state.approval_ui.pending_approval = Some(ApprovalState::pending("shell".to_string(), "risky".to_string()));
+    let mut press = |code| EventHandler::handle_key_event(KeyEvent::new(code, KeyModifiers::NONE), state);
+
+    assert_eq!(press(KeyCode::Char('r')), None);
+    for c in "use make ".chars() {
+        assert_eq!(press(KeyCode::Char(c)), None);
+    }
+    assert_eq!(press(KeyCode::Char('y')), None);
+    assert_eq!(press(KeyCode::Backspace), None);
+    assert_eq!(
+        press(KeyCode::Enter),
+        Some(KeyAction::RejectWithReason { reason: "use make".to_string() })
+    );
+
+    assert_eq!(press(KeyCode::Char('r')), None);
+    assert_eq!(press(KeyCode::Esc), None);
+    assert!(matches!(press(KeyCode::Char('y')), Some(KeyAction::Approve { .. })));
+
+    assert_eq!(press(KeyCode::Char('r')), None);
+    assert!(matches!(press(KeyCode::Enter), Some(KeyAction::Reject { .. })));
+}
+
 #[test]
 fn test_approval_with_different_risk_levels() {
     let mut app = create_test_app();